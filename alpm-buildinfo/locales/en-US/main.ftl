@@ -20,9 +20,9 @@ error-io-derive-schema-data = deriving schema version from BUILDINFO data
 
 error-io-create-output-dir = creating output directory
 
-error-io-create-output-file = creating output file
+error-io-read-pkgbuild = reading a PKGBUILD file
 
-error-io-write-output-file = writing to output file
+error-io-read-pkgbuild-dir = reading a directory of PKGBUILD files
 
 error-deserialize-buildinfo =
   Failed to deserialize BUILDINFO file:
@@ -40,6 +40,35 @@ error-wrong-schema-version =
 error-missing-format-field =
   Missing format field.
 
+error-invalid-line = The line '{ $line }' is not a key value pair delimited by ' = '.
+
+error-unknown-field = The field '{ $keyword }' is unknown.
+
+error-duplicate-field = The field '{ $keyword }' must only be set once.
+
+error-invalid-field-value =
+  The field '{ $keyword }' has the invalid value '{ $value }':
+  { $reason }
+
+error-invalid-fields =
+  { $count ->
+      [one] Found an invalid field
+     *[other] Found { $count } invalid fields
+  } in BUILDINFO data:
+  { $errors }
+
+error-field-line = Line { $line }: { $error }
+
 error-json =
   JSON error:
   { $source }
+
+error-missing-field = The required field '{ $keyword }' is not set.
+
+error-invalid-environment-variable = The environment variable { $variable } has the invalid value '{ $value }'.
+
+error-builder-builddate = creating BUILDINFO data
+
+error-invalid-builddate = The build date { $builddate } is invalid while { $context }
+
+error-provenance-builddate = creating a provenance statement
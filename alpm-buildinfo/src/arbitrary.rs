@@ -0,0 +1,85 @@
+//! Generation of arbitrary [BUILDINFO] data for fuzzing and property tests.
+//!
+//! [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+
+use std::fmt::Display;
+
+use alpm_types::{
+    AbsolutePath,
+    Architecture,
+    BuildEnvironmentOption,
+    BuildTool,
+    BuildToolVersion,
+    FullVersion,
+    InstalledPackage,
+    Name,
+    PackageOption,
+    Packager,
+    Sha256Checksum,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Valid [BUILDINFO] data, that is created by construction from unstructured input.
+///
+/// Either a [BUILDINFOv1] or a [BUILDINFOv2] is created.
+/// All values are created using the [`Arbitrary`] implementations of their respective types, so
+/// that the resulting data can always be parsed as [`BuildInfo`](crate::BuildInfo).
+///
+/// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+/// [BUILDINFOv1]: https://alpm.archlinux.page/specifications/BUILDINFOv1.5.html
+/// [BUILDINFOv2]: https://alpm.archlinux.page/specifications/BUILDINFOv2.5.html
+#[derive(Clone, Debug)]
+pub struct BuildInfoText(String);
+
+impl BuildInfoText {
+    /// Returns the [BUILDINFO] data as string slice.
+    ///
+    /// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Appends a `key = value` line for each of an arbitrary number of `T` to `lines`.
+fn push_all<'a, T: Arbitrary<'a> + Display>(
+    u: &mut Unstructured<'a>,
+    lines: &mut Vec<String>,
+    key: &str,
+) -> Result<()> {
+    for value in u.arbitrary::<Vec<T>>()? {
+        lines.push(format!("{key} = {value}"));
+    }
+
+    Ok(())
+}
+
+impl<'a> Arbitrary<'a> for BuildInfoText {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let v2: bool = u.arbitrary()?;
+
+        let mut lines = vec![
+            format!("format = {}", if v2 { 2 } else { 1 }),
+            format!("pkgname = {}", u.arbitrary::<Name>()?),
+            format!("pkgbase = {}", u.arbitrary::<Name>()?),
+            format!("pkgver = {}", u.arbitrary::<FullVersion>()?),
+            format!("pkgarch = {}", u.arbitrary::<Architecture>()?),
+            format!("pkgbuild_sha256sum = {}", u.arbitrary::<Sha256Checksum>()?),
+            format!("packager = {}", u.arbitrary::<Packager>()?),
+            format!("builddate = {}", u.int_in_range(0..=i64::from(u32::MAX))?),
+            format!("builddir = {}", u.arbitrary::<AbsolutePath>()?),
+        ];
+        if v2 {
+            lines.push(format!("startdir = {}", u.arbitrary::<AbsolutePath>()?));
+            lines.push(format!("buildtool = {}", u.arbitrary::<BuildTool>()?));
+            lines.push(format!(
+                "buildtoolver = {}",
+                u.arbitrary::<BuildToolVersion>()?
+            ));
+        }
+        push_all::<BuildEnvironmentOption>(u, &mut lines, "buildenv")?;
+        push_all::<PackageOption>(u, &mut lines, "options")?;
+        push_all::<InstalledPackage>(u, &mut lines, "installed")?;
+
+        Ok(Self(lines.join("\n") + "\n"))
+    }
+}
@@ -0,0 +1,358 @@
+//! Programmatic creation of [BUILDINFOv2] data.
+//!
+//! [BUILDINFOv2]: https://alpm.archlinux.page/specifications/BUILDINFOv2.5.html
+
+use std::{
+    env::{current_dir, var},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use alpm_types::{
+    Architecture,
+    BuildDate,
+    BuildDirectory,
+    BuildEnvironmentOption,
+    BuildTool,
+    BuildToolVersion,
+    Checksum,
+    FullVersion,
+    InstalledPackage,
+    Name,
+    PackageOption,
+    Packager,
+    StartDirectory,
+    digests::Sha256,
+};
+use fluent_i18n::t;
+
+use crate::{BuildInfoV2, Error};
+
+/// A builder for [`BuildInfoV2`].
+///
+/// All fields of [`BuildInfoV2`] can be set individually.
+/// Required fields are validated when calling [`BuildInfoV2Builder::build`].
+/// If no `pkgbase` is set, the `pkgname` is used for it.
+///
+/// Default values for some fields can be derived from the environment using
+/// [`BuildInfoV2Builder::with_env_defaults`].
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_buildinfo::BuildInfoV2Builder;
+/// use alpm_types::{BuildToolVersion, Checksum};
+///
+/// # fn main() -> testresult::TestResult {
+/// let buildinfo = BuildInfoV2Builder::new()
+///     .pkgname("foo".parse()?)
+///     .pkgver("1:1.0.0-1".parse()?)
+///     .pkgarch("any".parse()?)
+///     .pkgbuild_sha256sum(Checksum::calculate_from("PKGBUILD data"))
+///     .packager("Foobar McFooface <foobar@mcfooface.org>".parse()?)
+///     .builddate(1)
+///     .builddir("/build".parse()?)
+///     .startdir("/startdir".parse()?)
+///     .buildtool("devtools".parse()?)
+///     .buildtoolver(BuildToolVersion::from_str("1:1.2.1-1-any")?)
+///     .installed(vec!["bar-1.2.3-1-any".parse()?])
+///     .build()?;
+///
+/// assert_eq!(buildinfo.pkgbase.to_string(), "foo");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BuildInfoV2Builder {
+    pkgname: Option<Name>,
+    pkgbase: Option<Name>,
+    pkgver: Option<FullVersion>,
+    pkgarch: Option<Architecture>,
+    pkgbuild_sha256sum: Option<Checksum<Sha256>>,
+    packager: Option<Packager>,
+    builddate: Option<BuildDate>,
+    builddir: Option<BuildDirectory>,
+    startdir: Option<StartDirectory>,
+    buildtool: Option<BuildTool>,
+    buildtoolver: Option<BuildToolVersion>,
+    buildenv: Vec<BuildEnvironmentOption>,
+    options: Vec<PackageOption>,
+    installed: Vec<InstalledPackage>,
+}
+
+impl BuildInfoV2Builder {
+    /// Creates a new, empty [`BuildInfoV2Builder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the package.
+    pub fn pkgname(mut self, pkgname: Name) -> Self {
+        self.pkgname = Some(pkgname);
+        self
+    }
+
+    /// Sets the name of the package base.
+    ///
+    /// If unset, the name of the package is used.
+    pub fn pkgbase(mut self, pkgbase: Name) -> Self {
+        self.pkgbase = Some(pkgbase);
+        self
+    }
+
+    /// Sets the full version of the package.
+    pub fn pkgver(mut self, pkgver: FullVersion) -> Self {
+        self.pkgver = Some(pkgver);
+        self
+    }
+
+    /// Sets the architecture of the package.
+    pub fn pkgarch(mut self, pkgarch: Architecture) -> Self {
+        self.pkgarch = Some(pkgarch);
+        self
+    }
+
+    /// Sets the SHA-256 checksum of the [PKGBUILD] used for building the package.
+    ///
+    /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+    pub fn pkgbuild_sha256sum(mut self, pkgbuild_sha256sum: Checksum<Sha256>) -> Self {
+        self.pkgbuild_sha256sum = Some(pkgbuild_sha256sum);
+        self
+    }
+
+    /// Sets the packager of the package.
+    pub fn packager(mut self, packager: Packager) -> Self {
+        self.packager = Some(packager);
+        self
+    }
+
+    /// Sets the build date of the package as seconds since the epoch.
+    pub fn builddate(mut self, builddate: BuildDate) -> Self {
+        self.builddate = Some(builddate);
+        self
+    }
+
+    /// Sets the directory in which the package has been built.
+    pub fn builddir(mut self, builddir: BuildDirectory) -> Self {
+        self.builddir = Some(builddir);
+        self
+    }
+
+    /// Sets the directory from which the build has been started.
+    pub fn startdir(mut self, startdir: StartDirectory) -> Self {
+        self.startdir = Some(startdir);
+        self
+    }
+
+    /// Sets the tool used for building the package.
+    pub fn buildtool(mut self, buildtool: BuildTool) -> Self {
+        self.buildtool = Some(buildtool);
+        self
+    }
+
+    /// Sets the version of the tool used for building the package.
+    pub fn buildtoolver(mut self, buildtoolver: BuildToolVersion) -> Self {
+        self.buildtoolver = Some(buildtoolver);
+        self
+    }
+
+    /// Sets the build environment options.
+    pub fn buildenv(mut self, buildenv: Vec<BuildEnvironmentOption>) -> Self {
+        self.buildenv = buildenv;
+        self
+    }
+
+    /// Sets the package options.
+    pub fn options(mut self, options: Vec<PackageOption>) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets the packages installed in the build environment.
+    pub fn installed(mut self, installed: Vec<InstalledPackage>) -> Self {
+        self.installed = installed;
+        self
+    }
+
+    /// Sets defaults derived from the environment for all fields that are not yet set.
+    ///
+    /// The following defaults are used:
+    ///
+    /// - `packager`: the value of the `PACKAGER` environment variable, if set
+    /// - `builddate`: the value of the `SOURCE_DATE_EPOCH` environment variable, if set, otherwise
+    ///   the current time
+    /// - `builddir`: the value of the `BUILDDIR` environment variable, if set
+    /// - `startdir`: the current working directory, if it can be determined
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the environment variables is set, but its value is invalid.
+    pub fn with_env_defaults(mut self) -> Result<Self, Error> {
+        if self.packager.is_none()
+            && let Ok(packager) = var("PACKAGER")
+        {
+            self.packager = Some(Packager::from_str(&packager)?);
+        }
+
+        if self.builddate.is_none() {
+            self.builddate = Some(match var("SOURCE_DATE_EPOCH") {
+                Ok(value) => value
+                    .parse()
+                    .map_err(|_| Error::InvalidEnvironmentVariable {
+                        variable: "SOURCE_DATE_EPOCH",
+                        value,
+                    })?,
+                Err(_) => SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|duration| duration.as_secs() as BuildDate)
+                    .unwrap_or_default(),
+            });
+        }
+
+        if self.builddir.is_none()
+            && let Ok(builddir) = var("BUILDDIR")
+        {
+            self.builddir = Some(BuildDirectory::from_str(&builddir)?);
+        }
+
+        if self.startdir.is_none()
+            && let Ok(startdir) = current_dir()
+        {
+            self.startdir = Some(StartDirectory::new(startdir)?);
+        }
+
+        Ok(self)
+    }
+
+    /// Creates the [`BuildInfoV2`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - a required field is not set,
+    /// - or the build date is negative.
+    pub fn build(self) -> Result<BuildInfoV2, Error> {
+        let pkgname = required(self.pkgname, "pkgname")?;
+        let builddate = required(self.builddate, "builddate")?;
+        if builddate < 0 {
+            return Err(Error::InvalidBuildDate {
+                builddate,
+                context: t!("error-builder-builddate"),
+            });
+        }
+
+        Ok(BuildInfoV2 {
+            pkgbase: self.pkgbase.unwrap_or_else(|| pkgname.clone()),
+            pkgname,
+            pkgver: required(self.pkgver, "pkgver")?,
+            pkgarch: required(self.pkgarch, "pkgarch")?,
+            pkgbuild_sha256sum: required(self.pkgbuild_sha256sum, "pkgbuild_sha256sum")?,
+            packager: required(self.packager, "packager")?,
+            builddate,
+            builddir: required(self.builddir, "builddir")?,
+            buildenv: self.buildenv,
+            options: self.options,
+            installed: self.installed,
+            startdir: required(self.startdir, "startdir")?,
+            buildtool: required(self.buildtool, "buildtool")?,
+            buildtoolver: required(self.buildtoolver, "buildtoolver")?,
+        })
+    }
+}
+
+/// Returns the value of the required field `keyword`.
+///
+/// # Errors
+///
+/// Returns an error if `value` is [`None`].
+fn required<T>(value: Option<T>, keyword: &'static str) -> Result<T, Error> {
+    value.ok_or(Error::MissingField { keyword })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Returns a [`BuildInfoV2Builder`] with all required fields set.
+    fn complete_builder() -> TestResult<BuildInfoV2Builder> {
+        Ok(BuildInfoV2Builder::new()
+            .pkgname(Name::new("foo")?)
+            .pkgver(FullVersion::from_str("1:1.0.0-1")?)
+            .pkgarch(Architecture::from_str("any")?)
+            .pkgbuild_sha256sum(Checksum::from_str(
+                "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c",
+            )?)
+            .packager(Packager::from_str(
+                "Foobar McFooface <foobar@mcfooface.org>",
+            )?)
+            .builddate(1)
+            .builddir(BuildDirectory::from_str("/build")?)
+            .startdir(StartDirectory::from_str("/startdir/")?)
+            .buildtool(BuildTool::from_str("devtools")?)
+            .buildtoolver(BuildToolVersion::from_str("1:1.2.1-1-any")?)
+            .buildenv(vec![BuildEnvironmentOption::from_str("ccache")?])
+            .options(vec![PackageOption::from_str("lto")?])
+            .installed(vec![InstalledPackage::from_str("bar-1.2.3-1-any")?]))
+    }
+
+    /// Ensures that a [`BuildInfoV2`] created by the builder equals the parsed representation.
+    #[test]
+    fn build_info_v2_builder() -> TestResult {
+        let buildinfo = complete_builder()?.build()?;
+        let expected = BuildInfoV2::from_str(
+            r#"format = 2
+pkgname = foo
+pkgbase = foo
+pkgver = 1:1.0.0-1
+pkgarch = any
+pkgbuild_sha256sum = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+packager = Foobar McFooface <foobar@mcfooface.org>
+builddate = 1
+builddir = /build
+startdir = /startdir/
+buildtool = devtools
+buildtoolver = 1:1.2.1-1-any
+buildenv = ccache
+options = lto
+installed = bar-1.2.3-1-any
+"#,
+        )?;
+
+        assert_eq!(buildinfo, expected);
+        Ok(())
+    }
+
+    /// Ensures that missing required fields are reported.
+    #[test]
+    fn build_info_v2_builder_missing_field() -> TestResult {
+        let result = BuildInfoV2Builder::new()
+            .pkgname(Name::new("foo")?)
+            .builddate(1)
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(Error::MissingField { keyword: "pkgver" })
+        ));
+        Ok(())
+    }
+
+    /// Ensures that negative build dates are rejected.
+    #[test]
+    fn build_info_v2_builder_negative_builddate() -> TestResult {
+        let result = complete_builder()?.builddate(-1).build();
+
+        assert!(matches!(
+            result,
+            Err(Error::InvalidBuildDate { builddate: -1, .. })
+        ));
+        Ok(())
+    }
+}
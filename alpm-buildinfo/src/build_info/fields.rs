@@ -0,0 +1,171 @@
+//! Field-level validation of BUILDINFO data.
+
+use std::{collections::BTreeSet, fmt::Display, str::FromStr};
+
+use crate::{BuildInfoFieldError, Error};
+
+/// A field of a BUILDINFO version.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Field {
+    /// The keyword of the field.
+    keyword: &'static str,
+    /// Whether the field must be set.
+    required: bool,
+    /// Whether the field may be set more than once.
+    multiple: bool,
+    /// Validates a single value of the field.
+    validate: fn(&str) -> Result<(), String>,
+}
+
+impl Field {
+    /// Creates a required [`Field`] called `keyword`, which must be set exactly once.
+    ///
+    /// Its value must be parseable as `T`.
+    pub(crate) const fn single<T>(keyword: &'static str) -> Self
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        Self {
+            keyword,
+            required: true,
+            multiple: false,
+            validate: validate_value::<T>,
+        }
+    }
+
+    /// Creates an optional [`Field`] called `keyword`, which may be set any number of times.
+    ///
+    /// Each of its values must be parseable as `T`.
+    pub(crate) const fn list<T>(keyword: &'static str) -> Self
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        Self {
+            keyword,
+            required: false,
+            multiple: true,
+            validate: validate_value::<T>,
+        }
+    }
+}
+
+/// Validates that `value` can be parsed as `T`.
+fn validate_value<T>(value: &str) -> Result<(), String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    T::from_str(value)
+        .map(|_| ())
+        .map_err(|error| error.to_string())
+}
+
+/// Validates each line of BUILDINFO data in `input` against `fields`.
+///
+/// Instead of stopping at the first error, all errors are collected.
+/// This includes invalid lines, unknown fields, invalid values, fields that must only be set once
+/// but are set more than once, and missing required fields.
+///
+/// # Errors
+///
+/// Returns an [`Error::InvalidFields`] with all collected errors, if any error is encountered.
+pub(crate) fn validate_fields(input: &str, fields: &[Field]) -> Result<(), Error> {
+    let mut errors = Vec::new();
+    let mut seen = BTreeSet::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let mut push = |error: Error| {
+            errors.push(BuildInfoFieldError {
+                line: Some(index + 1),
+                error,
+            })
+        };
+
+        let content = line.trim_start();
+        if content.is_empty() || content.starts_with('#') {
+            continue;
+        }
+
+        let Some((keyword, value)) = content
+            .split_once(" = ")
+            .filter(|(keyword, _)| !keyword.is_empty() && !keyword.contains([' ', '=']))
+        else {
+            push(Error::InvalidLine {
+                line: line.to_string(),
+            });
+            continue;
+        };
+
+        let Some(field) = fields.iter().find(|field| field.keyword == keyword) else {
+            push(Error::UnknownField {
+                keyword: keyword.to_string(),
+            });
+            continue;
+        };
+
+        if !seen.insert(field.keyword) && !field.multiple {
+            push(Error::DuplicateField {
+                keyword: field.keyword,
+            });
+            continue;
+        }
+
+        if let Err(reason) = (field.validate)(value) {
+            push(Error::InvalidFieldValue {
+                keyword: field.keyword,
+                value: value.to_string(),
+                reason,
+            });
+        }
+    }
+
+    for field in fields {
+        if field.required && !seen.contains(field.keyword) {
+            errors.push(BuildInfoFieldError {
+                line: None,
+                error: Error::MissingField {
+                    keyword: field.keyword,
+                },
+            });
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InvalidFields(errors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alpm_types::Name;
+    use rstest::rstest;
+
+    use super::*;
+
+    const FIELDS: &[Field] = &[Field::single::<Name>("name"), Field::list::<u8>("number")];
+
+    /// Ensures that all errors are collected together with their line numbers.
+    #[rstest]
+    #[case::valid("name = foo\nnumber = 1\nnumber = 2\n", &[])]
+    #[case::comment("# comment\nname = foo\n", &[])]
+    #[case::invalid_line("name = foo\nnumber=1\n", &[Some(2)])]
+    #[case::unknown_field("name = foo\nunknown = 1\n", &[Some(2)])]
+    #[case::duplicate_field("name = foo\nname = bar\n", &[Some(2)])]
+    #[case::invalid_values("name = -foo\nnumber = 1\nnumber = 256\n", &[Some(1), Some(3)])]
+    #[case::missing_field("number = 1\n", &[None])]
+    #[case::multiple("name = foo\nname = bar\nnumber = x\nfoo = bar\n", &[Some(2), Some(3), Some(4)])]
+    fn validate(#[case] input: &str, #[case] lines: &[Option<usize>]) {
+        match validate_fields(input, FIELDS) {
+            Ok(()) => assert!(lines.is_empty()),
+            Err(Error::InvalidFields(errors)) => assert_eq!(
+                errors.iter().map(|error| error.line).collect::<Vec<_>>(),
+                lines
+            ),
+            Err(error) => panic!("Unexpected error: {error}"),
+        }
+    }
+}
@@ -1,19 +1,17 @@
-use alpm_common::FileFormatSchema;
-use alpm_types::SchemaVersion;
-use serde_with::{DisplayFromStr, serde_as};
-
-use crate::BuildInfoSchema;
-
-/// Used internally to detect the BUILDINFO version when deserializing.
-#[serde_as]
-#[derive(Clone, Debug, serde::Deserialize)]
-pub(crate) struct BuildInfoFormat {
-    #[serde_as(as = "DisplayFromStr")]
-    pub format: BuildInfoSchema,
-}
-
-impl From<BuildInfoFormat> for SchemaVersion {
-    fn from(format: BuildInfoFormat) -> Self {
-        format.format.inner().clone()
+/// Inserts the `format` property with the value `version` into the JSON Schema of an object.
+///
+/// The `format` field of [`BuildInfoV1`](crate::BuildInfoV1) and
+/// [`BuildInfoV2`](crate::BuildInfoV2) is not a struct field, but is added as a tag during
+/// serialization, which is not reflected in their derived JSON Schema.
+#[cfg(feature = "schemars")]
+pub(crate) fn insert_format_property(schema: &mut schemars::Schema, version: &str) {
+    if let Some(serde_json::Value::Object(properties)) = schema.get_mut("properties") {
+        properties.insert(
+            "format".to_string(),
+            serde_json::json!({ "type": "string", "const": version }),
+        );
+    }
+    if let Some(serde_json::Value::Array(required)) = schema.get_mut("required") {
+        required.insert(0, "format".into());
     }
 }
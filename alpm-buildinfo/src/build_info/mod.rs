@@ -1,5 +1,7 @@
 //! Handling of BuildInfo versions.
 
+pub mod builder;
+mod fields;
 mod format;
 pub mod v1;
 pub mod v2;
@@ -22,6 +24,7 @@ use crate::{BuildInfoSchema, BuildInfoV1, BuildInfoV2, Error};
 ///
 /// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
 #[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum BuildInfo {
     /// The [BUILDINFOv1] file format.
@@ -3,6 +3,7 @@ use std::{
     str::FromStr,
 };
 
+use alpm_common::FileFormatSchema;
 use alpm_types::{
     Architecture,
     BuildDate,
@@ -20,7 +21,11 @@ use alpm_types::{
 };
 use serde_with::{DisplayFromStr, serde_as};
 
-use crate::{BuildInfoSchema, Error, build_info::format::BuildInfoFormat};
+use crate::{
+    BuildInfoSchema,
+    Error,
+    build_info::fields::{Field, validate_fields},
+};
 
 /// BUILDINFO version 1
 ///
@@ -58,8 +63,13 @@ use crate::{BuildInfoSchema, Error, build_info::format::BuildInfoFormat};
 /// # }
 /// ```
 #[serde_as]
-#[derive(Clone, Debug, serde::Deserialize, PartialEq, serde_more::SerializeMore)]
-#[more(key = "format", position = "front")]
+#[derive(Clone, Debug, serde::Deserialize, PartialEq, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(rename = "BuildInfoV1", transform = insert_format_property_v1)
+)]
+#[serde(tag = "format", rename(serialize = "1"))]
 pub struct BuildInfoV1 {
     /// The package name
     #[serde_as(as = "DisplayFromStr")]
@@ -110,12 +120,34 @@ pub struct BuildInfoV1 {
 }
 
 impl BuildInfoV1 {
-    /// Used by serde_more to serialize the additional `format` field.
+    /// Returns the `format` of the BUILDINFO data.
     fn format(&self) -> String {
         BuildInfoSchema::V1(SchemaVersion::new(SemverVersion::new(1, 0, 0))).to_string()
     }
 }
 
+/// Inserts the `format` property of [`BuildInfoV1`] into its JSON Schema.
+#[cfg(feature = "schemars")]
+fn insert_format_property_v1(schema: &mut schemars::Schema) {
+    crate::build_info::format::insert_format_property(schema, "1");
+}
+
+/// The fields of [`BuildInfoV1`].
+const FIELDS: &[Field] = &[
+    Field::single::<BuildInfoSchema>("format"),
+    Field::single::<Name>("pkgname"),
+    Field::single::<Name>("pkgbase"),
+    Field::single::<FullVersion>("pkgver"),
+    Field::single::<Architecture>("pkgarch"),
+    Field::single::<Checksum<Sha256>>("pkgbuild_sha256sum"),
+    Field::single::<Packager>("packager"),
+    Field::single::<BuildDate>("builddate"),
+    Field::single::<BuildDirectory>("builddir"),
+    Field::list::<BuildEnvironmentOption>("buildenv"),
+    Field::list::<PackageOption>("options"),
+    Field::list::<InstalledPackage>("installed"),
+];
+
 impl FromStr for BuildInfoV1 {
     type Err = Error;
     /// Create a BuildInfoV1 from a &str
@@ -124,13 +156,14 @@ impl FromStr for BuildInfoV1 {
     ///
     /// Returns an `Error` if any of the fields in `input` can not be validated according to
     /// `BuildInfoV1` or their respective own specification.
+    /// All invalid fields are reported at once using [`Error::InvalidFields`].
     fn from_str(input: &str) -> Result<BuildInfoV1, Self::Err> {
-        let build_info_format: BuildInfoFormat = alpm_parsers::custom_ini::from_str(input)?;
-        let schema_version: SchemaVersion = build_info_format.into();
+        let schema_version = BuildInfoSchema::derive_from_str(input)?.inner().clone();
         if schema_version.inner().major != 1 {
             return Err(Error::WrongSchemaVersion(schema_version));
         }
 
+        validate_fields(input, FIELDS)?;
         let buildinfo: BuildInfoV1 = alpm_parsers::custom_ini::from_str(input)?;
         Ok(buildinfo)
     }
@@ -274,4 +307,22 @@ pkgver = 1:1.0.0-1
         valid_buildinfov1.push_str(duplicate);
         assert!(BuildInfoV1::from_str(&valid_buildinfov1).is_err());
     }
+
+    /// Ensures that all invalid fields are reported at once.
+    #[rstest]
+    fn buildinfov1_from_str_collects_errors(valid_buildinfov1: String) -> TestResult {
+        let buildinfo = valid_buildinfov1
+            .replace("pkgarch = any", "pkgarch = any any")
+            .replace("pkgname = foo", "pkgname = -foo")
+            + "pkgbase = foo\nunknown = foo\n";
+
+        let Err(Error::InvalidFields(errors)) = BuildInfoV1::from_str(&buildinfo) else {
+            panic!("Expected invalid fields");
+        };
+        assert_eq!(
+            errors.iter().map(|error| error.line).collect::<Vec<_>>(),
+            vec![Some(11), Some(14), Some(16), Some(17)]
+        );
+        Ok(())
+    }
 }
@@ -3,6 +3,7 @@ use std::{
     str::FromStr,
 };
 
+use alpm_common::FileFormatSchema;
 use alpm_types::{
     Architecture,
     BuildDate,
@@ -23,7 +24,11 @@ use alpm_types::{
 };
 use serde_with::{DisplayFromStr, serde_as};
 
-use crate::{BuildInfoSchema, Error, build_info::format::BuildInfoFormat};
+use crate::{
+    BuildInfoSchema,
+    Error,
+    build_info::fields::{Field, validate_fields},
+};
 
 /// BUILDINFO version 2
 ///
@@ -64,8 +69,13 @@ use crate::{BuildInfoSchema, Error, build_info::format::BuildInfoFormat};
 /// # }
 /// ```
 #[serde_as]
-#[derive(Clone, Debug, serde::Deserialize, PartialEq, serde_more::SerializeMore)]
-#[more(key = "format", position = "front")]
+#[derive(Clone, Debug, serde::Deserialize, PartialEq, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(rename = "BuildInfoV2", transform = insert_format_property_v2)
+)]
+#[serde(tag = "format", rename(serialize = "2"))]
 pub struct BuildInfoV2 {
     /// The package name
     #[serde_as(as = "DisplayFromStr")]
@@ -128,12 +138,37 @@ pub struct BuildInfoV2 {
 }
 
 impl BuildInfoV2 {
-    /// Used by serde_more to serialize the additional `format` field.
+    /// Returns the `format` of the BUILDINFO data.
     fn format(&self) -> String {
         BuildInfoSchema::V2(SchemaVersion::new(SemverVersion::new(2, 0, 0))).to_string()
     }
 }
 
+/// Inserts the `format` property of [`BuildInfoV2`] into its JSON Schema.
+#[cfg(feature = "schemars")]
+fn insert_format_property_v2(schema: &mut schemars::Schema) {
+    crate::build_info::format::insert_format_property(schema, "2");
+}
+
+/// The fields of [`BuildInfoV2`].
+const FIELDS: &[Field] = &[
+    Field::single::<BuildInfoSchema>("format"),
+    Field::single::<Name>("pkgname"),
+    Field::single::<Name>("pkgbase"),
+    Field::single::<FullVersion>("pkgver"),
+    Field::single::<Architecture>("pkgarch"),
+    Field::single::<Checksum<Sha256>>("pkgbuild_sha256sum"),
+    Field::single::<Packager>("packager"),
+    Field::single::<BuildDate>("builddate"),
+    Field::single::<BuildDirectory>("builddir"),
+    Field::single::<StartDirectory>("startdir"),
+    Field::single::<BuildTool>("buildtool"),
+    Field::single::<BuildToolVersion>("buildtoolver"),
+    Field::list::<BuildEnvironmentOption>("buildenv"),
+    Field::list::<PackageOption>("options"),
+    Field::list::<InstalledPackage>("installed"),
+];
+
 impl FromStr for BuildInfoV2 {
     type Err = Error;
     /// Create a BuildInfoV2 from a &str
@@ -142,13 +177,14 @@ impl FromStr for BuildInfoV2 {
     ///
     /// Returns an `Error` if any of the fields in `input` can not be validated according to
     /// `BuildInfoV2` or their respective own specification.
+    /// All invalid fields are reported at once using [`Error::InvalidFields`].
     fn from_str(input: &str) -> Result<BuildInfoV2, Self::Err> {
-        let build_info_format: BuildInfoFormat = alpm_parsers::custom_ini::from_str(input)?;
-        let schema_version: SchemaVersion = build_info_format.into();
+        let schema_version = BuildInfoSchema::derive_from_str(input)?.inner().clone();
         if schema_version.inner().major != 2 {
             return Err(Error::WrongSchemaVersion(schema_version));
         }
 
+        validate_fields(input, FIELDS)?;
         let buildinfo: BuildInfoV2 = alpm_parsers::custom_ini::from_str(input)?;
         Ok(buildinfo)
     }
@@ -1,23 +1,25 @@
 //! Commandline functions, that're called by the `alpm-buildinfo` executable.
 
 use std::{
-    fs::{File, create_dir_all},
-    io::{self, IsTerminal, Write},
+    fs::create_dir_all,
+    io::{self, IsTerminal},
     str::FromStr,
 };
 
-use alpm_buildinfo::{
+use alpm_common::{ErrorCategory, MetadataFile, write_atomic};
+use alpm_types::Sha256Checksum;
+use fluent_i18n::t;
+use schemars::generate::SchemaSettings;
+use thiserror::Error;
+
+use crate::{
     BuildInfo,
     BuildInfoV1,
     BuildInfoV2,
     cli::{CreateCommand, OutputFormat, ValidateArgs},
 };
-use alpm_common::MetadataFile;
-use alpm_types::Sha256Checksum;
-use fluent_i18n::t;
-use thiserror::Error;
 
-/// A high-level error wrapper around [`alpm_buildinfo::Error`] to add CLI error cases.
+/// A high-level error wrapper around [`crate::Error`] to add CLI error cases.
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum Error {
@@ -33,9 +35,26 @@ pub enum Error {
     #[error("{msg}", msg = t!("error-json", { "source" => .0.to_string() }))]
     Json(#[from] serde_json::Error),
 
-    /// An [alpm_buildinfo::Error]
+    /// An [alpm_common::Error]
+    #[error(transparent)]
+    AlpmCommon(#[from] alpm_common::Error),
+
+    /// An [crate::Error]
     #[error(transparent)]
-    BuildInfo(#[from] alpm_buildinfo::Error),
+    BuildInfo(#[from] crate::Error),
+}
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::AlpmType(error) => error.into(),
+            Error::NoInputFile => Self::Io,
+            Error::Json(_) => Self::Parse,
+            Error::AlpmCommon(error) => error.into(),
+            Error::BuildInfo(error) => error.into(),
+        }
+    }
 }
 
 /// Create a file according to a BUILDINFO schema
@@ -87,26 +106,14 @@ pub fn create_file(command: CreateCommand) -> Result<(), Error> {
 
     // create any parent directories if necessary
     if let Some(output_dir) = output.0.parent() {
-        create_dir_all(output_dir).map_err(|source| alpm_buildinfo::Error::IoPath {
+        create_dir_all(output_dir).map_err(|source| crate::Error::IoPath {
             path: output_dir.to_path_buf(),
             context: t!("error-io-create-output-dir"),
             source,
         })?;
     }
 
-    let mut out = File::create(&output.0).map_err(|source| alpm_buildinfo::Error::IoPath {
-        path: output.0.clone(),
-        context: t!("error-io-create-output-file"),
-        source,
-    })?;
-
-    let _ = out
-        .write(data.as_bytes())
-        .map_err(|source| alpm_buildinfo::Error::IoPath {
-            path: output.0,
-            context: t!("error-io-write-output-file"),
-            source,
-        })?;
+    write_atomic(&output.0, data)?;
 
     Ok(())
 }
@@ -159,3 +166,25 @@ pub fn format(args: ValidateArgs, output_format: OutputFormat, pretty: bool) ->
     }
     Ok(())
 }
+
+/// Prints the JSON Schema of the JSON output of [`format`] on stdout.
+///
+/// The JSON Schema is generated from [`BuildInfo`] and describes all of its versions.
+/// If `pretty` is `true`, the JSON Schema is pretty-printed.
+///
+/// # Errors
+///
+/// Returns an error if the JSON Schema cannot be serialized.
+pub fn json_schema(pretty: bool) -> Result<(), Error> {
+    let schema = SchemaSettings::draft2020_12()
+        .for_serialize()
+        .into_generator()
+        .into_root_schema_for::<BuildInfo>();
+    let json = if pretty {
+        serde_json::to_string_pretty(&schema)?
+    } else {
+        serde_json::to_string(&schema)?
+    };
+    println!("{json}");
+    Ok(())
+}
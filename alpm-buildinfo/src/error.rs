@@ -1,5 +1,10 @@
-use std::{path::PathBuf, string::FromUtf8Error};
+use std::{
+    fmt::{Display, Formatter},
+    path::PathBuf,
+    string::FromUtf8Error,
+};
 
+use alpm_common::ErrorCategory;
 use alpm_types::SchemaVersion;
 use fluent_i18n::t;
 
@@ -58,7 +63,139 @@ pub enum Error {
     #[error("{msg}", msg = t!("error-wrong-schema-version", { "version" => .0.to_string() }))]
     WrongSchemaVersion(SchemaVersion),
 
+    /// A JSON error.
+    #[error("{msg}", msg = t!("error-json", { "source" => .0.to_string() }))]
+    Json(#[from] serde_json::Error),
+
+    /// A build date cannot be represented as a timestamp.
+    #[error("{msg}", msg = t!("error-invalid-builddate", {
+        "builddate" => builddate.to_string(),
+        "context" => context
+    }))]
+    InvalidBuildDate {
+        /// The invalid build date.
+        builddate: i64,
+        /// The context in which the error occurred.
+        ///
+        /// This is meant to complete the sentence "The build date ... is invalid while ".
+        context: String,
+    },
+
+    /// A required field is missing when creating BUILDINFO data.
+    #[error("{msg}", msg = t!("error-missing-field", { "keyword" => keyword }))]
+    MissingField {
+        /// The keyword of the missing field.
+        keyword: &'static str,
+    },
+
+    /// An environment variable has an invalid value.
+    #[error("{msg}", msg = t!("error-invalid-environment-variable", {
+        "variable" => variable,
+        "value" => value
+    }))]
+    InvalidEnvironmentVariable {
+        /// The name of the environment variable.
+        variable: &'static str,
+        /// The invalid value of the environment variable.
+        value: String,
+    },
+
     /// BuildInfo file is missing the format field.
     #[error("{msg}", msg = t!("error-missing-format-field"))]
     MissingFormatField,
+
+    /// A line of BUILDINFO data is not a key value pair.
+    #[error("{msg}", msg = t!("error-invalid-line", { "line" => line }))]
+    InvalidLine {
+        /// The content of the invalid line.
+        line: String,
+    },
+
+    /// A field of BUILDINFO data is unknown.
+    #[error("{msg}", msg = t!("error-unknown-field", { "keyword" => keyword }))]
+    UnknownField {
+        /// The keyword of the unknown field.
+        keyword: String,
+    },
+
+    /// A field of BUILDINFO data, that may only be set once, is set more than once.
+    #[error("{msg}", msg = t!("error-duplicate-field", { "keyword" => keyword }))]
+    DuplicateField {
+        /// The keyword of the duplicate field.
+        keyword: &'static str,
+    },
+
+    /// A field of BUILDINFO data has an invalid value.
+    #[error("{msg}", msg = t!("error-invalid-field-value", {
+        "keyword" => keyword,
+        "value" => value,
+        "reason" => reason
+    }))]
+    InvalidFieldValue {
+        /// The keyword of the field.
+        keyword: &'static str,
+        /// The invalid value.
+        value: String,
+        /// The reason why the value is invalid.
+        reason: String,
+    },
+
+    /// One or more fields of BUILDINFO data are invalid.
+    #[error("{msg}", msg = t!("error-invalid-fields", {
+        "count" => .0.len(),
+        "errors" => .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n")
+    }))]
+    InvalidFields(Vec<BuildInfoFieldError>),
+}
+
+/// An [`Error`] related to a field of BUILDINFO data, with the line it relates to.
+#[derive(Debug, thiserror::Error)]
+pub struct BuildInfoFieldError {
+    /// The line number (starting at 1) at which the error occurred.
+    ///
+    /// Is [`None`] if the error does not relate to a specific line (e.g. if a required field is
+    /// missing).
+    pub line: Option<usize>,
+    /// The error.
+    pub error: Error,
+}
+
+impl Display for BuildInfoFieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(
+                f,
+                "{}",
+                t!("error-field-line", {
+                    "line" => line,
+                    "error" => self.error.to_string()
+                })
+            ),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::AlpmType(error) => error.into(),
+            Error::IoPath { .. } | Error::IoRead { .. } => Self::Io,
+            Error::InvalidUTF8(_)
+            | Error::Deserialization(_)
+            | Error::Json(_)
+            | Error::InvalidLine { .. } => Self::Parse,
+            Error::UnsupportedSchemaVersion(_)
+            | Error::WrongSchemaVersion(_)
+            | Error::MissingFormatField => Self::Schema,
+            Error::InvalidBuildDate { .. }
+            | Error::MissingField { .. }
+            | Error::InvalidEnvironmentVariable { .. }
+            | Error::UnknownField { .. }
+            | Error::DuplicateField { .. }
+            | Error::InvalidFieldValue { .. }
+            | Error::InvalidFields(_) => Self::Validation,
+        }
+    }
 }
@@ -1,17 +1,34 @@
 #![doc = include_str!("../README.md")]
 
 mod build_info;
-pub use crate::build_info::{BuildInfo, v1::BuildInfoV1, v2::BuildInfoV2};
+pub use crate::build_info::{
+    BuildInfo,
+    builder::BuildInfoV2Builder,
+    v1::BuildInfoV1,
+    v2::BuildInfoV2,
+};
+
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
 
 /// Commandline argument handling. This is most likely not interesting for you.
 #[cfg(feature = "cli")]
 #[doc(hidden)]
 pub mod cli;
 
+#[cfg(feature = "cli")]
+#[doc(hidden)]
+pub mod commands;
+
 mod error;
-pub use error::Error;
+pub use error::{BuildInfoFieldError, Error};
+
+pub mod provenance;
 
 mod schema;
 pub use schema::BuildInfoSchema;
 
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 fluent_i18n::i18n!("locales");
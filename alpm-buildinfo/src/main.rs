@@ -2,19 +2,20 @@
 
 use std::process::ExitCode;
 
-use alpm_buildinfo::cli::{Cli, Command};
+use alpm_buildinfo::{
+    cli::{Cli, Command},
+    commands::{create_file, format, json_schema, validate},
+};
+use alpm_common::ErrorCategory;
 use clap::Parser;
 
-mod commands;
-
-use commands::{create_file, format, validate};
-
 fluent_i18n::i18n!("locales");
 
 /// The main entrypoint for the `alpm-buildinfo` executable.
 ///
 /// Returns an [`ExitCode::SUCCESS`] if the chosen command succeeded.
-/// Returns an [`ExitCode::FAILURE`] and prints an error on stderr if the chosen command failed.
+/// Returns an [`ExitCode`] based on the [`ErrorCategory`] of the error and prints the error on
+/// stderr if the chosen command failed.
 fn main() -> ExitCode {
     let cli = Cli::parse();
     let result = match cli.command {
@@ -25,11 +26,12 @@ fn main() -> ExitCode {
             output_format,
             pretty,
         } => format(args, output_format, pretty),
+        Command::JsonSchema { pretty } => json_schema(pretty),
     };
 
     if let Err(error) = result {
         eprintln!("{error}");
-        ExitCode::FAILURE
+        ErrorCategory::from(&error).into()
     } else {
         ExitCode::SUCCESS
     }
@@ -0,0 +1,479 @@
+//! Export of [BUILDINFO] data as [in-toto] provenance attestations.
+//!
+//! A [`BuildInfo`] is converted into an [in-toto Statement] carrying a [SLSA Provenance]
+//! predicate, so that ALPM based packages can take part in standard supply-chain attestation
+//! flows.
+//!
+//! Additionally, the [PKGBUILD] from which a package has been built can be looked up by comparing
+//! the `pkgbuild_sha256sum` of a [`BuildInfo`] with the SHA-256 checksums of candidate files (see
+//! [`BuildInfo::find_pkgbuilds`] and [`BuildInfo::find_pkgbuilds_in_dir`]).
+//!
+//! [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+//! [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+//! [SLSA Provenance]: https://slsa.dev/spec/v1.0/provenance
+//! [in-toto Statement]: https://github.com/in-toto/attestation/blob/main/spec/v1/statement.md
+//! [in-toto]: https://in-toto.io
+
+use std::{
+    collections::BTreeMap,
+    fs::{File, read_dir},
+    path::{Path, PathBuf},
+};
+
+use alpm_types::{Checksum, ChecksumVerificationError, InstalledPackage, digests::Sha256};
+use fluent_i18n::t;
+use serde::Serialize;
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+
+use crate::{BuildInfo, Error};
+
+/// The type identifier of an [in-toto Statement] v1.
+///
+/// [in-toto Statement]: https://github.com/in-toto/attestation/blob/main/spec/v1/statement.md
+pub const STATEMENT_TYPE: &str = "https://in-toto.io/Statement/v1";
+
+/// The predicate type identifier of [SLSA Provenance] v1.
+///
+/// [SLSA Provenance]: https://slsa.dev/spec/v1.0/provenance
+pub const PREDICATE_TYPE: &str = "https://slsa.dev/provenance/v1";
+
+/// The build type identifier used for builds described by [BUILDINFO] data.
+///
+/// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+pub const BUILD_TYPE: &str = "https://alpm.archlinux.page/specifications/BUILDINFO.5.html";
+
+/// The artifact that a [`ProvenanceStatement`] is about.
+///
+/// Usually this is an [alpm-package] file, identified by its file name and SHA-256 digest.
+///
+/// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ProvenanceSubject {
+    /// The name of the artifact (e.g. the package file name).
+    name: String,
+    /// The digests of the artifact, keyed by algorithm name.
+    digest: BTreeMap<String, String>,
+}
+
+impl ProvenanceSubject {
+    /// Creates a new [`ProvenanceSubject`] from a `name` and a SHA-256 `digest`.
+    pub fn new(name: impl Into<String>, digest: &Checksum<Sha256>) -> Self {
+        Self {
+            name: name.into(),
+            digest: BTreeMap::from([("sha256".to_string(), digest.to_string())]),
+        }
+    }
+
+    /// Returns the name of the artifact.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the digests of the artifact, keyed by algorithm name.
+    pub fn digest(&self) -> &BTreeMap<String, String> {
+        &self.digest
+    }
+}
+
+/// A resource descriptor, as used for the resolved dependencies of a build.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ResourceDescriptor {
+    /// The name of the resource.
+    pub name: String,
+    /// The [package URL] of the resource.
+    ///
+    /// [package URL]: https://github.com/package-url/purl-spec
+    pub uri: String,
+}
+
+impl From<&InstalledPackage> for ResourceDescriptor {
+    fn from(value: &InstalledPackage) -> Self {
+        Self {
+            name: value.name().to_string(),
+            uri: format!(
+                "pkg:alpm/arch/{}@{}?arch={}",
+                value.name(),
+                value.version(),
+                value.architecture()
+            ),
+        }
+    }
+}
+
+/// The build definition of a [`ProvenancePredicate`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildDefinition {
+    /// The type of build (see [`BUILD_TYPE`]).
+    pub build_type: String,
+    /// The parameters under control of the packager (e.g. package name and version).
+    pub external_parameters: BTreeMap<String, serde_json::Value>,
+    /// The parameters set by the build tool (e.g. build directory and build environment).
+    pub internal_parameters: BTreeMap<String, serde_json::Value>,
+    /// The packages installed in the build environment.
+    pub resolved_dependencies: Vec<ResourceDescriptor>,
+}
+
+/// The entity that carried out a build.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Builder {
+    /// The identifier of the builder.
+    pub id: String,
+    /// The versions of the components of the builder (e.g. the build tool).
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub version: BTreeMap<String, String>,
+}
+
+/// Metadata of a build run.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildMetadata {
+    /// The [RFC 3339] timestamp of the build.
+    ///
+    /// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+    pub started_on: String,
+}
+
+/// The details of a build run.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RunDetails {
+    /// The entity that carried out the build.
+    pub builder: Builder,
+    /// Metadata of the build run.
+    pub metadata: BuildMetadata,
+}
+
+/// A [SLSA Provenance] v1 predicate.
+///
+/// [SLSA Provenance]: https://slsa.dev/spec/v1.0/provenance
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvenancePredicate {
+    /// The definition of the build.
+    pub build_definition: BuildDefinition,
+    /// The details of the build run.
+    pub run_details: RunDetails,
+}
+
+/// An [in-toto Statement] v1 with a [SLSA Provenance] v1 predicate.
+///
+/// [SLSA Provenance]: https://slsa.dev/spec/v1.0/provenance
+/// [in-toto Statement]: https://github.com/in-toto/attestation/blob/main/spec/v1/statement.md
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ProvenanceStatement {
+    /// The statement type (see [`STATEMENT_TYPE`]).
+    #[serde(rename = "_type")]
+    pub statement_type: String,
+    /// The artifacts the statement is about.
+    pub subject: Vec<ProvenanceSubject>,
+    /// The predicate type (see [`PREDICATE_TYPE`]).
+    #[serde(rename = "predicateType")]
+    pub predicate_type: String,
+    /// The provenance predicate.
+    pub predicate: ProvenancePredicate,
+}
+
+impl ProvenanceStatement {
+    /// Returns the statement as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the statement cannot be serialized.
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self).map_err(Error::Json)
+    }
+}
+
+impl BuildInfo {
+    /// Creates a [`ProvenanceStatement`] from the [`BuildInfo`].
+    ///
+    /// The `subject` describes the built package file by its name and SHA-256 digest.
+    ///
+    /// The data is mapped as follows:
+    ///
+    /// - `pkgname`, `pkgbase`, `pkgver`, `pkgarch` and `pkgbuild_sha256sum` are used as external
+    ///   parameters,
+    /// - `builddir`, `startdir`, `buildenv` and `options` are used as internal parameters,
+    /// - `installed` is used as resolved dependencies,
+    /// - the `packager` is used as builder, `buildtool` and `buildtoolver` as its version,
+    /// - and `builddate` is used as the start of the build run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `builddate` cannot be represented as an [RFC 3339] timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_buildinfo::{BuildInfo, provenance::ProvenanceSubject};
+    /// use alpm_types::{Checksum, digests::Sha256};
+    ///
+    /// # fn main() -> Result<(), alpm_buildinfo::Error> {
+    /// let build_info = BuildInfo::from_str(
+    ///     r#"format = 2
+    /// pkgname = foo
+    /// pkgbase = foo
+    /// pkgver = 1:1.0.0-1
+    /// pkgarch = any
+    /// pkgbuild_sha256sum = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+    /// packager = Foobar McFooface <foobar@mcfooface.org>
+    /// builddate = 1
+    /// builddir = /build
+    /// startdir = /startdir/
+    /// buildtool = devtools
+    /// buildtoolver = 1:1.2.1-1-any
+    /// installed = bar-1.2.3-1-any
+    /// "#,
+    /// )?;
+    ///
+    /// let digest = Checksum::<Sha256>::calculate_from("package data");
+    /// let subject = ProvenanceSubject::new("foo-1:1.0.0-1-any.pkg.tar.zst", &digest);
+    ///
+    /// let statement = build_info.to_provenance(subject)?;
+    /// assert_eq!(statement.subject[0].name(), "foo-1:1.0.0-1-any.pkg.tar.zst");
+    /// assert_eq!(
+    ///     statement.predicate.run_details.metadata.started_on,
+    ///     "1970-01-01T00:00:01Z"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+    pub fn to_provenance(&self, subject: ProvenanceSubject) -> Result<ProvenanceStatement, Error> {
+        // Collect the data shared by all format versions.
+        let (
+            pkgname,
+            pkgbase,
+            pkgver,
+            pkgarch,
+            pkgbuild_sha256sum,
+            packager,
+            builddate,
+            builddir,
+            buildenv,
+            options,
+            installed,
+        ) = match self {
+            BuildInfo::V1(v1) => (
+                &v1.pkgname,
+                &v1.pkgbase,
+                &v1.pkgver,
+                &v1.pkgarch,
+                &v1.pkgbuild_sha256sum,
+                &v1.packager,
+                v1.builddate,
+                &v1.builddir,
+                &v1.buildenv,
+                &v1.options,
+                &v1.installed,
+            ),
+            BuildInfo::V2(v2) => (
+                &v2.pkgname,
+                &v2.pkgbase,
+                &v2.pkgver,
+                &v2.pkgarch,
+                &v2.pkgbuild_sha256sum,
+                &v2.packager,
+                v2.builddate,
+                &v2.builddir,
+                &v2.buildenv,
+                &v2.options,
+                &v2.installed,
+            ),
+        };
+
+        let started_on = OffsetDateTime::from_unix_timestamp(builddate)
+            .ok()
+            .and_then(|date| date.format(&Rfc3339).ok())
+            .ok_or(Error::InvalidBuildDate {
+                builddate,
+                context: t!("error-provenance-builddate"),
+            })?;
+
+        let external_parameters = BTreeMap::from([
+            ("pkgname".to_string(), pkgname.to_string().into()),
+            ("pkgbase".to_string(), pkgbase.to_string().into()),
+            ("pkgver".to_string(), pkgver.to_string().into()),
+            ("pkgarch".to_string(), pkgarch.to_string().into()),
+            (
+                "pkgbuild_sha256sum".to_string(),
+                pkgbuild_sha256sum.to_string().into(),
+            ),
+        ]);
+
+        let mut internal_parameters = BTreeMap::from([
+            ("builddir".to_string(), builddir.to_string().into()),
+            (
+                "buildenv".to_string(),
+                buildenv.iter().map(ToString::to_string).collect(),
+            ),
+            (
+                "options".to_string(),
+                options.iter().map(ToString::to_string).collect(),
+            ),
+        ]);
+
+        let mut version = BTreeMap::new();
+        if let BuildInfo::V2(v2) = self {
+            internal_parameters.insert("startdir".to_string(), v2.startdir.to_string().into());
+            version.insert(v2.buildtool.to_string(), v2.buildtoolver.to_string());
+        }
+
+        Ok(ProvenanceStatement {
+            statement_type: STATEMENT_TYPE.to_string(),
+            subject: vec![subject],
+            predicate_type: PREDICATE_TYPE.to_string(),
+            predicate: ProvenancePredicate {
+                build_definition: BuildDefinition {
+                    build_type: BUILD_TYPE.to_string(),
+                    external_parameters,
+                    internal_parameters,
+                    resolved_dependencies: installed.iter().map(ResourceDescriptor::from).collect(),
+                },
+                run_details: RunDetails {
+                    builder: Builder {
+                        id: format!("mailto:{}", packager.email()),
+                        version,
+                    },
+                    metadata: BuildMetadata { started_on },
+                },
+            },
+        })
+    }
+}
+
+impl BuildInfo {
+    /// Returns the SHA-256 checksum of the [PKGBUILD] from which the package has been built.
+    ///
+    /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+    pub fn pkgbuild_sha256sum(&self) -> &Checksum<Sha256> {
+        match self {
+            BuildInfo::V1(v1) => &v1.pkgbuild_sha256sum,
+            BuildInfo::V2(v2) => &v2.pkgbuild_sha256sum,
+        }
+    }
+
+    /// Returns all `candidates` whose SHA-256 checksum matches the `pkgbuild_sha256sum`.
+    ///
+    /// Each of the `candidates` is the path to a [PKGBUILD] file (e.g. of a specific revision in a
+    /// packaging repository).
+    /// The matching paths are returned in the order of `candidates`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the `candidates` cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{fs::write, str::FromStr};
+    ///
+    /// use alpm_buildinfo::BuildInfo;
+    ///
+    /// # fn main() -> testresult::TestResult {
+    /// let build_info = BuildInfo::from_str(
+    ///     r#"format = 1
+    /// pkgname = foo
+    /// pkgbase = foo
+    /// pkgver = 1:1.0.0-1
+    /// pkgarch = any
+    /// pkgbuild_sha256sum = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+    /// packager = Foobar McFooface <foobar@mcfooface.org>
+    /// builddate = 1
+    /// builddir = /build
+    /// buildenv = ccache
+    /// options = lto
+    /// installed = bar-1.2.3-1-any
+    /// "#,
+    /// )?;
+    ///
+    /// let temp_dir = tempfile::tempdir()?;
+    /// let matching = temp_dir.path().join("PKGBUILD-1");
+    /// let other = temp_dir.path().join("PKGBUILD-2");
+    /// write(&matching, "foo\n")?;
+    /// write(&other, "bar\n")?;
+    ///
+    /// assert_eq!(
+    ///     build_info.find_pkgbuilds([&matching, &other])?,
+    ///     vec![matching]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+    pub fn find_pkgbuilds<I, P>(&self, candidates: I) -> Result<Vec<PathBuf>, Error>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let checksum = self.pkgbuild_sha256sum();
+        let mut matches = Vec::new();
+        for candidate in candidates {
+            let path = candidate.as_ref();
+            let read_error = |source| Error::IoPath {
+                path: path.to_path_buf(),
+                context: t!("error-io-read-pkgbuild"),
+                source,
+            };
+            let file = File::open(path).map_err(read_error)?;
+            match checksum.verify_reader(file) {
+                Ok(()) => matches.push(path.to_path_buf()),
+                Err(ChecksumVerificationError::Mismatch(_)) => {}
+                Err(ChecksumVerificationError::Io(source)) => return Err(read_error(source)),
+            }
+        }
+
+        Ok(matches)
+    }
+
+    /// Returns all files in `dir` whose SHA-256 checksum matches the `pkgbuild_sha256sum`.
+    ///
+    /// The directory is searched recursively and all regular files are considered as candidates,
+    /// as [PKGBUILD] files may be stored under arbitrary names (e.g. one file per revision).
+    /// Symlinks are not followed.
+    /// The matching paths are returned in lexical order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - `dir` or one of its subdirectories cannot be read,
+    /// - or [`BuildInfo::find_pkgbuilds`] fails for the found files.
+    ///
+    /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+    pub fn find_pkgbuilds_in_dir(&self, dir: impl AsRef<Path>) -> Result<Vec<PathBuf>, Error> {
+        let mut candidates = Vec::new();
+        collect_files(dir.as_ref(), &mut candidates)?;
+        candidates.sort();
+
+        self.find_pkgbuilds(candidates)
+    }
+}
+
+/// Adds the paths of all regular files in `dir` and its subdirectories to `files`.
+///
+/// # Errors
+///
+/// Returns an error if `dir` or one of its subdirectories cannot be read.
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), Error> {
+    let read_error = |source| Error::IoPath {
+        path: dir.to_path_buf(),
+        context: t!("error-io-read-pkgbuild-dir"),
+        source,
+    };
+    for entry in read_dir(dir).map_err(read_error)? {
+        let entry = entry.map_err(read_error)?;
+        let file_type = entry.file_type().map_err(read_error)?;
+        if file_type.is_dir() {
+            collect_files(&entry.path(), files)?;
+        } else if file_type.is_file() {
+            files.push(entry.path());
+        }
+    }
+
+    Ok(())
+}
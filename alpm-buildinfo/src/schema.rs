@@ -1,5 +1,4 @@
 use std::{
-    collections::HashMap,
     fmt::{Display, Formatter},
     fs::File,
     path::{Path, PathBuf},
@@ -7,7 +6,6 @@ use std::{
 };
 
 use alpm_common::FileFormatSchema;
-use alpm_parsers::custom_ini::parser::Item;
 use alpm_types::{SchemaVersion, semver_version::Version};
 use fluent_i18n::t;
 
@@ -144,13 +142,14 @@ impl FileFormatSchema for BuildInfoSchema {
     /// - the `format` field is missing from `s`
     /// - or deriving a [`BuildInfoSchema`] from `format` field fails.
     fn derive_from_str(s: &str) -> Result<BuildInfoSchema, Error> {
-        // Deserialize the file into a simple map, so we can take a look at the `format` string
-        // that determines the buildinfo version.
-        let raw_buildinfo: HashMap<String, Item> = alpm_parsers::custom_ini::from_str(s)?;
-        if let Some(Item::Value(version)) = raw_buildinfo.get("format") {
-            Self::from_str(version)
-        } else {
-            Err(Error::MissingFormatField)
+        // Only look at the `format` field that determines the buildinfo version, so that errors
+        // in any other line can be reported when parsing the data using the detected version.
+        match s
+            .lines()
+            .find_map(|line| line.trim_start().strip_prefix("format = "))
+        {
+            Some(version) => Self::from_str(version),
+            None => Err(Error::MissingFormatField),
         }
     }
 }
@@ -0,0 +1,48 @@
+//! Entry points for using the [BUILDINFO] parser from JavaScript.
+//!
+//! The functions in this module are exported using [`wasm_bindgen`], so that the parser can be
+//! used when compiling for `wasm32-unknown-unknown` (e.g. in a browser).
+//! All data is exchanged as strings, while structured output is provided as JSON.
+//!
+//! [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+
+use std::str::FromStr;
+
+use wasm_bindgen::{JsError, prelude::wasm_bindgen};
+
+use crate::BuildInfo;
+
+/// Validates [BUILDINFO] data provided as string `input`.
+///
+/// # Errors
+///
+/// Returns an error if `input` is not valid [BUILDINFO] data.
+///
+/// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+#[wasm_bindgen(js_name = validateBuildinfo)]
+pub fn validate_buildinfo(input: &str) -> Result<(), JsError> {
+    BuildInfo::from_str(input)?;
+
+    Ok(())
+}
+
+/// Parses [BUILDINFO] data provided as string `input` and returns it as JSON.
+///
+/// If `pretty` is `true`, the JSON output is pretty-printed.
+///
+/// # Errors
+///
+/// Returns an error if `input` is not valid [BUILDINFO] data or if it cannot be serialized as JSON.
+///
+/// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+#[wasm_bindgen(js_name = formatBuildinfo)]
+pub fn format_buildinfo(input: &str, pretty: bool) -> Result<String, JsError> {
+    let buildinfo = BuildInfo::from_str(input)?;
+    let json = if pretty {
+        serde_json::to_string_pretty(&buildinfo)?
+    } else {
+        serde_json::to_string(&buildinfo)?
+    };
+
+    Ok(json)
+}
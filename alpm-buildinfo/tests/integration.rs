@@ -136,7 +136,7 @@ fn wrong_schema_buildinfov1_as_v2() -> TestResult {
     let mut cmd = cargo_bin_cmd!("alpm-buildinfo");
     cmd.args(["validate", "-s", "2"]);
     cmd.write_stdin(VALID_BUILDINFO_V1_DATA);
-    cmd.assert().failure().code(1);
+    cmd.assert().failure().code(5);
     Ok(())
 }
 
@@ -146,7 +146,7 @@ fn wrong_schema_buildinfov2_as_v1() -> TestResult {
     let mut cmd = cargo_bin_cmd!("alpm-buildinfo");
     cmd.args(["validate", "-s", "1"]);
     cmd.write_stdin(VALID_BUILDINFO_V2_DATA);
-    cmd.assert().failure().code(1);
+    cmd.assert().failure().code(5);
     Ok(())
 }
 
@@ -171,6 +171,24 @@ fn format_buildinfo_and_serialize_as_json(#[case] data: &str) -> TestResult {
     Ok(())
 }
 
+/// Ensures that the JSON Schema of the JSON output contains the serialized `format` field.
+#[rstest]
+#[case::buildinfov1("BuildInfoV1", "1")]
+#[case::buildinfov2("BuildInfoV2", "2")]
+fn json_schema_contains_format(#[case] name: &str, #[case] format: &str) -> TestResult {
+    let mut cmd = cargo_bin_cmd!("alpm-buildinfo");
+    cmd.arg("json-schema");
+    let cmd = cmd.unwrap();
+    let schema: serde_json::Value = serde_json::from_slice(&cmd.stdout)?;
+
+    assert_eq!(
+        schema["$defs"][name]["properties"]["format"]["const"],
+        format
+    );
+    assert_eq!(schema["$defs"][name]["required"][0], "format");
+    Ok(())
+}
+
 #[rstest]
 #[case::buildinfov1_all_fields(
     BuildInfoInput {
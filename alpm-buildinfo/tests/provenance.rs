@@ -0,0 +1,137 @@
+//! Integration tests for the export of BUILDINFO data as provenance statements.
+
+use std::{
+    fs::{create_dir_all, write},
+    str::FromStr,
+};
+
+use alpm_buildinfo::{
+    BuildInfo,
+    provenance::{PREDICATE_TYPE, ProvenanceSubject, STATEMENT_TYPE},
+};
+use alpm_types::{Checksum, digests::Sha256};
+use rstest::rstest;
+use serde_json::{Value, json};
+use testresult::TestResult;
+
+/// A string slice representing valid [BUILDINFOv1] data.
+///
+/// [BUILDINFOv1]: https://alpm.archlinux.page/specifications/BUILDINFOv1.5.html
+const BUILDINFO_V1_DATA: &str = r#"format = 1
+pkgname = foo
+pkgbase = foo
+pkgver = 1:1.0.0-1
+pkgarch = any
+pkgbuild_sha256sum = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+packager = Foobar McFooface <foobar@mcfooface.org>
+builddate = 1
+builddir = /build
+buildenv = ccache
+options = lto
+installed = bar-1.2.3-1-any
+"#;
+
+/// A string slice representing valid [BUILDINFOv2] data.
+///
+/// [BUILDINFOv2]: https://alpm.archlinux.page/specifications/BUILDINFOv2.5.html
+const BUILDINFO_V2_DATA: &str = r#"format = 2
+pkgname = foo
+pkgbase = foo
+pkgver = 1:1.0.0-1
+pkgarch = any
+pkgbuild_sha256sum = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+packager = Foobar McFooface <foobar@mcfooface.org>
+builddate = 1
+builddir = /build
+startdir = /startdir/
+buildtool = devtools
+buildtoolver = 1:1.2.1-1-any
+buildenv = ccache
+options = lto
+installed = bar-1.2.3-1-any
+"#;
+
+/// Ensures that BUILDINFO data of all versions is exported as provenance statement.
+#[rstest]
+#[case::v1(BUILDINFO_V1_DATA, json!(null))]
+#[case::v2(BUILDINFO_V2_DATA, json!({"devtools": "1:1.2.1-1-any"}))]
+fn provenance_statement(#[case] data: &str, #[case] builder_version: Value) -> TestResult {
+    let build_info = BuildInfo::from_str(data)?;
+    let digest = Checksum::<Sha256>::calculate_from("package data");
+    let subject = ProvenanceSubject::new("foo-1:1.0.0-1-any.pkg.tar.zst", &digest);
+
+    let statement = build_info.to_provenance(subject)?;
+    let json: Value = serde_json::from_str(&statement.to_json()?)?;
+
+    assert_eq!(json["_type"], STATEMENT_TYPE);
+    assert_eq!(json["predicateType"], PREDICATE_TYPE);
+    assert_eq!(json["subject"][0]["name"], "foo-1:1.0.0-1-any.pkg.tar.zst");
+    assert_eq!(json["subject"][0]["digest"]["sha256"], digest.to_string());
+
+    let predicate = &json["predicate"];
+    assert_eq!(
+        predicate["buildDefinition"]["externalParameters"]["pkgver"],
+        "1:1.0.0-1"
+    );
+    assert_eq!(
+        predicate["buildDefinition"]["internalParameters"]["buildenv"],
+        json!(["ccache"])
+    );
+    assert!(
+        predicate["buildDefinition"]["internalParameters"]
+            .get("packager")
+            .is_none()
+    );
+    assert_eq!(
+        predicate["buildDefinition"]["resolvedDependencies"],
+        json!([{"name": "bar", "uri": "pkg:alpm/arch/bar@1.2.3-1?arch=any"}])
+    );
+    assert_eq!(
+        predicate["runDetails"]["builder"]["id"],
+        "mailto:foobar@mcfooface.org"
+    );
+    assert_eq!(
+        predicate["runDetails"]["builder"]["version"],
+        builder_version
+    );
+    assert_eq!(
+        predicate["runDetails"]["metadata"]["startedOn"],
+        "1970-01-01T00:00:01Z"
+    );
+
+    Ok(())
+}
+
+/// Ensures that the PKGBUILD files matching the `pkgbuild_sha256sum` are found in a directory.
+#[rstest]
+#[case::v1(BUILDINFO_V1_DATA)]
+#[case::v2(BUILDINFO_V2_DATA)]
+fn find_pkgbuilds_in_dir(#[case] data: &str) -> TestResult {
+    let build_info = BuildInfo::from_str(data)?;
+    let temp_dir = tempfile::tempdir()?;
+    let path = temp_dir.path();
+    create_dir_all(path.join("foo/revisions"))?;
+    create_dir_all(path.join("bar"))?;
+    // The content "foo\n" matches the checksum of the BUILDINFO data.
+    write(path.join("foo/PKGBUILD"), "foo\n")?;
+    write(path.join("foo/revisions/1"), "foo\n")?;
+    write(path.join("foo/revisions/2"), "foo2\n")?;
+    write(path.join("bar/PKGBUILD"), "bar\n")?;
+
+    assert_eq!(
+        build_info.find_pkgbuilds_in_dir(path)?,
+        vec![path.join("foo/PKGBUILD"), path.join("foo/revisions/1")]
+    );
+    assert!(
+        build_info
+            .find_pkgbuilds([path.join("bar/PKGBUILD")])?
+            .is_empty()
+    );
+    assert!(
+        build_info
+            .find_pkgbuilds([path.join("does-not-exist")])
+            .is_err()
+    );
+
+    Ok(())
+}
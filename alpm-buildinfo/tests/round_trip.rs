@@ -0,0 +1,30 @@
+//! Round-trip property tests for [BUILDINFO] data.
+//!
+//! These tests are only executed when the `arbitrary` feature flag is enabled.
+//!
+//! [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+#![cfg(feature = "arbitrary")]
+
+use std::str::FromStr;
+
+use alpm_buildinfo::{BuildInfo, arbitrary::BuildInfoText};
+use arbitrary::Unstructured;
+use proptest::{collection::vec, prelude::*};
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(500))]
+
+    /// Ensures that generated [BUILDINFO] data can be parsed and that its string representation
+    /// parses to the same [`BuildInfo`].
+    ///
+    /// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+    #[test]
+    fn buildinfo_round_trip(data in vec(any::<u8>(), 0..4096)) {
+        let mut unstructured = Unstructured::new(&data);
+        let text: BuildInfoText = unstructured.arbitrary().unwrap();
+
+        let buildinfo = BuildInfo::from_str(text.as_str()).unwrap();
+        let reparsed = BuildInfo::from_str(&buildinfo.to_string()).unwrap();
+        prop_assert_eq!(buildinfo, reparsed);
+    }
+}
@@ -0,0 +1,8 @@
+error-io-path = I/O error at path { $path } while { $context }:
+  { $source }
+
+error-io-absolute-input-dir = getting the absolute path of the input directory
+
+error-io-absolute-output-dir = getting the absolute path of the output directory
+
+error-unsupported-output-format = The output format { $output_format } is not supported by { $command }
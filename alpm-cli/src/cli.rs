@@ -0,0 +1,174 @@
+//! Command line argument handling.
+
+use std::path::PathBuf;
+
+use clap::{ColorChoice, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use strum::Display;
+
+/// The identifier of the `--output-format` options of the subcommands.
+const SUBCOMMAND_OUTPUT_FORMAT_ID: &str = "output_format";
+
+/// The output format of the `alpm` executable.
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// The JSON output format.
+    #[strum(to_string = "json")]
+    Json,
+
+    /// The SRCINFO output format.
+    #[strum(to_string = "srcinfo")]
+    Srcinfo,
+}
+
+/// Command line argument handling for the `alpm` executable.
+#[derive(Clone, Debug, Parser)]
+#[command(about, author, name = "alpm", version)]
+pub struct Cli {
+    /// Log verbosity level
+    #[command(flatten)]
+    pub verbose: clap_verbosity_flag::Verbosity,
+
+    /// Control the use of colors in the log output
+    #[arg(
+        default_value_t = ColorChoice::Auto,
+        global = true,
+        long,
+        value_name = "WHEN"
+    )]
+    pub color: ColorChoice,
+
+    /// Provide the output format of the subcommands that output data
+    ///
+    /// If unset, the default output format of the respective subcommand is used.
+    #[arg(
+        global = true,
+        id = "global_output_format",
+        long = "output-format",
+        value_name = "OUTPUT_FORMAT"
+    )]
+    pub output_format: Option<OutputFormat>,
+
+    /// Available subcommands
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+impl Cli {
+    /// Parses the command line arguments of the `alpm` executable.
+    ///
+    /// In contrast to [`Parser::parse`], the `--output-format` options of the subcommands are
+    /// replaced by the global `--output-format` option (see [`Cli::output_format`]).
+    /// Their short `-o` option is kept, but hidden.
+    ///
+    /// Exits the process with an error message if the arguments cannot be parsed.
+    pub fn parse_with_global_output_format() -> Self {
+        let matches = replace_output_format(Self::command()).get_matches();
+        Self::from_arg_matches(&matches).unwrap_or_else(|error| error.exit())
+    }
+}
+
+/// Replaces the `--output-format` options of `command` and all of its subcommands.
+///
+/// The long `--output-format` option is removed from the arguments, so that it does not conflict
+/// with the global `--output-format` option of [`Cli`].
+fn replace_output_format(mut command: clap::Command) -> clap::Command {
+    if command
+        .get_arguments()
+        .any(|arg| arg.get_id() == SUBCOMMAND_OUTPUT_FORMAT_ID)
+    {
+        command = command.mut_arg(SUBCOMMAND_OUTPUT_FORMAT_ID, |arg| arg.long(None).hide(true));
+    }
+
+    let names: Vec<String> = command
+        .get_subcommands()
+        .map(|subcommand| subcommand.get_name().to_string())
+        .collect();
+    for name in names {
+        command = command.mut_subcommand(name, replace_output_format);
+    }
+
+    command
+}
+
+/// Available commands for the `alpm` executable.
+///
+/// Apart from [`Command::Package`], all commands expose the commands of the respective
+/// single-purpose executable (e.g. `alpm buildinfo` exposes the commands of `alpm-buildinfo`).
+#[derive(Clone, Debug, Subcommand)]
+pub enum Command {
+    /// Create, validate and format BUILDINFO files
+    #[command()]
+    Buildinfo {
+        /// The `alpm-buildinfo` commands.
+        #[command(subcommand)]
+        command: alpm_buildinfo::cli::Command,
+    },
+
+    /// Validate and format ALPM-MTREE files
+    #[command()]
+    Mtree {
+        /// The `alpm-mtree` commands.
+        #[command(subcommand)]
+        command: alpm_mtree::cli::Command,
+    },
+
+    /// Create packages from prepared build roots
+    #[command()]
+    Package {
+        /// The package commands.
+        #[command(subcommand)]
+        command: PackageCommand,
+    },
+
+    /// Create, validate and format PKGINFO files
+    #[command()]
+    Pkginfo {
+        /// The `alpm-pkginfo` commands.
+        #[command(subcommand)]
+        command: alpm_pkginfo::cli::Command,
+    },
+
+    /// Manage repository sync databases
+    #[command()]
+    RepoDb {
+        /// The `alpm-repo-db` commands.
+        #[command(subcommand)]
+        command: alpm_repo_db::db::cli::Command,
+    },
+
+    /// Create, validate and format SRCINFO files
+    #[command()]
+    Srcinfo {
+        /// The `alpm-srcinfo` commands.
+        #[command(subcommand)]
+        command: alpm_srcinfo::cli::Command,
+    },
+}
+
+/// Commands for the handling of packages.
+#[derive(Clone, Debug, Subcommand)]
+pub enum PackageCommand {
+    /// Create a package file from a prepared build root
+    ///
+    /// The ALPM-MTREE file of the build root is always (re)created.
+    /// If no PKGINFO or BUILDINFO file is provided, the respective file must exist in the build
+    /// root already.
+    #[command()]
+    Create {
+        /// The build root containing the data files of the package
+        #[arg(value_name = "INPUT_DIR")]
+        input_dir: PathBuf,
+
+        /// The directory in which the package file is created
+        #[arg(default_value = ".", long, short, value_name = "OUTPUT_DIR")]
+        output_dir: PathBuf,
+
+        /// A PKGINFO file that is written to the build root
+        #[arg(long, value_name = "FILE")]
+        pkginfo: Option<PathBuf>,
+
+        /// A BUILDINFO file that is written to the build root
+        #[arg(long, value_name = "FILE")]
+        buildinfo: Option<PathBuf>,
+    },
+}
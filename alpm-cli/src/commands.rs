@@ -0,0 +1,386 @@
+//! Command line functions that are called by the `alpm` executable.
+
+use std::path::{Path, absolute};
+
+use alpm_buildinfo::BuildInfo;
+use alpm_common::MetadataFile;
+use alpm_package::{InputDir, OutputDir, builder::PackageBuilder};
+use alpm_pkginfo::PackageInfo;
+use alpm_repo_db::db::{GpgSigner, RepoDbSigner};
+use fluent_i18n::t;
+use log::info;
+
+use crate::{
+    Error,
+    cli::{Command, OutputFormat, PackageCommand},
+};
+
+/// Runs a `command`.
+///
+/// Delegates to the command functions of the respective library.
+/// If `output_format` is provided, it is used instead of the output format of the `command`.
+/// Returns `false` if the command ran successfully, but its outcome should be considered a
+/// failure (e.g. if `alpm repo-db validate` finds inconsistencies).
+///
+/// # Errors
+///
+/// Returns an error if the command fails.
+pub fn run(command: Command, output_format: Option<OutputFormat>) -> Result<bool, Error> {
+    match command {
+        Command::Buildinfo { command } => run_buildinfo(command, output_format).map(|()| true),
+        Command::Mtree { command } => run_mtree(command, output_format).map(|()| true),
+        Command::Package { command } => run_package(command).map(|()| true),
+        Command::Pkginfo { command } => run_pkginfo(command, output_format).map(|()| true),
+        Command::RepoDb { command } => run_repo_db(command),
+        Command::Srcinfo { command } => run_srcinfo(command, output_format).map(|()| true),
+    }
+}
+
+/// Selects the output format of a `command`.
+///
+/// Returns `default` if no global `output_format` is provided.
+/// Otherwise, returns the output format that `select` maps the global `output_format` to.
+///
+/// # Errors
+///
+/// Returns an error if `select` does not map the global `output_format` to an output format
+/// supported by `command`.
+fn select_output_format<T>(
+    output_format: Option<OutputFormat>,
+    default: T,
+    command: &str,
+    select: impl FnOnce(OutputFormat) -> Option<T>,
+) -> Result<T, Error> {
+    let Some(output_format) = output_format else {
+        return Ok(default);
+    };
+
+    select(output_format).ok_or_else(|| Error::UnsupportedOutputFormat {
+        output_format,
+        command: command.to_string(),
+    })
+}
+
+/// Runs an `alpm-buildinfo` `command`.
+///
+/// If `global_output_format` is provided, it overrides the output format of the `command`.
+///
+/// # Errors
+///
+/// Returns an error if the command fails or does not support `global_output_format`.
+fn run_buildinfo(
+    command: alpm_buildinfo::cli::Command,
+    global_output_format: Option<OutputFormat>,
+) -> Result<(), Error> {
+    use alpm_buildinfo::{
+        cli::{Command, OutputFormat as BuildInfoOutputFormat},
+        commands::{create_file, format, json_schema, validate},
+    };
+
+    match command {
+        Command::Create { command } => create_file(command)?,
+        Command::Validate { args } => validate(args)?,
+        Command::Format {
+            args,
+            output_format,
+            pretty,
+        } => {
+            let output_format = select_output_format(
+                global_output_format,
+                output_format,
+                "alpm buildinfo format",
+                |output_format| match output_format {
+                    OutputFormat::Json => Some(BuildInfoOutputFormat::Json),
+                    OutputFormat::Srcinfo => None,
+                },
+            )?;
+            format(args, output_format, pretty)?
+        }
+        Command::JsonSchema { pretty } => json_schema(pretty)?,
+    }
+
+    Ok(())
+}
+
+/// Runs an `alpm-mtree` `command`.
+///
+/// If `global_output_format` is provided, it overrides the output format of the `command`.
+///
+/// # Errors
+///
+/// Returns an error if the command fails or does not support `global_output_format`.
+fn run_mtree(
+    command: alpm_mtree::cli::Command,
+    global_output_format: Option<OutputFormat>,
+) -> Result<(), Error> {
+    use alpm_mtree::{
+        cli::{Command, OutputFormat as MtreeOutputFormat},
+        commands::{format, json_schema, validate},
+    };
+
+    match command {
+        Command::Validate { file, schema } => validate(file.as_ref(), schema)?,
+        Command::Format {
+            file,
+            schema,
+            output_format,
+            pretty,
+        } => {
+            let output_format = select_output_format(
+                global_output_format,
+                output_format,
+                "alpm mtree format",
+                |output_format| match output_format {
+                    OutputFormat::Json => Some(MtreeOutputFormat::Json),
+                    OutputFormat::Srcinfo => None,
+                },
+            )?;
+            format(file.as_ref(), schema, output_format, pretty)?
+        }
+        Command::JsonSchema { pretty } => json_schema(pretty)?,
+    }
+
+    Ok(())
+}
+
+/// Runs a package `command`.
+///
+/// # Errors
+///
+/// Returns an error if the command fails.
+fn run_package(command: PackageCommand) -> Result<(), Error> {
+    match command {
+        PackageCommand::Create {
+            input_dir,
+            output_dir,
+            pkginfo,
+            buildinfo,
+        } => create_package(
+            &input_dir,
+            &output_dir,
+            pkginfo.as_deref(),
+            buildinfo.as_deref(),
+        ),
+    }
+}
+
+/// Creates a package file in `output_dir` from the build root `input_dir`.
+///
+/// If `pkginfo` or `buildinfo` are provided, the respective files are parsed and written to
+/// `input_dir` before the package file is created (see [`PackageBuilder`]).
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - the absolute path of `input_dir` or `output_dir` cannot be retrieved,
+/// - `input_dir` is not a valid [`InputDir`] or `output_dir` not a valid [`OutputDir`],
+/// - `pkginfo` or `buildinfo` are provided, but cannot be parsed,
+/// - or creating the package file fails.
+pub fn create_package(
+    input_dir: &Path,
+    output_dir: &Path,
+    pkginfo: Option<&Path>,
+    buildinfo: Option<&Path>,
+) -> Result<(), Error> {
+    let input_dir = absolute(input_dir).map_err(|source| Error::IoPath {
+        path: input_dir.to_path_buf(),
+        context: t!("error-io-absolute-input-dir"),
+        source,
+    })?;
+    let output_dir = absolute(output_dir).map_err(|source| Error::IoPath {
+        path: output_dir.to_path_buf(),
+        context: t!("error-io-absolute-output-dir"),
+        source,
+    })?;
+
+    let mut builder = PackageBuilder::new(InputDir::new(input_dir)?, OutputDir::new(output_dir)?);
+    if let Some(pkginfo) = pkginfo {
+        builder = builder.package_info(PackageInfo::from_file_with_schema(pkginfo, None)?);
+    }
+    if let Some(buildinfo) = buildinfo {
+        builder = builder.build_info(BuildInfo::from_file_with_schema(buildinfo, None)?);
+    }
+
+    let artifacts = builder.build()?;
+    info!("Created package file {:?}", artifacts.package.to_path_buf());
+
+    Ok(())
+}
+
+/// Runs an `alpm-pkginfo` `command`.
+///
+/// If `global_output_format` is provided, it overrides the output format of the `command`.
+///
+/// # Errors
+///
+/// Returns an error if the command fails or does not support `global_output_format`.
+fn run_pkginfo(
+    command: alpm_pkginfo::cli::Command,
+    global_output_format: Option<OutputFormat>,
+) -> Result<(), Error> {
+    use alpm_pkginfo::{
+        cli::{Command, OutputFormat as PackageInfoOutputFormat},
+        commands::{create_file, format, json_schema, validate},
+    };
+
+    match command {
+        Command::Create { command } => create_file(command)?,
+        Command::Validate { file, schema } => validate(file, schema)?,
+        Command::Format {
+            file,
+            schema,
+            output_format,
+            pretty,
+        } => {
+            let output_format = select_output_format(
+                global_output_format,
+                output_format,
+                "alpm pkginfo format",
+                |output_format| match output_format {
+                    OutputFormat::Json => Some(PackageInfoOutputFormat::Json),
+                    OutputFormat::Srcinfo => None,
+                },
+            )?;
+            format(file, schema, output_format, pretty)?
+        }
+        Command::JsonSchema { pretty } => json_schema(pretty)?,
+    }
+
+    Ok(())
+}
+
+/// Runs an `alpm-repo-db` `command`.
+///
+/// Returns `false` if `validate` finds inconsistencies or `verify` finds missing or invalid
+/// signatures.
+///
+/// # Errors
+///
+/// Returns an error if the command fails.
+fn run_repo_db(command: alpm_repo_db::db::cli::Command) -> Result<bool, Error> {
+    use alpm_repo_db::db::{
+        cli::Command,
+        commands::{add, diff, remove, validate, verify},
+    };
+
+    let success = match command {
+        Command::Add {
+            db_file,
+            packages,
+            sign,
+            key,
+        } => {
+            let signer = sign.then(|| GpgSigner::new(key));
+            add(
+                &db_file,
+                &packages,
+                signer.as_ref().map(|signer| signer as &dyn RepoDbSigner),
+            )?;
+            true
+        }
+        Command::Remove {
+            db_file,
+            names,
+            sign,
+            key,
+        } => {
+            let signer = sign.then(|| GpgSigner::new(key));
+            remove(
+                &db_file,
+                &names,
+                signer.as_ref().map(|signer| signer as &dyn RepoDbSigner),
+            )?;
+            true
+        }
+        Command::Diff { old, new, pretty } => {
+            diff(&old, &new, pretty)?;
+            true
+        }
+        Command::Validate {
+            db_file,
+            require_signatures,
+            pretty,
+        } => validate(&db_file, require_signatures, pretty)?,
+        Command::Verify { db_file, os } => verify(&db_file, os)?,
+    };
+
+    Ok(success)
+}
+
+/// Runs an `alpm-srcinfo` `command`.
+///
+/// If `global_output_format` is provided, it overrides the output format of the `command`.
+///
+/// # Errors
+///
+/// Returns an error if the command fails or does not support `global_output_format`.
+fn run_srcinfo(
+    command: alpm_srcinfo::cli::Command,
+    global_output_format: Option<OutputFormat>,
+) -> Result<(), Error> {
+    use alpm_srcinfo::{
+        cli::{Command, PackagesOutputFormat, SourceInfoOutputFormat},
+        commands::{create, format_packages, format_source_info, json_schema, validate},
+    };
+
+    /// Maps the global `output_format` to a [`SourceInfoOutputFormat`].
+    fn source_info_output_format(output_format: OutputFormat) -> Option<SourceInfoOutputFormat> {
+        match output_format {
+            OutputFormat::Json => Some(SourceInfoOutputFormat::Json),
+            OutputFormat::Srcinfo => Some(SourceInfoOutputFormat::Srcinfo),
+        }
+    }
+
+    match command {
+        Command::Create {
+            file,
+            output_format,
+            pretty,
+        } => {
+            let output_format = select_output_format(
+                global_output_format,
+                output_format,
+                "alpm srcinfo create",
+                source_info_output_format,
+            )?;
+            create(&file, output_format, pretty)?
+        }
+        Command::Validate { file, schema } => validate(file.as_ref(), schema)?,
+        Command::Format {
+            file,
+            schema,
+            output_format,
+            pretty,
+        } => {
+            let output_format = select_output_format(
+                global_output_format,
+                output_format,
+                "alpm srcinfo format",
+                source_info_output_format,
+            )?;
+            format_source_info(file.as_ref(), schema, output_format, pretty)?
+        }
+        Command::FormatPackages {
+            file,
+            schema,
+            architecture,
+            output_format,
+            pretty,
+        } => {
+            let output_format = select_output_format(
+                global_output_format,
+                output_format,
+                "alpm srcinfo format-packages",
+                |output_format| match output_format {
+                    OutputFormat::Json => Some(PackagesOutputFormat::Json),
+                    OutputFormat::Srcinfo => None,
+                },
+            )?;
+            format_packages(file.as_ref(), schema, output_format, architecture, pretty)?
+        }
+        Command::JsonSchema { pretty } => json_schema(pretty)?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! Error handling.
+
+use std::path::PathBuf;
+
+use alpm_common::ErrorCategory;
+use fluent_i18n::t;
+
+use crate::cli::OutputFormat;
+
+/// The error that can occur when using the `alpm` executable.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// An [`alpm_buildinfo::Error`].
+    #[error(transparent)]
+    BuildInfo(#[from] alpm_buildinfo::Error),
+
+    /// An [`alpm_buildinfo::commands::Error`].
+    #[error(transparent)]
+    BuildInfoCommand(#[from] alpm_buildinfo::commands::Error),
+
+    /// An I/O error occurred at a path.
+    #[error("{msg}", msg = t!("error-io-path", {
+        "path" => path.display().to_string(),
+        "context" => context,
+        "source" => source.to_string()
+    }))]
+    IoPath {
+        /// The path at which the error occurred.
+        path: PathBuf,
+        /// The context in which the error occurred.
+        ///
+        /// This is meant to complete the sentence "I/O error at path {path} while ".
+        context: String,
+        /// The source error.
+        source: std::io::Error,
+    },
+
+    /// An [`alpm_mtree::commands::Error`].
+    #[error(transparent)]
+    MtreeCommand(#[from] alpm_mtree::commands::Error),
+
+    /// An [`alpm_package::Error`].
+    #[error(transparent)]
+    Package(#[from] alpm_package::Error),
+
+    /// An [`alpm_pkginfo::Error`].
+    #[error(transparent)]
+    PackageInfo(#[from] alpm_pkginfo::Error),
+
+    /// An [`alpm_pkginfo::commands::Error`].
+    #[error(transparent)]
+    PackageInfoCommand(#[from] alpm_pkginfo::commands::Error),
+
+    /// An [`alpm_repo_db::Error`].
+    #[error(transparent)]
+    RepoDb(#[from] alpm_repo_db::Error),
+
+    /// An [`alpm_srcinfo::commands::Error`].
+    #[error(transparent)]
+    SourceInfoCommand(#[from] alpm_srcinfo::commands::Error),
+
+    /// An output format is not supported by a command.
+    #[error("{msg}", msg = t!("error-unsupported-output-format", {
+        "output_format" => output_format.to_string(),
+        "command" => command,
+    }))]
+    UnsupportedOutputFormat {
+        /// The unsupported output format.
+        output_format: OutputFormat,
+        /// The command that does not support the output format.
+        command: String,
+    },
+}
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::BuildInfo(error) => error.into(),
+            Error::BuildInfoCommand(error) => error.into(),
+            Error::IoPath { .. } => Self::Io,
+            Error::MtreeCommand(error) => error.into(),
+            Error::Package(error) => error.into(),
+            Error::PackageInfo(error) => error.into(),
+            Error::PackageInfoCommand(error) => error.into(),
+            Error::RepoDb(error) => error.into(),
+            Error::SourceInfoCommand(error) => error.into(),
+            Error::UnsupportedOutputFormat { .. } => Self::Unsupported,
+        }
+    }
+}
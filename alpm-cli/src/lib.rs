@@ -0,0 +1,9 @@
+#![doc = include_str!("../README.md")]
+
+pub mod cli;
+pub mod commands;
+
+mod error;
+pub use error::Error;
+
+fluent_i18n::i18n!("locales");
@@ -0,0 +1,45 @@
+//! The `alpm` CLI tool.
+
+use std::process::ExitCode;
+
+use alpm_cli::{cli::Cli, commands::run};
+use alpm_common::ErrorCategory;
+use log::{debug, error};
+use simplelog::{ColorChoice, Config, TermLogger, TerminalMode};
+
+/// The main entrypoint for the `alpm` executable.
+///
+/// Initializes logging according to the global verbosity and color flags and delegates to
+/// [`run`] with the global output format.
+///
+/// Returns an [`ExitCode::SUCCESS`] if the chosen command succeeded.
+/// Returns an [`ExitCode`] based on the [`ErrorCategory`] of the error and logs the error if the
+/// chosen command failed.
+fn main() -> ExitCode {
+    let cli = Cli::parse_with_global_output_format();
+
+    let color_choice = match cli.color {
+        clap::ColorChoice::Auto => ColorChoice::Auto,
+        clap::ColorChoice::Always => ColorChoice::Always,
+        clap::ColorChoice::Never => ColorChoice::Never,
+    };
+    if TermLogger::init(
+        cli.verbose.log_level_filter(),
+        Config::default(),
+        TerminalMode::Stderr,
+        color_choice,
+    )
+    .is_err()
+    {
+        debug!("Not initializing another logger, as one is initialized already.");
+    }
+
+    match run(cli.command, cli.output_format) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            error!("{error}");
+            ErrorCategory::from(&error).into()
+        }
+    }
+}
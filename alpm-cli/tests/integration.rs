@@ -0,0 +1,130 @@
+//! Binary integration tests for `alpm`.
+
+use std::fs::write;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use rstest::rstest;
+use tempfile::tempdir;
+use testresult::TestResult;
+
+/// A string slice representing valid [BUILDINFOv2] data.
+///
+/// [BUILDINFOv2]: https://alpm.archlinux.page/specifications/BUILDINFOv2.5.html
+const VALID_BUILDINFO_V2_DATA: &str = r#"
+format = 2
+builddate = 1
+builddir = /build
+startdir = /startdir/
+buildtool = devtools
+buildtoolver = 1:1.2.1-1-any
+buildenv = ccache
+installed = bar-1.2.3-1-any
+options = lto
+packager = Foobar McFooface <foobar@mcfooface.org>
+pkgarch = any
+pkgbase = foo
+pkgbuild_sha256sum = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+pkgname = foo
+pkgver = 1:1.0.0-1
+"#;
+
+/// Ensures that the subcommands of `alpm` are available.
+#[rstest]
+#[case::buildinfo("buildinfo")]
+#[case::mtree("mtree")]
+#[case::package("package")]
+#[case::pkginfo("pkginfo")]
+#[case::repo_db("repo-db")]
+#[case::srcinfo("srcinfo")]
+fn subcommand_help(#[case] subcommand: &str) -> TestResult {
+    let mut cmd = cargo_bin_cmd!("alpm");
+    cmd.args([subcommand, "--help"]);
+    cmd.assert().success();
+
+    Ok(())
+}
+
+/// Ensures that `alpm buildinfo validate` delegates to the `alpm-buildinfo` command functions.
+#[test]
+fn buildinfo_validate() -> TestResult {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().join(".BUILDINFO");
+    write(&path, VALID_BUILDINFO_V2_DATA)?;
+
+    let mut cmd = cargo_bin_cmd!("alpm");
+    cmd.args(["buildinfo", "validate"]).arg(&path);
+    cmd.assert().success();
+
+    write(&path, "format = 2\n")?;
+    let mut cmd = cargo_bin_cmd!("alpm");
+    cmd.args(["buildinfo", "validate"]).arg(&path);
+    cmd.assert().failure();
+
+    Ok(())
+}
+
+/// Ensures that the JSON Schema of the subcommands' JSON output is available.
+#[rstest]
+#[case::buildinfo("buildinfo")]
+#[case::mtree("mtree")]
+#[case::pkginfo("pkginfo")]
+#[case::srcinfo("srcinfo")]
+fn subcommand_json_schema(#[case] subcommand: &str) -> TestResult {
+    let mut cmd = cargo_bin_cmd!("alpm");
+    cmd.args([subcommand, "json-schema"]);
+    let output = cmd.unwrap();
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert!(schema["$schema"].is_string());
+
+    Ok(())
+}
+
+/// Ensures that the global options can be provided before and after the subcommands.
+#[rstest]
+#[case::before(&["--color", "never", "-v", "buildinfo", "validate"])]
+#[case::after(&["buildinfo", "validate", "--color", "never", "-v"])]
+fn global_options(#[case] args: &[&str]) -> TestResult {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().join(".BUILDINFO");
+    write(&path, VALID_BUILDINFO_V2_DATA)?;
+
+    let mut cmd = cargo_bin_cmd!("alpm");
+    cmd.args(args).arg(&path);
+    cmd.assert().success();
+
+    Ok(())
+}
+
+/// Ensures that the global `--output-format` option is passed on to the subcommands.
+#[rstest]
+#[case::before_subcommand(&["--output-format", "json", "buildinfo", "format"])]
+#[case::after_subcommand(&["buildinfo", "format", "--output-format", "json"])]
+#[case::short_subcommand_option(&["buildinfo", "format", "-o", "json"])]
+fn global_output_format(#[case] args: &[&str]) -> TestResult {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().join(".BUILDINFO");
+    write(&path, VALID_BUILDINFO_V2_DATA)?;
+
+    let mut cmd = cargo_bin_cmd!("alpm");
+    cmd.args(args).arg(&path);
+    let output = cmd.unwrap();
+    let buildinfo: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(buildinfo["pkgname"], "foo");
+
+    Ok(())
+}
+
+/// Ensures that a global `--output-format` that is not supported by a subcommand is rejected.
+#[test]
+fn global_output_format_unsupported() -> TestResult {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().join(".BUILDINFO");
+    write(&path, VALID_BUILDINFO_V2_DATA)?;
+
+    let mut cmd = cargo_bin_cmd!("alpm");
+    cmd.args(["--output-format", "srcinfo", "buildinfo", "format"])
+        .arg(&path);
+    cmd.assert().failure().code(8);
+
+    Ok(())
+}
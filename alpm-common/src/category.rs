@@ -0,0 +1,172 @@
+//! Categorization of errors across ALPM crates.
+
+use std::process::ExitCode;
+
+/// The category of an error.
+///
+/// Each ALPM crate provides a conversion from (a reference to) its error type(s) to an
+/// [`ErrorCategory`].
+/// This allows to distinguish classes of errors without having to match on the error types of
+/// individual crates (e.g. when mapping errors to exit codes of command line interfaces).
+///
+/// Each category has a stable numeric [code](ErrorCategory::code).
+/// The codes `0` (success), `1` (generic failure) and `2` (command line usage error) are reserved.
+///
+/// # Examples
+///
+/// ```
+/// use alpm_common::ErrorCategory;
+///
+/// assert_eq!(ErrorCategory::Io.code(), 3);
+/// assert_eq!(ErrorCategory::Unsupported.code(), 8);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ErrorCategory {
+    /// An error that occurred while reading or writing data (e.g. from or to a file).
+    Io,
+
+    /// Data could not be parsed (e.g. due to invalid syntax or encoding).
+    Parse,
+
+    /// A file format schema is unknown, unsupported or does not match.
+    Schema,
+
+    /// Data is well-formed, but does not satisfy the requirements of its specification.
+    Validation,
+
+    /// A cryptographic operation (e.g. signing or verification) or cryptographic data failed.
+    Crypto,
+
+    /// An operation, file type or input is not supported.
+    Unsupported,
+}
+
+impl ErrorCategory {
+    /// Returns the stable numeric code of the [`ErrorCategory`].
+    ///
+    /// | Category        | Code |
+    /// |-----------------|------|
+    /// | [`Io`]          | 3    |
+    /// | [`Parse`]       | 4    |
+    /// | [`Schema`]      | 5    |
+    /// | [`Validation`]  | 6    |
+    /// | [`Crypto`]      | 7    |
+    /// | [`Unsupported`] | 8    |
+    ///
+    /// [`Io`]: ErrorCategory::Io
+    /// [`Parse`]: ErrorCategory::Parse
+    /// [`Schema`]: ErrorCategory::Schema
+    /// [`Validation`]: ErrorCategory::Validation
+    /// [`Crypto`]: ErrorCategory::Crypto
+    /// [`Unsupported`]: ErrorCategory::Unsupported
+    pub const fn code(&self) -> u8 {
+        match self {
+            Self::Io => 3,
+            Self::Parse => 4,
+            Self::Schema => 5,
+            Self::Validation => 6,
+            Self::Crypto => 7,
+            Self::Unsupported => 8,
+        }
+    }
+}
+
+impl From<ErrorCategory> for ExitCode {
+    /// Creates an [`ExitCode`] from the [code](ErrorCategory::code) of an [`ErrorCategory`].
+    fn from(value: ErrorCategory) -> Self {
+        ExitCode::from(value.code())
+    }
+}
+
+impl From<&crate::Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`alpm_common::Error`](crate::Error).
+    fn from(value: &crate::Error) -> Self {
+        match value {
+            crate::Error::IoPath { .. } => Self::Io,
+            crate::Error::NotADirectory { .. }
+            | crate::Error::NonAbsolutePaths { .. }
+            | crate::Error::NonRelativePaths { .. }
+            | crate::Error::PathStripPrefix { .. } => Self::Validation,
+        }
+    }
+}
+
+impl From<&alpm_types::Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`alpm_types::Error`].
+    fn from(value: &alpm_types::Error) -> Self {
+        use alpm_types::Error;
+
+        match value {
+            Error::InvalidInteger { .. }
+            | Error::InvalidVariant(_)
+            | Error::InvalidEmail(_)
+            | Error::InvalidUrl(_)
+            | Error::InvalidLicense(_)
+            | Error::InvalidSemver { .. }
+            | Error::DelimiterNotFound { .. }
+            | Error::ParseError(_)
+            | Error::MissingComponent { .. }
+            | Error::InvalidSonameV1(_) => Self::Parse,
+            Error::InvalidArchitectures { .. }
+            | Error::ValueContainsInvalidChars { .. }
+            | Error::IncorrectLength { .. }
+            | Error::ValueDoesNotMatchRestrictions { .. }
+            | Error::RegexDoesNotMatch { .. }
+            | Error::PathNotAbsolute(_)
+            | Error::PathNotRelative(_)
+            | Error::PathIsNotAFile(_)
+            | Error::FileNameContainsInvalidChars(_, _)
+            | Error::FileNameIsEmpty
+            | Error::DeprecatedLicense(_)
+            | Error::InvalidComponent { .. }
+            | Error::SourceChecksumCountMismatch { .. }
+            | Error::PackageDescriptionTooLong { .. } => Self::Validation,
+            Error::InvalidOpenPGPv4Fingerprint
+            | Error::InvalidOpenPGPKeyId(_)
+            | Error::InvalidBase64Encoding { .. } => Self::Crypto,
+            Error::UnknownCompressionAlgorithmFileExtension { .. }
+            | Error::UnknownFileTypeIdentifier { .. } => Self::Unsupported,
+            Error::Package(error) => error.into(),
+        }
+    }
+}
+
+impl From<&alpm_types::PackageError> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`alpm_types::PackageError`].
+    fn from(value: &alpm_types::PackageError) -> Self {
+        use alpm_types::PackageError;
+
+        match value {
+            PackageError::InvalidMetadataFilename { .. }
+            | PackageError::InvalidPackageFileNamePath { .. }
+            | PackageError::InvalidPackageFileNameVersion { .. } => Self::Validation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    /// Ensures that all categories have distinct codes, which do not collide with reserved codes.
+    #[test]
+    fn error_category_codes_are_distinct() {
+        let categories = [
+            ErrorCategory::Io,
+            ErrorCategory::Parse,
+            ErrorCategory::Schema,
+            ErrorCategory::Validation,
+            ErrorCategory::Crypto,
+            ErrorCategory::Unsupported,
+        ];
+        let codes = categories
+            .iter()
+            .map(ErrorCategory::code)
+            .collect::<HashSet<_>>();
+
+        assert_eq!(codes.len(), categories.len());
+        assert!(codes.iter().all(|code| *code > 2));
+    }
+}
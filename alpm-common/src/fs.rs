@@ -0,0 +1,268 @@
+//! Helpers for writing files to the filesystem.
+
+use std::{
+    fs::{File, Permissions},
+    io::Write,
+    os::unix::fs::PermissionsExt,
+    path::Path,
+};
+
+use tempfile::{Builder, NamedTempFile};
+
+use crate::Error;
+
+/// Returns the parent directory of `path`.
+///
+/// Falls back to the current working directory, if `path` has no parent (e.g. `"file.txt"`).
+fn parent_dir(path: &Path) -> &Path {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    }
+}
+
+/// Creates a new, uniquely named temporary file in `dir`.
+///
+/// The temporary file is created in the same directory as the file it replaces, so that it can be
+/// renamed atomically.
+/// Its permissions are derived from the process umask, like those of a file created with
+/// [`File::create`].
+///
+/// # Errors
+///
+/// Returns an error if the temporary file cannot be created.
+fn create_temporary_file(dir: &Path) -> Result<NamedTempFile, Error> {
+    Builder::new()
+        .prefix(".")
+        .suffix(".tmp")
+        .permissions(Permissions::from_mode(0o666))
+        .tempfile_in(dir)
+        .map_err(|source| Error::IoPath {
+            path: dir.to_path_buf(),
+            context: "creating temporary file",
+            source,
+        })
+}
+
+/// Writes `contents` to the temporary file `file` and renames it to `path`.
+///
+/// If `mode` is [`Some`], the permissions of the temporary file are set before it is renamed.
+/// If any step fails, the temporary file is removed when `file` is dropped.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - the temporary file cannot be written to or synchronized,
+/// - the permissions of the temporary file cannot be set,
+/// - or the temporary file cannot be renamed to `path`.
+fn persist_temporary_file(
+    mut file: NamedTempFile,
+    path: &Path,
+    contents: &[u8],
+    mode: Option<u32>,
+) -> Result<(), Error> {
+    file.write_all(contents)
+        .and_then(|_| file.as_file().sync_all())
+        .map_err(|source| Error::IoPath {
+            path: file.path().to_path_buf(),
+            context: "writing temporary file",
+            source,
+        })?;
+
+    if let Some(mode) = mode {
+        file.as_file()
+            .set_permissions(Permissions::from_mode(mode))
+            .map_err(|source| Error::IoPath {
+                path: file.path().to_path_buf(),
+                context: "setting permissions of temporary file",
+                source,
+            })?;
+    }
+
+    file.persist(path).map_err(|error| Error::IoPath {
+        path: path.to_path_buf(),
+        context: "renaming temporary file",
+        source: error.error,
+    })?;
+
+    Ok(())
+}
+
+/// Synchronizes the directory at `path` to disk.
+///
+/// This persists changes to the entries of the directory (e.g. a file that has been renamed into
+/// it).
+///
+/// # Examples
+///
+/// ```
+/// use alpm_common::sync_dir;
+///
+/// # fn main() -> testresult::TestResult {
+/// let temp_dir = tempfile::tempdir()?;
+/// sync_dir(temp_dir.path())?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the directory at `path` cannot be opened or synchronized.
+pub fn sync_dir(path: impl AsRef<Path>) -> Result<(), Error> {
+    let path = path.as_ref();
+    File::open(path)
+        .and_then(|dir| dir.sync_all())
+        .map_err(|source| Error::IoPath {
+            path: path.to_path_buf(),
+            context: "synchronizing directory",
+            source,
+        })
+}
+
+/// Atomically writes `contents` to a file at `path`.
+///
+/// Delegates to [`write_atomic_with_mode`] without an explicit mode.
+/// The permissions of a newly created file are derived from the process umask.
+///
+/// # Examples
+///
+/// ```
+/// use alpm_common::write_atomic;
+///
+/// # fn main() -> testresult::TestResult {
+/// let temp_dir = tempfile::tempdir()?;
+/// let path = temp_dir.path().join(".PKGINFO");
+///
+/// write_atomic(&path, "pkgname = example\n")?;
+/// assert_eq!(std::fs::read_to_string(&path)?, "pkgname = example\n");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if [`write_atomic_with_mode`] fails.
+pub fn write_atomic(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> Result<(), Error> {
+    write_atomic_with_mode(path, contents, None)
+}
+
+/// Atomically writes `contents` to a file at `path`, optionally setting its permission `mode`.
+///
+/// The `contents` are first written to a uniquely named temporary file in the parent directory of
+/// `path`.
+/// After the temporary file is synchronized to disk, it is renamed to `path` and the parent
+/// directory is synchronized as well.
+/// This ensures that `path` either contains its previous or the new `contents`, even if the
+/// process is interrupted.
+///
+/// If `mode` is [`Some`], the permissions of the file are set to the provided mode (e.g. `0o644`)
+/// before it is renamed to `path`.
+/// An existing file at `path` is replaced and its permissions are not preserved.
+///
+/// # Examples
+///
+/// ```
+/// use std::os::unix::fs::PermissionsExt;
+///
+/// use alpm_common::write_atomic_with_mode;
+///
+/// # fn main() -> testresult::TestResult {
+/// let temp_dir = tempfile::tempdir()?;
+/// let path = temp_dir.path().join(".BUILDINFO");
+///
+/// write_atomic_with_mode(&path, "format = 2\n", Some(0o600))?;
+/// assert_eq!(std::fs::read_to_string(&path)?, "format = 2\n");
+/// assert_eq!(
+///     std::fs::metadata(&path)?.permissions().mode() & 0o777,
+///     0o600
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - the temporary file cannot be created, written to, or synchronized,
+/// - the permissions of the temporary file cannot be set,
+/// - the temporary file cannot be renamed to `path`,
+/// - or the parent directory of `path` cannot be synchronized.
+pub fn write_atomic_with_mode(
+    path: impl AsRef<Path>,
+    contents: impl AsRef<[u8]>,
+    mode: Option<u32>,
+) -> Result<(), Error> {
+    let path = path.as_ref();
+    let file = create_temporary_file(parent_dir(path))?;
+    persist_temporary_file(file, path, contents.as_ref(), mode)?;
+
+    sync_dir(parent_dir(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{read_dir, read_to_string};
+
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that an existing file is replaced and no temporary file is left behind.
+    #[test]
+    fn write_atomic_replaces_existing_file() -> TestResult {
+        let temp_dir = tempfile::tempdir()?;
+        let path = temp_dir.path().join("file.txt");
+
+        write_atomic(&path, "old")?;
+        write_atomic(&path, "new")?;
+
+        assert_eq!(read_to_string(&path)?, "new");
+        assert_eq!(read_dir(temp_dir.path())?.count(), 1);
+        Ok(())
+    }
+
+    /// Ensures that the temporary file is removed if the file cannot be persisted.
+    #[test]
+    fn write_atomic_cleans_up_on_error() -> TestResult {
+        let temp_dir = tempfile::tempdir()?;
+        // A directory cannot be replaced by a file.
+        let path = temp_dir.path().join("directory");
+        std::fs::create_dir(&path)?;
+
+        assert!(write_atomic(&path, "data").is_err());
+        assert_eq!(read_dir(temp_dir.path())?.count(), 1);
+        Ok(())
+    }
+
+    /// Ensures that concurrent writers to the same path do not interfere with each other.
+    #[test]
+    fn write_atomic_concurrently() -> TestResult {
+        let temp_dir = tempfile::tempdir()?;
+        let path = temp_dir.path().join("file.txt");
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|index| {
+                    let path = &path;
+                    scope.spawn(move || write_atomic(path, format!("{index}")))
+                })
+                .collect();
+            handles
+                .into_iter()
+                .try_for_each(|handle| handle.join().expect("writer thread panicked"))
+        })?;
+
+        assert!(read_to_string(&path)?.parse::<u8>()? < 8);
+        assert_eq!(read_dir(temp_dir.path())?.count(), 1);
+        Ok(())
+    }
+
+    /// Ensures that the parent directory of a relative path without parent is the current one.
+    #[test]
+    fn parent_dir_of_file_name() {
+        assert_eq!(parent_dir(Path::new("file.txt")), Path::new("."));
+        assert_eq!(parent_dir(Path::new("dir/file.txt")), Path::new("dir"));
+    }
+}
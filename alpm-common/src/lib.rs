@@ -1,9 +1,17 @@
 #![doc = include_str!("../README.md")]
 
+mod category;
 mod error;
+#[cfg(feature = "fs")]
+mod fs;
+#[cfg(feature = "fs")]
 mod package;
 mod traits;
+pub use category::ErrorCategory;
 pub use error::Error;
+#[cfg(feature = "fs")]
+pub use fs::{sync_dir, write_atomic, write_atomic_with_mode};
+#[cfg(feature = "fs")]
 pub use package::input::{InputPath, InputPaths, relative_data_files, relative_files};
 pub use traits::{metadata_file::MetadataFile, schema::FileFormatSchema};
 
@@ -2,9 +2,9 @@
 
 #[cfg(doc)]
 use std::str::FromStr;
-use std::{io::Read, path::Path};
+use std::{io::Read, ops::RangeInclusive, path::Path};
 
-use alpm_types::SchemaVersion;
+use alpm_types::{SchemaVersion, semver_version::Version};
 
 /// A trait for file format schemas and their versioning.
 ///
@@ -58,4 +58,101 @@ pub trait FileFormatSchema {
     fn derive_from_str(s: &str) -> Result<Self, Self::Err>
     where
         Self: Sized;
+
+    /// Returns the range of [`SchemaVersion`]s of data that can be handled using [`Self`].
+    ///
+    /// By default, data is considered compatible if it uses the same major version as [`Self`]
+    /// and a version lower than or equal to that of [`Self`] (e.g. data using schema version
+    /// `2.0.0` can be handled using a schema with version `2.1.0`, but not using one with version
+    /// `3.0.0`).
+    ///
+    /// Implementers may override this function to declare a different range of compatible
+    /// versions.
+    fn compatible_versions(&self) -> RangeInclusive<SchemaVersion> {
+        let version = self.inner();
+        SchemaVersion::new(Version::new(version.inner().major, 0, 0))..=version.clone()
+    }
+
+    /// Returns whether data using schema `version` can be handled using [`Self`].
+    ///
+    /// Defers to [`FileFormatSchema::compatible_versions`].
+    fn is_compatible_with(&self, version: &SchemaVersion) -> bool {
+        self.compatible_versions().contains(version)
+    }
+
+    /// Selects the best schema for the string slice `s` from a list of `supported` schemas.
+    ///
+    /// Derives the schema of `s` using [`FileFormatSchema::derive_from_str`] and returns the
+    /// schema with the highest version in `supported`, that is compatible with it (see
+    /// [`FileFormatSchema::is_compatible_with`]).
+    /// Returns [`None`] if none of the `supported` schemas is compatible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{io::Read, path::Path};
+    ///
+    /// use alpm_common::FileFormatSchema;
+    /// use alpm_types::SchemaVersion;
+    ///
+    /// /// A schema that is derived from the first line of a string slice.
+    /// #[derive(Debug, PartialEq)]
+    /// struct Schema(SchemaVersion);
+    ///
+    /// impl FileFormatSchema for Schema {
+    ///     type Err = alpm_types::Error;
+    ///
+    ///     fn inner(&self) -> &SchemaVersion {
+    ///         &self.0
+    ///     }
+    ///
+    ///     fn derive_from_file(_file: impl AsRef<Path>) -> Result<Self, Self::Err> {
+    ///         unimplemented!()
+    ///     }
+    ///
+    ///     fn derive_from_reader(_reader: impl Read) -> Result<Self, Self::Err> {
+    ///         unimplemented!()
+    ///     }
+    ///
+    ///     fn derive_from_str(s: &str) -> Result<Self, Self::Err> {
+    ///         Ok(Self(s.lines().next().unwrap_or_default().parse()?))
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let supported = [
+    ///     Schema("1".parse()?),
+    ///     Schema("2.0.0".parse()?),
+    ///     Schema("2.1.0".parse()?),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     Schema::best_schema_for("1\n", &supported)?,
+    ///     Some(&supported[0])
+    /// );
+    /// assert_eq!(
+    ///     Schema::best_schema_for("2\n", &supported)?,
+    ///     Some(&supported[2])
+    /// );
+    /// assert_eq!(Schema::best_schema_for("2.2.0\n", &supported)?, None);
+    /// assert_eq!(Schema::best_schema_for("3\n", &supported)?, None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no schema can be derived from `s`.
+    fn best_schema_for<'a>(s: &str, supported: &'a [Self]) -> Result<Option<&'a Self>, Self::Err>
+    where
+        Self: Sized,
+    {
+        let derived = Self::derive_from_str(s)?;
+        let version = derived.inner();
+
+        Ok(supported
+            .iter()
+            .filter(|schema| schema.is_compatible_with(version))
+            .max_by(|a, b| a.inner().cmp(b.inner())))
+    }
 }
@@ -1,3 +1,5 @@
+error-cancelled = The operation has been cancelled.
+
 error-create-zstd-encoder =
   Error creating a Zstandard encoder while { $context } with { $compression_settings }:
   { $source }
@@ -55,3 +57,25 @@ error-unknown-compression-extension =
 
 error-unsupported-compression =
   Unsupported compression algorithm: { $value }
+
+error-io-write-seekable-frame = writing a seekable zstd frame
+
+error-io-write-seek-table = writing the seek table
+
+error-io-read-seek-table = reading the seek table
+
+error-invalid-seek-table =
+  Invalid seek table of seekable zstd compressed data:
+  { $reason }
+
+error-invalid-seek-table-too-small = The data is too small to contain a seek table.
+
+error-invalid-seek-table-magic = The data does not end with the seekable magic number.
+
+error-invalid-seek-table-header = The seek table is not contained in a valid skippable frame.
+
+error-invalid-seek-table-frame-sizes = The frame sizes in the seek table do not match the size of the data.
+
+error-io-write-finish-uncompressed = finishing the uncompressed stream
+
+error-io-read-compressed-position = retrieving the position in the compressed output
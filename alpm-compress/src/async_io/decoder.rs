@@ -0,0 +1,156 @@
+//! Asynchronous decoder for decompression which supports multiple backends.
+
+use std::{
+    fmt::{self, Debug},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use async_compression::tokio::bufread::{BzDecoder, GzipDecoder, XzDecoder, ZstdDecoder};
+use tokio::io::{AsyncBufRead, AsyncRead, ReadBuf};
+
+use crate::decompression::DecompressionSettings;
+
+/// Asynchronous decoder for decompression which supports multiple backends.
+///
+/// Wraps [`BzDecoder`], [`GzipDecoder`], [`XzDecoder`] and [`ZstdDecoder`]
+/// and provides a unified [`AsyncRead`] implementation across all of them.
+pub enum AsyncCompressionDecoder<R: AsyncBufRead + Unpin> {
+    /// The bzip2 decompression decoder.
+    Bzip2(BzDecoder<R>),
+
+    /// The gzip decompression decoder.
+    Gzip(GzipDecoder<R>),
+
+    /// The xz decompression decoder.
+    Xz(XzDecoder<R>),
+
+    /// The zstd decompression decoder.
+    Zstd(ZstdDecoder<R>),
+
+    /// No compression.
+    None(R),
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncCompressionDecoder<R> {
+    /// Creates a new [`AsyncCompressionDecoder`].
+    ///
+    /// Uses a `reader` to stream from and initializes a specific backend based on the provided
+    /// [`DecompressionSettings`].
+    ///
+    /// # Note
+    ///
+    /// Decoders for gzip and zstd decode all consecutive members or frames in the stream, which
+    /// matches the behavior of [`CompressionDecoder`](crate::decompression::CompressionDecoder).
+    pub fn new(reader: R, settings: DecompressionSettings) -> Self {
+        match settings {
+            DecompressionSettings::Bzip2 => Self::Bzip2(BzDecoder::new(reader)),
+            DecompressionSettings::Gzip => {
+                let mut decoder = GzipDecoder::new(reader);
+                decoder.multiple_members(true);
+                Self::Gzip(decoder)
+            }
+            DecompressionSettings::Xz => Self::Xz(XzDecoder::new(reader)),
+            DecompressionSettings::Zstd => {
+                let mut decoder = ZstdDecoder::new(reader);
+                decoder.multiple_members(true);
+                Self::Zstd(decoder)
+            }
+            DecompressionSettings::None => Self::None(reader),
+        }
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> Debug for AsyncCompressionDecoder<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "AsyncCompressionDecoder({})",
+            match self {
+                AsyncCompressionDecoder::Bzip2(_) => "Bzip2",
+                AsyncCompressionDecoder::Gzip(_) => "Gzip",
+                AsyncCompressionDecoder::Xz(_) => "Xz",
+                AsyncCompressionDecoder::Zstd(_) => "Zstd",
+                AsyncCompressionDecoder::None(_) => "None",
+            }
+        )
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncRead for AsyncCompressionDecoder<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            AsyncCompressionDecoder::Bzip2(decoder) => Pin::new(decoder).poll_read(cx, buf),
+            AsyncCompressionDecoder::Gzip(decoder) => Pin::new(decoder).poll_read(cx, buf),
+            AsyncCompressionDecoder::Xz(decoder) => Pin::new(decoder).poll_read(cx, buf),
+            AsyncCompressionDecoder::Zstd(decoder) => Pin::new(decoder).poll_read(cx, buf),
+            AsyncCompressionDecoder::None(reader) => Pin::new(reader).poll_read(cx, buf),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use rstest::rstest;
+    use tempfile::tempfile;
+    use testresult::TestResult;
+    use tokio::io::{AsyncReadExt, BufReader};
+
+    use super::*;
+    use crate::compression::{
+        Bzip2CompressionLevel,
+        CompressionEncoder,
+        CompressionSettings,
+        GzipCompressionLevel,
+        XzCompressionLevel,
+        ZstdCompressionLevel,
+        ZstdThreads,
+    };
+
+    /// Ensures that the [`AsyncCompressionDecoder`] can decompress data compressed by
+    /// [`CompressionEncoder`].
+    #[rstest]
+    #[case::bzip2(DecompressionSettings::Bzip2, CompressionSettings::Bzip2 {
+        compression_level: Bzip2CompressionLevel::default()
+    })]
+    #[case::gzip(DecompressionSettings::Gzip, CompressionSettings::Gzip {
+        compression_level: GzipCompressionLevel::default()
+    })]
+    #[case::xz(DecompressionSettings::Xz, CompressionSettings::Xz {
+        compression_level: XzCompressionLevel::default()
+    })]
+    #[case::zstd(DecompressionSettings::Zstd, CompressionSettings::Zstd {
+        compression_level: ZstdCompressionLevel::default(),
+        threads: ZstdThreads::new(0),
+    })]
+    #[case::no_compression(DecompressionSettings::None, CompressionSettings::None)]
+    #[tokio::test]
+    async fn test_async_compression_decoder_roundtrip(
+        #[case] decompression_settings: DecompressionSettings,
+        #[case] compression_settings: CompressionSettings,
+    ) -> TestResult {
+        let input_data = b"alpm4ever";
+
+        let file = tempfile()?;
+        let mut encoder = CompressionEncoder::new(file.try_clone()?, &compression_settings)?;
+        encoder.write_all(input_data)?;
+        encoder.finish()?;
+
+        let mut file = tokio::fs::File::from_std(file);
+        tokio::io::AsyncSeekExt::rewind(&mut file).await?;
+
+        let mut decoder =
+            AsyncCompressionDecoder::new(BufReader::new(file), decompression_settings);
+        let mut output = Vec::new();
+        decoder.read_to_end(&mut output).await?;
+
+        assert_eq!(output, input_data);
+        Ok(())
+    }
+}
@@ -0,0 +1,278 @@
+//! Asynchronous encoder for compression which supports multiple backends.
+
+use std::{
+    fmt::{self, Debug},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use alpm_types::CompressionAlgorithmFileExtension;
+use async_compression::{
+    Level,
+    tokio::write::{BzEncoder, GzipEncoder, XzEncoder, ZstdEncoder},
+    zstd::CParameter,
+};
+use fluent_i18n::t;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::{
+    Error,
+    compression::{CompressionSettings, ZstdThreads},
+};
+
+/// Asynchronous encoder for compression which supports multiple backends.
+///
+/// Wraps [`BzEncoder`], [`GzipEncoder`], [`XzEncoder`] and [`ZstdEncoder`]
+/// and provides a unified [`AsyncWrite`] implementation across all of them.
+///
+/// # Note
+///
+/// Seekable zstd compression ([`CompressionSettings::ZstdSeekable`]) is not supported.
+pub enum AsyncCompressionEncoder<W: AsyncWrite + Unpin> {
+    /// The bzip2 compression encoder.
+    Bzip2(BzEncoder<W>),
+
+    /// The gzip compression encoder.
+    Gzip(GzipEncoder<W>),
+
+    /// The xz compression encoder.
+    Xz(XzEncoder<W>),
+
+    /// The zstd compression encoder.
+    Zstd(ZstdEncoder<W>),
+
+    /// No compression.
+    None(W),
+}
+
+impl<W: AsyncWrite + Unpin> AsyncCompressionEncoder<W> {
+    /// Creates a new [`AsyncCompressionEncoder`].
+    ///
+    /// Uses a `writer` to stream to and initializes a specific backend based on the provided
+    /// [`CompressionSettings`].
+    /// Zstd compressed data includes a checksum at the end of each frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the amount of physical CPU cores can not be turned into a `u32`,
+    /// - or [`CompressionSettings::ZstdSeekable`] is used.
+    pub fn new(writer: W, settings: &CompressionSettings) -> Result<Self, Error> {
+        Ok(match settings {
+            CompressionSettings::Bzip2 { compression_level } => Self::Bzip2(
+                BzEncoder::with_quality(writer, Level::Precise(compression_level.into())),
+            ),
+            CompressionSettings::Gzip { compression_level } => Self::Gzip(
+                GzipEncoder::with_quality(writer, Level::Precise(compression_level.into())),
+            ),
+            CompressionSettings::Xz { compression_level } => Self::Xz(XzEncoder::with_quality(
+                writer,
+                Level::Precise(compression_level.into()),
+            )),
+            CompressionSettings::Zstd {
+                compression_level,
+                threads,
+            } => {
+                // Use available physical CPU cores if the special value `0` is used.
+                let threads = match threads {
+                    ZstdThreads(0) => {
+                        u32::try_from(num_cpus::get_physical()).map_err(Error::IntegerConversion)?
+                    }
+                    ZstdThreads(threads) => *threads,
+                };
+                Self::Zstd(ZstdEncoder::with_quality_and_params(
+                    writer,
+                    Level::Precise(compression_level.into()),
+                    &[
+                        CParameter::checksum_flag(true),
+                        CParameter::nb_workers(threads),
+                    ],
+                ))
+            }
+            CompressionSettings::ZstdSeekable { .. } => {
+                return Err(Error::UnsupportedCompressionAlgorithm {
+                    value: format!("{settings:?}"),
+                });
+            }
+            CompressionSettings::None => Self::None(writer),
+        })
+    }
+
+    /// Finishes the compression stream.
+    ///
+    /// Writes all remaining compressed data and shuts down the inner writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the wrapped encoder fails.
+    pub async fn finish(mut self) -> Result<W, Error> {
+        let compression_type = match &self {
+            AsyncCompressionEncoder::Bzip2(_) => Some(CompressionAlgorithmFileExtension::Bzip2),
+            AsyncCompressionEncoder::Gzip(_) => Some(CompressionAlgorithmFileExtension::Gzip),
+            AsyncCompressionEncoder::Xz(_) => Some(CompressionAlgorithmFileExtension::Xz),
+            AsyncCompressionEncoder::Zstd(_) => Some(CompressionAlgorithmFileExtension::Zstd),
+            AsyncCompressionEncoder::None(_) => None,
+        };
+
+        if let Err(source) = self.shutdown().await {
+            return Err(match compression_type {
+                Some(compression_type) => Error::FinishEncoder {
+                    compression_type,
+                    source,
+                },
+                None => Error::IoWrite {
+                    context: t!("error-io-write-finish-uncompressed"),
+                    source,
+                },
+            });
+        }
+
+        Ok(match self {
+            AsyncCompressionEncoder::Bzip2(encoder) => encoder.into_inner(),
+            AsyncCompressionEncoder::Gzip(encoder) => encoder.into_inner(),
+            AsyncCompressionEncoder::Xz(encoder) => encoder.into_inner(),
+            AsyncCompressionEncoder::Zstd(encoder) => encoder.into_inner(),
+            AsyncCompressionEncoder::None(writer) => writer,
+        })
+    }
+}
+
+impl<W: AsyncWrite + Unpin> Debug for AsyncCompressionEncoder<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "AsyncCompressionEncoder({})",
+            match self {
+                AsyncCompressionEncoder::Bzip2(_) => "Bzip2",
+                AsyncCompressionEncoder::Gzip(_) => "Gzip",
+                AsyncCompressionEncoder::Xz(_) => "Xz",
+                AsyncCompressionEncoder::Zstd(_) => "Zstd",
+                AsyncCompressionEncoder::None(_) => "None",
+            }
+        )
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for AsyncCompressionEncoder<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        match self.get_mut() {
+            AsyncCompressionEncoder::Bzip2(encoder) => Pin::new(encoder).poll_write(cx, buf),
+            AsyncCompressionEncoder::Gzip(encoder) => Pin::new(encoder).poll_write(cx, buf),
+            AsyncCompressionEncoder::Xz(encoder) => Pin::new(encoder).poll_write(cx, buf),
+            AsyncCompressionEncoder::Zstd(encoder) => Pin::new(encoder).poll_write(cx, buf),
+            AsyncCompressionEncoder::None(writer) => Pin::new(writer).poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            AsyncCompressionEncoder::Bzip2(encoder) => Pin::new(encoder).poll_flush(cx),
+            AsyncCompressionEncoder::Gzip(encoder) => Pin::new(encoder).poll_flush(cx),
+            AsyncCompressionEncoder::Xz(encoder) => Pin::new(encoder).poll_flush(cx),
+            AsyncCompressionEncoder::Zstd(encoder) => Pin::new(encoder).poll_flush(cx),
+            AsyncCompressionEncoder::None(writer) => Pin::new(writer).poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            AsyncCompressionEncoder::Bzip2(encoder) => Pin::new(encoder).poll_shutdown(cx),
+            AsyncCompressionEncoder::Gzip(encoder) => Pin::new(encoder).poll_shutdown(cx),
+            AsyncCompressionEncoder::Xz(encoder) => Pin::new(encoder).poll_shutdown(cx),
+            AsyncCompressionEncoder::Zstd(encoder) => Pin::new(encoder).poll_shutdown(cx),
+            AsyncCompressionEncoder::None(writer) => Pin::new(writer).poll_shutdown(cx),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::{
+        compression::{
+            Bzip2CompressionLevel,
+            GzipCompressionLevel,
+            XzCompressionLevel,
+            ZstdCompressionLevel,
+        },
+        seekable::ZstdSeekableFrameSize,
+    };
+
+    /// Ensures that data compressed by [`AsyncCompressionEncoder`] can be decompressed by the
+    /// respective synchronous decoder.
+    #[rstest]
+    #[case::bzip2(CompressionSettings::Bzip2 {
+        compression_level: Bzip2CompressionLevel::default()
+    })]
+    #[case::gzip(CompressionSettings::Gzip {
+        compression_level: GzipCompressionLevel::default()
+    })]
+    #[case::xz(CompressionSettings::Xz {
+        compression_level: XzCompressionLevel::default()
+    })]
+    #[case::zstd(CompressionSettings::Zstd {
+        compression_level: ZstdCompressionLevel::default(),
+        threads: ZstdThreads::new(1),
+    })]
+    #[case::no_compression(CompressionSettings::None)]
+    #[tokio::test]
+    async fn test_async_compression_encoder_roundtrip(
+        #[case] settings: CompressionSettings,
+    ) -> TestResult {
+        let input_data = b"alpm4ever";
+
+        let mut encoder = AsyncCompressionEncoder::new(Vec::new(), &settings)?;
+        encoder.write_all(input_data).await?;
+        let compressed = encoder.finish().await?;
+
+        let mut output = Vec::new();
+        match settings {
+            CompressionSettings::Bzip2 { .. } => {
+                std::io::Read::read_to_end(
+                    &mut bzip2::read::BzDecoder::new(compressed.as_slice()),
+                    &mut output,
+                )?;
+            }
+            CompressionSettings::Gzip { .. } => {
+                std::io::Read::read_to_end(
+                    &mut flate2::read::GzDecoder::new(compressed.as_slice()),
+                    &mut output,
+                )?;
+            }
+            CompressionSettings::Xz { .. } => {
+                std::io::Read::read_to_end(
+                    &mut liblzma::read::XzDecoder::new(compressed.as_slice()),
+                    &mut output,
+                )?;
+            }
+            CompressionSettings::Zstd { .. } => output = zstd::decode_all(compressed.as_slice())?,
+            CompressionSettings::ZstdSeekable { .. } | CompressionSettings::None => {
+                output = compressed
+            }
+        }
+
+        assert_eq!(output, input_data);
+        Ok(())
+    }
+
+    /// Ensures that seekable zstd compression is rejected.
+    #[test]
+    fn test_async_compression_encoder_seekable_unsupported() {
+        let settings = CompressionSettings::ZstdSeekable {
+            compression_level: ZstdCompressionLevel::default(),
+            frame_size: ZstdSeekableFrameSize::default(),
+        };
+        assert!(matches!(
+            AsyncCompressionEncoder::new(Vec::new(), &settings),
+            Err(Error::UnsupportedCompressionAlgorithm { .. })
+        ));
+    }
+}
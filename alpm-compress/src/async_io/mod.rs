@@ -0,0 +1,19 @@
+//! Asynchronous compression, decompression and tarball reading.
+//!
+//! Provides [`tokio`] compatible counterparts to the synchronous
+//! [`CompressionEncoder`](crate::compression::CompressionEncoder),
+//! [`CompressionDecoder`](crate::decompression::CompressionDecoder) and
+//! [`TarballReader`](crate::tarball::TarballReader).
+//! This allows streaming compressed data (e.g. from a network connection) without dedicating a
+//! blocking thread to each stream.
+//!
+//! This module is only available if the `tokio` feature is enabled.
+
+mod decoder;
+pub use decoder::AsyncCompressionDecoder;
+
+mod encoder;
+pub use encoder::AsyncCompressionEncoder;
+
+mod tarball;
+pub use tarball::{AsyncTarballEntries, AsyncTarballEntry, AsyncTarballReader};
@@ -0,0 +1,285 @@
+//! Asynchronous reading of tarballs.
+
+use std::{
+    fmt::{self, Debug},
+    path::{Path, PathBuf},
+};
+
+use fluent_i18n::t;
+use tokio::{
+    fs::File,
+    io::{AsyncBufRead, AsyncReadExt, BufReader},
+};
+use tokio_stream::StreamExt;
+use tokio_tar::{Archive, Entries, Entry, EntryType};
+
+use crate::{Error, async_io::AsyncCompressionDecoder, decompression::DecompressionSettings};
+
+/// An asynchronous tarball reader that can be used to read both compressed tarballs `.tar.*` and
+/// uncompressed tar archives `.tar`.
+///
+/// This is the asynchronous counterpart to [`TarballReader`](crate::tarball::TarballReader).
+/// Can be created from any [`AsyncBufRead`] implementation (e.g. a network stream) using
+/// [`AsyncTarballReader::new`], or from a file using [`AsyncTarballReader::open`].
+pub struct AsyncTarballReader<R: AsyncBufRead + Unpin> {
+    archive: Archive<AsyncCompressionDecoder<R>>,
+}
+
+impl<R: AsyncBufRead + Unpin> Debug for AsyncTarballReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncTarballReader")
+            .field("archive", &"Archive<AsyncCompressionDecoder>")
+            .finish()
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncTarballReader<R> {
+    /// Creates a new [`AsyncTarballReader`] that reads from an [`AsyncCompressionDecoder`].
+    pub fn new(decoder: AsyncCompressionDecoder<R>) -> Self {
+        Self {
+            archive: Archive::new(decoder),
+        }
+    }
+
+    /// Returns a stream of the entries in the tarball.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`Archive::entries`] fails.
+    pub fn entries(&mut self) -> Result<AsyncTarballEntries<R>, Error> {
+        let inner = self.archive.entries().map_err(|source| Error::IoRead {
+            context: t!("error-io-read-archive-entries"),
+            source,
+        })?;
+        Ok(AsyncTarballEntries { inner })
+    }
+
+    /// Reads a specific [`AsyncTarballEntry`] by its path.
+    ///
+    /// Returns [`None`] if the entry does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`Self::entries`] or reading an entry fails.
+    pub async fn read_entry<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<Option<AsyncTarballEntry<R>>, Error> {
+        let mut entries = self.entries()?;
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.path() == path.as_ref() {
+                return Ok(Some(entry));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl AsyncTarballReader<BufReader<File>> {
+    /// Creates a new [`AsyncTarballReader`] from a file at `path`.
+    ///
+    /// The optional compression algorithm is detected based on the file extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the file at `path` cannot be opened for reading,
+    /// - or the file extension is neither a `.tar` nor an extension of a supported compression
+    ///   algorithm.
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let settings = DecompressionSettings::from_archive_path(path)?;
+        let file = File::open(path).await.map_err(|source| Error::IoRead {
+            context: t!("error-io-open-archive"),
+            source,
+        })?;
+        Ok(Self::new(AsyncCompressionDecoder::new(
+            BufReader::new(file),
+            settings,
+        )))
+    }
+}
+
+/// An entry in a tarball that is read asynchronously.
+pub struct AsyncTarballEntry<R: AsyncBufRead + Unpin> {
+    /// The path of the entry in the archive.
+    path: PathBuf,
+    /// The raw tar entry.
+    entry: Entry<Archive<AsyncCompressionDecoder<R>>>,
+}
+
+impl<R: AsyncBufRead + Unpin> Debug for AsyncTarballEntry<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncTarballEntry")
+            .field("path", &self.path)
+            .field("entry", &"tokio_tar::Entry<AsyncCompressionDecoder>")
+            .finish()
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncTarballEntry<R> {
+    /// Returns the path of the entry in the archive.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the content of the entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the entry fails.
+    pub async fn content(&mut self) -> Result<Vec<u8>, Error> {
+        let mut buffer = Vec::new();
+        self.entry
+            .read_to_end(&mut buffer)
+            .await
+            .map_err(|source| Error::IoRead {
+                context: t!("error-io-read-archive-entry-content"),
+                source,
+            })?;
+        Ok(buffer)
+    }
+
+    /// Checks whether the [`AsyncTarballEntry`] represents a directory.
+    pub fn is_dir(&self) -> bool {
+        self.entry.header().entry_type() == EntryType::Directory
+    }
+
+    /// Checks whether the [`AsyncTarballEntry`] represents a regular file.
+    pub fn is_file(&self) -> bool {
+        self.entry.header().entry_type() == EntryType::Regular
+    }
+
+    /// Checks whether the [`AsyncTarballEntry`] represents a symlink.
+    pub fn is_symlink(&self) -> bool {
+        self.entry.header().entry_type() == EntryType::Symlink
+    }
+
+    /// Returns the access permissions that apply for the [`AsyncTarballEntry`].
+    ///
+    /// The mode is masked with `0o7777` to ensure only the permission bits are returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if retrieving the mode from the entry's header fails.
+    pub fn permissions(&self) -> Result<u32, Error> {
+        Ok(self.entry.header().mode().map_err(|source| Error::IoRead {
+            context: t!("error-io-read-archive-entry-mode"),
+            source,
+        })? & 0o7777)
+    }
+}
+
+/// A stream of the entries in a tarball that is read asynchronously.
+pub struct AsyncTarballEntries<R: AsyncBufRead + Unpin> {
+    inner: Entries<AsyncCompressionDecoder<R>>,
+}
+
+impl<R: AsyncBufRead + Unpin> Debug for AsyncTarballEntries<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncTarballEntries")
+            .field("inner", &"Entries<AsyncCompressionDecoder>")
+            .finish()
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncTarballEntries<R> {
+    /// Returns the next [`AsyncTarballEntry`].
+    ///
+    /// Returns [`None`] if there are no more entries.
+    ///
+    /// # Note
+    ///
+    /// The content of an entry must be read before advancing to the next entry, as all entries
+    /// share the same underlying stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the entry or its path fails.
+    pub async fn next_entry(&mut self) -> Result<Option<AsyncTarballEntry<R>>, Error> {
+        let Some(entry) = self.inner.next().await else {
+            return Ok(None);
+        };
+        let entry = entry.map_err(|source| Error::IoRead {
+            context: t!("error-io-read-archive-entry"),
+            source,
+        })?;
+        let path = entry
+            .path()
+            .map_err(|source| Error::IoRead {
+                context: t!("error-io-read-archive-entry-path"),
+                source,
+            })?
+            .to_path_buf();
+
+        Ok(Some(AsyncTarballEntry { path, entry }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use rstest::rstest;
+    use tempfile::NamedTempFile;
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::{
+        compression::{
+            Bzip2CompressionLevel,
+            CompressionSettings,
+            GzipCompressionLevel,
+            XzCompressionLevel,
+            ZstdCompressionLevel,
+            ZstdThreads,
+        },
+        tarball::TarballBuilder,
+    };
+
+    /// Ensures that tarballs created by [`TarballBuilder`] can be read by [`AsyncTarballReader`].
+    #[rstest]
+    #[case::bzip2(".tar.bz2", CompressionSettings::Bzip2 {
+        compression_level: Bzip2CompressionLevel::default()
+    })]
+    #[case::gzip(".tar.gz", CompressionSettings::Gzip {
+        compression_level: GzipCompressionLevel::default()
+    })]
+    #[case::xz(".tar.xz", CompressionSettings::Xz {
+        compression_level: XzCompressionLevel::default()
+    })]
+    #[case::zstd(".tar.zst", CompressionSettings::Zstd {
+        compression_level: ZstdCompressionLevel::default(),
+        threads: ZstdThreads::new(0),
+    })]
+    #[case::no_compression(".tar", CompressionSettings::None)]
+    #[tokio::test]
+    async fn test_async_tarball_reader_read_entry(
+        #[case] extension: &str,
+        #[case] compression_settings: CompressionSettings,
+    ) -> TestResult {
+        const TEST_CONTENT: &[u8; 9] = b"alpm4ever";
+        let test_file = NamedTempFile::new()?;
+        test_file.reopen()?.write_all(TEST_CONTENT)?;
+        let test_file_name = test_file.path().file_name().unwrap();
+
+        let archive = NamedTempFile::with_suffix(extension)?;
+        {
+            let mut builder = TarballBuilder::new(archive.reopen()?, &compression_settings)?;
+            builder
+                .inner_mut()
+                .append_path_with_name(test_file.path(), test_file_name)?;
+            builder.finish()?;
+        }
+
+        let mut reader = AsyncTarballReader::open(archive.path()).await?;
+        let Some(mut entry) = reader.read_entry(test_file_name).await? else {
+            panic!("The entry {test_file_name:?} should exist");
+        };
+
+        assert!(entry.is_file());
+        assert_eq!(entry.content().await?, TEST_CONTENT);
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@ use zstd::Encoder;
 use crate::{
     Error,
     compression::{CompressionSettings, ZstdThreads, level::ZstdCompressionLevel},
+    seekable::SeekableZstdEncoder,
 };
 
 /// Creates and configures an [`Encoder`].
@@ -76,7 +77,7 @@ fn create_zstd_encoder(
 
 /// Encoder for compression which supports multiple backends.
 ///
-/// Wraps [`BzEncoder`], [`GzEncoder`], [`XzEncoder`] and [`Encoder`].
+/// Wraps [`BzEncoder`], [`GzEncoder`], [`XzEncoder`], [`Encoder`] and [`SeekableZstdEncoder`].
 /// Provides a unified [`Write`] implementation across all of them.
 pub enum CompressionEncoder<'a> {
     /// The bzip2 compression encoder.
@@ -91,6 +92,9 @@ pub enum CompressionEncoder<'a> {
     /// The zstd compression encoder.
     Zstd(Encoder<'a, File>),
 
+    /// The seekable zstd compression encoder.
+    ZstdSeekable(SeekableZstdEncoder<File>),
+
     /// No compression.
     None(File),
 }
@@ -127,10 +131,36 @@ impl CompressionEncoder<'_> {
                 threads,
                 settings,
             )?),
+            CompressionSettings::ZstdSeekable {
+                compression_level,
+                frame_size,
+            } => Self::ZstdSeekable(SeekableZstdEncoder::new(
+                file,
+                compression_level,
+                frame_size,
+                settings,
+            )?),
             CompressionSettings::None => Self::None(file),
         })
     }
 
+    /// Returns a reference to the [`File`] that compressed data is written to.
+    ///
+    /// # Note
+    ///
+    /// Encoders buffer data internally, so the [`File`] may not yet contain all data written to
+    /// the [`CompressionEncoder`].
+    pub fn get_ref(&self) -> &File {
+        match self {
+            CompressionEncoder::Bzip2(encoder) => encoder.get_ref(),
+            CompressionEncoder::Gzip(encoder) => encoder.get_ref(),
+            CompressionEncoder::Xz(encoder) => encoder.get_ref(),
+            CompressionEncoder::Zstd(encoder) => encoder.get_ref(),
+            CompressionEncoder::ZstdSeekable(encoder) => encoder.get_ref(),
+            CompressionEncoder::None(file) => file,
+        }
+    }
+
     /// Finishes the compression stream.
     ///
     /// # Error
@@ -162,6 +192,7 @@ impl CompressionEncoder<'_> {
                     source,
                 })
             }
+            CompressionEncoder::ZstdSeekable(encoder) => encoder.finish(),
             CompressionEncoder::None(file) => Ok(file),
         }
     }
@@ -177,6 +208,7 @@ impl Debug for CompressionEncoder<'_> {
                 CompressionEncoder::Gzip(_) => "Gzip",
                 CompressionEncoder::Xz(_) => "Xz",
                 CompressionEncoder::Zstd(_) => "Zstd",
+                CompressionEncoder::ZstdSeekable(_) => "ZstdSeekable",
                 &CompressionEncoder::None(_) => "None",
             }
         )
@@ -190,6 +222,7 @@ impl Write for CompressionEncoder<'_> {
             CompressionEncoder::Gzip(encoder) => encoder.write(buf),
             CompressionEncoder::Xz(encoder) => encoder.write(buf),
             CompressionEncoder::Zstd(encoder) => encoder.write(buf),
+            CompressionEncoder::ZstdSeekable(encoder) => encoder.write(buf),
             CompressionEncoder::None(file) => file.write(buf),
         }
     }
@@ -200,6 +233,7 @@ impl Write for CompressionEncoder<'_> {
             CompressionEncoder::Gzip(encoder) => encoder.write_vectored(bufs),
             CompressionEncoder::Xz(encoder) => encoder.write_vectored(bufs),
             CompressionEncoder::Zstd(encoder) => encoder.write_vectored(bufs),
+            CompressionEncoder::ZstdSeekable(encoder) => encoder.write_vectored(bufs),
             CompressionEncoder::None(file) => file.write_vectored(bufs),
         }
     }
@@ -210,6 +244,7 @@ impl Write for CompressionEncoder<'_> {
             CompressionEncoder::Gzip(encoder) => encoder.flush(),
             CompressionEncoder::Xz(encoder) => encoder.flush(),
             CompressionEncoder::Zstd(encoder) => encoder.flush(),
+            CompressionEncoder::ZstdSeekable(encoder) => encoder.flush(),
             CompressionEncoder::None(file) => file.flush(),
         }
     }
@@ -220,6 +255,7 @@ impl Write for CompressionEncoder<'_> {
             CompressionEncoder::Gzip(encoder) => encoder.write_all(buf),
             CompressionEncoder::Xz(encoder) => encoder.write_all(buf),
             CompressionEncoder::Zstd(encoder) => encoder.write_all(buf),
+            CompressionEncoder::ZstdSeekable(encoder) => encoder.write_all(buf),
             CompressionEncoder::None(file) => file.write_all(buf),
         }
     }
@@ -230,6 +266,7 @@ impl Write for CompressionEncoder<'_> {
             CompressionEncoder::Gzip(encoder) => encoder.write_fmt(fmt),
             CompressionEncoder::Xz(encoder) => encoder.write_fmt(fmt),
             CompressionEncoder::Zstd(encoder) => encoder.write_fmt(fmt),
+            CompressionEncoder::ZstdSeekable(encoder) => encoder.write_fmt(fmt),
             CompressionEncoder::None(file) => file.write_fmt(fmt),
         }
     }
@@ -251,11 +288,14 @@ mod tests {
     use testresult::TestResult;
 
     use super::*;
-    use crate::compression::level::{
-        Bzip2CompressionLevel,
-        GzipCompressionLevel,
-        XzCompressionLevel,
-        ZstdCompressionLevel,
+    use crate::{
+        compression::level::{
+            Bzip2CompressionLevel,
+            GzipCompressionLevel,
+            XzCompressionLevel,
+            ZstdCompressionLevel,
+        },
+        seekable::ZstdSeekableFrameSize,
     };
 
     /// Ensures that the [`Write::write`] implementation works for each [`CompressionEncoder`].
@@ -266,6 +306,7 @@ mod tests {
     #[case::zstd_all_threads(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(0) })]
     #[case::zstd_one_thread(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(1) })]
     #[case::zstd_crazy_threads(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(99999) })]
+    #[case::zstd_seekable(CompressionSettings::ZstdSeekable { compression_level: ZstdCompressionLevel::default(), frame_size: ZstdSeekableFrameSize::new(4) })]
     #[case::no_compression(CompressionSettings::None)]
     fn test_compression_encoder_write(#[case] settings: CompressionSettings) -> TestResult {
         let file = tempfile()?;
@@ -293,6 +334,7 @@ mod tests {
     #[case::zstd_all_threads(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(0) })]
     #[case::zstd_one_thread(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(1) })]
     #[case::zstd_crazy_threads(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(99999) })]
+    #[case::zstd_seekable(CompressionSettings::ZstdSeekable { compression_level: ZstdCompressionLevel::default(), frame_size: ZstdSeekableFrameSize::new(4) })]
     #[case::no_compression(CompressionSettings::None)]
     fn test_compression_encoder_write_vectored(
         #[case] settings: CompressionSettings,
@@ -325,6 +367,7 @@ mod tests {
     #[case::zstd_all_threads(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(0) })]
     #[case::zstd_one_thread(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(1) })]
     #[case::zstd_crazy_threads(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(99999) })]
+    #[case::zstd_seekable(CompressionSettings::ZstdSeekable { compression_level: ZstdCompressionLevel::default(), frame_size: ZstdSeekableFrameSize::new(4) })]
     #[case::no_compression(CompressionSettings::None)]
     fn test_compression_encoder_write_all(#[case] settings: CompressionSettings) -> TestResult {
         let file = tempfile()?;
@@ -347,6 +390,7 @@ mod tests {
     #[case::zstd_all_threads(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(0) })]
     #[case::zstd_one_thread(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(1) })]
     #[case::zstd_crazy_threads(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(99999) })]
+    #[case::zstd_seekable(CompressionSettings::ZstdSeekable { compression_level: ZstdCompressionLevel::default(), frame_size: ZstdSeekableFrameSize::new(4) })]
     #[case::no_compression(CompressionSettings::None)]
     fn test_compression_encoder_write_fmt(#[case] settings: CompressionSettings) -> TestResult {
         let file = tempfile()?;
@@ -2,11 +2,14 @@
 
 use alpm_types::CompressionAlgorithmFileExtension;
 
-use crate::compression::{
-    Bzip2CompressionLevel,
-    GzipCompressionLevel,
-    XzCompressionLevel,
-    ZstdCompressionLevel,
+use crate::{
+    compression::{
+        Bzip2CompressionLevel,
+        GzipCompressionLevel,
+        XzCompressionLevel,
+        ZstdCompressionLevel,
+    },
+    seekable::ZstdSeekableFrameSize,
 };
 
 /// The amount of threads to use when compressing using zstd.
@@ -72,6 +75,19 @@ pub enum CompressionSettings {
         threads: ZstdThreads,
     },
 
+    /// Settings for the zstandard compression algorithm using the [Zstandard Seekable Format].
+    ///
+    /// The output is split into independent frames of `frame_size` and is followed by a seek
+    /// table, which allows for random access to the decompressed data.
+    ///
+    /// [Zstandard Seekable Format]: https://github.com/facebook/zstd/blob/dev/contrib/seekable_format/zstd_seekable_compression_format.md
+    ZstdSeekable {
+        /// The used compression level.
+        compression_level: ZstdCompressionLevel,
+        /// The uncompressed size of each frame.
+        frame_size: ZstdSeekableFrameSize,
+    },
+
     /// No compression.
     None,
 }
@@ -98,7 +114,9 @@ impl From<&CompressionSettings> for Option<CompressionAlgorithmFileExtension> {
             CompressionSettings::Bzip2 { .. } => Some(CompressionAlgorithmFileExtension::Bzip2),
             CompressionSettings::Gzip { .. } => Some(CompressionAlgorithmFileExtension::Gzip),
             CompressionSettings::Xz { .. } => Some(CompressionAlgorithmFileExtension::Xz),
-            CompressionSettings::Zstd { .. } => Some(CompressionAlgorithmFileExtension::Zstd),
+            CompressionSettings::Zstd { .. } | CompressionSettings::ZstdSeekable { .. } => {
+                Some(CompressionAlgorithmFileExtension::Zstd)
+            }
             CompressionSettings::None => None,
         }
     }
@@ -3,7 +3,7 @@
 use std::{
     fmt::Debug,
     fs::File,
-    io::{BufReader, Read},
+    io::{BufRead, BufReader, Read},
 };
 
 use bzip2::bufread::BzDecoder;
@@ -17,21 +17,24 @@ use crate::{Error, decompression::DecompressionSettings};
 ///
 /// Wraps [`BzDecoder`], [`GzDecoder`], [`XzDecoder`] and [`Decoder`]
 /// and provides a unified [`Read`] implementation across all of them.
-pub enum CompressionDecoder<'a> {
+///
+/// By default, the decoder reads from a buffered [`File`], but any [`BufRead`] implementation can
+/// be used as source (see [`CompressionDecoder::from_reader`]).
+pub enum CompressionDecoder<'a, R: BufRead = BufReader<File>> {
     /// The bzip2 decompression decoder.
-    Bzip2(BzDecoder<BufReader<File>>),
+    Bzip2(BzDecoder<R>),
 
     /// The gzip decompression decoder.
-    Gzip(GzDecoder<BufReader<File>>),
+    Gzip(GzDecoder<R>),
 
     /// The xz decompression decoder.
-    Xz(XzDecoder<BufReader<File>>),
+    Xz(XzDecoder<R>),
 
     /// The zstd decompression decoder.
-    Zstd(Decoder<'a, BufReader<File>>),
+    Zstd(Decoder<'a, R>),
 
     /// No compression.
-    None(BufReader<File>),
+    None(R),
 }
 
 impl CompressionDecoder<'_> {
@@ -45,19 +48,34 @@ impl CompressionDecoder<'_> {
     /// Returns an error if creating the decoder for zstd compression fails
     /// (all other decoder initializations are infallible).
     pub fn new(file: File, settings: DecompressionSettings) -> Result<Self, Error> {
+        Self::from_reader(BufReader::new(file), settings)
+    }
+}
+
+impl<R: BufRead> CompressionDecoder<'_, R> {
+    /// Creates a new [`CompressionDecoder`] from a [`BufRead`] implementation.
+    ///
+    /// Uses `reader` to stream from and initializes a specific backend based on the provided
+    /// [`DecompressionSettings`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if creating the decoder for zstd compression fails
+    /// (all other decoder initializations are infallible).
+    pub fn from_reader(reader: R, settings: DecompressionSettings) -> Result<Self, Error> {
         match settings {
-            DecompressionSettings::Bzip2 => Ok(Self::Bzip2(BzDecoder::new(BufReader::new(file)))),
-            DecompressionSettings::Gzip => Ok(Self::Gzip(GzDecoder::new(BufReader::new(file)))),
-            DecompressionSettings::Xz => Ok(Self::Xz(XzDecoder::new(BufReader::new(file)))),
+            DecompressionSettings::Bzip2 => Ok(Self::Bzip2(BzDecoder::new(reader))),
+            DecompressionSettings::Gzip => Ok(Self::Gzip(GzDecoder::new(reader))),
+            DecompressionSettings::Xz => Ok(Self::Xz(XzDecoder::new(reader))),
             DecompressionSettings::Zstd => Ok(Self::Zstd(
-                Decoder::new(file).map_err(Error::CreateZstandardDecoder)?,
+                Decoder::with_buffer(reader).map_err(Error::CreateZstandardDecoder)?,
             )),
-            DecompressionSettings::None => Ok(Self::None(BufReader::new(file))),
+            DecompressionSettings::None => Ok(Self::None(reader)),
         }
     }
 }
 
-impl Debug for CompressionDecoder<'_> {
+impl<R: BufRead> Debug for CompressionDecoder<'_, R> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
@@ -73,7 +91,7 @@ impl Debug for CompressionDecoder<'_> {
     }
 }
 
-impl Read for CompressionDecoder<'_> {
+impl<R: BufRead> Read for CompressionDecoder<'_, R> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
         match self {
             CompressionDecoder::Bzip2(decoder) => decoder.read(buf),
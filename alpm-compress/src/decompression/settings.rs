@@ -57,6 +57,49 @@ impl TryFrom<&Path> for DecompressionSettings {
     }
 }
 
+impl DecompressionSettings {
+    /// Creates [`DecompressionSettings`] for the tar archive at `path`.
+    ///
+    /// In addition to the file extensions of supported compression algorithms, this considers
+    /// uncompressed `.tar` archives, for which [`DecompressionSettings::None`] is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file extension is neither a `.tar` nor an extension of a supported
+    /// compression algorithm.
+    pub fn from_archive_path(path: &Path) -> Result<Self, Error> {
+        match DecompressionSettings::try_from(path) {
+            Ok(settings) => Ok(settings),
+            Err(err) => {
+                // Check if it's a plain .tar archive without compression.
+                if let Some(extension) = path.extension()
+                    && extension.eq_ignore_ascii_case("tar")
+                {
+                    Ok(DecompressionSettings::None)
+                } else {
+                    Err(err)
+                }
+            }
+        }
+    }
+
+    /// Creates [`DecompressionSettings`] from the magic bytes at the start of compressed data.
+    ///
+    /// This allows detecting the compression algorithm of data for which no file extension is
+    /// available (e.g. of a stream or a file with a generic file extension).
+    /// Returns [`DecompressionSettings::None`] if `bytes` do not start with the magic bytes of a
+    /// supported compression algorithm.
+    pub fn from_magic_bytes(bytes: &[u8]) -> Self {
+        match bytes {
+            [b'B', b'Z', b'h', ..] => Self::Bzip2,
+            [0x1f, 0x8b, ..] => Self::Gzip,
+            [0xfd, b'7', b'z', b'X', b'Z', 0x00, ..] => Self::Xz,
+            [0x28, 0xb5, 0x2f, 0xfd, ..] => Self::Zstd,
+            _ => Self::None,
+        }
+    }
+}
+
 impl From<&CompressionSettings> for DecompressionSettings {
     /// Converts a [`CompressionSettings`] into a [`DecompressionSettings`].
     fn from(value: &CompressionSettings) -> Self {
@@ -64,7 +107,9 @@ impl From<&CompressionSettings> for DecompressionSettings {
             CompressionSettings::Bzip2 { .. } => DecompressionSettings::Bzip2,
             CompressionSettings::Gzip { .. } => DecompressionSettings::Gzip,
             CompressionSettings::Xz { .. } => DecompressionSettings::Xz,
-            CompressionSettings::Zstd { .. } => DecompressionSettings::Zstd,
+            CompressionSettings::Zstd { .. } | CompressionSettings::ZstdSeekable { .. } => {
+                DecompressionSettings::Zstd
+            }
             CompressionSettings::None => DecompressionSettings::None,
         }
     }
@@ -147,4 +192,19 @@ mod tests {
         assert_eq!(result, expected);
         Ok(())
     }
+
+    /// Ensures that the compression algorithm is detected from magic bytes.
+    #[rstest]
+    #[case::bzip2(b"BZh91AY&SY", DecompressionSettings::Bzip2)]
+    #[case::gzip(&[0x1f, 0x8b, 0x08, 0x00], DecompressionSettings::Gzip)]
+    #[case::xz(&[0xfd, b'7', b'z', b'X', b'Z', 0x00, 0x00], DecompressionSettings::Xz)]
+    #[case::zstd(&[0x28, 0xb5, 0x2f, 0xfd, 0x04], DecompressionSettings::Zstd)]
+    #[case::tar(b"foo/\0\0\0\0", DecompressionSettings::None)]
+    #[case::empty(&[], DecompressionSettings::None)]
+    fn test_decompression_settings_from_magic_bytes(
+        #[case] bytes: &[u8],
+        #[case] expected: DecompressionSettings,
+    ) {
+        assert_eq!(DecompressionSettings::from_magic_bytes(bytes), expected);
+    }
 }
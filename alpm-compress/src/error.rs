@@ -10,6 +10,12 @@ use crate::compression::CompressionSettings;
 /// An error that can occur when using compression.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    /// An operation has been cancelled using a [`CancellationToken`].
+    ///
+    /// [`CancellationToken`]: crate::progress::CancellationToken
+    #[error("{msg}", msg = t!("error-cancelled"))]
+    Cancelled,
+
     /// An error occurred while creating a Zstandard encoder.
     #[error("{msg}", msg = t!("error-create-zstd-encoder", {
         "context" => context,
@@ -80,6 +86,13 @@ pub enum Error {
         source: std::io::Error,
     },
 
+    /// A seek table of seekable zstd compressed data is not valid.
+    #[error("{msg}", msg = t!("error-invalid-seek-table", { "reason" => reason }))]
+    InvalidSeekTable {
+        /// The reason why the seek table is not valid.
+        reason: String,
+    },
+
     /// A compression level is not valid.
     #[error("{msg}", msg = t!("error-invalid-compression-level", {
         "level" => level.to_string(),
@@ -2,8 +2,12 @@
 
 mod error;
 
+#[cfg(feature = "tokio")]
+pub mod async_io;
 pub mod compression;
 pub mod decompression;
+pub mod progress;
+pub mod seekable;
 pub mod tarball;
 
 pub use error::Error;
@@ -0,0 +1,325 @@
+//! Progress reporting and cooperative cancellation.
+//!
+//! [`ProgressReader`] and [`ProgressWriter`] wrap arbitrary readers and writers (e.g. the source of
+//! a [`CompressionDecoder`] or a [`CompressionEncoder`]) and report the amount of bytes read or
+//! written to a callback.
+//! A [`CancellationToken`] allows aborting long running operations from another thread.
+//!
+//! [`CompressionDecoder`]: crate::decompression::CompressionDecoder
+//! [`CompressionEncoder`]: crate::compression::CompressionEncoder
+
+use std::{
+    fmt::{self, Debug},
+    io::{BufRead, Read, Write},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use crate::Error;
+
+/// A token for the cooperative cancellation of operations.
+///
+/// Clones of a [`CancellationToken`] share their state, so that a clone can be passed to an
+/// operation, while the original is used to cancel it (e.g. from a signal handler or another
+/// thread).
+/// Operations check the token regularly and fail with [`Error::Cancelled`] once it is cancelled.
+///
+/// # Examples
+///
+/// ```
+/// use alpm_compress::progress::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let clone = token.clone();
+/// assert!(!clone.is_cancelled());
+///
+/// token.cancel();
+/// assert!(clone.is_cancelled());
+/// assert!(clone.check().is_err());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a new [`CancellationToken`] that is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels all operations that use this token or one of its clones.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Checks whether the token has been cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Cancelled`] if the token has been cancelled.
+    pub fn check(&self) -> Result<(), Error> {
+        if self.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+        Ok(())
+    }
+
+    /// Checks whether the token has been cancelled, for use in I/O operations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error that wraps [`Error::Cancelled`] if the token has been cancelled.
+    pub(crate) fn check_io(&self) -> std::io::Result<()> {
+        self.check().map_err(std::io::Error::other)
+    }
+}
+
+/// A reader that reports the amount of bytes read and supports cancellation.
+///
+/// Wraps a [`Read`] (and optionally [`BufRead`]) implementation.
+/// After each read, the callback set using [`ProgressReader::with_callback`] is called with the
+/// total amount of bytes read so far.
+/// If a [`CancellationToken`] is set using [`ProgressReader::with_cancellation`] and has been
+/// cancelled, reading fails with an I/O error that wraps [`Error::Cancelled`].
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{BufReader, Read};
+///
+/// use alpm_compress::{
+///     decompression::{CompressionDecoder, DecompressionSettings},
+///     progress::ProgressReader,
+/// };
+///
+/// # fn main() -> testresult::TestResult {
+/// let mut total = 0;
+/// let reader =
+///     ProgressReader::new(BufReader::new(&b"alpm4ever"[..])).with_callback(|bytes| total = bytes);
+/// let mut decoder = CompressionDecoder::from_reader(reader, DecompressionSettings::None)?;
+///
+/// let mut output = String::new();
+/// decoder.read_to_string(&mut output)?;
+/// drop(decoder);
+/// assert_eq!(total, 9);
+/// # Ok(())
+/// # }
+/// ```
+pub struct ProgressReader<'a, R> {
+    inner: R,
+    bytes: u64,
+    callback: Option<Box<dyn FnMut(u64) + 'a>>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl<R> Debug for ProgressReader<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressReader")
+            .field("bytes", &self.bytes)
+            .field("cancellation", &self.cancellation)
+            .finish()
+    }
+}
+
+impl<'a, R> ProgressReader<'a, R> {
+    /// Creates a new [`ProgressReader`] that reads from `inner`.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            bytes: 0,
+            callback: None,
+            cancellation: None,
+        }
+    }
+
+    /// Sets the `callback` that is called with the total amount of bytes read after each read.
+    pub fn with_callback(mut self, callback: impl FnMut(u64) + 'a) -> Self {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the [`CancellationToken`] that is checked before each read.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Returns the total amount of bytes read so far.
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Returns the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Checks for cancellation.
+    fn check(&self) -> std::io::Result<()> {
+        match &self.cancellation {
+            Some(token) => token.check_io(),
+            None => Ok(()),
+        }
+    }
+
+    /// Adds `amount` to the total amount of bytes read and reports it.
+    fn advance(&mut self, amount: usize) {
+        self.bytes += amount as u64;
+        if let Some(callback) = self.callback.as_mut() {
+            callback(self.bytes);
+        }
+    }
+}
+
+impl<R: Read> Read for ProgressReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.check()?;
+        let amount = self.inner.read(buf)?;
+        self.advance(amount);
+        Ok(amount)
+    }
+}
+
+impl<R: BufRead> BufRead for ProgressReader<'_, R> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.check()?;
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.inner.consume(amount);
+        self.advance(amount);
+    }
+}
+
+/// A writer that reports the amount of bytes written and supports cancellation.
+///
+/// Wraps a [`Write`] implementation (e.g. a [`CompressionEncoder`]).
+/// After each write, the callback set using [`ProgressWriter::with_callback`] is called with the
+/// total amount of bytes written so far.
+/// If a [`CancellationToken`] is set using [`ProgressWriter::with_cancellation`] and has been
+/// cancelled, writing fails with an I/O error that wraps [`Error::Cancelled`].
+///
+/// [`CompressionEncoder`]: crate::compression::CompressionEncoder
+pub struct ProgressWriter<'a, W> {
+    inner: W,
+    bytes: u64,
+    callback: Option<Box<dyn FnMut(u64) + 'a>>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl<W> Debug for ProgressWriter<'_, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressWriter")
+            .field("bytes", &self.bytes)
+            .field("cancellation", &self.cancellation)
+            .finish()
+    }
+}
+
+impl<'a, W> ProgressWriter<'a, W> {
+    /// Creates a new [`ProgressWriter`] that writes to `inner`.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            bytes: 0,
+            callback: None,
+            cancellation: None,
+        }
+    }
+
+    /// Sets the `callback` that is called with the total amount of bytes written after each
+    /// write.
+    pub fn with_callback(mut self, callback: impl FnMut(u64) + 'a) -> Self {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the [`CancellationToken`] that is checked before each write.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Returns the total amount of bytes written so far.
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Returns the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for ProgressWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if let Some(token) = &self.cancellation {
+            token.check_io()?;
+        }
+        let amount = self.inner.write(buf)?;
+        self.bytes += amount as u64;
+        if let Some(callback) = self.callback.as_mut() {
+            callback(self.bytes);
+        }
+        Ok(amount)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, Read, Write};
+
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that reading fails once the cancellation token is cancelled.
+    #[test]
+    fn progress_reader_cancellation() -> TestResult {
+        let token = CancellationToken::new();
+        let mut reader =
+            ProgressReader::new(BufReader::new(&b"alpm4ever"[..])).with_cancellation(token.clone());
+
+        let mut buffer = [0; 4];
+        reader.read_exact(&mut buffer)?;
+        assert_eq!(reader.bytes_read(), 4);
+
+        token.cancel();
+        let Err(error) = reader.read(&mut buffer) else {
+            panic!("Expected reading to fail after cancellation");
+        };
+        assert!(
+            error.get_ref().is_some_and(|error| matches!(
+                error.downcast_ref::<Error>(),
+                Some(Error::Cancelled)
+            ))
+        );
+        Ok(())
+    }
+
+    /// Ensures that the callback receives the total amount of written bytes.
+    #[test]
+    fn progress_writer_callback() -> TestResult {
+        let mut reported = Vec::new();
+        {
+            let mut writer =
+                ProgressWriter::new(Vec::new()).with_callback(|bytes| reported.push(bytes));
+            writer.write_all(b"alpm")?;
+            writer.write_all(b"4ever")?;
+        }
+
+        assert_eq!(reported, vec![4, 9]);
+        Ok(())
+    }
+}
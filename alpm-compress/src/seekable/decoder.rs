@@ -0,0 +1,238 @@
+//! Decoder for seekable zstd compression.
+
+use std::{
+    fmt::{self, Debug},
+    io::{Read, Seek, SeekFrom},
+};
+
+use crate::{Error, seekable::SeekTable};
+
+/// Decoder for seekable zstd compression.
+///
+/// Provides [`Read`] and [`Seek`] implementations on the decompressed data.
+/// Only the zstd frames that contain the requested data are read and decompressed, based on the
+/// [`SeekTable`] at the end of the compressed data.
+///
+/// The most recently decompressed frame is cached, so that sequential reads do not decompress a
+/// frame more than once.
+pub struct SeekableZstdDecoder<R: Read + Seek> {
+    inner: R,
+    table: SeekTable,
+    position: u64,
+    frame: Option<(usize, Vec<u8>)>,
+}
+
+impl<R: Read + Seek> Debug for SeekableZstdDecoder<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SeekableZstdDecoder")
+            .field("table", &self.table)
+            .field("position", &self.position)
+            .finish()
+    }
+}
+
+impl<R: Read + Seek> SeekableZstdDecoder<R> {
+    /// Creates a new [`SeekableZstdDecoder`] that reads from `inner`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no valid [`SeekTable`] can be read from the end of `inner`.
+    pub fn new(mut inner: R) -> Result<Self, Error> {
+        let table = SeekTable::read_from(&mut inner)?;
+        Ok(Self {
+            inner,
+            table,
+            position: 0,
+            frame: None,
+        })
+    }
+
+    /// Returns the [`SeekTable`] of the compressed data.
+    pub fn seek_table(&self) -> &SeekTable {
+        &self.table
+    }
+
+    /// Returns the decompressed data of the frame at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the compressed frame cannot be read from the inner reader,
+    /// - the frame cannot be decompressed,
+    /// - or the decompressed size of the frame does not match its entry in the [`SeekTable`].
+    fn frame(&mut self, index: usize) -> std::io::Result<&[u8]> {
+        if self
+            .frame
+            .as_ref()
+            .is_none_or(|(cached, _)| *cached != index)
+        {
+            let entry = self.table.entries()[index];
+            let mut compressed = vec![0u8; entry.compressed_size as usize];
+            self.inner.seek(SeekFrom::Start(entry.compressed_offset))?;
+            self.inner.read_exact(&mut compressed)?;
+            let data = zstd::bulk::decompress(&compressed, entry.decompressed_size as usize)
+                .map_err(|source| std::io::Error::new(std::io::ErrorKind::InvalidData, source))?;
+            if data.len() != entry.decompressed_size as usize {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!(
+                        "frame {index} decompresses to {} bytes, but the seek table expects {}",
+                        data.len(),
+                        entry.decompressed_size
+                    ),
+                ));
+            }
+            self.frame = Some((index, data));
+        }
+
+        Ok(self
+            .frame
+            .as_ref()
+            .map(|(_, data)| data.as_slice())
+            .unwrap_or_default())
+    }
+}
+
+impl<R: Read + Seek> Read for SeekableZstdDecoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let Some(index) = self.table.frame_index(self.position) else {
+            return Ok(0);
+        };
+        let frame_offset = self.table.entries()[index].decompressed_offset;
+        let start = (self.position - frame_offset) as usize;
+
+        let data = self.frame(index)?;
+        let len = buf.len().min(data.len().saturating_sub(start));
+        buf[..len].copy_from_slice(&data[start..start + len]);
+
+        self.position += len as u64;
+        Ok(len)
+    }
+}
+
+impl<R: Read + Seek> Seek for SeekableZstdDecoder<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.table.decompressed_size().checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        let Some(position) = position else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            ));
+        };
+
+        self.position = position;
+        Ok(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::{
+        compression::{CompressionSettings, ZstdCompressionLevel},
+        seekable::{SeekableZstdEncoder, ZstdSeekableFrameSize},
+    };
+
+    /// Creates seekable zstd compressed data from `data` using frames of `frame_size`.
+    fn compress(data: &[u8], frame_size: u32) -> TestResult<Vec<u8>> {
+        let level = ZstdCompressionLevel::default();
+        let frame_size = ZstdSeekableFrameSize::new(frame_size);
+        let settings = CompressionSettings::ZstdSeekable {
+            compression_level: level.clone(),
+            frame_size: frame_size.clone(),
+        };
+        let mut encoder = SeekableZstdEncoder::new(Vec::new(), &level, &frame_size, &settings)?;
+        encoder.write_all(data)?;
+        Ok(encoder.finish()?)
+    }
+
+    /// Ensures that reading a frame, that does not match its seek table entry, fails.
+    #[test]
+    fn seekable_frame_size_mismatch() -> TestResult {
+        let data: Vec<u8> = (0..10_000u32).flat_map(u32::to_le_bytes).collect();
+        let mut compressed = compress(&data, 4096)?;
+
+        // Increase the decompressed size of the first of the ten seek table entries.
+        let entry = compressed.len() - 9 - 10 * 8;
+        compressed[entry + 4..entry + 8].copy_from_slice(&4100u32.to_le_bytes());
+
+        let mut decoder = SeekableZstdDecoder::new(Cursor::new(compressed))?;
+        let mut buf = [0u8; 16];
+        let error = decoder
+            .read(&mut buf)
+            .expect_err("reading a short frame should fail");
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+        Ok(())
+    }
+
+    /// Ensures that reading a corrupted frame fails.
+    #[test]
+    fn seekable_corrupted_frame() -> TestResult {
+        let data: Vec<u8> = (0..10_000u32).flat_map(u32::to_le_bytes).collect();
+        let mut compressed = compress(&data, 4096)?;
+
+        // Overwrite the magic number of the first frame.
+        compressed[..4].fill(0);
+
+        let mut decoder = SeekableZstdDecoder::new(Cursor::new(compressed))?;
+        let mut buf = [0u8; 16];
+        let error = decoder
+            .read(&mut buf)
+            .expect_err("reading a corrupted frame should fail");
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+        Ok(())
+    }
+
+    /// Ensures that seekable data can be decompressed by a regular zstd decoder.
+    #[test]
+    fn seekable_compatible_with_zstd() -> TestResult {
+        let data: Vec<u8> = (0..10_000u32).flat_map(u32::to_le_bytes).collect();
+        let compressed = compress(&data, 4096)?;
+
+        assert_eq!(zstd::decode_all(compressed.as_slice())?, data);
+
+        Ok(())
+    }
+
+    /// Ensures that seeking only decompresses the requested data.
+    #[test]
+    fn seekable_random_access() -> TestResult {
+        let data: Vec<u8> = (0..10_000u32).flat_map(u32::to_le_bytes).collect();
+        let compressed = compress(&data, 4096)?;
+
+        let mut decoder = SeekableZstdDecoder::new(Cursor::new(compressed))?;
+        assert_eq!(decoder.seek_table().entries().len(), 10);
+        assert_eq!(decoder.seek_table().decompressed_size(), 40_000);
+
+        // Read across a frame boundary.
+        decoder.seek(SeekFrom::Start(4090))?;
+        let mut buf = [0u8; 12];
+        decoder.read_exact(&mut buf)?;
+        assert_eq!(buf, data[4090..4102]);
+
+        // Read from the end.
+        decoder.seek(SeekFrom::End(-4))?;
+        let mut buf = Vec::new();
+        decoder.read_to_end(&mut buf)?;
+        assert_eq!(buf, data[39_996..]);
+
+        // Read everything.
+        decoder.rewind()?;
+        let mut buf = Vec::new();
+        decoder.read_to_end(&mut buf)?;
+        assert_eq!(buf, data);
+
+        Ok(())
+    }
+}
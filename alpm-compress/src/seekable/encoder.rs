@@ -0,0 +1,180 @@
+//! Encoder for seekable zstd compression.
+
+use std::{
+    fmt::{self, Debug},
+    io::Write,
+};
+
+use fluent_i18n::t;
+use zstd::{bulk::Compressor, zstd_safe::CParameter};
+
+use crate::{
+    Error,
+    compression::{CompressionSettings, ZstdCompressionLevel},
+    seekable::SeekTable,
+};
+
+/// The uncompressed size of each frame when using seekable zstd compression.
+///
+/// Smaller frames allow for more fine-grained random access, while larger frames achieve better
+/// compression ratios.
+/// The default is 1 MiB.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ZstdSeekableFrameSize(pub(crate) u32);
+
+impl ZstdSeekableFrameSize {
+    /// Creates a new [`ZstdSeekableFrameSize`] from a [`u32`].
+    ///
+    /// A `frame_size` of `0` is raised to `1`.
+    pub fn new(frame_size: u32) -> Self {
+        Self(frame_size.max(1))
+    }
+
+    /// Returns the frame size in bytes.
+    pub fn get(&self) -> u32 {
+        self.0
+    }
+}
+
+impl Default for ZstdSeekableFrameSize {
+    /// Returns the default frame size of 1 MiB.
+    fn default() -> Self {
+        Self(1024 * 1024)
+    }
+}
+
+/// Encoder for seekable zstd compression.
+///
+/// Splits the written data into independent zstd frames of [`ZstdSeekableFrameSize`] and writes
+/// a [`SeekTable`] when finishing.
+/// Each frame includes a content checksum.
+pub struct SeekableZstdEncoder<W: Write> {
+    inner: W,
+    compressor: Compressor<'static>,
+    frame_size: usize,
+    buffer: Vec<u8>,
+    table: SeekTable,
+}
+
+impl<W: Write> Debug for SeekableZstdEncoder<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SeekableZstdEncoder")
+            .field("frame_size", &self.frame_size)
+            .field("table", &self.table)
+            .finish()
+    }
+}
+
+impl<W: Write> SeekableZstdEncoder<W> {
+    /// Creates a new [`SeekableZstdEncoder`] that writes to `inner`.
+    ///
+    /// The `settings` are merely used for additional context in cases of error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the zstd compressor cannot be created or configured.
+    pub fn new(
+        inner: W,
+        compression_level: &ZstdCompressionLevel,
+        frame_size: &ZstdSeekableFrameSize,
+        settings: &CompressionSettings,
+    ) -> Result<Self, Error> {
+        let mut compressor = Compressor::new(compression_level.into()).map_err(|source| {
+            Error::CreateZstandardEncoder {
+                context: t!("error-create-zstd-encoder-init"),
+                compression_settings: settings.clone(),
+                source,
+            }
+        })?;
+
+        // Include a content checksum at the end of each frame.
+        compressor
+            .set_parameter(CParameter::ChecksumFlag(true))
+            .map_err(|source| Error::CreateZstandardEncoder {
+                context: t!("error-create-zstd-encoder-set-checksum"),
+                compression_settings: settings.clone(),
+                source,
+            })?;
+
+        Ok(Self {
+            inner,
+            compressor,
+            frame_size: frame_size.get() as usize,
+            buffer: Vec::new(),
+            table: SeekTable::default(),
+        })
+    }
+
+    /// Returns a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns the [`SeekTable`] of all frames written so far.
+    pub fn seek_table(&self) -> &SeekTable {
+        &self.table
+    }
+
+    /// Compresses `data` into a single frame and writes it to the inner writer.
+    fn write_frame(&mut self, data: &[u8]) -> std::io::Result<()> {
+        let frame = self.compressor.compress(data)?;
+        let compressed_size = u32::try_from(frame.len()).map_err(std::io::Error::other)?;
+        let decompressed_size = u32::try_from(data.len()).map_err(std::io::Error::other)?;
+
+        self.inner.write_all(&frame)?;
+        self.table.push(compressed_size, decompressed_size);
+        Ok(())
+    }
+
+    /// Writes all full frames in the buffer.
+    fn write_full_frames(&mut self) -> std::io::Result<()> {
+        while self.buffer.len() >= self.frame_size {
+            let rest = self.buffer.split_off(self.frame_size);
+            let frame = std::mem::replace(&mut self.buffer, rest);
+            self.write_frame(&frame)?;
+        }
+        Ok(())
+    }
+
+    /// Finishes the compression stream.
+    ///
+    /// Writes the remaining buffered data as last frame, followed by the [`SeekTable`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing the last frame or the seek table fails.
+    pub fn finish(mut self) -> Result<W, Error> {
+        if !self.buffer.is_empty() {
+            let frame = std::mem::take(&mut self.buffer);
+            self.write_frame(&frame).map_err(|source| Error::IoWrite {
+                context: t!("error-io-write-seekable-frame"),
+                source,
+            })?;
+        }
+        self.table.write_to(&mut self.inner)?;
+        self.inner.flush().map_err(|source| Error::IoWrite {
+            context: t!("error-io-write-seek-table"),
+            source,
+        })?;
+
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for SeekableZstdEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        self.write_full_frames()?;
+        Ok(buf.len())
+    }
+
+    /// Flushes the inner writer.
+    ///
+    /// # Note
+    ///
+    /// Buffered data of an incomplete frame is not written, as this would create an additional
+    /// frame.
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,32 @@
+//! Seekable zstd compression.
+//!
+//! Implements the [Zstandard Seekable Format], which splits compressed data into independent
+//! zstd frames and appends a seek table in a skippable frame.
+//! Standard zstd decoders ignore the seek table and decompress the data as usual, while
+//! [`SeekableZstdDecoder`] uses it to only decompress the frames that contain the requested data.
+//!
+//! [Zstandard Seekable Format]: https://github.com/facebook/zstd/blob/dev/contrib/seekable_format/zstd_seekable_compression_format.md
+
+mod decoder;
+pub use decoder::SeekableZstdDecoder;
+
+mod encoder;
+pub use encoder::{SeekableZstdEncoder, ZstdSeekableFrameSize};
+
+mod table;
+pub use table::{SeekTable, SeekTableEntry};
+
+/// The magic number of the skippable frame that contains the seek table.
+const SKIPPABLE_MAGIC_NUMBER: u32 = 0x184D_2A5E;
+
+/// The magic number at the very end of the seek table.
+const SEEKABLE_MAGIC_NUMBER: u32 = 0x8F92_EAB1;
+
+/// The size of the header of a skippable frame (magic number and frame size).
+const SKIPPABLE_HEADER_SIZE: u64 = 8;
+
+/// The size of the seek table footer (number of frames, descriptor and magic number).
+const SEEK_TABLE_FOOTER_SIZE: u64 = 9;
+
+/// The bit in the seek table descriptor that indicates the presence of checksums.
+const CHECKSUM_FLAG: u8 = 1 << 7;
@@ -0,0 +1,233 @@
+//! The seek table of seekable zstd compressed data.
+
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use fluent_i18n::t;
+
+use crate::{
+    Error,
+    seekable::{
+        CHECKSUM_FLAG,
+        SEEK_TABLE_FOOTER_SIZE,
+        SEEKABLE_MAGIC_NUMBER,
+        SKIPPABLE_HEADER_SIZE,
+        SKIPPABLE_MAGIC_NUMBER,
+    },
+};
+
+/// An entry in a [`SeekTable`], describing a single zstd frame.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SeekTableEntry {
+    /// The offset of the frame in the compressed data.
+    pub compressed_offset: u64,
+    /// The size of the frame in the compressed data.
+    pub compressed_size: u32,
+    /// The offset of the frame's content in the decompressed data.
+    pub decompressed_offset: u64,
+    /// The size of the frame's content in the decompressed data.
+    pub decompressed_size: u32,
+}
+
+/// The seek table of seekable zstd compressed data.
+///
+/// Tracks the compressed and decompressed offsets and sizes of all zstd frames.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SeekTable {
+    entries: Vec<SeekTableEntry>,
+}
+
+impl SeekTable {
+    /// Adds a frame of `compressed_size` and `decompressed_size` to the end of the table.
+    pub(crate) fn push(&mut self, compressed_size: u32, decompressed_size: u32) {
+        let (compressed_offset, decompressed_offset) = self
+            .entries
+            .last()
+            .map(|last| {
+                (
+                    last.compressed_offset + u64::from(last.compressed_size),
+                    last.decompressed_offset + u64::from(last.decompressed_size),
+                )
+            })
+            .unwrap_or_default();
+
+        self.entries.push(SeekTableEntry {
+            compressed_offset,
+            compressed_size,
+            decompressed_offset,
+            decompressed_size,
+        });
+    }
+
+    /// Returns all entries of the table.
+    pub fn entries(&self) -> &[SeekTableEntry] {
+        &self.entries
+    }
+
+    /// Returns the total size of the decompressed data.
+    pub fn decompressed_size(&self) -> u64 {
+        self.entries
+            .last()
+            .map(|last| last.decompressed_offset + u64::from(last.decompressed_size))
+            .unwrap_or_default()
+    }
+
+    /// Returns the index of the frame that contains the decompressed `offset`.
+    ///
+    /// Returns [`None`] if `offset` is beyond the end of the decompressed data.
+    pub fn frame_index(&self, offset: u64) -> Option<usize> {
+        if offset >= self.decompressed_size() {
+            return None;
+        }
+        let index = self.entries.partition_point(|entry| {
+            entry.decompressed_offset + u64::from(entry.decompressed_size) <= offset
+        });
+        Some(index)
+    }
+
+    /// Writes the table as skippable frame to `writer`.
+    ///
+    /// Checksums are not written, as each frame already carries a zstd content checksum.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table is too large or writing to `writer` fails.
+    pub(crate) fn write_to(&self, mut writer: impl Write) -> Result<(), Error> {
+        let frames = u32::try_from(self.entries.len()).map_err(Error::IntegerConversion)?;
+        let frame_size = u32::try_from(self.entries.len() * 8 + SEEK_TABLE_FOOTER_SIZE as usize)
+            .map_err(Error::IntegerConversion)?;
+
+        let mut buffer = Vec::with_capacity(frame_size as usize + SKIPPABLE_HEADER_SIZE as usize);
+        buffer.extend_from_slice(&SKIPPABLE_MAGIC_NUMBER.to_le_bytes());
+        buffer.extend_from_slice(&frame_size.to_le_bytes());
+        for entry in &self.entries {
+            buffer.extend_from_slice(&entry.compressed_size.to_le_bytes());
+            buffer.extend_from_slice(&entry.decompressed_size.to_le_bytes());
+        }
+        buffer.extend_from_slice(&frames.to_le_bytes());
+        buffer.push(0);
+        buffer.extend_from_slice(&SEEKABLE_MAGIC_NUMBER.to_le_bytes());
+
+        writer.write_all(&buffer).map_err(|source| Error::IoWrite {
+            context: t!("error-io-write-seek-table"),
+            source,
+        })
+    }
+
+    /// Reads the table from the end of `reader`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - reading from `reader` fails,
+    /// - or the data does not end with a valid seek table.
+    pub fn read_from<R: Read + Seek>(reader: &mut R) -> Result<Self, Error> {
+        let io_error = |source| Error::IoRead {
+            context: t!("error-io-read-seek-table"),
+            source,
+        };
+        let invalid = |reason: String| Error::InvalidSeekTable { reason };
+
+        let total_size = reader.seek(SeekFrom::End(0)).map_err(io_error)?;
+        if total_size < SKIPPABLE_HEADER_SIZE + SEEK_TABLE_FOOTER_SIZE {
+            return Err(invalid(t!("error-invalid-seek-table-too-small")));
+        }
+
+        // Read the footer.
+        let mut footer = [0u8; SEEK_TABLE_FOOTER_SIZE as usize];
+        reader
+            .seek(SeekFrom::Start(total_size - SEEK_TABLE_FOOTER_SIZE))
+            .map_err(io_error)?;
+        reader.read_exact(&mut footer).map_err(io_error)?;
+        let [f0, f1, f2, f3, descriptor, m0, m1, m2, m3] = footer;
+        if u32::from_le_bytes([m0, m1, m2, m3]) != SEEKABLE_MAGIC_NUMBER {
+            return Err(invalid(t!("error-invalid-seek-table-magic")));
+        }
+        let frames = u64::from(u32::from_le_bytes([f0, f1, f2, f3]));
+        let entry_size: u64 = if descriptor & CHECKSUM_FLAG != 0 {
+            12
+        } else {
+            8
+        };
+
+        // Read the skippable frame header.
+        let table_size = frames * entry_size + SEEK_TABLE_FOOTER_SIZE;
+        let Some(table_start) = total_size.checked_sub(table_size + SKIPPABLE_HEADER_SIZE) else {
+            return Err(invalid(t!("error-invalid-seek-table-too-small")));
+        };
+        let mut header = [0u8; SKIPPABLE_HEADER_SIZE as usize];
+        reader
+            .seek(SeekFrom::Start(table_start))
+            .map_err(io_error)?;
+        reader.read_exact(&mut header).map_err(io_error)?;
+        let [h0, h1, h2, h3, s0, s1, s2, s3] = header;
+        if u32::from_le_bytes([h0, h1, h2, h3]) != SKIPPABLE_MAGIC_NUMBER
+            || u64::from(u32::from_le_bytes([s0, s1, s2, s3])) != table_size
+        {
+            return Err(invalid(t!("error-invalid-seek-table-header")));
+        }
+
+        // Read the entries.
+        let mut raw_entries = vec![0u8; (frames * entry_size) as usize];
+        reader.read_exact(&mut raw_entries).map_err(io_error)?;
+        let mut table = SeekTable::default();
+        for raw_entry in raw_entries.chunks_exact(entry_size as usize) {
+            let compressed_size =
+                u32::from_le_bytes([raw_entry[0], raw_entry[1], raw_entry[2], raw_entry[3]]);
+            let decompressed_size =
+                u32::from_le_bytes([raw_entry[4], raw_entry[5], raw_entry[6], raw_entry[7]]);
+            table.push(compressed_size, decompressed_size);
+        }
+
+        // The frames must exactly fill the space in front of the seek table.
+        let compressed_end = table
+            .entries
+            .last()
+            .map(|last| last.compressed_offset + u64::from(last.compressed_size))
+            .unwrap_or_default();
+        if compressed_end != table_start {
+            return Err(invalid(t!("error-invalid-seek-table-frame-sizes")));
+        }
+
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that a [`SeekTable`] can be written and read again.
+    #[test]
+    fn seek_table_roundtrip() -> TestResult {
+        let mut table = SeekTable::default();
+        table.push(3, 10);
+        table.push(4, 10);
+        table.push(2, 5);
+
+        // Prepend fake frame data, so that the frame sizes line up.
+        let mut data = vec![0u8; 9];
+        table.write_to(&mut data)?;
+        let read = SeekTable::read_from(&mut Cursor::new(data))?;
+
+        assert_eq!(read, table);
+        assert_eq!(read.decompressed_size(), 25);
+        assert_eq!(read.frame_index(0), Some(0));
+        assert_eq!(read.frame_index(10), Some(1));
+        assert_eq!(read.frame_index(24), Some(2));
+        assert_eq!(read.frame_index(25), None);
+
+        Ok(())
+    }
+
+    /// Ensures that data without a seek table is rejected.
+    #[test]
+    fn seek_table_missing() {
+        let result = SeekTable::read_from(&mut Cursor::new(vec![0u8; 64]));
+        assert!(matches!(result, Err(Error::InvalidSeekTable { .. })));
+    }
+}
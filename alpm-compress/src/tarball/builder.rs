@@ -1,6 +1,6 @@
 //! Creation of tarballs.
 
-use std::{fmt, fmt::Debug, fs::File};
+use std::{fmt, fmt::Debug, fs::File, path::Path, time::Instant};
 
 use fluent_i18n::t;
 use tar::Builder;
@@ -8,20 +8,48 @@ use tar::Builder;
 use crate::{
     Error,
     compression::{CompressionEncoder, CompressionSettings},
+    progress::CancellationToken,
+    tarball::{
+        TarPathEncoding,
+        path::append_path_with_pax,
+        statistics::{
+            ByteCounter,
+            CompressionStatistics,
+            EntryStatistics,
+            StatisticsRecorder,
+            compressed_position,
+        },
+    },
 };
 
 /// Wraps a [`Builder`] that writes to a [`CompressionEncoder`].
 ///
 /// As [`CompressionEncoder`] has an uncompressed variant, this can be used to create
 /// either compressed tarballs `.tar.*` or uncompressed tar archives `.tar`.
+///
+/// Optionally, [`EntryStatistics`] can be recorded for each entry appended using
+/// [`TarballBuilder::append_path_with_name`] (see [`TarballBuilder::with_statistics`]).
+///
+/// Paths and symlink targets that do not fit into a ustar header or contain non-ASCII characters
+/// are encoded using pax extended headers (see [`TarPathEncoding`]).
+///
+/// Progress can be reported using [`TarballBuilder::with_progress_callback`] and the creation can
+/// be aborted using a [`CancellationToken`] (see [`TarballBuilder::with_cancellation`]).
 pub struct TarballBuilder<'c> {
-    inner: Builder<CompressionEncoder<'c>>,
+    inner: Builder<ByteCounter<CompressionEncoder<'c>>>,
+    statistics: Option<StatisticsRecorder<'c>>,
+    follow_symlinks: bool,
+    progress: Option<Box<dyn FnMut(u64) + 'c>>,
+    cancellation: Option<CancellationToken>,
 }
 
 impl Debug for TarballBuilder<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("TarballBuilder")
-            .field("inner", &"Builder<CompressionEncoder>")
+            .field("inner", &"Builder<ByteCounter<CompressionEncoder>>")
+            .field("statistics", &self.statistics)
+            .field("follow_symlinks", &self.follow_symlinks)
+            .field("cancellation", &self.cancellation)
             .finish()
     }
 }
@@ -49,14 +77,173 @@ impl<'c> TarballBuilder<'c> {
     /// # use testresult::TestResult;
     /// # fn main() -> TestResult {
     /// # let mut builder = TarballBuilder::new(tempfile()?, &CompressionSettings::None)?;
-    /// builder.inner_mut().follow_symlinks(false);
+    /// builder.inner_mut().mode(tar::HeaderMode::Deterministic);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn inner_mut(&mut self) -> &mut Builder<CompressionEncoder<'c>> {
+    ///
+    /// # Note
+    ///
+    /// No [`EntryStatistics`] are recorded for entries appended directly to the inner [`Builder`].
+    /// Paths appended directly to the inner [`Builder`] are encoded using GNU long name extensions
+    /// instead of pax extended headers.
+    pub fn inner_mut(&mut self) -> &mut Builder<ByteCounter<CompressionEncoder<'c>>> {
         &mut self.inner
     }
 
+    /// Sets whether symlinks are followed when appending paths to the tarball.
+    ///
+    /// If `follow` is `false`, symlinks are added to the tarball as symlinks.
+    /// Defaults to `true`.
+    pub fn follow_symlinks(&mut self, follow: bool) {
+        self.follow_symlinks = follow;
+        self.inner.follow_symlinks(follow);
+    }
+
+    /// Enables the recording of [`EntryStatistics`].
+    ///
+    /// Statistics are recorded for each entry appended using
+    /// [`TarballBuilder::append_path_with_name`] and can be retrieved using
+    /// [`TarballBuilder::finish_with_statistics`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the current position in the output file cannot be retrieved (e.g.
+    /// because it is a pipe).
+    pub fn with_statistics(mut self) -> Result<Self, Error> {
+        if self.statistics.is_none() {
+            self.statistics = Some(StatisticsRecorder::new(
+                self.inner.get_ref().get_ref().get_ref(),
+            )?);
+        }
+        Ok(self)
+    }
+
+    /// Enables the recording of [`EntryStatistics`] and calls `callback` for each recorded entry.
+    ///
+    /// This allows reporting statistics while the tarball is still being created.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`TarballBuilder::with_statistics`] fails.
+    pub fn with_statistics_callback(
+        self,
+        callback: impl FnMut(&EntryStatistics) + 'c,
+    ) -> Result<Self, Error> {
+        let mut builder = self.with_statistics()?;
+        if let Some(statistics) = builder.statistics.as_mut() {
+            statistics.set_callback(callback);
+        }
+        Ok(builder)
+    }
+
+    /// Sets the `callback` that is called after each entry appended using
+    /// [`TarballBuilder::append_path_with_name`].
+    ///
+    /// The callback receives the total amount of bytes written to the uncompressed tar stream so
+    /// far (see [`TarballBuilder::bytes_written`]).
+    pub fn with_progress_callback(mut self, callback: impl FnMut(u64) + 'c) -> Self {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the [`CancellationToken`] that is checked before each entry is appended using
+    /// [`TarballBuilder::append_path_with_name`].
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Returns the total amount of bytes written to the uncompressed tar stream so far.
+    pub fn bytes_written(&self) -> u64 {
+        self.inner.get_ref().count()
+    }
+
+    /// Appends the file, directory or symlink at `path` to the tarball as `name`.
+    ///
+    /// If `name` or the target of a symlink require [`TarPathEncoding::Pax`], the entry is
+    /// preceded by a pax extended header that contains the full path or link target.
+    /// Otherwise, delegates to [`Builder::append_path_with_name`].
+    ///
+    /// Records [`EntryStatistics`] for the entry and reports progress, if enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the [`CancellationToken`] has been cancelled (the error wraps [`Error::Cancelled`]),
+    /// - the metadata or symlink target of `path` cannot be read,
+    /// - writing the entry to the tarball fails,
+    /// - or the current position in the output file cannot be retrieved.
+    pub fn append_path_with_name(
+        &mut self,
+        path: impl AsRef<Path>,
+        name: impl AsRef<Path>,
+    ) -> std::io::Result<()> {
+        if let Some(token) = &self.cancellation {
+            token.check_io()?;
+        }
+
+        self.append_path_with_statistics(path.as_ref(), name.as_ref())?;
+
+        if let Some(progress) = self.progress.as_mut() {
+            progress(self.inner.get_ref().count());
+        }
+
+        Ok(())
+    }
+
+    /// Appends the file, directory or symlink at `path` to the tarball as `name`.
+    ///
+    /// Records [`EntryStatistics`] for the entry, if enabled.
+    fn append_path_with_statistics(&mut self, path: &Path, name: &Path) -> std::io::Result<()> {
+        let Some(statistics) = self.statistics.as_mut() else {
+            return Self::append_entry(&mut self.inner, path, name, self.follow_symlinks);
+        };
+
+        let start_time = Instant::now();
+        let uncompressed_start = self.inner.get_ref().count();
+        let compressed_start = compressed_position(self.inner.get_ref().get_ref().get_ref())
+            .map_err(std::io::Error::other)?;
+
+        Self::append_entry(&mut self.inner, path, name, self.follow_symlinks)?;
+
+        let compressed_end = compressed_position(self.inner.get_ref().get_ref().get_ref())
+            .map_err(std::io::Error::other)?;
+        statistics.record(EntryStatistics {
+            path: name.to_path_buf(),
+            uncompressed_size: self.inner.get_ref().count() - uncompressed_start,
+            compressed_size: compressed_end.saturating_sub(compressed_start),
+            duration: start_time.elapsed(),
+        });
+
+        Ok(())
+    }
+
+    /// Appends the file, directory or symlink at `path` to `inner` as `name`.
+    ///
+    /// Uses pax extended headers if `name` or the target of a symlink require
+    /// [`TarPathEncoding::Pax`].
+    fn append_entry(
+        inner: &mut Builder<ByteCounter<CompressionEncoder<'c>>>,
+        path: &Path,
+        name: &Path,
+        follow_symlinks: bool,
+    ) -> std::io::Result<()> {
+        let link_encoding = if !follow_symlinks && path.is_symlink() {
+            TarPathEncoding::for_link_target(&std::fs::read_link(path)?)
+        } else {
+            TarPathEncoding::Ustar
+        };
+
+        match (TarPathEncoding::for_path(name), link_encoding) {
+            (TarPathEncoding::Ustar, TarPathEncoding::Ustar) => {
+                inner.append_path_with_name(path, name)
+            }
+            _ => append_path_with_pax(inner, path, name, follow_symlinks),
+        }
+    }
+
     /// Finishes writing the tarball.
     ///
     /// Delegates to [`CompressionEncoder::finish`] of the inner [`Builder`].
@@ -65,31 +252,58 @@ impl<'c> TarballBuilder<'c> {
     ///
     /// Returns an error if the [`CompressionEncoder`] fails to finish the compression stream.
     pub fn finish(self) -> Result<(), Error> {
-        self.inner
-            .into_inner()
-            .map_err(|source| Error::IoWrite {
-                context: t!("error-io-write-archive"),
-                source,
-            })?
-            .finish()?;
+        self.finish_with_statistics()?;
         Ok(())
     }
+
+    /// Finishes writing the tarball and returns the [`CompressionStatistics`].
+    ///
+    /// If the recording of statistics has not been enabled using
+    /// [`TarballBuilder::with_statistics`], the returned [`CompressionStatistics`] are empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the [`CompressionEncoder`] fails to finish the compression stream,
+    /// - or the current position in the output file cannot be retrieved.
+    pub fn finish_with_statistics(self) -> Result<CompressionStatistics, Error> {
+        let counter = self.inner.into_inner().map_err(|source| Error::IoWrite {
+            context: t!("error-io-write-archive"),
+            source,
+        })?;
+        let uncompressed_size = counter.count();
+        let file = counter.into_inner().finish()?;
+
+        match self.statistics {
+            Some(statistics) => statistics.finish(&file, uncompressed_size),
+            None => Ok(CompressionStatistics::default()),
+        }
+    }
 }
 
 impl<'c> From<CompressionEncoder<'c>> for TarballBuilder<'c> {
     /// Creates a [`TarballBuilder`] from a [`CompressionEncoder`].
     fn from(encoder: CompressionEncoder<'c>) -> Self {
         Self {
-            inner: Builder::new(encoder),
+            inner: Builder::new(ByteCounter::new(encoder)),
+            statistics: None,
+            follow_symlinks: true,
+            progress: None,
+            cancellation: None,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::io::Write;
+    use std::{
+        io::{Read, Write},
+        path::PathBuf,
+    };
 
     use rstest::rstest;
+    use tar::{EntryType, Header};
     use tempfile::{NamedTempFile, tempfile};
     use testresult::TestResult;
 
@@ -140,4 +354,174 @@ mod tests {
         assert!(dbg.contains("TarballBuilder"));
         Ok(())
     }
+
+    /// Ensures that statistics are recorded for each appended entry.
+    #[rstest]
+    #[case::gzip(CompressionSettings::Gzip { compression_level: GzipCompressionLevel::default() })]
+    #[case::zstd(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(1) })]
+    #[case::no_compression(CompressionSettings::None)]
+    fn test_tarball_builder_statistics(
+        #[case] compression_settings: CompressionSettings,
+    ) -> TestResult {
+        let compressible = NamedTempFile::new()?;
+        compressible.reopen()?.write_all(&[0u8; 65536])?;
+        let incompressible = NamedTempFile::new()?;
+        let data: Vec<u8> = (0..65536u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect();
+        incompressible.reopen()?.write_all(&data)?;
+
+        let archive = NamedTempFile::new()?;
+        let mut recorded = Vec::new();
+        let mut builder = TarballBuilder::new(archive.reopen()?, &compression_settings)?
+            .with_statistics_callback(|entry| recorded.push(entry.path.clone()))?;
+        builder.append_path_with_name(compressible.path(), "compressible")?;
+        builder.append_path_with_name(incompressible.path(), "incompressible")?;
+        let statistics = builder.finish_with_statistics()?;
+
+        assert_eq!(
+            recorded,
+            [
+                PathBuf::from("compressible"),
+                PathBuf::from("incompressible")
+            ]
+        );
+        assert_eq!(statistics.entries.len(), 2);
+        for entry in &statistics.entries {
+            // The content and a single tar header.
+            assert_eq!(entry.uncompressed_size, 65536 + 512);
+        }
+        // The entries and two zero-filled end-of-archive blocks.
+        assert_eq!(statistics.uncompressed_size, 2 * (65536 + 512) + 1024);
+        assert_eq!(
+            statistics.compressed_size,
+            archive.as_file().metadata()?.len()
+        );
+        if compression_settings == CompressionSettings::None {
+            assert_eq!(statistics.compressed_size, statistics.uncompressed_size);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the entry types of all headers in the uncompressed tar `data`.
+    ///
+    /// Unlike when reading entries using [`tar::Archive`], pax extended headers are included.
+    fn raw_entry_types(data: &[u8]) -> TestResult<Vec<EntryType>> {
+        let mut entry_types = Vec::new();
+        let mut offset = 0;
+        while let Some(block) = data.get(offset..offset + 512) {
+            // The archive ends with zero-filled blocks.
+            if block.iter().all(|byte| *byte == 0) {
+                break;
+            }
+            let header = Header::from_byte_slice(block);
+            entry_types.push(header.entry_type());
+            offset += 512 + usize::try_from(header.entry_size()?.div_ceil(512) * 512)?;
+        }
+
+        Ok(entry_types)
+    }
+
+    /// Ensures that long and non-ASCII paths and symlink targets survive a round-trip.
+    #[rstest]
+    #[case::gzip(CompressionSettings::Gzip { compression_level: GzipCompressionLevel::default() })]
+    #[case::zstd(CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::new(1) })]
+    #[case::no_compression(CompressionSettings::None)]
+    fn test_tarball_builder_pax_round_trip(
+        #[case] compression_settings: CompressionSettings,
+    ) -> TestResult {
+        let input_dir = tempfile::tempdir()?;
+        let file = input_dir.path().join("file");
+        std::fs::write(&file, b"alpm4ever")?;
+        let long_target = format!("{}/file", "t".repeat(120));
+        let symlink = input_dir.path().join("symlink");
+        std::os::unix::fs::symlink(&long_target, &symlink)?;
+
+        let short_name = "usr/bin/foo".to_string();
+        let long_name = format!("usr/share/{}/{}", "a".repeat(160), "b".repeat(120));
+        let utf8_name = "usr/share/doc/f\u{f6}\u{f6} b\u{e4}r/\u{1f980}.txt".to_string();
+        let symlink_name = "usr/lib/symlink".to_string();
+
+        let archive = NamedTempFile::new()?;
+        let mut builder = TarballBuilder::new(archive.reopen()?, &compression_settings)?;
+        builder.follow_symlinks(false);
+        for name in [&short_name, &long_name, &utf8_name] {
+            builder.append_path_with_name(&file, name)?;
+        }
+        builder.append_path_with_name(&symlink, &symlink_name)?;
+        builder.finish()?;
+
+        let settings = crate::decompression::DecompressionSettings::from(&compression_settings);
+        let mut data = Vec::new();
+        crate::decompression::CompressionDecoder::new(archive.reopen()?, settings.clone())?
+            .read_to_end(&mut data)?;
+        // Whether a pax extended header precedes each (non pax extended header) entry.
+        let mut has_pax = Vec::new();
+        let mut previous = None;
+        for entry_type in raw_entry_types(&data)? {
+            if entry_type != EntryType::XHeader {
+                has_pax.push(previous == Some(EntryType::XHeader));
+            }
+            previous = Some(entry_type);
+        }
+
+        let decoder = crate::decompression::CompressionDecoder::new(archive.reopen()?, settings)?;
+        let mut reader = crate::tarball::TarballReader::new(decoder);
+        let mut paths = Vec::new();
+        for (entry, has_pax) in reader.entries()?.zip(has_pax) {
+            let mut entry = entry?;
+            let path = entry.path().to_string_lossy().to_string();
+            if entry.is_symlink() {
+                let target = entry.raw().link_name()?.map(|target| target.to_path_buf());
+                assert_eq!(target, Some(long_target.clone().into()));
+            } else {
+                assert_eq!(entry.content()?, b"alpm4ever");
+            }
+            paths.push((path, has_pax));
+        }
+
+        assert_eq!(
+            paths,
+            [
+                (short_name, false),
+                (long_name, true),
+                (utf8_name, true),
+                (symlink_name, true),
+            ]
+        );
+
+        Ok(())
+    }
+
+    /// Ensures that progress is reported and that appending fails after cancellation.
+    #[test]
+    fn test_tarball_builder_progress_and_cancellation() -> TestResult {
+        let test_file = NamedTempFile::new()?;
+        test_file.reopen()?.write_all(b"alpm4ever")?;
+        let token = CancellationToken::new();
+        let mut progress = Vec::new();
+
+        {
+            let mut builder = TarballBuilder::new(tempfile()?, &CompressionSettings::None)?
+                .with_progress_callback(|bytes| progress.push(bytes))
+                .with_cancellation(token.clone());
+            builder.append_path_with_name(test_file.path(), "first")?;
+            assert_eq!(builder.bytes_written(), 1024);
+
+            token.cancel();
+            let Err(error) = builder.append_path_with_name(test_file.path(), "second") else {
+                panic!("Expected appending to fail after cancellation");
+            };
+            assert!(
+                error
+                    .get_ref()
+                    .and_then(|error| error.downcast_ref::<Error>())
+                    .is_some_and(|error| matches!(error, Error::Cancelled))
+            );
+        }
+
+        assert_eq!(progress, vec![1024]);
+        Ok(())
+    }
 }
@@ -1,7 +1,13 @@
 //! Reading and writing optionally compressed tarballs.
 
 mod builder;
+mod path;
 mod reader;
+mod seekable;
+mod statistics;
 
 pub use builder::TarballBuilder;
+pub use path::TarPathEncoding;
 pub use reader::{TarballEntries, TarballEntry, TarballReader};
+pub use seekable::SeekableTarballReader;
+pub use statistics::{ByteCounter, CompressionStatistics, EntryStatistics};
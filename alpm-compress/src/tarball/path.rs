@@ -0,0 +1,230 @@
+//! Encoding of paths in tarballs.
+
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::Path,
+};
+
+use tar::{Builder, EntryType, Header, HeaderMode};
+
+/// The maximum length of the name field of a ustar header.
+const USTAR_NAME_LENGTH: usize = 100;
+
+/// The maximum length of the prefix field of a ustar header.
+const USTAR_PREFIX_LENGTH: usize = 155;
+
+/// The maximum length of the link name field of a ustar header.
+const USTAR_LINK_NAME_LENGTH: usize = 100;
+
+/// The encoding used for a path in a tarball.
+///
+/// Paths that consist of ASCII characters and fit into the fields of a ustar header are encoded
+/// directly in the header.
+/// All other paths (i.e. paths that are too long, contain non-ASCII characters or are not valid
+/// UTF-8) are encoded using [pax] extended headers, which are understood by all common tar
+/// implementations.
+///
+/// [pax]: https://pubs.opengroup.org/onlinepubs/9799919799/utilities/pax.html#tag_20_94_13_03
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TarPathEncoding {
+    /// The path is encoded in the fields of a ustar header.
+    Ustar,
+    /// The path is encoded in a pax extended header.
+    Pax,
+}
+
+impl TarPathEncoding {
+    /// Returns the [`TarPathEncoding`] used for the path of an entry in a tarball.
+    ///
+    /// A path is encoded in a ustar header if it consists of ASCII characters only and either has
+    /// at most 100 bytes, or can be split at a `/` into a prefix of at most 155 bytes and a name of
+    /// at most 100 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use alpm_compress::tarball::TarPathEncoding;
+    ///
+    /// assert_eq!(
+    ///     TarPathEncoding::for_path(Path::new("usr/bin/foo")),
+    ///     TarPathEncoding::Ustar
+    /// );
+    /// assert_eq!(
+    ///     TarPathEncoding::for_path(Path::new("usr/share/doc/föö")),
+    ///     TarPathEncoding::Pax
+    /// );
+    /// assert_eq!(
+    ///     TarPathEncoding::for_path(&Path::new("usr").join("a".repeat(101))),
+    ///     TarPathEncoding::Pax
+    /// );
+    /// ```
+    pub fn for_path(path: &Path) -> Self {
+        let bytes = path.as_os_str().as_encoded_bytes();
+        if !bytes.is_ascii() {
+            return Self::Pax;
+        }
+        if bytes.len() <= USTAR_NAME_LENGTH {
+            return Self::Ustar;
+        }
+
+        // Find a `/` at which the path can be split into prefix and name.
+        let splittable = bytes.iter().enumerate().any(|(index, byte)| {
+            *byte == b'/'
+                && index <= USTAR_PREFIX_LENGTH
+                && bytes.len() - index - 1 <= USTAR_NAME_LENGTH
+                && index + 1 < bytes.len()
+        });
+        if splittable { Self::Ustar } else { Self::Pax }
+    }
+
+    /// Returns the [`TarPathEncoding`] used for the target of a symlink in a tarball.
+    ///
+    /// A link target is encoded in a ustar header if it consists of ASCII characters only and has
+    /// at most 100 bytes.
+    pub fn for_link_target(target: &Path) -> Self {
+        let bytes = target.as_os_str().as_encoded_bytes();
+        if bytes.is_ascii() && bytes.len() <= USTAR_LINK_NAME_LENGTH {
+            Self::Ustar
+        } else {
+            Self::Pax
+        }
+    }
+}
+
+/// Creates a single [pax] extended header record for `key` and `value`.
+///
+/// The record has the format `"{length} {key}={value}\n"`, where `length` is the length of the
+/// entire record (including `length` itself).
+///
+/// [pax]: https://pubs.opengroup.org/onlinepubs/9799919799/utilities/pax.html#tag_20_94_13_03
+fn pax_record(key: &str, value: &[u8]) -> Vec<u8> {
+    // The length of the record without the length field.
+    let base = key.len() + value.len() + 3;
+    let mut length = base + 1;
+    while base + length.to_string().len() != length {
+        length = base + length.to_string().len();
+    }
+
+    let mut record = format!("{length} {key}=").into_bytes();
+    record.extend_from_slice(value);
+    record.push(b'\n');
+    record
+}
+
+/// Copies as many leading bytes of `value` as fit into `field`.
+fn set_truncated(field: &mut [u8], value: &[u8]) {
+    let len = field.len().min(value.len());
+    field.fill(0);
+    field[..len].copy_from_slice(&value[..len]);
+}
+
+/// Appends the file, directory or symlink at `path` to `builder` as `name`, while encoding `name`
+/// and symlink targets in [pax] extended headers if necessary.
+///
+/// Uses [`HeaderMode::Complete`] and follows symlinks if `follow_symlinks` is `true`.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - the metadata of `path` cannot be retrieved,
+/// - `path` is neither a file, a directory nor a symlink,
+/// - the target of a symlink cannot be read,
+/// - or writing to `builder` fails.
+///
+/// [pax]: https://pubs.opengroup.org/onlinepubs/9799919799/utilities/pax.html#tag_20_94_13_03
+pub(crate) fn append_path_with_pax<W: Write>(
+    builder: &mut Builder<W>,
+    path: &Path,
+    name: &Path,
+    follow_symlinks: bool,
+) -> io::Result<()> {
+    let metadata = if follow_symlinks {
+        fs::metadata(path)?
+    } else {
+        fs::symlink_metadata(path)?
+    };
+    let file_type = metadata.file_type();
+    if !file_type.is_file() && !file_type.is_dir() && !file_type.is_symlink() {
+        return Err(io::Error::other(format!(
+            "{} has an unsupported file type",
+            path.display()
+        )));
+    }
+
+    let mut header = Header::new_ustar();
+    header.set_metadata_in_mode(&metadata, HeaderMode::Complete);
+
+    let mut records = Vec::new();
+    let name_bytes = name.as_os_str().as_encoded_bytes();
+    match TarPathEncoding::for_path(name) {
+        TarPathEncoding::Ustar => header.set_path(name)?,
+        TarPathEncoding::Pax => {
+            records.extend(pax_record("path", name_bytes));
+            set_truncated(&mut header.as_old_mut().name, name_bytes);
+        }
+    }
+    if file_type.is_symlink() {
+        let target = fs::read_link(path)?;
+        match TarPathEncoding::for_link_target(&target) {
+            TarPathEncoding::Ustar => header.set_link_name(&target)?,
+            TarPathEncoding::Pax => {
+                let target_bytes = target.as_os_str().as_encoded_bytes();
+                records.extend(pax_record("linkpath", target_bytes));
+                set_truncated(&mut header.as_old_mut().linkname, target_bytes);
+            }
+        }
+    }
+
+    if !records.is_empty() {
+        let mut pax_header = Header::new_ustar();
+        let pax_name = [b"PaxHeaders/".as_slice(), name_bytes].concat();
+        set_truncated(&mut pax_header.as_old_mut().name, &pax_name);
+        pax_header.set_entry_type(EntryType::XHeader);
+        pax_header.set_mode(0o644);
+        pax_header.set_mtime(header.mtime().unwrap_or_default());
+        pax_header.set_size(records.len() as u64);
+        pax_header.set_cksum();
+        builder.append(&pax_header, records.as_slice())?;
+    }
+
+    header.set_cksum();
+    if file_type.is_file() {
+        builder.append(&header, File::open(path)?)
+    } else {
+        builder.append(&header, io::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Ensures that the encoding of paths is detected correctly.
+    #[rstest]
+    #[case::short("usr/bin/foo", TarPathEncoding::Ustar)]
+    #[case::name_limit(&"a".repeat(100), TarPathEncoding::Ustar)]
+    #[case::name_too_long(&"a".repeat(101), TarPathEncoding::Pax)]
+    #[case::prefix_split(&format!("{}/{}", "a".repeat(155), "b".repeat(100)), TarPathEncoding::Ustar)]
+    #[case::prefix_too_long(&format!("{}/{}", "a".repeat(156), "b".repeat(100)), TarPathEncoding::Pax)]
+    #[case::utf8("usr/share/doc/f\u{f6}\u{f6}", TarPathEncoding::Pax)]
+    #[case::space("usr/share/doc/foo bar", TarPathEncoding::Ustar)]
+    fn tar_path_encoding(#[case] path: &str, #[case] expected: TarPathEncoding) {
+        assert_eq!(TarPathEncoding::for_path(Path::new(path)), expected);
+    }
+
+    /// Ensures that pax records carry their own length.
+    #[rstest]
+    #[case::short("path", b"foo", b"12 path=foo\n".to_vec())]
+    #[case::two_digit_length("path", &[b'a'; 90], [b"99 path=".as_slice(), &[b'a'; 90], b"\n"].concat())]
+    #[case::length_digit_overflow("path", &[b'a'; 91], [b"101 path=".as_slice(), &[b'a'; 91], b"\n"].concat())]
+    fn pax_record_length(#[case] key: &str, #[case] value: &[u8], #[case] expected: Vec<u8>) {
+        let record = pax_record(key, value);
+        assert_eq!(record, expected);
+    }
+}
@@ -14,6 +14,7 @@ use tar::{Archive, Entries, Entry, EntryType};
 use crate::{
     Error,
     decompression::{CompressionDecoder, DecompressionSettings},
+    progress::CancellationToken,
 };
 
 /// A generic tarball reader that can be used to read both compressed tarballs
@@ -22,17 +23,23 @@ use crate::{
 /// Can be created from a [`Path`] or [`PathBuf`],
 /// which will automatically detect the optional compression algorithm based on the file extension.
 ///
+/// Progress can be reported using [`TarballReader::with_progress_callback`] and reading can be
+/// aborted using a [`CancellationToken`] (see [`TarballReader::with_cancellation`]).
+///
 /// # Note
 ///
 /// The lifetime `'c` is for [`CompressionDecoder`] of the underlying [`Archive`].
 pub struct TarballReader<'c> {
     archive: Archive<CompressionDecoder<'c>>,
+    progress: Option<Box<dyn FnMut(u64) + 'c>>,
+    cancellation: Option<CancellationToken>,
 }
 
 impl Debug for TarballReader<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("TarballReader")
             .field("archive", &"Archive<CompressionDecoder>")
+            .field("cancellation", &self.cancellation)
             .finish()
     }
 }
@@ -42,11 +49,31 @@ impl<'c> TarballReader<'c> {
     pub fn new(decoder: CompressionDecoder<'c>) -> Self {
         Self {
             archive: Archive::new(decoder),
+            progress: None,
+            cancellation: None,
         }
     }
 
+    /// Sets the `callback` that is called for each entry returned by [`TarballReader::entries`].
+    ///
+    /// The callback receives the amount of bytes of the uncompressed tar stream up to the end of
+    /// the entry.
+    pub fn with_progress_callback(mut self, callback: impl FnMut(u64) + 'c) -> Self {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the [`CancellationToken`] that is checked before each entry is read using
+    /// [`TarballReader::entries`].
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
     /// Returns an iterator over the entries in the tarball.
     ///
+    /// Reports progress and checks for cancellation for each entry, if enabled.
+    ///
     /// # Errors
     ///
     /// Returns an error if [`Archive::entries`] fails.
@@ -55,7 +82,11 @@ impl<'c> TarballReader<'c> {
             context: t!("error-io-read-archive-entries"),
             source,
         })?;
-        Ok(raw_entries.into())
+        Ok(TarballEntries {
+            inner: raw_entries,
+            progress: self.progress.as_deref_mut(),
+            cancellation: self.cancellation.clone(),
+        })
     }
 
     /// Reads a specific [`TarballEntry`] by its path.
@@ -97,19 +128,7 @@ impl TryFrom<&Path> for TarballReader<'_> {
             context: t!("error-io-open-archive"),
             source,
         })?;
-        let settings = match DecompressionSettings::try_from(path) {
-            Ok(settings) => settings,
-            Err(err) => {
-                // Check if it's a plain .tar archive without compression.
-                if let Some(extension) = path.extension()
-                    && extension.eq_ignore_ascii_case("tar")
-                {
-                    DecompressionSettings::None
-                } else {
-                    return Err(err);
-                }
-            }
-        };
+        let settings = DecompressionSettings::from_archive_path(path)?;
         let decoder = CompressionDecoder::new(file, settings)?;
         Ok(Self::new(decoder))
     }
@@ -254,12 +273,15 @@ impl Read for TarballEntry<'_, '_> {
 /// - `'c` for the [`CompressionDecoder`]
 pub struct TarballEntries<'a, 'c> {
     inner: Entries<'a, CompressionDecoder<'c>>,
+    progress: Option<&'a mut (dyn FnMut(u64) + 'c)>,
+    cancellation: Option<CancellationToken>,
 }
 
 impl Debug for TarballEntries<'_, '_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("TarballEntries")
             .field("inner", &"Entries<CompressionDecoder>")
+            .field("cancellation", &self.cancellation)
             .finish()
     }
 }
@@ -268,6 +290,12 @@ impl<'a, 'c> Iterator for TarballEntries<'a, 'c> {
     type Item = Result<TarballEntry<'a, 'c>, Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(token) = &self.cancellation
+            && let Err(error) = token.check()
+        {
+            return Some(Err(error));
+        }
+
         self.inner.next().map(|entry| {
             let entry = entry.map_err(|source| Error::IoRead {
                 context: t!("error-io-read-archive-entry"),
@@ -282,6 +310,10 @@ impl<'a, 'c> Iterator for TarballEntries<'a, 'c> {
                 })?
                 .to_path_buf();
 
+            if let Some(progress) = self.progress.as_mut() {
+                progress(entry.raw_file_position() + entry.size());
+            }
+
             Ok(TarballEntry { path, entry })
         })
     }
@@ -289,7 +321,11 @@ impl<'a, 'c> Iterator for TarballEntries<'a, 'c> {
 
 impl<'a, 'c> From<Entries<'a, CompressionDecoder<'c>>> for TarballEntries<'a, 'c> {
     fn from(inner: Entries<'a, CompressionDecoder<'c>>) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            progress: None,
+            cancellation: None,
+        }
     }
 }
 
@@ -375,4 +411,36 @@ mod tests {
         assert_eq!(content, test_file_content);
         Ok(())
     }
+
+    /// Ensures that progress is reported for each entry and that reading entries fails after
+    /// cancellation.
+    #[rstest]
+    fn test_tarball_reader_progress_and_cancellation(
+        test_data: TestResult<(NamedTempFile, [u8; 9])>,
+    ) -> TestResult {
+        let (test_file, _) = test_data?;
+        let archive = NamedTempFile::with_suffix(".tar")?;
+        {
+            let mut builder = TarballBuilder::new(archive.reopen()?, &CompressionSettings::None)?;
+            builder.append_path_with_name(test_file.path(), "first")?;
+            builder.append_path_with_name(test_file.path(), "second")?;
+            builder.finish()?;
+        }
+
+        let token = CancellationToken::new();
+        let mut progress = Vec::new();
+        {
+            let mut reader = TarballReader::try_from(archive.path())?
+                .with_progress_callback(|bytes| progress.push(bytes))
+                .with_cancellation(token.clone());
+            let mut entries = reader.entries()?;
+            assert!(entries.next().is_some_and(|entry| entry.is_ok()));
+
+            token.cancel();
+            assert!(matches!(entries.next(), Some(Err(Error::Cancelled))));
+        }
+
+        assert_eq!(progress, vec![521]);
+        Ok(())
+    }
 }
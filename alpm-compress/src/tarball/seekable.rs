@@ -0,0 +1,187 @@
+//! Random access to seekable zstd compressed tarballs.
+
+use std::{
+    fs::File,
+    io::{BufReader, Read, Seek},
+    path::{Path, PathBuf},
+};
+
+use fluent_i18n::t;
+use tar::Archive;
+
+use crate::{Error, seekable::SeekableZstdDecoder};
+
+/// A reader for tarballs compressed using the [Zstandard Seekable Format].
+///
+/// Unlike [`TarballReader`](crate::tarball::TarballReader), this reader skips the content of
+/// entries by seeking, so that only the zstd frames containing the requested entry headers and
+/// contents are decompressed.
+/// This allows for retrieving a single entry from a large tarball without decompressing all
+/// data in front of it.
+///
+/// [Zstandard Seekable Format]: https://github.com/facebook/zstd/blob/dev/contrib/seekable_format/zstd_seekable_compression_format.md
+#[derive(Debug)]
+pub struct SeekableTarballReader<R: Read + Seek> {
+    decoder: SeekableZstdDecoder<R>,
+}
+
+impl<R: Read + Seek> SeekableTarballReader<R> {
+    /// Creates a new [`SeekableTarballReader`] from a [`SeekableZstdDecoder`].
+    pub fn new(decoder: SeekableZstdDecoder<R>) -> Self {
+        Self { decoder }
+    }
+
+    /// Returns an [`Archive`] that reads from the start of the decompressed data.
+    fn archive(&mut self) -> Result<Archive<&mut SeekableZstdDecoder<R>>, Error> {
+        self.decoder.rewind().map_err(|source| Error::IoRead {
+            context: t!("error-io-read-archive-entries"),
+            source,
+        })?;
+        Ok(Archive::new(&mut self.decoder))
+    }
+
+    /// Returns the paths of all entries in the tarball.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the entries or their paths fails.
+    pub fn entry_paths(&mut self) -> Result<Vec<PathBuf>, Error> {
+        let mut archive = self.archive()?;
+        let entries = archive
+            .entries_with_seek()
+            .map_err(|source| Error::IoRead {
+                context: t!("error-io-read-archive-entries"),
+                source,
+            })?;
+
+        let mut paths = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|source| Error::IoRead {
+                context: t!("error-io-read-archive-entry"),
+                source,
+            })?;
+            let path = entry.path().map_err(|source| Error::IoRead {
+                context: t!("error-io-read-archive-entry-path"),
+                source,
+            })?;
+            paths.push(path.to_path_buf());
+        }
+
+        Ok(paths)
+    }
+
+    /// Reads the content of the entry at `path`.
+    ///
+    /// Returns [`None`] if the entry does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the entries, their paths or the content of the entry fails.
+    pub fn read_entry_content<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        let mut archive = self.archive()?;
+        let entries = archive
+            .entries_with_seek()
+            .map_err(|source| Error::IoRead {
+                context: t!("error-io-read-archive-entries"),
+                source,
+            })?;
+
+        for entry in entries {
+            let mut entry = entry.map_err(|source| Error::IoRead {
+                context: t!("error-io-read-archive-entry"),
+                source,
+            })?;
+            let entry_path = entry.path().map_err(|source| Error::IoRead {
+                context: t!("error-io-read-archive-entry-path"),
+                source,
+            })?;
+            if entry_path != path.as_ref() {
+                continue;
+            }
+
+            let mut buffer = Vec::new();
+            entry
+                .read_to_end(&mut buffer)
+                .map_err(|source| Error::IoRead {
+                    context: t!("error-io-read-archive-entry-content"),
+                    source,
+                })?;
+            return Ok(Some(buffer));
+        }
+
+        Ok(None)
+    }
+}
+
+impl TryFrom<&Path> for SeekableTarballReader<BufReader<File>> {
+    type Error = Error;
+
+    /// Creates a new [`SeekableTarballReader`] from a [`Path`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the file at `path` cannot be opened for reading,
+    /// - or the file does not contain seekable zstd compressed data.
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        let file = File::open(path).map_err(|source| Error::IoRead {
+            context: t!("error-io-open-archive"),
+            source,
+        })?;
+        Ok(Self::new(SeekableZstdDecoder::new(BufReader::new(file))?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use tempfile::NamedTempFile;
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::{
+        compression::{CompressionSettings, ZstdCompressionLevel},
+        seekable::ZstdSeekableFrameSize,
+        tarball::TarballBuilder,
+    };
+
+    /// Ensures that single entries can be read from a seekable zstd compressed tarball.
+    #[test]
+    fn seekable_tarball_read_entry() -> TestResult {
+        let archive = NamedTempFile::with_suffix(".tar.zst")?;
+        {
+            let settings = CompressionSettings::ZstdSeekable {
+                compression_level: ZstdCompressionLevel::default(),
+                frame_size: ZstdSeekableFrameSize::new(1024),
+            };
+            let mut builder = TarballBuilder::new(archive.reopen()?, &settings)?;
+            for (name, size) in [("first", 10_000), ("second", 20_000), ("last", 9)] {
+                let file = NamedTempFile::new()?;
+                file.reopen()?.write_all(&vec![b'a'; size])?;
+                builder
+                    .inner_mut()
+                    .append_path_with_name(file.path(), name)?;
+            }
+            builder.finish()?;
+        }
+
+        let mut reader = SeekableTarballReader::try_from(archive.path())?;
+        assert_eq!(
+            reader.entry_paths()?,
+            vec![
+                PathBuf::from("first"),
+                PathBuf::from("second"),
+                PathBuf::from("last")
+            ]
+        );
+        assert_eq!(reader.read_entry_content("last")?, Some(vec![b'a'; 9]));
+        assert_eq!(reader.read_entry_content("missing")?, None);
+
+        Ok(())
+    }
+}
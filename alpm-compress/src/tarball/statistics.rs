@@ -0,0 +1,220 @@
+//! Statistics on the compression of tarball entries.
+
+use std::{
+    fmt::{self, Debug},
+    fs::File,
+    io::{Seek, Write},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use fluent_i18n::t;
+
+use crate::Error;
+
+/// Compression statistics of a single entry in a tarball.
+///
+/// # Note
+///
+/// Compression encoders buffer data internally, so compressed data is not necessarily written
+/// while the entry is appended.
+/// The `compressed_size` of an entry is the amount of compressed data written while appending
+/// the entry, which may include data of previous entries and may not include all data of the
+/// entry itself.
+/// For large entries this approximation is close to the actual compressed size of the entry.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntryStatistics {
+    /// The path of the entry in the tarball.
+    pub path: PathBuf,
+    /// The size of the entry in the uncompressed tar stream (including tar headers and padding).
+    pub uncompressed_size: u64,
+    /// The amount of compressed data written while appending the entry.
+    pub compressed_size: u64,
+    /// The time spent on appending and compressing the entry.
+    pub duration: Duration,
+}
+
+impl EntryStatistics {
+    /// Returns the compression ratio of the entry.
+    ///
+    /// The ratio is the `compressed_size` divided by the `uncompressed_size`, so smaller values
+    /// indicate better compression.
+    /// Returns `1.0` if the `uncompressed_size` is `0`.
+    pub fn ratio(&self) -> f64 {
+        if self.uncompressed_size == 0 {
+            return 1.0;
+        }
+        self.compressed_size as f64 / self.uncompressed_size as f64
+    }
+}
+
+/// Compression statistics of a tarball.
+///
+/// Created by
+/// [`TarballBuilder::finish_with_statistics`](crate::tarball::TarballBuilder::finish_with_statistics).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CompressionStatistics {
+    /// The statistics of each entry, in the order in which the entries were appended.
+    pub entries: Vec<EntryStatistics>,
+    /// The total size of the uncompressed tar stream.
+    pub uncompressed_size: u64,
+    /// The total size of the compressed tarball.
+    pub compressed_size: u64,
+    /// The total time spent on creating the tarball.
+    pub duration: Duration,
+}
+
+impl CompressionStatistics {
+    /// Returns the compression ratio of the entire tarball.
+    ///
+    /// The ratio is the `compressed_size` divided by the `uncompressed_size`, so smaller values
+    /// indicate better compression.
+    /// Returns `1.0` if the `uncompressed_size` is `0`.
+    pub fn ratio(&self) -> f64 {
+        if self.uncompressed_size == 0 {
+            return 1.0;
+        }
+        self.compressed_size as f64 / self.uncompressed_size as f64
+    }
+
+    /// Returns an iterator over all entries with a [`EntryStatistics::ratio`] of at least
+    /// `min_ratio`.
+    ///
+    /// This can be used to identify content that compresses poorly (e.g. already compressed
+    /// files).
+    pub fn poorly_compressing(&self, min_ratio: f64) -> impl Iterator<Item = &EntryStatistics> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.ratio() >= min_ratio)
+    }
+}
+
+/// A [`Write`] implementation that counts the bytes written to an inner writer.
+///
+/// Used by [`TarballBuilder`](crate::tarball::TarballBuilder) to track the size of the
+/// uncompressed tar stream.
+#[derive(Debug)]
+pub struct ByteCounter<W: Write> {
+    inner: W,
+    count: u64,
+}
+
+impl<W: Write> ByteCounter<W> {
+    /// Creates a new [`ByteCounter`] that writes to `inner`.
+    pub fn new(inner: W) -> Self {
+        Self { inner, count: 0 }
+    }
+
+    /// Returns the amount of bytes written so far.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for ByteCounter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.count += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A callback that is called with the [`EntryStatistics`] of each recorded entry.
+pub(crate) type EntryCallback<'c> = Box<dyn FnMut(&EntryStatistics) + 'c>;
+
+/// Records [`EntryStatistics`] while a tarball is created.
+pub(crate) struct StatisticsRecorder<'c> {
+    /// The statistics of all entries recorded so far.
+    entries: Vec<EntryStatistics>,
+    /// An optional callback that is called for each recorded entry.
+    callback: Option<EntryCallback<'c>>,
+    /// The position in the output file at which the tarball starts.
+    start_position: u64,
+    /// The point in time at which recording started.
+    start_time: Instant,
+}
+
+impl Debug for StatisticsRecorder<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StatisticsRecorder")
+            .field("entries", &self.entries)
+            .field("callback", &self.callback.as_ref().map(|_| "FnMut"))
+            .field("start_position", &self.start_position)
+            .field("start_time", &self.start_time)
+            .finish()
+    }
+}
+
+impl<'c> StatisticsRecorder<'c> {
+    /// Creates a new [`StatisticsRecorder`] for a tarball written to `file`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the current position in `file` cannot be retrieved.
+    pub(crate) fn new(file: &File) -> Result<Self, Error> {
+        Ok(Self {
+            entries: Vec::new(),
+            callback: None,
+            start_position: compressed_position(file)?,
+            start_time: Instant::now(),
+        })
+    }
+
+    /// Sets a `callback` that is called for each recorded entry.
+    pub(crate) fn set_callback(&mut self, callback: impl FnMut(&EntryStatistics) + 'c) {
+        self.callback = Some(Box::new(callback));
+    }
+
+    /// Records the [`EntryStatistics`] of an entry and calls the optional callback.
+    pub(crate) fn record(&mut self, entry: EntryStatistics) {
+        if let Some(callback) = self.callback.as_mut() {
+            callback(&entry);
+        }
+        self.entries.push(entry);
+    }
+
+    /// Creates the [`CompressionStatistics`] once the tarball is finished.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the current position in `file` cannot be retrieved.
+    pub(crate) fn finish(
+        self,
+        file: &File,
+        uncompressed_size: u64,
+    ) -> Result<CompressionStatistics, Error> {
+        Ok(CompressionStatistics {
+            entries: self.entries,
+            uncompressed_size,
+            compressed_size: compressed_position(file)?.saturating_sub(self.start_position),
+            duration: self.start_time.elapsed(),
+        })
+    }
+}
+
+/// Returns the current position in `file`.
+///
+/// # Errors
+///
+/// Returns an error if the current position in `file` cannot be retrieved (e.g. because `file`
+/// is a pipe).
+pub(crate) fn compressed_position(mut file: &File) -> Result<u64, Error> {
+    file.stream_position().map_err(|source| Error::IoRead {
+        context: t!("error-io-read-compressed-position"),
+        source,
+    })
+}
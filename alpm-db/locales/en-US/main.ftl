@@ -28,8 +28,12 @@ error-unsupported-schema-version = Unsupported schema version: { $version }
 
 error-invalid-format = Failed to parse v1 or v2 format.
 
+error-local-db-entry-mismatch = The local database entry "{ $path }" does not match the package it contains (expected directory name "{ $expected }")
+
 error-io-path-open-file = opening the file for reading
 
+error-io-path-read-local-db = reading the local database directory
+
 error-io-read-db-desc = reading DB desc data
 
 error-io-context-reading-alpm-db-files-data = reading alpm-db-files data
@@ -47,10 +51,6 @@ error-io-read-schema-data =
 
 error-io-path-output-dir = creating output directory
 
-error-io-path-output-file = creating output file
-
-error-io-path-write-file = writing to output file
-
 error-json-serialize-pretty = serializing to pretty JSON
 
 error-json-serialize = serializing to JSON
@@ -117,6 +117,31 @@ cli-output-long-help = A file path to write to.
   
   If no file is provided, stdout is used instead.
 
+cli-json-schema-about = Output the JSON Schema of the JSON output format of alpm-db-files data.
+
+cli-json-schema-long-about = Output the JSON Schema of the JSON output format of alpm-db-files data.
+  
+  The JSON Schema describes the data returned by the format command when using the JSON output
+  format and can be used by external tools to validate it.
+
+cli-diff-about = Compare alpm-db-files data with other alpm-db-files data or a directory.
+
+cli-diff-long-about = Compare alpm-db-files data with other alpm-db-files data or a directory.
+
+  The paths that are missing from, or extra in the compared file or directory are emitted as JSON
+  on stdout.
+  If there are differences, the program exits with a non-zero exit code.
+
+cli-diff-input-file-help = The alpm-db-files file listing the expected paths.
+
+cli-diff-other-help = An alpm-db-files file or a directory to compare with.
+
+cli-diff-other-long-help = An alpm-db-files file or a directory to compare with.
+
+  If a directory is provided (e.g. an extracted package), package metadata files in it are ignored.
+
+cli-error-json-serializing-alpm-db-files-diff-as-json-string = serializing the differences of alpm-db-files data as JSON string
+
 cli-validate-about = Validate an alpm-db-files file.
 
 cli-validate-long-about = Validate an alpm-db-files file.
@@ -134,10 +159,6 @@ cli-error-json = JSON error while { $context }:
 
 cli-error-stdin-is-terminal = Stdin is a terminal and cannot be piped to
 
-cli-error-io-path-opening-output-file-for-writing = opening output file for writing
-
-cli-error-io-writing-to-output-file = writing to output file
-
 cli-error-io-writing-to-stdout = writing to stdout
 
 cli-error-io-path-reading-file-to-string = reading file to string
@@ -147,3 +168,7 @@ cli-error-io-reading-stdin-to-string = reading stdin to string
 cli-error-json-serializing-alpm-db-files-data-as-pretty-printed-json-string = serializing alpm-db-files data as pretty printed JSON string
 
 cli-error-json-serializing-alpm-db-files-data-as-json-string = serializing alpm-db-files data as JSON string
+
+cli-error-json-serializing-json-schema-as-pretty-printed-json-string = serializing the JSON Schema as pretty printed JSON string
+
+cli-error-json-serializing-json-schema-as-json-string = serializing the JSON Schema as JSON string
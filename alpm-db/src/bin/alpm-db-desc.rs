@@ -2,6 +2,7 @@
 
 use std::process::ExitCode;
 
+use alpm_common::ErrorCategory;
 use alpm_db::desc::{
     cli::{Cli, Command},
     commands::{create_file, format, validate},
@@ -14,7 +15,8 @@ fluent_i18n::i18n!("locales");
 /// The main entrypoint for the `alpm-db-desc` executable.
 ///
 /// Returns an [`ExitCode::SUCCESS`] if the chosen command succeeded.
-/// Returns an [`ExitCode::FAILURE`] and prints an error on stderr if the chosen command failed.
+/// Returns an [`ExitCode`] based on the [`ErrorCategory`] of the error and prints the error on
+/// stderr if the chosen command failed.
 fn main() -> ExitCode {
     let cli = Cli::parse();
     let result = match cli.command {
@@ -29,7 +31,7 @@ fn main() -> ExitCode {
 
     if let Err(error) = result {
         eprintln!("{error}");
-        ExitCode::FAILURE
+        ErrorCategory::from(&error).into()
     } else {
         ExitCode::SUCCESS
     }
@@ -3,13 +3,14 @@
 //! [alpm-db-files]: https://alpm.archlinux.page/specifications/alpm-db-files.5.html
 
 use std::{
-    fs::{File, read_to_string},
+    fs::read_to_string,
     io::{IsTerminal, Read, Write, stdin, stdout},
     path::PathBuf,
     process::ExitCode,
     str::FromStr,
 };
 
+use alpm_common::{ErrorCategory, MetadataFile, write_atomic};
 use alpm_db::files::{
     DbFiles,
     DbFilesV1,
@@ -17,6 +18,7 @@ use alpm_db::files::{
 };
 use clap::Parser;
 use fluent_i18n::t;
+use schemars::generate::SchemaSettings;
 
 // Initialize i18n support.
 fluent_i18n::i18n!("locales");
@@ -44,6 +46,17 @@ enum Error {
     StdinIsTerminal,
 }
 
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::AlpmDbFiles(error) => error.into(),
+            Error::Json { .. } => Self::Parse,
+            Error::StdinIsTerminal => Self::Io,
+        }
+    }
+}
+
 /// Creates [`alpm-db-files`] data from a directory.
 ///
 /// Outputs data on [`stdout`] if no `output` is provided.
@@ -63,16 +76,7 @@ fn create_from_dir(input_dir: PathBuf, output: Option<PathBuf>) -> Result<(), Er
     let files = DbFiles::V1(DbFilesV1::try_from(input_dir)?);
 
     if let Some(output) = output {
-        let mut output_file =
-            File::create(&output).map_err(|source| alpm_db::files::Error::IoPath {
-                path: output.to_path_buf(),
-                context: t!("cli-error-io-path-opening-output-file-for-writing"),
-                source,
-            })?;
-        write!(output_file, "{files}").map_err(|source| alpm_db::files::Error::Io {
-            context: t!("cli-error-io-writing-to-output-file"),
-            source,
-        })?;
+        write_atomic(&output, files.to_string()).map_err(alpm_db::files::Error::from)?;
     } else {
         stdout()
             .write(&files.to_string().into_bytes())
@@ -156,16 +160,7 @@ fn format_output(
     };
 
     if let Some(output) = output {
-        let mut output_file =
-            File::create(&output).map_err(|source| alpm_db::files::Error::IoPath {
-                path: output.to_path_buf(),
-                context: t!("cli-opening-output-file-for-writing"),
-                source,
-            })?;
-        write!(output_file, "{data}").map_err(|source| alpm_db::files::Error::Io {
-            context: t!("cli-writing-to-output-file"),
-            source,
-        })?;
+        write_atomic(&output, data).map_err(alpm_db::files::Error::from)?;
     } else {
         write!(stdout(), "{data}").map_err(|source| alpm_db::files::Error::Io {
             context: t!("cli-writing-to-stdout"),
@@ -176,6 +171,51 @@ fn format_output(
     Ok(())
 }
 
+/// Compares [`alpm-db-files`] data with other [`alpm-db-files`] data or a directory.
+///
+/// The paths listed in `input_file` are compared with those listed in `other`, or with the contents
+/// of `other`, if it is a directory.
+/// The [`DbFilesDiff`](alpm_db::files::DbFilesDiff) is printed as JSON on [`stdout`].
+/// If `pretty` is `true`, the JSON output is pretty-printed.
+/// Returns `true` if there are no differences.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - no [`DbFiles`] can be created from `input_file` or `other`,
+/// - `other` is a directory and [`DbFiles::diff_dir`] fails,
+/// - the differences cannot be serialized as JSON,
+/// - or [`stdout`] cannot be written to.
+///
+/// [alpm-db-files]: https://alpm.archlinux.page/specifications/alpm-db-files.5.html
+fn diff(input_file: PathBuf, other: PathBuf, pretty: bool) -> Result<bool, Error> {
+    let files = DbFiles::from_file_with_schema(&input_file, None)?;
+    let diff = if other.is_dir() {
+        files.diff_dir(&other)?
+    } else {
+        files.diff(&DbFiles::from_file_with_schema(&other, None)?)
+    };
+
+    let mut output = if pretty {
+        serde_json::to_string_pretty(&diff)
+    } else {
+        serde_json::to_string(&diff)
+    }
+    .map_err(|source| Error::Json {
+        context: t!("cli-error-json-serializing-alpm-db-files-diff-as-json-string"),
+        source,
+    })?;
+    output.push('\n');
+
+    write!(stdout(), "{output}").map_err(|source| alpm_db::files::Error::Io {
+        context: t!("cli-error-io-writing-to-stdout"),
+        source,
+    })?;
+
+    Ok(diff.is_empty())
+}
+
 /// Validates [`alpm-db-files`] data.
 ///
 /// If no `input_file` is provided, data is read from [`stdin`].
@@ -216,32 +256,80 @@ fn validate_input(input_file: Option<PathBuf>) -> Result<(), Error> {
     Ok(())
 }
 
+/// Outputs the JSON Schema of the JSON representation of [`alpm-db-files`] data on [`stdout`].
+///
+/// The JSON Schema is generated from [`DbFiles`] and describes all of its versions.
+/// If `pretty` is `true`, the JSON Schema is pretty-printed.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - the JSON Schema cannot be serialized,
+/// - or [`stdout`] cannot be written to.
+///
+/// [alpm-db-files]: https://alpm.archlinux.page/specifications/alpm-db-files.5.html
+fn json_schema(pretty: bool) -> Result<(), Error> {
+    let schema = SchemaSettings::draft2020_12()
+        .for_serialize()
+        .into_generator()
+        .into_root_schema_for::<DbFiles>();
+    let mut output = if pretty {
+        serde_json::to_string_pretty(&schema).map_err(|source| Error::Json {
+            context: t!("cli-error-json-serializing-json-schema-as-pretty-printed-json-string"),
+            source,
+        })?
+    } else {
+        serde_json::to_string(&schema).map_err(|source| Error::Json {
+            context: t!("cli-error-json-serializing-json-schema-as-json-string"),
+            source,
+        })?
+    };
+    output.push('\n');
+
+    write!(stdout(), "{output}").map_err(|source| alpm_db::files::Error::Io {
+        context: t!("cli-error-io-writing-to-stdout"),
+        source,
+    })?;
+
+    Ok(())
+}
+
 /// Runs the `alpm-db-files` executable.
 ///
-/// Depending on [`Cli`], delegates to `alpm_db_create`, `alpm_db_format` or
-/// `alpm_db_validate`.
+/// Depending on [`Cli`], delegates to `create_from_dir`, `format_output`, `diff`,
+/// `validate_input` or `json_schema`.
 /// In case of success, exits with [`ExitCode::SUCCESS`].
+/// If `diff` finds differences, exits with [`ExitCode::FAILURE`].
 ///
-/// If an error occurs, the error message is emitted on stderr and the executable exits with
-/// [`ExitCode::FAILURE`].
+/// If an error occurs, the error message is emitted on stderr and the executable exits with an
+/// [`ExitCode`] based on the [`ErrorCategory`] of the error.
 fn main() -> ExitCode {
     let cli = Cli::parse();
 
     let result = match cli.command {
-        Command::Create { input_dir, output } => create_from_dir(input_dir, output),
+        Command::Create { input_dir, output } => create_from_dir(input_dir, output).map(|()| true),
         Command::Format {
             input_file,
             output,
             format,
             pretty,
-        } => format_output(input_file, output, format, pretty),
-        Command::Validate { input_file } => validate_input(input_file),
+        } => format_output(input_file, output, format, pretty).map(|()| true),
+        Command::Diff {
+            input_file,
+            other,
+            pretty,
+        } => diff(input_file, other, pretty),
+        Command::Validate { input_file } => validate_input(input_file).map(|()| true),
+        Command::JsonSchema { pretty } => json_schema(pretty).map(|()| true),
     };
 
-    if let Err(error) = result {
-        eprintln!("{error}");
-        ExitCode::FAILURE
-    } else {
-        ExitCode::SUCCESS
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("{error}");
+            ErrorCategory::from(&error).into()
+        }
     }
 }
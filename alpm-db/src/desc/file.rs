@@ -6,6 +6,7 @@ use std::{
 };
 
 use alpm_common::{FileFormatSchema, MetadataFile};
+use alpm_types::{FullVersion, Name, PackageInstallReason, RelationOrSoname};
 use fluent_i18n::t;
 
 use crate::{
@@ -361,3 +362,37 @@ impl FromStr for DbDescFile {
         Self::from_str_with_schema(s, None)
     }
 }
+
+impl DbDescFile {
+    /// Returns the name of the package.
+    pub fn name(&self) -> &Name {
+        match self {
+            Self::V1(file) => &file.name,
+            Self::V2(file) => &file.name,
+        }
+    }
+
+    /// Returns the version of the package.
+    pub fn version(&self) -> &FullVersion {
+        match self {
+            Self::V1(file) => &file.version,
+            Self::V2(file) => &file.version,
+        }
+    }
+
+    /// Returns the reason for the installation of the package.
+    pub fn reason(&self) -> PackageInstallReason {
+        match self {
+            Self::V1(file) => file.reason,
+            Self::V2(file) => file.reason,
+        }
+    }
+
+    /// Returns the virtual components or packages that the package provides.
+    pub fn provides(&self) -> &[RelationOrSoname] {
+        match self {
+            Self::V1(file) => &file.provides,
+            Self::V2(file) => &file.provides,
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::path::PathBuf;
 
+use alpm_common::ErrorCategory;
 use fluent_i18n::t;
 use winnow::error::{ContextError, ParseError};
 
@@ -11,6 +12,10 @@ use crate::desc::SectionKeyword;
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum Error {
+    /// An [`alpm_common::Error`].
+    #[error(transparent)]
+    AlpmCommon(#[from] alpm_common::Error),
+
     /// An [`alpm_types::Error`].
     #[error("{msg}", msg = t!("error-alpm-types", { "source" => .0.to_string() }))]
     AlpmTypes(#[from] alpm_types::Error),
@@ -54,6 +59,26 @@ pub enum Error {
         source: std::io::Error,
     },
 
+    /// An [`alpm_mtree::Error`].
+    #[error(transparent)]
+    AlpmMtree(#[from] alpm_mtree::Error),
+
+    /// A [`files::Error`][`crate::files::Error`].
+    #[error(transparent)]
+    Files(#[from] crate::files::Error),
+
+    /// The directory of a local database entry does not match the name and version of the package.
+    #[error("{msg}", msg = t!("error-local-db-entry-mismatch", {
+        "path" => path.display().to_string(),
+        "expected" => expected,
+    }))]
+    LocalDbEntryMismatch {
+        /// The path of the database entry directory.
+        path: PathBuf,
+        /// The expected name of the directory.
+        expected: String,
+    },
+
     /// A winnow parser for a type didn't work and produced an error.
     #[error("{msg}", msg = t!("error-parse", { "error" => .0 }))]
     Parse(String),
@@ -97,3 +122,25 @@ impl<'a> From<ParseError<&'a str, ContextError>> for Error {
         Self::Parse(value.to_string())
     }
 }
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::AlpmCommon(error) => error.into(),
+            Error::AlpmTypes(error) => error.into(),
+            Error::AlpmMtree(error) => error.into(),
+            Error::Files(error) => error.into(),
+            Error::Io { .. } | Error::IoPath { .. } | Error::IoRead { .. } | Error::NoInputFile => {
+                Self::Io
+            }
+            Error::Parse(_) | Error::InvalidFormat => Self::Parse,
+            #[cfg(feature = "cli")]
+            Error::Json { .. } => Self::Parse,
+            Error::LocalDbEntryMismatch { .. }
+            | Error::MissingSection(_)
+            | Error::DuplicateSection(_) => Self::Validation,
+            Error::UnsupportedSchemaVersion(_) => Self::Schema,
+        }
+    }
+}
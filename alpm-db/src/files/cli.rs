@@ -105,6 +105,31 @@ pub enum Command {
         pretty: bool,
     },
 
+    /// The diff command.
+    #[command(about = t!("cli-diff-about"), long_about = t!("cli-diff-long-about"))]
+    Diff {
+        /// The expected alpm-db-files file.
+        #[arg(help = t!("cli-diff-input-file-help"), value_name = "INPUT_FILE")]
+        input_file: PathBuf,
+
+        /// An alpm-db-files file or a directory to compare with.
+        #[arg(
+            help = t!("cli-diff-other-help"),
+            long_help = t!("cli-diff-other-long-help"),
+            value_name = "OTHER"
+        )]
+        other: PathBuf,
+
+        /// Determines whether the output will be displayed in a pretty non-minimized fashion.
+        #[arg(
+            env = "ALPM_DB_FILES_DIFF_PRETTY",
+            help = t!("cli-format-pretty-help"),
+            long,
+            short,
+        )]
+        pretty: bool,
+    },
+
     /// The validate command.
     #[command(about = t!("cli-validate-about"), long_about = t!("cli-validate-long-about"))]
     Validate {
@@ -119,4 +144,17 @@ pub enum Command {
         )]
         input_file: Option<PathBuf>,
     },
+
+    /// The json-schema command.
+    #[command(about = t!("cli-json-schema-about"), long_about = t!("cli-json-schema-long-about"))]
+    JsonSchema {
+        /// Determines whether the output will be displayed in a pretty non-minimized fashion.
+        #[arg(
+            env = "ALPM_DB_FILES_JSON_SCHEMA_PRETTY",
+            help = t!("cli-format-pretty-help"),
+            long,
+            short,
+        )]
+        pretty: bool,
+    },
 }
@@ -0,0 +1,150 @@
+//! Comparison of [alpm-db-files] data.
+//!
+//! [alpm-db-files]: https://alpm.archlinux.page/specifications/alpm-db-files.5.html
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use alpm_common::relative_data_files;
+use serde::Serialize;
+
+use crate::files::{DbFiles, DbFilesV1, Error};
+
+/// The differences between two sets of [alpm-db-files] data.
+///
+/// Created using [`DbFiles::diff`] or [`DbFiles::diff_dir`].
+/// All lists are sorted.
+/// Directories are considered equal, regardless of whether they are listed with a trailing slash.
+///
+/// [alpm-db-files]: https://alpm.archlinux.page/specifications/alpm-db-files.5.html
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct DbFilesDiff {
+    /// The paths that are only present in the expected [`DbFiles`].
+    pub missing: Vec<PathBuf>,
+    /// The paths that are only present in the compared [`DbFiles`] or directory.
+    pub extra: Vec<PathBuf>,
+}
+
+impl DbFilesDiff {
+    /// Returns `true` if there are no differences.
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+impl DbFiles {
+    /// Returns the differences between `self` and `other`.
+    ///
+    /// Considers `self` the expected file listing.
+    /// Paths that are only listed in `self` are reported as missing, while paths that are only
+    /// listed in `other` are reported as extra.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{path::PathBuf, str::FromStr};
+    ///
+    /// use alpm_db::files::DbFiles;
+    ///
+    /// # fn main() -> testresult::TestResult {
+    /// let expected = DbFiles::from_str("%FILES%\nusr/\nusr/bin/\nusr/bin/foo\n")?;
+    /// let actual = DbFiles::from_str("%FILES%\nusr/\nusr/bin/\nusr/bin/bar\n")?;
+    ///
+    /// let diff = expected.diff(&actual);
+    /// assert_eq!(diff.missing, vec![PathBuf::from("usr/bin/foo")]);
+    /// assert_eq!(diff.extra, vec![PathBuf::from("usr/bin/bar")]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff(&self, other: &DbFiles) -> DbFilesDiff {
+        let expected: BTreeSet<&Path> = self.as_ref().iter().map(PathBuf::as_path).collect();
+        let actual: BTreeSet<&Path> = other.as_ref().iter().map(PathBuf::as_path).collect();
+
+        DbFilesDiff {
+            missing: expected
+                .difference(&actual)
+                .map(|path| path.to_path_buf())
+                .collect(),
+            extra: actual
+                .difference(&expected)
+                .map(|path| path.to_path_buf())
+                .collect(),
+        }
+    }
+
+    /// Returns the differences between `self` and the contents of the directory at `path`.
+    ///
+    /// Considers `self` the expected file listing and `path` e.g. the directory into which a
+    /// package has been extracted.
+    /// The metadata files of a package (e.g. `.PKGINFO` or `.MTREE`) in `path` are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the contents of the directory at `path` cannot be collected,
+    /// - or no [`DbFilesV1`] can be created from the contents of the directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{
+    ///     fs::{File, create_dir_all},
+    ///     path::PathBuf,
+    ///     str::FromStr,
+    /// };
+    ///
+    /// use alpm_db::files::DbFiles;
+    /// use tempfile::tempdir;
+    ///
+    /// # fn main() -> testresult::TestResult {
+    /// let temp_dir = tempdir()?;
+    /// create_dir_all(temp_dir.path().join("usr/bin/"))?;
+    /// File::create(temp_dir.path().join("usr/bin/foo"))?;
+    /// File::create(temp_dir.path().join(".PKGINFO"))?;
+    ///
+    /// let expected = DbFiles::from_str("%FILES%\nusr/\nusr/bin/\nusr/bin/foo\nusr/bin/bar\n")?;
+    /// let diff = expected.diff_dir(temp_dir.path())?;
+    /// assert_eq!(diff.missing, vec![PathBuf::from("usr/bin/bar")]);
+    /// assert!(diff.extra.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff_dir(&self, path: impl AsRef<Path>) -> Result<DbFilesDiff, Error> {
+        let files = DbFiles::V1(DbFilesV1::try_from(relative_data_files(path)?)?);
+        Ok(self.diff(&files))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that identical file listings have no differences.
+    #[test]
+    fn db_files_diff_identical() -> TestResult {
+        let files = DbFiles::from_str("%FILES%\nusr/\nusr/bin/\nusr/bin/foo\n")?;
+
+        assert!(files.diff(&files).is_empty());
+        Ok(())
+    }
+
+    /// Ensures that directories are compared regardless of trailing slashes.
+    #[test]
+    fn db_files_diff_trailing_slash() -> TestResult {
+        let expected = DbFiles::from_str("%FILES%\nusr/\nusr/bin/\n")?;
+        let actual = DbFiles::V1(DbFilesV1::try_from(vec![
+            PathBuf::from("usr"),
+            PathBuf::from("usr/bin"),
+        ])?);
+
+        assert!(expected.diff(&actual).is_empty());
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::path::PathBuf;
 
+use alpm_common::ErrorCategory;
 use fluent_i18n::t;
 use winnow::error::{ContextError, ParseError};
 
@@ -87,3 +88,18 @@ impl<'a> From<ParseError<&'a str, ContextError>> for Error {
         Self::ParseError(value.to_string())
     }
 }
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::AlpmCommon(error) => error.into(),
+            Error::InvalidFilesPaths { .. } | Error::InvalidBackupEntries { .. } => {
+                Self::Validation
+            }
+            Error::Io { .. } | Error::IoPath { .. } => Self::Io,
+            Error::ParseError(_) => Self::Parse,
+            Error::UnknownSchemaVersion => Self::Schema,
+        }
+    }
+}
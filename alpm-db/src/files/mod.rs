@@ -6,6 +6,7 @@
 #[doc(hidden)]
 pub mod cli;
 
+mod diff;
 mod error;
 mod schema;
 pub mod v1;
@@ -19,6 +20,7 @@ use std::{
 };
 
 use alpm_common::{FileFormatSchema, MetadataFile};
+pub use diff::DbFilesDiff;
 pub use error::Error;
 use fluent_i18n::t;
 pub use schema::DbFilesSchema;
@@ -30,6 +32,7 @@ pub use v1::{BackupEntry, DbFilesV1};
 ///
 /// [alpm-db-files]: https://alpm.archlinux.page/specifications/alpm-db-files.5.html
 #[derive(Clone, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum DbFiles {
     /// Version 1 of the [alpm-db-files] specification.
@@ -123,6 +123,7 @@ impl FilesSection {
 
 /// A path that should be tracked for backup together with its checksum.
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BackupEntry {
     /// The path to the file that is backed up.
     pub path: RelativeFilePath,
@@ -384,6 +385,7 @@ impl Display for BackupV1Errors {
 ///
 /// [alpm-db-files]: https://alpm.archlinux.page/specifications/alpm-db-files.5.html
 #[derive(Clone, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DbFilesV1 {
     files: Vec<PathBuf>,
     #[serde(default)]
@@ -7,5 +7,7 @@ pub mod desc;
 
 pub mod files;
 
+pub mod local;
+
 // Initialize i18n support.
 fluent_i18n::i18n!("locales");
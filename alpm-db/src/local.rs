@@ -0,0 +1,342 @@
+//! Reading of local [alpm-db] directories.
+//!
+//! A local [alpm-db] (e.g. `/var/lib/pacman/local/`) contains one directory per installed package,
+//! which is named after the name and version of the package (e.g. `foo-1.0.0-1`).
+//! Each of these directories contains an [alpm-db-desc] file named `desc`, and optionally an
+//! [alpm-db-files] file named `files` and a compressed [ALPM-MTREE] file named `mtree`.
+//!
+//! [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+//! [alpm-db]: https://alpm.archlinux.page/specifications/alpm-db.7.html
+//! [alpm-db-desc]: https://alpm.archlinux.page/specifications/alpm-db-desc.5.html
+//! [alpm-db-files]: https://alpm.archlinux.page/specifications/alpm-db-files.5.html
+
+use std::{
+    collections::BTreeMap,
+    fs::read_dir,
+    path::{Path, PathBuf},
+};
+
+use alpm_common::MetadataFile;
+use alpm_mtree::Mtree;
+use alpm_types::{FullVersion, Name, PackageInstallReason};
+use fluent_i18n::t;
+
+use crate::{Error, desc::DbDescFile, files::DbFiles};
+
+/// The file name of the [alpm-db-desc] file in a database entry.
+///
+/// [alpm-db-desc]: https://alpm.archlinux.page/specifications/alpm-db-desc.5.html
+pub const DESC_FILE_NAME: &str = "desc";
+
+/// The file name of the [alpm-db-files] file in a database entry.
+///
+/// [alpm-db-files]: https://alpm.archlinux.page/specifications/alpm-db-files.5.html
+pub const FILES_FILE_NAME: &str = "files";
+
+/// The file name of the [ALPM-MTREE] file in a database entry.
+///
+/// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+pub const MTREE_FILE_NAME: &str = "mtree";
+
+/// A single installed package in a local [alpm-db].
+///
+/// [alpm-db]: https://alpm.archlinux.page/specifications/alpm-db.7.html
+#[derive(Clone, Debug)]
+pub struct LocalDbEntry {
+    /// The [alpm-db-desc] data of the package.
+    ///
+    /// [alpm-db-desc]: https://alpm.archlinux.page/specifications/alpm-db-desc.5.html
+    pub desc: DbDescFile,
+    /// The [alpm-db-files] data of the package, if present.
+    ///
+    /// [alpm-db-files]: https://alpm.archlinux.page/specifications/alpm-db-files.5.html
+    pub files: Option<DbFiles>,
+    /// The [ALPM-MTREE] data of the package, if present.
+    ///
+    /// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+    pub mtree: Option<Mtree>,
+}
+
+impl LocalDbEntry {
+    /// Creates a new [`LocalDbEntry`] from a database entry directory.
+    ///
+    /// Reads the `desc` file and, if they exist, the `files` and `mtree` files in `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the `desc` file cannot be read or parsed,
+    /// - the `files` or `mtree` file exists, but cannot be read or parsed,
+    /// - or the name of the directory does not match the name and version of the package.
+    pub fn from_dir(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let desc = DbDescFile::from_file(path.join(DESC_FILE_NAME))?;
+
+        let expected = format!("{}-{}", desc.name(), desc.version());
+        if path.file_name().and_then(|name| name.to_str()) != Some(expected.as_str()) {
+            return Err(Error::LocalDbEntryMismatch {
+                path: path.to_path_buf(),
+                expected,
+            });
+        }
+
+        let files_path = path.join(FILES_FILE_NAME);
+        let files = if files_path.is_file() {
+            Some(DbFiles::from_file(files_path)?)
+        } else {
+            None
+        };
+        let mtree_path = path.join(MTREE_FILE_NAME);
+        let mtree = if mtree_path.is_file() {
+            Some(Mtree::from_file(mtree_path)?)
+        } else {
+            None
+        };
+
+        Ok(Self { desc, files, mtree })
+    }
+
+    /// Returns the name of the package.
+    pub fn name(&self) -> &Name {
+        self.desc.name()
+    }
+
+    /// Returns the version of the package.
+    pub fn version(&self) -> &FullVersion {
+        self.desc.version()
+    }
+
+    /// Returns the reason for the installation of the package.
+    pub fn reason(&self) -> PackageInstallReason {
+        self.desc.reason()
+    }
+
+    /// Returns the paths of the files owned by the package.
+    ///
+    /// The paths are relative to the root of the system (e.g. `usr/bin/foo`).
+    /// Returns an empty slice if no [alpm-db-files] data is present.
+    ///
+    /// [alpm-db-files]: https://alpm.archlinux.page/specifications/alpm-db-files.5.html
+    pub fn files(&self) -> &[PathBuf] {
+        self.files.as_ref().map(AsRef::as_ref).unwrap_or_default()
+    }
+}
+
+/// A local [alpm-db], which tracks the packages installed on a system.
+///
+/// [alpm-db]: https://alpm.archlinux.page/specifications/alpm-db.7.html
+#[derive(Clone, Debug, Default)]
+pub struct LocalDb {
+    entries: BTreeMap<Name, LocalDbEntry>,
+}
+
+impl LocalDb {
+    /// Creates a new [`LocalDb`] from a local [alpm-db] directory.
+    ///
+    /// Reads each directory in `path` using [`LocalDbEntry::from_dir`].
+    /// Other files in `path` (e.g. `ALPM_DB_VERSION`) are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use alpm_db::local::LocalDb;
+    /// use alpm_types::Name;
+    ///
+    /// # fn main() -> testresult::TestResult {
+    /// let db = LocalDb::from_dir("/var/lib/pacman/local")?;
+    /// if let Some(version) = db.installed_version(&Name::new("pacman")?) {
+    ///     println!("pacman {version} is installed");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - `path` cannot be read,
+    /// - or [`LocalDbEntry::from_dir`] fails for one of the directories in `path`.
+    ///
+    /// [alpm-db]: https://alpm.archlinux.page/specifications/alpm-db.7.html
+    pub fn from_dir(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let io_error = |source| Error::IoPath {
+            path: path.to_path_buf(),
+            context: t!("error-io-path-read-local-db"),
+            source,
+        };
+
+        let mut db = Self::default();
+        for entry in read_dir(path).map_err(io_error)? {
+            let entry_path = entry.map_err(io_error)?.path();
+            if entry_path.is_dir() {
+                db.insert(LocalDbEntry::from_dir(entry_path)?);
+            }
+        }
+
+        Ok(db)
+    }
+
+    /// Inserts a [`LocalDbEntry`].
+    ///
+    /// Returns the replaced entry, if an entry of the same name already existed.
+    pub fn insert(&mut self, entry: LocalDbEntry) -> Option<LocalDbEntry> {
+        self.entries.insert(entry.name().clone(), entry)
+    }
+
+    /// Returns the [`LocalDbEntry`] of the package called `name`.
+    pub fn get(&self, name: &Name) -> Option<&LocalDbEntry> {
+        self.entries.get(name)
+    }
+
+    /// Returns an iterator over all [`LocalDbEntry`]s, ordered by name.
+    pub fn entries(&self) -> impl Iterator<Item = &LocalDbEntry> {
+        self.entries.values()
+    }
+
+    /// Returns the number of installed packages.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no packages are installed.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the installed version of the package called `name`.
+    pub fn installed_version(&self, name: &Name) -> Option<&FullVersion> {
+        self.get(name).map(LocalDbEntry::version)
+    }
+
+    /// Returns the reason for the installation of the package called `name`.
+    pub fn install_reason(&self, name: &Name) -> Option<PackageInstallReason> {
+        self.get(name).map(LocalDbEntry::reason)
+    }
+
+    /// Returns the [`LocalDbEntry`] of the package that owns `path`.
+    ///
+    /// Both absolute paths (e.g. `/usr/bin/foo`) and paths relative to the root of the system
+    /// (e.g. `usr/bin/foo`) are supported.
+    pub fn owner(&self, path: impl AsRef<Path>) -> Option<&LocalDbEntry> {
+        let path = path.as_ref();
+        let path = path.strip_prefix("/").unwrap_or(path);
+        self.entries()
+            .find(|entry| entry.files().iter().any(|file| file == path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{create_dir, write};
+
+    use tempfile::tempdir;
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Writes a database entry for `name` in `version` with `files` to `path`.
+    ///
+    /// The `files` file is omitted if `files` is empty.
+    fn write_entry(path: &Path, name: &str, version: &str, files: &[&str]) -> TestResult {
+        let entry_path = path.join(format!("{name}-{version}"));
+        create_dir(&entry_path)?;
+        write(
+            entry_path.join(DESC_FILE_NAME),
+            format!(
+                r#"%NAME%
+{name}
+
+%VERSION%
+{version}
+
+%BASE%
+{name}
+
+%DESC%
+An example package
+
+%URL%
+https://example.org
+
+%ARCH%
+x86_64
+
+%BUILDDATE%
+1733737242
+
+%INSTALLDATE%
+1733737243
+
+%PACKAGER%
+Foobar McFooface <foobar@mcfooface.org>
+
+%SIZE%
+123
+
+%REASON%
+1
+
+%VALIDATION%
+pgp
+
+"#
+            ),
+        )?;
+        if !files.is_empty() {
+            write(
+                entry_path.join(FILES_FILE_NAME),
+                format!("%FILES%\n{}\n\n", files.join("\n")),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Ensures that installed packages, their files and install reasons are read.
+    #[test]
+    fn local_db_from_dir() -> TestResult {
+        let temp_dir = tempdir()?;
+        let path = temp_dir.path();
+        write(path.join("ALPM_DB_VERSION"), "9\n")?;
+        write_entry(path, "foo", "1.0.0-1", &["usr/", "usr/bin/", "usr/bin/foo"])?;
+        write_entry(path, "bar", "2.0.0-1", &["usr/", "usr/bin/", "usr/bin/bar"])?;
+
+        let db = LocalDb::from_dir(path)?;
+
+        assert_eq!(db.len(), 2);
+        assert_eq!(
+            db.installed_version(&Name::new("foo")?)
+                .map(ToString::to_string),
+            Some("1.0.0-1".to_string())
+        );
+        assert_eq!(
+            db.install_reason(&Name::new("bar")?),
+            Some(PackageInstallReason::Depend)
+        );
+        assert_eq!(
+            db.owner("/usr/bin/bar")
+                .map(|entry| entry.name().to_string()),
+            Some("bar".to_string())
+        );
+        assert!(db.owner("usr/bin/baz").is_none());
+        assert!(db.installed_version(&Name::new("baz")?).is_none());
+        Ok(())
+    }
+
+    /// Ensures that entries in wrongly named directories are rejected.
+    #[test]
+    fn local_db_entry_mismatch() -> TestResult {
+        let temp_dir = tempdir()?;
+        write_entry(temp_dir.path(), "foo", "1.0.0-1", &[])?;
+        let path = temp_dir.path().join("foo-1.0.0-2");
+        std::fs::rename(temp_dir.path().join("foo-1.0.0-1"), &path)?;
+
+        assert!(matches!(
+            LocalDbEntry::from_dir(&path),
+            Err(Error::LocalDbEntryMismatch { .. })
+        ));
+        Ok(())
+    }
+}
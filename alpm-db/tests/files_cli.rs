@@ -456,6 +456,84 @@ mod format {
     }
 }
 
+/// Integration tests for `alpm-db-files diff`.
+mod diff {
+    use super::*;
+
+    /// Ensures that `alpm-db-files diff` succeeds if a directory matches the `alpm-db-files` data.
+    #[rstest]
+    fn succeeds_with_matching_dir(dir_with_entries: TestResult<TempDir>) -> TestResult {
+        let temp_dir = dir_with_entries?;
+        let mut input_file = NamedTempFile::new()?;
+        input_file.write_all(ALPM_DB_FILES_WITH_ENTRIES.as_bytes())?;
+
+        let mut cmd = cargo_bin_cmd!("alpm-db-files");
+        cmd.args(vec![
+            "diff".into(),
+            input_file.path().to_string_lossy().to_string(),
+            temp_dir.path().to_string_lossy().to_string(),
+        ]);
+
+        let output = cmd.assert().success();
+        assert_eq!(
+            String::from_utf8_lossy(&output.get_output().stdout),
+            "{\"missing\":[],\"extra\":[]}\n"
+        );
+
+        Ok(())
+    }
+
+    /// Ensures that `alpm-db-files diff` reports missing and extra paths and fails.
+    #[rstest]
+    fn fails_with_differing_file() -> TestResult {
+        let mut input_file = NamedTempFile::new()?;
+        input_file.write_all(ALPM_DB_FILES_WITH_ENTRIES.as_bytes())?;
+        let mut other_file = NamedTempFile::new()?;
+        other_file.write_all(b"%FILES%\nusr/\nusr/bin/\nusr/bin/bar\n")?;
+
+        let mut cmd = cargo_bin_cmd!("alpm-db-files");
+        cmd.args(vec![
+            "diff".into(),
+            input_file.path().to_string_lossy().to_string(),
+            other_file.path().to_string_lossy().to_string(),
+        ]);
+
+        let output = cmd.assert().failure().code(1);
+        assert_eq!(
+            String::from_utf8_lossy(&output.get_output().stdout),
+            "{\"missing\":[\"usr/bin/foo\"],\"extra\":[\"usr/bin/bar\"]}\n"
+        );
+
+        Ok(())
+    }
+}
+
+/// Integration tests for `alpm-db-files json-schema`.
+mod json_schema {
+    use super::*;
+
+    /// Ensures that `alpm-db-files json-schema` outputs a JSON Schema that describes the file list
+    /// and backup entries.
+    #[rstest]
+    #[case::compact(false)]
+    #[case::pretty(true)]
+    fn succeeds(#[case] pretty: bool) -> TestResult {
+        let mut cmd = cargo_bin_cmd!("alpm-db-files");
+        cmd.args(vec!["json-schema"]);
+        if pretty {
+            cmd.arg("--pretty");
+        }
+
+        let output = cmd.unwrap();
+        let schema: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        let properties = &schema["$defs"]["DbFilesV1"]["properties"];
+        assert!(properties["files"].is_object());
+        assert!(properties["backup"].is_object());
+
+        Ok(())
+    }
+}
+
 /// Integration tests for `alpm-db-files validate`.
 mod validate {
     use super::*;
@@ -8,6 +8,20 @@ error-toml-deserialization =
   Failed to deserialize configuration:
   { $source }
 
+error-json-deserialization =
+  Failed to deserialize JSON data:
+  { $source }
+
+error-unknown-profile = The lint profile bundle { $version } contains no profile called "{ $name }". Available profiles: { $available }
+
+error-unknown-configuration-profile = The lint configuration contains no profile called "{ $name }". Available profiles: { $available }
+
+error-cyclic-profile-inheritance = The lint profiles inherit from each other in a cycle: { $chain }
+
 error-io-open-config = opening the config for reading
 
 error-io-read-config = reading config data
+
+error-io-open-profile-bundle = opening the lint profile bundle for reading
+
+error-io-read-profile-bundle = reading lint profile bundle data
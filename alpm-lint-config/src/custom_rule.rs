@@ -0,0 +1,165 @@
+use std::fmt;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::LintLevel;
+
+/// A lint rule that is defined declaratively in a [`LintConfiguration`].
+///
+/// Custom lint rules allow distributions and private repositories to enforce house rules (e.g.
+/// required fields or a specific format of URLs), without having to implement a lint rule in
+/// `alpm-lint`.
+///
+/// # Examples
+///
+/// ```
+/// use alpm_lint_config::{CustomRule, CustomRuleCheck, LintLevel};
+///
+/// # fn main() -> testresult::TestResult {
+/// let rule: CustomRule = toml::from_str(
+///     r#"
+/// name = "https_url"
+/// scope = "SourceInfo"
+/// description = "Upstream URLs must use HTTPS."
+/// help = "Use an HTTPS URL for the upstream project."
+/// check = { type = "Pattern", field = "url", pattern = "^https://" }
+/// "#,
+/// )?;
+///
+/// assert_eq!(rule.level, LintLevel::Warn);
+/// let CustomRuleCheck::Pattern { field, pattern } = rule.check else {
+///     panic!("the check should be a pattern");
+/// };
+/// assert_eq!(field, "url");
+/// assert!(pattern.is_match("https://example.org"));
+///
+/// // Invalid regular expressions are rejected.
+/// assert!(
+///     toml::from_str::<CustomRuleCheck>(
+///         r#"type = "Pattern"
+/// field = "url"
+/// pattern = "(""#
+///     )
+///     .is_err()
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`LintConfiguration`]: crate::LintConfiguration
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CustomRule {
+    /// The name of the lint rule.
+    ///
+    /// The name must only consist of lowercase alphanumeric characters and underscores.
+    /// Together with the [`CustomRule::scope`], it forms the scoped name of the lint rule (e.g.
+    /// `source_info::https_url`).
+    pub name: String,
+    /// The metadata file that is checked by the lint rule.
+    pub scope: CustomRuleScope,
+    /// The severity level of the lint rule.
+    #[serde(default)]
+    pub level: LintLevel,
+    /// The documentation of the lint rule.
+    pub description: String,
+    /// The help text, that is shown when the lint rule encounters an issue.
+    pub help: String,
+    /// The check that is performed by the lint rule.
+    pub check: CustomRuleCheck,
+}
+
+/// The metadata file that is checked by a [`CustomRule`].
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum CustomRuleScope {
+    /// A [SRCINFO] file.
+    ///
+    /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+    SourceInfo,
+    /// A [PKGINFO] file.
+    ///
+    /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+    PackageInfo,
+}
+
+/// The check that is performed by a [`CustomRule`].
+///
+/// Fields are identified by their keys in the checked metadata file (e.g. `url` or
+/// `depends_x86_64`).
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum CustomRuleCheck {
+    /// All values of a field must match a regular expression.
+    Pattern {
+        /// The key of the checked field.
+        field: String,
+        /// The regular expression that all values of the field must match.
+        pattern: CustomRulePattern,
+    },
+    /// A field must not contain any of a list of values.
+    ForbiddenValues {
+        /// The key of the checked field.
+        field: String,
+        /// The values that are forbidden for the field.
+        values: Vec<String>,
+    },
+    /// A field must be set.
+    RequiredField {
+        /// The key of the required field.
+        field: String,
+    },
+}
+
+/// A regular expression used in a [`CustomRuleCheck::Pattern`].
+///
+/// The regular expression is validated when deserializing it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CustomRulePattern(Regex);
+
+impl CustomRulePattern {
+    /// Returns whether `value` matches the regular expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_lint_config::CustomRulePattern;
+    ///
+    /// # fn main() -> Result<(), regex::Error> {
+    /// let pattern = CustomRulePattern::try_from("^https://".to_string())?;
+    ///
+    /// assert!(pattern.is_match("https://example.org"));
+    /// assert!(!pattern.is_match("http://example.org"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_match(&self, value: &str) -> bool {
+        self.0.is_match(value)
+    }
+}
+
+impl TryFrom<String> for CustomRulePattern {
+    type Error = regex::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Ok(Self(Regex::new(&value)?))
+    }
+}
+
+impl From<CustomRulePattern> for String {
+    fn from(value: CustomRulePattern) -> Self {
+        value.0.as_str().to_string()
+    }
+}
+
+impl PartialEq for CustomRulePattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl fmt::Display for CustomRulePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.as_str())
+    }
+}
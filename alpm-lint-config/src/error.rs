@@ -40,4 +40,45 @@ pub enum Error {
     /// TOML de/serialization error
     #[error("{msg}", msg = t!("error-toml-deserialization", { "source" => .0.to_string() }))]
     Deserialization(#[from] toml::de::Error),
+
+    /// JSON deserialization error
+    #[error("{msg}", msg = t!("error-json-deserialization", { "source" => .0.to_string() }))]
+    JsonDeserialization(#[from] serde_json::Error),
+
+    /// A lint profile is not part of a lint profile bundle.
+    #[error("{msg}", msg = t!("error-unknown-profile", {
+        "name" => name,
+        "version" => version,
+        "available" => available.join(", ")
+    }))]
+    UnknownProfile {
+        /// The name of the requested profile.
+        name: String,
+        /// The version of the lint profile bundle.
+        version: String,
+        /// The names of the profiles available in the lint profile bundle.
+        available: Vec<String>,
+    },
+
+    /// A lint profile is not part of a lint configuration.
+    #[error("{msg}", msg = t!("error-unknown-configuration-profile", {
+        "name" => name,
+        "available" => available.join(", ")
+    }))]
+    UnknownConfigurationProfile {
+        /// The name of the requested profile.
+        name: String,
+        /// The names of the profiles available in the lint configuration.
+        available: Vec<String>,
+    },
+
+    /// Lint profiles inherit from each other in a cycle.
+    #[error("{msg}", msg = t!("error-cyclic-profile-inheritance", {
+        "chain" => chain.join(" -> ")
+    }))]
+    CyclicProfileInheritance {
+        /// The names of the profiles in the order of inheritance, ending with the first repeated
+        /// profile.
+        chain: Vec<String>,
+    },
 }
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// The severity level of a lint rule.
+///
+/// Used to set the level of a [`CustomRule`] and to override the levels of lint rules in a
+/// [`LintConfiguration`].
+///
+/// [`CustomRule`]: crate::CustomRule
+/// [`LintConfiguration`]: crate::LintConfiguration
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum LintLevel {
+    /// Issues of the lint rule lead to errors.
+    Error,
+    /// Issues of the lint rule lead to denials.
+    Deny,
+    /// Issues of the lint rule lead to warnings.
+    #[default]
+    Warn,
+    /// Issues of the lint rule lead to suggestions.
+    Suggest,
+}
@@ -1,17 +1,23 @@
 #![doc = include_str!("../README.md")]
 
+mod custom_rule;
 mod error;
 mod group;
+mod level;
 mod lint_config;
 mod lint_rule_config;
+mod profile;
 
+pub use custom_rule::{CustomRule, CustomRuleCheck, CustomRulePattern, CustomRuleScope};
 pub use error::Error;
 pub use group::LintGroup;
+pub use level::LintLevel;
 pub use lint_config::LintConfiguration;
 pub use lint_rule_config::{
     LintRuleConfiguration,
     LintRuleConfigurationOption,
     LintRuleConfigurationOptionName,
 };
+pub use profile::{LintProfile, LintProfileBundle};
 
 fluent_i18n::i18n!("locales");
@@ -1,9 +1,17 @@
-use std::{fs::File, io::Read, path::Path};
+use std::{collections::BTreeMap, fs::File, io::Read, path::Path};
 
 use fluent_i18n::t;
 use serde::{Deserialize, Serialize};
 
-use crate::{Error, LintGroup, LintRuleConfiguration};
+use crate::{
+    CustomRule,
+    Error,
+    LintGroup,
+    LintLevel,
+    LintProfile,
+    LintRuleConfiguration,
+    profile::resolve_profile,
+};
 
 /// Configuration options for linting.
 ///
@@ -11,8 +19,14 @@ use crate::{Error, LintGroup, LintRuleConfiguration};
 ///
 /// - configure the general lint rule behavior,
 /// - explicitly enable or disable individual lint rules,
-/// - and enable non-default lint groups.
+/// - override the levels of individual lint rules,
+/// - enable non-default lint groups,
+/// - define custom lint rules,
+/// - and define named profiles for specific contexts (see [`LintConfiguration::profile`]).
+///
+/// Settings that are not set use their default value.
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
 pub struct LintConfiguration {
     /// All options that can be used to configure various lint rules.
     pub options: LintRuleConfiguration,
@@ -22,6 +36,16 @@ pub struct LintConfiguration {
     pub disabled_rules: Vec<String>,
     /// A list of lint rules that are explicitly enabled.
     pub enabled_rules: Vec<String>,
+    /// The levels of lint rules, that override their default levels.
+    ///
+    /// The lint rules are identified by their scoped names (e.g. `source_info::unsafe_checksum`).
+    pub levels: BTreeMap<String, LintLevel>,
+    /// A list of lint rules that are defined declaratively in the configuration.
+    pub custom_rules: Vec<CustomRule>,
+    /// Named profiles that adjust the configuration for specific contexts (e.g. `aur` or
+    /// `strict`).
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, LintProfile>,
 }
 
 impl LintConfiguration {
@@ -78,4 +102,134 @@ impl LintConfiguration {
 
         Ok(toml::from_str(&buf)?)
     }
+
+    /// Merges the settings of `other` into this configuration.
+    ///
+    /// - Options of `other` that differ from their default value are merged (see
+    ///   [`LintRuleConfiguration::merge`]).
+    /// - Groups, disabled and enabled lint rules of `other` are added. Lint rules that are enabled
+    ///   by `other` are no longer disabled and vice versa.
+    /// - Level overrides and custom lint rules of `other` take precedence over the ones with the
+    ///   same names.
+    ///
+    /// The profiles of `other` are not merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_lint_config::{LintConfiguration, LintLevel};
+    ///
+    /// let mut config = LintConfiguration {
+    ///     disabled_rules: vec!["source_info::unsafe_checksum".to_string()],
+    ///     ..Default::default()
+    /// };
+    /// config.merge(LintConfiguration {
+    ///     enabled_rules: vec!["source_info::unsafe_checksum".to_string()],
+    ///     levels: [("source_info::unsafe_checksum".to_string(), LintLevel::Error)].into(),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// assert!(config.disabled_rules.is_empty());
+    /// assert_eq!(config.enabled_rules, ["source_info::unsafe_checksum"]);
+    /// assert_eq!(
+    ///     config.levels.get("source_info::unsafe_checksum"),
+    ///     Some(&LintLevel::Error)
+    /// );
+    /// ```
+    pub fn merge(&mut self, other: LintConfiguration) {
+        self.options.merge(&other.options);
+
+        for group in other.groups {
+            if !self.groups.contains(&group) {
+                self.groups.push(group);
+            }
+        }
+        for rule in other.disabled_rules {
+            self.enabled_rules.retain(|enabled| enabled != &rule);
+            if !self.disabled_rules.contains(&rule) {
+                self.disabled_rules.push(rule);
+            }
+        }
+        for rule in other.enabled_rules {
+            self.disabled_rules.retain(|disabled| disabled != &rule);
+            if !self.enabled_rules.contains(&rule) {
+                self.enabled_rules.push(rule);
+            }
+        }
+
+        self.levels.extend(other.levels);
+        for custom_rule in other.custom_rules {
+            self.custom_rules
+                .retain(|existing| existing.name != custom_rule.name);
+            self.custom_rules.push(custom_rule);
+        }
+    }
+
+    /// Returns the named [`LintProfile`] called `name` of the configuration.
+    ///
+    /// The profile inherits the settings of the profile it names in [`LintProfile::inherits`], if
+    /// any.
+    /// The configuration of the returned profile consists of the settings of this configuration,
+    /// into which the settings of the profile are merged (see [`LintConfiguration::merge`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_lint_config::{LintConfiguration, LintLevel};
+    ///
+    /// # fn main() -> Result<(), alpm_lint_config::Error> {
+    /// let config: LintConfiguration = toml::from_str(
+    ///     r#"
+    /// disabled_rules = ["source_info::openpgp_key_id"]
+    ///
+    /// [profiles.aur.config]
+    /// disabled_rules = ["source_info::invalid_spdx_license"]
+    ///
+    /// [profiles.strict]
+    /// inherits = "aur"
+    ///
+    /// [profiles.strict.config]
+    /// levels = { "source_info::duplicate_architecture" = "Error" }
+    /// "#,
+    /// )?;
+    ///
+    /// let strict = config.profile("strict")?.config;
+    /// assert_eq!(
+    ///     strict.disabled_rules,
+    ///     [
+    ///         "source_info::openpgp_key_id",
+    ///         "source_info::invalid_spdx_license"
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     strict.levels.get("source_info::duplicate_architecture"),
+    ///     Some(&LintLevel::Error)
+    /// );
+    /// assert!(config.profile("official-repos").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the configuration contains no profile called `name`,
+    /// - a profile inherits from a profile that does not exist,
+    /// - or the inheritance of profiles is cyclic.
+    pub fn profile(&self, name: &str) -> Result<LintProfile, Error> {
+        let mut profile = resolve_profile(&self.profiles, name, |name| {
+            Error::UnknownConfigurationProfile {
+                name: name.to_string(),
+                available: self.profiles.keys().cloned().collect(),
+            }
+        })?;
+
+        let mut config = self.clone();
+        config.profiles.clear();
+        config.merge(profile.config);
+        profile.config = config;
+
+        Ok(profile)
+    }
 }
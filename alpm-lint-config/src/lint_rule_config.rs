@@ -68,11 +68,14 @@ macro_rules! create_lint_rule_config {
         use std::collections::BTreeMap;
 
         /// Configuration struct that contains all options to adjust ALPM-related linting rules.
+        ///
+        /// Options that are not set use their default value.
         #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+        #[serde(default)]
         pub struct LintRuleConfiguration {
             $(
                 $(#[doc = $doc])+
-                pub $name: $type
+                pub $name: $type,
             )*
         }
 
@@ -116,6 +119,36 @@ macro_rules! create_lint_rule_config {
                 map
             }
 
+            /// Merges the options of `other` into this configuration.
+            ///
+            /// Only options of `other` that differ from their default value are merged, as all
+            /// other options are considered to be unset.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use alpm_lint_config::LintRuleConfiguration;
+            ///
+            /// let mut options = LintRuleConfiguration {
+            ///     allowed_checksum_algorithms: vec!["sha256".to_string()],
+            ///     ..Default::default()
+            /// };
+            /// options.merge(&LintRuleConfiguration::default());
+            /// assert_eq!(options.allowed_checksum_algorithms, vec!["sha256"]);
+            ///
+            /// options.merge(&LintRuleConfiguration {
+            ///     allowed_checksum_algorithms: vec!["sha512".to_string()],
+            ///     ..Default::default()
+            /// });
+            /// assert_eq!(options.allowed_checksum_algorithms, vec!["sha512"]);
+            /// ```
+            pub fn merge(&mut self, other: &Self) {
+                $(
+                    if other.$name != defaults::$name() {
+                        self.$name = other.$name.clone();
+                    }
+                )*
+            }
         }
 
         /// An enum with variants representing the literal field names of [`LintRuleConfiguration`].
@@ -131,8 +164,8 @@ macro_rules! create_lint_rule_config {
         pub enum LintRuleConfigurationOptionName {
             $(
                 $(#[doc = $doc])+
-                $name
-            )*,
+                $name,
+            )*
         }
     }
 }
@@ -152,6 +185,21 @@ pub struct LintRuleConfigurationOption {
 }
 
 create_lint_rule_config! {
-    /// This is an example option
-    example_option: String = "Remove this as soon as the first proper option is added :)",
+    /// The checksum algorithms that may be used in PKGBUILD files.
+    ///
+    /// Checksum variables (e.g. `md5sums` or `sha256sums_x86_64`) of any other algorithm are
+    /// considered deprecated.
+    allowed_checksum_algorithms: Vec<String> = vec![
+        "b2".to_string(),
+        "sha224".to_string(),
+        "sha256".to_string(),
+        "sha384".to_string(),
+        "sha512".to_string(),
+    ],
+    /// The packages that are expected to be present in every build environment.
+    ///
+    /// Packages of this list that are installed in a build environment (see the `installed`
+    /// field of BUILDINFO data) are not required to be declared as build dependencies.
+    /// This is commonly used for the members of the `base-devel` package group.
+    build_environment_packages: Vec<String> = Vec::new(),
 }
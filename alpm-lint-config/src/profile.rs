@@ -0,0 +1,303 @@
+use std::{collections::BTreeMap, fs::File, io::Read, path::Path};
+
+use fluent_i18n::t;
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, LintConfiguration};
+
+/// A named set of lint settings, e.g. for a specific distribution release or context.
+///
+/// Profiles are distributed as part of a [`LintProfileBundle`] or defined in the
+/// [`LintConfiguration::profiles`].
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct LintProfile {
+    /// An optional description of the profile.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The name of a profile whose settings this profile inherits.
+    ///
+    /// The settings of this profile are merged into the ones of the inherited profile (see
+    /// [`LintConfiguration::merge`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherits: Option<String>,
+    /// The pinned set of lint rules, identified by their scoped names.
+    ///
+    /// If set, only the lint rules in this list may run.
+    /// This ensures that lint rules added in newer versions of the linter are not run
+    /// automatically.
+    /// If [`None`], all lint rules may run.
+    #[serde(default)]
+    pub rules: Option<Vec<String>>,
+    /// The lint configuration of the profile.
+    #[serde(default)]
+    pub config: LintConfiguration,
+}
+
+impl LintProfile {
+    /// Returns the [`LintConfiguration`] of the profile for a set of `available_rules`.
+    ///
+    /// If the profile pins its set of lint rules, all lint rules in `available_rules` that are not
+    /// part of the pinned set are added to [`LintConfiguration::disabled_rules`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_lint_config::{LintConfiguration, LintProfile};
+    ///
+    /// let profile = LintProfile {
+    ///     description: None,
+    ///     inherits: None,
+    ///     rules: Some(vec!["source_info::no_architecture".to_string()]),
+    ///     config: LintConfiguration::default(),
+    /// };
+    ///
+    /// let config = profile.configuration([
+    ///     "source_info::no_architecture",
+    ///     "source_info::unsafe_checksum",
+    /// ]);
+    /// assert_eq!(config.disabled_rules, ["source_info::unsafe_checksum"]);
+    /// ```
+    pub fn configuration<'a>(
+        &self,
+        available_rules: impl IntoIterator<Item = &'a str>,
+    ) -> LintConfiguration {
+        let mut config = self.config.clone();
+        if let Some(rules) = &self.rules {
+            for rule in available_rules {
+                if !rules.iter().any(|pinned| pinned == rule)
+                    && !config
+                        .disabled_rules
+                        .iter()
+                        .any(|disabled| disabled == rule)
+                {
+                    config.disabled_rules.push(rule.to_string());
+                }
+            }
+        }
+        config
+    }
+
+    /// Returns the profile, that results from `self` inheriting the settings of `parent`.
+    ///
+    /// The description and pinned set of lint rules of `self` take precedence over the ones of
+    /// `parent`, while the configuration of `self` is merged into the one of `parent`.
+    fn inherit(self, parent: LintProfile) -> LintProfile {
+        let mut config = parent.config;
+        config.merge(self.config);
+
+        LintProfile {
+            description: self.description.or(parent.description),
+            inherits: parent.inherits,
+            rules: self.rules.or(parent.rules),
+            config,
+        }
+    }
+}
+
+/// Returns the [`LintProfile`] called `name` in `profiles`, including all inherited settings.
+///
+/// The settings of the profiles named in [`LintProfile::inherits`] are inherited recursively.
+/// The error returned for unknown profiles is created by `unknown`.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - `profiles` contains no profile called `name`, or no profile that is inherited,
+/// - or the inheritance of profiles is cyclic.
+pub(crate) fn resolve_profile(
+    profiles: &BTreeMap<String, LintProfile>,
+    name: &str,
+    unknown: impl Fn(&str) -> Error,
+) -> Result<LintProfile, Error> {
+    let mut chain = vec![name.to_string()];
+    let mut profile = profiles.get(name).ok_or_else(|| unknown(name))?.clone();
+
+    while let Some(parent_name) = profile.inherits.take() {
+        if chain.contains(&parent_name) {
+            chain.push(parent_name);
+            return Err(Error::CyclicProfileInheritance { chain });
+        }
+
+        let parent = profiles
+            .get(&parent_name)
+            .ok_or_else(|| unknown(&parent_name))?;
+        chain.push(parent_name);
+        profile = profile.inherit(parent.clone());
+    }
+
+    Ok(profile)
+}
+
+/// A versioned bundle of [`LintProfile`]s.
+///
+/// Bundles allow distributions to ship stable lint settings for a release cycle, so that the lint
+/// behavior does not change across upgrades of the linter.
+/// Bundles can be provided as TOML or JSON files.
+///
+/// # Examples
+///
+/// ```
+/// use alpm_lint_config::LintProfileBundle;
+///
+/// # fn main() -> Result<(), alpm_lint_config::Error> {
+/// let bundle = LintProfileBundle::from_toml_str(
+///     r#"
+/// version = "2025.10"
+///
+/// [profiles.stable]
+/// description = "The lint rules used for the stable release"
+/// rules = ["source_info::duplicate_architecture", "source_info::no_architecture"]
+/// "#,
+/// )?;
+///
+/// let profile = bundle.profile("stable")?;
+/// assert_eq!(profile.rules.as_ref().map(Vec::len), Some(2));
+/// assert!(bundle.profile("testing").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LintProfileBundle {
+    /// The version of the bundle (e.g. the distribution release it belongs to).
+    pub version: String,
+    /// The profiles of the bundle by name.
+    #[serde(default)]
+    pub profiles: BTreeMap<String, LintProfile>,
+}
+
+impl LintProfileBundle {
+    /// Creates a [`LintProfileBundle`] from a TOML string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` cannot be parsed as a [`LintProfileBundle`].
+    pub fn from_toml_str(s: &str) -> Result<Self, Error> {
+        Ok(toml::from_str(s)?)
+    }
+
+    /// Creates a [`LintProfileBundle`] from a JSON string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_lint_config::LintProfileBundle;
+    ///
+    /// # fn main() -> Result<(), alpm_lint_config::Error> {
+    /// let bundle = LintProfileBundle::from_json_str(
+    ///     r#"{
+    ///   "version": "2025.10",
+    ///   "profiles": {
+    ///     "stable": {
+    ///       "rules": ["source_info::no_architecture"]
+    ///     }
+    ///   }
+    /// }"#,
+    /// )?;
+    ///
+    /// assert_eq!(bundle.version, "2025.10");
+    /// assert!(bundle.profile("stable")?.config.disabled_rules.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` cannot be parsed as a [`LintProfileBundle`].
+    pub fn from_json_str(s: &str) -> Result<Self, Error> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    /// Loads a [`LintProfileBundle`] from a file.
+    ///
+    /// Files with a `.json` extension are parsed as JSON, all other files are parsed as TOML.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    ///
+    /// - the file at `path` cannot be opened for reading,
+    /// - the file contents cannot be read,
+    /// - or the file contents cannot be parsed as a [`LintProfileBundle`].
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        let mut file = File::open(path).map_err(|source| Error::IoPath {
+            path: path.to_path_buf(),
+            context: t!("error-io-open-profile-bundle"),
+            source,
+        })?;
+
+        let mut buf = String::new();
+        file.read_to_string(&mut buf)
+            .map_err(|source| Error::IoPath {
+                path: path.to_path_buf(),
+                context: t!("error-io-read-profile-bundle"),
+                source,
+            })?;
+
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            Self::from_json_str(&buf)
+        } else {
+            Self::from_toml_str(&buf)
+        }
+    }
+
+    /// Returns the [`LintProfile`] called `name`.
+    ///
+    /// The profile inherits the settings of the profile it names in [`LintProfile::inherits`], if
+    /// any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_lint_config::{LintLevel, LintProfileBundle};
+    ///
+    /// # fn main() -> Result<(), alpm_lint_config::Error> {
+    /// let bundle = LintProfileBundle::from_toml_str(
+    ///     r#"
+    /// version = "2025.10"
+    ///
+    /// [profiles.official-repos]
+    /// rules = ["source_info::duplicate_architecture", "source_info::no_architecture"]
+    ///
+    /// [profiles.strict]
+    /// inherits = "official-repos"
+    ///
+    /// [profiles.strict.config]
+    /// levels = { "source_info::duplicate_architecture" = "Error" }
+    ///
+    /// [profiles.cyclic]
+    /// inherits = "cyclic"
+    /// "#,
+    /// )?;
+    ///
+    /// let profile = bundle.profile("strict")?;
+    /// assert_eq!(profile.rules.as_ref().map(Vec::len), Some(2));
+    /// assert_eq!(
+    ///     profile
+    ///         .config
+    ///         .levels
+    ///         .get("source_info::duplicate_architecture"),
+    ///     Some(&LintLevel::Error)
+    /// );
+    /// assert!(bundle.profile("cyclic").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the bundle contains no profile called `name`, or no profile that is inherited,
+    /// - or the inheritance of profiles is cyclic.
+    pub fn profile(&self, name: &str) -> Result<LintProfile, Error> {
+        resolve_profile(&self.profiles, name, |name| Error::UnknownProfile {
+            name: name.to_string(),
+            version: self.version.clone(),
+            available: self.profiles.keys().cloned().collect(),
+        })
+    }
+}
@@ -0,0 +1,147 @@
+//! Baselines of known lint issues.
+//!
+//! A [`Baseline`] records the lint issues that exist at a given point in time (e.g. when adopting
+//! `alpm-lint` for a large existing repository).
+//! When linting with a baseline, only issues that are not part of it are reported.
+
+use serde::{Deserialize, Serialize};
+
+use crate::issue::{LintIssue, LintIssueType};
+
+/// A known lint issue, that is recorded in a [`Baseline`].
+///
+/// Only the data identifying an issue is recorded, so that changes to e.g. the help text or the
+/// level of a lint rule do not invalidate a baseline.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct BaselineEntry {
+    /// The name of the lint rule that triggered the issue.
+    pub lint_rule: String,
+    /// The type of issue that is encountered.
+    pub issue_type: LintIssueType,
+}
+
+impl From<&LintIssue> for BaselineEntry {
+    fn from(issue: &LintIssue) -> Self {
+        Self {
+            lint_rule: issue.lint_rule.clone(),
+            issue_type: issue.issue_type.clone(),
+        }
+    }
+}
+
+/// A set of known lint issues, that are suppressed when linting.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Baseline {
+    /// The recorded lint issues.
+    pub issues: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    /// Creates a new [`Baseline`] that records all `issues`.
+    pub fn from_issues(issues: &[LintIssue]) -> Self {
+        Self {
+            issues: issues.iter().map(BaselineEntry::from).collect(),
+        }
+    }
+
+    /// Returns all issues of `issues` that are not recorded in the [`Baseline`].
+    ///
+    /// Each recorded entry suppresses at most one issue.
+    /// If an issue is encountered more often than it is recorded, the additional occurrences are
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_lint::{
+    ///     Level,
+    ///     LintScope,
+    ///     baseline::Baseline,
+    ///     issue::{LintIssue, SourceInfoIssue},
+    /// };
+    ///
+    /// let issue = LintIssue {
+    ///     lint_rule: "source_info::duplicate_architecture".to_string(),
+    ///     level: Level::Warn,
+    ///     help_text: String::new(),
+    ///     scope: LintScope::SourceInfo,
+    ///     issue_type: SourceInfoIssue::MissingField {
+    ///         field_name: "arch".to_string(),
+    ///     }
+    ///     .into(),
+    ///     links: Default::default(),
+    ///     suppressed: false,
+    ///     suggestion: None,
+    /// };
+    /// let baseline = Baseline::from_issues(&[issue.clone()]);
+    ///
+    /// assert!(baseline.new_issues(vec![issue.clone()]).is_empty());
+    /// assert_eq!(baseline.new_issues(vec![issue.clone(), issue]).len(), 1);
+    /// ```
+    pub fn new_issues(&self, issues: Vec<LintIssue>) -> Vec<LintIssue> {
+        let mut known: Vec<&BaselineEntry> = self.issues.iter().collect();
+        issues
+            .into_iter()
+            .filter(|issue| {
+                let Some(index) = known.iter().position(|entry| {
+                    entry.lint_rule == issue.lint_rule && entry.issue_type == issue.issue_type
+                }) else {
+                    return true;
+                };
+                known.swap_remove(index);
+                false
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{Level, LintScope, issue::SourceInfoIssue};
+
+    fn issue(lint_rule: &str, field_name: &str) -> LintIssue {
+        LintIssue {
+            lint_rule: lint_rule.to_string(),
+            level: Level::Warn,
+            help_text: "help".to_string(),
+            scope: LintScope::SourceInfo,
+            issue_type: SourceInfoIssue::MissingField {
+                field_name: field_name.to_string(),
+            }
+            .into(),
+            links: Default::default(),
+            suppressed: false,
+            suggestion: None,
+        }
+    }
+
+    /// Ensures that only issues, which are not recorded in the baseline are returned.
+    #[rstest]
+    #[case::empty_baseline(&[], &[("rule", "arch")], 1)]
+    #[case::known_issue(&[("rule", "arch")], &[("rule", "arch")], 0)]
+    #[case::other_rule(&[("rule", "arch")], &[("other_rule", "arch")], 1)]
+    #[case::other_issue_type(&[("rule", "arch")], &[("rule", "pkgver")], 1)]
+    #[case::additional_occurrence(&[("rule", "arch")], &[("rule", "arch"), ("rule", "arch")], 1)]
+    #[case::fixed_issue(&[("rule", "arch"), ("rule", "pkgver")], &[("rule", "arch")], 0)]
+    fn new_issues_of_baseline(
+        #[case] recorded: &[(&str, &str)],
+        #[case] encountered: &[(&str, &str)],
+        #[case] expected: usize,
+    ) {
+        let recorded: Vec<LintIssue> = recorded
+            .iter()
+            .map(|(rule, field)| issue(rule, field))
+            .collect();
+        let encountered: Vec<LintIssue> = encountered
+            .iter()
+            .map(|(rule, field)| issue(rule, field))
+            .collect();
+
+        let baseline = Baseline::from_issues(&recorded);
+
+        assert_eq!(baseline.new_issues(encountered).len(), expected);
+    }
+}
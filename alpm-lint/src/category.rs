@@ -0,0 +1,22 @@
+//! Categorization of lint rules.
+
+use serde::{Deserialize, Serialize};
+use strum::{Display as StrumDisplay, VariantArray};
+
+/// The category of a lint rule.
+///
+/// Categories describe the kind of problem a lint rule detects.
+/// Unlike [`LintGroup`](alpm_lint_config::LintGroup)s, categories do not affect whether a lint
+/// rule is run and are only used to present lint rules to users (e.g. in editors or on websites).
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StrumDisplay, VariantArray)]
+#[strum(serialize_all = "snake_case")]
+pub enum LintCategory {
+    /// Lint rules that detect invalid, contradicting or unusable data.
+    Correctness,
+    /// Lint rules that detect data which weakens the verification and authentication of sources.
+    Security,
+    /// Lint rules that detect data which prevents reproducible builds.
+    Reproducibility,
+    /// Lint rules that detect redundant or unidiomatic data.
+    Style,
+}
@@ -1,6 +1,6 @@
 //! Command-line argument handling for `alpm-lint`.
 
-use std::path::PathBuf;
+use std::{num::NonZeroUsize, path::PathBuf};
 
 use clap::{Parser, ValueEnum};
 use strum::Display;
@@ -17,6 +17,10 @@ pub enum LintOutputFormat {
     /// The JSON output format.
     #[strum(to_string = "json")]
     Json,
+
+    /// The SARIF 2.1.0 output format for code review integrations.
+    #[strum(to_string = "sarif")]
+    Sarif,
 }
 
 /// Output format for all subcommands that only output data.
@@ -54,6 +58,12 @@ pub enum Command {
     ///
     /// By default, `alpm-lint` will try to determine the current linting scope based on the
     /// provided filename or on available files in the provided directory.
+    /// With `--recursive`, all package source repositories and package files in the provided
+    /// directory tree are linted in parallel.
+    ///
+    /// Human readable output ends with a summary of all issues, which lists the number of issues
+    /// per level and the lint rules and packages with the most issues.
+    /// When using `--quiet`, only this summary is printed.
     Check {
         /// An optional path to a file or directory to be linted.
         #[arg(value_name = "DIR")]
@@ -80,7 +90,8 @@ pub enum Command {
         /// The level of lints to consider.
         ///
         /// Any lints with this level and above (more severe) will be shown.
-        /// If such lints are found, the command will return with an non-zero exit code.
+        /// If such lints are found, the command will return with an non-zero exit code (see
+        /// `--max-level`).
         #[arg(short,
             long,
             value_name = "LEVEL",
@@ -91,15 +102,88 @@ pub enum Command {
         /// Supply a lint config path.
         ///
         /// This overwrites any options from the project wide configuration file.
-        #[arg(short, long, value_name = "LEVEL")]
+        #[arg(short, long, value_name = "LEVEL", conflicts_with = "profile_bundle")]
         config: Option<PathBuf>,
 
+        /// Supply a lint profile bundle path.
+        ///
+        /// A lint profile bundle is a TOML or JSON file that contains versioned lint profiles
+        /// (e.g. for a specific distribution release).
+        /// The profile to use is selected using `--profile`.
+        #[arg(long, value_name = "FILE", requires = "profile")]
+        profile_bundle: Option<PathBuf>,
+
+        /// The name of the profile to use.
+        ///
+        /// The profile is selected from the lint profile bundle (see `--profile-bundle`) or, if
+        /// none is provided, from the profiles of the lint config (see `--config`).
+        /// Profiles may inherit the settings of other profiles.
+        /// If the profile pins a set of lint rules, all other lint rules are disabled.
+        #[arg(long, value_name = "NAME")]
+        profile: Option<String>,
+
         /// Optional output file path. If not provided, output goes to stdout.
         #[arg(short, long, value_name = "FILE")]
         output: Option<PathBuf>,
+
+        /// Automatically fix issues of lint rules that support it.
+        ///
+        /// Non-conflicting fixes are applied to the linted files in place and a summary of the
+        /// applied fixes is written to stderr.
+        /// Afterwards, only the remaining issues are reported.
+        #[arg(long)]
+        fix: bool,
+
+        /// Supply a baseline file path.
+        ///
+        /// Issues recorded in the baseline file are suppressed, so that only new issues are
+        /// reported.
+        #[arg(long, value_name = "FILE")]
+        baseline: Option<PathBuf>,
+
+        /// Record all current issues in the baseline file, instead of reporting them.
+        ///
+        /// Requires `--baseline`.
+        #[arg(long, requires = "baseline")]
+        write_baseline: bool,
+
+        /// Also report issues that are suppressed by inline suppression comments.
+        ///
+        /// Suppression comments in the form of `# alpm-lint: allow(rule-name)` suppress a lint
+        /// rule for the following field or section of a PKGBUILD or SRCINFO file.
+        /// Suppressed issues are marked as such and do not cause a non-zero exit code.
+        #[arg(long)]
+        show_suppressed: bool,
+
+        /// Lint all package source repositories and package files in a directory tree.
+        ///
+        /// Each directory for which a lint scope can be detected is linted on its own, while all
+        /// other directories are searched for further package source repositories and package
+        /// files.
+        /// Issues are reported per linted directory or file, followed by a summary of all issues.
+        #[arg(short, long, conflicts_with_all = ["scope", "fix", "baseline"])]
+        recursive: bool,
+
+        /// The number of directories and files to lint in parallel.
+        ///
+        /// Defaults to the available parallelism of the system.
+        #[arg(short, long, value_name = "JOBS", requires = "recursive")]
+        jobs: Option<NonZeroUsize>,
+
+        /// The least severe level of lints that leads to a non-zero exit code.
+        ///
+        /// Lints that are less severe than this level are still shown (see `--level`), but do
+        /// not cause a non-zero exit code.
+        #[arg(long, value_name = "LEVEL", default_value_t = Level::Suggest)]
+        max_level: Level,
     },
 
     /// Return the definition of all lint rules as structured data.
+    ///
+    /// Each lint rule is identified by its stable scoped name (e.g.
+    /// `source_info::duplicate_architecture`) and provides its scope, category, default level,
+    /// documentation, example, documentation URL and whether its issues can be fixed
+    /// automatically.
     Rules {
         /// The output format to use.
         #[arg(
@@ -149,7 +233,8 @@ pub enum Command {
         output: Option<PathBuf>,
     },
 
-    /// Return metadata about available lint groups, scopes, and levels for static site generators.
+    /// Return metadata about available lint groups, scopes, levels, and categories for static site
+    /// generators.
     ///
     /// By default the metadata is returned on stdout.
     Meta {
@@ -1,15 +1,32 @@
-use std::{env::current_dir, fs::File, io::Write, path::PathBuf};
+use std::{
+    collections::BTreeMap,
+    env::current_dir,
+    fs::{File, read_to_string, write},
+    io::Write,
+    num::NonZeroUsize,
+    panic,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread::{self, available_parallelism},
+};
 
 use alpm_lint::{
     Error,
     Level,
+    LintCategory,
+    LintRule,
     LintScope,
     LintStore,
     Resources,
+    baseline::Baseline,
     cli::{LintOutputFormat, OutputFormat},
-    issue::display::LintIssueDisplay,
+    fix::{Edit, apply_edits},
+    issue::{LintIssue, display::LintIssueDisplay, sarif::SarifLog, summary::LintSummary},
+    suppression::{Suppression, apply_suppressions, parse_suppressions},
+    tree::{LintTarget, find_lint_targets},
 };
-use alpm_lint_config::{LintConfiguration, LintGroup, LintRuleConfiguration};
+use alpm_lint_config::{LintConfiguration, LintGroup, LintProfileBundle, LintRuleConfiguration};
+use alpm_types::{MetadataFileName, PKGBUILD_FILE_NAME, SRCINFO_FILE_NAME};
 use log::debug;
 use serde::Serialize;
 use strum::VariantArray;
@@ -72,34 +89,285 @@ fn serialize_output<T: Serialize>(
     Ok(output)
 }
 
+/// Returns the path of the file called `file_name`, that is linted at `path` in `scope`.
+///
+/// Returns [`None`] if no file called `file_name` is linted in `scope`, or if the linted file is
+/// contained in a package file.
+fn linted_file_path(path: &Path, scope: LintScope, file_name: &str) -> Option<PathBuf> {
+    let canonical_file_name = match scope {
+        LintScope::SourceRepository | LintScope::Package => return Some(path.join(file_name)),
+        // The files in a package file cannot be linted individually.
+        LintScope::PackageArtifact => return None,
+        LintScope::BuildInfo => MetadataFileName::BuildInfo.to_string(),
+        LintScope::PackageBuild => PKGBUILD_FILE_NAME.to_string(),
+        LintScope::PackageInfo => MetadataFileName::PackageInfo.to_string(),
+        LintScope::SourceInfo => SRCINFO_FILE_NAME.to_string(),
+    };
+    if file_name != canonical_file_name {
+        return None;
+    }
+
+    // Single files may be linted with a non-canonical file name.
+    if path.is_dir() {
+        Some(path.join(file_name))
+    } else {
+        Some(path.to_path_buf())
+    }
+}
+
+/// Returns the inline [`Suppression`]s of the [PKGBUILD] and [SRCINFO] files linted at `path` in
+/// `scope`.
+///
+/// # Errors
+///
+/// Returns an error if a linted file cannot be read.
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+fn gather_suppressions(path: &Path, scope: LintScope) -> Result<Vec<Suppression>, Error> {
+    let mut suppressions = Vec::new();
+    for file_name in [PKGBUILD_FILE_NAME, SRCINFO_FILE_NAME] {
+        let Some(file_path) = linted_file_path(path, scope, file_name) else {
+            continue;
+        };
+        if !file_path.is_file() {
+            continue;
+        }
+
+        let content = read_to_string(&file_path).map_err(|source| Error::IoPath {
+            path: file_path.clone(),
+            context: "reading file for suppression comments",
+            source,
+        })?;
+        suppressions.extend(parse_suppressions(&content));
+    }
+
+    Ok(suppressions)
+}
+
+/// Returns `path` or, if it is [`None`], the current working directory.
+///
+/// # Errors
+///
+/// Returns an error if the current working directory cannot be detected.
+fn path_or_current_dir(path: Option<PathBuf>) -> Result<PathBuf, Error> {
+    match path {
+        Some(path) => Ok(path),
+        None => current_dir().map_err(|source| Error::Io {
+            context: "detect current working directory",
+            source,
+        }),
+    }
+}
+
+/// Loads the [`LintConfiguration`].
+///
+/// The lint configuration is read from `config_path`, or created from a named profile in a lint
+/// profile bundle at `profile_bundle` (see [`LintProfileBundle`]).
+/// If only `profile` is provided, the named profile is selected from the lint configuration
+/// instead (see [`LintConfiguration::profile`]).
+/// If neither is provided, the default configuration is used.
+///
+/// # Errors
+///
+/// Returns an error if the lint configuration or lint profile bundle cannot be read, or if the
+/// profile cannot be resolved.
+fn load_config(
+    config_path: Option<PathBuf>,
+    profile_bundle: Option<PathBuf>,
+    profile: Option<String>,
+) -> Result<LintConfiguration, Error> {
+    let default_store = LintStore::new(LintConfiguration::default());
+    let available_rules = default_store.lint_rules().keys().map(String::as_str);
+
+    let config = match (config_path, profile_bundle, profile) {
+        (_, Some(bundle_path), Some(name)) => {
+            let bundle = LintProfileBundle::from_path(&bundle_path)?;
+            debug!(
+                "Using profile '{name}' of lint profile bundle {}",
+                bundle.version
+            );
+            bundle.profile(&name)?.configuration(available_rules)
+        }
+        (config_path, _, profile) => {
+            let config = match config_path {
+                Some(path) => LintConfiguration::from_path(&path)?,
+                None => LintConfiguration::default(),
+            };
+            match profile {
+                Some(name) => {
+                    debug!("Using profile '{name}' of the lint configuration");
+                    config.profile(&name)?.configuration(available_rules)
+                }
+                None => config,
+            }
+        }
+    };
+
+    Ok(config)
+}
+
+/// Runs all lint rules of `store` that are enabled for `scope` and `level` on `resources`.
+///
+/// Issues that are suppressed by inline suppression comments in the files at `path` are marked
+/// as suppressed (see [`alpm_lint::suppression`]).
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - a lint rule fails,
+/// - or a linted file cannot be read for suppression comments.
+fn lint(
+    store: &LintStore,
+    path: &Path,
+    scope: LintScope,
+    level: Level,
+    resources: &Resources,
+) -> Result<Vec<LintIssue>, Error> {
+    let mut issues = Vec::new();
+    let lint_rules = store.filtered_lint_rules(&scope, level);
+
+    debug!("Start of linting {path:?}.");
+    for (name, rule) in lint_rules {
+        debug!("Running rule: '{name}'");
+        rule.run(resources, &mut issues)?;
+    }
+
+    let suppressions = gather_suppressions(path, scope)?;
+    let suppressed_count = apply_suppressions(&mut issues, &suppressions);
+    debug!("Suppressed {suppressed_count} issues with inline suppression comments.");
+
+    Ok(issues)
+}
+
+/// Returns whether any of `issues` is at least as severe as `max_level`.
+fn fails(issues: &[LintIssue], max_level: Level) -> bool {
+    issues.iter().any(|issue| issue.level <= max_level)
+}
+
+/// Applies the fixes of all lint rules for `resources` to the files at `path`.
+///
+/// Only lint rules that are enabled for `scope` and `level` are considered.
+/// A summary of the applied fixes is written to stderr.
+///
+/// Returns `true` if any fixes have been applied.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - a lint rule cannot create its fixes,
+/// - or a file cannot be read or written.
+fn apply_fixes(
+    store: &LintStore,
+    path: &Path,
+    scope: LintScope,
+    level: Level,
+    resources: &Resources,
+) -> Result<bool, Error> {
+    let mut edits_by_file: BTreeMap<String, Vec<Edit>> = BTreeMap::new();
+    for (name, rule) in store.filtered_lint_rules(&scope, level) {
+        for edit in rule.fix(resources)? {
+            debug!("Lint rule '{name}' proposes fix: {edit:?}");
+            edits_by_file
+                .entry(edit.file_name.clone())
+                .or_default()
+                .push(edit);
+        }
+    }
+
+    let mut fixed = false;
+    for (file_name, edits) in edits_by_file {
+        let Some(file_path) = linted_file_path(path, scope, &file_name) else {
+            eprintln!(
+                "Skipped {} fixes for {file_name}, which is not linted in scope {scope}.",
+                edits.len()
+            );
+            continue;
+        };
+
+        let content = read_to_string(&file_path).map_err(|source| Error::IoPath {
+            path: file_path.clone(),
+            context: "reading file to fix",
+            source,
+        })?;
+        let result = apply_edits(&content, &edits);
+        if !result.applied.is_empty() {
+            write(&file_path, result.content).map_err(|source| Error::IoPath {
+                path: file_path.clone(),
+                context: "writing fixed file",
+                source,
+            })?;
+            fixed = true;
+        }
+
+        eprintln!(
+            "Applied {} fixes to {file_path:?} ({} skipped):",
+            result.applied.len(),
+            result.skipped.len()
+        );
+        for edit in result.applied {
+            match edit.replacement {
+                Some(replacement) => eprintln!("  - replaced '{}' with '{replacement}'", edit.line),
+                None => eprintln!("  - removed '{}'", edit.line),
+            }
+        }
+    }
+
+    Ok(fixed)
+}
+
 /// Runs a lint check.
 ///
 /// If not provided, the `path` and `scope` are automatically detected.
 /// Defaults to the current working directory if no `path` is provided.
+///
+/// The lint configuration is loaded from `config_path`, or from a named `profile` in the lint
+/// configuration or a lint profile bundle at `profile_bundle` (see [`LintProfileBundle`]).
+/// If neither is provided, the default configuration is used.
+///
+/// If `fix` is `true`, the fixes of all lint rules that support them are applied to the linted
+/// files first and only the remaining issues are reported.
+///
+/// Issues are suppressed by inline suppression comments in the linted [PKGBUILD] and [SRCINFO]
+/// files (see [`alpm_lint::suppression`]).
+/// Suppressed issues are only reported if `show_suppressed` is `true` and never lead to a
+/// non-zero exit code.
+///
+/// If `baseline` is provided, all issues recorded in the [`Baseline`] file are suppressed.
+/// If `write_baseline` is `true`, all issues are instead recorded in a new [`Baseline`] file at
+/// `baseline`.
+///
+/// Human readable output is concluded by a [`LintSummary`] of all issues.
+/// If `quiet` is `true`, only the [`LintSummary`] is written for human readable output.
+///
+/// Exits with a non-zero exit code if any issue is at least as severe as `max_level`.
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+#[allow(clippy::too_many_arguments)]
 pub fn check(
     config_path: Option<PathBuf>,
+    profile_bundle: Option<PathBuf>,
+    profile: Option<String>,
     path: Option<PathBuf>,
     scope: Option<LintScope>,
     level: Level,
     format: LintOutputFormat,
     output: Option<PathBuf>,
     pretty: bool,
+    quiet: bool,
+    fix: bool,
+    baseline: Option<PathBuf>,
+    write_baseline: bool,
+    show_suppressed: bool,
+    max_level: Level,
 ) -> Result<(), Error> {
-    let path = match path {
-        Some(path) => path,
-        None => current_dir().map_err(|source| Error::Io {
-            context: "detect current working directory",
-            source,
-        })?,
-    };
+    let path = path_or_current_dir(path)?;
     debug!("Using path: {path:?}");
 
-    // Load the config or fall back to the default config.
-    let config = if let Some(path) = config_path {
-        LintConfiguration::from_path(&path)?
-    } else {
-        LintConfiguration::default()
-    };
+    let config = load_config(config_path, profile_bundle, profile)?;
 
     // Get or detect the scope.
     let scope = match scope {
@@ -108,38 +376,117 @@ pub fn check(
     };
     debug!("Detected scope: {scope:?}");
 
-    let resources = Resources::gather(&path, scope)?;
+    let mut resources = Resources::gather(&path, scope)?;
     debug!("Resources have been gathered.");
 
     let store = LintStore::new(config);
 
-    let mut issues = Vec::new();
-    let lint_rules = store.filtered_lint_rules(&scope, level);
+    if fix && apply_fixes(&store, &path, scope, level, &resources)? {
+        // Lint the fixed files.
+        resources = Resources::gather(&path, scope)?;
+        debug!("Resources have been gathered after applying fixes.");
+    }
 
-    debug!("Start of linting.");
-    for (name, rule) in lint_rules {
-        debug!("Running rule: '{name}'");
-        rule.run(&resources, &mut issues)?;
+    let issues = lint(&store, &path, scope, level, &resources)?;
+    let (suppressed_issues, mut issues): (Vec<_>, Vec<_>) =
+        issues.into_iter().partition(|issue| issue.suppressed);
+
+    if let Some(baseline_path) = baseline {
+        if write_baseline {
+            let baseline = Baseline::from_issues(&issues);
+            let content = serialize_output(&baseline, OutputFormat::Json, true, "baseline")?;
+            write(&baseline_path, content).map_err(|source| Error::IoPath {
+                path: baseline_path.clone(),
+                context: "writing baseline file",
+                source,
+            })?;
+            eprintln!(
+                "Recorded {} issues in baseline {}.",
+                baseline.issues.len(),
+                baseline_path.display()
+            );
+            return Ok(());
+        }
+
+        let content = read_to_string(&baseline_path).map_err(|source| Error::IoPath {
+            path: baseline_path.clone(),
+            context: "reading baseline file",
+            source,
+        })?;
+        let baseline: Baseline = serde_json::from_str(&content).map_err(|error| Error::Json {
+            error,
+            context: format!("baseline file {}", baseline_path.display()),
+        })?;
+        let issue_count = issues.len();
+        issues = baseline.new_issues(issues);
+        debug!(
+            "Suppressed {} issues recorded in the baseline.",
+            issue_count - issues.len()
+        );
     }
 
-    let found_issues = !issues.is_empty();
+    let found_issues = fails(&issues, max_level);
+    let summary = LintSummary::new(&issues);
+    if show_suppressed {
+        issues.extend(suppressed_issues);
+    }
 
     debug!("Using output format {format:?}.");
     let content = match format {
-        LintOutputFormat::Text => issues
-            .into_iter()
-            .map(|issue| {
-                let display: LintIssueDisplay = issue.into();
-                display.to_string()
-            })
-            .collect::<Vec<_>>()
-            .join("\n"),
-        _ => {
-            let output_format = match format {
-                LintOutputFormat::Text => unreachable!(),
-                LintOutputFormat::Json => OutputFormat::Json,
+        LintOutputFormat::Text => {
+            let mut displays = if quiet {
+                Vec::new()
+            } else {
+                issues
+                    .into_iter()
+                    .map(|issue| {
+                        let display: LintIssueDisplay = issue.into();
+                        display.to_string()
+                    })
+                    .collect::<Vec<_>>()
             };
-            serialize_output(issues, output_format, pretty, "lint issues")?
+            displays.push(summary.to_string());
+            displays.join("\n")
+        }
+        LintOutputFormat::Json => {
+            serialize_output(issues, OutputFormat::Json, pretty, "lint issues")?
+        }
+        LintOutputFormat::Sarif => {
+            let file_name = match scope {
+                LintScope::SourceRepository | LintScope::SourceInfo => {
+                    SRCINFO_FILE_NAME.to_string()
+                }
+                LintScope::PackageBuild => PKGBUILD_FILE_NAME.to_string(),
+                LintScope::Package | LintScope::PackageArtifact | LintScope::PackageInfo => {
+                    MetadataFileName::PackageInfo.to_string()
+                }
+                LintScope::BuildInfo => MetadataFileName::BuildInfo.to_string(),
+            };
+            // Issues in package files are not located in a text file, but in the package file.
+            let (file_path, file_content) = match linted_file_path(&path, scope, &file_name) {
+                Some(file_path) => {
+                    let file_content =
+                        read_to_string(&file_path).map_err(|source| Error::IoPath {
+                            path: file_path.clone(),
+                            context: "reading linted file",
+                            source,
+                        })?;
+                    (file_path, Some(file_content))
+                }
+                None => (path, None),
+            };
+
+            let lint_rules: Vec<&dyn LintRule> = store
+                .filtered_lint_rules(&scope, level)
+                .map(|(_, rule)| rule.as_ref())
+                .collect();
+            let log = SarifLog::new(
+                &issues,
+                &lint_rules,
+                &file_path.to_string_lossy(),
+                file_content.as_deref(),
+            );
+            serialize_output(log, OutputFormat::Json, pretty, "SARIF log")?
         }
     };
 
@@ -153,6 +500,186 @@ pub fn check(
     Ok(())
 }
 
+/// Lints a single [`LintTarget`] with all lint rules of `store` that are enabled for `level`.
+///
+/// # Errors
+///
+/// Returns an error if the resources of the target cannot be gathered or linting fails.
+fn lint_target(
+    store: &LintStore,
+    target: &LintTarget,
+    level: Level,
+) -> Result<Vec<LintIssue>, Error> {
+    let resources = Resources::gather(&target.path, target.scope)?;
+    lint(store, &target.path, target.scope, level, &resources)
+}
+
+/// Runs a lint check on all [`LintTarget`]s in the directory tree at `path`.
+///
+/// Defaults to the current working directory if no `path` is provided.
+/// The lint targets are detected with [`find_lint_targets`] and linted in parallel by up to
+/// `jobs` threads.
+/// If `jobs` is not provided, the available parallelism of the system is used.
+///
+/// The lint configuration is loaded the same way as for [`check`].
+/// Issues suppressed by inline suppression comments are only reported if `show_suppressed` is
+/// `true`.
+///
+/// Human readable output lists the issues per lint target and is concluded by a [`LintSummary`]
+/// of the issues of all lint targets.
+/// If `quiet` is `true`, only the [`LintSummary`] is written for human readable output.
+/// JSON output is a map of the paths of all lint targets to their issues.
+///
+/// Errors of individual lint targets are written to stderr.
+/// Exits with a non-zero exit code if linting any lint target fails or if any issue is at least as
+/// severe as `max_level`.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - the lint configuration cannot be loaded,
+/// - `format` is [`LintOutputFormat::Sarif`], which is not supported for directory trees,
+/// - the directory tree cannot be read,
+/// - or the output cannot be serialized or written.
+#[allow(clippy::too_many_arguments)]
+pub fn check_tree(
+    config_path: Option<PathBuf>,
+    profile_bundle: Option<PathBuf>,
+    profile: Option<String>,
+    path: Option<PathBuf>,
+    level: Level,
+    format: LintOutputFormat,
+    output: Option<PathBuf>,
+    pretty: bool,
+    quiet: bool,
+    show_suppressed: bool,
+    max_level: Level,
+    jobs: Option<NonZeroUsize>,
+) -> Result<(), Error> {
+    // A SARIF log only describes the issues of a single linted file.
+    if matches!(format, LintOutputFormat::Sarif) {
+        return Err(Error::UnsupportedTreeOutputFormat {
+            format: format.to_string(),
+        });
+    }
+
+    let path = path_or_current_dir(path)?;
+    debug!("Using path: {path:?}");
+
+    let config = load_config(config_path, profile_bundle, profile)?;
+
+    let targets = find_lint_targets(&path)?;
+    let jobs = jobs
+        .or_else(|| available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get)
+        .min(targets.len().max(1));
+    debug!(
+        "Linting {} targets with {jobs} parallel jobs.",
+        targets.len()
+    );
+
+    // Each worker takes the next unlinted target until all targets are linted.
+    let next_target = &AtomicUsize::new(0);
+    let (config, targets) = (&config, &targets);
+    let mut results: Vec<(usize, Result<Vec<LintIssue>, Error>)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs)
+            .map(|_| {
+                scope.spawn(move || {
+                    // A lint store cannot be shared between threads.
+                    let store = LintStore::new(config.clone());
+                    let mut results = Vec::new();
+                    loop {
+                        let index = next_target.fetch_add(1, Ordering::Relaxed);
+                        let Some(target) = targets.get(index) else {
+                            break;
+                        };
+                        debug!("Linting {:?} in scope {}.", target.path, target.scope);
+                        results.push((index, lint_target(&store, target, level)));
+                    }
+                    results
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|payload| panic::resume_unwind(payload))
+            })
+            .collect()
+    });
+    results.sort_by_key(|(index, _)| *index);
+
+    let mut failed = false;
+    let mut active_issues = Vec::new();
+    let mut issues_by_target = Vec::new();
+    for (target, (_, result)) in targets.iter().zip(results) {
+        let issues = match result {
+            Ok(issues) => issues,
+            Err(error) => {
+                eprintln!("Failed to lint {:?}:\n{error}", target.path);
+                failed = true;
+                continue;
+            }
+        };
+
+        let (suppressed_issues, mut issues): (Vec<_>, Vec<_>) =
+            issues.into_iter().partition(|issue| issue.suppressed);
+        failed |= fails(&issues, max_level);
+        active_issues.extend(issues.iter().cloned());
+        if show_suppressed {
+            issues.extend(suppressed_issues);
+        }
+        issues_by_target.push((target, issues));
+    }
+    let summary = LintSummary::new(&active_issues);
+
+    debug!("Using output format {format:?}.");
+    let content = match format {
+        LintOutputFormat::Text => {
+            let mut displays = Vec::new();
+            if !quiet {
+                for (target, issues) in issues_by_target {
+                    if issues.is_empty() {
+                        continue;
+                    }
+                    displays.push(format!("==> {}\n", target.path.display()));
+                    displays.extend(issues.into_iter().map(|issue| {
+                        let display: LintIssueDisplay = issue.into();
+                        display.to_string()
+                    }));
+                }
+            }
+            displays.push(summary.to_string());
+            displays.join("\n")
+        }
+        LintOutputFormat::Json => {
+            let issues_by_path: BTreeMap<String, Vec<LintIssue>> = issues_by_target
+                .into_iter()
+                .map(|(target, issues)| (target.path.to_string_lossy().to_string(), issues))
+                .collect();
+            serialize_output(issues_by_path, OutputFormat::Json, pretty, "lint issues")?
+        }
+        LintOutputFormat::Sarif => {
+            return Err(Error::UnsupportedTreeOutputFormat {
+                format: format.to_string(),
+            });
+        }
+    };
+
+    write_output(&content, output)?;
+
+    // Exit with code 1 if any target failed or there were any relevant lints.
+    if failed {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
 /// Writes the definition of all linting rules to output.
 ///
 /// Writes a map of [`SerializableLintRule`]s in serialized form.
@@ -187,7 +714,7 @@ pub fn options(
 
 /// Metadata information for static site generator integration.
 ///
-/// Contains all available lint groups, scopes, and levels that can be used
+/// Contains all available lint groups, scopes, levels, and categories that can be used
 /// to create dropdown fields in a static site generator.
 #[derive(Debug, Serialize)]
 pub struct Meta {
@@ -197,6 +724,8 @@ pub struct Meta {
     pub scopes: Vec<LintScope>,
     /// All available lint levels.
     pub levels: Vec<Level>,
+    /// All available lint categories.
+    pub categories: Vec<LintCategory>,
 }
 
 impl Default for Meta {
@@ -206,20 +735,21 @@ impl Default for Meta {
 }
 
 impl Meta {
-    /// Creates a new [`Meta`] instance with all available groups, scopes, and levels.
+    /// Creates a new [`Meta`] instance with all available groups, scopes, levels, and categories.
     ///
-    /// This function collects all enum variants for lint groups, scopes, and levels
+    /// This function collects all enum variants for lint groups, scopes, levels, and categories
     /// that can be used in configuration and CLI options.
     pub fn new() -> Self {
         Self {
             groups: LintGroup::VARIANTS.to_vec(),
             scopes: LintScope::VARIANTS.to_vec(),
             levels: Level::VARIANTS.to_vec(),
+            categories: LintCategory::VARIANTS.to_vec(),
         }
     }
 }
 
-/// Writes metadata about available lint groups, scopes, and levels to output.
+/// Writes metadata about available lint groups, scopes, levels, and categories to output.
 ///
 /// This is primarily intended for static site generators that need to create
 /// dropdown fields based on the available configuration options.
@@ -1,5 +1,7 @@
 use std::path::PathBuf;
 
+use alpm_common::ErrorCategory;
+
 use crate::LintScope;
 
 /// Errors that can occur when using alpm-lint.
@@ -90,6 +92,13 @@ pub enum Error {
         expected: LintScope,
     },
 
+    /// An output format is not supported when linting a directory tree.
+    #[error("The output format '{format}' is not supported when linting a directory tree.")]
+    UnsupportedTreeOutputFormat {
+        /// The name of the output format.
+        format: String,
+    },
+
     /// JSON serialization error.
     #[error("JSON serialization error for {context}: {error}")]
     Json {
@@ -105,6 +114,10 @@ pub enum Error {
     #[error(transparent)]
     BuildInfo(#[from] alpm_buildinfo::Error),
 
+    /// `alpm-package` error.
+    #[error(transparent)]
+    Package(#[from] alpm_package::Error),
+
     /// `alpm-pkgbuild` error.
     #[error(transparent)]
     PackageBuild(#[from] alpm_pkgbuild::Error),
@@ -121,3 +134,23 @@ pub enum Error {
     #[error(transparent)]
     LintConfig(#[from] alpm_lint_config::Error),
 }
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::Io { .. } | Error::IoPath { .. } => Self::Io,
+            Error::NoLintScope { .. }
+            | Error::InvalidPathForLintScope { .. }
+            | Error::InvalidLintScope { .. }
+            | Error::InvalidResources { .. }
+            | Error::LintConfig(_) => Self::Validation,
+            Error::UnsupportedTreeOutputFormat { .. } => Self::Unsupported,
+            Error::Json { .. } | Error::PackageBuild(_) => Self::Parse,
+            Error::BuildInfo(error) => error.into(),
+            Error::Package(error) => error.into(),
+            Error::PackageInfo(error) => error.into(),
+            Error::SourceInfo(error) => error.into(),
+        }
+    }
+}
@@ -0,0 +1,212 @@
+//! Automatic fixes for lint issues.
+//!
+//! Lint rules whose issues can be fixed mechanically return [`Edit`]s from [`LintRule::fix`].
+//! The edits of all lint rules are applied to the contents of a file using [`apply_edits`].
+//!
+//! [`LintRule::fix`]: crate::LintRule::fix
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+/// A line-based edit of a metadata file, that fixes a lint issue.
+///
+/// Metadata files such as [SRCINFO], [PKGINFO] or [BUILDINFO] consist of `key = value` lines.
+/// As the parsed representation of these files does not track the location of data, an edit
+/// addresses a line by its content (ignoring surrounding whitespace) and the index of its
+/// occurrence among all lines with the same content.
+///
+/// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Edit {
+    /// The well-known file name of the file that is edited (e.g. `.SRCINFO`).
+    pub file_name: String,
+    /// The content of the edited line, without surrounding whitespace.
+    pub line: String,
+    /// The index of the occurrence (starting at 0) of `line` among all lines with the same
+    /// content.
+    pub occurrence: usize,
+    /// The replacement for the line, without leading whitespace.
+    ///
+    /// If [`None`], the line is removed.
+    pub replacement: Option<String>,
+}
+
+impl Edit {
+    /// Creates a new [`Edit`] that removes a line.
+    pub fn remove(
+        file_name: impl Into<String>,
+        line: impl Into<String>,
+        occurrence: usize,
+    ) -> Self {
+        Self {
+            file_name: file_name.into(),
+            line: line.into(),
+            occurrence,
+            replacement: None,
+        }
+    }
+
+    /// Creates a new [`Edit`] that replaces a line with `replacement`.
+    ///
+    /// The indentation of the line is preserved.
+    pub fn replace(
+        file_name: impl Into<String>,
+        line: impl Into<String>,
+        occurrence: usize,
+        replacement: impl Into<String>,
+    ) -> Self {
+        Self {
+            file_name: file_name.into(),
+            line: line.into(),
+            occurrence,
+            replacement: Some(replacement.into()),
+        }
+    }
+}
+
+/// The result of applying [`Edit`]s to the contents of a file using [`apply_edits`].
+#[derive(Clone, Debug)]
+pub struct FixResult {
+    /// The contents of the file with all applied edits.
+    pub content: String,
+    /// The edits that have been applied.
+    pub applied: Vec<Edit>,
+    /// The edits that have been skipped.
+    ///
+    /// Edits are skipped if they target a line that is already targeted by a previous edit, or if
+    /// the line they target does not exist.
+    pub skipped: Vec<Edit>,
+}
+
+/// Applies `edits` to `content`.
+///
+/// Edits are considered in order.
+/// If several edits target the same line, only the first one is applied, as the others conflict
+/// with it.
+/// Occurrences of lines always refer to the original `content`, so edits do not influence each
+/// other.
+///
+/// # Examples
+///
+/// ```
+/// use alpm_lint::fix::{Edit, apply_edits};
+///
+/// let content = "pkgbase = example\n\tarch = x86_64\n\tarch = x86_64\n";
+/// let result = apply_edits(
+///     content,
+///     &[
+///         Edit::remove(".SRCINFO", "arch = x86_64", 1),
+///         Edit::replace(".SRCINFO", "arch = x86_64", 1, "arch = any"),
+///     ],
+/// );
+///
+/// assert_eq!(result.content, "pkgbase = example\n\tarch = x86_64\n");
+/// assert_eq!(result.applied.len(), 1);
+/// assert_eq!(result.skipped.len(), 1);
+/// ```
+pub fn apply_edits(content: &str, edits: &[Edit]) -> FixResult {
+    let mut targets = HashSet::new();
+    let (mut accepted, mut skipped): (Vec<&Edit>, Vec<Edit>) = (Vec::new(), Vec::new());
+    for edit in edits {
+        if targets.insert((edit.line.as_str(), edit.occurrence)) {
+            accepted.push(edit);
+        } else {
+            skipped.push(edit.clone());
+        }
+    }
+
+    let mut occurrences: Vec<(&str, usize)> = Vec::new();
+    let mut applied = Vec::new();
+    let mut result = String::with_capacity(content.len());
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim();
+        let occurrence = match occurrences.iter_mut().find(|(known, _)| *known == trimmed) {
+            Some((_, count)) => {
+                *count += 1;
+                *count
+            }
+            None => {
+                occurrences.push((trimmed, 0));
+                0
+            }
+        };
+
+        let Some(index) = accepted
+            .iter()
+            .position(|edit| edit.line == trimmed && edit.occurrence == occurrence)
+        else {
+            result.push_str(line);
+            continue;
+        };
+        let edit = accepted.swap_remove(index);
+
+        if let Some(replacement) = &edit.replacement {
+            let indentation = &line[..line.len() - line.trim_start().len()];
+            result.push_str(indentation);
+            result.push_str(replacement);
+            if line.ends_with('\n') {
+                result.push('\n');
+            }
+        }
+        applied.push(edit.clone());
+    }
+
+    skipped.extend(accepted.into_iter().cloned());
+
+    FixResult {
+        content: result,
+        applied,
+        skipped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const CONTENT: &str = "pkgbase = example
+\tpkgver = 1.0.0
+\tarch = x86_64
+\tarch = x86_64
+\tarch = x86_64
+
+pkgname = example
+\tarch = x86_64
+";
+
+    /// Ensures that edits are applied to the correct lines.
+    #[rstest]
+    #[case::no_edits(&[], CONTENT, 0)]
+    #[case::remove(
+        &[Edit::remove(".SRCINFO", "arch = x86_64", 1), Edit::remove(".SRCINFO", "arch = x86_64", 2)],
+        "pkgbase = example\n\tpkgver = 1.0.0\n\tarch = x86_64\n\npkgname = example\n\tarch = x86_64\n",
+        0
+    )]
+    #[case::replace(
+        &[Edit::replace(".SRCINFO", "arch = x86_64", 3, "arch = aarch64")],
+        "pkgbase = example\n\tpkgver = 1.0.0\n\tarch = x86_64\n\tarch = x86_64\n\tarch = x86_64\n\npkgname = example\n\tarch = aarch64\n",
+        0
+    )]
+    #[case::conflict(
+        &[Edit::remove(".SRCINFO", "pkgver = 1.0.0", 0), Edit::replace(".SRCINFO", "pkgver = 1.0.0", 0, "pkgver = 1.0.1")],
+        "pkgbase = example\n\tarch = x86_64\n\tarch = x86_64\n\tarch = x86_64\n\npkgname = example\n\tarch = x86_64\n",
+        1
+    )]
+    #[case::missing_line(&[Edit::remove(".SRCINFO", "arch = x86_64", 4)], CONTENT, 1)]
+    fn apply_edits_to_content(
+        #[case] edits: &[Edit],
+        #[case] expected: &str,
+        #[case] skipped: usize,
+    ) {
+        let result = apply_edits(CONTENT, edits);
+
+        assert_eq!(result.content, expected);
+        assert_eq!(result.applied.len(), edits.len() - skipped);
+        assert_eq!(result.skipped.len(), skipped);
+    }
+}
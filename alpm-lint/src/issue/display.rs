@@ -5,11 +5,22 @@
 
 use std::{collections::BTreeMap, fmt};
 
-use colored::Colorize;
+use colored::{ColoredString, Colorize};
 
 use crate::Level;
 
-const ALPM_LINT_WEBSITE: &str = "https://alpm.archlinux.page/lints/index.html";
+/// The URL of the ALPM lints website.
+pub(crate) const ALPM_LINT_WEBSITE: &str = "https://alpm.archlinux.page/lints/index.html";
+
+/// Returns the colored label that is used for a [`Level`] in human readable output.
+pub(crate) fn level_label(level: Level) -> ColoredString {
+    match level {
+        Level::Error => "error".bold().red(),
+        Level::Deny => "denied".bold().red(),
+        Level::Warn => "warning".bold().yellow(),
+        Level::Suggest => "suggestion".bold().bright_blue(),
+    }
+}
 
 /// A generic structure that represents all possible components of a lint issue display.
 ///
@@ -22,6 +33,7 @@ const ALPM_LINT_WEBSITE: &str = "https://alpm.archlinux.page/lints/index.html";
 ///    --> arrow_line                 <- arrow line with context (optional)
 ///     |
 ///     | message                     <- main issue description
+///     | suggestion: replacement     <- suggested replacement (optional)
 ///     |
 ///    help: help_text line 1         <- help section
 ///          help_text line 2...
@@ -29,6 +41,8 @@ const ALPM_LINT_WEBSITE: &str = "https://alpm.archlinux.page/lints/index.html";
 ///       = see: documentation_url    <- auto-generated doc link
 /// ```
 ///
+/// Suppressed issues are marked with `(suppressed)` after the scoped name in the header.
+///
 /// # Examples
 ///
 /// ```text
@@ -58,20 +72,22 @@ pub struct LintIssueDisplay {
     pub help_text: String,
     /// A map of additional URL names and URLs.
     pub custom_links: BTreeMap<String, String>,
+    /// Whether the issue is suppressed by an inline suppression comment.
+    pub suppressed: bool,
+    /// An optional replacement for the value that causes the issue.
+    pub suggestion: Option<String>,
 }
 
 impl fmt::Display for LintIssueDisplay {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Header with level and lint rule
-        let level_str = match self.level {
-            Level::Error => "error".bold().red(),
-            Level::Deny => "denied".bold().red(),
-            Level::Warn => "warning".bold().yellow(),
-            Level::Suggest => "suggestion".bold().bright_blue(),
-        };
+        let level_str = level_label(self.level);
 
         // Header line
         write!(f, "{}[{}]", level_str, self.scoped_name.blue().bold())?;
+        if self.suppressed {
+            write!(f, " {}", "(suppressed)".dimmed())?;
+        }
         // Optionally append summary to header line or add a newline.
         if let Some(summary) = &self.summary {
             writeln!(f, ": {}", summary.bright_white())?;
@@ -90,6 +106,15 @@ impl fmt::Display for LintIssueDisplay {
         for line in self.message.lines() {
             writeln!(f, "   {} {}", "|".bright_blue().bold(), line)?;
         }
+        if let Some(suggestion) = &self.suggestion {
+            writeln!(
+                f,
+                "   {} {}: {}",
+                "|".bright_blue().bold(),
+                "suggestion".green().bold(),
+                suggestion
+            )?;
+        }
         writeln!(f, "   {}", "|".bright_blue().bold())?;
 
         let mut is_first_line = true;
@@ -1,6 +1,6 @@
 //! Generic representation of a lint issue.
 
-use std::{collections::BTreeMap, fmt};
+use std::{collections::BTreeMap, fmt, path::PathBuf};
 
 use alpm_types::SystemArchitecture;
 use colored::{ColoredString, Colorize};
@@ -9,6 +9,8 @@ use serde::{Deserialize, Serialize};
 use crate::{Level, LintRule, LintScope};
 
 pub mod display;
+pub mod sarif;
+pub mod summary;
 
 use display::LintIssueDisplay;
 
@@ -28,6 +30,14 @@ pub struct LintIssue {
     /// Links that can be appended to an issue.
     /// Stored as a map of `name -> URL`.
     pub links: BTreeMap<String, String>,
+    /// Whether the issue is suppressed by an inline suppression comment.
+    ///
+    /// See [`crate::suppression`] for details.
+    #[serde(default)]
+    pub suppressed: bool,
+    /// A suggested replacement for the value that causes the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
 }
 
 impl LintIssue {
@@ -40,6 +50,32 @@ impl LintIssue {
             scope: rule.scope(),
             issue_type,
             links: rule.extra_links().unwrap_or_default(),
+            suppressed: false,
+            suggestion: None,
+        }
+    }
+
+    /// Adds a `suggestion` for a replacement of the value that causes the issue.
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Returns the name of the package that this issue is specific to.
+    ///
+    /// Returns [`None`] if the issue does not concern a single package (e.g. if it is encountered
+    /// in the package base of a [SRCINFO] file).
+    ///
+    /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+    pub fn package_name(&self) -> Option<&str> {
+        match &self.issue_type {
+            LintIssueType::SourceInfo(SourceInfoIssue::PackageField { package_name, .. }) => {
+                Some(package_name)
+            }
+            LintIssueType::SourceInfo(_)
+            | LintIssueType::PackageBuild(_)
+            | LintIssueType::PackageInfo(_)
+            | LintIssueType::PackageArtifact(_) => None,
         }
     }
 }
@@ -97,6 +133,41 @@ impl From<LintIssue> for LintIssueDisplay {
                     format!("Field '{}' is required but missing", field_name.bold())
                 }
             },
+            LintIssueType::PackageBuild(issue) => match issue {
+                PackageBuildIssue::Line {
+                    line,
+                    function,
+                    content,
+                    context,
+                } => {
+                    arrow_line = Some(match function {
+                        Some(function) => {
+                            format!("in line {line} (function '{}')", function.bold())
+                        }
+                        None => format!("in line {line}"),
+                    });
+                    format!("{context}: {}", content.trim())
+                }
+            },
+            LintIssueType::PackageInfo(issue) => match issue {
+                PackageInfoIssue::Field {
+                    field_name,
+                    value,
+                    context,
+                } => {
+                    arrow_line = Some(format!("in field '{}'", field_name.bold()));
+                    format!("{context}: {value}")
+                }
+                PackageInfoIssue::MissingField { field_name } => {
+                    format!("Field '{}' is required but missing", field_name.bold())
+                }
+            },
+            LintIssueType::PackageArtifact(issue) => match issue {
+                PackageArtifactIssue::Path { path, context } => {
+                    arrow_line = Some(format!("in path '{}'", path.display().to_string().bold()));
+                    context
+                }
+            },
         };
 
         LintIssueDisplay {
@@ -107,6 +178,8 @@ impl From<LintIssue> for LintIssueDisplay {
             message,
             help_text: other.help_text,
             custom_links: other.links,
+            suppressed: other.suppressed,
+            suggestion: other.suggestion,
         }
     }
 }
@@ -115,18 +188,30 @@ impl From<LintIssue> for LintIssueDisplay {
 ///
 /// This is used to categorize lint issues and to provide detailed data
 /// for good error messages for each type of issue.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum LintIssueType {
     /// All issues that can be encountered when linting a [SRCINFO] file.
     ///
     /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
     SourceInfo(SourceInfoIssue),
+    /// All issues that can be encountered when linting a [PKGBUILD] file.
+    ///
+    /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+    PackageBuild(PackageBuildIssue),
+    /// All issues that can be encountered when linting a [PKGINFO] file.
+    ///
+    /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+    PackageInfo(PackageInfoIssue),
+    /// All issues that can be encountered when linting an [alpm-package] file.
+    ///
+    /// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+    PackageArtifact(PackageArtifactIssue),
 }
 
 /// A specific type of [SRCINFO] related lint issues that may be encountered during linting.
 ///
 /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum SourceInfoIssue {
     /// A generic issue that only consists of some text without any additional fields.
     ///
@@ -236,6 +321,109 @@ impl SourceInfoIssue {
     }
 }
 
+/// A specific type of [PKGBUILD] related lint issues that may be encountered during linting.
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum PackageBuildIssue {
+    /// A lint issue on a specific line.
+    Line {
+        /// The number of the line (starting at 1) which causes the issue.
+        ///
+        /// Used as [`LintIssueDisplay::arrow_line`] in the form of:
+        /// `"in line {line} (function {function})"`
+        line: usize,
+
+        /// The name of the function that the line is part of.
+        ///
+        /// Is [`None`] for lines outside of functions.
+        function: Option<String>,
+
+        /// The contents of the line which causes the issue.
+        ///
+        /// Used as [`LintIssueDisplay::message`] in the form of:
+        /// `"{context}: {content}"`
+        content: String,
+
+        /// Additional context that describes what kind of issue is found.
+        ///
+        /// Used as [`LintIssueDisplay::message`] in the form of:
+        /// `"{context}: {content}"`
+        context: String,
+    },
+}
+
+impl From<PackageBuildIssue> for LintIssueType {
+    fn from(issue: PackageBuildIssue) -> Self {
+        LintIssueType::PackageBuild(issue)
+    }
+}
+
+/// A specific type of [PKGINFO] related lint issues that may be encountered during linting.
+///
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum PackageInfoIssue {
+    /// A lint issue on a field.
+    Field {
+        /// The field name which causes the issue.
+        ///
+        /// Used as [`LintIssueDisplay::arrow_line`] in the form of:
+        /// `"in field {field_name}"`
+        field_name: String,
+
+        /// The value that causes the issue.
+        ///
+        /// Used as [`LintIssueDisplay::message`] in the form of:
+        /// `"{context}: {value}"`
+        value: String,
+
+        /// Additional context that describes what kind of issue is found.
+        ///
+        /// Used as [`LintIssueDisplay::message`] in the form of:
+        /// `"{context}: {value}"`
+        context: String,
+    },
+
+    /// A required field is missing.
+    MissingField {
+        /// The name of the field that is missing.
+        field_name: String,
+    },
+}
+
+impl From<PackageInfoIssue> for LintIssueType {
+    fn from(issue: PackageInfoIssue) -> Self {
+        LintIssueType::PackageInfo(issue)
+    }
+}
+
+/// A specific type of [alpm-package] related lint issues that may be encountered during linting.
+///
+/// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum PackageArtifactIssue {
+    /// A lint issue on a path contained in the package.
+    Path {
+        /// The path which causes the issue.
+        ///
+        /// Used as [`LintIssueDisplay::arrow_line`] in the form of:
+        /// `"in path {path}"`
+        path: PathBuf,
+
+        /// Additional context that describes what kind of issue is found.
+        ///
+        /// Used as [`LintIssueDisplay::message`].
+        context: String,
+    },
+}
+
+impl From<PackageArtifactIssue> for LintIssueType {
+    fn from(issue: PackageArtifactIssue) -> Self {
+        LintIssueType::PackageArtifact(issue)
+    }
+}
+
 impl From<SourceInfoIssue> for LintIssueType {
     fn from(issue: SourceInfoIssue) -> Self {
         LintIssueType::SourceInfo(issue)
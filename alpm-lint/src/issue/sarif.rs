@@ -0,0 +1,564 @@
+//! Representation of lint issues in the [SARIF] format.
+//!
+//! Provides the [`SarifLog`] type, which describes a lint run using a subset of the [SARIF] 2.1.0
+//! format.
+//! This allows code review platforms (e.g. GitLab or GitHub) to annotate changes with lint issues.
+//!
+//! [SARIF]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use serde::Serialize;
+
+use crate::{
+    Level,
+    LintRule,
+    issue::{
+        LintIssue,
+        LintIssueType,
+        PackageArtifactIssue,
+        PackageBuildIssue,
+        PackageInfoIssue,
+        SourceInfoIssue,
+        display::ALPM_LINT_WEBSITE,
+    },
+};
+
+/// The version of the [SARIF] format.
+///
+/// [SARIF]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+const SARIF_VERSION: &str = "2.1.0";
+
+/// The JSON schema of the [SARIF] format.
+///
+/// [SARIF]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// A [SARIF] log of a single lint run.
+///
+/// # Examples
+///
+/// ```
+/// use alpm_lint::{
+///     Level,
+///     LintScope,
+///     LintStore,
+///     config::LintConfiguration,
+///     issue::sarif::SarifLog,
+/// };
+///
+/// let store = LintStore::new(LintConfiguration::default());
+/// let rules: Vec<_> = store
+///     .filtered_lint_rules(&LintScope::SourceInfo, Level::Suggest)
+///     .map(|(_, rule)| rule.as_ref())
+///     .collect();
+/// let log = SarifLog::new(&[], &rules, ".SRCINFO", None);
+///
+/// assert_eq!(log.version, "2.1.0");
+/// assert!(log.runs[0].results.is_empty());
+/// ```
+///
+/// [SARIF]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Clone, Debug, Serialize)]
+pub struct SarifLog {
+    /// The JSON schema of the log.
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    /// The version of the SARIF format.
+    pub version: String,
+    /// The runs of the log.
+    ///
+    /// Always contains exactly one run.
+    pub runs: Vec<SarifRun>,
+}
+
+impl SarifLog {
+    /// Creates a new [`SarifLog`] from a list of [`LintIssue`]s.
+    ///
+    /// The `lint_rules` are the lint rules that have been run and are described in the log.
+    /// All `issues` are located in the file at `artifact_uri`.
+    /// If the `artifact_content` is provided, the line of an issue in the file is derived from the
+    /// field and package the issue relates to.
+    pub fn new(
+        issues: &[LintIssue],
+        lint_rules: &[&dyn LintRule],
+        artifact_uri: &str,
+        artifact_content: Option<&str>,
+    ) -> Self {
+        let rules: Vec<SarifRule> = lint_rules
+            .iter()
+            .map(|rule| SarifRule::from(*rule))
+            .collect();
+
+        let results = issues
+            .iter()
+            .map(|issue| {
+                let rule_index = rules.iter().position(|rule| rule.id == issue.lint_rule);
+                let start_line = artifact_content.and_then(|content| find_line(content, issue));
+                SarifResult {
+                    rule_id: issue.lint_rule.clone(),
+                    rule_index,
+                    level: sarif_level(issue.level).to_string(),
+                    message: SarifMessage {
+                        text: issue_message(issue),
+                    },
+                    locations: vec![SarifLocation {
+                        physical_location: SarifPhysicalLocation {
+                            artifact_location: SarifArtifactLocation {
+                                uri: artifact_uri.to_string(),
+                            },
+                            region: start_line.map(|start_line| SarifRegion { start_line }),
+                        },
+                    }],
+                    suppressions: if issue.suppressed {
+                        vec![SarifSuppression {
+                            kind: "inSource".to_string(),
+                        }]
+                    } else {
+                        Vec::new()
+                    },
+                }
+            })
+            .collect();
+
+        Self {
+            schema: SARIF_SCHEMA.to_string(),
+            version: SARIF_VERSION.to_string(),
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: env!("CARGO_PKG_NAME").to_string(),
+                        version: env!("CARGO_PKG_VERSION").to_string(),
+                        information_uri: ALPM_LINT_WEBSITE.to_string(),
+                        rules,
+                    },
+                },
+                results,
+            }],
+        }
+    }
+}
+
+/// A single run of a tool in a [`SarifLog`].
+#[derive(Clone, Debug, Serialize)]
+pub struct SarifRun {
+    /// The tool that has been run.
+    pub tool: SarifTool,
+    /// The results of the run.
+    pub results: Vec<SarifResult>,
+}
+
+/// The tool of a [`SarifRun`].
+#[derive(Clone, Debug, Serialize)]
+pub struct SarifTool {
+    /// The component of the tool that has been run.
+    pub driver: SarifDriver,
+}
+
+/// The component of a [`SarifTool`] that has been run.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifDriver {
+    /// The name of the tool.
+    pub name: String,
+    /// The version of the tool.
+    pub version: String,
+    /// The URI of the documentation of the tool.
+    pub information_uri: String,
+    /// The lint rules that have been run.
+    pub rules: Vec<SarifRule>,
+}
+
+/// The metadata of a lint rule.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    /// The scoped name of the lint rule.
+    pub id: String,
+    /// The name of the lint rule.
+    pub name: String,
+    /// The documentation of the lint rule.
+    pub full_description: SarifMessage,
+    /// The help text of the lint rule.
+    pub help: SarifMessage,
+    /// The URI of the documentation of the lint rule.
+    pub help_uri: String,
+    /// The default configuration of the lint rule.
+    pub default_configuration: SarifRuleConfiguration,
+}
+
+impl From<&dyn LintRule> for SarifRule {
+    fn from(rule: &dyn LintRule) -> Self {
+        let id = rule.scoped_name();
+        Self {
+            help_uri: format!("{ALPM_LINT_WEBSITE}#{id}"),
+            id,
+            name: rule.name().to_string(),
+            full_description: SarifMessage {
+                text: rule.documentation(),
+            },
+            help: SarifMessage {
+                text: rule.help_text(),
+            },
+            default_configuration: SarifRuleConfiguration {
+                level: sarif_level(rule.level()).to_string(),
+            },
+        }
+    }
+}
+
+/// The default configuration of a [`SarifRule`].
+#[derive(Clone, Debug, Serialize)]
+pub struct SarifRuleConfiguration {
+    /// The SARIF level of the lint rule.
+    pub level: String,
+}
+
+/// A lint issue in a [`SarifRun`].
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    /// The scoped name of the lint rule that encountered the issue.
+    pub rule_id: String,
+    /// The index of the lint rule in [`SarifDriver::rules`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_index: Option<usize>,
+    /// The SARIF level of the issue.
+    pub level: String,
+    /// The message of the issue.
+    pub message: SarifMessage,
+    /// The locations of the issue.
+    pub locations: Vec<SarifLocation>,
+    /// The suppressions of the issue.
+    ///
+    /// Is empty if the issue is not suppressed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suppressions: Vec<SarifSuppression>,
+}
+
+/// The suppression of a [`SarifResult`].
+#[derive(Clone, Debug, Serialize)]
+pub struct SarifSuppression {
+    /// The kind of suppression.
+    ///
+    /// Inline suppression comments are of kind `inSource`.
+    pub kind: String,
+}
+
+/// A plain text message.
+#[derive(Clone, Debug, Serialize)]
+pub struct SarifMessage {
+    /// The text of the message.
+    pub text: String,
+}
+
+/// The location of a [`SarifResult`].
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    /// The location in a file.
+    pub physical_location: SarifPhysicalLocation,
+}
+
+/// A location in a file.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    /// The file.
+    pub artifact_location: SarifArtifactLocation,
+    /// The region in the file.
+    ///
+    /// Is [`None`] if the location of the issue in the file is unknown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<SarifRegion>,
+}
+
+/// The location of a file.
+#[derive(Clone, Debug, Serialize)]
+pub struct SarifArtifactLocation {
+    /// The URI of the file.
+    pub uri: String,
+}
+
+/// A region in a file.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    /// The line (starting at 1) at which the region starts.
+    pub start_line: usize,
+}
+
+/// Returns the SARIF level for a [`Level`].
+fn sarif_level(level: Level) -> &'static str {
+    match level {
+        Level::Error | Level::Deny => "error",
+        Level::Warn => "warning",
+        Level::Suggest => "note",
+    }
+}
+
+/// Returns the plain text message of a [`LintIssue`].
+///
+/// The suggestion of the issue is appended to the message, if there is one.
+fn issue_message(issue: &LintIssue) -> String {
+    let message = match &issue.issue_type {
+        LintIssueType::SourceInfo(issue) => match issue {
+            SourceInfoIssue::Generic {
+                summary, message, ..
+            } => format!("{summary}: {message}"),
+            SourceInfoIssue::BaseField {
+                field_name,
+                value,
+                context,
+                architecture,
+            } => format!(
+                "{context}: {value} (in field '{}')",
+                field_key(field_name, architecture.as_ref())
+            ),
+            SourceInfoIssue::PackageField {
+                field_name,
+                package_name,
+                value,
+                context,
+                architecture,
+            } => format!(
+                "{context}: {value} (in field '{}' for package '{package_name}')",
+                field_key(field_name, architecture.as_ref())
+            ),
+            SourceInfoIssue::MissingField { field_name } => {
+                format!("Field '{field_name}' is required but missing")
+            }
+        },
+        LintIssueType::PackageBuild(PackageBuildIssue::Line {
+            line,
+            content,
+            context,
+            ..
+        }) => format!("{context}: {} (in line {line})", content.trim()),
+        LintIssueType::PackageInfo(issue) => match issue {
+            PackageInfoIssue::Field {
+                field_name,
+                value,
+                context,
+            } => format!("{context}: {value} (in field '{field_name}')"),
+            PackageInfoIssue::MissingField { field_name } => {
+                format!("Field '{field_name}' is required but missing")
+            }
+        },
+        LintIssueType::PackageArtifact(issue) => match issue {
+            PackageArtifactIssue::Path { path, context } => {
+                format!("{context}: {}", path.display())
+            }
+        },
+    };
+
+    match &issue.suggestion {
+        Some(suggestion) => format!("{message} (suggestion: {suggestion})"),
+        None => message,
+    }
+}
+
+/// Returns the key of a field with an optional architecture suffix.
+pub(crate) fn field_key(field_name: &str, architecture: Option<&impl std::fmt::Display>) -> String {
+    match architecture {
+        Some(architecture) => format!("{field_name}_{architecture}"),
+        None => field_name.to_string(),
+    }
+}
+
+/// Returns the line (starting at 1) in `content` that a [`LintIssue`] relates to.
+///
+/// For [SRCINFO] data, the line is searched for in the `pkgbase` section or the `pkgname` section
+/// of the package the issue relates to.
+/// Lines matching both the field and the value of the issue are preferred over lines only
+/// matching the field.
+///
+/// For [PKGBUILD] data, the line of the issue is used if it still has the expected content.
+///
+/// Returns [`None`] if the issue does not relate to a field or no matching line is found.
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+fn find_line(content: &str, issue: &LintIssue) -> Option<usize> {
+    let source_info_issue = match &issue.issue_type {
+        LintIssueType::SourceInfo(source_info_issue) => source_info_issue,
+        LintIssueType::PackageBuild(PackageBuildIssue::Line {
+            line,
+            content: expected,
+            ..
+        }) => {
+            return content
+                .lines()
+                .nth(line.checked_sub(1)?)
+                .filter(|actual| actual.trim() == expected.trim())
+                .map(|_| *line);
+        }
+        LintIssueType::PackageInfo(PackageInfoIssue::Field {
+            field_name, value, ..
+        }) => return find_field_line(content.lines().enumerate(), field_name, value),
+        LintIssueType::PackageInfo(PackageInfoIssue::MissingField { .. })
+        | LintIssueType::PackageArtifact(_) => return None,
+    };
+    let (key, value, package_name) = match source_info_issue {
+        SourceInfoIssue::BaseField {
+            field_name,
+            value,
+            architecture,
+            ..
+        } => (field_key(field_name, architecture.as_ref()), value, None),
+        SourceInfoIssue::PackageField {
+            field_name,
+            package_name,
+            value,
+            architecture,
+            ..
+        } => (
+            field_key(field_name, architecture.as_ref()),
+            value,
+            Some(package_name.as_str()),
+        ),
+        SourceInfoIssue::Generic { .. } | SourceInfoIssue::MissingField { .. } => return None,
+    };
+
+    // Only consider the lines of the relevant section.
+    let mut section = None;
+    let lines = content.lines().enumerate().filter(|(_, line)| {
+        if let Some(name) = line.trim().strip_prefix("pkgname = ") {
+            section = Some(name);
+            return false;
+        }
+        section == package_name
+    });
+
+    find_field_line(lines, &key, value)
+}
+
+/// Returns the line (starting at 1) of a `key = value` field in the enumerated `lines`.
+///
+/// Lines matching both `key` and `value` are preferred over lines only matching `key`.
+///
+/// Returns [`None`] if no matching line is found.
+fn find_field_line<'a>(
+    lines: impl Iterator<Item = (usize, &'a str)>,
+    key: &str,
+    value: &str,
+) -> Option<usize> {
+    let field = format!("{key} = ");
+    let lines: Vec<(usize, &str)> = lines.map(|(index, line)| (index, line.trim())).collect();
+    lines
+        .iter()
+        .find(|(_, line)| line.strip_prefix(&field) == Some(value))
+        .or_else(|| lines.iter().find(|(_, line)| line.starts_with(&field)))
+        .map(|(index, _)| index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::LintScope;
+
+    const CONTENT: &str = "pkgbase = example
+\tpkgver = 1.0.0
+\tarch = x86_64
+\tarch = x86_64
+\tlicense = MIT
+\tmd5sums_x86_64 = 11111111111111111111111111111111
+
+pkgname = example
+\tlicense = GPL
+\tlicense = Apache
+";
+
+    /// Creates a [`LintIssue`] of a specific `issue_type`.
+    fn issue(issue_type: impl Into<LintIssueType>) -> LintIssue {
+        LintIssue {
+            lint_rule: "source_info::test".to_string(),
+            level: Level::Warn,
+            help_text: String::new(),
+            scope: LintScope::SourceInfo,
+            issue_type: issue_type.into(),
+            links: BTreeMap::new(),
+            suppressed: false,
+            suggestion: None,
+        }
+    }
+
+    /// Ensures that the lines of issues are found.
+    #[rstest]
+    #[case::base_field(
+        SourceInfoIssue::BaseField {
+            field_name: "arch".to_string(),
+            value: "x86_64".to_string(),
+            context: String::new(),
+            architecture: None,
+        },
+        Some(3)
+    )]
+    #[case::base_field_with_architecture(
+        SourceInfoIssue::BaseField {
+            field_name: "md5sums".to_string(),
+            value: "11111111111111111111111111111111".to_string(),
+            context: String::new(),
+            architecture: Some(alpm_types::SystemArchitecture::X86_64),
+        },
+        Some(6)
+    )]
+    #[case::package_field(
+        SourceInfoIssue::PackageField {
+            field_name: "license".to_string(),
+            package_name: "example".to_string(),
+            value: "Apache".to_string(),
+            context: String::new(),
+            architecture: None,
+        },
+        Some(10)
+    )]
+    #[case::package_field_without_value(
+        SourceInfoIssue::PackageField {
+            field_name: "license".to_string(),
+            package_name: "example".to_string(),
+            value: "BSD".to_string(),
+            context: String::new(),
+            architecture: None,
+        },
+        Some(9)
+    )]
+    #[case::unknown_package(
+        SourceInfoIssue::PackageField {
+            field_name: "license".to_string(),
+            package_name: "other".to_string(),
+            value: "GPL".to_string(),
+            context: String::new(),
+            architecture: None,
+        },
+        None
+    )]
+    #[case::missing_field(SourceInfoIssue::MissingField { field_name: "arch".to_string() }, None)]
+    fn find_line_of_issue(#[case] issue_type: SourceInfoIssue, #[case] expected: Option<usize>) {
+        assert_eq!(find_line(CONTENT, &issue(issue_type)), expected);
+    }
+
+    /// Ensures that the lines of [PKGINFO] issues are found.
+    ///
+    /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+    #[rstest]
+    #[case::field("depend", "glibc", Some(4))]
+    #[case::field_without_value("depend", "gcc-libs", Some(3))]
+    #[case::unknown_field("optdepend", "glibc", None)]
+    fn find_line_of_package_info_issue(
+        #[case] field_name: &str,
+        #[case] value: &str,
+        #[case] expected: Option<usize>,
+    ) {
+        let content = "pkgname = example\npkgver = 1.0.0-1\ndepend = bash\ndepend = glibc\n";
+        let issue_type = PackageInfoIssue::Field {
+            field_name: field_name.to_string(),
+            value: value.to_string(),
+            context: String::new(),
+        };
+
+        assert_eq!(find_line(content, &issue(issue_type)), expected);
+    }
+}
@@ -0,0 +1,150 @@
+//! Aggregated human readable summaries of lint issues.
+//!
+//! Provides the [`LintSummary`] type, which condenses a list of [`LintIssue`]s into counts per
+//! [`Level`], per lint rule and per package.
+
+use std::{collections::BTreeMap, fmt};
+
+use colored::Colorize;
+use strum::VariantArray;
+
+use crate::{
+    Level,
+    issue::{LintIssue, display::level_label},
+};
+
+/// The maximum number of lint rules and packages that are listed in a [`LintSummary`] display.
+const MAX_LISTED_ENTRIES: usize = 10;
+
+/// An aggregated summary of a list of [`LintIssue`]s.
+///
+/// The summary is meant to keep the output of large lint runs interpretable, by providing counts
+/// per [`Level`] and by pointing out the lint rules and packages with the most issues.
+///
+/// # Visual Layout
+///
+/// ```text
+/// summary: total                    <- total number of issues
+///   levels:
+///     level: count                  <- one line per level with issues
+///   rules:
+///     scoped_name: count            <- lint rules with the most issues
+///   packages:
+///     package_name: count           <- packages with the most issues (optional)
+/// ```
+///
+/// At most ten lint rules and packages are listed.
+///
+/// # Examples
+///
+/// ```text
+/// summary: 3 lint issues
+///   levels:
+///     warning: 2
+///     suggestion: 1
+///   rules:
+///     source_info::duplicate_architecture: 2
+///     source_info::no_checksums: 1
+///   packages:
+///     example: 2
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LintSummary {
+    /// The total number of issues.
+    pub total: usize,
+    /// The number of issues per [`Level`], ordered by severity.
+    ///
+    /// Levels without any issues are omitted.
+    pub levels: Vec<(Level, usize)>,
+    /// The number of issues per lint rule, ordered by descending count and name.
+    pub rules: Vec<(String, usize)>,
+    /// The number of issues per package, ordered by descending count and name.
+    ///
+    /// Only issues that are specific to a single package are considered (see
+    /// [`LintIssue::package_name`]).
+    pub packages: Vec<(String, usize)>,
+}
+
+impl LintSummary {
+    /// Creates a new [`LintSummary`] from a list of [`LintIssue`]s.
+    pub fn new(issues: &[LintIssue]) -> Self {
+        let levels = Level::VARIANTS
+            .iter()
+            .map(|level| {
+                let count = issues.iter().filter(|issue| issue.level == *level).count();
+                (*level, count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect();
+
+        let mut rules: BTreeMap<&str, usize> = BTreeMap::new();
+        let mut packages: BTreeMap<&str, usize> = BTreeMap::new();
+        for issue in issues {
+            *rules.entry(&issue.lint_rule).or_default() += 1;
+            if let Some(package_name) = issue.package_name() {
+                *packages.entry(package_name).or_default() += 1;
+            }
+        }
+
+        Self {
+            total: issues.len(),
+            levels,
+            rules: sorted_by_count(rules),
+            packages: sorted_by_count(packages),
+        }
+    }
+}
+
+/// Turns a map of names and counts into a list ordered by descending count and name.
+fn sorted_by_count(map: BTreeMap<&str, usize>) -> Vec<(String, usize)> {
+    let mut entries: Vec<(String, usize)> = map
+        .into_iter()
+        .map(|(name, count)| (name.to_string(), count))
+        .collect();
+    // The sort is stable, so entries with the same count remain ordered by name.
+    entries.sort_by(|(_, a), (_, b)| b.cmp(a));
+    entries
+}
+
+/// Writes a titled section of names and counts, listing at most [`MAX_LISTED_ENTRIES`] entries.
+fn write_section(
+    f: &mut fmt::Formatter<'_>,
+    title: &str,
+    entries: &[(String, usize)],
+) -> fmt::Result {
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(f, "  {}:", title.bold())?;
+    for (name, count) in entries.iter().take(MAX_LISTED_ENTRIES) {
+        writeln!(f, "    {}: {count}", name.blue().bold())?;
+    }
+    if entries.len() > MAX_LISTED_ENTRIES {
+        writeln!(f, "    ... and {} more", entries.len() - MAX_LISTED_ENTRIES)?;
+    }
+
+    Ok(())
+}
+
+impl fmt::Display for LintSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let noun = if self.total == 1 {
+            "lint issue"
+        } else {
+            "lint issues"
+        };
+        writeln!(f, "{}: {} {noun}", "summary".bold(), self.total)?;
+
+        if !self.levels.is_empty() {
+            writeln!(f, "  {}:", "levels".bold())?;
+            for (level, count) in &self.levels {
+                writeln!(f, "    {}: {count}", level_label(*level))?;
+            }
+        }
+        write_section(f, "rules", &self.rules)?;
+        write_section(f, "packages", &self.packages)?;
+
+        Ok(())
+    }
+}
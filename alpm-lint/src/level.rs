@@ -1,3 +1,4 @@
+use alpm_lint_config::LintLevel;
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use strum::{Display as StrumDisplay, VariantArray};
@@ -38,3 +39,14 @@ pub enum Level {
     /// followed do not lead to functional issues.
     Suggest = 4,
 }
+
+impl From<LintLevel> for Level {
+    fn from(level: LintLevel) -> Self {
+        match level {
+            LintLevel::Error => Level::Error,
+            LintLevel::Deny => Level::Deny,
+            LintLevel::Warn => Level::Warn,
+            LintLevel::Suggest => Level::Suggest,
+        }
+    }
+}
@@ -1,22 +1,28 @@
 #![doc = include_str!("../README.md")]
 
+pub mod baseline;
+mod category;
 #[cfg(feature = "cli")]
 #[doc(hidden)]
 pub mod cli;
 mod error;
+pub mod fix;
 pub mod issue;
 mod level;
 pub mod lint_rules;
 mod resources;
 mod rule;
 mod scope;
+pub mod suppression;
+pub mod tree;
 mod utils;
 
 pub use crate::{
+    category::LintCategory,
     error::Error,
     level::Level,
     lint_rules::store::LintStore,
-    resources::Resources,
+    resources::{PackageRelations, Resources},
     rule::LintRule,
     scope::{LintScope, ScopedName},
 };
@@ -31,6 +37,8 @@ mod internal_prelude {
 
     pub use crate::{
         Error,
+        category::LintCategory,
+        fix::Edit,
         issue::LintIssue,
         level::Level,
         resources::Resources,
@@ -41,8 +49,13 @@ mod internal_prelude {
 /// Convenience re-export of [`alpm_lint_config`] types.
 pub mod config {
     pub use alpm_lint_config::{
+        CustomRule,
+        CustomRuleCheck,
+        CustomRulePattern,
+        CustomRuleScope,
         LintConfiguration,
         LintGroup,
+        LintLevel,
         LintRuleConfiguration,
         LintRuleConfigurationOption,
     };
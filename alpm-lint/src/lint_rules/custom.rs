@@ -0,0 +1,222 @@
+//! Lint rules that are defined declaratively in a lint configuration.
+//!
+//! See [`CustomRule`] for the definition of such lint rules.
+
+use alpm_lint_config::{CustomRule, CustomRuleCheck, CustomRuleScope};
+
+use crate::{
+    internal_prelude::*,
+    issue::{PackageInfoIssue, SourceInfoIssue},
+    lint_rules::source_info::source_info_from_resource,
+};
+
+impl From<CustomRuleScope> for LintScope {
+    fn from(scope: CustomRuleScope) -> Self {
+        match scope {
+            CustomRuleScope::SourceInfo => LintScope::SourceInfo,
+            CustomRuleScope::PackageInfo => LintScope::PackageInfo,
+        }
+    }
+}
+
+/// A field of a metadata file that is checked by a [`CustomLintRule`].
+#[derive(Debug)]
+struct Field {
+    /// The name of the package the field belongs to.
+    ///
+    /// Is [`None`] for fields of the package base of a [SRCINFO] file and all fields of a
+    /// [PKGINFO] file.
+    ///
+    /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+    /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+    package_name: Option<String>,
+    /// The key of the field.
+    key: String,
+    /// The value of the field.
+    value: String,
+}
+
+/// Returns all [`Field`]s of the `content` of a [PKGINFO] or [SRCINFO] file.
+///
+/// If `sections` is `true`, the content is split into a package base section and package sections
+/// (as in [SRCINFO] files).
+///
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+fn parse_fields(content: &str, sections: bool) -> Vec<Field> {
+    let mut package_name = None;
+    content
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.trim().split_once(" = ")?;
+            if sections {
+                match key {
+                    "pkgbase" => package_name = None,
+                    "pkgname" => package_name = Some(value.to_string()),
+                    _ => {}
+                }
+            }
+            Some(Field {
+                package_name: package_name.clone(),
+                key: key.to_string(),
+                value: value.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// A lint rule that is defined declaratively in a [`LintConfiguration`].
+///
+/// Depending on its [`CustomRuleCheck`], the lint rule ensures that
+///
+/// - all values of a field match a regular expression,
+/// - a field does not contain any forbidden values,
+/// - or a field is set.
+///
+/// [`LintConfiguration`]: alpm_lint_config::LintConfiguration
+#[derive(Clone, Debug)]
+pub struct CustomLintRule {
+    /// The name of the lint rule.
+    name: &'static str,
+    /// The definition of the lint rule.
+    definition: CustomRule,
+}
+
+impl CustomLintRule {
+    /// Create a new, boxed instance of [`CustomLintRule`] from its `definition`.
+    ///
+    /// # Note
+    ///
+    /// As [`LintRule::name`] requires a static name, the name of the lint rule is leaked.
+    /// Custom lint rules are only created once per [`LintStore`](crate::LintStore), so this is
+    /// negligible.
+    pub fn new_boxed(definition: CustomRule) -> Box<dyn LintRule> {
+        Box::new(Self {
+            name: Box::leak(definition.name.clone().into_boxed_str()),
+            definition,
+        })
+    }
+
+    /// Returns all fields of the metadata file that is checked by the lint rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `resources` does not contain the data of the metadata file.
+    fn fields(&self, resources: &Resources) -> Result<Vec<Field>, Error> {
+        let fields = match self.definition.scope {
+            CustomRuleScope::SourceInfo => parse_fields(
+                &source_info_from_resource(resources, self.scoped_name())?.as_srcinfo(),
+                true,
+            ),
+            CustomRuleScope::PackageInfo => match resources {
+                Resources::Package { package_info, .. }
+                | Resources::PackageArtifact { package_info, .. }
+                | Resources::PackageInfo(package_info) => {
+                    parse_fields(&package_info.to_string(), false)
+                }
+                _ => {
+                    return Err(Error::InvalidResources {
+                        scope: resources.scope(),
+                        lint_rule: self.scoped_name(),
+                        expected: LintScope::PackageInfo,
+                    });
+                }
+            },
+        };
+
+        Ok(fields)
+    }
+
+    /// Creates a [`LintIssue`] for a `field` with additional `context`.
+    fn field_issue(&self, field: &Field, context: String) -> LintIssue {
+        let issue_type = match (self.definition.scope, &field.package_name) {
+            (CustomRuleScope::SourceInfo, None) => SourceInfoIssue::BaseField {
+                field_name: field.key.clone(),
+                value: field.value.clone(),
+                context,
+                architecture: None,
+            }
+            .into(),
+            (CustomRuleScope::SourceInfo, Some(package_name)) => SourceInfoIssue::PackageField {
+                field_name: field.key.clone(),
+                package_name: package_name.clone(),
+                value: field.value.clone(),
+                context,
+                architecture: None,
+            }
+            .into(),
+            (CustomRuleScope::PackageInfo, _) => PackageInfoIssue::Field {
+                field_name: field.key.clone(),
+                value: field.value.clone(),
+                context,
+            }
+            .into(),
+        };
+
+        LintIssue::from_rule(self, issue_type)
+    }
+
+    /// Creates a [`LintIssue`] for a missing field called `field_name`.
+    fn missing_field_issue(&self, field_name: &str) -> LintIssue {
+        let field_name = field_name.to_string();
+        let issue_type = match self.definition.scope {
+            CustomRuleScope::SourceInfo => SourceInfoIssue::MissingField { field_name }.into(),
+            CustomRuleScope::PackageInfo => PackageInfoIssue::MissingField { field_name }.into(),
+        };
+
+        LintIssue::from_rule(self, issue_type)
+    }
+}
+
+impl LintRule for CustomLintRule {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn scope(&self) -> LintScope {
+        self.definition.scope.into()
+    }
+
+    fn level(&self) -> Level {
+        self.definition.level.into()
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        let fields = self.fields(resources)?;
+
+        match &self.definition.check {
+            CustomRuleCheck::Pattern { field, pattern } => {
+                for field in fields.iter().filter(|candidate| &candidate.key == field) {
+                    if !pattern.is_match(&field.value) {
+                        issues.push(self.field_issue(
+                            field,
+                            format!("Value does not match the pattern '{pattern}'"),
+                        ));
+                    }
+                }
+            }
+            CustomRuleCheck::ForbiddenValues { field, values } => {
+                for field in fields.iter().filter(|candidate| &candidate.key == field) {
+                    if values.contains(&field.value) {
+                        issues.push(self.field_issue(field, "Forbidden value".to_string()));
+                    }
+                }
+            }
+            CustomRuleCheck::RequiredField { field } => {
+                if !fields.iter().any(|candidate| &candidate.key == field) {
+                    issues.push(self.missing_field_issue(field));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn documentation(&self) -> String {
+        self.definition.description.clone()
+    }
+
+    fn help_text(&self) -> String {
+        self.definition.help.clone()
+    }
+}
@@ -0,0 +1,104 @@
+//! Lint rules whose levels are overridden in a lint configuration.
+//!
+//! See [`LintConfiguration::levels`] for the definition of such overrides.
+//!
+//! [`LintConfiguration::levels`]: alpm_lint_config::LintConfiguration::levels
+
+use std::collections::BTreeMap;
+
+use alpm_lint_config::LintRuleConfigurationOptionName;
+
+use crate::internal_prelude::*;
+
+/// A [`LintRule`] with an overridden [`Level`].
+///
+/// All behavior is delegated to the wrapped lint rule, apart from the level of the lint rule and
+/// the level of the issues it encounters.
+pub struct LevelOverride {
+    /// The wrapped lint rule.
+    rule: Box<dyn LintRule>,
+    /// The overridden level of the lint rule.
+    level: Level,
+}
+
+impl std::fmt::Debug for LevelOverride {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LevelOverride")
+            .field("rule", &self.rule.scoped_name())
+            .field("level", &self.level)
+            .finish()
+    }
+}
+
+impl LevelOverride {
+    /// Create a new, boxed instance of [`LevelOverride`], which overrides the level of `rule`
+    /// with `level`.
+    pub fn new_boxed(rule: Box<dyn LintRule>, level: Level) -> Box<dyn LintRule> {
+        Box::new(Self { rule, level })
+    }
+}
+
+impl LintRule for LevelOverride {
+    fn name(&self) -> &'static str {
+        self.rule.name()
+    }
+
+    fn scoped_name(&self) -> String {
+        self.rule.scoped_name()
+    }
+
+    fn scope(&self) -> LintScope {
+        self.rule.scope()
+    }
+
+    fn category(&self) -> LintCategory {
+        self.rule.category()
+    }
+
+    fn level(&self) -> Level {
+        self.level
+    }
+
+    fn groups(&self) -> &'static [LintGroup] {
+        self.rule.groups()
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        // The wrapped lint rule creates its issues with its default level.
+        let issue_count = issues.len();
+        self.rule.run(resources, issues)?;
+        for issue in issues.iter_mut().skip(issue_count) {
+            issue.level = self.level;
+        }
+
+        Ok(())
+    }
+
+    fn fix(&self, resources: &Resources) -> Result<Vec<Edit>, Error> {
+        self.rule.fix(resources)
+    }
+
+    fn fixable(&self) -> bool {
+        self.rule.fixable()
+    }
+
+    fn documentation(&self) -> String {
+        self.rule.documentation()
+    }
+
+    fn example(&self) -> Option<String> {
+        self.rule.example()
+    }
+
+    fn help_text(&self) -> String {
+        self.rule.help_text()
+    }
+
+    fn configuration_options(&self) -> &[LintRuleConfigurationOptionName] {
+        self.rule.configuration_options()
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        self.rule.extra_links()
+    }
+}
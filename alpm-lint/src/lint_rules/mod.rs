@@ -1,4 +1,10 @@
 //! Lint rules covering all supported scopes.
 
+pub mod custom;
+pub mod level_override;
+pub mod package;
+pub mod package_artifact;
+pub mod package_build;
+pub mod relations;
 pub mod source_info;
 pub mod store;
@@ -0,0 +1,264 @@
+//! Ensures that the build dependencies of a package match the packages installed during its build.
+
+use std::collections::{BTreeMap, HashSet};
+
+use alpm_buildinfo::BuildInfo;
+use alpm_lint_config::LintRuleConfigurationOptionName;
+use alpm_pkginfo::PackageInfo;
+use alpm_srcinfo::{MergedPackage, SourceInfo};
+use alpm_types::{InstalledPackage, PackageRelation, RelationOrSoname, Version};
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    issue::SourceInfoIssue,
+    lint_rules::package::package_metadata_from_resource,
+};
+
+/// # What it does
+///
+/// Compares the packages installed in the build environment of a package (the `installed` field
+/// of its [BUILDINFO] data) with the build dependencies declared for it in the [SRCINFO] data it
+/// has been built from (the `makedepends`, `checkdepends` and `depends` of the package for its
+/// architecture).
+///
+/// Reports
+///
+/// - installed packages that are neither declared as (build) dependency of the package, nor listed
+///   in the `build_environment_packages` option,
+/// - and declared build dependencies that were not installed (or only in a version that does not
+///   satisfy the version requirement of the build dependency).
+///
+/// The [SRCINFO] data is read from a `.SRCINFO` file next to the [PKGINFO] and [BUILDINFO] files
+/// of the package.
+/// If no such file exists, or it does not describe the package, this lint rule does not report
+/// any issues.
+///
+/// # Why is this bad?
+///
+/// A package that was built with undeclared build dependencies may only build successfully in an
+/// environment that happens to provide them.
+/// Such packages can not be rebuilt reliably in a clean build environment.
+///
+/// A declared build dependency that was not installed during the build hints at a stale
+/// declaration or at a build environment that differs from the one described by the package
+/// sources.
+///
+/// # Note
+///
+/// Without access to package repositories, the transitive dependencies of the declared build
+/// dependencies can not be resolved.
+/// Those, as well as build dependencies that are satisfied by a provision of another package,
+/// are reported as well, which is why this lint rule is part of the pedantic group.
+/// Packages that are present in every build environment (e.g. the members of the `base-devel`
+/// package group) can be listed in the `build_environment_packages` option.
+///
+/// # Example
+///
+/// A package built from the following [SRCINFO] data:
+///
+/// ```ini,ignore
+/// pkgbase = example
+///     makedepends = cmake
+/// ```
+///
+/// that has been built using the following [BUILDINFO] data:
+///
+/// ```text,ignore
+/// installed = meson-1.5.1-1-any
+/// ```
+///
+/// Use instead:
+///
+/// ```ini,ignore
+/// pkgbase = example
+///     makedepends = meson
+/// ```
+///
+/// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+#[derive(Clone, Debug, Documented)]
+pub struct BuildDependencyMismatch {
+    build_environment_packages: Vec<String>,
+}
+
+impl BuildDependencyMismatch {
+    /// Create a new, boxed instance of [`BuildDependencyMismatch`].
+    ///
+    /// The packages expected in every build environment are taken from the
+    /// `build_environment_packages` option of `config`.
+    pub fn new_boxed(config: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {
+            build_environment_packages: config.build_environment_packages.clone(),
+        })
+    }
+}
+
+/// Returns the [`MergedPackage`] that `package_info` has been built from.
+///
+/// Returns [`None`] if `source_info` does not describe the package of `package_info` for its
+/// architecture.
+fn merged_package(source_info: &SourceInfo, package_info: &PackageInfo) -> Option<MergedPackage> {
+    let (name, architecture) = match package_info {
+        PackageInfo::V1(package_info) => (&package_info.pkgname, &package_info.arch),
+        PackageInfo::V2(package_info) => (&package_info.pkgname, &package_info.arch),
+    };
+    let SourceInfo::V1(source_info) = source_info;
+
+    source_info
+        .packages_for_architecture(architecture.clone())
+        .find(|package| &package.name == name)
+}
+
+/// Returns the packages installed in the build environment of `build_info`.
+fn installed_packages(build_info: &BuildInfo) -> &[InstalledPackage] {
+    match build_info {
+        BuildInfo::V1(build_info) => &build_info.installed,
+        BuildInfo::V2(build_info) => &build_info.installed,
+    }
+}
+
+impl LintRule for BuildDependencyMismatch {
+    fn name(&self) -> &'static str {
+        "build_dependency_mismatch"
+    }
+
+    fn scope(&self) -> LintScope {
+        LintScope::Package
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Reproducibility
+    }
+
+    fn groups(&self) -> &'static [LintGroup] {
+        &[LintGroup::Pedantic]
+    }
+
+    fn documentation(&self) -> String {
+        BuildDependencyMismatch::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        r#"The build dependencies of a package must match the packages installed during its build.
+
+Declare all packages required for building and testing the package in the 'makedepends' and
+'checkdepends' arrays of the PKGBUILD and remove declarations that are no longer needed.
+Packages that are present in every build environment can be listed in the
+'build_environment_packages' option.
+"#
+        .into()
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        let (package_info, build_info, source_info) =
+            package_metadata_from_resource(resources, self.scoped_name())?;
+        let Some(package) =
+            source_info.and_then(|source_info| merged_package(source_info, package_info))
+        else {
+            return Ok(());
+        };
+        let installed = installed_packages(build_info);
+        let build_dependencies: Vec<(&str, &PackageRelation)> = package
+            .make_dependencies
+            .iter()
+            .map(|relation| ("makedepends", relation))
+            .chain(
+                package
+                    .check_dependencies
+                    .iter()
+                    .map(|relation| ("checkdepends", relation)),
+            )
+            .collect();
+
+        // Report declared build dependencies that are not satisfied by the build environment.
+        for (field_name, relation) in &build_dependencies {
+            let context = match installed
+                .iter()
+                .find(|installed| installed.name() == &relation.name)
+            {
+                None => "Declared build dependency was not installed".to_string(),
+                Some(installed) => {
+                    let Some(requirement) = &relation.version_requirement else {
+                        continue;
+                    };
+                    if requirement.is_satisfied_by(&Version::from(installed.version())) {
+                        continue;
+                    }
+                    format!(
+                        "Declared build dependency is not satisfied by installed version {}",
+                        installed.version()
+                    )
+                }
+            };
+
+            issues.push(LintIssue::from_rule(
+                self,
+                SourceInfoIssue::PackageField {
+                    field_name: field_name.to_string(),
+                    package_name: package.name.to_string(),
+                    value: relation.to_string(),
+                    context,
+                    architecture: None,
+                }
+                .into(),
+            ));
+        }
+
+        // Report installed packages that are not declared.
+        let declared_names: HashSet<&str> = build_dependencies
+            .iter()
+            .map(|(_, relation)| relation.name.inner())
+            .chain(
+                package
+                    .dependencies
+                    .iter()
+                    .filter_map(|dependency| match dependency {
+                        RelationOrSoname::Relation(relation) => Some(relation.name.inner()),
+                        RelationOrSoname::SonameV1(_) | RelationOrSoname::SonameV2(_) => None,
+                    }),
+            )
+            .chain(std::iter::once(package.name.inner()))
+            .chain(self.build_environment_packages.iter().map(String::as_str))
+            .collect();
+
+        for installed in installed {
+            if declared_names.contains(installed.name().inner()) {
+                continue;
+            }
+
+            issues.push(LintIssue::from_rule(
+                self,
+                SourceInfoIssue::PackageField {
+                    field_name: "makedepends".to_string(),
+                    package_name: package.name.to_string(),
+                    value: installed.to_string(),
+                    context: "Installed package is not declared as build dependency".to_string(),
+                    architecture: None,
+                }
+                .into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn configuration_options(&self) -> &[LintRuleConfigurationOptionName] {
+        &[LintRuleConfigurationOptionName::build_environment_packages]
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "BUILDINFO specification".to_string(),
+            "https://alpm.archlinux.page/specifications/BUILDINFO.5.html".to_string(),
+        );
+        links.insert(
+            "SRCINFO specification".to_string(),
+            "https://alpm.archlinux.page/specifications/SRCINFO.5.html".to_string(),
+        );
+
+        Some(links)
+    }
+}
@@ -0,0 +1,45 @@
+//! All lints for the metadata files of a single package.
+//!
+//! Each lint rule in this module is available in the [`LintScope::Package`] scope and may
+//! cross-check the data of the [BUILDINFO] and [PKGINFO] files of a package, as well as the
+//! [SRCINFO] file it has been built from (if available).
+//!
+//! [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+//! [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+//! [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+
+use alpm_buildinfo::BuildInfo;
+use alpm_pkginfo::PackageInfo;
+use alpm_srcinfo::SourceInfo;
+
+use crate::{Error, LintScope, Resources};
+
+pub mod build_dependency_mismatch;
+
+/// Extracts the [`PackageInfo`], [`BuildInfo`] and optional [`SourceInfo`] of a package from a
+/// [`Resources`].
+///
+/// # Note
+///
+/// The `lint_rule` needs to be provided to provide a meaningful message in case of an error.
+///
+/// # Errors
+///
+/// Returns an error if `resources` does not contain the metadata files of a package.
+fn package_metadata_from_resource(
+    resources: &Resources,
+    lint_rule: String,
+) -> Result<(&PackageInfo, &BuildInfo, Option<&SourceInfo>), Error> {
+    match resources {
+        Resources::Package {
+            package_info,
+            build_info,
+            source_info,
+        } => Ok((package_info, build_info, source_info.as_ref())),
+        _ => Err(Error::InvalidResources {
+            scope: resources.scope(),
+            lint_rule,
+            expected: LintScope::Package,
+        }),
+    }
+}
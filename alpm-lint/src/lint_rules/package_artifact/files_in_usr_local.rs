@@ -0,0 +1,117 @@
+//! Ensures that an [alpm-package] does not contain files in `/usr/local`.
+//!
+//! [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+
+use std::{collections::BTreeMap, path::Path};
+
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    issue::PackageArtifactIssue,
+    lint_rules::package_artifact::mtree_paths_from_resource,
+};
+
+/// # What it does
+///
+/// Ensures that an [alpm-package] does not contain any files, directories or symlinks below
+/// `/usr/local`.
+///
+/// # Why is this bad?
+///
+/// The `/usr/local` hierarchy is reserved for use by the local system administrator (see the
+/// [Filesystem Hierarchy Standard]).
+/// Packages must never install files into it, as this may overwrite or conflict with locally
+/// installed software.
+///
+/// # Example
+///
+/// A package that contains the following paths:
+///
+/// ```text,ignore
+/// /usr/local/bin/example
+/// ```
+///
+/// Use instead:
+///
+/// ```text,ignore
+/// /usr/bin/example
+/// ```
+///
+/// [Filesystem Hierarchy Standard]: https://refspecs.linuxfoundation.org/FHS_3.0/fhs/ch04s09.html
+/// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+#[derive(Clone, Debug, Documented)]
+pub struct FilesInUsrLocal {}
+
+impl FilesInUsrLocal {
+    /// Create a new, boxed instance of [`FilesInUsrLocal`].
+    pub fn new_boxed(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {})
+    }
+}
+
+impl LintRule for FilesInUsrLocal {
+    fn name(&self) -> &'static str {
+        "files_in_usr_local"
+    }
+
+    fn scope(&self) -> LintScope {
+        LintScope::PackageArtifact
+    }
+
+    fn level(&self) -> Level {
+        Level::Deny
+    }
+
+    fn documentation(&self) -> String {
+        FilesInUsrLocal::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        r#"Packages must not contain files in '/usr/local'.
+
+The '/usr/local' hierarchy is reserved for the local system administrator.
+Install the files to '/usr' instead (e.g. by setting the installation prefix to '/usr').
+"#
+        .into()
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        let paths = mtree_paths_from_resource(resources, self.scoped_name())?;
+
+        for path in paths {
+            // Paths in ALPM-MTREE files are relative to the root of the package (e.g.
+            // `./usr/local`).
+            let Ok(normalized_path) = path.as_normalized_path() else {
+                continue;
+            };
+
+            if normalized_path.starts_with(Path::new("usr/local")) {
+                issues.push(LintIssue::from_rule(
+                    self,
+                    PackageArtifactIssue::Path {
+                        path: Path::new("/").join(normalized_path),
+                        context: "Found path in '/usr/local'".to_string(),
+                    }
+                    .into(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "Filesystem Hierarchy Standard".to_string(),
+            "https://refspecs.linuxfoundation.org/FHS_3.0/fhs/ch04s09.html".to_string(),
+        );
+        links.insert(
+            "alpm-package specification".to_string(),
+            "https://alpm.archlinux.page/specifications/alpm-package.7.html".to_string(),
+        );
+
+        Some(links)
+    }
+}
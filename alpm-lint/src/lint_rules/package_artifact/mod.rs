@@ -0,0 +1,32 @@
+//! All lints for [alpm-package] files.
+//!
+//! [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+
+use alpm_mtree::{Mtree, mtree::v2::Path};
+
+use crate::{Error, LintScope, Resources};
+
+pub mod files_in_usr_local;
+
+/// Extracts the [`Path`]s of the [`Mtree`] of a package from a [`Resources`].
+///
+/// # Note
+///
+/// The `lint_rule` needs to be provided to provide a meaningful message in case of an error.
+///
+/// # Errors
+///
+/// Returns an error if `resources` does not contain the data of a package file.
+fn mtree_paths_from_resource(resources: &Resources, lint_rule: String) -> Result<&[Path], Error> {
+    match resources {
+        Resources::PackageArtifact {
+            mtree: Mtree::V1(paths) | Mtree::V2(paths),
+            ..
+        } => Ok(paths),
+        _ => Err(Error::InvalidResources {
+            scope: resources.scope(),
+            lint_rule,
+            expected: LintScope::PackageArtifact,
+        }),
+    }
+}
@@ -0,0 +1,187 @@
+//! Ensures that a [PKGBUILD] only declares checksum variables of allowed algorithms.
+//!
+//! [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+
+use std::collections::BTreeMap;
+
+use alpm_lint_config::LintRuleConfigurationOptionName;
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    issue::PackageBuildIssue,
+    lint_rules::package_build::package_build_script_from_resource,
+};
+
+/// The prefixes of all checksum variables supported by [makepkg] (e.g. `md5` for `md5sums`).
+///
+/// [makepkg]: https://man.archlinux.org/man/makepkg.8
+const CHECKSUM_ALGORITHMS: &[&str] = &[
+    "b2", "ck", "md5", "sha1", "sha224", "sha256", "sha384", "sha512",
+];
+
+/// # What it does
+///
+/// Ensures that a [PKGBUILD] only declares checksum variables (e.g. `sha256sums` or
+/// `b2sums_x86_64`) of the algorithms allowed by the `allowed_checksum_algorithms` option.
+///
+/// # Why is this bad?
+///
+/// Upstream artifacts are validated against hash digests (see [alpm-package-source-checksum])
+/// declared in checksum variables of a [PKGBUILD].
+///
+/// Some hash functions (e.g. MD-5 and SHA-1) are unsafe to use from a cryptographic perspective,
+/// and a distribution's packaging policy may require the use of specific hash functions.
+/// Declaring checksum variables of other algorithms is deprecated.
+///
+/// Unlike the `source_info::unsafe_checksum` lint rule, this lint rule refers to the line of the
+/// [PKGBUILD] in which a deprecated checksum variable is declared.
+///
+/// # Example
+///
+/// ```text,ignore
+/// source=("https://domain.tld/testing/test.tar.gz")
+/// md5sums=('10245815f893d79f3d779690774f0b43')
+/// ```
+///
+/// Use instead:
+///
+/// ```text,ignore
+/// source=("https://domain.tld/testing/test.tar.gz")
+/// sha256sums=('b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c')
+/// ```
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+/// [alpm-package-source-checksum]: https://alpm.archlinux.page/specifications/alpm-package-source-checksum.7.html
+#[derive(Clone, Debug, Documented)]
+pub struct DeprecatedChecksumVariable {
+    allowed_checksum_algorithms: Vec<String>,
+}
+
+impl DeprecatedChecksumVariable {
+    /// Create a new, boxed instance of [`DeprecatedChecksumVariable`].
+    ///
+    /// The allowed checksum algorithms are taken from the `allowed_checksum_algorithms` option of
+    /// `config`.
+    pub fn new_boxed(config: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {
+            allowed_checksum_algorithms: config.allowed_checksum_algorithms.clone(),
+        })
+    }
+}
+
+/// Returns the checksum algorithm of a checksum variable called `name`.
+///
+/// Architecture specific checksum variables (e.g. `md5sums_x86_64`) are considered.
+///
+/// Returns [`None`] if `name` is not the name of a checksum variable.
+fn checksum_algorithm(name: &str) -> Option<&str> {
+    let (algorithm, suffix) = name.split_once("sums")?;
+    if !suffix.is_empty() && !suffix.starts_with('_') {
+        return None;
+    }
+
+    CHECKSUM_ALGORITHMS
+        .contains(&algorithm)
+        .then_some(algorithm)
+}
+
+impl LintRule for DeprecatedChecksumVariable {
+    fn name(&self) -> &'static str {
+        "deprecated_checksum_variable"
+    }
+
+    fn scope(&self) -> LintScope {
+        LintScope::PackageBuild
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Security
+    }
+
+    fn level(&self) -> Level {
+        Level::Warn
+    }
+
+    fn documentation(&self) -> String {
+        DeprecatedChecksumVariable::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        format!(
+            r#"Checksum variables of deprecated algorithms must not be used.
+
+Instead, use one of the following algorithms: {}
+"#,
+            self.allowed_checksum_algorithms.join(", ")
+        )
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        let Some(script) = package_build_script_from_resource(resources, self.scoped_name())?
+        else {
+            return Ok(());
+        };
+
+        for assignment in script.assignments() {
+            let Some(algorithm) = checksum_algorithm(&assignment.name) else {
+                continue;
+            };
+            if self
+                .allowed_checksum_algorithms
+                .iter()
+                .any(|allowed| allowed == algorithm)
+            {
+                continue;
+            }
+
+            let line = &script.lines()[assignment.line - 1];
+            issues.push(LintIssue::from_rule(
+                self,
+                PackageBuildIssue::Line {
+                    line: line.number,
+                    function: None,
+                    content: line.content.clone(),
+                    context: format!("Deprecated checksum variable '{}'", assignment.name),
+                }
+                .into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn configuration_options(&self) -> &[LintRuleConfigurationOptionName] {
+        &[LintRuleConfigurationOptionName::allowed_checksum_algorithms]
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "alpm-package-source-checksum specification".to_string(),
+            "https://alpm.archlinux.page/specifications/alpm-package-source-checksum.7.html"
+                .to_string(),
+        );
+
+        Some(links)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Ensures that the algorithms of checksum variables are recognized.
+    #[rstest]
+    #[case("md5sums", Some("md5"))]
+    #[case("sha256sums_x86_64", Some("sha256"))]
+    #[case("cksums", Some("ck"))]
+    #[case("foosums", None)]
+    #[case("md5sumsx", None)]
+    #[case("source", None)]
+    fn checksum_algorithms(#[case] name: &str, #[case] expected: Option<&str>) {
+        assert_eq!(checksum_algorithm(name), expected);
+    }
+}
@@ -0,0 +1,110 @@
+//! All lints for [PKGBUILD] files.
+//!
+//! [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+
+use alpm_pkgbuild::script::PackageBuildScript;
+
+use crate::{Error, LintScope, Resources};
+
+pub mod deprecated_checksum_variable;
+pub mod unnecessary_cd_guard;
+pub mod unquoted_build_directory;
+
+/// Extracts a [`PackageBuildScript`] from a [`Resources`].
+///
+/// Returns [`None`] for [`Resources::Package`], as the [PKGBUILD] is not part of its data.
+///
+/// # Note
+///
+/// The `lint_rule` needs to be provided to provide a meaningful message in case of an error.
+///
+/// # Errors
+///
+/// Returns an error if `resources` can not contain [PKGBUILD] data.
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+fn package_build_script_from_resource(
+    resources: &Resources,
+    lint_rule: String,
+) -> Result<Option<&PackageBuildScript>, Error> {
+    match resources {
+        Resources::SourceRepository {
+            package_build_script: script,
+            ..
+        }
+        | Resources::PackageBuild { script, .. } => Ok(Some(script)),
+        Resources::Package { .. } => Ok(None),
+        _ => Err(Error::InvalidResources {
+            scope: resources.scope(),
+            lint_rule,
+            expected: LintScope::PackageBuild,
+        }),
+    }
+}
+
+/// Returns the code of a single line of a [PKGBUILD], in which quoted strings are masked.
+///
+/// All characters in single or double quotes (including the quotes themselves) and all escaped
+/// characters are replaced with `"`.
+/// Comments are removed.
+///
+/// # Note
+///
+/// Quotes spanning multiple lines are not considered.
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+fn unquoted_code(line: &str) -> String {
+    let mut code = String::with_capacity(line.len());
+    let mut previous = ' ';
+    let mut single_quoted = false;
+    let mut double_quoted = false;
+    let mut escaped = false;
+
+    for c in line.chars() {
+        let masked = if escaped {
+            escaped = false;
+            true
+        } else if single_quoted {
+            single_quoted = c != '\'';
+            true
+        } else if double_quoted {
+            match c {
+                '\\' => escaped = true,
+                '"' => double_quoted = false,
+                _ => {}
+            }
+            true
+        } else {
+            match c {
+                '\\' => escaped = true,
+                '\'' => single_quoted = true,
+                '"' => double_quoted = true,
+                '#' if previous.is_whitespace() => break,
+                _ => {}
+            }
+            escaped || single_quoted || double_quoted
+        };
+
+        code.push(if masked { '"' } else { c });
+        previous = c;
+    }
+
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Ensures that quoted strings and comments are masked.
+    #[rstest]
+    #[case(r#"cd "$srcdir" # comment"#, r#"cd """"""""" "#)]
+    #[case("echo '$pkgdir' $pkgdir", r#"echo """"""""" $pkgdir"#)]
+    #[case(r"echo \$srcdir", r#"echo ""srcdir"#)]
+    #[case("echo foo#bar", "echo foo#bar")]
+    fn unquoted_code_masks_quotes(#[case] line: &str, #[case] expected: &str) {
+        assert_eq!(unquoted_code(line), expected);
+    }
+}
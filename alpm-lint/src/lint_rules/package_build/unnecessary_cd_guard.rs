@@ -0,0 +1,158 @@
+//! Detects `cd` commands with unnecessary `||` guards in the functions of a [PKGBUILD].
+//!
+//! [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+
+use std::collections::BTreeMap;
+
+use alpm_pkgbuild::script::ScriptLine;
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    issue::PackageBuildIssue,
+    lint_rules::package_build::{package_build_script_from_resource, unquoted_code},
+};
+
+/// # What it does
+///
+/// Detects `cd` commands in the functions of a [PKGBUILD], which are guarded using `||` (e.g.
+/// `cd "$srcdir" || exit 1`).
+///
+/// # Why is this bad?
+///
+/// [makepkg] runs all functions of a [PKGBUILD] with the `errexit` option of [bash] (i.e.
+/// `set -e`).
+/// If a `cd` command fails, the function is aborted and the build fails.
+///
+/// Guarding `cd` commands is therefore unnecessary and only adds noise to the [PKGBUILD].
+/// Such guards are usually added to silence shell linters, which are not aware of the environment
+/// in which the functions are run.
+///
+/// # Example
+///
+/// ```text,ignore
+/// build() {
+///   cd "$pkgname-$pkgver" || exit 1
+///   make
+/// }
+/// ```
+///
+/// Use instead:
+///
+/// ```text,ignore
+/// build() {
+///   cd "$pkgname-$pkgver"
+///   make
+/// }
+/// ```
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+/// [bash]: https://man.archlinux.org/man/bash.1
+/// [makepkg]: https://man.archlinux.org/man/makepkg.8
+#[derive(Clone, Debug, Documented)]
+pub struct UnnecessaryCdGuard {}
+
+impl UnnecessaryCdGuard {
+    /// Create a new, boxed instance of [`UnnecessaryCdGuard`].
+    pub fn new_boxed(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {})
+    }
+
+    /// Returns whether `line` contains a `cd` command that is guarded using `||`.
+    fn has_guarded_cd(line: &ScriptLine) -> bool {
+        let code = unquoted_code(&line.content);
+        let code = code.trim_start();
+
+        (code.starts_with("cd ") || code.starts_with("cd\t")) && code.contains("||")
+    }
+}
+
+impl LintRule for UnnecessaryCdGuard {
+    fn name(&self) -> &'static str {
+        "unnecessary_cd_guard"
+    }
+
+    fn scope(&self) -> LintScope {
+        LintScope::PackageBuild
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn level(&self) -> Level {
+        Level::Suggest
+    }
+
+    fn documentation(&self) -> String {
+        UnnecessaryCdGuard::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        r#"Guarding 'cd' commands with '||' is unnecessary in PKGBUILD functions.
+
+makepkg runs all functions with 'set -e', which aborts the build if 'cd' fails.
+Remove the '||' guard.
+"#
+        .into()
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        let Some(script) = package_build_script_from_resource(resources, self.scoped_name())?
+        else {
+            return Ok(());
+        };
+
+        for line in script.all_function_lines() {
+            if Self::has_guarded_cd(line) {
+                issues.push(LintIssue::from_rule(
+                    self,
+                    PackageBuildIssue::Line {
+                        line: line.number,
+                        function: line.function.clone(),
+                        content: line.content.clone(),
+                        context: "Unnecessary guard of 'cd'".to_string(),
+                    }
+                    .into(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "makepkg man page".to_string(),
+            "https://man.archlinux.org/man/makepkg.8".to_string(),
+        );
+
+        Some(links)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Ensures that guarded `cd` commands are detected.
+    #[rstest]
+    #[case::exit(r#"  cd "$srcdir" || exit 1"#, true)]
+    #[case::return_(r#"  cd "$pkgname-$pkgver" || return"#, true)]
+    #[case::unguarded(r#"  cd "$srcdir""#, false)]
+    #[case::quoted(r#"  cd "foo || bar""#, false)]
+    #[case::comment(r#"  cd "$srcdir" # || exit 1"#, false)]
+    #[case::other_command("  make || true", false)]
+    fn guarded_cd(#[case] content: &str, #[case] expected: bool) {
+        let line = ScriptLine {
+            number: 1,
+            content: content.to_string(),
+            function: Some("build".to_string()),
+        };
+
+        assert_eq!(UnnecessaryCdGuard::has_guarded_cd(&line), expected);
+    }
+}
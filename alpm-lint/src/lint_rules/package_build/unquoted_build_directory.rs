@@ -0,0 +1,224 @@
+//! Ensures that the `srcdir` and `pkgdir` variables are quoted in the functions of a [PKGBUILD].
+//!
+//! [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+
+use std::collections::BTreeMap;
+
+use alpm_pkgbuild::script::ScriptLine;
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    issue::PackageBuildIssue,
+    lint_rules::package_build::{package_build_script_from_resource, unquoted_code},
+};
+
+/// The names of the build directory variables that are set by [makepkg].
+///
+/// [makepkg]: https://man.archlinux.org/man/makepkg.8
+const BUILD_DIRECTORY_VARIABLES: &[&str] = &["srcdir", "pkgdir"];
+
+/// # What it does
+///
+/// Ensures that the `srcdir` and `pkgdir` variables are quoted when they are used in the functions
+/// of a [PKGBUILD].
+///
+/// # Why is this bad?
+///
+/// The `srcdir` and `pkgdir` variables are set by [makepkg] and contain absolute paths to the
+/// build directories.
+/// These paths depend on the environment in which a package is built and may contain whitespace
+/// (e.g. if the `BUILDDIR` of a user contains a space).
+///
+/// Unquoted variables are subject to word splitting and pathname expansion in [bash].
+/// If the path of a build directory contains whitespace, commands in the [PKGBUILD] operate on
+/// the wrong files, which may lead to failing builds or even data loss (e.g. with `rm -rf`).
+///
+/// # Example
+///
+/// ```text,ignore
+/// package() {
+///   install -Dm644 README $pkgdir/usr/share/doc/example/README
+/// }
+/// ```
+///
+/// Use instead:
+///
+/// ```text,ignore
+/// package() {
+///   install -Dm644 README "$pkgdir/usr/share/doc/example/README"
+/// }
+/// ```
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+/// [bash]: https://man.archlinux.org/man/bash.1
+/// [makepkg]: https://man.archlinux.org/man/makepkg.8
+#[derive(Clone, Debug, Documented)]
+pub struct UnquotedBuildDirectory {}
+
+impl UnquotedBuildDirectory {
+    /// Create a new, boxed instance of [`UnquotedBuildDirectory`].
+    pub fn new_boxed(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {})
+    }
+
+    /// Returns the first unquoted build directory variable in `line`.
+    ///
+    /// Variables on the right-hand side of variable assignments are ignored, as no word splitting
+    /// is performed for them.
+    fn unquoted_variable(line: &ScriptLine) -> Option<&'static str> {
+        let code = unquoted_code(&line.content);
+
+        for (index, _) in code.match_indices('$') {
+            let expansion = &code[index + 1..];
+            for &variable in BUILD_DIRECTORY_VARIABLES {
+                let matches = match expansion.strip_prefix(variable) {
+                    Some(rest) => {
+                        !rest.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+                    }
+                    None => expansion.starts_with(&format!("{{{variable}}}")),
+                };
+                if !matches {
+                    continue;
+                }
+
+                if !is_assignment(&code[..index]) {
+                    return Some(variable);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// The names of the [bash] builtins, whose arguments may be variable assignments.
+///
+/// [bash]: https://man.archlinux.org/man/bash.1
+const DECLARATION_BUILTINS: &[&str] = &["declare", "export", "local", "readonly", "typeset"];
+
+/// Returns whether the `code` preceding a variable expansion ends in a variable assignment.
+///
+/// This is the case if the last word of `code` is the beginning of an assignment (e.g. `dir=` or
+/// `dir+=`), which is either placed at the start of a command or passed to a declaration builtin
+/// (e.g. `local dir=`).
+fn is_assignment(code: &str) -> bool {
+    let mut words = code
+        .split(char::is_whitespace)
+        .filter(|word| !word.is_empty());
+    // The code directly preceding the expansion is part of the last word.
+    let last_word = if code.ends_with(char::is_whitespace) {
+        ""
+    } else {
+        words.next_back().unwrap_or_default()
+    };
+
+    is_assignment_word(last_word)
+        && words.all(|word| {
+            is_assignment_word(word)
+                || DECLARATION_BUILTINS.contains(&word)
+                || word.starts_with('-')
+        })
+}
+
+/// Returns whether `word` is the beginning of a variable assignment (e.g. `dir=` or `dir+=`).
+fn is_assignment_word(word: &str) -> bool {
+    let Some((name, _)) = word.split_once('=') else {
+        return false;
+    };
+    let name = name.strip_suffix('+').unwrap_or(name);
+
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl LintRule for UnquotedBuildDirectory {
+    fn name(&self) -> &'static str {
+        "unquoted_build_directory"
+    }
+
+    fn scope(&self) -> LintScope {
+        LintScope::PackageBuild
+    }
+
+    fn level(&self) -> Level {
+        Level::Warn
+    }
+
+    fn documentation(&self) -> String {
+        UnquotedBuildDirectory::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        r#"The 'srcdir' and 'pkgdir' variables must be quoted.
+
+The paths of the build directories may contain whitespace.
+Use double quotes (e.g. "$pkgdir/usr/bin") to prevent word splitting.
+"#
+        .into()
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        let Some(script) = package_build_script_from_resource(resources, self.scoped_name())?
+        else {
+            return Ok(());
+        };
+
+        for line in script.all_function_lines() {
+            if let Some(variable) = Self::unquoted_variable(line) {
+                issues.push(LintIssue::from_rule(
+                    self,
+                    PackageBuildIssue::Line {
+                        line: line.number,
+                        function: line.function.clone(),
+                        content: line.content.clone(),
+                        context: format!("Unquoted variable '{variable}'"),
+                    }
+                    .into(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "PKGBUILD man page".to_string(),
+            "https://man.archlinux.org/man/PKGBUILD.5".to_string(),
+        );
+
+        Some(links)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Ensures that unquoted build directory variables are detected.
+    #[rstest]
+    #[case::unquoted("  cd $srcdir/example", Some("srcdir"))]
+    #[case::unquoted_braces("  install -Dm644 README ${pkgdir}/README", Some("pkgdir"))]
+    #[case::double_quoted(r#"  cd "$srcdir/example""#, None)]
+    #[case::double_quoted_braces(r#"  cd "${srcdir}"/example"#, None)]
+    #[case::single_quoted("  echo '$pkgdir'", None)]
+    #[case::escaped(r"  echo \$pkgdir", None)]
+    #[case::comment("  true # cd $srcdir", None)]
+    #[case::other_variable("  cd $srcdir_example", None)]
+    #[case::assignment("  local dir=$srcdir/example", None)]
+    #[case::assignment_argument("  make DESTDIR=$pkgdir install", Some("pkgdir"))]
+    fn unquoted_variables(#[case] content: &str, #[case] expected: Option<&str>) {
+        let line = ScriptLine {
+            number: 1,
+            content: content.to_string(),
+            function: Some("package".to_string()),
+        };
+
+        assert_eq!(UnquotedBuildDirectory::unquoted_variable(&line), expected);
+    }
+}
@@ -0,0 +1,145 @@
+//! Ensures that dependencies of packages are not specified twice.
+
+use std::collections::BTreeMap;
+
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    lint_rules::relations::{RelationField, push_relation_issue, relations_from_resource},
+};
+
+/// # What it does
+///
+/// Ensures that the run-time dependencies and optional dependencies (see
+/// [alpm-package-relations]) of a package do not contain identical entries.
+///
+/// # Why is this bad?
+///
+/// Duplicate dependencies are confusing and do not serve a purpose.
+/// They are usually the result of merging dependency lists or of architecture-specific
+/// dependencies, that repeat a dependency which applies to all architectures.
+///
+/// # Example
+///
+/// ```ini,ignore
+/// pkgbase = example
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     depends = glibc
+///     depends_x86_64 = glibc
+///
+/// pkgname = example
+/// ```
+///
+/// Use instead:
+///
+/// ```ini,ignore
+/// pkgbase = example
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     depends = glibc
+///
+/// pkgname = example
+/// ```
+///
+/// [alpm-package-relations]: https://alpm.archlinux.page/specifications/alpm-package-relation.7.html
+#[derive(Clone, Debug, Documented)]
+pub struct DuplicateDependency {
+    scope: LintScope,
+}
+
+impl DuplicateDependency {
+    /// Create a new, boxed instance of [`DuplicateDependency`] for [`LintScope::SourceInfo`].
+    pub fn new_boxed_source_info(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {
+            scope: LintScope::SourceInfo,
+        })
+    }
+
+    /// Create a new, boxed instance of [`DuplicateDependency`] for [`LintScope::PackageInfo`].
+    pub fn new_boxed_package_info(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {
+            scope: LintScope::PackageInfo,
+        })
+    }
+}
+
+impl LintRule for DuplicateDependency {
+    fn name(&self) -> &'static str {
+        "duplicate_dependency"
+    }
+
+    fn scope(&self) -> LintScope {
+        self.scope
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn level(&self) -> Level {
+        Level::Warn
+    }
+
+    fn documentation(&self) -> String {
+        DuplicateDependency::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        r#"Dependency lists of packages should always be unique.
+
+Remove the duplicate entries from the run-time or optional dependencies.
+"#
+        .into()
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        let packages = relations_from_resource(resources, self.scope, self.scoped_name())?;
+
+        for package in &packages {
+            for (index, dependency) in package.dependencies.iter().enumerate() {
+                if package.dependencies[..index].contains(dependency) {
+                    push_relation_issue(
+                        issues,
+                        self,
+                        &package.name,
+                        RelationField::Dependency,
+                        dependency.to_string(),
+                        "Found duplicate dependency",
+                    );
+                }
+            }
+
+            for (index, dependency) in package.optional_dependencies.iter().enumerate() {
+                if package.optional_dependencies[..index]
+                    .iter()
+                    .any(|other| other.package_relation() == dependency.package_relation())
+                {
+                    push_relation_issue(
+                        issues,
+                        self,
+                        &package.name,
+                        RelationField::OptionalDependency,
+                        dependency.to_string(),
+                        "Found duplicate optional dependency",
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "alpm-package-relation specification".to_string(),
+            "https://alpm.archlinux.page/specifications/alpm-package-relation.7.html".to_string(),
+        );
+
+        Some(links)
+    }
+}
@@ -0,0 +1,134 @@
+//! Ensures that optional dependencies of packages have a description.
+
+use std::collections::BTreeMap;
+
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    lint_rules::relations::{RelationField, push_relation_issue, relations_from_resource},
+};
+
+/// # What it does
+///
+/// Ensures that each optional dependency (see [alpm-package-relations]) of a package has a
+/// description.
+///
+/// # Why is this bad?
+///
+/// The description of an optional dependency is shown to users when installing a package.
+/// Without it, users cannot know which functionality an optional dependency enables and whether
+/// they need to install it.
+///
+/// # Example
+///
+/// ```ini,ignore
+/// pkgbase = example
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     optdepends = python
+///
+/// pkgname = example
+/// ```
+///
+/// Use instead:
+///
+/// ```ini,ignore
+/// pkgbase = example
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     optdepends = python: for the python bindings
+///
+/// pkgname = example
+/// ```
+///
+/// [alpm-package-relations]: https://alpm.archlinux.page/specifications/alpm-package-relation.7.html
+#[derive(Clone, Debug, Documented)]
+pub struct MissingOptionalDependencyDescription {
+    scope: LintScope,
+}
+
+impl MissingOptionalDependencyDescription {
+    /// Create a new, boxed instance of [`MissingOptionalDependencyDescription`] for
+    /// [`LintScope::SourceInfo`].
+    pub fn new_boxed_source_info(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {
+            scope: LintScope::SourceInfo,
+        })
+    }
+
+    /// Create a new, boxed instance of [`MissingOptionalDependencyDescription`] for
+    /// [`LintScope::PackageInfo`].
+    pub fn new_boxed_package_info(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {
+            scope: LintScope::PackageInfo,
+        })
+    }
+}
+
+impl LintRule for MissingOptionalDependencyDescription {
+    fn name(&self) -> &'static str {
+        "missing_optional_dependency_description"
+    }
+
+    fn scope(&self) -> LintScope {
+        self.scope
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn level(&self) -> Level {
+        Level::Suggest
+    }
+
+    fn documentation(&self) -> String {
+        MissingOptionalDependencyDescription::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        r#"Optional dependencies should have a description.
+
+Describe which functionality an optional dependency enables (e.g. 'python: for the python bindings').
+"#
+        .into()
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        let packages = relations_from_resource(resources, self.scope, self.scoped_name())?;
+
+        for package in &packages {
+            for dependency in &package.optional_dependencies {
+                if dependency
+                    .description()
+                    .as_ref()
+                    .is_none_or(|description| description.trim().is_empty())
+                {
+                    push_relation_issue(
+                        issues,
+                        self,
+                        &package.name,
+                        RelationField::OptionalDependency,
+                        dependency.to_string(),
+                        "Found optional dependency without description",
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "alpm-package-relation specification".to_string(),
+            "https://alpm.archlinux.page/specifications/alpm-package-relation.7.html".to_string(),
+        );
+
+        Some(links)
+    }
+}
@@ -0,0 +1,117 @@
+//! All lints for [alpm-package-relations] in [SRCINFO] and [PKGINFO] data.
+//!
+//! Each lint rule in this module is available in the [`LintScope::SourceInfo`] and the
+//! [`LintScope::PackageInfo`] scope.
+//!
+//! [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+//! [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+//! [alpm-package-relations]: https://alpm.archlinux.page/specifications/alpm-package-relation.7.html
+
+use alpm_types::Name;
+
+use crate::{
+    Error,
+    LintRule,
+    LintScope,
+    PackageRelations,
+    Resources,
+    issue::{LintIssue, PackageInfoIssue, SourceInfoIssue},
+};
+
+pub mod duplicate_dependency;
+pub mod missing_optional_dependency_description;
+pub mod provides_own_name;
+pub mod self_dependency;
+pub mod unsatisfiable_dependency;
+
+/// A field that contains [alpm-package-relations].
+///
+/// [alpm-package-relations]: https://alpm.archlinux.page/specifications/alpm-package-relation.7.html
+#[derive(Clone, Copy, Debug)]
+enum RelationField {
+    /// The run-time dependencies of a package.
+    Dependency,
+    /// The optional dependencies of a package.
+    OptionalDependency,
+    /// The provisions of a package.
+    Provision,
+}
+
+impl RelationField {
+    /// Returns the name of the field in the metadata file of `scope`.
+    fn name(&self, scope: LintScope) -> &'static str {
+        match (self, scope) {
+            (RelationField::Dependency, LintScope::PackageInfo) => "depend",
+            (RelationField::Dependency, _) => "depends",
+            (RelationField::OptionalDependency, LintScope::PackageInfo) => "optdepend",
+            (RelationField::OptionalDependency, _) => "optdepends",
+            (RelationField::Provision, _) => "provides",
+        }
+    }
+}
+
+/// Returns the [`PackageRelations`] of all packages in `resources`.
+///
+/// # Note
+///
+/// The `lint_rule` needs to be provided to provide a meaningful message in case of an error.
+///
+/// # Errors
+///
+/// Returns an error if `resources` are not in `scope`.
+fn relations_from_resource(
+    resources: &Resources,
+    scope: LintScope,
+    lint_rule: String,
+) -> Result<Vec<PackageRelations>, Error> {
+    if !resources.scope().contains(&scope) {
+        return Err(Error::InvalidResources {
+            scope: resources.scope(),
+            lint_rule,
+            expected: scope,
+        });
+    }
+
+    Ok(resources.package_relations())
+}
+
+/// Appends a new [`LintIssue`] for a `value` in a `field` of the package `package_name` to
+/// `issues`.
+///
+/// The type of the issue depends on the scope of `rule`.
+/// As the relations of a package may be checked for several architectures, the issue is only
+/// appended if it has not been encountered before.
+fn push_relation_issue<T: LintRule>(
+    issues: &mut Vec<LintIssue>,
+    rule: &T,
+    package_name: &Name,
+    field: RelationField,
+    value: String,
+    context: &str,
+) {
+    let scope = rule.scope();
+    let issue_type = match scope {
+        LintScope::PackageInfo => PackageInfoIssue::Field {
+            field_name: field.name(scope).to_string(),
+            value,
+            context: context.to_string(),
+        }
+        .into(),
+        _ => SourceInfoIssue::PackageField {
+            field_name: field.name(scope).to_string(),
+            package_name: package_name.to_string(),
+            value,
+            context: context.to_string(),
+            architecture: None,
+        }
+        .into(),
+    };
+    let issue = LintIssue::from_rule(rule, issue_type);
+
+    if !issues
+        .iter()
+        .any(|known| known.lint_rule == issue.lint_rule && known.issue_type == issue.issue_type)
+    {
+        issues.push(issue);
+    }
+}
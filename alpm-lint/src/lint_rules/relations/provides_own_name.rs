@@ -0,0 +1,131 @@
+//! Ensures that packages do not provide their own name.
+
+use std::collections::BTreeMap;
+
+use alpm_types::RelationOrSoname;
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    lint_rules::relations::{RelationField, push_relation_issue, relations_from_resource},
+};
+
+/// # What it does
+///
+/// Ensures that a package does not list its own name as a provision (see
+/// [alpm-package-relations]).
+///
+/// # Why is this bad?
+///
+/// A package implicitly provides its own name in its own version.
+/// Explicitly providing its own name is redundant at best and conflicts with the package's own
+/// version at worst (e.g. if the provision uses a different version).
+///
+/// # Example
+///
+/// ```ini,ignore
+/// pkgbase = example
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     provides = example=2.0.0
+///
+/// pkgname = example
+/// ```
+///
+/// Use instead:
+///
+/// ```ini,ignore
+/// pkgbase = example
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///
+/// pkgname = example
+/// ```
+///
+/// [alpm-package-relations]: https://alpm.archlinux.page/specifications/alpm-package-relation.7.html
+#[derive(Clone, Debug, Documented)]
+pub struct ProvidesOwnName {
+    scope: LintScope,
+}
+
+impl ProvidesOwnName {
+    /// Create a new, boxed instance of [`ProvidesOwnName`] for [`LintScope::SourceInfo`].
+    pub fn new_boxed_source_info(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {
+            scope: LintScope::SourceInfo,
+        })
+    }
+
+    /// Create a new, boxed instance of [`ProvidesOwnName`] for [`LintScope::PackageInfo`].
+    pub fn new_boxed_package_info(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {
+            scope: LintScope::PackageInfo,
+        })
+    }
+}
+
+impl LintRule for ProvidesOwnName {
+    fn name(&self) -> &'static str {
+        "provides_own_name"
+    }
+
+    fn scope(&self) -> LintScope {
+        self.scope
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
+    fn level(&self) -> Level {
+        Level::Warn
+    }
+
+    fn documentation(&self) -> String {
+        ProvidesOwnName::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        r#"A package must not provide its own name.
+
+A package always provides its own name in its own version.
+Remove the package's own name from its provisions.
+"#
+        .into()
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        let packages = relations_from_resource(resources, self.scope, self.scoped_name())?;
+
+        for package in &packages {
+            for provision in &package.provides {
+                if let RelationOrSoname::Relation(relation) = provision
+                    && relation.name == package.name
+                {
+                    push_relation_issue(
+                        issues,
+                        self,
+                        &package.name,
+                        RelationField::Provision,
+                        provision.to_string(),
+                        "Found provision of the package's own name",
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "alpm-package-relation specification".to_string(),
+            "https://alpm.archlinux.page/specifications/alpm-package-relation.7.html".to_string(),
+        );
+
+        Some(links)
+    }
+}
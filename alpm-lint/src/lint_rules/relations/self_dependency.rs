@@ -0,0 +1,139 @@
+//! Ensures that packages do not depend on themselves.
+
+use std::collections::BTreeMap;
+
+use alpm_types::RelationOrSoname;
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    lint_rules::relations::{RelationField, push_relation_issue, relations_from_resource},
+};
+
+/// # What it does
+///
+/// Ensures that a package does not list itself as a run-time dependency or an optional dependency
+/// (see [alpm-package-relations]).
+///
+/// # Why is this bad?
+///
+/// A package is always installed when its own files are used, so depending on itself does not
+/// serve a purpose.
+/// Such a self-dependency is usually the result of a typo or a copy-paste error.
+///
+/// # Example
+///
+/// ```ini,ignore
+/// pkgbase = example
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     depends = example
+///
+/// pkgname = example
+/// ```
+///
+/// Use instead:
+///
+/// ```ini,ignore
+/// pkgbase = example
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///
+/// pkgname = example
+/// ```
+///
+/// [alpm-package-relations]: https://alpm.archlinux.page/specifications/alpm-package-relation.7.html
+#[derive(Clone, Debug, Documented)]
+pub struct SelfDependency {
+    scope: LintScope,
+}
+
+impl SelfDependency {
+    /// Create a new, boxed instance of [`SelfDependency`] for [`LintScope::SourceInfo`].
+    pub fn new_boxed_source_info(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {
+            scope: LintScope::SourceInfo,
+        })
+    }
+
+    /// Create a new, boxed instance of [`SelfDependency`] for [`LintScope::PackageInfo`].
+    pub fn new_boxed_package_info(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {
+            scope: LintScope::PackageInfo,
+        })
+    }
+}
+
+impl LintRule for SelfDependency {
+    fn name(&self) -> &'static str {
+        "self_dependency"
+    }
+
+    fn scope(&self) -> LintScope {
+        self.scope
+    }
+
+    fn level(&self) -> Level {
+        Level::Warn
+    }
+
+    fn documentation(&self) -> String {
+        SelfDependency::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        r#"A package must not depend on itself.
+
+Remove the package's own name from its run-time and optional dependencies.
+"#
+        .into()
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        let packages = relations_from_resource(resources, self.scope, self.scoped_name())?;
+
+        for package in &packages {
+            for dependency in &package.dependencies {
+                if let RelationOrSoname::Relation(relation) = dependency
+                    && relation.name == package.name
+                {
+                    push_relation_issue(
+                        issues,
+                        self,
+                        &package.name,
+                        RelationField::Dependency,
+                        dependency.to_string(),
+                        "Found dependency on the package itself",
+                    );
+                }
+            }
+
+            for dependency in &package.optional_dependencies {
+                if *dependency.name() == package.name {
+                    push_relation_issue(
+                        issues,
+                        self,
+                        &package.name,
+                        RelationField::OptionalDependency,
+                        dependency.to_string(),
+                        "Found optional dependency on the package itself",
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "alpm-package-relation specification".to_string(),
+            "https://alpm.archlinux.page/specifications/alpm-package-relation.7.html".to_string(),
+        );
+
+        Some(links)
+    }
+}
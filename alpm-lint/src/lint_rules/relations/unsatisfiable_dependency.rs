@@ -0,0 +1,239 @@
+//! Ensures that the version requirements of dependencies can be satisfied.
+
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use alpm_types::{
+    PackageRelation,
+    RelationOrSoname,
+    Version,
+    VersionComparison,
+    VersionRequirement,
+};
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    lint_rules::relations::{RelationField, push_relation_issue, relations_from_resource},
+};
+
+/// # What it does
+///
+/// Ensures that the version requirements of all run-time dependencies (see
+/// [alpm-package-relations]) on the same package can be satisfied at the same time.
+///
+/// # Why is this bad?
+///
+/// If a package depends on several, mutually exclusive versions of another package (e.g.
+/// `foo>=2` and `foo<1`), no version of the other package can satisfy all of these dependencies.
+/// Hence, the package can never be installed.
+///
+/// # Example
+///
+/// ```ini,ignore
+/// pkgbase = example
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     depends = foo>=2
+///     depends = foo<1
+///
+/// pkgname = example
+/// ```
+///
+/// Use instead:
+///
+/// ```ini,ignore
+/// pkgbase = example
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     depends = foo>=2
+///     depends = foo<3
+///
+/// pkgname = example
+/// ```
+///
+/// [alpm-package-relations]: https://alpm.archlinux.page/specifications/alpm-package-relation.7.html
+#[derive(Clone, Debug, Documented)]
+pub struct UnsatisfiableDependency {
+    scope: LintScope,
+}
+
+impl UnsatisfiableDependency {
+    /// Create a new, boxed instance of [`UnsatisfiableDependency`] for [`LintScope::SourceInfo`].
+    pub fn new_boxed_source_info(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {
+            scope: LintScope::SourceInfo,
+        })
+    }
+
+    /// Create a new, boxed instance of [`UnsatisfiableDependency`] for
+    /// [`LintScope::PackageInfo`].
+    pub fn new_boxed_package_info(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {
+            scope: LintScope::PackageInfo,
+        })
+    }
+}
+
+impl LintRule for UnsatisfiableDependency {
+    fn name(&self) -> &'static str {
+        "unsatisfiable_dependency"
+    }
+
+    fn scope(&self) -> LintScope {
+        self.scope
+    }
+
+    fn level(&self) -> Level {
+        Level::Error
+    }
+
+    fn documentation(&self) -> String {
+        UnsatisfiableDependency::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        r#"The version requirements of dependencies on the same package must be satisfiable.
+
+No version of the package satisfies all of the version requirements, so the depending package can never be installed.
+Remove or adjust the conflicting version requirements.
+"#
+        .into()
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        let packages = relations_from_resource(resources, self.scope, self.scoped_name())?;
+
+        for package in &packages {
+            let relations: Vec<(&PackageRelation, &VersionRequirement)> = package
+                .dependencies
+                .iter()
+                .filter_map(|dependency| match dependency {
+                    RelationOrSoname::Relation(relation) => relation
+                        .version_requirement
+                        .as_ref()
+                        .map(|requirement| (relation, requirement)),
+                    RelationOrSoname::SonameV1(_) | RelationOrSoname::SonameV2(_) => None,
+                })
+                .collect();
+
+            for (index, (relation, requirement)) in relations.iter().enumerate() {
+                for (other_relation, other_requirement) in &relations[index + 1..] {
+                    if relation.name == other_relation.name
+                        && are_disjoint(requirement, other_requirement)
+                    {
+                        push_relation_issue(
+                            issues,
+                            self,
+                            &package.name,
+                            RelationField::Dependency,
+                            format!("{relation} and {other_relation}"),
+                            "Found dependencies with mutually exclusive version requirements",
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "alpm-package-relation specification".to_string(),
+            "https://alpm.archlinux.page/specifications/alpm-package-relation.7.html".to_string(),
+        );
+        links.insert(
+            "alpm-comparison specification".to_string(),
+            "https://alpm.archlinux.page/specifications/alpm-comparison.7.html".to_string(),
+        );
+
+        Some(links)
+    }
+}
+
+/// Checks whether no version can satisfy both `requirement` and `other`.
+///
+/// As each requirement describes a range of versions, two requirements are disjoint if the lower
+/// bound of one of them is above the upper bound of the other.
+fn are_disjoint(requirement: &VersionRequirement, other: &VersionRequirement) -> bool {
+    is_above(requirement, other) || is_above(other, requirement)
+}
+
+/// Checks whether the lower bound of `lower` is above the upper bound of `upper`.
+///
+/// If one of the versions does not specify a `pkgrel`, it is ignored in the comparison, just like
+/// in [`VersionRequirement::is_satisfied_by`].
+fn is_above(lower: &VersionRequirement, upper: &VersionRequirement) -> bool {
+    let lower_is_strict = match lower.comparison {
+        VersionComparison::Greater => true,
+        VersionComparison::GreaterOrEqual | VersionComparison::Equal => false,
+        VersionComparison::Less | VersionComparison::LessOrEqual => return false,
+    };
+    let upper_is_strict = match upper.comparison {
+        VersionComparison::Less => true,
+        VersionComparison::LessOrEqual | VersionComparison::Equal => false,
+        VersionComparison::Greater | VersionComparison::GreaterOrEqual => return false,
+    };
+
+    let (lower_pkgrel, upper_pkgrel) = (&lower.version.pkgrel, &upper.version.pkgrel);
+    let ordering = if lower_pkgrel.is_some() && upper_pkgrel.is_some() {
+        lower.version.cmp(&upper.version)
+    } else {
+        let without_pkgrel = |version: &Version| Version {
+            pkgrel: None,
+            ..version.clone()
+        };
+        without_pkgrel(&lower.version).cmp(&without_pkgrel(&upper.version))
+    };
+
+    match ordering {
+        Ordering::Greater => true,
+        // A strict comparison only excludes the shared version, if it applies to all of its
+        // releases (e.g. `>1.0-2` does not exclude `1.0-3`, which satisfies `<=1.0`).
+        Ordering::Equal => {
+            (lower_is_strict && (lower_pkgrel.is_none() || upper_pkgrel.is_some()))
+                || (upper_is_strict && (upper_pkgrel.is_none() || lower_pkgrel.is_some()))
+        }
+        Ordering::Less => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that disjoint version requirements are detected.
+    #[rstest]
+    #[case::greater_and_less(">=2", "<1", true)]
+    #[case::overlapping_range(">=1", "<2", false)]
+    #[case::touching_inclusive(">=1", "<=1", false)]
+    #[case::touching_exclusive(">1", "<=1", true)]
+    #[case::different_equal("=1", "=2", true)]
+    #[case::same_equal("=1", "=1", false)]
+    #[case::equal_in_range("=1.5", ">1", false)]
+    #[case::same_direction(">1", ">2", false)]
+    #[case::pkgrel_ignored(">1.0-2", "<=1.0", false)]
+    #[case::pkgrel_ignored_strict(">1.0-2", "<1.0", true)]
+    #[case::pkgrel_ignored_strict_upper(">=1.0", "<1.0-2", false)]
+    fn disjoint_requirements(
+        #[case] requirement: &str,
+        #[case] other: &str,
+        #[case] expected: bool,
+    ) -> TestResult {
+        let requirement = VersionRequirement::from_str(requirement)?;
+        let other = VersionRequirement::from_str(other)?;
+
+        assert_eq!(are_disjoint(&requirement, &other), expected);
+        assert_eq!(are_disjoint(&other, &requirement), expected);
+
+        Ok(())
+    }
+}
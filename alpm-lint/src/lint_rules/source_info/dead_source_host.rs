@@ -0,0 +1,180 @@
+//! Ensures that [alpm-package-source] URLs in [SRCINFO] data do not use discontinued hosting
+//! services.
+//!
+//! [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+//! [alpm-package-source]: https://alpm.archlinux.page/specifications/alpm-package-source.7.html
+
+use std::collections::BTreeMap;
+
+use alpm_types::Source;
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    issue::SourceInfoIssue,
+    lint_rules::source_info::{source_info_from_resource, sources},
+};
+
+/// The hosts of discontinued source code hosting services and mirrors.
+///
+/// Each host is paired with the name of the service.
+/// All subdomains of a host are considered as well.
+const DEAD_HOSTS: &[(&str, &str)] = &[
+    ("alioth.debian.org", "Alioth"),
+    ("berlios.de", "BerliOS"),
+    ("code.google.com", "Google Code"),
+    ("codeplex.com", "CodePlex"),
+    ("fedorahosted.org", "Fedora Hosted"),
+    ("gitorious.org", "Gitorious"),
+    ("gna.org", "Gna!"),
+    ("googlecode.com", "Google Code"),
+];
+
+/// # What it does
+///
+/// Ensures that no [alpm-package-source] in [SRCINFO] data is retrieved from a discontinued source
+/// code hosting service or mirror.
+///
+/// The following hosts (and all of their subdomains) are considered:
+///
+/// - `alioth.debian.org` (Alioth)
+/// - `berlios.de` (BerliOS)
+/// - `code.google.com` and `googlecode.com` (Google Code)
+/// - `codeplex.com` (CodePlex)
+/// - `fedorahosted.org` (Fedora Hosted)
+/// - `gitorious.org` (Gitorious)
+/// - `gna.org` (Gna!)
+///
+/// # Why is this bad?
+///
+/// Sources on discontinued hosting services can no longer be retrieved, which means that the
+/// package can not be (re)built.
+/// Even if a host is still reachable, it may be in control of a different party by now, which
+/// may serve arbitrary data.
+///
+/// Most projects have moved to a different hosting service, which should be used instead.
+///
+/// # Example
+///
+/// ```ini,ignore
+/// pkgbase = test
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     source = https://test.googlecode.com/files/test-1.0.0.tar.gz
+///     sha256sums = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+/// ```
+///
+/// Use instead:
+///
+/// ```ini,ignore
+/// pkgbase = test
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     source = https://domain.tld/testing/test-1.0.0.tar.gz
+///     sha256sums = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+/// ```
+///
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+/// [alpm-package-source]: https://alpm.archlinux.page/specifications/alpm-package-source.7.html
+#[derive(Clone, Debug, Documented)]
+pub struct DeadSourceHost {}
+
+impl DeadSourceHost {
+    /// Create a new, boxed instance of [`DeadSourceHost`].
+    pub fn new_boxed(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(DeadSourceHost {})
+    }
+
+    /// Returns the name of the discontinued service that `host` belongs to.
+    ///
+    /// Returns [`None`] if `host` does not belong to a discontinued service.
+    fn dead_service(host: &str) -> Option<&'static str> {
+        DEAD_HOSTS.iter().find_map(|(dead_host, service)| {
+            let is_subdomain = host
+                .strip_suffix(dead_host)
+                .is_some_and(|prefix| prefix.ends_with('.'));
+            (host == *dead_host || is_subdomain).then_some(*service)
+        })
+    }
+}
+
+impl LintRule for DeadSourceHost {
+    fn name(&self) -> &'static str {
+        "dead_source_host"
+    }
+
+    fn scope(&self) -> LintScope {
+        LintScope::SourceInfo
+    }
+
+    fn level(&self) -> Level {
+        crate::Level::Warn
+    }
+
+    fn documentation(&self) -> String {
+        DeadSourceHost::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        format!(
+            r#"Sources can not be retrieved from discontinued hosting services.
+
+The following hosting services are discontinued: {}.
+Retrieve the sources from the current upstream location of the project instead.
+"#,
+            DEAD_HOSTS
+                .iter()
+                .map(|(host, _)| *host)
+                .collect::<Vec<&str>>()
+                .join(", ")
+        )
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        // Extract the SourceInfo from the given resources.
+        let source_info = source_info_from_resource(resources, self.scoped_name())?;
+
+        for (source, architecture) in sources(source_info) {
+            let Source::SourceUrl { source_url, .. } = source else {
+                continue;
+            };
+            let Some(service) = source_url
+                .url
+                .inner()
+                .host_str()
+                .and_then(Self::dead_service)
+            else {
+                continue;
+            };
+
+            issues.push(LintIssue::from_rule(
+                self,
+                SourceInfoIssue::BaseField {
+                    field_name: "source".to_string(),
+                    value: source.to_string(),
+                    context: format!("Source is hosted on the discontinued service {service}"),
+                    architecture: architecture.cloned(),
+                }
+                .into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "SRCINFO specification".to_string(),
+            "https://alpm.archlinux.page/specifications/SRCINFO.5.html".to_string(),
+        );
+        links.insert(
+            "alpm-package-source specification".to_string(),
+            "https://alpm.archlinux.page/specifications/alpm-package-source.7.html".to_string(),
+        );
+
+        Some(links)
+    }
+}
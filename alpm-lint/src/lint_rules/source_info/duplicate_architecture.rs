@@ -1,7 +1,8 @@
 //! Checks that architectures are not specified twice.
 
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
+use alpm_types::SRCINFO_FILE_NAME;
 use documented::Documented;
 
 use crate::{
@@ -30,7 +31,7 @@ use crate::{
 ///     arch = x86_64
 /// ```
 ///
-/// Use instead:
+/// Use instead (can be fixed automatically):
 ///
 /// ```ini,ignore
 /// pkgbase = test
@@ -61,6 +62,10 @@ impl LintRule for DuplicateArchitecture {
         LintScope::SourceInfo
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Style
+    }
+
     fn documentation(&self) -> String {
         DuplicateArchitecture::DOCS.into()
     }
@@ -97,6 +102,33 @@ Duplicate architecture declarations such as `arch=(x86_64 x86_64)` are ignored.
         Ok(())
     }
 
+    fn fix(&self, resources: &Resources) -> Result<Vec<Edit>, Error> {
+        let source_info = source_info_from_resource(resources, self.scoped_name())?;
+
+        // Remove all but the first occurrence of each architecture.
+        // The `pkgbase` section precedes all `pkgname` sections, so the occurrences of an
+        // architecture in it always come first.
+        let mut occurrences = HashMap::new();
+        let mut edits = Vec::new();
+        for architecture in &source_info.base.architectures {
+            let occurrence = occurrences.entry(architecture.clone()).or_insert(0);
+            if *occurrence > 0 {
+                edits.push(Edit::remove(
+                    SRCINFO_FILE_NAME,
+                    format!("arch = {architecture}"),
+                    *occurrence,
+                ));
+            }
+            *occurrence += 1;
+        }
+
+        Ok(edits)
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+
     fn extra_links(&self) -> Option<BTreeMap<String, String>> {
         let mut links = BTreeMap::new();
         links.insert(
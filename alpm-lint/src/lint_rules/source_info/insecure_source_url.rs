@@ -0,0 +1,141 @@
+//! Ensures that [alpm-package-source] URLs in [SRCINFO] data use HTTPS instead of HTTP.
+//!
+//! [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+//! [alpm-package-source]: https://alpm.archlinux.page/specifications/alpm-package-source.7.html
+
+use std::collections::BTreeMap;
+
+use alpm_types::Source;
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    issue::SourceInfoIssue,
+    lint_rules::source_info::{source_info_from_resource, sources},
+};
+
+/// # What it does
+///
+/// Ensures that no [alpm-package-source] in [SRCINFO] data is retrieved using unencrypted HTTP.
+///
+/// # Why is this bad?
+///
+/// Sources retrieved via HTTP are transferred unencrypted and without authentication of the remote
+/// server.
+/// Attackers with access to the network path may swap the upstream sources with malicious ones.
+///
+/// While checksums and digital signatures guard against swapped artifacts, they are not always
+/// available (e.g. for VCS sources).
+/// Most upstream servers support HTTPS and it should always be preferred.
+///
+/// # Example
+///
+/// ```ini,ignore
+/// pkgbase = test
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     source = http://domain.tld/testing/x86_64_test.tar.gz
+///     sha256sums = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+/// ```
+///
+/// Use instead:
+///
+/// ```ini,ignore
+/// pkgbase = test
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     source = https://domain.tld/testing/x86_64_test.tar.gz
+///     sha256sums = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+/// ```
+///
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+/// [alpm-package-source]: https://alpm.archlinux.page/specifications/alpm-package-source.7.html
+#[derive(Clone, Debug, Documented)]
+pub struct InsecureSourceUrl {}
+
+impl InsecureSourceUrl {
+    /// Create a new, boxed instance of [`InsecureSourceUrl`].
+    pub fn new_boxed(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(InsecureSourceUrl {})
+    }
+}
+
+impl LintRule for InsecureSourceUrl {
+    fn name(&self) -> &'static str {
+        "insecure_source_url"
+    }
+
+    fn scope(&self) -> LintScope {
+        LintScope::SourceInfo
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Security
+    }
+
+    fn level(&self) -> Level {
+        crate::Level::Warn
+    }
+
+    fn documentation(&self) -> String {
+        InsecureSourceUrl::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        r#"Sources should not be retrieved using unencrypted HTTP.
+
+Sources retrieved via HTTP may be swapped by attackers on the network path.
+Use HTTPS instead, which is supported by most upstream servers.
+"#
+        .into()
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        // Extract the SourceInfo from the given resources.
+        let source_info = source_info_from_resource(resources, self.scoped_name())?;
+
+        for (source, architecture) in sources(source_info) {
+            let Source::SourceUrl { source_url, .. } = source else {
+                continue;
+            };
+            if source_url.url.inner().scheme() != "http" {
+                continue;
+            }
+
+            let value = source.to_string();
+            // Only the scheme of the URL is replaced, so that any VCS prefix is preserved.
+            let suggestion = value.replacen("http://", "https://", 1);
+            issues.push(
+                LintIssue::from_rule(
+                    self,
+                    SourceInfoIssue::BaseField {
+                        field_name: "source".to_string(),
+                        value,
+                        context: "Source is retrieved using unencrypted HTTP".to_string(),
+                        architecture: architecture.cloned(),
+                    }
+                    .into(),
+                )
+                .with_suggestion(suggestion),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "SRCINFO specification".to_string(),
+            "https://alpm.archlinux.page/specifications/SRCINFO.5.html".to_string(),
+        );
+        links.insert(
+            "alpm-package-source specification".to_string(),
+            "https://alpm.archlinux.page/specifications/alpm-package-source.7.html".to_string(),
+        );
+
+        Some(links)
+    }
+}
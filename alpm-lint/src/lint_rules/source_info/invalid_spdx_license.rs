@@ -3,9 +3,10 @@
 //! [SPDX]: https://spdx.org/licenses/
 //! [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 use alpm_srcinfo::source_info::v1::package::Override;
+use alpm_types::{License, SRCINFO_FILE_NAME};
 use documented::Documented;
 
 use crate::{
@@ -19,6 +20,11 @@ use crate::{
 ///
 /// Ensures that each license in a [SRCINFO] is a valid SPDX license expression.
 ///
+/// Licenses using unknown identifiers, as well as deprecated ones (e.g. the SPDX identifier
+/// `GPL-2.0` or the legacy identifier `GPL`), are reported.
+/// If a canonical SPDX license expression can be derived for a license (e.g. `GPL-2.0-only` for
+/// `GPL-2.0`), it is suggested as a replacement and can be applied automatically.
+///
 /// # Why is this bad?
 ///
 /// The license attribution for packages clearly defines under what license(s) a package is
@@ -84,6 +90,9 @@ SPDX license identifiers provide a standardized way to identify licenses.
 - Apache-2.0 (instead of "Apache")
 - GPL-3.0-or-later or GPL-3.0-only (instead of "GPL3")
 - MIT (instead of "MIT License")
+
+Suggested SPDX identifiers are derived from the original identifier.
+Make sure that they match the actual license before applying them.
 "#
         .into()
     }
@@ -94,18 +103,23 @@ SPDX license identifiers provide a standardized way to identify licenses.
 
         // Check licenses in the base package
         for license in &source_info.base.licenses {
-            if !license.is_spdx() {
-                issues.push(LintIssue::from_rule(
-                    self,
-                    SourceInfoIssue::BaseField {
-                        field_name: "license".to_string(),
-                        value: license.to_string(),
-                        context: "License is not SPDX compliant".to_string(),
-                        architecture: None,
-                    }
-                    .into(),
-                ));
-            }
+            let Some((context, canonical)) = license_issue(license) else {
+                continue;
+            };
+            let issue = LintIssue::from_rule(
+                self,
+                SourceInfoIssue::BaseField {
+                    field_name: "license".to_string(),
+                    value: license.to_string(),
+                    context,
+                    architecture: None,
+                }
+                .into(),
+            );
+            issues.push(match canonical {
+                Some(canonical) => issue.with_suggestion(canonical.to_string()),
+                None => issue,
+            });
         }
 
         // Check licenses for all split packages
@@ -116,25 +130,67 @@ SPDX license identifiers provide a standardized way to identify licenses.
             };
 
             for license in value {
-                if !license.is_spdx() {
-                    issues.push(LintIssue::from_rule(
-                        self,
-                        SourceInfoIssue::PackageField {
-                            package_name: package.name.to_string(),
-                            field_name: "license".to_string(),
-                            value: license.to_string(),
-                            context: "License is not SPDX compliant".to_string(),
-                            architecture: None,
-                        }
-                        .into(),
-                    ));
-                }
+                let Some((context, canonical)) = license_issue(license) else {
+                    continue;
+                };
+                let issue = LintIssue::from_rule(
+                    self,
+                    SourceInfoIssue::PackageField {
+                        package_name: package.name.to_string(),
+                        field_name: "license".to_string(),
+                        value: license.to_string(),
+                        context,
+                        architecture: None,
+                    }
+                    .into(),
+                );
+                issues.push(match canonical {
+                    Some(canonical) => issue.with_suggestion(canonical.to_string()),
+                    None => issue,
+                });
             }
         }
 
         Ok(())
     }
 
+    fn fix(&self, resources: &Resources) -> Result<Vec<Edit>, Error> {
+        let source_info = source_info_from_resource(resources, self.scoped_name())?;
+
+        // Replace all licenses for which a canonical SPDX license expression exists.
+        // The `pkgbase` section precedes all `pkgname` sections, so the occurrences of a license
+        // in it always come first.
+        let package_licenses = source_info
+            .packages
+            .iter()
+            .filter_map(|package| match &package.licenses {
+                Override::Yes { value } => Some(value),
+                _ => None,
+            })
+            .flatten();
+        let mut occurrences = HashMap::new();
+        let mut edits = Vec::new();
+        for license in source_info.base.licenses.iter().chain(package_licenses) {
+            let line = format!("license = {license}");
+            let occurrence = occurrences.entry(line.clone()).or_insert(0);
+            if let Some(canonical) = license.canonical_spdx() {
+                edits.push(Edit::replace(
+                    SRCINFO_FILE_NAME,
+                    line,
+                    *occurrence,
+                    format!("license = {canonical}"),
+                ));
+            }
+            *occurrence += 1;
+        }
+
+        Ok(edits)
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+
     /// Return the associated links for this lint rule.
     fn extra_links(&self) -> Option<BTreeMap<String, String>> {
         let mut links = BTreeMap::new();
@@ -150,3 +206,26 @@ SPDX license identifiers provide a standardized way to identify licenses.
         Some(links)
     }
 }
+
+/// Returns the context of the issue with a `license` and its canonical SPDX form, if it can be
+/// derived.
+///
+/// Returns [`None`] if the `license` is a valid SPDX license expression.
+fn license_issue(license: &License) -> Option<(String, Option<License>)> {
+    let License::Unknown(identifier) = license else {
+        return None;
+    };
+
+    let canonical = license.canonical_spdx();
+    let deprecated = matches!(
+        License::from_valid_spdx(identifier.clone()),
+        Err(alpm_types::Error::DeprecatedLicense(_))
+    );
+    let context = if deprecated || canonical.is_some() {
+        "License uses a deprecated identifier"
+    } else {
+        "License uses an unknown identifier"
+    };
+
+    Some((context.to_string(), canonical))
+}
@@ -3,15 +3,20 @@
 //! [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
 
 use alpm_srcinfo::{SourceInfo, SourceInfoV1};
+use alpm_types::{Source, SystemArchitecture};
 
 use crate::{Error, LintScope, Resources};
 
+pub mod dead_source_host;
 pub mod duplicate_architecture;
+pub mod insecure_source_url;
 pub mod invalid_spdx_license;
 pub mod no_architecture;
 pub mod openpgp_key_id;
+pub mod skipped_checksum;
 pub mod undefined_architecture;
 pub mod unknown_architecture;
+pub mod unpinned_vcs_source;
 pub mod unsafe_checksum;
 
 /// Extracts a [`SourceInfoV1`] from a [`Resources`].
@@ -23,7 +28,7 @@ pub mod unsafe_checksum;
 /// # Errors
 ///
 /// Returns an error if `resources` does not contain [`SourceInfo`] data.
-fn source_info_from_resource(
+pub(crate) fn source_info_from_resource(
     resources: &Resources,
     lint_rule: String,
 ) -> Result<&SourceInfoV1, Error> {
@@ -40,3 +45,23 @@ fn source_info_from_resource(
         }),
     }
 }
+
+/// Returns all [`Source`]s of a [`SourceInfoV1`].
+///
+/// Each [`Source`] is paired with the [`SystemArchitecture`] it is specific to, or [`None`] if it
+/// is used for all architectures.
+pub(crate) fn sources(
+    source_info: &SourceInfoV1,
+) -> impl Iterator<Item = (&Source, Option<&SystemArchitecture>)> {
+    let base = &source_info.base;
+    base.sources.iter().map(|source| (source, None)).chain(
+        base.architecture_properties
+            .iter()
+            .flat_map(|(architecture, properties)| {
+                properties
+                    .sources
+                    .iter()
+                    .map(move |source| (source, Some(architecture)))
+            }),
+    )
+}
@@ -81,6 +81,10 @@ impl LintRule for OpenPGPKeyId {
         LintScope::SourceInfo
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Security
+    }
+
     fn level(&self) -> Level {
         crate::Level::Deny
     }
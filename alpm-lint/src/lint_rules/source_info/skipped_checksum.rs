@@ -0,0 +1,250 @@
+//! Ensures that [alpm-package-source-checksum] verification is only skipped for VCS sources in
+//! [SRCINFO] data.
+//!
+//! [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+//! [alpm-package-source-checksum]: https://alpm.archlinux.page/specifications/alpm-package-source-checksum.7.html
+
+use std::{collections::BTreeMap, path::Path};
+
+use alpm_types::{Digest, SkippableChecksum, Source, SystemArchitecture};
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    issue::SourceInfoIssue,
+    lint_rules::source_info::source_info_from_resource,
+};
+
+/// The file extensions of digital signatures, which are commonly not verified using checksums.
+const SIGNATURE_EXTENSIONS: &[&str] = &["asc", "sig", "sign"];
+
+/// # What it does
+///
+/// Ensures that the [alpm-package-source-checksum] of each non-VCS source in [SRCINFO] data is not
+/// skipped using `SKIP`.
+///
+/// Sources that are digital signatures (i.e. files ending on `.asc`, `.sig` or `.sign`) are not
+/// considered, as they are verified using [OpenPGP certificates] instead.
+///
+/// # Why is this bad?
+///
+/// Upstream artifacts are validated against hash digests (see [alpm-package-source-checksum]) set
+/// in [PKGBUILD] and [SRCINFO] files.
+/// If the verification is skipped, the upstream artifacts may be swapped with malicious ones
+/// without notice.
+///
+/// Skipping the checksum verification is only necessary for VCS sources, as their checksums can
+/// not be calculated reliably.
+///
+/// # Example
+///
+/// ```ini,ignore
+/// pkgbase = test
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     source = https://domain.tld/testing/x86_64_test.tar.gz
+///     sha256sums = SKIP
+/// ```
+///
+/// Use instead:
+///
+/// ```ini,ignore
+/// pkgbase = test
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     source = https://domain.tld/testing/x86_64_test.tar.gz
+///     sha256sums = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+/// ```
+///
+/// [OpenPGP certificates]: https://openpgp.dev/book/certificates.html
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+/// [alpm-package-source-checksum]: https://alpm.archlinux.page/specifications/alpm-package-source-checksum.7.html
+#[derive(Clone, Debug, Documented)]
+pub struct SkippedChecksum {}
+
+impl SkippedChecksum {
+    /// Create a new, boxed instance of [`SkippedChecksum`].
+    pub fn new_boxed(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(Self {})
+    }
+
+    /// Returns `true` if the checksum verification of `source` may be skipped.
+    ///
+    /// This is the case for VCS sources and digital signatures.
+    fn may_skip(source: &Source) -> bool {
+        let path = match source {
+            Source::SourceUrl { source_url, .. } if source_url.vcs_info.is_some() => return true,
+            Source::SourceUrl {
+                filename: Some(filename),
+                ..
+            }
+            | Source::File {
+                filename: Some(filename),
+                ..
+            } => filename.as_path(),
+            Source::SourceUrl { source_url, .. } => Path::new(source_url.url.inner().path()),
+            Source::File { location, .. } => location.as_path(),
+        };
+
+        path.extension()
+            .is_some_and(|extension| SIGNATURE_EXTENSIONS.iter().any(|sig| extension == *sig))
+    }
+
+    /// Creates a lint issue for each skipped checksum in `checksums`, whose source may not be
+    /// skipped.
+    ///
+    /// The `checksums` are paired with `sources` without validating their lengths, as mismatching
+    /// array lengths are not the concern of this lint rule.
+    fn check_checksums<D: Digest + Clone>(
+        &self,
+        field_name: &str,
+        sources: &[Source],
+        checksums: &[SkippableChecksum<D>],
+        architecture: Option<&SystemArchitecture>,
+        issues: &mut Vec<LintIssue>,
+    ) {
+        for (source, checksum) in sources.iter().zip(checksums) {
+            if matches!(checksum, SkippableChecksum::Skip) && !Self::may_skip(source) {
+                issues.push(LintIssue::from_rule(
+                    self,
+                    SourceInfoIssue::BaseField {
+                        field_name: field_name.to_string(),
+                        value: source.to_string(),
+                        context: "Checksum verification is skipped for non-VCS source".to_string(),
+                        architecture: architecture.cloned(),
+                    }
+                    .into(),
+                ));
+            }
+        }
+    }
+}
+
+impl LintRule for SkippedChecksum {
+    fn name(&self) -> &'static str {
+        "skipped_checksum"
+    }
+
+    fn scope(&self) -> LintScope {
+        LintScope::SourceInfo
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Security
+    }
+
+    fn level(&self) -> Level {
+        crate::Level::Warn
+    }
+
+    fn documentation(&self) -> String {
+        SkippedChecksum::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        r#"Checksum verification should only be skipped for VCS sources.
+
+Without checksum verification, upstream artifacts may be swapped with malicious ones.
+Replace `SKIP` with the checksum of the source.
+"#
+        .into()
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        // Extract the SourceInfo from the given resources.
+        let source_info = source_info_from_resource(resources, self.scoped_name())?;
+        let base = &source_info.base;
+
+        let sources = &base.sources;
+        self.check_checksums("b2sums", sources, &base.b2_checksums, None, issues);
+        self.check_checksums("md5sums", sources, &base.md5_checksums, None, issues);
+        self.check_checksums("sha1sums", sources, &base.sha1_checksums, None, issues);
+        self.check_checksums("sha224sums", sources, &base.sha224_checksums, None, issues);
+        self.check_checksums("sha256sums", sources, &base.sha256_checksums, None, issues);
+        self.check_checksums("sha384sums", sources, &base.sha384_checksums, None, issues);
+        self.check_checksums("sha512sums", sources, &base.sha512_checksums, None, issues);
+        self.check_checksums("cksums", sources, &base.crc_checksums, None, issues);
+
+        // Also check architecture-specific checksums
+        for (architecture, arch_props) in &base.architecture_properties {
+            let sources = &arch_props.sources;
+            let architecture = Some(architecture);
+            self.check_checksums(
+                "b2sums",
+                sources,
+                &arch_props.b2_checksums,
+                architecture,
+                issues,
+            );
+            self.check_checksums(
+                "md5sums",
+                sources,
+                &arch_props.md5_checksums,
+                architecture,
+                issues,
+            );
+            self.check_checksums(
+                "sha1sums",
+                sources,
+                &arch_props.sha1_checksums,
+                architecture,
+                issues,
+            );
+            self.check_checksums(
+                "sha224sums",
+                sources,
+                &arch_props.sha224_checksums,
+                architecture,
+                issues,
+            );
+            self.check_checksums(
+                "sha256sums",
+                sources,
+                &arch_props.sha256_checksums,
+                architecture,
+                issues,
+            );
+            self.check_checksums(
+                "sha384sums",
+                sources,
+                &arch_props.sha384_checksums,
+                architecture,
+                issues,
+            );
+            self.check_checksums(
+                "sha512sums",
+                sources,
+                &arch_props.sha512_checksums,
+                architecture,
+                issues,
+            );
+            self.check_checksums(
+                "cksums",
+                sources,
+                &arch_props.crc_checksums,
+                architecture,
+                issues,
+            );
+        }
+
+        Ok(())
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "SRCINFO specification".to_string(),
+            "https://alpm.archlinux.page/specifications/SRCINFO.5.html".to_string(),
+        );
+        links.insert(
+            "alpm-package-source-checksum specification".to_string(),
+            "https://alpm.archlinux.page/specifications/alpm-package-source-checksum.7.html"
+                .to_string(),
+        );
+
+        Some(links)
+    }
+}
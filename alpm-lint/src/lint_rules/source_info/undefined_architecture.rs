@@ -118,6 +118,8 @@ Make sure all architecture-specific fields correspond to architectures declared
                         }
                         .into(),
                     links: std::collections::BTreeMap::new(),
+                    suppressed: false,
+                    suggestion: None,
                 });
             }
         }
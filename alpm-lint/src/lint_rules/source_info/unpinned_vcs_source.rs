@@ -0,0 +1,158 @@
+//! Ensures that VCS [alpm-package-source] URLs in [SRCINFO] data pin a specific revision.
+//!
+//! [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+//! [alpm-package-source]: https://alpm.archlinux.page/specifications/alpm-package-source.7.html
+
+use std::collections::BTreeMap;
+
+use alpm_types::Source;
+use documented::Documented;
+
+use crate::{
+    internal_prelude::*,
+    issue::SourceInfoIssue,
+    lint_rules::source_info::{source_info_from_resource, sources},
+};
+
+/// The package name suffixes that identify packages which track the latest state of a VCS
+/// repository.
+const VCS_PACKAGE_SUFFIXES: &[&str] = &["-bzr", "-fossil", "-git", "-hg", "-svn"];
+
+/// # What it does
+///
+/// Ensures that each VCS source (see [alpm-package-source]) in [SRCINFO] data pins a specific
+/// commit, revision or tag using a URL fragment (e.g. `#commit=` or `#tag=`).
+///
+/// Package bases with a name that ends on a VCS specific suffix (i.e. `-bzr`, `-fossil`, `-git`,
+/// `-hg` or `-svn`) are not considered, as they deliberately track the latest state of a
+/// repository.
+///
+/// # Why is this bad?
+///
+/// VCS sources without URL fragment (or with a `#branch=` URL fragment) always retrieve the latest
+/// state of a branch.
+/// As the retrieved sources change over time, the package can not be reproduced and the version
+/// of the package does not match the version of the sources.
+///
+/// As checksums can not be used for VCS sources, pinning a specific commit or tag is also the only
+/// way to ensure that the sources have not been altered since they have been packaged.
+///
+/// # Example
+///
+/// ```ini,ignore
+/// pkgbase = test
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     source = git+https://domain.tld/testing/test.git
+///     sha256sums = SKIP
+/// ```
+///
+/// Use instead:
+///
+/// ```ini,ignore
+/// pkgbase = test
+///     pkgver = 1.0.0
+///     pkgrel = 1
+///     arch = x86_64
+///     source = git+https://domain.tld/testing/test.git#tag=v1.0.0
+///     sha256sums = SKIP
+/// ```
+///
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+/// [alpm-package-source]: https://alpm.archlinux.page/specifications/alpm-package-source.7.html
+#[derive(Clone, Debug, Documented)]
+pub struct UnpinnedVcsSource {}
+
+impl UnpinnedVcsSource {
+    /// Create a new, boxed instance of [`UnpinnedVcsSource`].
+    pub fn new_boxed(_: &LintRuleConfiguration) -> Box<dyn LintRule> {
+        Box::new(UnpinnedVcsSource {})
+    }
+}
+
+impl LintRule for UnpinnedVcsSource {
+    fn name(&self) -> &'static str {
+        "unpinned_vcs_source"
+    }
+
+    fn scope(&self) -> LintScope {
+        LintScope::SourceInfo
+    }
+
+    fn category(&self) -> LintCategory {
+        LintCategory::Reproducibility
+    }
+
+    fn level(&self) -> Level {
+        crate::Level::Warn
+    }
+
+    fn documentation(&self) -> String {
+        UnpinnedVcsSource::DOCS.into()
+    }
+
+    fn help_text(&self) -> String {
+        format!(
+            r#"VCS sources should pin a specific commit, revision or tag.
+
+Unpinned VCS sources track the latest state of a branch, which prevents reproducible builds.
+Add a URL fragment such as `#commit=<hash>` or `#tag=<tag>` to the source.
+Packages that intentionally track the latest state use one of the following suffixes: {}
+"#,
+            VCS_PACKAGE_SUFFIXES.join(", ")
+        )
+    }
+
+    fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error> {
+        // Extract the SourceInfo from the given resources.
+        let source_info = source_info_from_resource(resources, self.scoped_name())?;
+
+        let name = source_info.base.name.inner();
+        if VCS_PACKAGE_SUFFIXES
+            .iter()
+            .any(|suffix| name.ends_with(suffix))
+        {
+            return Ok(());
+        }
+
+        for (source, architecture) in sources(source_info) {
+            let Source::SourceUrl { source_url, .. } = source else {
+                continue;
+            };
+            let Some(vcs_info) = &source_url.vcs_info else {
+                continue;
+            };
+            if vcs_info.is_pinned() {
+                continue;
+            }
+
+            issues.push(LintIssue::from_rule(
+                self,
+                SourceInfoIssue::BaseField {
+                    field_name: "source".to_string(),
+                    value: source.to_string(),
+                    context: "VCS source does not pin a commit, revision or tag".to_string(),
+                    architecture: architecture.cloned(),
+                }
+                .into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn extra_links(&self) -> Option<BTreeMap<String, String>> {
+        let mut links = BTreeMap::new();
+        links.insert(
+            "SRCINFO specification".to_string(),
+            "https://alpm.archlinux.page/specifications/SRCINFO.5.html".to_string(),
+        );
+        links.insert(
+            "alpm-package-source specification".to_string(),
+            "https://alpm.archlinux.page/specifications/alpm-package-source.7.html".to_string(),
+        );
+
+        Some(links)
+    }
+}
@@ -3,7 +3,14 @@
 //! [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
 //! [alpm-package-source-checksum]: https://alpm.archlinux.page/specifications/alpm-package-source-checksum.7.html
 
-use alpm_types::{ChecksumAlgorithm, SystemArchitecture};
+use alpm_types::{
+    ChecksumAlgorithm,
+    Digest,
+    SkippableChecksum,
+    Source,
+    SourceChecksums,
+    SystemArchitecture,
+};
 use documented::Documented;
 use strum::VariantArray;
 
@@ -69,6 +76,29 @@ impl UnsafeChecksum {
         Box::new(Self {})
     }
 
+    /// Creates a lint issue for each non-skipped checksum in `checksums`.
+    ///
+    /// The `checksums` are paired with `sources` without validating their lengths, as mismatching
+    /// array lengths are not the concern of this lint rule.
+    fn check_checksums<D: Digest + Clone>(
+        &self,
+        field_name: &str,
+        sources: &[Source],
+        checksums: &[SkippableChecksum<D>],
+        architecture: Option<&SystemArchitecture>,
+        issues: &mut Vec<LintIssue>,
+    ) {
+        for (_source, checksum) in SourceChecksums::new_unchecked(sources, checksums).iter() {
+            if let Some(checksum) = checksum {
+                issues.push(self.create_checksum_issue(
+                    field_name,
+                    &checksum.to_string(),
+                    architecture.cloned(),
+                ));
+            }
+        }
+    }
+
     /// Helper function to create a lint issue for unsafe checksum field.
     fn create_checksum_issue(
         &self,
@@ -98,6 +128,10 @@ impl LintRule for UnsafeChecksum {
         LintScope::SourceInfo
     }
 
+    fn category(&self) -> LintCategory {
+        LintCategory::Security
+    }
+
     fn level(&self) -> Level {
         crate::Level::Deny
     }
@@ -133,73 +167,42 @@ Instead, use one of the following algorithms: {}
         let source_info = source_info_from_resource(resources, self.scoped_name())?;
         let base = &source_info.base;
 
-        // Check for SHA1 checksums - these are unsafe
-        for (_source, checksum) in base.sources.iter().zip(base.sha1_checksums.iter()) {
-            if !checksum.is_skipped() {
-                issues.push(self.create_checksum_issue("sha1sums", &checksum.to_string(), None));
-            }
-        }
-
-        // Check for MD5 checksums - these are unsafe
-        for (_source, checksum) in base.sources.iter().zip(base.md5_checksums.iter()) {
-            if !checksum.is_skipped() {
-                issues.push(self.create_checksum_issue("md5sums", &checksum.to_string(), None));
-            }
-        }
-
-        // Check for CRC checksums - these are unsafe
-        for (_source, checksum) in base.sources.iter().zip(base.crc_checksums.iter()) {
-            if !checksum.is_skipped() {
-                issues.push(self.create_checksum_issue("cksums", &checksum.to_string(), None));
-            }
-        }
+        // Check for SHA1, MD5 and CRC checksums - these are unsafe
+        self.check_checksums(
+            "sha1sums",
+            &base.sources,
+            &base.sha1_checksums,
+            None,
+            issues,
+        );
+        self.check_checksums("md5sums", &base.sources, &base.md5_checksums, None, issues);
+        self.check_checksums("cksums", &base.sources, &base.crc_checksums, None, issues);
 
         // Also check architecture-specific checksums
         for (architecture, arch_props) in &base.architecture_properties {
-            // Check SHA1 checksums in architecture-specific properties
-            for (_source, checksum) in arch_props
-                .sources
-                .iter()
-                .zip(arch_props.sha1_checksums.iter())
-            {
-                if !checksum.is_skipped() {
-                    issues.push(self.create_checksum_issue(
-                        "sha1sums",
-                        &checksum.to_string(),
-                        Some(architecture.clone()),
-                    ));
-                }
-            }
-
-            // Check MD5 checksums in architecture-specific properties
-            for (_source, checksum) in arch_props
-                .sources
-                .iter()
-                .zip(arch_props.md5_checksums.iter())
-            {
-                if !checksum.is_skipped() {
-                    issues.push(self.create_checksum_issue(
-                        "md5sums",
-                        &checksum.to_string(),
-                        Some(architecture.clone()),
-                    ));
-                }
-            }
-
-            // Check CRC checksums in architecture-specific properties
-            for (_source, checksum) in arch_props
-                .sources
-                .iter()
-                .zip(arch_props.crc_checksums.iter())
-            {
-                if !checksum.is_skipped() {
-                    issues.push(self.create_checksum_issue(
-                        "cksums",
-                        &checksum.to_string(),
-                        Some(architecture.clone()),
-                    ));
-                }
-            }
+            let sources = &arch_props.sources;
+            let architecture = Some(architecture);
+            self.check_checksums(
+                "sha1sums",
+                sources,
+                &arch_props.sha1_checksums,
+                architecture,
+                issues,
+            );
+            self.check_checksums(
+                "md5sums",
+                sources,
+                &arch_props.md5_checksums,
+                architecture,
+                issues,
+            );
+            self.check_checksums(
+                "cksums",
+                sources,
+                &arch_props.crc_checksums,
+                architecture,
+                issues,
+            );
         }
 
         Ok(())
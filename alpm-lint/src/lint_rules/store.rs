@@ -11,34 +11,65 @@ use std::{
 };
 
 use alpm_lint_config::{LintConfiguration, LintRuleConfiguration, LintRuleConfigurationOptionName};
+use log::warn;
 use serde::Serialize;
 
 use crate::{
     ScopedName,
-    internal_prelude::{Level, LintGroup, LintRule, LintScope},
-    lint_rules::source_info::{
-        duplicate_architecture::DuplicateArchitecture,
-        invalid_spdx_license::NotSPDX,
-        no_architecture::NoArchitecture,
-        openpgp_key_id::OpenPGPKeyId,
-        undefined_architecture::UndefinedArchitecture,
-        unknown_architecture::UnknownArchitecture,
-        unsafe_checksum::UnsafeChecksum,
+    internal_prelude::{Level, LintCategory, LintGroup, LintRule, LintScope},
+    lint_rules::{
+        custom::CustomLintRule,
+        level_override::LevelOverride,
+        package::build_dependency_mismatch::BuildDependencyMismatch,
+        package_artifact::files_in_usr_local::FilesInUsrLocal,
+        package_build::{
+            deprecated_checksum_variable::DeprecatedChecksumVariable,
+            unnecessary_cd_guard::UnnecessaryCdGuard,
+            unquoted_build_directory::UnquotedBuildDirectory,
+        },
+        relations::{
+            duplicate_dependency::DuplicateDependency,
+            missing_optional_dependency_description::MissingOptionalDependencyDescription,
+            provides_own_name::ProvidesOwnName,
+            self_dependency::SelfDependency,
+            unsatisfiable_dependency::UnsatisfiableDependency,
+        },
+        source_info::{
+            dead_source_host::DeadSourceHost,
+            duplicate_architecture::DuplicateArchitecture,
+            insecure_source_url::InsecureSourceUrl,
+            invalid_spdx_license::NotSPDX,
+            no_architecture::NoArchitecture,
+            openpgp_key_id::OpenPGPKeyId,
+            skipped_checksum::SkippedChecksum,
+            undefined_architecture::UndefinedArchitecture,
+            unknown_architecture::UnknownArchitecture,
+            unpinned_vcs_source::UnpinnedVcsSource,
+            unsafe_checksum::UnsafeChecksum,
+        },
     },
 };
 
 /// The data representation of a singular lint rule.
 ///
 /// This is used to expose lints via the CLI so that the lints can be used in website generation or
-/// for development integration.
+/// for development integration (e.g. in editors).
+///
+/// Each lint rule is identified by its stable scoped name (see [`LintRule::scoped_name`]) and
+/// describes its category, default level, documentation, example and documentation URL, as well as
+/// whether its issues can be fixed automatically.
 #[derive(Clone, Debug, Serialize)]
 pub struct SerializableLintRule {
     name: String,
     scoped_name: String,
     scope: LintScope,
+    category: LintCategory,
     level: Level,
     groups: Vec<LintGroup>,
     documentation: String,
+    example: Option<String>,
+    fixable: bool,
+    documentation_url: String,
     option_names: Vec<String>,
 }
 
@@ -97,18 +128,41 @@ impl LintStore {
         // When you edit this, please sort the array while at it :)
         // Much appreciated!
         self.lint_constructors = vec![
+            BuildDependencyMismatch::new_boxed,
+            DeadSourceHost::new_boxed,
+            DeprecatedChecksumVariable::new_boxed,
             DuplicateArchitecture::new_boxed,
+            DuplicateDependency::new_boxed_package_info,
+            DuplicateDependency::new_boxed_source_info,
+            FilesInUsrLocal::new_boxed,
+            InsecureSourceUrl::new_boxed,
+            MissingOptionalDependencyDescription::new_boxed_package_info,
+            MissingOptionalDependencyDescription::new_boxed_source_info,
             NoArchitecture::new_boxed,
             NotSPDX::new_boxed,
             OpenPGPKeyId::new_boxed,
+            ProvidesOwnName::new_boxed_package_info,
+            ProvidesOwnName::new_boxed_source_info,
+            SelfDependency::new_boxed_package_info,
+            SelfDependency::new_boxed_source_info,
+            SkippedChecksum::new_boxed,
             UndefinedArchitecture::new_boxed,
             UnknownArchitecture::new_boxed,
+            UnnecessaryCdGuard::new_boxed,
+            UnpinnedVcsSource::new_boxed,
+            UnquotedBuildDirectory::new_boxed,
             UnsafeChecksum::new_boxed,
+            UnsatisfiableDependency::new_boxed_package_info,
+            UnsatisfiableDependency::new_boxed_source_info,
         ];
     }
 
     /// Initializes and configures all linting rules.
     ///
+    /// Afterwards, the custom lint rules of the configuration are added.
+    /// Custom lint rules with invalid names or names of existing lint rules are ignored.
+    /// Finally, the level overrides of the configuration are applied to the lint rules.
+    ///
     /// This function instantly returns if the lints have already been initialized.
     fn initialize_lint_rules(&mut self) {
         // Early return if the lints are already initialized.
@@ -122,6 +176,43 @@ impl LintStore {
             self.initialized_lints
                 .insert(initialized.scoped_name(), initialized);
         }
+
+        for definition in &self.config.custom_rules {
+            if definition.name.is_empty()
+                || !definition
+                    .name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+            {
+                warn!(
+                    "Ignoring custom lint rule '{}', as its name does not only consist of lowercase alphanumeric characters and underscores.",
+                    definition.name
+                );
+                continue;
+            }
+
+            let custom_rule = CustomLintRule::new_boxed(definition.clone());
+            let scoped_name = custom_rule.scoped_name();
+            if self.initialized_lints.contains_key(&scoped_name) {
+                warn!(
+                    "Ignoring custom lint rule '{scoped_name}', as a lint rule with the same name exists."
+                );
+                continue;
+            }
+
+            self.initialized_lints.insert(scoped_name, custom_rule);
+        }
+
+        for (name, level) in &self.config.levels {
+            let Some(rule) = self.initialized_lints.remove(name) else {
+                warn!("Ignoring level override for unknown lint rule '{name}'.");
+                continue;
+            };
+            self.initialized_lints.insert(
+                name.clone(),
+                LevelOverride::new_boxed(rule, (*level).into()),
+            );
+        }
     }
 
     /// Returns a reference to the map of all available and configured lint rules.
@@ -156,9 +247,13 @@ impl LintStore {
                     name: lint.name().to_string(),
                     scoped_name: scoped_name.clone(),
                     scope: lint.scope(),
+                    category: lint.category(),
                     level: lint.level(),
                     groups: lint.groups().to_vec(),
                     documentation: lint.documentation().to_string(),
+                    example: lint.example(),
+                    fixable: lint.fixable(),
+                    documentation_url: lint.documentation_url(),
                     option_names: lint
                         .configuration_options()
                         .iter()
@@ -308,10 +403,18 @@ mod tests {
     mod lint_store {
         use std::collections::HashSet;
 
-        use alpm_lint_config::{LintConfiguration, LintRuleConfiguration};
+        use alpm_lint_config::{
+            CustomRule,
+            CustomRuleCheck,
+            CustomRuleScope,
+            LintConfiguration,
+            LintLevel,
+            LintRuleConfiguration,
+        };
         use testresult::TestResult;
 
         use super::LintStore;
+        use crate::Level;
 
         /// Ensures that no two lint rules have the same scoped name.
         ///
@@ -363,6 +466,88 @@ Lint rule names are only allowed to consist of lowercase alphanumeric characters
 
             Ok(())
         }
+
+        /// Ensures that all built-in lint rules provide an example and declare whether they are
+        /// fixable.
+        #[test]
+        fn lint_rule_metadata() {
+            let store = LintStore::new(LintConfiguration::default());
+
+            for (scoped_name, lint_rule) in store.serializable_lint_rules() {
+                assert!(
+                    lint_rule.example.is_some(),
+                    "Lint rule '{scoped_name}' has no '# Example' section in its documentation"
+                );
+                assert!(
+                    lint_rule
+                        .documentation_url
+                        .ends_with(&format!("#{scoped_name}"))
+                );
+            }
+
+            let lint_rules = store.serializable_lint_rules();
+            assert!(lint_rules["source_info::duplicate_architecture"].fixable);
+            assert!(!lint_rules["source_info::no_architecture"].fixable);
+        }
+
+        /// Ensures that custom lint rules of the configuration are added to the store, unless
+        /// their names are invalid or already in use.
+        #[test]
+        fn custom_lint_rules() {
+            let custom_rule = |name: &str| CustomRule {
+                name: name.to_string(),
+                scope: CustomRuleScope::SourceInfo,
+                level: LintLevel::Warn,
+                description: String::new(),
+                help: String::new(),
+                check: CustomRuleCheck::RequiredField {
+                    field: "url".to_string(),
+                },
+            };
+            let config = LintConfiguration {
+                custom_rules: vec![
+                    custom_rule("required_url"),
+                    custom_rule("Invalid-Name"),
+                    custom_rule("duplicate_architecture"),
+                ],
+                ..Default::default()
+            };
+
+            let store = LintStore::new(config);
+            let lint_rules = store.lint_rules();
+
+            assert!(lint_rules.contains_key("source_info::required_url"));
+            assert!(!lint_rules.contains_key("source_info::Invalid-Name"));
+            assert!(
+                !lint_rules["source_info::duplicate_architecture"]
+                    .help_text()
+                    .is_empty()
+            );
+        }
+
+        /// Ensures that the levels of lint rules are overridden by the configuration.
+        #[test]
+        fn level_overrides() {
+            let config = LintConfiguration {
+                levels: [
+                    (
+                        "source_info::duplicate_architecture".to_string(),
+                        LintLevel::Error,
+                    ),
+                    ("source_info::unknown_rule".to_string(), LintLevel::Error),
+                ]
+                .into(),
+                ..Default::default()
+            };
+
+            let store = LintStore::new(config);
+            let lint_rules = store.lint_rules();
+
+            let rule = &lint_rules["source_info::duplicate_architecture"];
+            assert_eq!(rule.level(), Level::Error);
+            assert_eq!(rule.scoped_name(), "source_info::duplicate_architecture");
+            assert!(!lint_rules.contains_key("source_info::unknown_rule"));
+        }
     }
 
     /// Tests for the the FilteredLintRules iterator
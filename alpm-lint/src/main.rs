@@ -2,13 +2,14 @@
 
 use std::process::ExitCode;
 
+use alpm_common::ErrorCategory;
 use alpm_lint::cli::{Cli, Command};
 use clap::Parser;
 use simplelog::{ColorChoice, Config, TermLogger};
 
 mod commands;
 
-use crate::commands::{check, meta, options, rules};
+use crate::commands::{check, check_tree, meta, options, rules};
 
 fn main() -> ExitCode {
     let cli = Cli::parse();
@@ -23,16 +24,61 @@ fn main() -> ExitCode {
         return ExitCode::FAILURE;
     };
 
+    let quiet = cli.verbose.is_silent();
     let result = match cli.command {
         Command::Check {
             config,
+            profile_bundle,
+            profile,
             path,
             scope,
             level,
             format,
             output,
             pretty,
-        } => check(config, path, scope, level, format, output, pretty),
+            fix,
+            baseline,
+            write_baseline,
+            show_suppressed,
+            recursive,
+            jobs,
+            max_level,
+        } => {
+            if recursive {
+                check_tree(
+                    config,
+                    profile_bundle,
+                    profile,
+                    path,
+                    level,
+                    format,
+                    output,
+                    pretty,
+                    quiet,
+                    show_suppressed,
+                    max_level,
+                    jobs,
+                )
+            } else {
+                check(
+                    config,
+                    profile_bundle,
+                    profile,
+                    path,
+                    scope,
+                    level,
+                    format,
+                    output,
+                    pretty,
+                    quiet,
+                    fix,
+                    baseline,
+                    write_baseline,
+                    show_suppressed,
+                    max_level,
+                )
+            }
+        }
         Command::Rules {
             format: output_format,
             pretty,
@@ -52,7 +98,7 @@ fn main() -> ExitCode {
 
     if let Err(error) = result {
         eprintln!("{error}");
-        ExitCode::FAILURE
+        ErrorCategory::from(&error).into()
     } else {
         ExitCode::SUCCESS
     }
@@ -1,12 +1,27 @@
 //! Types to gather, represent and provide data for linting.
 
-use std::{fs::metadata, path::Path};
+use std::{
+    fs::metadata,
+    path::{Path, absolute},
+};
 
 use alpm_buildinfo::BuildInfo;
 use alpm_common::MetadataFile;
+use alpm_mtree::Mtree;
+use alpm_package::Package;
+use alpm_pkgbuild::script::PackageBuildScript;
 use alpm_pkginfo::PackageInfo;
-use alpm_srcinfo::{SourceInfo, SourceInfoV1};
-use alpm_types::{MetadataFileName, PKGBUILD_FILE_NAME, SRCINFO_FILE_NAME};
+use alpm_srcinfo::{MergedPackage, SourceInfo, SourceInfoV1};
+use alpm_types::{
+    Architecture,
+    Architectures,
+    MetadataFileName,
+    Name,
+    OptionalDependency,
+    PKGBUILD_FILE_NAME,
+    RelationOrSoname,
+    SRCINFO_FILE_NAME,
+};
 
 use crate::{Error, LintScope};
 
@@ -30,6 +45,10 @@ pub enum Resources {
         ///
         /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
         source_info: SourceInfo,
+        /// The line based contents of the [PKGBUILD].
+        ///
+        /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+        package_build_script: PackageBuildScript,
     },
     /// All resources of a single package.
     Package {
@@ -41,6 +60,29 @@ pub enum Resources {
         ///
         /// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
         build_info: BuildInfo,
+        /// The parsed [SRCINFO] file the package has been built from.
+        ///
+        /// This is [`None`], if the package directory contains no [SRCINFO] file.
+        ///
+        /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+        source_info: Option<SourceInfo>,
+    },
+    /// All resources of a built package file.
+    PackageArtifact {
+        /// The parsed [PKGINFO] file of the package.
+        ///
+        /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+        package_info: PackageInfo,
+        /// The parsed [BUILDINFO] file of the package.
+        ///
+        /// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+        build_info: BuildInfo,
+        /// The parsed [ALPM-MTREE] file of the package.
+        ///
+        /// It describes all files, directories and symlinks contained in the package.
+        ///
+        /// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+        mtree: Mtree,
     },
     /// A singular [BUILDINFO] file.
     ///
@@ -51,32 +93,134 @@ pub enum Resources {
     /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
     PackageInfo(PackageInfo),
     /// A singular [PKGBUILD] file.
-    ///
-    /// We cannot lint the [PKGBUILD] directly, hence we have to convert it into a [`SourceInfo`]
-    /// representation first.
-    ///
-    /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
-    /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
-    PackageBuild(SourceInfo),
+    PackageBuild {
+        /// The [SRCINFO] representation of the [PKGBUILD].
+        ///
+        /// We cannot lint the data of the [PKGBUILD] directly, hence we have to convert it into
+        /// a [`SourceInfo`] representation first.
+        ///
+        /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+        /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+        source_info: SourceInfo,
+        /// The line based contents of the [PKGBUILD].
+        ///
+        /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+        script: PackageBuildScript,
+    },
     /// A singular [SRCINFO] file.
     ///
     /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
     SourceInfo(SourceInfo),
 }
 
+/// The [alpm-package-relations] of a single package.
+///
+/// [alpm-package-relations]: https://alpm.archlinux.page/specifications/alpm-package-relation.7.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct PackageRelations {
+    /// The name of the package.
+    pub name: Name,
+    /// The run-time dependencies of the package.
+    pub dependencies: Vec<RelationOrSoname>,
+    /// The optional dependencies of the package.
+    pub optional_dependencies: Vec<OptionalDependency>,
+    /// The provisions of the package.
+    pub provides: Vec<RelationOrSoname>,
+}
+
+impl From<&PackageInfo> for PackageRelations {
+    fn from(package_info: &PackageInfo) -> Self {
+        match package_info {
+            PackageInfo::V1(package_info) => Self {
+                name: package_info.pkgname.clone(),
+                dependencies: package_info.depend.clone(),
+                optional_dependencies: package_info.optdepend.clone(),
+                provides: package_info.provides.clone(),
+            },
+            PackageInfo::V2(package_info) => Self {
+                name: package_info.pkgname.clone(),
+                dependencies: package_info.depend.clone(),
+                optional_dependencies: package_info.optdepend.clone(),
+                provides: package_info.provides.clone(),
+            },
+        }
+    }
+}
+
+impl From<MergedPackage> for PackageRelations {
+    fn from(package: MergedPackage) -> Self {
+        Self {
+            name: package.name,
+            dependencies: package.dependencies,
+            optional_dependencies: package.optional_dependencies,
+            provides: package.provides,
+        }
+    }
+}
+
 impl Resources {
     /// Returns the [`LintScope`] for the [`Resources`].
     pub fn scope(&self) -> LintScope {
         match self {
             Resources::SourceRepository { .. } => LintScope::SourceRepository,
             Resources::Package { .. } => LintScope::Package,
+            Resources::PackageArtifact { .. } => LintScope::PackageArtifact,
             Resources::BuildInfo(_) => LintScope::BuildInfo,
             Resources::PackageInfo(_) => LintScope::PackageInfo,
-            Resources::PackageBuild(_) => LintScope::PackageBuild,
+            Resources::PackageBuild { .. } => LintScope::PackageBuild,
             Resources::SourceInfo(_) => LintScope::SourceInfo,
         }
     }
 
+    /// Returns the [`PackageRelations`] of all packages in the [`Resources`].
+    ///
+    /// For [SRCINFO] data, the relations of each package are resolved for each of its
+    /// architectures.
+    /// If the relations of a package are identical for several architectures, they are only
+    /// returned once.
+    ///
+    /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+    pub fn package_relations(&self) -> Vec<PackageRelations> {
+        let source_info = match self {
+            Resources::SourceRepository {
+                source_info: SourceInfo::V1(source_info),
+                ..
+            }
+            | Resources::PackageBuild {
+                source_info: SourceInfo::V1(source_info),
+                ..
+            }
+            | Resources::SourceInfo(SourceInfo::V1(source_info)) => source_info,
+            Resources::Package { package_info, .. }
+            | Resources::PackageArtifact { package_info, .. }
+            | Resources::PackageInfo(package_info) => {
+                return vec![PackageRelations::from(package_info)];
+            }
+            Resources::BuildInfo(_) => return Vec::new(),
+        };
+
+        let architectures: Vec<Architecture> = match &source_info.base.architectures {
+            Architectures::Any => vec![Architecture::Any],
+            Architectures::Some(architectures) => architectures
+                .iter()
+                .cloned()
+                .map(Architecture::from)
+                .collect(),
+        };
+
+        let mut relations = Vec::new();
+        for architecture in architectures {
+            for package in source_info.packages_for_architecture(architecture) {
+                let package_relations = PackageRelations::from(package);
+                if !relations.contains(&package_relations) {
+                    relations.push(package_relations);
+                }
+            }
+        }
+
+        relations
+    }
+
     /// Creates a [`Resources`] from a file path and a [`LintScope`].
     ///
     /// Gathers all files and other resources in a `path` in the context of a `scope`.
@@ -112,6 +256,7 @@ impl Resources {
 
         let resource = match scope {
             LintScope::BuildInfo
+            | LintScope::PackageArtifact
             | LintScope::PackageBuild
             | LintScope::PackageInfo
             | LintScope::SourceInfo => {
@@ -126,6 +271,9 @@ impl Resources {
                     &path.join(PKGBUILD_FILE_NAME),
                 )?),
                 source_info: SourceInfo::from_file_with_schema(path.join(SRCINFO_FILE_NAME), None)?,
+                package_build_script: PackageBuildScript::from_file(
+                    &path.join(PKGBUILD_FILE_NAME),
+                )?,
             },
             LintScope::Package => Resources::Package {
                 package_info: PackageInfo::from_file_with_schema(
@@ -136,6 +284,14 @@ impl Resources {
                     path.join(MetadataFileName::BuildInfo.to_string()),
                     None,
                 )?,
+                source_info: if path.join(SRCINFO_FILE_NAME).exists() {
+                    Some(SourceInfo::from_file_with_schema(
+                        path.join(SRCINFO_FILE_NAME),
+                        None,
+                    )?)
+                } else {
+                    None
+                },
             },
         };
 
@@ -176,6 +332,13 @@ impl Resources {
                         expected: "single file lint scope",
                     });
                 }
+                LintScope::PackageArtifact => {
+                    return Err(Error::InvalidPathForLintScope {
+                        path: path.to_owned(),
+                        scope,
+                        expected: "package file",
+                    });
+                }
                 LintScope::BuildInfo => MetadataFileName::BuildInfo.to_string(),
                 LintScope::PackageBuild => PKGBUILD_FILE_NAME.to_string(),
                 LintScope::PackageInfo => MetadataFileName::PackageInfo.to_string(),
@@ -195,10 +358,25 @@ impl Resources {
                     expected: "single file lint scope",
                 });
             }
-            LintScope::BuildInfo => Self::BuildInfo(BuildInfo::from_file_with_schema(path, None)?),
-            LintScope::PackageBuild => {
-                Self::PackageBuild(SourceInfo::V1(SourceInfoV1::from_pkgbuild(&path)?))
+            LintScope::PackageArtifact => {
+                // Packages can only be read from absolute paths.
+                let path = absolute(&path).map_err(|source| Error::IoPath {
+                    path: path.clone(),
+                    context: "making path absolute",
+                    source,
+                })?;
+                let package = Package::try_from(path.as_path())?;
+                Self::PackageArtifact {
+                    package_info: package.read_pkginfo()?,
+                    build_info: package.read_buildinfo()?,
+                    mtree: package.read_mtree()?,
+                }
             }
+            LintScope::BuildInfo => Self::BuildInfo(BuildInfo::from_file_with_schema(path, None)?),
+            LintScope::PackageBuild => Self::PackageBuild {
+                source_info: SourceInfo::V1(SourceInfoV1::from_pkgbuild(&path)?),
+                script: PackageBuildScript::from_file(&path)?,
+            },
             LintScope::PackageInfo => {
                 Self::PackageInfo(PackageInfo::from_file_with_schema(path, None)?)
             }
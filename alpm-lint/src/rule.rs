@@ -4,7 +4,17 @@ use std::collections::BTreeMap;
 
 use alpm_lint_config::{LintGroup, LintRuleConfigurationOptionName};
 
-use crate::{Error, Level, LintScope, ScopedName, issue::LintIssue, resources::Resources};
+use crate::{
+    Error,
+    Level,
+    LintCategory,
+    LintScope,
+    ScopedName,
+    fix::Edit,
+    issue::{LintIssue, display::ALPM_LINT_WEBSITE},
+    resources::Resources,
+    utils::documentation_section,
+};
 
 /// The trait definition and behavioral description of a lint rule.
 ///
@@ -20,6 +30,9 @@ pub trait LintRule {
     /// Returns the full name of this lint by combining [`LintRule::scope`] and [`LintRule::name`]
     /// as `{scope}::{name}`.
     ///
+    /// The full name is the stable identifier of the lint rule, which is used in configuration
+    /// files, suppression comments and all output formats.
+    ///
     /// # Warning
     ///
     /// Do not re-implement this. The default implementation should cover all cases.
@@ -45,6 +58,17 @@ pub trait LintRule {
         Level::Warn
     }
 
+    /// Returns the category of this lint rule.
+    ///
+    /// The category describes the kind of problem this lint rule detects.
+    ///
+    /// # Note
+    ///
+    /// The default category is [`LintCategory::Correctness`].
+    fn category(&self) -> LintCategory {
+        LintCategory::Correctness
+    }
+
     /// Returns the static lint groups this lint rule belongs to.
     ///
     /// Lint rules can be in zero or more lint groups.
@@ -63,6 +87,31 @@ pub trait LintRule {
     /// If your lint rule encounters an issue, add it to that list.
     fn run(&self, resources: &Resources, issues: &mut Vec<LintIssue>) -> Result<(), Error>;
 
+    /// Returns the edits that fix the issues this lint rule encounters in [`Resources`].
+    ///
+    /// Lint rules whose issues can be fixed mechanically (e.g. by removing duplicate entries)
+    /// should implement this method.
+    /// The returned [`Edit`]s are applied to the files from which the [`Resources`] have been
+    /// created (see [`apply_edits`](crate::fix::apply_edits)).
+    ///
+    /// # Note
+    ///
+    /// The default implementation returns no edits.
+    fn fix(&self, _resources: &Resources) -> Result<Vec<Edit>, Error> {
+        Ok(Vec::new())
+    }
+
+    /// Returns whether the issues of this lint rule can be fixed automatically.
+    ///
+    /// Lint rules that implement [`LintRule::fix`] must return `true`.
+    ///
+    /// # Note
+    ///
+    /// The default implementation returns `false`.
+    fn fixable(&self) -> bool {
+        false
+    }
+
     /// Returns the full documentation for this lint rule.
     ///
     /// This includes:
@@ -82,6 +131,30 @@ pub trait LintRule {
     /// ```
     fn documentation(&self) -> String;
 
+    /// Returns an example for this lint rule.
+    ///
+    /// The example illustrates data that triggers this lint rule and how to correct it.
+    ///
+    /// # Note
+    ///
+    /// The default implementation returns the `# Example` (or `# Examples`) section of
+    /// [`LintRule::documentation`], or [`None`] if there is no such section.
+    fn example(&self) -> Option<String> {
+        let documentation = self.documentation();
+        documentation_section(&documentation, "Example")
+            .or_else(|| documentation_section(&documentation, "Examples"))
+    }
+
+    /// Returns the URL of the documentation for this lint rule on the central
+    /// `lint-config-website`.
+    ///
+    /// # Warning
+    ///
+    /// Do not re-implement this. The default implementation should cover all cases.
+    fn documentation_url(&self) -> String {
+        format!("{ALPM_LINT_WEBSITE}#{}", self.scoped_name())
+    }
+
     /// Returns the help text for this lint rule.
     ///
     /// The help text explains why this lint is encountered and
@@ -5,9 +5,10 @@ use std::{
     fmt::Display,
     fs::{metadata, read_dir},
     path::Path,
+    str::FromStr,
 };
 
-use alpm_types::{MetadataFileName, PKGBUILD_FILE_NAME, SRCINFO_FILE_NAME};
+use alpm_types::{MetadataFileName, PKGBUILD_FILE_NAME, PackageFileName, SRCINFO_FILE_NAME};
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use strum::{Display as StrumDisplay, VariantArray};
@@ -79,6 +80,19 @@ pub enum LintScope {
     ///
     /// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
     Package,
+    /// Lint rules with this scope are specific to a built [alpm-package] file (e.g.
+    /// `example-1.0.0-1-x86_64.pkg.tar.zst`).
+    ///
+    /// Such lint rules check the metadata files and the contents of an Arch Linux package file.
+    /// The contents of the package are represented by its [ALPM-MTREE] file.
+    ///
+    /// When this scope is selected, the following lint scopes are implied:
+    /// - [`LintScope::PackageInfo`]
+    /// - [`LintScope::BuildInfo`]
+    ///
+    /// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+    /// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+    PackageArtifact,
     /// Lint rules with this scope are specific to a single [BUILDINFO] file.
     ///
     /// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
@@ -122,12 +136,26 @@ impl LintScope {
                 LintScope::SourceRepository | LintScope::SourceInfo | LintScope::PackageBuild => {
                     true
                 }
-                LintScope::BuildInfo | LintScope::PackageInfo | LintScope::Package => false,
+                LintScope::BuildInfo
+                | LintScope::PackageInfo
+                | LintScope::Package
+                | LintScope::PackageArtifact => false,
             },
             // A `Package` scope may contain a PackageBuild or PackageInfo file.
             LintScope::Package => match other {
                 LintScope::Package | LintScope::PackageBuild | LintScope::PackageInfo => true,
-                LintScope::BuildInfo | LintScope::SourceRepository | LintScope::SourceInfo => false,
+                LintScope::BuildInfo
+                | LintScope::PackageArtifact
+                | LintScope::SourceRepository
+                | LintScope::SourceInfo => false,
+            },
+            // A `PackageArtifact` scope contains a BuildInfo and a PackageInfo file.
+            LintScope::PackageArtifact => match other {
+                LintScope::PackageArtifact | LintScope::BuildInfo | LintScope::PackageInfo => true,
+                LintScope::Package
+                | LintScope::PackageBuild
+                | LintScope::SourceRepository
+                | LintScope::SourceInfo => false,
             },
             // All scopes that are restricted to a single file require the exact same scope.
             LintScope::BuildInfo
@@ -173,6 +201,11 @@ impl LintScope {
                 return Ok(LintScope::BuildInfo);
             } else if filename == Into::<&'static str>::into(MetadataFileName::PackageInfo) {
                 return Ok(LintScope::PackageInfo);
+            } else if filename
+                .to_str()
+                .is_some_and(|filename| PackageFileName::from_str(filename).is_ok())
+            {
+                return Ok(LintScope::PackageArtifact);
             } else {
                 return Err(Error::NoLintScope {
                     path: path.to_path_buf(),
@@ -243,6 +276,7 @@ impl LintScope {
         match self {
             LintScope::SourceRepository | LintScope::Package => false,
             LintScope::BuildInfo
+            | LintScope::PackageArtifact
             | LintScope::PackageBuild
             | LintScope::PackageInfo
             | LintScope::SourceInfo => true,
@@ -327,6 +361,7 @@ mod tests {
     #[case::source_info(".SRCINFO", LintScope::SourceInfo)]
     #[case::build_info(".BUILDINFO", LintScope::BuildInfo)]
     #[case::package_info(".PKGINFO", LintScope::PackageInfo)]
+    #[case::package_artifact("example-1.0.0-1-x86_64.pkg.tar.zst", LintScope::PackageArtifact)]
     fn detect_scope_of_file(
         #[case] file: &'static str,
         #[case] expected: LintScope,
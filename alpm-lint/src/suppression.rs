@@ -0,0 +1,585 @@
+//! Inline suppression of lint issues.
+//!
+//! Lint rules can be suppressed for parts of a [PKGBUILD] or [SRCINFO] file with comments in the
+//! form of `# alpm-lint: allow(rule-name)`.
+//! Several rules can be allowed at once (e.g. `# alpm-lint: allow(rule_a, rule_b)`) and rules may
+//! be referred to by their name (e.g. `duplicate_architecture`) or their scoped name (e.g.
+//! `source_info::duplicate_architecture`).
+//!
+//! A suppression comment applies to the line following it:
+//!
+//! - If the line is a field (e.g. `arch = x86_64` or `arch=(x86_64)`), the rules are allowed for
+//!   all issues of that field in the current section.
+//! - If the line starts a section (e.g. `pkgname = example` or `package_example() {`), the rules
+//!   are allowed for all issues in that section.
+//!
+//! As packages inherit the fields of the package base, suppressions in the package base (e.g.
+//! `pkgbase = example`) also apply to the respective issues of all packages.
+//!
+//! [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+//! [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+
+use crate::issue::{LintIssue, LintIssueType, SourceInfoIssue, sarif::field_key};
+
+/// The prefix of a suppression comment.
+const SUPPRESSION_PREFIX: &str = "alpm-lint:";
+
+/// A section of a [PKGBUILD] or [SRCINFO] file.
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Section {
+    /// The package base, which is inherited by all packages.
+    Base,
+    /// The section of the package with the given name.
+    Package(String),
+    /// The `package` function of a [PKGBUILD] for a single package.
+    ///
+    /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+    SinglePackage,
+}
+
+impl Section {
+    /// Returns whether this [`Section`] covers the package called `package_name`.
+    ///
+    /// A `package_name` of [`None`] refers to the package base.
+    fn covers(&self, package_name: Option<&str>) -> bool {
+        match self {
+            Section::Base => true,
+            Section::Package(name) => package_name == Some(name.as_str()),
+            Section::SinglePackage => package_name.is_some(),
+        }
+    }
+}
+
+/// A lint rule that is allowed for a part of a [PKGBUILD] or [SRCINFO] file.
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Suppression {
+    /// The (optionally scoped) name of the allowed lint rule.
+    pub lint_rule: String,
+    /// The section in which the lint rule is allowed.
+    pub section: Section,
+    /// The field for which the lint rule is allowed.
+    ///
+    /// If [`None`], the lint rule is allowed for the entire [`Section`].
+    pub field: Option<String>,
+}
+
+impl Suppression {
+    /// Returns whether this [`Suppression`] applies to `issue`.
+    ///
+    /// Only issues of [SRCINFO] data can be suppressed.
+    /// Issues that are not related to a specific field (see [`SourceInfoIssue::Generic`]) can
+    /// only be suppressed for an entire [`Section`].
+    ///
+    /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+    pub fn applies_to(&self, issue: &LintIssue) -> bool {
+        let rule_name = issue
+            .lint_rule
+            .rsplit_once("::")
+            .map_or(issue.lint_rule.as_str(), |(_, name)| name);
+        if self.lint_rule != issue.lint_rule && self.lint_rule != rule_name {
+            return false;
+        }
+
+        let LintIssueType::SourceInfo(source_info_issue) = &issue.issue_type else {
+            return false;
+        };
+        let (package_name, field) = match source_info_issue {
+            SourceInfoIssue::Generic { .. } => (None, None),
+            SourceInfoIssue::BaseField {
+                field_name,
+                architecture,
+                ..
+            } => (None, Some(field_key(field_name, architecture.as_ref()))),
+            SourceInfoIssue::PackageField {
+                field_name,
+                package_name,
+                architecture,
+                ..
+            } => (
+                Some(package_name.as_str()),
+                Some(field_key(field_name, architecture.as_ref())),
+            ),
+            SourceInfoIssue::MissingField { field_name } => (None, Some(field_name.clone())),
+        };
+
+        if !self.section.covers(package_name) {
+            return false;
+        }
+        match &self.field {
+            Some(suppressed_field) => field.as_ref() == Some(suppressed_field),
+            None => true,
+        }
+    }
+}
+
+/// Returns the lint rules allowed by a suppression `comment`.
+///
+/// Returns [`None`] if `comment` is not a suppression comment.
+fn allowed_rules(comment: &str) -> Option<Vec<String>> {
+    let rules = comment
+        .trim()
+        .strip_prefix(SUPPRESSION_PREFIX)?
+        .trim()
+        .strip_prefix("allow(")?
+        .strip_suffix(')')?;
+
+    Some(
+        rules
+            .split(',')
+            .map(str::trim)
+            .filter(|rule| !rule.is_empty())
+            .map(ToString::to_string)
+            .collect(),
+    )
+}
+
+/// Returns the [`Section`] that is started by a `package` function definition in `line`.
+///
+/// Returns [`None`] if `line` does not define a `package` function.
+fn package_function(line: &str) -> Option<Section> {
+    let (name, _) = line.strip_prefix("package")?.split_once("()")?;
+    if name.is_empty() {
+        return Some(Section::SinglePackage);
+    }
+
+    let name = name.strip_prefix('_')?;
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return None;
+    }
+    Some(Section::Package(name.to_string()))
+}
+
+/// Returns the number of opening braces minus the number of closing braces in `line`.
+fn brace_balance(line: &str) -> isize {
+    line.chars().fold(0, |balance, char| match char {
+        '{' => balance + 1,
+        '}' => balance - 1,
+        _ => balance,
+    })
+}
+
+/// Returns the key of the field that is assigned in `line`.
+///
+/// Returns [`None`] if `line` does not assign a field.
+fn assigned_field(line: &str) -> Option<String> {
+    let (key, _) = line.split_once('=')?;
+    let key = key.trim_end();
+    let key = key.strip_suffix('+').unwrap_or(key);
+    if key.is_empty()
+        || !key
+            .chars()
+            .all(|char| char.is_alphanumeric() || char == '_')
+    {
+        return None;
+    }
+    Some(key.to_string())
+}
+
+/// Parses all [`Suppression`]s in the `content` of a [PKGBUILD] or [SRCINFO] file.
+///
+/// Suppression comments that are not followed by a field or a section are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use alpm_lint::suppression::{Section, Suppression, parse_suppressions};
+///
+/// let content = r#"pkgbase = example
+///     ## alpm-lint: allow(duplicate_architecture)
+///     arch = x86_64
+///     arch = x86_64
+///
+/// ## alpm-lint: allow(source_info::unsafe_checksum)
+/// pkgname = example
+/// "#;
+///
+/// assert_eq!(
+///     parse_suppressions(content),
+///     vec![
+///         Suppression {
+///             lint_rule: "duplicate_architecture".to_string(),
+///             section: Section::Base,
+///             field: Some("arch".to_string()),
+///         },
+///         Suppression {
+///             lint_rule: "source_info::unsafe_checksum".to_string(),
+///             section: Section::Package("example".to_string()),
+///             field: None,
+///         },
+///     ]
+/// );
+/// ```
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+pub fn parse_suppressions(content: &str) -> Vec<Suppression> {
+    let mut suppressions = Vec::new();
+    let mut section = Section::Base;
+    let mut pending_rules: Vec<String> = Vec::new();
+    // The nesting depth of braces in the current package function and whether its body has been
+    // opened.
+    let mut function: Option<(isize, bool)> = None;
+
+    for line in content.lines().map(str::trim) {
+        if let Some(comment) = line.strip_prefix('#') {
+            if let Some(rules) = allowed_rules(comment) {
+                pending_rules.extend(rules);
+            }
+            continue;
+        }
+        if line.is_empty() {
+            continue;
+        }
+
+        // Track the nesting of braces in package functions, to detect their end.
+        if let Some((depth, opened)) = function.as_mut()
+            && package_function(line).is_none()
+        {
+            *depth += brace_balance(line);
+            *opened |= *depth > 0;
+            if *opened && *depth <= 0 {
+                function = None;
+                section = Section::Base;
+                pending_rules.clear();
+                continue;
+            }
+        }
+
+        // Detect the start of sections.
+        let field = if line.starts_with("pkgbase = ") {
+            section = Section::Base;
+            None
+        } else if let Some(name) = line.strip_prefix("pkgname = ") {
+            section = Section::Package(name.to_string());
+            None
+        } else if let Some(package_section) = package_function(line) {
+            section = package_section;
+            let depth = brace_balance(line);
+            function = Some((depth, depth > 0));
+            None
+        } else if let Some(field) = assigned_field(line) {
+            Some(field)
+        } else {
+            pending_rules.clear();
+            continue;
+        };
+
+        suppressions.extend(pending_rules.drain(..).map(|lint_rule| Suppression {
+            lint_rule,
+            section: section.clone(),
+            field: field.clone(),
+        }));
+    }
+
+    suppressions
+}
+
+/// Marks all `issues` as suppressed, to which any of the `suppressions` applies.
+///
+/// Returns the number of issues that have been marked as suppressed.
+pub fn apply_suppressions(issues: &mut [LintIssue], suppressions: &[Suppression]) -> usize {
+    let mut count = 0;
+    for issue in issues.iter_mut() {
+        if suppressions
+            .iter()
+            .any(|suppression| suppression.applies_to(issue))
+        {
+            issue.suppressed = true;
+            count += 1;
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use alpm_types::SystemArchitecture;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{Level, LintScope};
+
+    /// Creates a [`LintIssue`] of `lint_rule` for a field in `package_name`.
+    ///
+    /// A `package_name` of [`None`] refers to the package base.
+    fn issue(lint_rule: &str, package_name: Option<&str>, field_name: &str) -> LintIssue {
+        let issue_type = match package_name {
+            Some(package_name) => SourceInfoIssue::PackageField {
+                field_name: field_name.to_string(),
+                package_name: package_name.to_string(),
+                value: "value".to_string(),
+                context: "context".to_string(),
+                architecture: None,
+            },
+            None => SourceInfoIssue::BaseField {
+                field_name: field_name.to_string(),
+                value: "value".to_string(),
+                context: "context".to_string(),
+                architecture: None,
+            },
+        };
+
+        LintIssue {
+            lint_rule: lint_rule.to_string(),
+            level: Level::Warn,
+            help_text: String::new(),
+            scope: LintScope::SourceInfo,
+            issue_type: issue_type.into(),
+            links: BTreeMap::new(),
+            suppressed: false,
+            suggestion: None,
+        }
+    }
+
+    /// Ensures that suppression comments in [SRCINFO] files are parsed.
+    ///
+    /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+    #[test]
+    fn parse_source_info_suppressions() {
+        let content = "pkgbase = example
+\t# alpm-lint: allow(rule_a, source_info::rule_b)
+\tarch = x86_64
+\t# A regular comment
+\tpkgver = 1.0.0
+
+# alpm-lint: allow(rule_c)
+pkgname = example
+\t# alpm-lint: allow(rule_d)
+
+\tdepends_x86_64 = glibc
+";
+
+        assert_eq!(
+            parse_suppressions(content),
+            vec![
+                Suppression {
+                    lint_rule: "rule_a".to_string(),
+                    section: Section::Base,
+                    field: Some("arch".to_string()),
+                },
+                Suppression {
+                    lint_rule: "source_info::rule_b".to_string(),
+                    section: Section::Base,
+                    field: Some("arch".to_string()),
+                },
+                Suppression {
+                    lint_rule: "rule_c".to_string(),
+                    section: Section::Package("example".to_string()),
+                    field: None,
+                },
+                Suppression {
+                    lint_rule: "rule_d".to_string(),
+                    section: Section::Package("example".to_string()),
+                    field: Some("depends_x86_64".to_string()),
+                },
+            ]
+        );
+    }
+
+    /// Ensures that suppression comments in [PKGBUILD] files are parsed.
+    ///
+    /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+    #[test]
+    fn parse_package_build_suppressions() {
+        let content = "pkgname=(example example-docs)
+# alpm-lint: allow(rule_a)
+arch=(x86_64 x86_64)
+
+build() {
+  # alpm-lint: allow(rule_b)
+  make
+}
+
+# alpm-lint: allow(rule_c)
+package_example() {
+  # alpm-lint: allow(rule_d)
+  depends+=(glibc)
+}
+
+# alpm-lint: allow(rule_e)
+optdepends=(bash)
+";
+
+        assert_eq!(
+            parse_suppressions(content),
+            vec![
+                Suppression {
+                    lint_rule: "rule_a".to_string(),
+                    section: Section::Base,
+                    field: Some("arch".to_string()),
+                },
+                Suppression {
+                    lint_rule: "rule_c".to_string(),
+                    section: Section::Package("example".to_string()),
+                    field: None,
+                },
+                Suppression {
+                    lint_rule: "rule_d".to_string(),
+                    section: Section::Package("example".to_string()),
+                    field: Some("depends".to_string()),
+                },
+                Suppression {
+                    lint_rule: "rule_e".to_string(),
+                    section: Section::Base,
+                    field: Some("optdepends".to_string()),
+                },
+            ]
+        );
+    }
+
+    /// Ensures that nested blocks do not end the section of a package function in [PKGBUILD]
+    /// files.
+    ///
+    /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+    #[test]
+    fn parse_package_build_suppressions_nested_blocks() {
+        let content = "pkgname=(example example-docs)
+
+package_example()
+{
+  if [[ -n $foo ]]; then
+    {
+      echo foo
+    } > \"${pkgdir}/foo\"
+  fi
+  # alpm-lint: allow(rule_a)
+  depends+=(glibc)
+}
+
+# alpm-lint: allow(rule_b)
+optdepends=(bash)
+";
+
+        assert_eq!(
+            parse_suppressions(content),
+            vec![
+                Suppression {
+                    lint_rule: "rule_a".to_string(),
+                    section: Section::Package("example".to_string()),
+                    field: Some("depends".to_string()),
+                },
+                Suppression {
+                    lint_rule: "rule_b".to_string(),
+                    section: Section::Base,
+                    field: Some("optdepends".to_string()),
+                },
+            ]
+        );
+    }
+
+    /// Ensures that suppressions only apply to issues of the allowed rule, section and field.
+    #[rstest]
+    #[case::field(
+        "rule",
+        Section::Base,
+        Some("arch"),
+        issue("scope::rule", None, "arch"),
+        true
+    )]
+    #[case::scoped_rule_name(
+        "scope::rule",
+        Section::Base,
+        Some("arch"),
+        issue("scope::rule", None, "arch"),
+        true
+    )]
+    #[case::other_rule(
+        "rule",
+        Section::Base,
+        Some("arch"),
+        issue("scope::other", None, "arch"),
+        false
+    )]
+    #[case::other_scope(
+        "other_scope::rule",
+        Section::Base,
+        Some("arch"),
+        issue("scope::rule", None, "arch"),
+        false
+    )]
+    #[case::other_field(
+        "rule",
+        Section::Base,
+        Some("arch"),
+        issue("scope::rule", None, "url"),
+        false
+    )]
+    #[case::inherited_field(
+        "rule",
+        Section::Base,
+        Some("arch"),
+        issue("scope::rule", Some("example"), "arch"),
+        true
+    )]
+    #[case::package_section(
+        "rule",
+        Section::Package("example".to_string()),
+        None,
+        issue("scope::rule", Some("example"), "arch"),
+        true
+    )]
+    #[case::other_package_section(
+        "rule",
+        Section::Package("other".to_string()),
+        None,
+        issue("scope::rule", Some("example"), "arch"),
+        false
+    )]
+    #[case::package_section_for_base(
+        "rule",
+        Section::Package("example".to_string()),
+        None,
+        issue("scope::rule", None, "arch"),
+        false
+    )]
+    #[case::single_package_section(
+        "rule",
+        Section::SinglePackage,
+        None,
+        issue("scope::rule", Some("example"), "arch"),
+        true
+    )]
+    fn suppression_applies_to_issue(
+        #[case] lint_rule: &str,
+        #[case] section: Section,
+        #[case] field: Option<&str>,
+        #[case] issue: LintIssue,
+        #[case] expected: bool,
+    ) {
+        let suppression = Suppression {
+            lint_rule: lint_rule.to_string(),
+            section,
+            field: field.map(ToString::to_string),
+        };
+
+        assert_eq!(suppression.applies_to(&issue), expected);
+    }
+
+    /// Ensures that suppressions of architecture specific fields apply to the respective issues.
+    #[test]
+    fn suppression_applies_to_architecture_specific_field() {
+        let suppression = Suppression {
+            lint_rule: "rule".to_string(),
+            section: Section::Base,
+            field: Some("depends_x86_64".to_string()),
+        };
+        let mut issue = issue("scope::rule", None, "depends");
+        issue.issue_type = SourceInfoIssue::BaseField {
+            field_name: "depends".to_string(),
+            value: "value".to_string(),
+            context: "context".to_string(),
+            architecture: Some(SystemArchitecture::X86_64),
+        }
+        .into();
+
+        assert!(suppression.applies_to(&issue));
+    }
+}
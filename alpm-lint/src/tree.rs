@@ -0,0 +1,143 @@
+//! Detection of lint targets in a directory tree.
+//!
+//! A directory tree may contain many package source repositories (e.g. one directory per package
+//! base) or many built package files.
+//! The [`LintTarget`]s in such a tree can be found with [`find_lint_targets`] and linted
+//! individually.
+
+use std::{
+    fs::read_dir,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use alpm_types::PackageFileName;
+
+use crate::{Error, LintScope};
+
+/// A file or directory in a directory tree, that can be linted on its own.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LintTarget {
+    /// The path of the file or directory.
+    pub path: PathBuf,
+    /// The [`LintScope`] in which the file or directory is linted.
+    pub scope: LintScope,
+}
+
+/// Returns all [`LintTarget`]s in the directory tree at `path`.
+///
+/// A directory for which a [`LintScope`] can be detected (see [`LintScope::detect`]) is a lint
+/// target on its own and is not searched any further.
+/// In all other directories, files with valid [alpm-package] file names are lint targets in the
+/// [`LintScope::PackageArtifact`] scope and all subdirectories are searched.
+/// Hidden files and directories (e.g. `.git`) are ignored.
+///
+/// The lint targets are sorted by path.
+///
+/// # Errors
+///
+/// Returns an error if a directory cannot be read.
+///
+/// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+pub fn find_lint_targets(path: &Path) -> Result<Vec<LintTarget>, Error> {
+    let mut targets = Vec::new();
+    find_lint_targets_in(path, &mut targets)?;
+    targets.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(targets)
+}
+
+/// Adds all [`LintTarget`]s in the directory tree at `path` to `targets`.
+///
+/// # Errors
+///
+/// Returns an error if a directory cannot be read.
+fn find_lint_targets_in(path: &Path, targets: &mut Vec<LintTarget>) -> Result<(), Error> {
+    if let Ok(scope) = LintScope::detect(path) {
+        targets.push(LintTarget {
+            path: path.to_path_buf(),
+            scope,
+        });
+        return Ok(());
+    }
+
+    let entries = read_dir(path).map_err(|source| Error::IoPath {
+        path: path.to_owned(),
+        context: "read directory entries",
+        source,
+    })?;
+    for entry in entries {
+        let entry = entry.map_err(|source| Error::IoPath {
+            path: path.to_owned(),
+            context: "read a specific directory entries",
+            source,
+        })?;
+        let entry_path = entry.path();
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name.starts_with('.') {
+            continue;
+        }
+
+        if entry_path.is_dir() {
+            find_lint_targets_in(&entry_path, targets)?;
+        } else if PackageFileName::from_str(&file_name).is_ok() {
+            targets.push(LintTarget {
+                path: entry_path,
+                scope: LintScope::PackageArtifact,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{File, create_dir_all};
+
+    use tempfile::tempdir;
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that package source repositories and package files are found in a directory tree.
+    #[test]
+    fn lint_targets_in_tree() -> TestResult {
+        let tempdir = tempdir()?;
+        let root = tempdir.path();
+        for (directory, file_name) in [
+            ("example", "PKGBUILD"),
+            ("example", ".SRCINFO"),
+            // Package files in a package source repository are not searched for.
+            ("example", "example-1.0.0-1-x86_64.pkg.tar.zst"),
+            ("nested/other", ".SRCINFO"),
+            ("packages", "other-1.0.0-1-any.pkg.tar.zst"),
+            ("packages", "other-1.0.0-1-any.pkg.tar.zst.sig"),
+            ("packages", "README.md"),
+            (".git/hidden", ".SRCINFO"),
+        ] {
+            create_dir_all(root.join(directory))?;
+            File::create(root.join(directory).join(file_name))?;
+        }
+
+        assert_eq!(
+            find_lint_targets(root)?,
+            vec![
+                LintTarget {
+                    path: root.join("example"),
+                    scope: LintScope::SourceRepository,
+                },
+                LintTarget {
+                    path: root.join("nested/other"),
+                    scope: LintScope::SourceInfo,
+                },
+                LintTarget {
+                    path: root.join("packages/other-1.0.0-1-any.pkg.tar.zst"),
+                    scope: LintScope::PackageArtifact,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -45,6 +45,43 @@ impl EditDistance for &str {
     }
 }
 
+/// Returns the section with the `heading` of a Markdown `documentation`.
+///
+/// The section consists of all lines following a first level heading (e.g. `# Example`) up to the
+/// next first level heading.
+/// Headings in code blocks and trailing link reference definitions (e.g. `[name]: https://...`)
+/// are not considered.
+///
+/// Returns [`None`] if `documentation` has no such section or if the section is empty.
+pub(crate) fn documentation_section(documentation: &str, heading: &str) -> Option<String> {
+    let lines = documentation
+        .lines()
+        .skip_while(|line| line.trim().strip_prefix("# ") != Some(heading))
+        .skip(1);
+
+    let mut section = Vec::new();
+    let mut in_code_block = false;
+    for line in lines {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        } else if !in_code_block && line.trim_start().starts_with("# ") {
+            break;
+        }
+        section.push(line);
+    }
+
+    // Link reference definitions are collected at the end of the documentation.
+    while section.last().is_some_and(|line| {
+        let line = line.trim();
+        line.is_empty() || (line.starts_with('[') && line.contains("]: "))
+    }) {
+        section.pop();
+    }
+    let start = section.iter().position(|line| !line.trim().is_empty())?;
+
+    Some(section[start..].join("\n"))
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -62,4 +99,34 @@ mod tests {
         let distance = s1.edit_distance(&s2);
         assert_eq!(distance, expected);
     }
+
+    #[test]
+    fn test_documentation_section() {
+        let documentation = r#"# What it does
+
+Checks something.
+
+# Example
+
+```ini,ignore
+# This is not a heading.
+pkgbase = test
+```
+
+Use instead [PKGBUILD].
+
+[PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5"#;
+
+        assert_eq!(
+            documentation_section(documentation, "Example").as_deref(),
+            Some(
+                "```ini,ignore\n# This is not a heading.\npkgbase = test\n```\n\nUse instead [PKGBUILD]."
+            )
+        );
+        assert_eq!(
+            documentation_section(documentation, "What it does").as_deref(),
+            Some("Checks something.")
+        );
+        assert_eq!(documentation_section(documentation, "Note"), None);
+    }
 }
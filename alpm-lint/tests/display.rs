@@ -1,10 +1,20 @@
-//! Integration tests for [`LintIssueDisplay`] formatting.
+//! Integration tests for [`LintIssueDisplay`] and [`LintSummary`] formatting.
 //!
 //! These tests verify that the display output is correctly formatted via insta snapshots
 
 use std::collections::BTreeMap;
 
-use alpm_lint::{Level, issue::display::LintIssueDisplay};
+use alpm_lint::{
+    Level,
+    LintScope,
+    issue::{
+        LintIssue,
+        LintIssueType,
+        SourceInfoIssue,
+        display::LintIssueDisplay,
+        summary::LintSummary,
+    },
+};
 use rstest::rstest;
 
 /// Helper function to create a default LintIssueDisplay for testing
@@ -17,6 +27,8 @@ fn default_display() -> LintIssueDisplay {
         message: "message".to_string(),
         help_text: "help_text".to_string(),
         custom_links: BTreeMap::new(),
+        suppressed: false,
+        suggestion: None,
     }
 }
 
@@ -149,6 +161,45 @@ fn test_display_with_custom_links() {
     });
 }
 
+/// Test display of a suppressed issue
+#[test]
+fn test_display_suppressed() {
+    force_color_off();
+
+    let display = LintIssueDisplay {
+        summary: Some("summary".to_string()),
+        suppressed: true,
+        ..default_display()
+    };
+
+    insta::with_settings!({
+        description => "Display of a suppressed issue.",
+        snapshot_path => "display_snapshots",
+        prepend_module_to_snapshot => false,
+    }, {
+        insta::assert_snapshot!("suppressed", format!("{display}"));
+    });
+}
+
+/// Test display of an issue with a suggested replacement
+#[test]
+fn test_display_suggestion() {
+    force_color_off();
+
+    let display = LintIssueDisplay {
+        suggestion: Some("replacement".to_string()),
+        ..default_display()
+    };
+
+    insta::with_settings!({
+        description => "Display of an issue with a suggested replacement.",
+        snapshot_path => "display_snapshots",
+        prepend_module_to_snapshot => false,
+    }, {
+        insta::assert_snapshot!("suggestion", format!("{display}"));
+    });
+}
+
 /// Test display with all everything.
 #[test]
 fn test_display_everything() {
@@ -191,3 +242,91 @@ fn test_display_minimal() {
         insta::assert_snapshot!("minimal", format!("{display}"));
     });
 }
+
+/// Helper function to create a [`LintIssue`] for a field of a package.
+fn package_issue(lint_rule: &str, level: Level, package_name: &str) -> LintIssue {
+    LintIssue {
+        lint_rule: lint_rule.to_string(),
+        level,
+        help_text: "help_text".to_string(),
+        scope: LintScope::SourceInfo,
+        issue_type: LintIssueType::SourceInfo(SourceInfoIssue::PackageField {
+            field_name: "field".to_string(),
+            package_name: package_name.to_string(),
+            value: "value".to_string(),
+            context: "context".to_string(),
+            architecture: None,
+        }),
+        links: BTreeMap::new(),
+        suppressed: false,
+        suggestion: None,
+    }
+}
+
+/// Test the aggregation of issues in a summary.
+#[test]
+fn test_summary_counts() {
+    let mut issues = vec![
+        package_issue("rule_b", Level::Warn, "foo"),
+        package_issue("rule_a", Level::Suggest, "bar"),
+        package_issue("rule_b", Level::Error, "bar"),
+    ];
+    issues.push(LintIssue {
+        issue_type: LintIssueType::SourceInfo(SourceInfoIssue::MissingField {
+            field_name: "field".to_string(),
+        }),
+        ..package_issue("rule_a", Level::Warn, "unused")
+    });
+
+    let summary = LintSummary::new(&issues);
+
+    assert_eq!(summary.total, 4);
+    assert_eq!(
+        summary.levels,
+        vec![(Level::Error, 1), (Level::Warn, 2), (Level::Suggest, 1)]
+    );
+    assert_eq!(
+        summary.rules,
+        vec![("rule_a".to_string(), 2), ("rule_b".to_string(), 2)]
+    );
+    assert_eq!(
+        summary.packages,
+        vec![("bar".to_string(), 2), ("foo".to_string(), 1)]
+    );
+}
+
+/// Test the display of a summary.
+#[test]
+fn test_display_summary() {
+    force_color_off();
+
+    let issues: Vec<LintIssue> = (0..12)
+        .map(|index| package_issue(&format!("rule_{index:02}"), Level::Warn, "example"))
+        .chain([package_issue("rule_00", Level::Deny, "other")])
+        .collect();
+    let summary = LintSummary::new(&issues);
+
+    insta::with_settings!({
+        description => "Summary display with truncated rule list.",
+        snapshot_path => "display_snapshots",
+        prepend_module_to_snapshot => false,
+    }, {
+        insta::assert_snapshot!("summary", format!("{summary}"));
+    });
+}
+
+/// Test the display of a summary without any issues.
+#[test]
+fn test_display_summary_empty() {
+    force_color_off();
+
+    let summary = LintSummary::new(&[]);
+
+    insta::with_settings!({
+        description => "Summary display without issues.",
+        snapshot_path => "display_snapshots",
+        prepend_module_to_snapshot => false,
+    }, {
+        insta::assert_snapshot!("summary_empty", format!("{summary}"));
+    });
+}
@@ -48,7 +48,11 @@ fn setup_valid_srcinfo() -> TestResult<TempDir> {
 }
 
 mod check {
+    use std::{collections::BTreeMap, fs::read_to_string};
+
     use alpm_lint::issue::LintIssue;
+    use alpm_srcinfo::SourceInfoV1;
+    use alpm_types::{Architectures, SystemArchitecture};
 
     use super::*;
 
@@ -117,6 +121,309 @@ mod check {
         Ok(())
     }
 
+    /// Test the check command with SARIF output format.
+    ///
+    /// The issues should be located at the affected line of the linted file.
+    #[test]
+    fn check_sarif_output() -> TestResult {
+        let tempdir = setup_faulty_srcinfo()?;
+
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            "--format",
+            "sarif",
+            &tempdir.path().join(".SRCINFO").to_string_lossy(),
+        ]);
+
+        let output = cmd.assert().failure().get_output().clone();
+        let output_str = String::from_utf8_lossy(&output.stdout);
+
+        let log: serde_json::Value = serde_json::from_str(&output_str)?;
+        assert_eq!(log["version"], "2.1.0");
+
+        let result = &log["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "source_info::unsafe_checksum");
+        assert_eq!(result["level"], "error");
+        assert!(
+            result["locations"][0]["physicalLocation"]["region"]["startLine"]
+                .as_u64()
+                .is_some()
+        );
+
+        Ok(())
+    }
+
+    /// Test the check command with a baseline.
+    ///
+    /// Issues recorded with `--write-baseline` should be suppressed in subsequent runs.
+    #[test]
+    fn check_baseline() -> TestResult {
+        let tempdir = setup_faulty_srcinfo()?;
+        let srcinfo_path = tempdir.path().join(".SRCINFO");
+        let baseline_path = tempdir.path().join("baseline.json");
+
+        // Record the existing issues.
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            "--baseline",
+            &baseline_path.to_string_lossy(),
+            "--write-baseline",
+            &srcinfo_path.to_string_lossy(),
+        ]);
+        cmd.assert().success();
+
+        // The recorded issues are suppressed.
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            "--format",
+            "json",
+            "--baseline",
+            &baseline_path.to_string_lossy(),
+            &srcinfo_path.to_string_lossy(),
+        ]);
+        let output = cmd.assert().success().get_output().clone();
+        let issues: Vec<LintIssue> =
+            serde_json::from_str(&String::from_utf8_lossy(&output.stdout))?;
+        assert!(issues.is_empty());
+
+        // Issues that are not recorded are still reported.
+        std::fs::write(&baseline_path, r#"{"issues":[]}"#)?;
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            "--baseline",
+            &baseline_path.to_string_lossy(),
+            &srcinfo_path.to_string_lossy(),
+        ]);
+        cmd.assert().failure();
+
+        Ok(())
+    }
+
+    /// Test the check command with inline suppression comments.
+    ///
+    /// Suppressed issues should only be reported with `--show-suppressed` and should not lead to
+    /// a non-zero exit code.
+    #[test]
+    fn check_suppressed() -> TestResult {
+        let tempdir = setup_faulty_srcinfo()?;
+        let srcinfo_path = tempdir.path().join(".SRCINFO");
+        let content = read_to_string(&srcinfo_path)?.replace(
+            "\tmd5sums = ",
+            "\t# alpm-lint: allow(unsafe_checksum)\n\tmd5sums = ",
+        );
+        std::fs::write(&srcinfo_path, content)?;
+
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            "--format",
+            "json",
+            &srcinfo_path.to_string_lossy(),
+        ]);
+        let output = cmd.assert().success().get_output().clone();
+        let issues: Vec<LintIssue> =
+            serde_json::from_str(&String::from_utf8_lossy(&output.stdout))?;
+        assert!(issues.is_empty());
+
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            "--format",
+            "json",
+            "--show-suppressed",
+            &srcinfo_path.to_string_lossy(),
+        ]);
+        let output = cmd.assert().success().get_output().clone();
+        let issues: Vec<LintIssue> =
+            serde_json::from_str(&String::from_utf8_lossy(&output.stdout))?;
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].lint_rule, "source_info::unsafe_checksum");
+        assert!(issues[0].suppressed);
+
+        Ok(())
+    }
+
+    /// Test the check command with human readable output.
+    ///
+    /// The issues should be followed by a summary.
+    #[test]
+    fn check_text_output_summary() -> TestResult {
+        let tempdir = setup_faulty_srcinfo()?;
+
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            &tempdir.path().join(".SRCINFO").to_string_lossy(),
+        ]);
+
+        let output = cmd.assert().failure().get_output().clone();
+        let output_str = String::from_utf8_lossy(&output.stdout);
+
+        let issue_position = output_str
+            .find("[source_info::unsafe_checksum]")
+            .ok_or("the issue should be printed")?;
+        let summary_position = output_str
+            .find("summary: ")
+            .ok_or("the summary should be printed")?;
+        assert!(issue_position < summary_position);
+
+        Ok(())
+    }
+
+    /// Test the check command in quiet mode.
+    ///
+    /// Only the summary should be printed and the command should still exit with code 1.
+    #[test]
+    fn check_quiet_output() -> TestResult {
+        let tempdir = setup_faulty_srcinfo()?;
+
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            "--quiet",
+            &tempdir.path().join(".SRCINFO").to_string_lossy(),
+        ]);
+
+        let output = cmd.assert().failure().get_output().clone();
+        let output_str = String::from_utf8_lossy(&output.stdout);
+
+        assert!(output_str.starts_with("summary: "));
+        assert!(output_str.contains("source_info::unsafe_checksum: 1"));
+        assert!(!output_str.contains("help:"));
+
+        Ok(())
+    }
+
+    /// Test the check command with automatic fixes.
+    ///
+    /// The duplicate architecture should be removed from the .SRCINFO file, after which no issues
+    /// remain and the command should exit with code 0.
+    #[test]
+    fn check_fix() -> TestResult {
+        let tempdir = tempdir()?;
+        let srcinfo_path = tempdir.path().join(".SRCINFO");
+
+        let mut source_info = default_source_info_v1()?;
+        source_info.base.architectures =
+            Architectures::Some(vec![SystemArchitecture::X86_64, SystemArchitecture::X86_64]);
+        let mut file = File::create(&srcinfo_path)?;
+        file.write_all(source_info.as_srcinfo().as_bytes())?;
+
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec!["check", "--fix", &srcinfo_path.to_string_lossy()]);
+        cmd.assert().success();
+
+        let fixed = SourceInfoV1::from_string(&read_to_string(&srcinfo_path)?)?;
+        assert_eq!(
+            fixed.base.architectures,
+            Architectures::Some(vec![SystemArchitecture::X86_64])
+        );
+
+        Ok(())
+    }
+
+    /// Test the check command with a profile from a lint profile bundle.
+    ///
+    /// The profile pins a set of lint rules, which does not contain the rule triggered by the
+    /// faulty .SRCINFO file, so the command should exit with code 0.
+    #[test]
+    fn check_profile() -> TestResult {
+        let tempdir = setup_faulty_srcinfo()?;
+        let bundle_path = tempdir.path().join("profiles.toml");
+        let mut file = File::create(&bundle_path)?;
+        file.write_all(
+            br#"version = "2025.10"
+
+[profiles.stable]
+rules = ["source_info::no_architecture"]
+"#,
+        )?;
+
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            "--profile-bundle",
+            &bundle_path.to_string_lossy(),
+            "--profile",
+            "stable",
+            &tempdir.path().join(".SRCINFO").to_string_lossy(),
+        ]);
+        cmd.assert().success();
+
+        // Unknown profiles are rejected.
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            "--profile-bundle",
+            &bundle_path.to_string_lossy(),
+            "--profile",
+            "testing",
+            &tempdir.path().join(".SRCINFO").to_string_lossy(),
+        ]);
+        cmd.assert().failure();
+
+        Ok(())
+    }
+
+    /// Test the check command with a profile of the lint configuration.
+    ///
+    /// The profile inherits a level override, which turns the issue of the faulty .SRCINFO file
+    /// into a suggestion.
+    #[test]
+    fn check_config_profile() -> TestResult {
+        let tempdir = setup_faulty_srcinfo()?;
+        let config_path = tempdir.path().join("config.toml");
+        let mut file = File::create(&config_path)?;
+        file.write_all(
+            br#"[profiles.aur.config]
+levels = { "source_info::unsafe_checksum" = "Suggest" }
+
+[profiles.strict]
+inherits = "aur"
+"#,
+        )?;
+
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            "--config",
+            &config_path.to_string_lossy(),
+            "--profile",
+            "strict",
+            &tempdir.path().join(".SRCINFO").to_string_lossy(),
+        ]);
+        cmd.assert().success();
+
+        // The issue is reported with the overridden level.
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            "--config",
+            &config_path.to_string_lossy(),
+            "--profile",
+            "strict",
+            "--level",
+            "suggest",
+            "--format",
+            "json",
+            &tempdir.path().join(".SRCINFO").to_string_lossy(),
+        ]);
+        let output = cmd.assert().failure().get_output().clone();
+        let issues: Vec<LintIssue> =
+            serde_json::from_str(&String::from_utf8_lossy(&output.stdout))?;
+        let issue = issues
+            .iter()
+            .find(|issue| issue.lint_rule == "source_info::unsafe_checksum")
+            .ok_or("the issue should be reported")?;
+        assert_eq!(issue.level, alpm_lint::Level::Suggest);
+
+        Ok(())
+    }
+
     /// Test the check command with pretty output.
     #[test]
     fn check_pretty_output() -> TestResult {
@@ -136,6 +443,64 @@ mod check {
 
         Ok(())
     }
+
+    /// Test the check command with a directory tree of package source repositories.
+    ///
+    /// Issues should be reported per linted directory and the exit code should respect the
+    /// `--max-level` option.
+    #[test]
+    fn check_recursive() -> TestResult {
+        let tempdir = tempdir()?;
+        let faulty = setup_faulty_srcinfo()?;
+        let valid = setup_valid_srcinfo()?;
+        for (name, source) in [("faulty", &faulty), ("nested/valid", &valid)] {
+            let directory = tempdir.path().join(name);
+            std::fs::create_dir_all(&directory)?;
+            std::fs::copy(source.path().join(".SRCINFO"), directory.join(".SRCINFO"))?;
+        }
+
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            "--recursive",
+            "--jobs",
+            "2",
+            "--format",
+            "json",
+            &tempdir.path().to_string_lossy(),
+        ]);
+        let output = cmd.assert().failure().get_output().clone();
+        let issues: BTreeMap<String, Vec<LintIssue>> =
+            serde_json::from_str(&String::from_utf8_lossy(&output.stdout))?;
+        let faulty_issues = &issues[&tempdir.path().join("faulty").to_string_lossy().to_string()];
+        assert_eq!(faulty_issues.len(), 1);
+        assert_eq!(faulty_issues[0].lint_rule, "source_info::unsafe_checksum");
+        assert!(
+            issues[&tempdir
+                .path()
+                .join("nested/valid")
+                .to_string_lossy()
+                .to_string()]
+                .is_empty()
+        );
+
+        // The unsafe checksum is a denial, which is less severe than an error.
+        let mut cmd = cargo_bin_cmd!("alpm-lint");
+        cmd.args(vec![
+            "check",
+            "--recursive",
+            "--max-level",
+            "error",
+            &tempdir.path().to_string_lossy(),
+        ]);
+        let output = cmd.assert().success().get_output().clone();
+        let output_str = String::from_utf8_lossy(&output.stdout);
+        assert!(output_str.contains(&format!("==> {}", tempdir.path().join("faulty").display())));
+        assert!(!output_str.contains("nested"));
+        assert!(output_str.contains("summary: "));
+
+        Ok(())
+    }
 }
 
 mod rules {
@@ -151,7 +516,19 @@ mod rules {
         let output_str = String::from_utf8_lossy(&output.stdout);
 
         // The output should contain valid JSON
-        let _: serde_json::Value = serde_json::from_str(&output_str)?;
+        let rules: serde_json::Value = serde_json::from_str(&output_str)?;
+
+        // Each lint rule should provide its metadata.
+        let rule = &rules["source_info::duplicate_architecture"];
+        assert_eq!(rule["scoped_name"], "source_info::duplicate_architecture");
+        assert_eq!(rule["category"], "Style");
+        assert_eq!(rule["level"], "Warn");
+        assert_eq!(rule["fixable"], true);
+        assert!(rule["example"].as_str().is_some());
+        assert_eq!(
+            rule["documentation_url"],
+            "https://alpm.archlinux.page/lints/index.html#source_info::duplicate_architecture"
+        );
 
         Ok(())
     }
@@ -0,0 +1,141 @@
+use alpm_lint::{
+    Level,
+    Resources,
+    config::{CustomRuleCheck, CustomRulePattern, CustomRuleScope},
+    issue::{LintIssueType, PackageInfoIssue, SourceInfoIssue},
+    lint_rules::custom::CustomLintRule,
+};
+use alpm_pkginfo::PackageInfo;
+use alpm_srcinfo::SourceInfo;
+use testresult::TestResult;
+
+use super::custom_rule;
+use crate::fixtures::{default_package_info_v2, default_source_info_v1};
+
+#[test]
+fn custom_lint_rule_pattern_passes() -> TestResult {
+    let resources = Resources::SourceInfo(SourceInfo::V1(default_source_info_v1()?));
+    let lint_rule = CustomLintRule::new_boxed(custom_rule(
+        CustomRuleScope::SourceInfo,
+        CustomRuleCheck::Pattern {
+            field: "pkgname".to_string(),
+            pattern: CustomRulePattern::try_from("^test-".to_string())?,
+        },
+    ));
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[test]
+fn custom_lint_rule_pattern_fails() -> TestResult {
+    let resources = Resources::SourceInfo(SourceInfo::V1(default_source_info_v1()?));
+    let lint_rule = CustomLintRule::new_boxed(custom_rule(
+        CustomRuleScope::SourceInfo,
+        CustomRuleCheck::Pattern {
+            field: "pkgname".to_string(),
+            pattern: CustomRulePattern::try_from("^python-".to_string())?,
+        },
+    ));
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint issue should have been found");
+    assert_eq!(issues[0].lint_rule, "source_info::house_rule");
+    assert_eq!(issues[0].level, Level::Deny);
+    assert!(matches!(
+        &issues[0].issue_type,
+        LintIssueType::SourceInfo(SourceInfoIssue::PackageField { package_name, .. })
+            if package_name == "test-package"
+    ));
+    Ok(())
+}
+
+#[test]
+fn custom_lint_rule_forbidden_values_fails() -> TestResult {
+    let resources = Resources::SourceInfo(SourceInfo::V1(default_source_info_v1()?));
+    let lint_rule = CustomLintRule::new_boxed(custom_rule(
+        CustomRuleScope::SourceInfo,
+        CustomRuleCheck::ForbiddenValues {
+            field: "arch".to_string(),
+            values: vec!["any".to_string()],
+        },
+    ));
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint issue should have been found");
+    assert!(matches!(
+        &issues[0].issue_type,
+        LintIssueType::SourceInfo(SourceInfoIssue::BaseField { field_name, .. })
+            if field_name == "arch"
+    ));
+    Ok(())
+}
+
+#[test]
+fn custom_lint_rule_required_field_fails() -> TestResult {
+    let resources = Resources::SourceInfo(SourceInfo::V1(default_source_info_v1()?));
+    let lint_rule = CustomLintRule::new_boxed(custom_rule(
+        CustomRuleScope::SourceInfo,
+        CustomRuleCheck::RequiredField {
+            field: "url".to_string(),
+        },
+    ));
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint issue should have been found");
+    assert!(matches!(
+        &issues[0].issue_type,
+        LintIssueType::SourceInfo(SourceInfoIssue::MissingField { field_name })
+            if field_name == "url"
+    ));
+    Ok(())
+}
+
+#[test]
+fn custom_lint_rule_passes_for_package_info() -> TestResult {
+    let resources = Resources::PackageInfo(PackageInfo::V2(default_package_info_v2()?));
+    let lint_rule = CustomLintRule::new_boxed(custom_rule(
+        CustomRuleScope::PackageInfo,
+        CustomRuleCheck::RequiredField {
+            field: "url".to_string(),
+        },
+    ));
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[test]
+fn custom_lint_rule_fails_for_package_info() -> TestResult {
+    let resources = Resources::PackageInfo(PackageInfo::V2(default_package_info_v2()?));
+    let lint_rule = CustomLintRule::new_boxed(custom_rule(
+        CustomRuleScope::PackageInfo,
+        CustomRuleCheck::Pattern {
+            field: "url".to_string(),
+            pattern: CustomRulePattern::try_from("^http://".to_string())?,
+        },
+    ));
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint issue should have been found");
+    assert_eq!(issues[0].lint_rule, "package_info::house_rule");
+    assert!(matches!(
+        &issues[0].issue_type,
+        LintIssueType::PackageInfo(PackageInfoIssue::Field { field_name, .. }) if field_name == "url"
+    ));
+    Ok(())
+}
@@ -0,0 +1,17 @@
+//! Tests for custom lint rules.
+
+use alpm_lint::config::{CustomRule, CustomRuleCheck, CustomRuleScope, LintLevel};
+
+pub mod custom_lint_rule;
+
+/// Creates a [`CustomRule`] for a `scope` that performs a `check`.
+fn custom_rule(scope: CustomRuleScope, check: CustomRuleCheck) -> CustomRule {
+    CustomRule {
+        name: "house_rule".to_string(),
+        scope,
+        level: LintLevel::Deny,
+        description: "A house rule.".to_string(),
+        help: "Follow the house rule.".to_string(),
+        check,
+    }
+}
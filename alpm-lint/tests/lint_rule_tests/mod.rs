@@ -1 +1,6 @@
+pub mod custom;
+pub mod package;
+pub mod package_artifact;
+pub mod package_build;
+pub mod relations;
 pub mod source_info;
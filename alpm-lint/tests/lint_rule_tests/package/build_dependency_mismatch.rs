@@ -0,0 +1,104 @@
+use std::str::FromStr;
+
+use alpm_buildinfo::BuildInfo;
+use alpm_lint::{
+    Resources,
+    config::LintRuleConfiguration,
+    lint_rules::package::build_dependency_mismatch::BuildDependencyMismatch,
+};
+use alpm_pkginfo::PackageInfo;
+use alpm_srcinfo::SourceInfo;
+use alpm_types::{InstalledPackage, PackageRelation};
+use testresult::TestResult;
+
+use crate::fixtures::{default_build_info_v2, default_package_info_v2, default_source_info_v1};
+
+/// Creates [`Resources`] for a package with the build dependencies `makedepends` in its SRCINFO
+/// data, that has been built with the packages `installed`.
+fn package_resources(makedepends: &[&str], installed: &[&str]) -> TestResult<Resources> {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.make_dependencies = makedepends
+        .iter()
+        .map(|relation| PackageRelation::from_str(relation))
+        .collect::<Result<_, _>>()?;
+    let mut build_info = default_build_info_v2()?;
+    build_info.installed = installed
+        .iter()
+        .map(|package| InstalledPackage::from_str(package))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Resources::Package {
+        package_info: PackageInfo::V2(default_package_info_v2()?),
+        build_info: BuildInfo::V2(build_info),
+        source_info: Some(SourceInfo::V1(source_info)),
+    })
+}
+
+#[test]
+fn build_dependency_mismatch_passes() -> TestResult {
+    let resources = package_resources(
+        &["gcc", "meson>=1.5"],
+        &["gcc-14.2.1-1-x86_64", "meson-1.5.1-1-any"],
+    )?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = BuildDependencyMismatch::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[test]
+fn build_dependency_mismatch_fails() -> TestResult {
+    let resources = package_resources(
+        &["cmake", "meson>=2.0"],
+        &["meson-1.5.1-1-any", "ninja-1.12.1-1-x86_64"],
+    )?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = BuildDependencyMismatch::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 3, "Three lint issues should have been found");
+    assert!(
+        issues
+            .iter()
+            .all(|issue| issue.lint_rule == "package::build_dependency_mismatch")
+    );
+    Ok(())
+}
+
+#[test]
+fn build_dependency_mismatch_build_environment_packages() -> TestResult {
+    let resources = package_resources(&[], &["ninja-1.12.1-1-x86_64"])?;
+    let config = LintRuleConfiguration {
+        build_environment_packages: vec!["ninja".to_string()],
+        ..Default::default()
+    };
+    let lint_rule = BuildDependencyMismatch::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[test]
+fn build_dependency_mismatch_without_source_info() -> TestResult {
+    let mut resources = package_resources(&["cmake"], &["ninja-1.12.1-1-x86_64"])?;
+    if let Resources::Package { source_info, .. } = &mut resources {
+        *source_info = None;
+    }
+    let config = LintRuleConfiguration::default();
+    let lint_rule = BuildDependencyMismatch::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
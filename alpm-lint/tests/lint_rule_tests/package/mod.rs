@@ -0,0 +1,3 @@
+//! Tests for package scope lint rules.
+
+pub mod build_dependency_mismatch;
@@ -0,0 +1,54 @@
+use alpm_buildinfo::BuildInfo;
+use alpm_lint::{
+    Resources,
+    config::LintRuleConfiguration,
+    lint_rules::package_artifact::files_in_usr_local::FilesInUsrLocal,
+};
+use alpm_mtree::{Mtree, parse_mtree_v2};
+use alpm_pkginfo::PackageInfo;
+use testresult::TestResult;
+
+use crate::fixtures::{default_build_info_v2, default_package_info_v2};
+
+/// Creates [`Resources`] for a package, that contains `paths`.
+fn package_resources(paths: &[&str]) -> TestResult<Resources> {
+    let mut mtree = "#mtree\n/set mode=644 uid=0 gid=0 type=file\n".to_string();
+    for path in paths {
+        mtree.push_str(&format!(
+            "{path} time=1700000000.0 size=1337 sha256digest=0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef\n"
+        ));
+    }
+
+    Ok(Resources::PackageArtifact {
+        package_info: PackageInfo::V2(default_package_info_v2()?),
+        build_info: BuildInfo::V2(default_build_info_v2()?),
+        mtree: Mtree::V2(parse_mtree_v2(mtree)?),
+    })
+}
+
+#[test]
+fn files_in_usr_local_passes() -> TestResult {
+    let resources = package_resources(&["./usr/bin/example", "./usr/share/local/example"])?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = FilesInUsrLocal::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[test]
+fn files_in_usr_local_fails() -> TestResult {
+    let resources = package_resources(&["./usr/bin/example", "./usr/local/bin/example"])?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = FilesInUsrLocal::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint issue should have been found");
+    assert_eq!(issues[0].lint_rule, "package_artifact::files_in_usr_local");
+    Ok(())
+}
@@ -0,0 +1,3 @@
+//! Tests for package artifact scope lint rules.
+
+pub mod files_in_usr_local;
@@ -0,0 +1,54 @@
+use alpm_lint::{
+    config::LintRuleConfiguration,
+    issue::{LintIssueType, PackageBuildIssue},
+    lint_rules::package_build::deprecated_checksum_variable::DeprecatedChecksumVariable,
+};
+
+use super::package_build_resources;
+
+const SCRIPT: &str = r#"pkgname=example
+source=("https://example.org/example.tar.gz")
+sha256sums=('b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c')
+md5sums_x86_64=('11111111111111111111111111111111')
+"#;
+
+#[test]
+fn deprecated_checksum_variable_fails() -> testresult::TestResult {
+    let resources = package_build_resources(SCRIPT)?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = DeprecatedChecksumVariable::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(
+        issues[0].lint_rule,
+        "package_build::deprecated_checksum_variable"
+    );
+    assert!(matches!(
+        &issues[0].issue_type,
+        LintIssueType::PackageBuild(PackageBuildIssue::Line { line: 4, .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn deprecated_checksum_variable_respects_configuration() -> testresult::TestResult {
+    let resources = package_build_resources(SCRIPT)?;
+    let config = LintRuleConfiguration {
+        allowed_checksum_algorithms: vec!["md5".to_string()],
+        ..Default::default()
+    };
+    let lint_rule = DeprecatedChecksumVariable::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1);
+    assert!(matches!(
+        &issues[0].issue_type,
+        LintIssueType::PackageBuild(PackageBuildIssue::Line { line: 3, .. })
+    ));
+    Ok(())
+}
@@ -0,0 +1,19 @@
+//! Tests for PKGBUILD scope lint rules.
+
+use alpm_lint::Resources;
+use alpm_pkgbuild::script::PackageBuildScript;
+use alpm_srcinfo::SourceInfo;
+
+use crate::fixtures::default_source_info_v1;
+
+pub mod deprecated_checksum_variable;
+pub mod unnecessary_cd_guard;
+pub mod unquoted_build_directory;
+
+/// Creates [`Resources`] of a PKGBUILD with the contents `script`.
+fn package_build_resources(script: &str) -> testresult::TestResult<Resources> {
+    Ok(Resources::PackageBuild {
+        source_info: SourceInfo::V1(default_source_info_v1()?),
+        script: PackageBuildScript::new(script),
+    })
+}
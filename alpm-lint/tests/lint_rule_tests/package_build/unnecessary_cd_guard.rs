@@ -0,0 +1,52 @@
+use alpm_lint::{
+    config::LintRuleConfiguration,
+    issue::{LintIssueType, PackageBuildIssue},
+    lint_rules::package_build::unnecessary_cd_guard::UnnecessaryCdGuard,
+};
+
+use super::package_build_resources;
+
+#[test]
+fn unnecessary_cd_guard_passes() -> testresult::TestResult {
+    let resources = package_build_resources(
+        r#"pkgname=example
+build() {
+  cd "$pkgname"
+  make || true
+}
+"#,
+    )?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = UnnecessaryCdGuard::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[test]
+fn unnecessary_cd_guard_fails() -> testresult::TestResult {
+    let resources = package_build_resources(
+        r#"pkgname=example
+build() {
+  cd "$pkgname" || exit 1
+  make
+}
+"#,
+    )?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = UnnecessaryCdGuard::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].lint_rule, "package_build::unnecessary_cd_guard");
+    assert!(matches!(
+        &issues[0].issue_type,
+        LintIssueType::PackageBuild(PackageBuildIssue::Line { line: 3, .. })
+    ));
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use alpm_lint::{
+    config::LintRuleConfiguration,
+    issue::{LintIssueType, PackageBuildIssue},
+    lint_rules::package_build::unquoted_build_directory::UnquotedBuildDirectory,
+};
+
+use super::package_build_resources;
+
+#[test]
+fn unquoted_build_directory_passes() -> testresult::TestResult {
+    let resources = package_build_resources(
+        r#"pkgname=example
+package() {
+  local docdir=$pkgdir/usr/share/doc
+  install -Dm644 README "$pkgdir/usr/share/doc/example/README"
+}
+"#,
+    )?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = UnquotedBuildDirectory::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[test]
+fn unquoted_build_directory_fails() -> testresult::TestResult {
+    let resources = package_build_resources(
+        r#"pkgname=example
+build() {
+  cd ${srcdir}/example
+}
+
+package() {
+  make DESTDIR=$pkgdir install
+}
+"#,
+    )?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = UnquotedBuildDirectory::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 2);
+    assert_eq!(
+        issues[0].lint_rule,
+        "package_build::unquoted_build_directory"
+    );
+    assert_eq!(
+        issues[0].issue_type,
+        LintIssueType::PackageBuild(PackageBuildIssue::Line {
+            line: 3,
+            function: Some("build".to_string()),
+            content: "  cd ${srcdir}/example".to_string(),
+            context: "Unquoted variable 'srcdir'".to_string(),
+        })
+    );
+    assert!(matches!(
+        &issues[1].issue_type,
+        LintIssueType::PackageBuild(PackageBuildIssue::Line { line: 7, .. })
+    ));
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use alpm_lint::{
+    config::LintRuleConfiguration,
+    lint_rules::relations::duplicate_dependency::DuplicateDependency,
+};
+use testresult::TestResult;
+
+use super::source_info_resources;
+
+#[test]
+fn duplicate_dependency_passes() -> TestResult {
+    let resources = source_info_resources(&["glibc", "glibc>=2"], &["python: for bindings"], &[])?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = DuplicateDependency::new_boxed_source_info(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[test]
+fn duplicate_dependency_fails() -> TestResult {
+    let resources = source_info_resources(
+        &["glibc", "glibc"],
+        &["python: for bindings", "python: for scripts"],
+        &[],
+    )?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = DuplicateDependency::new_boxed_source_info(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 2, "Two lint issues should have been found");
+    assert_eq!(issues[0].lint_rule, "source_info::duplicate_dependency");
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use alpm_lint::{
+    config::LintRuleConfiguration,
+    lint_rules::relations::missing_optional_dependency_description::MissingOptionalDependencyDescription,
+};
+use testresult::TestResult;
+
+use super::source_info_resources;
+
+#[test]
+fn missing_optional_dependency_description_passes() -> TestResult {
+    let resources = source_info_resources(&[], &["python: for bindings"], &[])?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = MissingOptionalDependencyDescription::new_boxed_source_info(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[test]
+fn missing_optional_dependency_description_fails() -> TestResult {
+    let resources = source_info_resources(&[], &["python: for bindings", "ruby"], &[])?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = MissingOptionalDependencyDescription::new_boxed_source_info(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint issue should have been found");
+    assert_eq!(
+        issues[0].lint_rule,
+        "source_info::missing_optional_dependency_description"
+    );
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! Tests for package relation lint rules.
+
+use std::str::FromStr;
+
+use alpm_lint::Resources;
+use alpm_srcinfo::SourceInfo;
+use alpm_types::{OptionalDependency, RelationOrSoname};
+use testresult::TestResult;
+
+use crate::fixtures::default_source_info_v1;
+
+pub mod duplicate_dependency;
+pub mod missing_optional_dependency_description;
+pub mod provides_own_name;
+pub mod self_dependency;
+pub mod unsatisfiable_dependency;
+
+/// Creates [`Resources`] for a [SRCINFO] file with the given relations in its package base.
+///
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+fn source_info_resources(
+    dependencies: &[&str],
+    optional_dependencies: &[&str],
+    provides: &[&str],
+) -> TestResult<Resources> {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.dependencies = dependencies
+        .iter()
+        .map(|value| RelationOrSoname::from_str(value))
+        .collect::<Result<_, _>>()?;
+    source_info.base.optional_dependencies = optional_dependencies
+        .iter()
+        .map(|value| OptionalDependency::from_str(value))
+        .collect::<Result<_, _>>()?;
+    source_info.base.provides = provides
+        .iter()
+        .map(|value| RelationOrSoname::from_str(value))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Resources::SourceInfo(SourceInfo::V1(source_info)))
+}
@@ -0,0 +1,34 @@
+use alpm_lint::{
+    config::LintRuleConfiguration,
+    lint_rules::relations::provides_own_name::ProvidesOwnName,
+};
+use testresult::TestResult;
+
+use super::source_info_resources;
+
+#[test]
+fn provides_own_name_passes() -> TestResult {
+    let resources = source_info_resources(&[], &[], &["test-package-git", "libexample.so"])?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = ProvidesOwnName::new_boxed_source_info(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[test]
+fn provides_own_name_fails() -> TestResult {
+    let resources = source_info_resources(&[], &[], &["test-package=2.0.0"])?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = ProvidesOwnName::new_boxed_source_info(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint issue should have been found");
+    assert_eq!(issues[0].lint_rule, "source_info::provides_own_name");
+    Ok(())
+}
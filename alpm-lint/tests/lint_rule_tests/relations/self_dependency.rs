@@ -0,0 +1,62 @@
+use std::str::FromStr;
+
+use alpm_lint::{
+    Resources,
+    config::LintRuleConfiguration,
+    lint_rules::relations::self_dependency::SelfDependency,
+};
+use alpm_pkginfo::PackageInfo;
+use alpm_types::RelationOrSoname;
+use testresult::TestResult;
+
+use super::source_info_resources;
+use crate::fixtures::default_package_info_v2;
+
+#[test]
+fn self_dependency_passes() -> TestResult {
+    let resources = source_info_resources(&["glibc"], &["python: for bindings"], &[])?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = SelfDependency::new_boxed_source_info(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[test]
+fn self_dependency_fails() -> TestResult {
+    let resources = source_info_resources(
+        &["glibc", "test-package"],
+        &["test-package: for itself"],
+        &[],
+    )?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = SelfDependency::new_boxed_source_info(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 2, "Two lint issues should have been found");
+    assert_eq!(issues[0].lint_rule, "source_info::self_dependency");
+    Ok(())
+}
+
+#[test]
+fn self_dependency_fails_for_package_info() -> TestResult {
+    let mut package_info = default_package_info_v2()?;
+    package_info
+        .depend
+        .push(RelationOrSoname::from_str("test-package")?);
+    let resources = Resources::PackageInfo(PackageInfo::V2(package_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = SelfDependency::new_boxed_package_info(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint issue should have been found");
+    assert_eq!(issues[0].lint_rule, "package_info::self_dependency");
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use alpm_lint::{
+    config::LintRuleConfiguration,
+    lint_rules::relations::unsatisfiable_dependency::UnsatisfiableDependency,
+};
+use testresult::TestResult;
+
+use super::source_info_resources;
+
+#[test]
+fn unsatisfiable_dependency_passes() -> TestResult {
+    let resources = source_info_resources(&["foo>=1", "foo<2", "bar<1"], &[], &[])?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = UnsatisfiableDependency::new_boxed_source_info(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[test]
+fn unsatisfiable_dependency_fails() -> TestResult {
+    let resources = source_info_resources(&["foo>=2", "bar>=2", "foo<1"], &[], &[])?;
+    let config = LintRuleConfiguration::default();
+    let lint_rule = UnsatisfiableDependency::new_boxed_source_info(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint issue should have been found");
+    assert_eq!(issues[0].lint_rule, "source_info::unsatisfiable_dependency");
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use alpm_lint::{
+    Resources,
+    config::LintRuleConfiguration,
+    lint_rules::source_info::dead_source_host::DeadSourceHost,
+};
+use alpm_srcinfo::SourceInfo;
+use alpm_types::Source;
+use rstest::rstest;
+
+use crate::fixtures::default_source_info_v1;
+
+#[rstest]
+#[case("https://example.com/source.tar.gz")]
+#[case("https://notgitorious.org/source.tar.gz")]
+#[case("git+https://gitlab.com/example/source.git#tag=v1.0.0")]
+#[case("source.tar.gz")]
+fn dead_source_host_passes(#[case] source: &str) -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.sources = vec![Source::from_str(source)?];
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = DeadSourceHost::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[rstest]
+#[case("https://example.googlecode.com/files/source.tar.gz")]
+#[case("https://code.google.com/p/example/source.tar.gz")]
+#[case("git+https://gitorious.org/example/source.git#tag=v1.0.0")]
+#[case("source.tar.gz::https://download.berlios.de/example/source.tar.gz")]
+fn dead_source_host_fails(#[case] source: &str) -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.sources = vec![Source::from_str(source)?];
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = DeadSourceHost::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint error should've been found.");
+    assert_eq!(issues[0].lint_rule, "source_info::dead_source_host");
+    Ok(())
+}
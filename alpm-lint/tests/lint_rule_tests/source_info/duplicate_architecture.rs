@@ -1,6 +1,7 @@
 use alpm_lint::{
     Resources,
     config::LintRuleConfiguration,
+    fix::Edit,
     lint_rules::source_info::duplicate_architecture::DuplicateArchitecture,
 };
 use alpm_srcinfo::SourceInfo;
@@ -51,3 +52,35 @@ fn duplicate_architecture_fails(#[case] architectures: Architectures) -> testres
     assert_eq!(issues[0].lint_rule, "source_info::duplicate_architecture");
     Ok(())
 }
+
+#[rstest]
+#[case::no_duplicates(
+    Architectures::Some(vec![SystemArchitecture::X86_64, SystemArchitecture::Aarch64]),
+    vec![],
+)]
+#[case::duplicates(
+    Architectures::Some(vec![
+        SystemArchitecture::X86_64,
+        SystemArchitecture::Aarch64,
+        SystemArchitecture::X86_64,
+        SystemArchitecture::X86_64,
+    ]),
+    vec![
+        Edit::remove(".SRCINFO", "arch = x86_64", 1),
+        Edit::remove(".SRCINFO", "arch = x86_64", 2),
+    ],
+)]
+fn duplicate_architecture_fix(
+    #[case] architectures: Architectures,
+    #[case] expected: Vec<Edit>,
+) -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.architectures = architectures;
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = DuplicateArchitecture::new_boxed(&config);
+
+    assert_eq!(lint_rule.fix(&resources)?, expected);
+    Ok(())
+}
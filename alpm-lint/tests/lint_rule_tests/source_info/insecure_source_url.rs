@@ -0,0 +1,87 @@
+use std::str::FromStr;
+
+use alpm_lint::{
+    Resources,
+    config::LintRuleConfiguration,
+    lint_rules::source_info::insecure_source_url::InsecureSourceUrl,
+};
+use alpm_srcinfo::{SourceInfo, source_info::v1::package_base::PackageBaseArchitecture};
+use alpm_types::{Source, SystemArchitecture};
+use rstest::rstest;
+
+use crate::fixtures::default_source_info_v1;
+
+#[rstest]
+#[case("https://example.com/source.tar.gz")]
+#[case("git+https://example.com/source.git#tag=v1.0.0")]
+#[case("source.tar.gz")]
+fn insecure_source_url_passes(#[case] source: &str) -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.sources = vec![Source::from_str(source)?];
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = InsecureSourceUrl::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[rstest]
+#[case(
+    "http://example.com/source.tar.gz",
+    "https://example.com/source.tar.gz"
+)]
+#[case(
+    "source.tar.gz::http://example.com/download",
+    "source.tar.gz::https://example.com/download"
+)]
+#[case(
+    "git+http://example.com/source.git#tag=v1.0.0",
+    "git+https://example.com/source.git#tag=v1.0.0"
+)]
+fn insecure_source_url_fails(
+    #[case] source: &str,
+    #[case] suggestion: &str,
+) -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.sources = vec![Source::from_str(source)?];
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = InsecureSourceUrl::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint error should've been found.");
+    assert_eq!(issues[0].lint_rule, "source_info::insecure_source_url");
+    assert_eq!(issues[0].suggestion.as_deref(), Some(suggestion));
+    Ok(())
+}
+
+#[test]
+fn architecture_specific_insecure_source_url_fails() -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.architecture_properties.insert(
+        SystemArchitecture::X86_64,
+        PackageBaseArchitecture {
+            sources: vec![Source::from_str("http://example.com/source.tar.gz")?],
+            ..PackageBaseArchitecture::default()
+        },
+    );
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = InsecureSourceUrl::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint error should've been found.");
+    assert_eq!(issues[0].lint_rule, "source_info::insecure_source_url");
+    Ok(())
+}
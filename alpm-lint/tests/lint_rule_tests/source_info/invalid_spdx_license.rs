@@ -3,6 +3,7 @@ use std::str::FromStr;
 use alpm_lint::{
     Resources,
     config::LintRuleConfiguration,
+    fix::Edit,
     lint_rules::source_info::invalid_spdx_license::NotSPDX,
 };
 use alpm_srcinfo::{
@@ -10,6 +11,7 @@ use alpm_srcinfo::{
     source_info::v1::package::{Override, Package},
 };
 use alpm_types::{License, Name};
+use rstest::rstest;
 
 use crate::fixtures::default_source_info_v1;
 
@@ -67,3 +69,54 @@ fn package_invalid_spdx_license_fails() -> testresult::TestResult {
     assert_eq!(issues[0].lint_rule, "source_info::invalid_spdx_license");
     Ok(())
 }
+
+#[rstest]
+#[case::deprecated_spdx("GPL-2.0", Some("GPL-2.0-only"))]
+#[case::legacy("GPL", Some("GPL-2.0-only"))]
+#[case::imprecise("Apache", Some("Apache-2.0"))]
+#[case::unknown("Custom-License", None)]
+fn invalid_spdx_license_suggestion(
+    #[case] license: &str,
+    #[case] suggestion: Option<&str>,
+) -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.licenses = vec![License::from_str(license)?];
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = NotSPDX::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint error should've been found.");
+    assert_eq!(issues[0].suggestion.as_deref(), suggestion);
+    Ok(())
+}
+
+#[test]
+fn invalid_spdx_license_fix() -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.licenses = vec![
+        License::from_str("GPL")?,
+        License::from_str("Custom-License")?,
+    ];
+    let mut package = Package::from(Name::from_str("test-package")?);
+    package.licenses = Override::Yes {
+        value: vec![License::from_str("GPL")?],
+    };
+    source_info.packages.push(package);
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = NotSPDX::new_boxed(&config);
+
+    assert_eq!(
+        lint_rule.fix(&resources)?,
+        vec![
+            Edit::replace(".SRCINFO", "license = GPL", 0, "license = GPL-2.0-only"),
+            Edit::replace(".SRCINFO", "license = GPL", 1, "license = GPL-2.0-only"),
+        ]
+    );
+    Ok(())
+}
@@ -1,9 +1,13 @@
 //! Tests for source info scope lint rules.
 
+pub mod dead_source_host;
 pub mod duplicate_architecture;
+pub mod insecure_source_url;
 pub mod invalid_spdx_license;
 pub mod no_architecture;
 pub mod openpgp_key_id;
+pub mod skipped_checksum;
 pub mod undefined_architecture;
 mod unknown_architecture;
+pub mod unpinned_vcs_source;
 pub mod unsafe_checksum;
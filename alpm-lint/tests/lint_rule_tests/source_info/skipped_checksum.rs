@@ -0,0 +1,78 @@
+use std::str::FromStr;
+
+use alpm_lint::{
+    Resources,
+    config::LintRuleConfiguration,
+    lint_rules::source_info::skipped_checksum::SkippedChecksum,
+};
+use alpm_srcinfo::{SourceInfo, source_info::v1::package_base::PackageBaseArchitecture};
+use alpm_types::{SkippableChecksum, Source, SystemArchitecture, digests::Sha256};
+use rstest::rstest;
+
+use crate::fixtures::default_source_info_v1;
+
+#[rstest]
+#[case("git+https://example.com/source.git#tag=v1.0.0")]
+#[case("https://example.com/source.tar.gz.sig")]
+#[case("source.tar.gz.asc::https://example.com/download")]
+#[case("source.tar.gz.sig")]
+fn skipped_checksum_passes(#[case] source: &str) -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.sources = vec![Source::from_str(source)?];
+    source_info.base.sha256_checksums = vec![SkippableChecksum::<Sha256>::from_str("SKIP")?];
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = SkippedChecksum::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[rstest]
+#[case("https://example.com/source.tar.gz")]
+#[case("source.tar.gz::https://example.com/download.sig")]
+#[case("source.patch")]
+fn skipped_checksum_fails(#[case] source: &str) -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.sources = vec![Source::from_str(source)?];
+    source_info.base.sha256_checksums = vec![SkippableChecksum::<Sha256>::from_str("SKIP")?];
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = SkippedChecksum::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint error should've been found.");
+    assert_eq!(issues[0].lint_rule, "source_info::skipped_checksum");
+    Ok(())
+}
+
+#[test]
+fn architecture_specific_skipped_checksum_fails() -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.architecture_properties.insert(
+        SystemArchitecture::X86_64,
+        PackageBaseArchitecture {
+            sources: vec![Source::from_str("https://example.com/source.tar.gz")?],
+            sha256_checksums: vec![SkippableChecksum::<Sha256>::from_str("SKIP")?],
+            ..PackageBaseArchitecture::default()
+        },
+    );
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = SkippedChecksum::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint error should've been found.");
+    assert_eq!(issues[0].lint_rule, "source_info::skipped_checksum");
+    Ok(())
+}
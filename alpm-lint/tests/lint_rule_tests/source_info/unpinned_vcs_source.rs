@@ -0,0 +1,73 @@
+use std::str::FromStr;
+
+use alpm_lint::{
+    Resources,
+    config::LintRuleConfiguration,
+    lint_rules::source_info::unpinned_vcs_source::UnpinnedVcsSource,
+};
+use alpm_srcinfo::SourceInfo;
+use alpm_types::{Name, Source};
+use rstest::rstest;
+
+use crate::fixtures::default_source_info_v1;
+
+#[rstest]
+#[case("https://example.com/source.tar.gz")]
+#[case("git+https://example.com/source.git#commit=154021a")]
+#[case("git+https://example.com/source.git?signed#tag=v1.0.0")]
+#[case("hg+https://example.com/source#revision=154021a")]
+#[case("svn+https://example.com/source#revision=42")]
+fn unpinned_vcs_source_passes(#[case] source: &str) -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.sources = vec![Source::from_str(source)?];
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = UnpinnedVcsSource::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
+
+#[rstest]
+#[case("git+https://example.com/source.git")]
+#[case("git+https://example.com/source.git#branch=main")]
+#[case("git://example.com/source.git")]
+#[case("hg+https://example.com/source#branch=default")]
+#[case("svn+https://example.com/source")]
+fn unpinned_vcs_source_fails(#[case] source: &str) -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.sources = vec![Source::from_str(source)?];
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = UnpinnedVcsSource::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert_eq!(issues.len(), 1, "A lint error should've been found.");
+    assert_eq!(issues[0].lint_rule, "source_info::unpinned_vcs_source");
+    Ok(())
+}
+
+/// Packages that track the latest state of a VCS repository are not considered.
+#[test]
+fn unpinned_vcs_source_in_vcs_package_passes() -> testresult::TestResult {
+    let mut source_info = default_source_info_v1()?;
+    source_info.base.name = Name::from_str("test-package-git")?;
+    source_info.base.sources = vec![Source::from_str("git+https://example.com/source.git")?];
+
+    let resources = Resources::SourceInfo(SourceInfo::V1(source_info));
+    let config = LintRuleConfiguration::default();
+    let lint_rule = UnpinnedVcsSource::new_boxed(&config);
+    let mut issues = Vec::new();
+
+    lint_rule.run(&resources, &mut issues)?;
+
+    assert!(issues.is_empty(), "No lint issues should have been found");
+    Ok(())
+}
@@ -153,6 +153,13 @@ fn single_file_in_dir(
 #[rstest]
 #[case::source_repository_scope(LintScope::SourceRepository, Box::new(create_source_repo_files))]
 #[case::package_scope(LintScope::Package, Box::new(create_package_files))]
+#[case::package_scope_with_srcinfo(
+    LintScope::Package,
+    Box::new(|path: &Path| {
+        create_package_files(path)?;
+        create_srcinfo_file(path)
+    })
+)]
 fn multi_file(#[case] scope: LintScope, #[case] setup_files: FileFactory) -> TestResult<()> {
     let temp_dir = tempdir()?;
     let path = temp_dir.path();
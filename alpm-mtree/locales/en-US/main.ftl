@@ -7,6 +7,9 @@ error-duplicate-paths = The following file system paths are duplicates:
 error-file-creation = File creation error:
   { $source }
 
+error-invalid-backup-entries = The following backup entries do not relate to files in the ALPM-MTREE data:
+  { $paths }
+
 error-io = I/O error while { $context }:
   { $source }
 
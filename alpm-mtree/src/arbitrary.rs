@@ -0,0 +1,116 @@
+//! Generation of arbitrary [ALPM-MTREE] data for fuzzing and property tests.
+//!
+//! [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+
+use std::path::PathBuf;
+
+use alpm_types::{Name, Sha256Checksum};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::mtree::v2::{Directory, File, Link, Path};
+
+/// The file modes used for generated paths.
+const MODES: &[&str] = &["644", "755", "777"];
+
+/// Valid [ALPM-MTREE] data, that is created by construction from unstructured input.
+///
+/// Alongside the textual representation, the list of [`Path`]s that it represents is tracked.
+/// This allows comparing the result of parsing the data with the expected paths.
+///
+/// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+#[derive(Clone, Debug)]
+pub struct MtreeText {
+    text: String,
+    paths: Vec<Path>,
+}
+
+impl MtreeText {
+    /// Returns the [ALPM-MTREE] data as string slice.
+    ///
+    /// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the sorted list of [`Path`]s represented by the data.
+    pub fn paths(&self) -> &[Path] {
+        &self.paths
+    }
+}
+
+impl<'a> Arbitrary<'a> for MtreeText {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut lines = vec!["#mtree".to_string()];
+        let mut paths = Vec::new();
+
+        let count = u.int_in_range(0..=16)?;
+        for index in 0..count {
+            // Prefix each path with its index, so that all paths are unique.
+            let path = PathBuf::from(format!("./{index}-{}", u.arbitrary::<Name>()?));
+            let uid = u.int_in_range(0..=65535)?;
+            let gid = u.int_in_range(0..=65535)?;
+            let mode = u.choose(MODES)?.to_string();
+            let time = u.int_in_range(0..=i64::from(u32::MAX))?;
+            let common = format!("uid={uid} gid={gid} mode={mode} time={time}.0");
+
+            let (line, path) = match u.int_in_range(0..=2)? {
+                0 => (
+                    format!("{} type=dir {common}", path.display()),
+                    Path::Directory(Directory {
+                        path,
+                        uid,
+                        gid,
+                        mode,
+                        time,
+                    }),
+                ),
+                1 => {
+                    let size = u.arbitrary()?;
+                    let sha256_digest: Sha256Checksum = u.arbitrary()?;
+                    (
+                        format!(
+                            "{} type=file {common} size={size} sha256digest={sha256_digest}",
+                            path.display()
+                        ),
+                        Path::File(File {
+                            path,
+                            uid,
+                            gid,
+                            mode,
+                            size,
+                            time,
+                            md5_digest: None,
+                            sha256_digest,
+                        }),
+                    )
+                }
+                _ => {
+                    let link_path = PathBuf::from(u.arbitrary::<Name>()?.to_string());
+                    (
+                        format!(
+                            "{} type=link {common} link={}",
+                            path.display(),
+                            link_path.display()
+                        ),
+                        Path::Link(Link {
+                            path,
+                            uid,
+                            gid,
+                            mode,
+                            time,
+                            link_path,
+                        }),
+                    )
+                }
+            };
+            lines.push(line);
+            paths.push(path);
+        }
+        paths.sort_unstable();
+
+        Ok(Self {
+            text: lines.join("\n") + "\n",
+            paths,
+        })
+    }
+}
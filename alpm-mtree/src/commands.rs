@@ -5,11 +5,13 @@ use std::{
     path::PathBuf,
 };
 
-use alpm_common::MetadataFile;
-use alpm_mtree::{Mtree, MtreeSchema, cli::OutputFormat};
+use alpm_common::{ErrorCategory, MetadataFile};
 use fluent_i18n::t;
+use schemars::generate::SchemaSettings;
 use thiserror::Error;
 
+use crate::{Mtree, MtreeSchema, cli::OutputFormat};
+
 /// A high-level error wrapper around [`alpm_soname::Error`] to add CLI error cases.
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -24,7 +26,18 @@ pub enum Error {
 
     /// An [alpm_pkginfo::Error]
     #[error(transparent)]
-    Mtree(#[from] alpm_mtree::Error),
+    Mtree(#[from] crate::Error),
+}
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::NoInputFile => Self::Io,
+            Error::Json(_) => Self::Parse,
+            Error::Mtree(error) => error.into(),
+        }
+    }
 }
 
 /// A small wrapper around the parsing of an MTREE file that simply ensures that there were no
@@ -65,6 +78,29 @@ pub fn format(
     Ok(())
 }
 
+/// Prints the JSON Schema of the JSON output of [`format`] on stdout.
+///
+/// The JSON Schema is generated from [`Mtree`] and describes all of its versions.
+/// If `pretty` is `true`, the JSON Schema is pretty-printed.
+///
+/// # Errors
+///
+/// Returns an error if the JSON Schema cannot be serialized.
+pub fn json_schema(pretty: bool) -> Result<(), Error> {
+    let schema = SchemaSettings::draft2020_12()
+        .for_serialize()
+        .into_generator()
+        .into_root_schema_for::<Mtree>();
+    let json = if pretty {
+        serde_json::to_string_pretty(&schema)?
+    } else {
+        serde_json::to_string(&schema)?
+    };
+    println!("{json}");
+
+    Ok(())
+}
+
 /// Parse and interpret an MTREE file.
 ///
 /// 1. Reads the contents of a file or stdin.
@@ -1,5 +1,6 @@
 use std::{collections::HashSet, path::PathBuf, string::FromUtf8Error};
 
+use alpm_common::ErrorCategory;
 use fluent_i18n::t;
 
 #[cfg(doc)]
@@ -26,6 +27,18 @@ pub enum Error {
         paths: HashSet<PathBuf>,
     },
 
+    /// One or more backup entries do not relate to files in the [`Mtree`] data.
+    #[error("{msg}", msg = t!("error-invalid-backup-entries", {
+        "paths" => paths.iter()
+            .map(|p| format!("{p:?}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }))]
+    InvalidBackupEntries {
+        /// The backup entries that do not relate to files.
+        paths: Vec<PathBuf>,
+    },
+
     /// File creation error.
     #[cfg(feature = "creation")]
     #[error("{msg}", msg = t!("error-file-creation", { "source" => .0.to_string() }))]
@@ -86,3 +99,23 @@ pub enum Error {
     #[error("Unsupported schema version: {0}")]
     UnsupportedSchemaVersion(String),
 }
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::AlpmCommon(error) => error.into(),
+            #[cfg(feature = "creation")]
+            Error::File(error) => error.into(),
+            Error::Io { .. } | Error::IoPath { .. } => Self::Io,
+            Error::InvalidUTF8(_)
+            | Error::InvalidGzip(_)
+            | Error::Parse(_)
+            | Error::InterpreterError(..) => Self::Parse,
+            Error::DuplicatePaths { .. }
+            | Error::InvalidBackupEntries { .. }
+            | Error::PathValidation(_) => Self::Validation,
+            Error::UnsupportedSchemaVersion(_) => Self::Schema,
+        }
+    }
+}
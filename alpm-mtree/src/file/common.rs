@@ -153,7 +153,7 @@ fn run_bsdtar(
 /// - or gzip compressing the [ALPM-MTREE] file fails.
 ///
 /// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
-fn create_mtree_file_in_dir(
+pub(crate) fn create_mtree_file_in_dir(
     path: impl AsRef<Path>,
     mtree_data: &[u8],
     schema: MtreeSchema,
@@ -2,7 +2,7 @@
 //!
 //! [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
 
-use std::process::ExitStatus;
+use std::{path::PathBuf, process::ExitStatus};
 
 /// The Error that can occur when creating [ALPM-MTREE] files.
 ///
@@ -20,6 +20,15 @@ pub enum Error {
         command: String,
     },
 
+    /// An entry could not be appended to a tar archive.
+    #[error("The entry {path:?} could not be appended to the tar archive:\n{source}")]
+    AppendTarEntry {
+        /// The path of the entry.
+        path: PathBuf,
+        /// The source error.
+        source: std::io::Error,
+    },
+
     /// A command could not be started in the background.
     #[error("The command {command:?} could not be started in the background:\n{source}")]
     CommandBackground {
@@ -68,4 +77,48 @@ pub enum Error {
         /// The source error.
         source: std::io::Error,
     },
+
+    /// A symlink tar entry has no link name.
+    #[error("The symlink tar entry {path:?} has no link name")]
+    MissingLinkName {
+        /// The path of the symlink.
+        path: PathBuf,
+    },
+
+    /// The header of a tar entry could not be read.
+    #[error("The header of the tar entry {path:?} could not be read:\n{source}")]
+    TarHeader {
+        /// The path of the entry.
+        path: PathBuf,
+        /// The source error.
+        source: std::io::Error,
+    },
+
+    /// A tar entry is of an unsupported type.
+    #[error("The tar entry {path:?} is of the unsupported type {entry_type}")]
+    UnsupportedEntryType {
+        /// The path of the entry.
+        path: PathBuf,
+        /// The type of the entry.
+        entry_type: String,
+    },
+}
+
+impl From<&Error> for alpm_common::ErrorCategory {
+    /// Returns the [`ErrorCategory`](alpm_common::ErrorCategory) of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::AlpmCommon(error) => error.into(),
+            Error::CommandAttachToStdin { .. }
+            | Error::CommandBackground { .. }
+            | Error::CommandExec { .. }
+            | Error::CommandNonZero { .. }
+            | Error::CommandNotFound { .. }
+            | Error::CommandWriteToStdin { .. }
+            | Error::AppendTarEntry { .. }
+            | Error::TarHeader { .. } => Self::Io,
+            Error::MissingLinkName { .. } => Self::Validation,
+            Error::UnsupportedEntryType { .. } => Self::Unsupported,
+        }
+    }
 }
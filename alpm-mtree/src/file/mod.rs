@@ -3,3 +3,4 @@
 pub mod common;
 pub mod create;
 pub mod error;
+pub mod stream;
@@ -0,0 +1,470 @@
+//! Creation of [ALPM-MTREEv2] data from tar entries while they are appended to an archive.
+//!
+//! Creating [ALPM-MTREE] data using [bsdtar] requires reading all files of an input directory
+//! once, before they are read again when appending them to a package archive.
+//! The [`MtreeRecorder`] instead derives [ALPM-MTREEv2] path entries from the tar entries that are
+//! appended to a [`tar::Builder`] and calculates hash digests from the streamed file contents.
+//!
+//! As [ALPM-MTREE] data is itself part of an [alpm-package] and precedes its data files, the
+//! [`MtreeRecorder`] is meant for writers that append the [ALPM-MTREE] file after all other
+//! entries (or write it to a separate location).
+//!
+//! [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+//! [ALPM-MTREEv2]: https://alpm.archlinux.page/specifications/ALPM-MTREEv2.5.html
+//! [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+//! [bsdtar]: https://man.archlinux.org/man/bsdtar.1
+
+use std::{
+    io::{Read, Write, empty},
+    path::{Path as FsPath, PathBuf},
+};
+
+use alpm_types::{
+    ChecksumHasher,
+    SchemaVersion,
+    Sha256Checksum,
+    digests::Sha256,
+    semver_version::Version,
+};
+use tar::{Builder, EntryType, Header};
+
+use crate::{
+    CreationError,
+    Error,
+    Mtree,
+    MtreeSchema,
+    file::common::create_mtree_file_in_dir,
+    mtree::v2::{Directory, File, Link, MTREE_PATH_PREFIX, Path},
+};
+
+/// A reader that calculates the size and SHA-256 hash digest of all data read through it.
+#[derive(Debug)]
+pub struct HashingReader<R: Read> {
+    inner: R,
+    hasher: ChecksumHasher<Sha256>,
+    size: u64,
+}
+
+impl<R: Read> HashingReader<R> {
+    /// Creates a new [`HashingReader`] for `inner`.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            hasher: ChecksumHasher::new(),
+            size: 0,
+        }
+    }
+
+    /// Consumes the [`HashingReader`] and returns the size and SHA-256 hash digest of all data
+    /// read so far.
+    pub fn finish(self) -> (u64, Sha256Checksum) {
+        (self.size, self.hasher.finalize())
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        self.size += read as u64;
+        Ok(read)
+    }
+}
+
+/// Records [ALPM-MTREEv2] path entries for tar entries, while appending them to a
+/// [`tar::Builder`].
+///
+/// Directories, regular files and symlinks are supported.
+/// The ownership, mode and modification time of each path entry are derived from the tar
+/// [`Header`], while the size and SHA-256 hash digest of regular files are calculated from the
+/// streamed file contents.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+///
+/// use alpm_mtree::{Mtree, file::stream::MtreeRecorder};
+/// use tar::{Builder, EntryType, Header};
+///
+/// # fn main() -> testresult::TestResult {
+/// let mut builder = Builder::new(Vec::new());
+/// let mut recorder = MtreeRecorder::new();
+///
+/// let mut header = Header::new_gnu();
+/// header.set_entry_type(EntryType::Regular);
+/// header.set_mode(0o644);
+/// header.set_mtime(1);
+/// header.set_size(4);
+/// recorder.append(&mut builder, &mut header, "foo.txt", Cursor::new(b"foo\n"))?;
+///
+/// let mtree = recorder.into_mtree();
+/// assert!(matches!(mtree, Mtree::V2(paths) if paths.len() == 1));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [ALPM-MTREEv2]: https://alpm.archlinux.page/specifications/ALPM-MTREEv2.5.html
+#[derive(Clone, Debug, Default)]
+pub struct MtreeRecorder {
+    paths: Vec<Path>,
+}
+
+impl MtreeRecorder {
+    /// Creates a new, empty [`MtreeRecorder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a tar entry for `path` to `builder` and records an [ALPM-MTREEv2] path entry for
+    /// it.
+    ///
+    /// The type of the entry is derived from `header`.
+    /// For regular files, `content` is streamed to `builder` while its size and SHA-256 hash
+    /// digest are calculated.
+    /// For symlinks, the link name must be set in `header`.
+    /// For directories and symlinks, `content` is ignored.
+    /// Numeric fields that are not set in `header` (i.e. the owner, mode or modification time) are
+    /// recorded as `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the metadata in `header` can not be read,
+    /// - the entry type of `header` is not supported,
+    /// - no link name is set in `header` for a symlink,
+    /// - or appending the entry to `builder` fails.
+    ///
+    /// [ALPM-MTREEv2]: https://alpm.archlinux.page/specifications/ALPM-MTREEv2.5.html
+    pub fn append<W: Write, R: Read>(
+        &mut self,
+        builder: &mut Builder<W>,
+        header: &mut Header,
+        path: impl AsRef<FsPath>,
+        content: R,
+    ) -> Result<(), Error> {
+        let path = path.as_ref();
+        let header_error = |source| CreationError::TarHeader {
+            path: path.to_path_buf(),
+            source,
+        };
+        let append_error = |source| CreationError::AppendTarEntry {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        let entry_type = header.entry_type();
+        if !matches!(
+            entry_type,
+            EntryType::Directory | EntryType::Symlink | EntryType::Regular
+        ) {
+            return Err(CreationError::UnsupportedEntryType {
+                path: path.to_path_buf(),
+                entry_type: format!("{entry_type:?}"),
+            }
+            .into());
+        }
+
+        let mtree_path = PathBuf::from(MTREE_PATH_PREFIX).join(path);
+        let raw = header.as_old();
+        let uid = numeric_field(&raw.uid, || header.uid()).map_err(header_error)?;
+        let gid = numeric_field(&raw.gid, || header.gid()).map_err(header_error)?;
+        let mode =
+            numeric_field(&raw.mode, || header.mode().map(u64::from)).map_err(header_error)?;
+        let mtime = numeric_field(&raw.mtime, || header.mtime()).map_err(header_error)?;
+        let uid = u32::try_from(uid).unwrap_or(u32::MAX);
+        let gid = u32::try_from(gid).unwrap_or(u32::MAX);
+        let mode = format!("{:o}", mode & 0o7777);
+        let time = i64::try_from(mtime).unwrap_or(i64::MAX);
+
+        let entry = match entry_type {
+            EntryType::Directory => {
+                builder
+                    .append_data(header, path, empty())
+                    .map_err(append_error)?;
+                Path::Directory(Directory {
+                    path: mtree_path,
+                    uid,
+                    gid,
+                    mode,
+                    time,
+                })
+            }
+            EntryType::Symlink => {
+                let link_path = header
+                    .link_name()
+                    .map_err(header_error)?
+                    .ok_or_else(|| CreationError::MissingLinkName {
+                        path: path.to_path_buf(),
+                    })?
+                    .into_owned();
+                builder
+                    .append_link(header, path, &link_path)
+                    .map_err(append_error)?;
+                Path::Link(Link {
+                    path: mtree_path,
+                    uid,
+                    gid,
+                    mode,
+                    time,
+                    link_path,
+                })
+            }
+            _ => {
+                let mut reader = HashingReader::new(content);
+                builder
+                    .append_data(header, path, &mut reader)
+                    .map_err(append_error)?;
+                let (size, sha256_digest) = reader.finish();
+                Path::File(File {
+                    path: mtree_path,
+                    uid,
+                    gid,
+                    mode,
+                    size,
+                    time,
+                    md5_digest: None,
+                    sha256_digest,
+                })
+            }
+        };
+        self.paths.push(entry);
+
+        Ok(())
+    }
+
+    /// Returns all recorded [ALPM-MTREEv2] path entries in the order in which they were recorded.
+    ///
+    /// [ALPM-MTREEv2]: https://alpm.archlinux.page/specifications/ALPM-MTREEv2.5.html
+    pub fn paths(&self) -> &[Path] {
+        &self.paths
+    }
+
+    /// Returns the [ALPM-MTREEv2] data of all recorded path entries.
+    ///
+    /// The path entries are sorted by path.
+    ///
+    /// [ALPM-MTREEv2]: https://alpm.archlinux.page/specifications/ALPM-MTREEv2.5.html
+    pub fn to_mtree_data(&self) -> String {
+        let mut paths = self.paths.clone();
+        paths.sort_unstable();
+
+        let mut output = String::from("#mtree\n");
+        for path in paths {
+            let line = match path {
+                Path::Directory(directory) => format!(
+                    "{} time={}.0 mode={} type=dir uid={} gid={}",
+                    encode_path(&directory.path),
+                    directory.time,
+                    directory.mode,
+                    directory.uid,
+                    directory.gid,
+                ),
+                Path::File(file) => format!(
+                    "{} time={}.0 mode={} size={} type=file uid={} gid={} sha256digest={}",
+                    encode_path(&file.path),
+                    file.time,
+                    file.mode,
+                    file.size,
+                    file.uid,
+                    file.gid,
+                    file.sha256_digest,
+                ),
+                Path::Link(link) => format!(
+                    "{} time={}.0 mode={} type=link uid={} gid={} link={}",
+                    encode_path(&link.path),
+                    link.time,
+                    link.mode,
+                    link.uid,
+                    link.gid,
+                    encode_path(&link.link_path),
+                ),
+            };
+            output.push_str(&line);
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// Consumes the [`MtreeRecorder`] and returns the recorded path entries as [`Mtree`].
+    ///
+    /// The path entries are sorted by path.
+    pub fn into_mtree(self) -> Mtree {
+        let mut paths = self.paths;
+        paths.sort_unstable();
+        Mtree::V2(paths)
+    }
+
+    /// Writes the [ALPM-MTREEv2] data of all recorded path entries to an [ALPM-MTREE] file in the
+    /// directory `path` and returns the path of the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the [ALPM-MTREEv2] data is not valid,
+    /// - creating the [ALPM-MTREE] file in `path` fails,
+    /// - or gzip compressing the [ALPM-MTREE] file fails.
+    ///
+    /// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+    /// [ALPM-MTREEv2]: https://alpm.archlinux.page/specifications/ALPM-MTREEv2.5.html
+    pub fn write_to_dir(&self, path: impl AsRef<FsPath>) -> Result<PathBuf, Error> {
+        create_mtree_file_in_dir(
+            path,
+            self.to_mtree_data().as_bytes(),
+            MtreeSchema::V2(SchemaVersion::new(Version::new(2, 0, 0))),
+        )
+    }
+}
+
+/// Reads a numeric field of a tar [`Header`] using `read`.
+///
+/// Fields that are not set (i.e. only consist of NUL bytes, as in a [`Header::new_gnu`]) can not
+/// be parsed by [`tar`] and are read as `0`.
+///
+/// # Errors
+///
+/// Returns an error if `read` fails for a field that is set.
+fn numeric_field(raw: &[u8], read: impl FnOnce() -> std::io::Result<u64>) -> std::io::Result<u64> {
+    if raw.iter().all(|byte| *byte == 0) {
+        return Ok(0);
+    }
+    read()
+}
+
+/// Encodes `path` for use in [ALPM-MTREE] data.
+///
+/// Whitespace characters and `#` are encoded using their VIS_CSTYLE representation.
+/// Backslashes, as well as all non-printable and non-ASCII bytes are encoded as octal triplets.
+///
+/// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+fn encode_path(path: &FsPath) -> String {
+    let mut output = String::new();
+    for &byte in path.as_os_str().as_encoded_bytes() {
+        match byte {
+            b' ' => output.push_str("\\s"),
+            b'\t' => output.push_str("\\t"),
+            b'\r' => output.push_str("\\r"),
+            b'\n' => output.push_str("\\n"),
+            b'#' => output.push_str("\\#"),
+            b'\\' | 0..=0x20 | 0x7f..=0xff => output.push_str(&format!("\\{byte:03o}")),
+            _ => output.push(char::from(byte)),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use alpm_common::MetadataFile;
+    use rstest::rstest;
+    use tar::Archive;
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that paths are encoded in a way that can be decoded again.
+    #[rstest]
+    #[case("./usr/bin/foo", "./usr/bin/foo")]
+    #[case("./usr/share/foo bar", "./usr/share/foo\\sbar")]
+    #[case("./etc/#foo", "./etc/\\#foo")]
+    #[case("./etc/back\\slash", "./etc/back\\134slash")]
+    #[case("./usr/share/🌠", "./usr/share/\\360\\237\\214\\240")]
+    fn encode_mtree_path(#[case] path: &str, #[case] expected: &str) {
+        assert_eq!(encode_path(FsPath::new(path)), expected);
+    }
+
+    /// Ensures that tar entries are appended and recorded as [ALPM-MTREEv2] path entries.
+    ///
+    /// [ALPM-MTREEv2]: https://alpm.archlinux.page/specifications/ALPM-MTREEv2.5.html
+    #[test]
+    fn record_tar_entries() -> TestResult {
+        let mut builder = Builder::new(Vec::new());
+        let mut recorder = MtreeRecorder::new();
+
+        let mut header = Header::new_gnu();
+        header.set_entry_type(EntryType::Directory);
+        header.set_mode(0o755);
+        header.set_mtime(1);
+        header.set_size(0);
+        recorder.append(&mut builder, &mut header, "usr", empty())?;
+
+        let mut header = Header::new_gnu();
+        header.set_entry_type(EntryType::Regular);
+        header.set_mode(0o644);
+        header.set_mtime(2);
+        header.set_size(4);
+        recorder.append(
+            &mut builder,
+            &mut header,
+            "usr/foo bar",
+            Cursor::new(b"foo\n"),
+        )?;
+
+        let mut header = Header::new_gnu();
+        header.set_entry_type(EntryType::Symlink);
+        header.set_mode(0o777);
+        header.set_mtime(3);
+        header.set_size(0);
+        header.set_link_name("foo bar")?;
+        recorder.append(&mut builder, &mut header, "usr/link", empty())?;
+
+        // All entries are present in the archive.
+        let data = builder.into_inner()?;
+        let mut archive = Archive::new(data.as_slice());
+        let archived_paths = archive
+            .entries()?
+            .map(|entry| Ok(entry?.path()?.to_path_buf()))
+            .collect::<Result<Vec<_>, std::io::Error>>()?;
+        assert_eq!(
+            archived_paths,
+            vec![
+                PathBuf::from("usr"),
+                PathBuf::from("usr/foo bar"),
+                PathBuf::from("usr/link")
+            ]
+        );
+
+        // The textual representation can be parsed and equals the recorded data.
+        let parsed = Mtree::from_str_with_schema(
+            &recorder.to_mtree_data(),
+            Some(MtreeSchema::V2(SchemaVersion::new(Version::new(2, 0, 0)))),
+        )?;
+        let recorded = recorder.into_mtree();
+        assert_eq!(parsed, recorded);
+
+        let Mtree::V2(paths) = recorded else {
+            panic!("Expected ALPM-MTREEv2 data");
+        };
+        assert!(matches!(
+            &paths[1],
+            Path::File(File { size: 4, sha256_digest, .. })
+                if *sha256_digest == Sha256Checksum::calculate_from("foo\n")
+        ));
+
+        Ok(())
+    }
+
+    /// Ensures that unsupported entry types are rejected.
+    #[test]
+    fn record_unsupported_entry_type() -> TestResult {
+        let mut builder = Builder::new(Vec::new());
+        let mut recorder = MtreeRecorder::new();
+
+        let mut header = Header::new_gnu();
+        header.set_entry_type(EntryType::Fifo);
+        header.set_size(0);
+
+        assert!(matches!(
+            recorder.append(&mut builder, &mut header, "fifo", empty()),
+            Err(Error::File(CreationError::UnsupportedEntryType { .. }))
+        ));
+
+        Ok(())
+    }
+}
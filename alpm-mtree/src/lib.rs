@@ -1,9 +1,17 @@
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+
 /// Commandline argument handling. This is most likely not interesting for you.
 #[cfg(feature = "cli")]
 #[doc(hidden)]
 pub mod cli;
+
+#[cfg(feature = "cli")]
+#[doc(hidden)]
+pub mod commands;
+
 mod error;
 pub use error::Error;
 
@@ -2,13 +2,13 @@
 
 use std::process::ExitCode;
 
-use alpm_mtree::cli::{Cli, Command};
+use alpm_common::ErrorCategory;
+use alpm_mtree::{
+    cli::{Cli, Command},
+    commands::{format, json_schema, validate},
+};
 use clap::Parser;
 
-mod commands;
-
-use commands::{format, validate};
-
 fluent_i18n::i18n!("locales");
 
 /// The entry point for the `alpm-mtree` binary.
@@ -24,11 +24,12 @@ fn main() -> ExitCode {
             output_format,
             pretty,
         } => format(file.as_ref(), schema, output_format, pretty),
+        Command::JsonSchema { pretty } => json_schema(pretty),
     };
 
     if let Err(error) = result {
         eprintln!("{error}");
-        ExitCode::FAILURE
+        ErrorCategory::from(&error).into()
     } else {
         ExitCode::SUCCESS
     }
@@ -0,0 +1,182 @@
+//! Partitioning of [ALPM-MTREE] data into backup-managed and regular paths.
+//!
+//! [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+
+use std::{collections::HashSet, path::PathBuf};
+
+use alpm_types::Backup;
+
+use crate::{
+    Error,
+    Mtree,
+    mtree::v2::{File, MTREE_PATH_PREFIX, Path},
+};
+
+/// The paths of [ALPM-MTREE] data, partitioned by whether they are subject to backup handling.
+///
+/// Installers use this distinction to decide whether a file that has been modified on the system
+/// is preserved (e.g. by creating `.pacnew` or `.pacsave` files) or overwritten.
+///
+/// Created using [`Mtree::partition_backup`].
+///
+/// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct BackupPartition<'a> {
+    /// The files that are tracked by a [`Backup`] entry.
+    pub backup: Vec<&'a File>,
+    /// All other paths (files, directories and symlinks).
+    pub regular: Vec<&'a Path>,
+}
+
+impl Mtree {
+    /// Partitions the paths of the [`Mtree`] into backup-managed files and regular paths.
+    ///
+    /// Each entry in `backups` is compared with the paths of the [`Mtree`] after stripping the
+    /// [`MTREE_PATH_PREFIX`] from them.
+    /// The order of the paths in the [`Mtree`] is retained in both partitions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_mtree::Mtree;
+    /// use alpm_types::Backup;
+    ///
+    /// # fn main() -> testresult::TestResult {
+    /// let mtree = Mtree::from_str(
+    ///     r#"#mtree
+    /// /set mode=644 uid=0 gid=0 type=file
+    /// ./etc type=dir time=1700000000.0 mode=755
+    /// ./etc/example.conf time=1700000000.0 size=0 sha256digest=e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855
+    /// ./usr type=dir time=1700000000.0 mode=755
+    /// "#,
+    /// )?;
+    ///
+    /// let partition = mtree.partition_backup(&[Backup::from_str("etc/example.conf")?])?;
+    /// assert_eq!(partition.backup.len(), 1);
+    /// assert_eq!(partition.regular.len(), 2);
+    ///
+    /// // Backup entries must be files in the ALPM-MTREE data.
+    /// assert!(
+    ///     mtree
+    ///         .partition_backup(&[Backup::from_str("etc/other.conf")?])
+    ///         .is_err()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one or more entries in `backups` do not relate to a file in the
+    /// [`Mtree`] (i.e. they are missing or relate to a directory or symlink).
+    pub fn partition_backup(&self, backups: &[Backup]) -> Result<BackupPartition<'_>, Error> {
+        let paths = match self {
+            Self::V1(paths) | Self::V2(paths) => paths,
+        };
+        let backups: HashSet<&std::path::Path> = backups.iter().map(Backup::inner).collect();
+
+        let mut partition = BackupPartition {
+            backup: Vec::new(),
+            regular: Vec::new(),
+        };
+        let mut found: HashSet<&std::path::Path> = HashSet::new();
+        for path in paths {
+            match path {
+                Path::File(file) if backups.contains(normalize(&file.path)) => {
+                    found.insert(normalize(&file.path));
+                    partition.backup.push(file);
+                }
+                _ => partition.regular.push(path),
+            }
+        }
+
+        let mut invalid: Vec<PathBuf> = backups
+            .difference(&found)
+            .map(|path| path.to_path_buf())
+            .collect();
+        if !invalid.is_empty() {
+            invalid.sort();
+            return Err(Error::InvalidBackupEntries { paths: invalid });
+        }
+
+        Ok(partition)
+    }
+}
+
+/// Strips the [`MTREE_PATH_PREFIX`] from `path`, if it is present.
+fn normalize(path: &std::path::Path) -> &std::path::Path {
+    path.strip_prefix(MTREE_PATH_PREFIX).unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// ALPM-MTREE data with a directory, a symlink and two files.
+    const MTREE_DATA: &str = r#"#mtree
+/set mode=644 uid=0 gid=0 type=file
+./etc type=dir time=1700000000.0 mode=755
+./etc/bar.conf time=1700000000.0 size=0 sha256digest=e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855
+./etc/baz.conf type=link link=bar.conf time=1700000000.0
+./etc/foo.conf time=1700000000.0 size=0 sha256digest=e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855
+"#;
+
+    /// Ensures that backup entries are split from regular paths.
+    #[test]
+    fn partition_backup() -> TestResult {
+        let mtree = Mtree::from_str(MTREE_DATA)?;
+
+        let partition = mtree.partition_backup(&[Backup::from_str("etc/foo.conf")?])?;
+
+        let backup: Vec<PathBuf> = partition
+            .backup
+            .iter()
+            .map(|file| file.path.clone())
+            .collect();
+        let regular: Vec<PathBuf> = partition
+            .regular
+            .iter()
+            .map(|path| path.to_path_buf())
+            .collect();
+        assert_eq!(backup, [PathBuf::from("./etc/foo.conf")]);
+        assert_eq!(
+            regular,
+            [
+                PathBuf::from("./etc"),
+                PathBuf::from("./etc/bar.conf"),
+                PathBuf::from("./etc/baz.conf"),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Ensures that backup entries, which are missing or are no files, are reported.
+    #[test]
+    fn partition_backup_invalid_entries() -> TestResult {
+        let mtree = Mtree::from_str(MTREE_DATA)?;
+
+        let result = mtree.partition_backup(&[
+            Backup::from_str("etc/foo.conf")?,
+            Backup::from_str("etc/baz.conf")?,
+            Backup::from_str("etc/missing.conf")?,
+        ]);
+
+        match result {
+            Err(Error::InvalidBackupEntries { paths }) => assert_eq!(
+                paths,
+                [
+                    PathBuf::from("etc/baz.conf"),
+                    PathBuf::from("etc/missing.conf"),
+                ]
+            ),
+            result => panic!("Expected invalid backup entries, got {result:?}"),
+        }
+        Ok(())
+    }
+}
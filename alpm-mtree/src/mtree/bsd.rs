@@ -0,0 +1,484 @@
+//! Interoperability with the BSD [mtree] file format.
+//!
+//! [ALPM-MTREE] is based on the BSD [mtree] format, but only supports a subset of its keywords and
+//! uses a different escaping of special characters in paths.
+//! The functionality in this module allows to export [`Mtree`] data, so that it can be used with
+//! stock tools such as [bsdtar] and [mtree], and to leniently import data created by them.
+//!
+//! [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+//! [bsdtar]: https://man.archlinux.org/man/bsdtar.1
+//! [mtree]: https://man.archlinux.org/man/mtree.5
+
+use std::path::Path as FsPath;
+
+use crate::{Error, Mtree, mtree::v2::Path, parse_mtree_v2};
+
+/// The keywords of a path entry that are supported by [ALPM-MTREE].
+///
+/// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+const PATH_KEYWORDS: &[&str] = &[
+    "type",
+    "uid",
+    "gid",
+    "mode",
+    "size",
+    "link",
+    "md5digest",
+    "sha256digest",
+    "time",
+];
+
+/// The keywords of `/set` and `/unset` statements that are supported by [ALPM-MTREE].
+///
+/// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+const SET_KEYWORDS: &[&str] = &["uid", "gid", "type", "mode"];
+
+impl Mtree {
+    /// Returns the [`Mtree`] data in the BSD [mtree] format.
+    ///
+    /// Each path is written as a full path entry (e.g. `./usr/bin/foo`) with an explicit `type`,
+    /// `uid`, `gid`, `mode` and `time`, followed by `size`, hash digests or `link` (depending on
+    /// the type of path).
+    /// Special characters in paths and symlink targets are escaped using octal triplets (e.g.
+    /// `\040` for a space), which is understood by all BSD [mtree] implementations.
+    ///
+    /// The output can e.g. be used to verify a directory using `mtree -p <dir> -f <file>` or
+    /// `bsdtar -cf - @<file>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_mtree::{Mtree, mtree::v2::parse_mtree_v2};
+    ///
+    /// # fn main() -> testresult::TestResult {
+    /// let mtree = Mtree::V2(parse_mtree_v2(
+    ///     "#mtree\n./foo\\sbar type=link uid=0 gid=0 mode=777 time=1700000000.0 link=foo\n"
+    ///         .to_string(),
+    /// )?);
+    ///
+    /// assert_eq!(
+    ///     mtree.to_bsd_mtree(),
+    ///     "#mtree\n./foo\\040bar type=link uid=0 gid=0 mode=777 time=1700000000.0 link=foo\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [mtree]: https://man.archlinux.org/man/mtree.5
+    pub fn to_bsd_mtree(&self) -> String {
+        let mut paths = match self {
+            Mtree::V1(paths) | Mtree::V2(paths) => paths.clone(),
+        };
+        paths.sort_unstable();
+
+        let mut output = String::from("#mtree\n");
+        for path in paths {
+            let line = match path {
+                Path::Directory(directory) => format!(
+                    "{} type=dir uid={} gid={} mode={} time={}.0",
+                    encode_bsd_path(&directory.path),
+                    directory.uid,
+                    directory.gid,
+                    directory.mode,
+                    directory.time,
+                ),
+                Path::File(file) => {
+                    let mut line = format!(
+                        "{} type=file uid={} gid={} mode={} size={} time={}.0",
+                        encode_bsd_path(&file.path),
+                        file.uid,
+                        file.gid,
+                        file.mode,
+                        file.size,
+                        file.time,
+                    );
+                    if let Some(md5_digest) = file.md5_digest {
+                        line.push_str(&format!(" md5digest={md5_digest}"));
+                    }
+                    line.push_str(&format!(" sha256digest={}", file.sha256_digest));
+                    line
+                }
+                Path::Link(link) => format!(
+                    "{} type=link uid={} gid={} mode={} time={}.0 link={}",
+                    encode_bsd_path(&link.path),
+                    link.uid,
+                    link.gid,
+                    link.mode,
+                    link.time,
+                    encode_bsd_path(&link.link_path),
+                ),
+            };
+            output.push_str(&line);
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// Creates [`Mtree`] data from data in the BSD [mtree] format.
+    ///
+    /// The import is lenient and converts the data to [ALPM-MTREEv2] before parsing it:
+    ///
+    /// - both the flat (full path) and the hierarchical (relative names and `..`) form of entries
+    ///   are supported,
+    /// - the keywords `md5` and `sha256` are treated as `md5digest` and `sha256digest`,
+    /// - keywords that are not supported by [ALPM-MTREE] (e.g. `uname`, `nlink` or `flags`) are
+    ///   ignored,
+    /// - `time` values without nanoseconds and `mode` values with leading zeros are accepted,
+    /// - escape sequences in paths (octal triplets and `VIS_CSTYLE`) are decoded,
+    /// - line continuations and indentation are supported,
+    /// - and the entry for the root directory (`.`) is skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - a line contains an invalid escape sequence or a path that is not valid UTF-8,
+    /// - a `..` entry moves above the root directory,
+    /// - or the converted data is not valid [ALPM-MTREEv2] (e.g. because a path uses an unsupported
+    ///   type or lacks a `uid`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_mtree::Mtree;
+    ///
+    /// # fn main() -> testresult::TestResult {
+    /// let data = r#"#mtree
+    /// /set type=file uid=0 gid=0 mode=0644 nlink=1 flags=none
+    /// .               type=dir mode=0755 time=1700000000
+    ///     usr         type=dir mode=0755 time=1700000000
+    ///         foo\040bar size=0 time=1700000000.000000000 \
+    ///                 sha256=e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855
+    ///     ..
+    /// ..
+    /// "#;
+    ///
+    /// let Mtree::V2(paths) = Mtree::from_bsd_mtree(data)? else {
+    ///     panic!("Expected ALPM-MTREEv2 data");
+    /// };
+    /// assert_eq!(paths.len(), 2);
+    /// assert_eq!(paths[1].as_path().to_string_lossy(), "./usr/foo bar");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+    /// [ALPM-MTREEv2]: https://alpm.archlinux.page/specifications/ALPM-MTREEv2.5.html
+    /// [mtree]: https://man.archlinux.org/man/mtree.5
+    pub fn from_bsd_mtree(data: &str) -> Result<Self, Error> {
+        Ok(Mtree::V2(parse_mtree_v2(bsd_to_alpm_mtree(data)?)?))
+    }
+}
+
+/// Escapes the bytes of `path` for use in BSD [mtree] data.
+///
+/// All whitespace, control and non-ASCII characters, as well as characters with special meaning
+/// (`\`, `#`, `=`, `*`, `?` and `[`) are encoded as octal triplets.
+///
+/// [mtree]: https://man.archlinux.org/man/mtree.5
+fn encode_bsd_path(path: impl AsRef<FsPath>) -> String {
+    let mut output = String::new();
+    for &byte in path.as_ref().as_os_str().as_encoded_bytes() {
+        match byte {
+            b'\\' | b'#' | b'=' | b'*' | b'?' | b'[' | 0..=0x20 | 0x7f..=0xff => {
+                output.push_str(&format!("\\{byte:03o}"))
+            }
+            _ => output.push(char::from(byte)),
+        }
+    }
+
+    output
+}
+
+/// Decodes the escape sequences in a path of BSD [mtree] data.
+///
+/// Supports octal triplets (e.g. `\040`) and the `VIS_CSTYLE` escape sequences of [strsvis].
+///
+/// # Errors
+///
+/// Returns the reason as error if `input` contains an unknown escape sequence or if the decoded
+/// data is not valid UTF-8.
+///
+/// [mtree]: https://man.archlinux.org/man/mtree.5
+/// [strsvis]: https://man.archlinux.org/man/strsvis.3bsd
+fn decode_bsd_path(input: &str) -> Result<String, String> {
+    let mut bytes = Vec::new();
+    let mut chars = input.chars();
+
+    while let Some(char) = chars.next() {
+        if char != '\\' {
+            let mut buffer = [0; 4];
+            bytes.extend_from_slice(char.encode_utf8(&mut buffer).as_bytes());
+            continue;
+        }
+
+        let byte = match chars.next() {
+            Some(digit @ '0'..='7') => {
+                let mut octal = String::from(digit);
+                for _ in 0..2 {
+                    match chars.next() {
+                        Some(digit @ '0'..='7') => octal.push(digit),
+                        _ => return Err(format!("Invalid octal escape sequence in {input:?}")),
+                    }
+                }
+                u8::from_str_radix(&octal, 8)
+                    .map_err(|_| format!("Invalid octal escape sequence in {input:?}"))?
+            }
+            Some('\\') => b'\\',
+            Some('#') => b'#',
+            Some('s') => b' ',
+            Some('t') => b'\t',
+            Some('n') => b'\n',
+            Some('r') => b'\r',
+            Some('a') => 0x07,
+            Some('b') => 0x08,
+            Some('f') => 0x0c,
+            Some('v') => 0x0b,
+            _ => return Err(format!("Unknown escape sequence in {input:?}")),
+        };
+        bytes.push(byte);
+    }
+
+    String::from_utf8(bytes).map_err(|_| format!("The path {input:?} is not valid UTF-8"))
+}
+
+/// Converts the value of a BSD [mtree] `keyword` to its [ALPM-MTREE] representation.
+///
+/// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+/// [mtree]: https://man.archlinux.org/man/mtree.5
+fn convert_value(keyword: &str, value: &str) -> Result<String, String> {
+    Ok(match keyword {
+        "time" if !value.contains('.') => format!("{value}.0"),
+        "mode" => format!("{:0>3}", value.trim_start_matches('0')),
+        "link" => encode_bsd_path(decode_bsd_path(value)?),
+        _ => value.to_string(),
+    })
+}
+
+/// Converts data in the BSD [mtree] format to [ALPM-MTREEv2] data.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - a line contains an invalid escape sequence or a path that is not valid UTF-8,
+/// - or a `..` entry moves above the root directory.
+///
+/// [ALPM-MTREEv2]: https://alpm.archlinux.page/specifications/ALPM-MTREEv2.5.html
+/// [mtree]: https://man.archlinux.org/man/mtree.5
+fn bsd_to_alpm_mtree(data: &str) -> Result<String, Error> {
+    let mut output = String::from("#mtree\n");
+    // The directory components of the current directory in hierarchical entries.
+    let mut directories: Vec<String> = Vec::new();
+    // The path type set using `/set`.
+    let mut default_type: Option<String> = None;
+    // A line that is continued on the next line.
+    let mut continued: Option<(usize, String)> = None;
+
+    for (index, raw_line) in data.lines().enumerate() {
+        let (line_number, mut line) = match continued.take() {
+            Some((line_number, mut line)) => {
+                line.push(' ');
+                line.push_str(raw_line);
+                (line_number, line)
+            }
+            None => (index + 1, raw_line.to_string()),
+        };
+
+        // A trailing backslash that is preceded by whitespace continues the line.
+        if let Some(stripped) = line.strip_suffix('\\')
+            && (stripped.is_empty() || stripped.ends_with(char::is_whitespace))
+        {
+            line.truncate(stripped.len());
+            continued = Some((line_number, line));
+            continue;
+        }
+
+        let error = |reason: String| Error::InterpreterError(line_number, line.clone(), reason);
+        let mut tokens = line.split_whitespace();
+        let Some(name) = tokens.next() else {
+            continue;
+        };
+        if name.starts_with('#') {
+            continue;
+        }
+
+        match name {
+            "/set" => {
+                let mut properties = Vec::new();
+                for (keyword, value) in tokens.filter_map(|token| token.split_once('=')) {
+                    if !SET_KEYWORDS.contains(&keyword) {
+                        continue;
+                    }
+                    if keyword == "type" {
+                        default_type = Some(value.to_string());
+                    }
+                    properties.push(format!(
+                        "{keyword}={}",
+                        convert_value(keyword, value).map_err(error)?
+                    ));
+                }
+                if !properties.is_empty() {
+                    output.push_str(&format!("/set {}\n", properties.join(" ")));
+                }
+            }
+            "/unset" => {
+                let mut keywords = Vec::new();
+                for keyword in tokens {
+                    let unset = if keyword == "all" {
+                        SET_KEYWORDS
+                    } else if SET_KEYWORDS.contains(&keyword) {
+                        std::slice::from_ref(&keyword)
+                    } else {
+                        &[]
+                    };
+                    for keyword in unset {
+                        if *keyword == "type" {
+                            default_type = None;
+                        }
+                        if !keywords.contains(keyword) {
+                            keywords.push(*keyword);
+                        }
+                    }
+                }
+                if !keywords.is_empty() {
+                    output.push_str(&format!("/unset {}\n", keywords.join(" ")));
+                }
+            }
+            ".." => {
+                if directories.pop().is_none() {
+                    return Err(error("Can not move above the root directory.".to_string()));
+                }
+            }
+            name => {
+                let decoded = decode_bsd_path(name).map_err(error)?;
+                let mut path_type = default_type.clone();
+                let mut properties = Vec::new();
+                for (keyword, value) in tokens.filter_map(|token| token.split_once('=')) {
+                    let keyword = match keyword {
+                        "md5" => "md5digest",
+                        "sha256" => "sha256digest",
+                        keyword => keyword,
+                    };
+                    if !PATH_KEYWORDS.contains(&keyword) {
+                        continue;
+                    }
+                    if keyword == "type" {
+                        path_type = Some(value.to_string());
+                    }
+                    properties.push(format!(
+                        "{keyword}={}",
+                        convert_value(keyword, value).map_err(error)?
+                    ));
+                }
+
+                // Names without a slash are relative to the current directory.
+                let is_relative = !decoded.contains('/');
+                let path = if is_relative && !directories.is_empty() {
+                    format!("{}/{decoded}", directories.join("/"))
+                } else {
+                    decoded.clone()
+                };
+                if is_relative && path_type.as_deref() == Some("dir") {
+                    directories.push(decoded);
+                }
+
+                // The root directory is not tracked in ALPM-MTREE data.
+                if path == "." {
+                    continue;
+                }
+                let path = if path.starts_with("./") {
+                    path
+                } else {
+                    format!("./{path}")
+                };
+
+                output.push_str(&encode_bsd_path(path));
+                for property in properties {
+                    output.push(' ');
+                    output.push_str(&property);
+                }
+                output.push('\n');
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that paths are escaped for BSD mtree data.
+    #[rstest]
+    #[case("./usr/bin/foo", "./usr/bin/foo")]
+    #[case("./usr/share/foo bar", "./usr/share/foo\\040bar")]
+    #[case("./etc/#foo=*?[", "./etc/\\043foo\\075\\052\\077\\133")]
+    #[case("./etc/back\\slash", "./etc/back\\134slash")]
+    #[case("./usr/share/🌠", "./usr/share/\\360\\237\\214\\240")]
+    fn encode_path(#[case] path: &str, #[case] expected: &str) -> TestResult {
+        assert_eq!(encode_bsd_path(path), expected);
+        assert_eq!(decode_bsd_path(expected)?, path);
+        Ok(())
+    }
+
+    /// Ensures that the escape sequences of BSD mtree data are decoded.
+    #[rstest]
+    #[case("foo\\sbar", Ok("foo bar".to_string()))]
+    #[case("foo\\\\bar\\#", Ok("foo\\bar#".to_string()))]
+    #[case("foo\\x", Err("Unknown escape sequence in \"foo\\\\x\"".to_string()))]
+    #[case("foo\\04", Err("Invalid octal escape sequence in \"foo\\\\04\"".to_string()))]
+    #[case("foo\\377", Err("The path \"foo\\\\377\" is not valid UTF-8".to_string()))]
+    fn decode_path(#[case] input: &str, #[case] expected: Result<String, String>) {
+        assert_eq!(decode_bsd_path(input), expected);
+    }
+
+    /// Ensures that exported data can be imported again.
+    #[test]
+    fn bsd_mtree_round_trip() -> TestResult {
+        let mtree = Mtree::V2(parse_mtree_v2(
+            r#"#mtree
+/set mode=644 uid=0 gid=0 type=file
+./usr time=1700000000.0 mode=755 type=dir
+./usr/foo\sbar time=1700000000.0 size=0 sha256digest=e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855
+./usr/🌠 type=link link=foo\sbar time=1700000000.0
+"#
+            .to_string(),
+        )?);
+
+        assert_eq!(Mtree::from_bsd_mtree(&mtree.to_bsd_mtree())?, mtree);
+        Ok(())
+    }
+
+    /// Ensures that keywords are mapped and unsupported keywords are ignored on import.
+    #[test]
+    fn bsd_mtree_keywords() -> TestResult {
+        let data = "#mtree\n/set type=file uname=root uid=0 gid=0 mode=0644 nlink=1\n\
+            ./foo size=3 time=1700000000 md5=d3b07384d113edec49eaa6238ad5ff00 \
+            sha256=b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c optional\n\
+            /unset all\n";
+
+        assert_eq!(
+            bsd_to_alpm_mtree(data)?,
+            "#mtree\n/set type=file uid=0 gid=0 mode=644\n\
+            ./foo size=3 time=1700000000.0 md5digest=d3b07384d113edec49eaa6238ad5ff00 \
+            sha256digest=b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c\n\
+            /unset uid gid type mode\n"
+        );
+        Ok(())
+    }
+
+    /// Ensures that moving above the root directory in hierarchical data fails.
+    #[test]
+    fn bsd_mtree_above_root() {
+        assert!(matches!(
+            bsd_to_alpm_mtree("#mtree\n. type=dir\n..\n..\n"),
+            Err(Error::InterpreterError(4, ..))
+        ));
+    }
+}
@@ -1,5 +1,7 @@
 //! Handling for the ALPM-MTREE file format.
 
+pub mod backup;
+pub mod bsd;
 pub mod path_validation_error;
 pub mod v2;
 use std::{
@@ -25,6 +27,7 @@ use crate::{Error, MtreeSchema, mtree_buffer_to_string, parse_mtree_v2};
 ///
 /// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
 #[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum Mtree {
     /// The [ALPM-MTREEv1] file format.
@@ -1,9 +1,9 @@
 //! Interpreter for ALPM-MTREE v1 and v2.
 
-use std::{fs::Metadata, io::Read, os::linux::fs::MetadataExt, path::PathBuf};
+use std::{fs::Metadata, os::linux::fs::MetadataExt, path::PathBuf};
 
 use alpm_common::InputPath;
-use alpm_types::{Checksum, Digest, Md5Checksum, Sha256Checksum};
+use alpm_types::{Checksum, ChecksumVerificationError, Digest, Md5Checksum, Sha256Checksum};
 use log::trace;
 use serde::{Serialize, Serializer, ser::Error as SerdeError}; // codespell:ignore ser
 use winnow::Parser;
@@ -174,6 +174,7 @@ fn path_metadata(
 
 /// A directory type path statement in an mtree file.
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Directory {
     /// The path of the directory.
     pub path: PathBuf,
@@ -291,6 +292,7 @@ impl Directory {
 ///
 /// The md5_digest is accepted for backwards compatibility reasons in v2 as well.
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct File {
     /// The path of the file.
     pub path: PathBuf,
@@ -396,9 +398,9 @@ impl File {
             return Err(errors);
         }
 
-        // Create the hash digest.
-        let path_digest = {
-            let mut file = match std::fs::File::open(path.as_path()) {
+        // Verify the hash digest.
+        let digest_mismatch = {
+            let file = match std::fs::File::open(path.as_path()) {
                 Ok(file) => file,
                 Err(source) => {
                     errors.push(PathValidationError::CreateHashDigest {
@@ -411,10 +413,10 @@ impl File {
                 }
             };
 
-            let mut buf = Vec::new();
-            match file.read_to_end(&mut buf) {
-                Ok(_) => {}
-                Err(source) => {
+            match self.sha256_digest.verify_reader(file) {
+                Ok(()) => None,
+                Err(ChecksumVerificationError::Mismatch(mismatch)) => Some(mismatch),
+                Err(ChecksumVerificationError::Io(source)) => {
                     errors.push(PathValidationError::CreateHashDigest {
                         path: path.to_path_buf(),
                         source,
@@ -424,8 +426,6 @@ impl File {
                     return Err(errors);
                 }
             }
-
-            Sha256Checksum::calculate_from(buf)
         };
 
         // Compare the file size.
@@ -438,13 +438,13 @@ impl File {
             });
         }
 
-        // Compare the hash digests.
-        if self.sha256_digest != path_digest {
+        // Report mismatching hash digests.
+        if let Some(mismatch) = digest_mismatch {
             errors.push(PathValidationError::PathDigestMismatch {
                 mtree_path: mtree_path.to_path_buf(),
-                mtree_digest: self.sha256_digest.clone(),
+                mtree_digest: mismatch.expected,
                 path: path.to_path_buf(),
-                path_digest,
+                path_digest: mismatch.actual,
             });
         }
 
@@ -502,6 +502,7 @@ where
 
 /// A link type path in an mtree file that points to a file somewhere on the system.
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Link {
     /// The path of the symlink.
     pub path: PathBuf,
@@ -624,6 +625,7 @@ impl Link {
 /// This means that `Vec<Path>` will be serialized to a list of maps where each map has a `type`
 /// entry with the respective name.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type")]
 pub enum Path {
     /// A directory.
@@ -0,0 +1,26 @@
+//! Round-trip property tests for [ALPM-MTREE] data.
+//!
+//! These tests are only executed when the `arbitrary` feature flag is enabled.
+//!
+//! [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+#![cfg(feature = "arbitrary")]
+
+use alpm_mtree::{arbitrary::MtreeText, parse_mtree_v2};
+use arbitrary::Unstructured;
+use proptest::{collection::vec, prelude::*};
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(500))]
+
+    /// Ensures that generated [ALPM-MTREE] data can be parsed and results in the expected paths.
+    ///
+    /// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+    #[test]
+    fn mtree_round_trip(data in vec(any::<u8>(), 0..4096)) {
+        let mut unstructured = Unstructured::new(&data);
+        let text: MtreeText = unstructured.arbitrary().unwrap();
+
+        let paths = parse_mtree_v2(text.as_str().to_string()).unwrap();
+        prop_assert_eq!(paths.as_slice(), text.paths());
+    }
+}
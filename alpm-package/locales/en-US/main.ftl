@@ -1,6 +1,8 @@
 error-compression = Compression error:
   { $source }
 
+error-entry-rejected = The entry { $path } has been rejected: { $reason }
+
 error-install-scriptlet = The alpm-install-scriptlet at { $path } is invalid because { $context }
 
 error-package-input = Package input error:
@@ -34,6 +36,8 @@ error-io-open-scriptlet = opening an alpm-install-scriptlet file for reading
 
 error-io-read-to-string = reading the contents to string
 
+error-io-read-entry-header = reading the header of a package archive entry
+
 error-invalid-utf8 = Invalid UTF-8 while { $context }:
   { $source }
 
@@ -53,3 +57,15 @@ error-path-no-parent = The path { $path } has no parent.
 error-path-not-file = The path { $path } is not a file.
 
 error-path-read-only = The path { $path } is read-only.
+
+content-violation-forbidden-path = The path { $path } is located in the forbidden path { $forbidden }.
+
+content-violation-unexpected-ownership = The path { $path } is owned by { $uid }:{ $gid }, which is not allowed.
+
+content-violation-setuid-not-allowed = The path { $path } has the mode { $mode }, but setuid and setgid are not allowed for it.
+
+content-violation-file-too-large = The file { $path } has a size of { $size } bytes, but at most { $max } bytes are allowed.
+
+content-violation-too-many-files = The package contains { $count } files, but at most { $max } are allowed.
+
+content-violation-empty-package = The package does not contain any files.
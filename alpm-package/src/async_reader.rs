@@ -0,0 +1,228 @@
+//! Asynchronous reading of [alpm-package] files.
+//!
+//! This module is only available if the `tokio` feature is enabled.
+//!
+//! [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+
+use std::path::Path;
+
+use alpm_compress::async_io::{AsyncTarballEntry, AsyncTarballReader};
+use alpm_types::MetadataFileName;
+use tokio::{
+    fs::File,
+    io::{AsyncBufRead, BufReader},
+};
+
+use crate::package::{
+    Metadata,
+    MetadataEntry,
+    PackageEntry,
+    is_package_entry_path,
+    read_package_entry,
+};
+
+/// An asynchronous reader for [alpm-package] files.
+///
+/// This is the asynchronous counterpart to [`PackageReader`](crate::PackageReader).
+/// It can read packages from any [`AsyncBufRead`] implementation (e.g. a network stream) without
+/// blocking the executor.
+///
+/// # Note
+///
+/// As the underlying archive can only be read in a single pass, each [`AsyncPackageReader`] can
+/// only be used for a single read operation.
+///
+/// # Examples
+///
+/// ```no_run
+/// use alpm_package::AsyncPackageReader;
+///
+/// # async fn run() -> testresult::TestResult {
+/// let mut reader = AsyncPackageReader::open("example-1.0.0-1-any.pkg.tar.zst").await?;
+/// let metadata = reader.metadata().await?;
+/// println!("{:?}", metadata.pkginfo);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+#[derive(Debug)]
+pub struct AsyncPackageReader<R: AsyncBufRead + Unpin>(AsyncTarballReader<R>);
+
+impl<R: AsyncBufRead + Unpin> AsyncPackageReader<R> {
+    /// Creates a new [`AsyncPackageReader`] from an [`AsyncTarballReader`].
+    pub fn new(tarball_reader: AsyncTarballReader<R>) -> Self {
+        Self(tarball_reader)
+    }
+
+    /// Reads all [`PackageEntry`]s from the [alpm-package] file.
+    ///
+    /// Stops reading once all [`PackageEntry`]s have been found.
+    /// This mirrors the behavior of [`PackageEntryIterator`](crate::package::PackageEntryIterator)
+    /// and relies on the ordering of files, where metadata files and the
+    /// [alpm-install-scriptlet] are placed in front of all data files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - reading the package archive entries fails,
+    /// - reading a package archive entry fails,
+    /// - or a [`PackageEntry`] is not valid.
+    ///
+    /// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
+    /// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+    pub async fn entries(&mut self) -> Result<Vec<PackageEntry>, crate::Error> {
+        let mut package_entries = Vec::new();
+        let mut found_buildinfo = false;
+        let mut found_mtree = false;
+        let mut found_pkginfo = false;
+
+        let mut entries = self.0.entries()?;
+        while let Some(mut entry) = entries.next_entry().await? {
+            if !is_package_entry_path(entry.path()) {
+                if found_buildinfo && found_mtree && found_pkginfo {
+                    // Found all required metadata files and hit the first non-metadata file.
+                    break;
+                }
+                continue;
+            }
+
+            let content = entry.content().await?;
+            let Some(package_entry) = read_package_entry(entry.path(), content.as_slice())? else {
+                continue;
+            };
+            let found_scriptlet = match &package_entry {
+                PackageEntry::Metadata(metadata_entry) => {
+                    match **metadata_entry {
+                        MetadataEntry::PackageInfo(_) => found_pkginfo = true,
+                        MetadataEntry::BuildInfo(_) => found_buildinfo = true,
+                        MetadataEntry::Mtree(_) => found_mtree = true,
+                    }
+                    false
+                }
+                PackageEntry::InstallScriptlet(_) => true,
+            };
+            package_entries.push(package_entry);
+
+            if found_scriptlet && found_buildinfo && found_mtree && found_pkginfo {
+                break;
+            }
+        }
+
+        Ok(package_entries)
+    }
+
+    /// Reads all metadata from an [alpm-package] file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - [`AsyncPackageReader::entries`] fails,
+    /// - or any of the required metadata files are not found in the package.
+    ///
+    /// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+    pub async fn metadata(&mut self) -> Result<Metadata, crate::Error> {
+        let mut pkginfo = None;
+        let mut buildinfo = None;
+        let mut mtree = None;
+        for entry in self.entries().await? {
+            if let PackageEntry::Metadata(metadata_entry) = entry {
+                match *metadata_entry {
+                    MetadataEntry::PackageInfo(m) => pkginfo = Some(m),
+                    MetadataEntry::BuildInfo(m) => buildinfo = Some(m),
+                    MetadataEntry::Mtree(m) => mtree = Some(m),
+                }
+            }
+        }
+        Ok(Metadata {
+            pkginfo: pkginfo.ok_or(crate::Error::MetadataFileNotFound {
+                name: MetadataFileName::PackageInfo,
+            })?,
+            buildinfo: buildinfo.ok_or(crate::Error::MetadataFileNotFound {
+                name: MetadataFileName::BuildInfo,
+            })?,
+            mtree: mtree.ok_or(crate::Error::MetadataFileNotFound {
+                name: MetadataFileName::Mtree,
+            })?,
+        })
+    }
+
+    /// Reads the data of a specific metadata file from the [alpm-package] file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - [`AsyncPackageReader::entries`] fails,
+    /// - or no metadata file matching `file_name` is found.
+    ///
+    /// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+    pub async fn read_metadata_file(
+        &mut self,
+        file_name: MetadataFileName,
+    ) -> Result<MetadataEntry, crate::Error> {
+        for entry in self.entries().await? {
+            let PackageEntry::Metadata(entry) = entry else {
+                continue;
+            };
+            match (&*entry, &file_name) {
+                (MetadataEntry::PackageInfo(_), MetadataFileName::PackageInfo)
+                | (MetadataEntry::BuildInfo(_), MetadataFileName::BuildInfo)
+                | (MetadataEntry::Mtree(_), MetadataFileName::Mtree) => return Ok(*entry),
+                _ => continue,
+            }
+        }
+        Err(crate::Error::MetadataFileNotFound { name: file_name })
+    }
+
+    /// Reads the content of the [alpm-install-scriptlet] from the package archive, if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`AsyncPackageReader::entries`] fails.
+    ///
+    /// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
+    pub async fn read_install_scriptlet(&mut self) -> Result<Option<String>, crate::Error> {
+        Ok(self
+            .entries()
+            .await?
+            .into_iter()
+            .find_map(|entry| match entry {
+                PackageEntry::InstallScriptlet(scriptlet) => Some(scriptlet),
+                PackageEntry::Metadata(_) => None,
+            }))
+    }
+
+    /// Reads an [`AsyncTarballEntry`] matching a specific path name from the data files of the
+    /// package archive.
+    ///
+    /// Returns [`None`] if no data file is found in the package archive that matches `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the package archive entries fails.
+    pub async fn read_data_entry<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<Option<AsyncTarballEntry<R>>, crate::Error> {
+        if is_package_entry_path(path.as_ref()) {
+            return Ok(None);
+        }
+        Ok(self.0.read_entry(path).await?)
+    }
+}
+
+impl AsyncPackageReader<BufReader<File>> {
+    /// Creates a new [`AsyncPackageReader`] for the [alpm-package] file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`AsyncTarballReader::open`] fails.
+    ///
+    /// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self, crate::Error> {
+        Ok(Self::new(AsyncTarballReader::open(path).await?))
+    }
+}
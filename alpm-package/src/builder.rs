@@ -0,0 +1,228 @@
+//! High-level creation of packages from prepared build roots.
+
+use std::path::Path;
+
+use alpm_buildinfo::BuildInfo;
+use alpm_common::write_atomic;
+use alpm_compress::{compression::CompressionSettings, progress::CancellationToken};
+use alpm_mtree::create_mtree_v2_from_input_dir;
+use alpm_pkginfo::PackageInfo;
+use alpm_types::MetadataFileName;
+use log::debug;
+
+use crate::{
+    InputDir,
+    OutputDir,
+    Package,
+    PackageCreationConfig,
+    PackageInput,
+    ScriptletValidation,
+    observer::PackageBuildObserver,
+};
+
+/// The artifacts created by [`PackageBuilder::build`].
+#[derive(Clone, Debug)]
+pub struct PackageArtifacts {
+    /// The created package file.
+    pub package: Package,
+    /// The configuration used for creating [`PackageArtifacts::package`].
+    ///
+    /// Provides access to the metadata of the package (see [`PackageInput`]).
+    pub config: PackageCreationConfig,
+}
+
+impl PackageArtifacts {
+    /// Returns the [`CompressionSettings`] used for creating [`PackageArtifacts::package`].
+    ///
+    /// The file extension of the package file is derived from these settings (see
+    /// [`Package::compression`]).
+    pub fn compression(&self) -> &CompressionSettings {
+        self.config.compression()
+    }
+}
+
+/// Creates a package file from a prepared build root.
+///
+/// A build root is an [`InputDir`] that contains the data files of a package and optionally an
+/// [alpm-install-scriptlet].
+/// Unlike when creating a [`Package`] using a [`PackageCreationConfig`], the build root does not
+/// need to contain all metadata files upfront:
+///
+/// - The [PKGINFO] and [BUILDINFO] files are written from the data provided using
+///   [`PackageBuilder::package_info`] and [`PackageBuilder::build_info`]. Existing files in the
+///   build root are used if no data is provided.
+/// - The [ALPM-MTREE] file is always (re)created from the contents of the build root.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::str::FromStr;
+///
+/// use alpm_buildinfo::BuildInfo;
+/// use alpm_package::{InputDir, OutputDir, builder::PackageBuilder};
+/// use alpm_pkginfo::PackageInfo;
+///
+/// # fn main() -> testresult::TestResult {
+/// let artifacts = PackageBuilder::new(
+///     InputDir::new("/build/pkg/example".into())?,
+///     OutputDir::new("/build/out".into())?,
+/// )
+/// .package_info(PackageInfo::from_str(&std::fs::read_to_string(
+///     "/build/example.pkginfo",
+/// )?)?)
+/// .build_info(BuildInfo::from_str(&std::fs::read_to_string(
+///     "/build/example.buildinfo",
+/// )?)?)
+/// .build()?;
+/// println!("Created {:?}", artifacts.package.to_path_buf());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+/// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+/// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
+#[derive(Clone, Debug)]
+pub struct PackageBuilder {
+    input_dir: InputDir,
+    output_dir: OutputDir,
+    compression: CompressionSettings,
+    package_info: Option<PackageInfo>,
+    build_info: Option<BuildInfo>,
+    scriptlet_validation: ScriptletValidation,
+    cancellation: Option<CancellationToken>,
+}
+
+impl PackageBuilder {
+    /// Creates a new [`PackageBuilder`] for the build root `input_dir`.
+    ///
+    /// The package file is created in `output_dir` using the default [`CompressionSettings`].
+    pub fn new(input_dir: InputDir, output_dir: OutputDir) -> Self {
+        Self {
+            input_dir,
+            output_dir,
+            compression: CompressionSettings::default(),
+            package_info: None,
+            build_info: None,
+            scriptlet_validation: ScriptletValidation::default(),
+            cancellation: None,
+        }
+    }
+
+    /// Sets the [`CompressionSettings`] used for the package file.
+    pub fn compression(mut self, compression: CompressionSettings) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Sets the [`PackageInfo`] that is written to the [PKGINFO] file of the build root.
+    ///
+    /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+    pub fn package_info(mut self, package_info: PackageInfo) -> Self {
+        self.package_info = Some(package_info);
+        self
+    }
+
+    /// Sets the [`BuildInfo`] that is written to the [BUILDINFO] file of the build root.
+    ///
+    /// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+    pub fn build_info(mut self, build_info: BuildInfo) -> Self {
+        self.build_info = Some(build_info);
+        self
+    }
+
+    /// Sets the checks run on the [alpm-install-scriptlet] of the build root.
+    ///
+    /// See [`PackageCreationConfig::with_scriptlet_validation`].
+    ///
+    /// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
+    pub fn scriptlet_validation(mut self, validation: ScriptletValidation) -> Self {
+        self.scriptlet_validation = validation;
+        self
+    }
+
+    /// Sets the [`CancellationToken`] used for creating the package file.
+    ///
+    /// See [`PackageCreationConfig::with_cancellation`].
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Writes the metadata files to the build root and creates the package file.
+    ///
+    /// Delegates to [`PackageBuilder::build_with_observer`] without observing the creation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`PackageBuilder::build_with_observer`] fails.
+    pub fn build(self) -> Result<PackageArtifacts, crate::Error> {
+        self.build_with_observer(&mut ())
+    }
+
+    /// Writes the metadata files to the build root and creates the package file, while reporting
+    /// progress to `observer`.
+    ///
+    /// [`PackageBuildObserver::on_metadata_generated`] is called for each metadata file written
+    /// to the build root, before the package file is created using
+    /// [`Package::create_with_observer`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - writing the [PKGINFO] or [BUILDINFO] file fails,
+    /// - creating the [ALPM-MTREE] file fails,
+    /// - creating a [`PackageInput`] from the build root fails,
+    /// - creating a [`PackageCreationConfig`] fails,
+    /// - the [alpm-install-scriptlet] of the build root fails validation,
+    /// - or creating the [`Package`] fails (e.g. because `observer` rejects one of the files).
+    ///
+    /// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+    /// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+    /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+    /// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
+    pub fn build_with_observer(
+        self,
+        observer: &mut impl PackageBuildObserver,
+    ) -> Result<PackageArtifacts, crate::Error> {
+        if let Some(package_info) = &self.package_info {
+            let path = self.input_dir.join(MetadataFileName::PackageInfo.as_ref());
+            write_metadata_file(&path, package_info)?;
+            observer.on_metadata_generated(MetadataFileName::PackageInfo, &path);
+        }
+        if let Some(build_info) = &self.build_info {
+            let path = self.input_dir.join(MetadataFileName::BuildInfo.as_ref());
+            write_metadata_file(&path, build_info)?;
+            observer.on_metadata_generated(MetadataFileName::BuildInfo, &path);
+        }
+
+        let mtree_path = create_mtree_v2_from_input_dir(&self.input_dir)?;
+        debug!("Created ALPM-MTREE file {mtree_path:?}");
+        observer.on_metadata_generated(MetadataFileName::Mtree, &mtree_path);
+
+        let package_input = PackageInput::try_from(self.input_dir)?;
+        let mut config =
+            PackageCreationConfig::new(package_input, self.output_dir, self.compression)?
+                .with_scriptlet_validation(self.scriptlet_validation)?;
+        if let Some(token) = self.cancellation {
+            config = config.with_cancellation(token);
+        }
+        let package = Package::create_with_observer(&config, observer)?;
+        debug!("Created package file {:?}", package.to_path_buf());
+
+        Ok(PackageArtifacts { package, config })
+    }
+}
+
+/// Atomically writes the string representation of `data` to the file at `path`.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written atomically.
+fn write_metadata_file(path: &Path, data: &impl std::fmt::Display) -> Result<(), crate::Error> {
+    debug!("Write metadata file {path:?}");
+    write_atomic(path, data.to_string())?;
+    Ok(())
+}
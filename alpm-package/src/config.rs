@@ -5,15 +5,18 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use alpm_compress::compression::CompressionSettings;
+use alpm_compress::{compression::CompressionSettings, progress::CancellationToken};
 #[cfg(doc)]
 use alpm_pkginfo::PackageInfo;
 use alpm_types::PackageFileName;
 use fluent_i18n::t;
 
-use crate::input::PackageInput;
 #[cfg(doc)]
 use crate::package::Package;
+use crate::{
+    input::PackageInput,
+    scriptlet::{InstallScriptlet, ScriptletValidation},
+};
 
 /// An output directory that is guaranteed to be an absolute, writable directory.
 #[derive(Clone, Debug)]
@@ -94,13 +97,18 @@ impl AsRef<Path> for OutputDir {
 ///
 /// Tracks a [`PackageInput`], optional [`CompressionSettings`] and an [`OutputDir`] in which an
 /// [alpm-package] is placed after creation.
+/// Optionally, the [alpm-install-scriptlet] of the [`PackageInput`] is validated (see
+/// [`PackageCreationConfig::with_scriptlet_validation`]).
 ///
+/// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
 /// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
 #[derive(Clone, Debug)]
 pub struct PackageCreationConfig {
     package_input: PackageInput,
     output_dir: OutputDir,
     compression: CompressionSettings,
+    scriptlet_validation: ScriptletValidation,
+    cancellation: Option<CancellationToken>,
 }
 
 impl PackageCreationConfig {
@@ -140,9 +148,47 @@ impl PackageCreationConfig {
             compression,
             package_input,
             output_dir,
+            scriptlet_validation: ScriptletValidation::default(),
+            cancellation: None,
         })
     }
 
+    /// Validates the [alpm-install-scriptlet] of the [`PackageInput`] using `validation`.
+    ///
+    /// If the [`PackageInput`] has no [alpm-install-scriptlet], no checks are run.
+    /// The selected checks are tracked and can be retrieved using
+    /// [`PackageCreationConfig::scriptlet_validation`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the [alpm-install-scriptlet] can not be read,
+    /// - or it fails one of the checks selected in `validation` (see
+    ///   [`InstallScriptlet::validate`]).
+    ///
+    /// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
+    pub fn with_scriptlet_validation(
+        mut self,
+        validation: ScriptletValidation,
+    ) -> Result<Self, crate::Error> {
+        if let Some(path) = self.package_input.install_scriptlet() {
+            InstallScriptlet::from_file(path)?
+                .validate(&validation, self.package_input.relative_paths())?;
+        }
+        self.scriptlet_validation = validation;
+
+        Ok(self)
+    }
+
+    /// Sets the [`CancellationToken`] that is checked before each file is added to a [`Package`].
+    ///
+    /// Once `token` is cancelled, the creation of a [`Package`] fails.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
     /// Returns a reference to the [`PackageInput`].
     pub fn package_input(&self) -> &PackageInput {
         &self.package_input
@@ -157,6 +203,16 @@ impl PackageCreationConfig {
     pub fn compression(&self) -> &CompressionSettings {
         &self.compression
     }
+
+    /// Returns a reference to the [`ScriptletValidation`] that has been used.
+    pub fn scriptlet_validation(&self) -> &ScriptletValidation {
+        &self.scriptlet_validation
+    }
+
+    /// Returns a reference to the [`CancellationToken`], if one is set.
+    pub fn cancellation(&self) -> Option<&CancellationToken> {
+        self.cancellation.as_ref()
+    }
 }
 
 impl From<&PackageCreationConfig> for PackageFileName {
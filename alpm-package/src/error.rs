@@ -2,6 +2,7 @@
 
 use std::{path::PathBuf, string::FromUtf8Error};
 
+use alpm_common::ErrorCategory;
 use alpm_types::MetadataFileName;
 use fluent_i18n::t;
 
@@ -40,6 +41,19 @@ pub enum Error {
     #[error(transparent)]
     AlpmCompress(#[from] alpm_compress::Error),
 
+    /// An entry has been rejected by a
+    /// [`PackageBuildObserver`](crate::observer::PackageBuildObserver).
+    #[error("{msg}", msg = t!("error-entry-rejected", {
+        "path" => path,
+        "reason" => reason
+    }))]
+    EntryRejected {
+        /// The path of the rejected entry.
+        path: PathBuf,
+        /// The reason for the rejection.
+        reason: String,
+    },
+
     /// An error with an [alpm-install-scriptlet].
     ///
     /// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
@@ -180,3 +194,46 @@ pub enum Error {
         path: PathBuf,
     },
 }
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::AlpmBuildInfo(error) => error.into(),
+            Error::AlpmCommon(error) => error.into(),
+            Error::AlpmMtree(error) => error.into(),
+            Error::AlpmPackageInfo(error) => error.into(),
+            Error::AlpmTypes(error) => error.into(),
+            Error::AlpmTypesPackage(error) => error.into(),
+            Error::Input(error) => error.into(),
+            Error::Package(error) => error.into(),
+            Error::AlpmCompress(_) | Error::IoPath { .. } | Error::IoRead { .. } => Self::Io,
+            Error::InvalidUTF8 { .. } | Error::EndOfPackageEntries => Self::Parse,
+            Error::AlpmMtreePathValidation(_)
+            | Error::EntryRejected { .. }
+            | Error::InstallScriptlet { .. }
+            | Error::InputDirIsOutputDir { .. }
+            | Error::InputDirInOutputDir { .. }
+            | Error::MetadataFileNotFound { .. }
+            | Error::OutputDirInInputDir { .. }
+            | Error::PathDoesNotExist { .. }
+            | Error::PathHasNoParent { .. }
+            | Error::PathIsNotAFile { .. }
+            | Error::PathIsReadOnly { .. } => Self::Validation,
+        }
+    }
+}
+
+impl From<&crate::input::Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`input::Error`](crate::input::Error).
+    fn from(_value: &crate::input::Error) -> Self {
+        Self::Validation
+    }
+}
+
+impl From<&crate::package::Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of a [`package::Error`](crate::package::Error).
+    fn from(_value: &crate::package::Error) -> Self {
+        Self::Io
+    }
+}
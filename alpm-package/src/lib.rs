@@ -1,14 +1,23 @@
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "tokio")]
+pub mod async_reader;
+pub mod builder;
 pub mod config;
 pub mod error;
 pub mod input;
+pub mod observer;
 pub mod package;
-mod scriptlet;
+pub mod policy;
+pub mod scriptlet;
 
+#[cfg(feature = "tokio")]
+pub use async_reader::AsyncPackageReader;
 pub use config::{OutputDir, PackageCreationConfig};
 pub use error::Error;
 pub use input::{InputDir, PackageInput};
+pub use observer::PackageBuildObserver;
 pub use package::{ExistingAbsoluteDir, MetadataEntry, Package, PackageEntry, PackageReader};
+pub use scriptlet::{InstallScriptlet, ScriptletValidation};
 
 fluent_i18n::i18n!("locales");
@@ -0,0 +1,76 @@
+//! Hooks for observing the creation of [alpm-package] files.
+//!
+//! [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+
+use std::{fs::Metadata, path::Path};
+
+use alpm_compress::tarball::CompressionStatistics;
+use alpm_types::MetadataFileName;
+
+#[cfg(doc)]
+use crate::{Package, PackageCreationConfig, builder::PackageBuilder};
+
+/// An observer of the creation of a [`Package`].
+///
+/// Allows callers to e.g. render progress, enforce policies on the contents of a package or
+/// collect statistics, without having to reimplement package creation.
+/// All methods have default implementations that do nothing, so implementations only need to
+/// provide the hooks they are interested in.
+///
+/// An observer is passed to [`Package::create_with_observer`] or
+/// [`PackageBuilder::build_with_observer`].
+/// The unit type `()` implements [`PackageBuildObserver`] and observes nothing.
+///
+/// # Examples
+///
+/// ```
+/// use std::{fs::Metadata, os::unix::fs::PermissionsExt, path::Path};
+///
+/// use alpm_package::observer::PackageBuildObserver;
+///
+/// /// Rejects all files with the setuid bit.
+/// struct RejectSetuid;
+///
+/// impl PackageBuildObserver for RejectSetuid {
+///     fn on_entry_added(&mut self, path: &Path, metadata: &Metadata) -> Result<(), String> {
+///         if metadata.permissions().mode() & 0o4000 != 0 {
+///             return Err(format!("{path:?} has the setuid bit set"));
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait PackageBuildObserver {
+    /// Called for each file, directory or symlink, before it is appended to the package file.
+    ///
+    /// The `path` is the path of the entry relative to the input directory and `metadata` the
+    /// (symlink) metadata of the on-disk file.
+    ///
+    /// # Errors
+    ///
+    /// Returning an error rejects the entry and aborts the creation of the package.
+    /// The returned string is used as reason of the rejection.
+    fn on_entry_added(&mut self, _path: &Path, _metadata: &Metadata) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Called after the metadata file `file` has been written to `path`.
+    ///
+    /// Only called by [`PackageBuilder`], as creating a [`Package`] from a
+    /// [`PackageCreationConfig`] does not generate any metadata files.
+    fn on_metadata_generated(&mut self, _file: MetadataFileName, _path: &Path) {}
+
+    /// Called after each entry has been appended to the package file.
+    ///
+    /// The `bytes_written` are the total amount of bytes written to the uncompressed tar stream so
+    /// far.
+    fn on_progress(&mut self, _bytes_written: u64) {}
+
+    /// Called after the package file at `path` has been finished.
+    ///
+    /// The `statistics` describe the compression of each entry and of the package file as a
+    /// whole.
+    fn on_compression_finished(&mut self, _path: &Path, _statistics: &CompressionStatistics) {}
+}
+
+impl PackageBuildObserver for () {}
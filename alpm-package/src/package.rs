@@ -4,7 +4,7 @@
 
 use std::{
     fmt::{self, Debug},
-    fs::{File, create_dir_all},
+    fs::{File, create_dir_all, symlink_metadata},
     io::Read,
     path::{Path, PathBuf},
     str::FromStr,
@@ -12,14 +12,23 @@ use std::{
 
 use alpm_buildinfo::BuildInfo;
 use alpm_common::{InputPaths, MetadataFile};
-use alpm_compress::tarball::{TarballBuilder, TarballEntries, TarballEntry, TarballReader};
+use alpm_compress::{
+    progress::CancellationToken,
+    tarball::{SeekableTarballReader, TarballBuilder, TarballEntries, TarballEntry, TarballReader},
+};
 use alpm_mtree::Mtree;
 use alpm_pkginfo::PackageInfo;
-use alpm_types::{INSTALL_SCRIPTLET_FILE_NAME, MetadataFileName, PackageError, PackageFileName};
+use alpm_types::{
+    CompressionAlgorithmFileExtension,
+    INSTALL_SCRIPTLET_FILE_NAME,
+    MetadataFileName,
+    PackageError,
+    PackageFileName,
+};
 use fluent_i18n::t;
 use log::debug;
 
-use crate::{OutputDir, PackageCreationConfig};
+use crate::{OutputDir, PackageCreationConfig, observer::PackageBuildObserver};
 
 /// An error that can occur when handling [alpm-package] files.
 ///
@@ -149,6 +158,8 @@ impl TryFrom<&Path> for ExistingAbsoluteDir {
 ///
 /// Before appending any files, all provided `input_paths` are validated against `mtree` (ALPM-MTREE
 /// data).
+/// Each file is passed to [`PackageBuildObserver::on_entry_added`] of `observer` before it is
+/// appended and [`PackageBuildObserver::on_progress`] is called after it is appended.
 ///
 /// # Errors
 ///
@@ -156,11 +167,16 @@ impl TryFrom<&Path> for ExistingAbsoluteDir {
 ///
 /// - validating any path in `input_paths` using `mtree` fails,
 /// - retrieving files relative to `input_dir` fails,
+/// - `cancellation` has been cancelled,
+/// - retrieving the metadata of one of the files fails,
+/// - `observer` rejects one of the files,
 /// - or adding one of the relative paths to the `builder` fails.
 fn append_relative_files<'c>(
     mut builder: TarballBuilder<'c>,
     mtree: &Mtree,
     input_paths: &InputPaths,
+    observer: &mut impl PackageBuildObserver,
+    cancellation: Option<&CancellationToken>,
 ) -> Result<TarballBuilder<'c>, crate::Error> {
     // Validate all paths using the ALPM-MTREE data before appending them to the builder.
     let mtree_path = PathBuf::from(MetadataFileName::Mtree.as_ref());
@@ -184,15 +200,32 @@ fn append_relative_files<'c>(
 
     // Append all files/directories to the archive.
     for relative_file in input_paths.paths() {
+        if let Some(token) = cancellation {
+            token.check()?;
+        }
+
         let from_path = input_paths.base_dir().join(relative_file.as_path());
+        let metadata =
+            symlink_metadata(from_path.as_path()).map_err(|source| crate::Error::IoPath {
+                path: from_path.clone(),
+                context: t!("error-io-get-metadata"),
+                source,
+            })?;
+        observer
+            .on_entry_added(relative_file.as_path(), &metadata)
+            .map_err(|reason| crate::Error::EntryRejected {
+                path: relative_file.clone(),
+                reason,
+            })?;
+
         builder
-            .inner_mut()
             .append_path_with_name(from_path.as_path(), relative_file.as_path())
             .map_err(|source| Error::AppendFileToArchive {
                 from_path,
                 to_path: relative_file.clone(),
                 source,
-            })?
+            })?;
+        observer.on_progress(builder.bytes_written());
     }
 
     Ok(builder)
@@ -266,6 +299,85 @@ pub struct Metadata {
     pub mtree: Mtree,
 }
 
+/// Checks whether `path` is the path of a [`PackageEntry`] in an [alpm-package] file.
+///
+/// Returns `true` if `path` matches the file name of a [PKGINFO], [BUILDINFO], [ALPM-MTREE] or
+/// [alpm-install-scriptlet] file, `false` otherwise.
+///
+/// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+/// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+/// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
+/// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+pub(crate) fn is_package_entry_path(path: &Path) -> bool {
+    let path = path.to_string_lossy();
+    [
+        MetadataFileName::PackageInfo.as_ref(),
+        MetadataFileName::BuildInfo.as_ref(),
+        MetadataFileName::Mtree.as_ref(),
+        INSTALL_SCRIPTLET_FILE_NAME,
+    ]
+    .contains(&path.as_ref())
+}
+
+/// Returns an optional [`PackageEntry`] from the `path` and `reader` of an archive entry.
+///
+/// Based on `path` either returns:
+///
+/// - `Ok(Some(PackageEntry))` when a valid [`PackageEntry`] is detected,
+/// - `Ok(None)` for any other files.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - `path` indicates a [BUILDINFO] file, but a [`BuildInfo`] cannot be created from `reader`,
+/// - `path` indicates an [ALPM-MTREE] file, but an [`Mtree`] cannot be created from `reader`,
+/// - `path` indicates a [PKGINFO] file, but a [`PackageInfo`] cannot be created from `reader`,
+/// - or `path` indicates an [alpm-install-scriptlet] file, but `reader` cannot be read to a string.
+///
+/// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
+/// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+/// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
+pub(crate) fn read_package_entry(
+    path: &Path,
+    mut reader: impl Read,
+) -> Result<Option<PackageEntry>, crate::Error> {
+    let path = path.to_string_lossy();
+    match path.as_ref() {
+        p if p == MetadataFileName::PackageInfo.as_ref() => {
+            let info = PackageInfo::from_reader(&mut reader)?;
+            Ok(Some(PackageEntry::Metadata(Box::new(
+                MetadataEntry::PackageInfo(info),
+            ))))
+        }
+        p if p == MetadataFileName::BuildInfo.as_ref() => {
+            let info = BuildInfo::from_reader(&mut reader)?;
+            Ok(Some(PackageEntry::Metadata(Box::new(
+                MetadataEntry::BuildInfo(info),
+            ))))
+        }
+        p if p == MetadataFileName::Mtree.as_ref() => {
+            let info = Mtree::from_reader(&mut reader)?;
+            Ok(Some(PackageEntry::Metadata(Box::new(
+                MetadataEntry::Mtree(info),
+            ))))
+        }
+        INSTALL_SCRIPTLET_FILE_NAME => {
+            let mut scriptlet = String::new();
+            reader
+                .read_to_string(&mut scriptlet)
+                .map_err(|source| crate::Error::IoRead {
+                    context: t!("error-io-read-install-scriptlet"),
+                    source,
+                })?;
+            Ok(Some(PackageEntry::InstallScriptlet(scriptlet)))
+        }
+        _ => Ok(None),
+    }
+}
+
 /// An iterator over each [`PackageEntry`] of a package.
 ///
 /// Stops early once all package entry files have been found.
@@ -327,62 +439,14 @@ impl<'a, 'c> PackageEntryIterator<'a, 'c> {
 
     /// A helper function that returns an optional [`PackageEntry`] from a [`TarballEntry`].
     ///
-    /// Based on the path of `entry` either returns:
-    ///
-    /// - `Ok(Some(PackageEntry))` when a valid [`PackageEntry`] is detected,
-    /// - `Ok(None)` for any other files.
+    /// Delegates to [`read_package_entry`].
     ///
     /// # Errors
     ///
-    /// Returns an error if
-    ///
-    /// - no path can be retrieved from `entry`,
-    /// - the path of `entry` indicates a [BUILDINFO] file, but a [`BuildInfo`] cannot be created
-    ///   from it,
-    /// - the path of `entry` indicates an [ALPM-MTREE] file, but an [`Mtree`] cannot be created
-    ///   from it,
-    /// - the path of `entry` indicates a [PKGINFO] file, but a [`PackageInfo`] cannot be created
-    ///   from it,
-    /// - or the path of `entry` indicates an [alpm-install-script] file, but it cannot be read to a
-    ///   string.
-    ///
-    /// [ALPM-MTREE]: https://alpm.archlinux.page/specifications/ALPM-MTREE.5.html
-    /// [BUILDINFO]: https://alpm.archlinux.page/specifications/BUILDINFO.5.html
-    /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
-    /// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
+    /// Returns an error if [`read_package_entry`] fails.
     fn get_package_entry(mut entry: TarballEntry) -> Result<Option<PackageEntry>, crate::Error> {
-        let path = entry.path().to_string_lossy();
-        match path.as_ref() {
-            p if p == MetadataFileName::PackageInfo.as_ref() => {
-                let info = PackageInfo::from_reader(&mut entry)?;
-                Ok(Some(PackageEntry::Metadata(Box::new(
-                    MetadataEntry::PackageInfo(info),
-                ))))
-            }
-            p if p == MetadataFileName::BuildInfo.as_ref() => {
-                let info = BuildInfo::from_reader(&mut entry)?;
-                Ok(Some(PackageEntry::Metadata(Box::new(
-                    MetadataEntry::BuildInfo(info),
-                ))))
-            }
-            p if p == MetadataFileName::Mtree.as_ref() => {
-                let info = Mtree::from_reader(&mut entry)?;
-                Ok(Some(PackageEntry::Metadata(Box::new(
-                    MetadataEntry::Mtree(info),
-                ))))
-            }
-            INSTALL_SCRIPTLET_FILE_NAME => {
-                let mut scriptlet = String::new();
-                entry
-                    .read_to_string(&mut scriptlet)
-                    .map_err(|source| crate::Error::IoRead {
-                        context: t!("error-io-read-install-scriptlet"),
-                        source,
-                    })?;
-                Ok(Some(PackageEntry::InstallScriptlet(scriptlet)))
-            }
-            _ => Ok(None),
-        }
+        let path = entry.path().to_path_buf();
+        read_package_entry(&path, &mut entry)
     }
 }
 
@@ -686,23 +750,24 @@ impl<'c> PackageReader<'c> {
         Self(tarball_reader)
     }
 
-    fn is_scriplet_file(entry: &TarballEntry) -> bool {
-        let path = entry.path().to_string_lossy();
-        path.as_ref() == INSTALL_SCRIPTLET_FILE_NAME
+    /// Sets the `callback` that is called for each entry read from the package.
+    ///
+    /// The callback receives the amount of bytes of the uncompressed tar stream read so far.
+    /// See [`TarballReader::with_progress_callback`].
+    pub fn with_progress_callback(self, callback: impl FnMut(u64) + 'c) -> Self {
+        Self(self.0.with_progress_callback(callback))
     }
 
-    fn is_metadata_file(entry: &TarballEntry) -> bool {
-        let metadata_file_names = [
-            MetadataFileName::PackageInfo.as_ref(),
-            MetadataFileName::BuildInfo.as_ref(),
-            MetadataFileName::Mtree.as_ref(),
-        ];
-        let path = entry.path().to_string_lossy();
-        metadata_file_names.contains(&path.as_ref())
+    /// Sets the [`CancellationToken`] that is checked before each entry is read from the package.
+    ///
+    /// Once `token` is cancelled, iterating over the entries of the package fails.
+    /// See [`TarballReader::with_cancellation`].
+    pub fn with_cancellation(self, token: CancellationToken) -> Self {
+        Self(self.0.with_cancellation(token))
     }
 
     fn is_data_file(entry: &TarballEntry) -> bool {
-        !Self::is_scriplet_file(entry) && !Self::is_metadata_file(entry)
+        !is_package_entry_path(entry.path())
     }
 
     /// Returns an iterator over the raw entries of the package's tar archive.
@@ -997,6 +1062,19 @@ impl Package {
         })
     }
 
+    /// Returns the [`PackageFileName`] of the [`Package`].
+    pub fn file_name(&self) -> &PackageFileName {
+        &self.file_name
+    }
+
+    /// Returns the compression algorithm of the [`Package`].
+    ///
+    /// The compression algorithm is derived from the file extension of the package file.
+    /// Returns [`None`] if the package file is not compressed (i.e. a `.pkg.tar` file).
+    pub fn compression(&self) -> Option<CompressionAlgorithmFileExtension> {
+        self.file_name.compression()
+    }
+
     /// Returns the absolute path of the [`Package`].
     pub fn to_path_buf(&self) -> PathBuf {
         self.parent_dir.join(self.file_name.to_path_buf())
@@ -1091,6 +1169,42 @@ impl Package {
         reader.read_install_scriptlet()
     }
 
+    /// Returns the content of the entry at `path` in the package archive.
+    ///
+    /// Returns [`None`] if the package archive contains no entry at `path`.
+    ///
+    /// If the package file is compressed using the [Zstandard Seekable Format], only the parts of
+    /// the archive that contain the entry headers and the requested content are decompressed.
+    /// Otherwise, the package archive is read sequentially up to the requested entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the package file cannot be opened,
+    /// - reading the entries of the package archive fails,
+    /// - or reading the content of the entry fails.
+    ///
+    /// [Zstandard Seekable Format]: https://github.com/facebook/zstd/blob/dev/contrib/seekable_format/zstd_seekable_compression_format.md
+    pub fn read_entry_content(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<Option<Vec<u8>>, crate::Error> {
+        let package_path = self.to_path_buf();
+        if let Ok(mut reader) = SeekableTarballReader::try_from(package_path.as_path()) {
+            return Ok(reader.read_entry_content(path)?);
+        }
+
+        let mut reader = PackageReader::try_from(self.clone())?;
+        for entry in reader.raw_entries()? {
+            let mut entry = entry?;
+            if entry.path() == path.as_ref() {
+                return Ok(Some(entry.content()?));
+            }
+        }
+        Ok(None)
+    }
+
     /// Creates a [`PackageReader`] for the package.
     ///
     /// Convenience wrapper for [`PackageReader::try_from`].
@@ -1101,6 +1215,57 @@ impl Package {
     pub fn into_reader<'c>(self) -> Result<PackageReader<'c>, crate::Error> {
         PackageReader::try_from(self)
     }
+
+    /// Creates a new [`Package`] from a [`PackageCreationConfig`] and reports progress to
+    /// `observer`.
+    ///
+    /// Before creating a [`Package`], guarantees the on-disk file consistency with the
+    /// help of available [`Mtree`] data.
+    /// Each file is passed to [`PackageBuildObserver::on_entry_added`] before it is appended to the
+    /// package file and [`PackageBuildObserver::on_compression_finished`] is called once the
+    /// package file is finished.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - creating a [`TarballBuilder`] fails,
+    /// - creating a compressed or uncompressed package file fails,
+    /// - validating any of the paths using ALPM-MTREE data (available through `config`) fails,
+    /// - the [`CancellationToken`] of `config` has been cancelled,
+    /// - `observer` rejects one of the files,
+    /// - appending files to a compressed or uncompressed package file fails,
+    /// - finishing a compressed or uncompressed package file fails,
+    /// - or creating a [`Package`] fails.
+    pub fn create_with_observer(
+        config: &PackageCreationConfig,
+        observer: &mut impl PackageBuildObserver,
+    ) -> Result<Self, crate::Error> {
+        let filename = PackageFileName::from(config);
+        let parent_dir: ExistingAbsoluteDir = config.output_dir().into();
+        let output_path = config.output_dir().join(filename.to_path_buf());
+
+        // Create the output file.
+        let file = File::create(output_path.as_path()).map_err(|source| crate::Error::IoPath {
+            path: output_path.clone(),
+            context: t!("error-io-create-package-file"),
+            source,
+        })?;
+
+        let mut builder = TarballBuilder::new(file, config.compression())?.with_statistics()?;
+        builder.follow_symlinks(false);
+        builder = append_relative_files(
+            builder,
+            config.package_input().mtree()?,
+            &config.package_input().input_paths()?,
+            observer,
+            config.cancellation(),
+        )?;
+        let statistics = builder.finish_with_statistics()?;
+        observer.on_compression_finished(output_path.as_path(), &statistics);
+
+        Self::new(filename, parent_dir)
+    }
 }
 
 impl TryFrom<&Path> for Package {
@@ -1138,41 +1303,13 @@ impl TryFrom<&PackageCreationConfig> for Package {
 
     /// Creates a new [`Package`] from a [`PackageCreationConfig`].
     ///
-    /// Before creating a [`Package`], guarantees the on-disk file consistency with the
-    /// help of available [`Mtree`] data.
+    /// Delegates to [`Package::create_with_observer`] without observing the creation.
     ///
     /// # Errors
     ///
-    /// Returns an error if
-    ///
-    /// - creating a [`TarballBuilder`] fails,
-    /// - creating a compressed or uncompressed package file fails,
-    /// - validating any of the paths using ALPM-MTREE data (available through `value`) fails,
-    /// - appending files to a compressed or uncompressed package file fails,
-    /// - finishing a compressed or uncompressed package file fails,
-    /// - or creating a [`Package`] fails.
+    /// Returns an error if [`Package::create_with_observer`] fails.
     fn try_from(value: &PackageCreationConfig) -> Result<Self, Self::Error> {
-        let filename = PackageFileName::from(value);
-        let parent_dir: ExistingAbsoluteDir = value.output_dir().into();
-        let output_path = value.output_dir().join(filename.to_path_buf());
-
-        // Create the output file.
-        let file = File::create(output_path.as_path()).map_err(|source| crate::Error::IoPath {
-            path: output_path.clone(),
-            context: t!("error-io-create-package-file"),
-            source,
-        })?;
-
-        let mut builder = TarballBuilder::new(file, value.compression())?;
-        builder.inner_mut().follow_symlinks(false);
-        builder = append_relative_files(
-            builder,
-            value.package_input().mtree()?,
-            &value.package_input().input_paths()?,
-        )?;
-        builder.finish()?;
-
-        Self::new(filename, parent_dir)
+        Self::create_with_observer(value, &mut ())
     }
 }
 
@@ -0,0 +1,496 @@
+//! Policies for the contents of [alpm-package] files.
+//!
+//! A [`ContentPolicy`] describes which data files are acceptable in a package (e.g. no files in
+//! `/usr/local`, only files owned by root, no unexpected setuid binaries).
+//! It can be evaluated while creating a package (using a [`ContentPolicyChecker`] as
+//! [`PackageBuildObserver`]) and when inspecting an existing package (using
+//! [`PackageReader::check_content_policy`] or [`Package::check_content_policy`]).
+//! Both return a [`ContentPolicyReport`] that lists all [`ContentViolation`]s.
+//!
+//! [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+
+use std::{
+    fmt::Display,
+    fs::Metadata,
+    os::unix::fs::MetadataExt,
+    path::{Component, Path, PathBuf},
+};
+
+use fluent_i18n::t;
+
+use crate::{Package, PackageBuildObserver, PackageReader, package::is_package_entry_path};
+
+/// The setuid and setgid bits of a file mode.
+const SETUID_SETGID: u32 = 0o6000;
+
+/// A policy for the data files of an [alpm-package].
+///
+/// A new [`ContentPolicy`] is permissive and all checks have to be enabled explicitly.
+/// [`ContentPolicy::recommended`] provides a policy that enables the checks that apply to most
+/// packages.
+///
+/// All paths are considered relative to the root of the package (e.g. `usr/local` or
+/// `/usr/local` both refer to the `usr/local` directory of a package).
+///
+/// # Examples
+///
+/// ```
+/// use alpm_package::policy::ContentPolicy;
+///
+/// let policy = ContentPolicy::recommended()
+///     .allow_setuid("usr/bin/sudo")
+///     .max_file_size(512 * 1024 * 1024);
+/// ```
+///
+/// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+#[derive(Clone, Debug, Default)]
+pub struct ContentPolicy {
+    forbidden_paths: Vec<PathBuf>,
+    ownership: Option<(u64, u64)>,
+    restrict_setuid: bool,
+    setuid_allowlist: Vec<PathBuf>,
+    max_file_count: Option<usize>,
+    max_file_size: Option<u64>,
+    reject_empty: bool,
+}
+
+impl ContentPolicy {
+    /// Creates a new, permissive [`ContentPolicy`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`ContentPolicy`] with the checks that apply to most packages.
+    ///
+    /// - The paths `usr/local` and `home` are forbidden.
+    /// - All entries are required to be owned by `root:root`.
+    /// - Setuid and setgid entries are forbidden, unless allowed using
+    ///   [`ContentPolicy::allow_setuid`].
+    ///
+    /// Empty packages are not rejected, as meta packages do not contain any files.
+    pub fn recommended() -> Self {
+        Self::new()
+            .forbid_path("usr/local")
+            .forbid_path("home")
+            .require_ownership(0, 0)
+            .restrict_setuid(true)
+    }
+
+    /// Adds a path that must neither exist in a package, nor contain any entries.
+    pub fn forbid_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.forbidden_paths.push(normalize(path.as_ref()));
+        self
+    }
+
+    /// Requires all entries to be owned by the user with `uid` and the group with `gid`.
+    pub fn require_ownership(mut self, uid: u64, gid: u64) -> Self {
+        self.ownership = Some((uid, gid));
+        self
+    }
+
+    /// Sets whether entries with the setuid or setgid bit are only allowed if they are in the
+    /// allowlist.
+    pub fn restrict_setuid(mut self, restrict_setuid: bool) -> Self {
+        self.restrict_setuid = restrict_setuid;
+        self
+    }
+
+    /// Adds a path to the allowlist for entries with the setuid or setgid bit.
+    ///
+    /// Implies [`ContentPolicy::restrict_setuid`].
+    pub fn allow_setuid(mut self, path: impl AsRef<Path>) -> Self {
+        self.restrict_setuid = true;
+        self.setuid_allowlist.push(normalize(path.as_ref()));
+        self
+    }
+
+    /// Sets the maximum number of regular files in a package.
+    pub fn max_file_count(mut self, max_file_count: usize) -> Self {
+        self.max_file_count = Some(max_file_count);
+        self
+    }
+
+    /// Sets the maximum size of a single regular file in bytes.
+    pub fn max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = Some(max_file_size);
+        self
+    }
+
+    /// Sets whether packages without any regular files are considered a violation.
+    pub fn reject_empty(mut self, reject_empty: bool) -> Self {
+        self.reject_empty = reject_empty;
+        self
+    }
+}
+
+/// Returns `path` without any root or current directory components.
+fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| matches!(component, Component::Normal(_) | Component::ParentDir))
+        .collect()
+}
+
+/// A violation of a [`ContentPolicy`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ContentViolation {
+    /// An entry is located in a forbidden path.
+    ForbiddenPath {
+        /// The path of the entry.
+        path: PathBuf,
+        /// The forbidden path.
+        forbidden: PathBuf,
+    },
+    /// An entry is not owned by the required user and group.
+    UnexpectedOwnership {
+        /// The path of the entry.
+        path: PathBuf,
+        /// The user ID of the entry.
+        uid: u64,
+        /// The group ID of the entry.
+        gid: u64,
+    },
+    /// An entry has the setuid or setgid bit, but is not in the allowlist.
+    SetuidNotAllowed {
+        /// The path of the entry.
+        path: PathBuf,
+        /// The permissions of the entry.
+        mode: u32,
+    },
+    /// A regular file is larger than allowed.
+    FileTooLarge {
+        /// The path of the file.
+        path: PathBuf,
+        /// The size of the file in bytes.
+        size: u64,
+        /// The maximum size in bytes.
+        max: u64,
+    },
+    /// A package contains more regular files than allowed.
+    TooManyFiles {
+        /// The number of regular files.
+        count: usize,
+        /// The maximum number of regular files.
+        max: usize,
+    },
+    /// A package does not contain any regular files.
+    EmptyPackage,
+}
+
+impl Display for ContentViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Self::ForbiddenPath { path, forbidden } => t!("content-violation-forbidden-path", {
+                "path" => path,
+                "forbidden" => forbidden,
+            }),
+            Self::UnexpectedOwnership { path, uid, gid } => {
+                t!("content-violation-unexpected-ownership", {
+                    "path" => path,
+                    "uid" => uid.to_string(),
+                    "gid" => gid.to_string(),
+                })
+            }
+            Self::SetuidNotAllowed { path, mode } => t!("content-violation-setuid-not-allowed", {
+                "path" => path,
+                "mode" => format!("{mode:04o}"),
+            }),
+            Self::FileTooLarge { path, size, max } => t!("content-violation-file-too-large", {
+                "path" => path,
+                "size" => size.to_string(),
+                "max" => max.to_string(),
+            }),
+            Self::TooManyFiles { count, max } => t!("content-violation-too-many-files", {
+                "count" => count.to_string(),
+                "max" => max.to_string(),
+            }),
+            Self::EmptyPackage => t!("content-violation-empty-package"),
+        };
+        write!(f, "{message}")
+    }
+}
+
+/// The report created when evaluating a [`ContentPolicy`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ContentPolicyReport {
+    /// All violations, in the order in which the entries have been checked.
+    pub violations: Vec<ContentViolation>,
+}
+
+impl ContentPolicyReport {
+    /// Returns `true` if there are no violations.
+    pub fn is_compliant(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// The information about an entry that is relevant for a [`ContentPolicy`].
+#[derive(Clone, Copy, Debug)]
+struct EntryInfo<'a> {
+    path: &'a Path,
+    is_file: bool,
+    mode: u32,
+    uid: u64,
+    gid: u64,
+    size: u64,
+}
+
+/// Evaluates a [`ContentPolicy`] for the entries of a package.
+///
+/// Implements [`PackageBuildObserver`], so that a [`ContentPolicy`] can be evaluated during the
+/// creation of a package.
+/// Violations are only collected and do not abort the creation of the package.
+/// The metadata files and the install scriptlet of a package are not checked.
+///
+/// # Examples
+///
+/// ```no_run
+/// use alpm_package::{
+///     Package,
+///     PackageCreationConfig,
+///     policy::{ContentPolicy, ContentPolicyChecker},
+/// };
+///
+/// # fn create(config: &PackageCreationConfig) -> Result<(), alpm_package::Error> {
+/// let policy = ContentPolicy::recommended();
+/// let mut checker = ContentPolicyChecker::new(&policy);
+/// Package::create_with_observer(config, &mut checker)?;
+///
+/// for violation in checker.finish().violations {
+///     eprintln!("{violation}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ContentPolicyChecker<'a> {
+    policy: &'a ContentPolicy,
+    file_count: usize,
+    violations: Vec<ContentViolation>,
+}
+
+impl<'a> ContentPolicyChecker<'a> {
+    /// Creates a new [`ContentPolicyChecker`] for `policy`.
+    pub fn new(policy: &'a ContentPolicy) -> Self {
+        Self {
+            policy,
+            file_count: 0,
+            violations: Vec::new(),
+        }
+    }
+
+    /// Checks a single entry and records all violations.
+    fn check_entry(&mut self, entry: EntryInfo) {
+        let path = normalize(entry.path);
+        if path.as_os_str().is_empty() || is_package_entry_path(&path) {
+            return;
+        }
+
+        if let Some(forbidden) = self
+            .policy
+            .forbidden_paths
+            .iter()
+            .find(|forbidden| path.starts_with(forbidden))
+        {
+            self.violations.push(ContentViolation::ForbiddenPath {
+                path: path.clone(),
+                forbidden: forbidden.clone(),
+            });
+        }
+
+        if let Some((uid, gid)) = self.policy.ownership
+            && (entry.uid != uid || entry.gid != gid)
+        {
+            self.violations.push(ContentViolation::UnexpectedOwnership {
+                path: path.clone(),
+                uid: entry.uid,
+                gid: entry.gid,
+            });
+        }
+
+        if self.policy.restrict_setuid
+            && entry.mode & SETUID_SETGID != 0
+            && !self.policy.setuid_allowlist.contains(&path)
+        {
+            self.violations.push(ContentViolation::SetuidNotAllowed {
+                path: path.clone(),
+                mode: entry.mode & 0o7777,
+            });
+        }
+
+        if entry.is_file {
+            self.file_count += 1;
+            if let Some(max) = self.policy.max_file_size
+                && entry.size > max
+            {
+                self.violations.push(ContentViolation::FileTooLarge {
+                    path,
+                    size: entry.size,
+                    max,
+                });
+            }
+        }
+    }
+
+    /// Finishes the evaluation and returns the [`ContentPolicyReport`].
+    ///
+    /// Adds the violations that concern the package as a whole (i.e. the number of files).
+    pub fn finish(mut self) -> ContentPolicyReport {
+        if let Some(max) = self.policy.max_file_count
+            && self.file_count > max
+        {
+            self.violations.push(ContentViolation::TooManyFiles {
+                count: self.file_count,
+                max,
+            });
+        }
+        if self.policy.reject_empty && self.file_count == 0 {
+            self.violations.push(ContentViolation::EmptyPackage);
+        }
+
+        ContentPolicyReport {
+            violations: self.violations,
+        }
+    }
+}
+
+impl PackageBuildObserver for ContentPolicyChecker<'_> {
+    fn on_entry_added(&mut self, path: &Path, metadata: &Metadata) -> Result<(), String> {
+        self.check_entry(EntryInfo {
+            path,
+            is_file: metadata.is_file(),
+            mode: metadata.mode(),
+            uid: u64::from(metadata.uid()),
+            gid: u64::from(metadata.gid()),
+            size: metadata.len(),
+        });
+        Ok(())
+    }
+}
+
+impl PackageReader<'_> {
+    /// Evaluates `policy` for all data entries of the package.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - reading the entries of the package fails,
+    /// - or the header of an entry cannot be read.
+    pub fn check_content_policy(
+        &mut self,
+        policy: &ContentPolicy,
+    ) -> Result<ContentPolicyReport, crate::Error> {
+        let mut checker = ContentPolicyChecker::new(policy);
+        for entry in self.data_entries()? {
+            let entry = entry?;
+            let header = entry.raw().header();
+            let read_error = |source| crate::Error::IoRead {
+                context: t!("error-io-read-entry-header"),
+                source,
+            };
+            checker.check_entry(EntryInfo {
+                path: entry.path(),
+                is_file: entry.is_file(),
+                mode: header.mode().map_err(read_error)?,
+                uid: header.uid().map_err(read_error)?,
+                gid: header.gid().map_err(read_error)?,
+                size: header.size().map_err(read_error)?,
+            });
+        }
+
+        Ok(checker.finish())
+    }
+}
+
+impl Package {
+    /// Evaluates `policy` for all data entries of the package.
+    ///
+    /// Convenience wrapper for [`PackageReader::check_content_policy`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - no [`PackageReader`] can be created for the package,
+    /// - or [`PackageReader::check_content_policy`] fails.
+    pub fn check_content_policy(
+        &self,
+        policy: &ContentPolicy,
+    ) -> Result<ContentPolicyReport, crate::Error> {
+        PackageReader::try_from(self.to_path_buf().as_path())?.check_content_policy(policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Returns the violations for a single entry.
+    fn check(policy: &ContentPolicy, entry: EntryInfo) -> Vec<ContentViolation> {
+        let mut checker = ContentPolicyChecker::new(policy);
+        checker.check_entry(entry);
+        checker.violations
+    }
+
+    /// Ensures that forbidden paths match regardless of leading slashes.
+    #[rstest]
+    #[case("usr/local/bin/foo", true)]
+    #[case("/usr/local", true)]
+    #[case("usr/localfoo", false)]
+    #[case("usr/bin/foo", false)]
+    fn forbidden_path(#[case] path: &str, #[case] violation: bool) {
+        let policy = ContentPolicy::new().forbid_path("/usr/local");
+        let entry = EntryInfo {
+            path: Path::new(path),
+            is_file: true,
+            mode: 0o644,
+            uid: 0,
+            gid: 0,
+            size: 0,
+        };
+
+        assert_eq!(!check(&policy, entry).is_empty(), violation);
+    }
+
+    /// Ensures that setuid and setgid entries are only allowed if they are in the allowlist.
+    #[rstest]
+    #[case("usr/bin/sudo", 0o4755, false)]
+    #[case("usr/bin/foo", 0o4755, true)]
+    #[case("usr/bin/foo", 0o2755, true)]
+    #[case("usr/bin/foo", 0o755, false)]
+    fn setuid_allowlist(#[case] path: &str, #[case] mode: u32, #[case] violation: bool) {
+        let policy = ContentPolicy::recommended().allow_setuid("/usr/bin/sudo");
+        let entry = EntryInfo {
+            path: Path::new(path),
+            is_file: true,
+            mode,
+            uid: 0,
+            gid: 0,
+            size: 0,
+        };
+
+        assert_eq!(!check(&policy, entry).is_empty(), violation);
+    }
+
+    /// Ensures that metadata files are ignored and that package-wide limits are checked.
+    #[test]
+    fn package_limits() {
+        let policy = ContentPolicy::new().max_file_count(0).reject_empty(true);
+        let mut checker = ContentPolicyChecker::new(&policy);
+        checker.check_entry(EntryInfo {
+            path: Path::new(".PKGINFO"),
+            is_file: true,
+            mode: 0o644,
+            uid: 1000,
+            gid: 1000,
+            size: 0,
+        });
+
+        assert_eq!(
+            checker.finish().violations,
+            vec![ContentViolation::EmptyPackage]
+        );
+    }
+}
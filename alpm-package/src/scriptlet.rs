@@ -2,7 +2,14 @@
 //!
 //! [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
 
-use std::{fs::File, io::Read, path::Path};
+use std::{
+    fs::{File, read_to_string},
+    io::Read,
+    iter::Peekable,
+    mem::take,
+    path::{Path, PathBuf},
+    str::Chars,
+};
 
 use fluent_i18n::t;
 
@@ -73,6 +80,571 @@ pub fn check_scriptlet(path: impl AsRef<Path>) -> Result<(), Error> {
     })
 }
 
+/// Absolute paths outside of a package, that an [alpm-install-scriptlet] may always reference.
+///
+/// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
+const ALLOWED_ABSOLUTE_PATHS: &[&str] = &["/dev/null"];
+
+/// The checks to run when validating an [`InstallScriptlet`].
+///
+/// By default, no checks are run.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ScriptletValidation {
+    /// Whether to check the shell syntax of the scriptlet.
+    pub syntax: bool,
+    /// Whether to reject functions other than those defined by [alpm-install-scriptlet].
+    ///
+    /// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
+    pub function_names: bool,
+    /// Whether to reject absolute paths that are not part of the package.
+    pub absolute_paths: bool,
+}
+
+impl ScriptletValidation {
+    /// Creates a [`ScriptletValidation`] that runs all checks.
+    pub fn all() -> Self {
+        Self {
+            syntax: true,
+            function_names: true,
+            absolute_paths: true,
+        }
+    }
+}
+
+/// An [alpm-install-scriptlet] file.
+///
+/// Tracks the contents of the file, as well as the functions defined in it.
+///
+/// # Note
+///
+/// The file is _neither sourced nor evaluated_.
+/// Its shell syntax is only analyzed to the extent needed for the checks of
+/// [`InstallScriptlet::validate`] (comparable to `bash -n`, but without support for all bash
+/// features).
+///
+/// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
+#[derive(Clone, Debug)]
+pub struct InstallScriptlet {
+    path: PathBuf,
+    content: String,
+    functions: Vec<String>,
+    syntax_error: Option<String>,
+}
+
+impl InstallScriptlet {
+    /// Creates a new [`InstallScriptlet`] from the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` can not be read to String.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let content = read_to_string(path).map_err(|source| Error::IoPath {
+            path: path.to_path_buf(),
+            context: t!("error-io-read-to-string"),
+            source,
+        })?;
+
+        let (functions, syntax_error) = match analyze(&content) {
+            Ok(functions) => (functions, None),
+            Err(error) => (Vec::new(), Some(error)),
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            content,
+            functions,
+            syntax_error,
+        })
+    }
+
+    /// Returns the path of the scriptlet.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the contents of the scriptlet.
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Returns the names of all functions defined in the scriptlet.
+    ///
+    /// If the syntax of the scriptlet is invalid, no functions are returned.
+    pub fn functions(&self) -> &[String] {
+        &self.functions
+    }
+
+    /// Returns all absolute paths referenced in the scriptlet.
+    ///
+    /// Comments are ignored.
+    pub fn absolute_paths(&self) -> Vec<&str> {
+        let mut paths = Vec::new();
+        for line in self.content.lines() {
+            let line = match line.find(" #") {
+                Some(index) => &line[..index],
+                None if line.trim_start().starts_with('#') => continue,
+                None => line,
+            };
+
+            let mut previous = None;
+            for (index, char) in line.char_indices() {
+                let starts_path = char == '/'
+                    && previous.is_none_or(|previous: char| {
+                        previous.is_whitespace() || "\"'=<>(:`".contains(previous)
+                    })
+                    && line[index + 1..]
+                        .chars()
+                        .next()
+                        .is_some_and(is_path_char_start);
+                if starts_path {
+                    let end = line[index + 1..]
+                        .find(|char: char| !is_path_char(char))
+                        .map_or(line.len(), |end| index + 1 + end);
+                    paths.push(&line[index..end]);
+                }
+                previous = Some(char);
+            }
+        }
+
+        paths
+    }
+
+    /// Validates the scriptlet using the checks selected in `validation`.
+    ///
+    /// The relative paths of all files and directories in the package are provided using
+    /// `package_paths`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - [`ScriptletValidation::syntax`] is set and the shell syntax of the scriptlet is invalid,
+    /// - [`ScriptletValidation::function_names`] is set and the scriptlet defines functions other
+    ///   than those defined by [alpm-install-scriptlet],
+    /// - or [`ScriptletValidation::absolute_paths`] is set and the scriptlet references absolute
+    ///   paths that are not part of `package_paths`.
+    ///
+    /// [alpm-install-scriptlet]: https://alpm.archlinux.page/specifications/alpm-install-scriptlet.5.html
+    pub fn validate(
+        &self,
+        validation: &ScriptletValidation,
+        package_paths: &[PathBuf],
+    ) -> Result<(), Error> {
+        if validation.syntax
+            && let Some(error) = &self.syntax_error
+        {
+            return Err(Error::InstallScriptlet {
+                path: self.path.clone(),
+                context: format!("its syntax is invalid: {error}"),
+            });
+        }
+
+        if validation.function_names {
+            let unknown: Vec<&str> = self
+                .functions
+                .iter()
+                .map(String::as_str)
+                .filter(|name| !REQUIRED_FUNCTION_SIGNATURES.contains(name))
+                .collect();
+            if !unknown.is_empty() {
+                return Err(Error::InstallScriptlet {
+                    path: self.path.clone(),
+                    context: format!(
+                        "it defines the functions {}, but only {} are allowed",
+                        unknown.join(", "),
+                        REQUIRED_FUNCTION_SIGNATURES.join(", ")
+                    ),
+                });
+            }
+        }
+
+        if validation.absolute_paths {
+            let outside: Vec<&str> = self
+                .absolute_paths()
+                .into_iter()
+                .filter(|path| !is_package_path(path, package_paths))
+                .collect();
+            if !outside.is_empty() {
+                return Err(Error::InstallScriptlet {
+                    path: self.path.clone(),
+                    context: format!(
+                        "it references absolute paths outside of the package: {}",
+                        outside.join(", ")
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns whether `char` may start a path component of an absolute path in a scriptlet.
+fn is_path_char_start(char: char) -> bool {
+    char.is_ascii_alphanumeric() || "._".contains(char)
+}
+
+/// Returns whether `char` may be part of an absolute path in a scriptlet.
+fn is_path_char(char: char) -> bool {
+    char.is_ascii_alphanumeric() || "._+@/-".contains(char)
+}
+
+/// Returns whether the absolute `path` refers to a file or directory in `package_paths`.
+///
+/// Paths in [`ALLOWED_ABSOLUTE_PATHS`] are always considered to be part of a package.
+fn is_package_path(path: &str, package_paths: &[PathBuf]) -> bool {
+    if ALLOWED_ABSOLUTE_PATHS.contains(&path) {
+        return true;
+    }
+
+    let relative = Path::new(path.trim_start_matches('/').trim_end_matches('/'));
+    package_paths
+        .iter()
+        .any(|package_path| package_path.starts_with(relative))
+}
+
+/// A token of shell code.
+#[derive(Debug, Eq, PartialEq)]
+enum Token {
+    /// A word, including all of its quoted parts.
+    Word(String),
+    /// A control operator (e.g. `;`, `&&` or `(`).
+    Operator(&'static str),
+    /// A redirection operator (e.g. `>` or `<<`).
+    Redirection,
+    /// The end of a line.
+    Newline,
+}
+
+/// A construct of shell code, that is opened and must be closed again.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Construct {
+    /// A group command (`{ ...; }`).
+    Group,
+    /// A subshell or function parentheses (`( ... )`).
+    Subshell,
+    /// A conditional (`if ...; fi`).
+    If,
+    /// A loop (`for`, `select`, `until` or `while` ... `done`).
+    Loop,
+    /// A case statement (`case ... esac`).
+    Case,
+}
+
+impl Construct {
+    /// Returns the token that closes the construct.
+    fn closing(&self) -> &'static str {
+        match self {
+            Self::Group => "}",
+            Self::Subshell => ")",
+            Self::If => "fi",
+            Self::Loop => "done",
+            Self::Case => "esac",
+        }
+    }
+}
+
+/// Analyzes the shell code in `content`.
+///
+/// Returns the names of all functions defined in `content`.
+///
+/// # Errors
+///
+/// Returns a description of the issue, if the syntax of `content` is invalid (e.g. due to
+/// unterminated quotes or unbalanced compound commands).
+fn analyze(content: &str) -> Result<Vec<String>, String> {
+    let tokens = tokenize(content)?;
+    let mut functions = Vec::new();
+    let mut stack: Vec<Construct> = Vec::new();
+    let mut command_start = true;
+
+    let mut index = 0;
+    while let Some(token) = tokens.get(index) {
+        index += 1;
+        match token {
+            Token::Newline | Token::Operator(";" | ";;" | "&&" | "||" | "|" | "&") => {
+                command_start = true;
+            }
+            Token::Redirection => {}
+            Token::Operator("(") => {
+                stack.push(Construct::Subshell);
+                command_start = true;
+            }
+            Token::Operator(_) => {
+                // Closing parentheses (or case patterns).
+                match stack.last() {
+                    Some(Construct::Subshell) => {
+                        stack.pop();
+                    }
+                    Some(Construct::Case) => {}
+                    _ => return Err("found an unexpected \")\"".to_string()),
+                }
+                command_start = true;
+            }
+            Token::Word(word) if command_start => {
+                // A function definition of the form `name()`.
+                if tokens.get(index) == Some(&Token::Operator("("))
+                    && tokens.get(index + 1) == Some(&Token::Operator(")"))
+                {
+                    functions.push(word.clone());
+                    index += 2;
+                    continue;
+                }
+
+                match word.as_str() {
+                    "function" => {
+                        let Some(Token::Word(name)) = tokens.get(index) else {
+                            return Err("found a function definition without a name".to_string());
+                        };
+                        functions.push(name.clone());
+                        index += 1;
+                        if tokens.get(index) == Some(&Token::Operator("("))
+                            && tokens.get(index + 1) == Some(&Token::Operator(")"))
+                        {
+                            index += 2;
+                        }
+                    }
+                    "{" => stack.push(Construct::Group),
+                    "if" => stack.push(Construct::If),
+                    "for" | "select" | "until" | "while" => stack.push(Construct::Loop),
+                    "case" => {
+                        stack.push(Construct::Case);
+                        command_start = false;
+                    }
+                    "then" | "else" | "elif" | "do" | "!" => {}
+                    "}" | "fi" | "done" | "esac" => {
+                        let expected = stack.pop().map(|construct| construct.closing());
+                        if expected != Some(word.as_str()) {
+                            return Err(format!("found an unexpected \"{word}\""));
+                        }
+                        command_start = false;
+                    }
+                    _ => command_start = false,
+                }
+            }
+            Token::Word(_) => {}
+        }
+    }
+
+    if let Some(construct) = stack.last() {
+        return Err(format!("a \"{}\" is missing", construct.closing()));
+    }
+
+    Ok(functions)
+}
+
+/// Splits the shell code in `content` into [`Token`]s.
+///
+/// Comments and the bodies of here-documents are skipped.
+/// Command substitutions are analyzed recursively.
+///
+/// # Errors
+///
+/// Returns a description of the issue, if quotes, command substitutions or here-documents are not
+/// terminated.
+fn tokenize(content: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    let mut heredocs: Vec<(String, bool)> = Vec::new();
+    let mut chars = content.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            ' ' | '\t' => finish_word(&mut word, &mut tokens),
+            '\n' => {
+                finish_word(&mut word, &mut tokens);
+                tokens.push(Token::Newline);
+                for (delimiter, strip_tabs) in heredocs.drain(..) {
+                    loop {
+                        let mut line = String::new();
+                        let mut terminated = false;
+                        for char in chars.by_ref() {
+                            if char == '\n' {
+                                terminated = true;
+                                break;
+                            }
+                            line.push(char);
+                        }
+                        let line = if strip_tabs {
+                            line.trim_start_matches('\t')
+                        } else {
+                            line.as_str()
+                        };
+                        if line == delimiter {
+                            break;
+                        }
+                        if !terminated {
+                            return Err(format!(
+                                "the here-document delimited by \"{delimiter}\" is not terminated"
+                            ));
+                        }
+                    }
+                }
+            }
+            '#' if word.is_empty() => while chars.next_if(|char| *char != '\n').is_some() {},
+            '\\' => {
+                if let Some(char) = chars.next()
+                    && char != '\n'
+                {
+                    word.push(char);
+                }
+            }
+            '\'' => {
+                word.push(char);
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(char) => word.push(char),
+                        None => return Err("a single quote is not terminated".to_string()),
+                    }
+                }
+                word.push(char);
+            }
+            '"' | '`' => {
+                word.push(char);
+                loop {
+                    match chars.next() {
+                        Some('\\') => {
+                            if let Some(char) = chars.next() {
+                                word.push(char);
+                            }
+                        }
+                        Some('$') if char == '"' && chars.next_if_eq(&'(').is_some() => {
+                            analyze(&read_substitution(&mut chars)?)?;
+                        }
+                        Some(next) if next == char => break,
+                        Some(next) => word.push(next),
+                        None => {
+                            return Err(if char == '"' {
+                                "a double quote is not terminated".to_string()
+                            } else {
+                                "a backtick is not terminated".to_string()
+                            });
+                        }
+                    }
+                }
+                word.push(char);
+            }
+            '$' if chars.next_if_eq(&'(').is_some() => {
+                analyze(&read_substitution(&mut chars)?)?;
+                word.push_str("$()");
+            }
+            '$' if chars.next_if_eq(&'{').is_some() => {
+                word.push_str("${");
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(char) => word.push(char),
+                        None => return Err("a parameter expansion is not terminated".to_string()),
+                    }
+                }
+                word.push('}');
+            }
+            ';' | '&' | '|' | '(' | ')' => {
+                finish_word(&mut word, &mut tokens);
+                let operator = match (char, chars.peek()) {
+                    (';', Some(';')) => ";;",
+                    ('&', Some('&')) => "&&",
+                    ('|', Some('|')) => "||",
+                    (';', _) => ";",
+                    ('&', _) => "&",
+                    ('|', _) => "|",
+                    ('(', _) => "(",
+                    _ => ")",
+                };
+                if operator.len() == 2 {
+                    chars.next();
+                }
+                tokens.push(Token::Operator(operator));
+            }
+            '<' | '>' => {
+                // File descriptor numbers are part of the redirection.
+                if word.chars().all(|char| char.is_ascii_digit()) {
+                    word.clear();
+                }
+                finish_word(&mut word, &mut tokens);
+                if char == '<' && chars.next_if_eq(&'<').is_some() {
+                    if chars.next_if_eq(&'<').is_none() {
+                        let strip_tabs = chars.next_if_eq(&'-').is_some();
+                        while chars
+                            .next_if(|char| *char == ' ' || *char == '\t')
+                            .is_some()
+                        {}
+                        let mut delimiter = String::new();
+                        while let Some(char) = chars
+                            .next_if(|char| !char.is_whitespace() && !";&|<>()".contains(*char))
+                        {
+                            if char != '\'' && char != '"' && char != '\\' {
+                                delimiter.push(char);
+                            }
+                        }
+                        if delimiter.is_empty() {
+                            return Err("a here-document has no delimiter".to_string());
+                        }
+                        heredocs.push((delimiter, strip_tabs));
+                    }
+                } else {
+                    chars.next_if(|next| *next == char || *next == '&' || *next == '|');
+                }
+                tokens.push(Token::Redirection);
+            }
+            char => word.push(char),
+        }
+    }
+    finish_word(&mut word, &mut tokens);
+
+    if let Some((delimiter, _)) = heredocs.first() {
+        return Err(format!(
+            "the here-document delimited by \"{delimiter}\" is not terminated"
+        ));
+    }
+
+    Ok(tokens)
+}
+
+/// Appends `word` to `tokens` if it is not empty.
+fn finish_word(word: &mut String, tokens: &mut Vec<Token>) {
+    if !word.is_empty() {
+        tokens.push(Token::Word(take(word)));
+    }
+}
+
+/// Reads the contents of a command substitution from `chars`.
+///
+/// Expects the opening `$(` to be consumed already and consumes the closing `)`.
+///
+/// # Errors
+///
+/// Returns an error if the command substitution is not terminated.
+fn read_substitution(chars: &mut Peekable<Chars<'_>>) -> Result<String, String> {
+    let mut content = String::new();
+    let mut depth = 0;
+    let mut quote = None;
+
+    loop {
+        let Some(char) = chars.next() else {
+            return Err("a command substitution is not terminated".to_string());
+        };
+        match (char, quote) {
+            ('\\', _) => {
+                content.push(char);
+                if let Some(char) = chars.next() {
+                    content.push(char);
+                }
+                continue;
+            }
+            ('\'' | '"', None) => quote = Some(char),
+            (char, Some(open)) if char == open => quote = None,
+            ('(', None) => depth += 1,
+            (')', None) if depth == 0 => return Ok(content),
+            (')', None) => depth -= 1,
+            _ => {}
+        }
+        content.push(char);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::io::Write;
@@ -167,4 +739,145 @@ function post_remove"#;
 
         Ok(())
     }
+
+    const INSTALL_SCRIPTLET_COMPLEX: &str = r#"# An example scriptlet
+post_install() {
+  local version="$(echo "$1" | cut -d- -f1)"
+  if [[ "$version" == 1.* ]]; then
+    echo "Version 1 (${version%%.*})" >/dev/null 2>&1
+  elif (( $(vercmp "$1" 2.0) < 0 )); then
+    true
+  else
+    for file in /usr/share/example/*; do
+      printf '%s\n' "$file"
+    done
+  fi
+
+  case "$version" in
+    1|2) echo "old" ;;
+    *) echo "new" ;;
+  esac
+
+  cat <<-EOF
+	Thank you for installing example ) }
+	EOF
+}
+
+function post_upgrade {
+  post_install "$1"
+}
+"#;
+
+    /// Creates an [`InstallScriptlet`] from `scriptlet`.
+    fn install_scriptlet(scriptlet: &str) -> TestResult<(NamedTempFile, InstallScriptlet)> {
+        let mut file = NamedTempFile::new()?;
+        write!(file, "{scriptlet}")?;
+        let install_scriptlet = InstallScriptlet::from_file(file.path())?;
+
+        Ok((file, install_scriptlet))
+    }
+
+    #[rstest]
+    #[case::full(INSTALL_SCRIPTLET_FULL, &["pre_install", "post_install", "pre_upgrade", "post_upgrade", "pre_remove", "post_remove"])]
+    #[case::function_prefix(INSTALL_SCRIPTLET_FULL_FUNCTION_PREFIX, &["pre_install", "post_install", "pre_upgrade", "post_upgrade", "pre_remove", "post_remove"])]
+    #[case::complex(INSTALL_SCRIPTLET_COMPLEX, &["post_install", "post_upgrade"])]
+    #[case::empty(INSTALL_SCRIPTLET_EMPTY, &[])]
+    fn install_scriptlet_syntax_valid(
+        #[case] scriptlet: &str,
+        #[case] functions: &[&str],
+    ) -> TestResult {
+        let (_file, install_scriptlet) = install_scriptlet(scriptlet)?;
+
+        install_scriptlet.validate(
+            &ScriptletValidation {
+                syntax: true,
+                function_names: true,
+                ..Default::default()
+            },
+            &[],
+        )?;
+        assert_eq!(install_scriptlet.functions(), functions);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::single_quote("post_install() {\n  echo 'foo\n}")]
+    #[case::double_quote("post_install() {\n  echo \"foo\n}")]
+    #[case::missing_brace("post_install() {\n  true\n")]
+    #[case::missing_fi("post_install() {\n  if true; then\n    true\n}")]
+    #[case::missing_done("post_install() {\n  while true; do\n    true\n  fi\n}")]
+    #[case::unexpected_parenthesis("post_install() {\n  true )\n}")]
+    #[case::command_substitution("post_install() {\n  echo $(true\n}")]
+    #[case::heredoc("post_install() {\n  cat <<EOF\n  foo\n}")]
+    fn install_scriptlet_syntax_invalid(#[case] scriptlet: &str) -> TestResult {
+        let (_file, install_scriptlet) = install_scriptlet(scriptlet)?;
+
+        // Without the syntax check, validation succeeds.
+        install_scriptlet.validate(&ScriptletValidation::default(), &[])?;
+        assert!(matches!(
+            install_scriptlet.validate(
+                &ScriptletValidation {
+                    syntax: true,
+                    ..Default::default()
+                },
+                &[]
+            ),
+            Err(Error::InstallScriptlet { .. })
+        ));
+
+        Ok(())
+    }
+
+    /// Ensures that functions not defined by alpm-install-scriptlet are rejected.
+    #[test]
+    fn install_scriptlet_unknown_function() -> TestResult {
+        let (_file, install_scriptlet) =
+            install_scriptlet("_helper() {\n  true\n}\npost_install() {\n  _helper\n}\n")?;
+
+        assert_eq!(install_scriptlet.functions(), &["_helper", "post_install"]);
+        assert!(matches!(
+            install_scriptlet.validate(
+                &ScriptletValidation {
+                    function_names: true,
+                    ..Default::default()
+                },
+                &[]
+            ),
+            Err(Error::InstallScriptlet { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::package_file("post_install() {\n  /usr/bin/example --init\n}", true)]
+    #[case::package_dir("post_install() {\n  ls \"/usr/share/example/\"\n}", true)]
+    #[case::dev_null("post_install() {\n  true >/dev/null\n}", true)]
+    #[case::relative("post_install() {\n  cat usr/share/other\n}", true)]
+    #[case::comment("# see /etc/other\npost_install() {\n  true # /etc/other\n}", true)]
+    #[case::outside("post_install() {\n  rm -f /etc/other\n}", false)]
+    #[case::assignment("post_install() {\n  file=/usr/share/other\n}", false)]
+    fn install_scriptlet_absolute_paths(
+        #[case] scriptlet: &str,
+        #[case] valid: bool,
+    ) -> TestResult {
+        let (_file, install_scriptlet) = install_scriptlet(scriptlet)?;
+        let package_paths = vec![
+            PathBuf::from(".PKGINFO"),
+            PathBuf::from("usr/bin/example"),
+            PathBuf::from("usr/share/example/data.txt"),
+        ];
+
+        let result = install_scriptlet.validate(
+            &ScriptletValidation {
+                absolute_paths: true,
+                ..Default::default()
+            },
+            &package_paths,
+        );
+        assert_eq!(result.is_ok(), valid, "{result:?}");
+
+        Ok(())
+    }
 }
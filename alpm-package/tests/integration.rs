@@ -1,20 +1,27 @@
 //! Integration tests for `alpm-package`.
 
 use std::{
-    fs::{File, FileTimes, create_dir, create_dir_all, read},
+    fs::{File, FileTimes, Metadata, create_dir, create_dir_all, read},
     io::Write,
     os::unix::fs::symlink,
     path::{Path, PathBuf},
+    str::FromStr,
     time::SystemTime,
 };
 
-use alpm_compress::compression::{
-    Bzip2CompressionLevel,
-    CompressionSettings,
-    GzipCompressionLevel,
-    XzCompressionLevel,
-    ZstdCompressionLevel,
-    ZstdThreads,
+use alpm_buildinfo::BuildInfo;
+use alpm_compress::{
+    compression::{
+        Bzip2CompressionLevel,
+        CompressionSettings,
+        GzipCompressionLevel,
+        XzCompressionLevel,
+        ZstdCompressionLevel,
+        ZstdThreads,
+    },
+    progress::CancellationToken,
+    seekable::ZstdSeekableFrameSize,
+    tarball::CompressionStatistics,
 };
 use alpm_mtree::create_mtree_v2_from_input_dir;
 use alpm_package::{
@@ -27,8 +34,17 @@ use alpm_package::{
     PackageEntry,
     PackageInput,
     PackageReader,
+    builder::PackageBuilder,
+    observer::PackageBuildObserver,
+    policy::{ContentPolicy, ContentPolicyChecker, ContentViolation},
+};
+use alpm_pkginfo::PackageInfo;
+use alpm_types::{
+    Blake2b512Checksum,
+    CompressionAlgorithmFileExtension,
+    INSTALL_SCRIPTLET_FILE_NAME,
+    MetadataFileName,
 };
-use alpm_types::{Blake2b512Checksum, INSTALL_SCRIPTLET_FILE_NAME, MetadataFileName};
 use filetime::{FileTime, set_symlink_file_times};
 use log::{LevelFilter, debug};
 use rstest::rstest;
@@ -415,6 +431,264 @@ fn create_package_from_input(
     Ok(())
 }
 
+/// Ensures that [`PackageBuilder`] writes the metadata files and creates a package from them.
+#[test]
+fn package_builder_build() -> TestResult {
+    init_logger();
+
+    let temp_dir = TempDir::new()?;
+    let input_dir = temp_dir.path().join("input");
+    create_dir(&input_dir)?;
+    create_data_files(&input_dir)?;
+    let package_info = PackageInfo::from_str(VALID_PKGINFO_V2_DATA)?;
+    let build_info = BuildInfo::from_str(VALID_BUILDINFO_V2_DATA)?;
+
+    let artifacts = PackageBuilder::new(
+        InputDir::new(input_dir)?,
+        OutputDir::new(temp_dir.path().join("output"))?,
+    )
+    .compression(CompressionSettings::None)
+    .package_info(package_info.clone())
+    .build_info(build_info.clone())
+    .build()?;
+
+    let metadata = artifacts.package.into_reader()?.metadata()?;
+    assert_eq!(metadata.pkginfo, package_info);
+    assert_eq!(metadata.buildinfo, build_info);
+    assert_eq!(&metadata.mtree, artifacts.config.package_input().mtree()?);
+
+    Ok(())
+}
+
+/// Ensures that [`PackageBuilder`] creates package files with the file extension of the selected
+/// compression and records the compression in the [`PackageArtifacts`].
+///
+/// [`PackageArtifacts`]: alpm_package::builder::PackageArtifacts
+#[rstest]
+#[case::gzip(
+    CompressionSettings::Gzip { compression_level: GzipCompressionLevel::default() },
+    Some(CompressionAlgorithmFileExtension::Gzip),
+    "pkg.tar.gz",
+)]
+#[case::xz(
+    CompressionSettings::Xz { compression_level: XzCompressionLevel::default() },
+    Some(CompressionAlgorithmFileExtension::Xz),
+    "pkg.tar.xz",
+)]
+#[case::zstd(
+    CompressionSettings::Zstd { compression_level: ZstdCompressionLevel::default(), threads: ZstdThreads::all() },
+    Some(CompressionAlgorithmFileExtension::Zstd),
+    "pkg.tar.zst",
+)]
+#[case::none(CompressionSettings::None, None, "pkg.tar")]
+fn package_builder_build_compression(
+    #[case] compression: CompressionSettings,
+    #[case] algorithm: Option<CompressionAlgorithmFileExtension>,
+    #[case] extension: &str,
+) -> TestResult {
+    init_logger();
+
+    let temp_dir = TempDir::new()?;
+    let input_dir = temp_dir.path().join("input");
+    create_dir(&input_dir)?;
+    create_data_files(&input_dir)?;
+
+    let artifacts = PackageBuilder::new(
+        InputDir::new(input_dir)?,
+        OutputDir::new(temp_dir.path().join("output"))?,
+    )
+    .compression(compression.clone())
+    .package_info(PackageInfo::from_str(VALID_PKGINFO_V2_DATA)?)
+    .build_info(BuildInfo::from_str(VALID_BUILDINFO_V2_DATA)?)
+    .build()?;
+
+    assert_eq!(artifacts.compression(), &compression);
+    assert_eq!(artifacts.package.compression(), algorithm);
+    assert!(
+        artifacts
+            .package
+            .to_path_buf()
+            .to_string_lossy()
+            .ends_with(extension)
+    );
+
+    // The package file can be read using the compression derived from its file extension.
+    let package = Package::try_from(artifacts.package.to_path_buf().as_path())?;
+    assert_eq!(package.compression(), algorithm);
+    package.into_reader()?.metadata()?;
+
+    Ok(())
+}
+
+/// A [`PackageBuildObserver`] that records all hook calls and optionally rejects an entry.
+#[derive(Debug, Default)]
+struct RecordingObserver {
+    entries: Vec<PathBuf>,
+    metadata_files: Vec<MetadataFileName>,
+    statistics: Option<CompressionStatistics>,
+    reject: Option<PathBuf>,
+}
+
+impl PackageBuildObserver for RecordingObserver {
+    fn on_entry_added(&mut self, path: &Path, _metadata: &Metadata) -> Result<(), String> {
+        if self.reject.as_deref() == Some(path) {
+            return Err("rejected by test".to_string());
+        }
+        self.entries.push(path.to_path_buf());
+        Ok(())
+    }
+
+    fn on_metadata_generated(&mut self, file: MetadataFileName, _path: &Path) {
+        self.metadata_files.push(file);
+    }
+
+    fn on_compression_finished(&mut self, _path: &Path, statistics: &CompressionStatistics) {
+        self.statistics = Some(statistics.clone());
+    }
+}
+
+/// Ensures that [`PackageBuilder::build_with_observer`] calls all hooks of a
+/// [`PackageBuildObserver`].
+#[test]
+fn package_builder_build_with_observer() -> TestResult {
+    init_logger();
+
+    let temp_dir = TempDir::new()?;
+    let input_dir = temp_dir.path().join("input");
+    create_dir(&input_dir)?;
+    create_data_files(&input_dir)?;
+
+    let mut observer = RecordingObserver::default();
+    PackageBuilder::new(
+        InputDir::new(input_dir)?,
+        OutputDir::new(temp_dir.path().join("output"))?,
+    )
+    .compression(CompressionSettings::None)
+    .package_info(PackageInfo::from_str(VALID_PKGINFO_V2_DATA)?)
+    .build_info(BuildInfo::from_str(VALID_BUILDINFO_V2_DATA)?)
+    .build_with_observer(&mut observer)?;
+
+    assert_eq!(
+        observer.metadata_files,
+        vec![
+            MetadataFileName::PackageInfo,
+            MetadataFileName::BuildInfo,
+            MetadataFileName::Mtree
+        ]
+    );
+    assert!(observer.entries.contains(&PathBuf::from("foo/beh.txt")));
+    let Some(statistics) = observer.statistics else {
+        panic!("Expected compression statistics");
+    };
+    assert_eq!(statistics.entries.len(), observer.entries.len());
+
+    Ok(())
+}
+
+/// Ensures that package creation fails if a [`PackageBuildObserver`] rejects an entry.
+#[test]
+fn package_builder_observer_rejects_entry() -> TestResult {
+    init_logger();
+
+    let temp_dir = TempDir::new()?;
+    let input_dir = temp_dir.path().join("input");
+    create_dir(&input_dir)?;
+    create_data_files(&input_dir)?;
+
+    let mut observer = RecordingObserver {
+        reject: Some(PathBuf::from("foo/beh.txt")),
+        ..Default::default()
+    };
+    let result = PackageBuilder::new(
+        InputDir::new(input_dir)?,
+        OutputDir::new(temp_dir.path().join("output"))?,
+    )
+    .package_info(PackageInfo::from_str(VALID_PKGINFO_V2_DATA)?)
+    .build_info(BuildInfo::from_str(VALID_BUILDINFO_V2_DATA)?)
+    .build_with_observer(&mut observer);
+
+    match result {
+        Err(Error::EntryRejected { path, .. }) => assert_eq!(path, PathBuf::from("foo/beh.txt")),
+        result => panic!("Expected an EntryRejected error, got {result:?}"),
+    }
+    assert!(observer.statistics.is_none());
+
+    Ok(())
+}
+
+/// Ensures that [`ContentPolicyChecker`] reports the same violations during package creation as
+/// [`Package::check_content_policy`] does for the created package.
+#[test]
+fn package_content_policy() -> TestResult {
+    init_logger();
+
+    let temp_dir = TempDir::new()?;
+    let input_dir = temp_dir.path().join("input");
+    create_dir(&input_dir)?;
+    create_data_files(&input_dir)?;
+
+    let policy = ContentPolicy::new().max_file_size(4);
+    let mut checker = ContentPolicyChecker::new(&policy);
+    let artifacts = PackageBuilder::new(
+        InputDir::new(input_dir)?,
+        OutputDir::new(temp_dir.path().join("output"))?,
+    )
+    .compression(CompressionSettings::None)
+    .package_info(PackageInfo::from_str(VALID_PKGINFO_V2_DATA)?)
+    .build_info(BuildInfo::from_str(VALID_BUILDINFO_V2_DATA)?)
+    .build_with_observer(&mut checker)?;
+    let build_report = checker.finish();
+
+    assert!(!build_report.is_compliant());
+    assert!(
+        build_report
+            .violations
+            .contains(&ContentViolation::FileTooLarge {
+                path: PathBuf::from(".ARBITRARY"),
+                size: 26,
+                max: 4,
+            })
+    );
+    assert!(!build_report.violations.iter().any(
+        |violation| matches!(violation, ContentViolation::FileTooLarge { path, .. } if path == Path::new("foo/beh.txt"))
+    ));
+    assert_eq!(
+        artifacts.package.check_content_policy(&policy)?,
+        build_report
+    );
+
+    Ok(())
+}
+
+/// Ensures that package creation fails if its [`CancellationToken`] has been cancelled.
+#[test]
+fn package_builder_cancelled() -> TestResult {
+    init_logger();
+
+    let temp_dir = TempDir::new()?;
+    let input_dir = temp_dir.path().join("input");
+    create_dir(&input_dir)?;
+    create_data_files(&input_dir)?;
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let result = PackageBuilder::new(
+        InputDir::new(input_dir)?,
+        OutputDir::new(temp_dir.path().join("output"))?,
+    )
+    .package_info(PackageInfo::from_str(VALID_PKGINFO_V2_DATA)?)
+    .build_info(BuildInfo::from_str(VALID_BUILDINFO_V2_DATA)?)
+    .cancellation(token)
+    .build();
+
+    assert!(matches!(
+        result,
+        Err(Error::AlpmCompress(alpm_compress::Error::Cancelled))
+    ));
+
+    Ok(())
+}
+
 /// Ensures that [`PackageInput::from_input_dir`] fails on missing metadata files.
 #[rstest]
 #[case::no_build_info(
@@ -769,6 +1043,19 @@ fn create_package(
         scriptlet: false,
     }
 )]
+#[case::all_files_zstd_seekable(
+    CompressionSettings::ZstdSeekable {
+        compression_level: Default::default(),
+        frame_size: ZstdSeekableFrameSize::new(512),
+    },
+    InputDirConfig {
+        build_info: true,
+        data_files: true,
+        mtree: true,
+        package_info: true,
+        scriptlet: true,
+    }
+)]
 fn read_package_contents(
     #[case] compression: CompressionSettings,
     #[case] config_flags: InputDirConfig,
@@ -902,6 +1189,115 @@ fn read_package_contents(
     Ok(())
 }
 
+/// Ensures that single entries can be read from seekable and non-seekable package files.
+#[rstest]
+#[case::zstd(CompressionSettings::Zstd {
+    compression_level: Default::default(),
+    threads: ZstdThreads::all(),
+})]
+#[case::zstd_seekable(CompressionSettings::ZstdSeekable {
+    compression_level: Default::default(),
+    frame_size: ZstdSeekableFrameSize::new(512),
+})]
+fn read_package_entry_content(#[case] compression: CompressionSettings) -> TestResult {
+    init_logger();
+
+    let temp_dir = TempDir::new()?;
+    let package = create_package(
+        &temp_dir,
+        &InputDirConfig {
+            build_info: true,
+            data_files: true,
+            mtree: true,
+            package_info: true,
+            scriptlet: false,
+        },
+        compression,
+    )?;
+
+    assert_eq!(
+        package.read_entry_content("foo/beh.txt")?,
+        Some(b"test".to_vec())
+    );
+    assert!(package.read_entry_content(".PKGINFO")?.is_some());
+    assert_eq!(package.read_entry_content("foo/missing.txt")?, None);
+
+    Ok(())
+}
+
+/// Ensures that data files with long paths, long symlink targets and UTF-8 characters in their
+/// paths survive a round-trip through a package file.
+#[rstest]
+#[case::zstd(CompressionSettings::Zstd {
+    compression_level: Default::default(),
+    threads: ZstdThreads::all(),
+})]
+#[case::zstd_seekable(CompressionSettings::ZstdSeekable {
+    compression_level: Default::default(),
+    frame_size: ZstdSeekableFrameSize::new(512),
+})]
+#[case::no_compression(CompressionSettings::None)]
+fn package_long_and_utf8_paths(#[case] compression: CompressionSettings) -> TestResult {
+    init_logger();
+
+    let temp_dir = TempDir::new()?;
+    let input_dir_path = temp_dir.path().join("input");
+    create_dir(&input_dir_path)?;
+    let input_dir = InputDir::new(input_dir_path)?;
+
+    let long_dir = format!("usr/share/{}/{}", "a".repeat(120), "b".repeat(120));
+    let long_file = format!("{long_dir}/{}.txt", "c".repeat(100));
+    let utf8_dir = "usr/share/doc/f\u{f6}\u{f6} b\u{e4}r";
+    let utf8_file = format!("{utf8_dir}/\u{1f980} \u{65e5}\u{672c}.txt");
+    let long_link = "usr/share/long-link";
+    for (dir, file, content) in [
+        (long_dir.as_str(), long_file.as_str(), "long"),
+        (utf8_dir, utf8_file.as_str(), "utf8"),
+    ] {
+        create_dir_all(input_dir.join(dir))?;
+        let mut data = File::create(input_dir.join(file))?;
+        write!(data, "{content}")?;
+    }
+    symlink(format!("/{long_file}"), input_dir.join(long_link))?;
+
+    prepare_input_dir(
+        &input_dir,
+        &InputDirConfig {
+            build_info: true,
+            data_files: false,
+            mtree: true,
+            package_info: true,
+            scriptlet: false,
+        },
+    )?;
+
+    let package_input: PackageInput = input_dir.try_into()?;
+    let output_dir = OutputDir::new(temp_dir.path().join("output"))?;
+    let config = PackageCreationConfig::new(package_input, output_dir, compression)?;
+    let package = Package::try_from(&config)?;
+
+    assert_eq!(
+        package.read_entry_content(&long_file)?,
+        Some(b"long".to_vec())
+    );
+    assert_eq!(
+        package.read_entry_content(&utf8_file)?,
+        Some(b"utf8".to_vec())
+    );
+
+    let mut reader = PackageReader::try_from(package)?;
+    let mut link_target = None;
+    for entry in reader.data_entries()? {
+        let entry = entry?;
+        if entry.path() == Path::new(long_link) {
+            link_target = entry.raw().link_name()?.map(|target| target.to_path_buf());
+        }
+    }
+    assert_eq!(link_target, Some(PathBuf::from(format!("/{long_file}"))));
+
+    Ok(())
+}
+
 // Ensure that the metadata iterator short-circuits after finding all metadata files.
 #[test]
 fn package_metadata_iterator() -> TestResult {
@@ -1076,3 +1472,56 @@ fn package_entry_iterator_without_scriptlet() -> TestResult {
 
     Ok(())
 }
+
+/// Ensures that packages can be read using an [`alpm_package::AsyncPackageReader`].
+#[cfg(feature = "tokio")]
+#[rstest]
+#[case::gzip(CompressionSettings::Gzip {
+    compression_level: Default::default()
+})]
+#[case::zstd(CompressionSettings::Zstd {
+    compression_level: Default::default(),
+    threads: ZstdThreads::all(),
+})]
+#[case::no_compression(CompressionSettings::None)]
+#[tokio::test]
+async fn read_package_async(#[case] compression: CompressionSettings) -> TestResult {
+    use alpm_package::AsyncPackageReader;
+
+    init_logger();
+
+    let temp_dir = TempDir::new()?;
+    let package = create_package(
+        &temp_dir,
+        &InputDirConfig {
+            build_info: true,
+            data_files: true,
+            mtree: true,
+            package_info: true,
+            scriptlet: true,
+        },
+        compression,
+    )?;
+    let path = package.to_path_buf();
+
+    let metadata = AsyncPackageReader::open(&path).await?.metadata().await?;
+    assert_eq!(metadata.pkginfo, package.read_pkginfo()?);
+    assert_eq!(metadata.buildinfo, package.read_buildinfo()?);
+
+    let scriptlet = AsyncPackageReader::open(&path)
+        .await?
+        .read_install_scriptlet()
+        .await?;
+    assert_eq!(scriptlet.as_deref(), Some(VALID_INSTALL_SCRIPTLET));
+
+    let mut reader = AsyncPackageReader::open(&path).await?;
+    let Some(mut entry) = reader.read_data_entry("foo/beh.txt").await? else {
+        panic!("The data entry foo/beh.txt should exist");
+    };
+    assert_eq!(entry.content().await?, b"test");
+
+    let mut reader = AsyncPackageReader::open(&path).await?;
+    assert!(reader.read_data_entry(".PKGINFO").await?.is_none());
+
+    Ok(())
+}
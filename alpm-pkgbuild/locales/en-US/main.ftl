@@ -6,6 +6,8 @@ error-io-path = I/O error at path { $path } while { $context }:
 
 error-io-path-check-pkgbuild = checking for PKGBUILD
 
+error-io-read-pkgbuild = reading PKGBUILD
+
 error-io-get-metadata = getting metadata of file
 
 error-no-filename = No filename provided in path
@@ -2,6 +2,7 @@
 
 pub mod bridge;
 pub mod error;
+pub mod script;
 
 pub use error::Error;
 
@@ -0,0 +1,351 @@
+//! Line based access to the contents of a [PKGBUILD].
+//!
+//! The [`alpm-pkgbuild-bridge`] script only exposes the values of variables declared in a
+//! [PKGBUILD], but neither the contents of its functions, nor the location of declarations.
+//! The [`PackageBuildScript`] complements the output of the bridge with this information, so that
+//! e.g. lint rules are able to refer to specific lines of a [PKGBUILD].
+//!
+//! [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+//! [`alpm-pkgbuild-bridge`]: https://gitlab.archlinux.org/archlinux/alpm/alpm-pkgbuild-bridge
+
+use std::{fs::read_to_string, path::Path};
+
+use fluent_i18n::t;
+
+use crate::Error;
+
+/// A single line of a [PKGBUILD].
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScriptLine {
+    /// The number of the line (starting at 1).
+    pub number: usize,
+    /// The contents of the line.
+    pub content: String,
+    /// The name of the function that the line is part of.
+    ///
+    /// Is [`None`] for lines outside of functions.
+    pub function: Option<String>,
+}
+
+/// A top-level variable assignment in a [PKGBUILD].
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScriptAssignment {
+    /// The number of the line (starting at 1) in which the assignment starts.
+    pub line: usize,
+    /// The name of the assigned variable (e.g. `md5sums_x86_64`).
+    pub name: String,
+}
+
+/// The contents of a [PKGBUILD], split into lines.
+///
+/// Each line is associated with the function it is part of.
+///
+/// # Note
+///
+/// Functions are detected using a heuristic, which tracks the nesting of curly braces outside of
+/// quotes and comments.
+/// Unusual constructs (e.g. here-documents containing unbalanced braces) may lead to incorrect
+/// function boundaries.
+///
+/// # Examples
+///
+/// ```
+/// use alpm_pkgbuild::script::PackageBuildScript;
+///
+/// let script = PackageBuildScript::new(
+///     r#"pkgname=example
+/// md5sums=(SKIP)
+///
+/// package() {
+///   install -Dm644 README "$pkgdir/usr/share/doc/example/README"
+/// }
+/// "#,
+/// );
+///
+/// let assignments = script.assignments().collect::<Vec<_>>();
+/// assert_eq!(assignments.len(), 2);
+/// assert_eq!(assignments[1].name, "md5sums");
+/// assert_eq!(assignments[1].line, 2);
+///
+/// let package_lines = script.function_lines("package").collect::<Vec<_>>();
+/// assert_eq!(package_lines.len(), 3);
+/// ```
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PackageBuildScript {
+    lines: Vec<ScriptLine>,
+}
+
+impl PackageBuildScript {
+    /// Creates a new [`PackageBuildScript`] from the contents of a [PKGBUILD].
+    ///
+    /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+    pub fn new(contents: &str) -> Self {
+        let mut lines = Vec::new();
+        let mut function: Option<String> = None;
+        // Whether the opening brace of the current function has been encountered.
+        let mut opened = false;
+        let mut depth: isize = 0;
+
+        for (index, content) in contents.lines().enumerate() {
+            let mut code = content;
+            if function.is_none()
+                && let Some((name, rest)) = function_header(content)
+            {
+                function = Some(name.to_string());
+                opened = false;
+                depth = 0;
+                code = rest;
+            }
+
+            if function.is_some() {
+                let (opening, closing) = count_braces(code);
+                if opening > 0 {
+                    opened = true;
+                }
+                depth += opening as isize - closing as isize;
+            }
+
+            lines.push(ScriptLine {
+                number: index + 1,
+                content: content.to_string(),
+                function: function.clone(),
+            });
+
+            if opened && depth <= 0 {
+                function = None;
+                opened = false;
+                depth = 0;
+            }
+        }
+
+        Self { lines }
+    }
+
+    /// Reads a [`PackageBuildScript`] from a [PKGBUILD] file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file at `path` cannot be read.
+    ///
+    /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+    pub fn from_file(path: &Path) -> Result<Self, Error> {
+        let contents = read_to_string(path).map_err(|source| Error::IoPath {
+            path: path.to_path_buf(),
+            context: t!("error-io-read-pkgbuild"),
+            source,
+        })?;
+
+        Ok(Self::new(&contents))
+    }
+
+    /// Returns all lines of the [PKGBUILD].
+    ///
+    /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+    pub fn lines(&self) -> &[ScriptLine] {
+        &self.lines
+    }
+
+    /// Returns all lines that are part of a function.
+    pub fn all_function_lines(&self) -> impl Iterator<Item = &ScriptLine> {
+        self.lines.iter().filter(|line| line.function.is_some())
+    }
+
+    /// Returns all lines that are part of the function `name`.
+    pub fn function_lines(&self, name: &str) -> impl Iterator<Item = &ScriptLine> {
+        self.lines
+            .iter()
+            .filter(move |line| line.function.as_deref() == Some(name))
+    }
+
+    /// Returns all top-level variable assignments.
+    ///
+    /// Assignments inside of functions are not considered.
+    pub fn assignments(&self) -> impl Iterator<Item = ScriptAssignment> {
+        self.lines
+            .iter()
+            .filter(|line| line.function.is_none())
+            .filter_map(|line| {
+                assignment_name(&line.content).map(|name| ScriptAssignment {
+                    line: line.number,
+                    name: name.to_string(),
+                })
+            })
+    }
+}
+
+/// Returns whether `c` may be used in the name of a shell variable.
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Recognizes a function header (e.g. `package_foo() {` or `function package_foo {`) in `line`.
+///
+/// Returns the name of the function and the remainder of the line after the parentheses.
+fn function_header(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim_start();
+    let (keyword, line) = match line.strip_prefix("function ") {
+        Some(line) => (true, line.trim_start()),
+        None => (false, line),
+    };
+    let end = line
+        .find(|c: char| !(is_name_char(c) || c == '-' || c == '.'))
+        .unwrap_or(line.len());
+    if end == 0 {
+        return None;
+    }
+    let (name, rest) = line.split_at(end);
+    let rest = rest.trim_start();
+    // The parentheses are optional if the `function` keyword is used.
+    let rest = match rest.strip_prefix("()") {
+        Some(rest) => rest,
+        None if keyword => rest,
+        None => return None,
+    };
+
+    Some((name, rest))
+}
+
+/// Recognizes a variable assignment (e.g. `md5sums=(...)` or `depends+=(...)`) in `line`.
+///
+/// Returns the name of the assigned variable.
+fn assignment_name(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    let end = line.find(|c: char| !is_name_char(c))?;
+    let (name, rest) = line.split_at(end);
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    if rest.starts_with('=') || rest.starts_with("+=") {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+/// Counts the opening and closing curly braces in `code`.
+///
+/// Braces in quotes and comments are ignored.
+fn count_braces(code: &str) -> (usize, usize) {
+    let mut opening = 0;
+    let mut closing = 0;
+    let mut previous = ' ';
+    let mut single_quoted = false;
+    let mut double_quoted = false;
+    let mut escaped = false;
+
+    for c in code.chars() {
+        if escaped {
+            escaped = false;
+        } else if single_quoted {
+            single_quoted = c != '\'';
+        } else {
+            match c {
+                '\\' => escaped = true,
+                '"' => double_quoted = !double_quoted,
+                '\'' if !double_quoted => single_quoted = true,
+                '#' if !double_quoted && previous.is_whitespace() => break,
+                '{' if !double_quoted => opening += 1,
+                '}' if !double_quoted => closing += 1,
+                _ => {}
+            }
+        }
+        previous = c;
+    }
+
+    (opening, closing)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const SCRIPT: &str = r#"pkgname=example
+pkgver=1.0.0
+source=("https://example.org/$pkgname-$pkgver.tar.gz")
+md5sums_x86_64=('SKIP')
+
+prepare()
+{
+  cd "$srcdir/$pkgname-$pkgver" # comment with }
+  sed -i 's/{/(/' Makefile
+}
+
+build() {
+  if [[ -n "${CFLAGS}" ]]; then
+    make
+  fi
+}
+
+function package_example {
+  true
+}
+
+package_example-docs() {
+  install -Dm644 README $pkgdir/usr/share/doc/README
+}
+"#;
+
+    /// Ensures that lines are associated with the correct functions.
+    #[test]
+    fn script_function_lines() {
+        let script = PackageBuildScript::new(SCRIPT);
+
+        let numbers = |name| {
+            script
+                .function_lines(name)
+                .map(|line| line.number)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(numbers("prepare"), vec![6, 7, 8, 9, 10]);
+        assert_eq!(numbers("build"), vec![12, 13, 14, 15, 16]);
+        assert_eq!(numbers("package_example"), vec![18, 19, 20]);
+        assert_eq!(numbers("package_example-docs"), vec![22, 23, 24]);
+    }
+
+    /// Ensures that top-level assignments are detected.
+    #[test]
+    fn script_assignments() {
+        let script = PackageBuildScript::new(SCRIPT);
+
+        assert_eq!(
+            script.assignments().collect::<Vec<_>>(),
+            vec![
+                ScriptAssignment {
+                    line: 1,
+                    name: "pkgname".to_string()
+                },
+                ScriptAssignment {
+                    line: 2,
+                    name: "pkgver".to_string()
+                },
+                ScriptAssignment {
+                    line: 3,
+                    name: "source".to_string()
+                },
+                ScriptAssignment {
+                    line: 4,
+                    name: "md5sums_x86_64".to_string()
+                },
+            ]
+        );
+    }
+
+    /// Ensures that the names of assigned variables are recognized.
+    #[rstest]
+    #[case("depends+=(foo)", Some("depends"))]
+    #[case("  pkgrel=1", Some("pkgrel"))]
+    #[case("pkgrel == 1", None)]
+    #[case("# pkgrel=1", None)]
+    #[case("1foo=bar", None)]
+    fn assignment_names(#[case] line: &str, #[case] expected: Option<&str>) {
+        assert_eq!(assignment_name(line), expected);
+    }
+}
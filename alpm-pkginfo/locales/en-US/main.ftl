@@ -12,10 +12,6 @@ error-io-open-file = opening the file for reading
 
 error-io-create-output-dir = creating output directory
 
-error-io-create-output-file = creating output file
-
-error-io-write-output-file = writing to output file
-
 error-deserialize = Failed to deserialize PKGINFO file:
   { $source }
 
@@ -32,4 +28,23 @@ error-invalid-variant = Invalid variant ({ $error }).
 
 error-missing-extra-data = Extra data is missing.
 
+error-missing-field = The required field '{ $keyword }' is not set.
+
 error-unsupported-schema = Unsupported schema version: { $version }.
+
+error-invalid-line = The line is not a key value pair delimited by ' = '.
+
+error-unknown-field = The field '{ $keyword }' is unknown.
+
+error-duplicate-field = The field '{ $keyword }' must only be set once.
+
+error-invalid-field-value = The field '{ $keyword }' has an invalid value: { $reason }
+
+error-invalid-fields =
+  { $count ->
+      [one] Found an invalid field
+     *[other] Found { $count } invalid fields
+  } in PKGINFO data:
+  { $errors }
+
+error-field-location = line { $line }, column { $column }
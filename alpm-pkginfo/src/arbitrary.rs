@@ -0,0 +1,93 @@
+//! Generation of arbitrary [PKGINFO] data for fuzzing and property tests.
+//!
+//! [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+
+use std::fmt::Display;
+
+use alpm_types::{
+    Architecture,
+    Backup,
+    FullVersion,
+    License,
+    Name,
+    OptionalDependency,
+    PackageDescription,
+    PackageRelation,
+    Packager,
+    RelationOrSoname,
+    Url,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Valid [PKGINFO] data, that is created by construction from unstructured input.
+///
+/// Either a [PKGINFOv1] or a [PKGINFOv2] is created.
+/// All values are created using the [`Arbitrary`] implementations of their respective types, so
+/// that the resulting data can always be parsed as [`PackageInfo`](crate::PackageInfo).
+///
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+/// [PKGINFOv1]: https://alpm.archlinux.page/specifications/PKGINFOv1.5.html
+/// [PKGINFOv2]: https://alpm.archlinux.page/specifications/PKGINFOv2.5.html
+#[derive(Clone, Debug)]
+pub struct PackageInfoText(String);
+
+impl PackageInfoText {
+    /// Returns the [PKGINFO] data as string slice.
+    ///
+    /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Appends a `key = value` line for each of an arbitrary number of `T` to `lines`.
+fn push_all<'a, T: Arbitrary<'a> + Display>(
+    u: &mut Unstructured<'a>,
+    lines: &mut Vec<String>,
+    key: &str,
+) -> Result<()> {
+    for value in u.arbitrary::<Vec<T>>()? {
+        lines.push(format!("{key} = {value}"));
+    }
+
+    Ok(())
+}
+
+impl<'a> Arbitrary<'a> for PackageInfoText {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let v2: bool = u.arbitrary()?;
+
+        let mut lines = vec![
+            format!("pkgname = {}", u.arbitrary::<Name>()?),
+            format!("pkgbase = {}", u.arbitrary::<Name>()?),
+        ];
+        if v2 {
+            lines.push("xdata = pkgtype=pkg".to_string());
+        }
+        lines.push(format!("pkgver = {}", u.arbitrary::<FullVersion>()?));
+        lines.push(format!(
+            "pkgdesc = {}",
+            u.arbitrary::<PackageDescription>()?
+        ));
+        lines.push(format!("url = {}", u.arbitrary::<Url>()?));
+        lines.push(format!(
+            "builddate = {}",
+            u.int_in_range(0..=i64::from(u32::MAX))?
+        ));
+        lines.push(format!("packager = {}", u.arbitrary::<Packager>()?));
+        lines.push(format!("size = {}", u.arbitrary::<u64>()?));
+        lines.push(format!("arch = {}", u.arbitrary::<Architecture>()?));
+        push_all::<License>(u, &mut lines, "license")?;
+        push_all::<PackageRelation>(u, &mut lines, "replaces")?;
+        push_all::<Name>(u, &mut lines, "group")?;
+        push_all::<PackageRelation>(u, &mut lines, "conflict")?;
+        push_all::<RelationOrSoname>(u, &mut lines, "provides")?;
+        push_all::<Backup>(u, &mut lines, "backup")?;
+        push_all::<RelationOrSoname>(u, &mut lines, "depend")?;
+        push_all::<OptionalDependency>(u, &mut lines, "optdepend")?;
+        push_all::<PackageRelation>(u, &mut lines, "makedepend")?;
+        push_all::<PackageRelation>(u, &mut lines, "checkdepend")?;
+
+        Ok(Self(lines.join("\n") + "\n"))
+    }
+}
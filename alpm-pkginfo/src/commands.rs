@@ -1,21 +1,23 @@
 //! Command-line functions, that are called by the `alpm-pkginfo` executable.
 
 use std::{
-    fs::{File, create_dir_all},
-    io::{self, IsTerminal, Write},
+    fs::create_dir_all,
+    io::{self, IsTerminal},
     path::PathBuf,
 };
 
-use alpm_common::MetadataFile;
-use alpm_pkginfo::{
+use alpm_common::{ErrorCategory, MetadataFile, write_atomic};
+use fluent_i18n::t;
+use schemars::generate::SchemaSettings;
+use thiserror::Error;
+
+use crate::{
     PackageInfo,
     PackageInfoSchema,
     PackageInfoV1,
     PackageInfoV2,
     cli::{CreateCommand, OutputFormat},
 };
-use fluent_i18n::t;
-use thiserror::Error;
 
 /// A high-level error wrapper around [`alpm_soname::Error`] to add CLI error cases.
 #[derive(Debug, Error)]
@@ -29,15 +31,32 @@ pub enum Error {
     #[error("{msg}", msg = t!("error-json", { "source" => .0.to_string() }))]
     Json(#[from] serde_json::Error),
 
-    /// An [alpm_pkginfo::Error]
+    /// An [alpm_common::Error]
     #[error(transparent)]
-    PkgInfo(#[from] alpm_pkginfo::Error),
+    AlpmCommon(#[from] alpm_common::Error),
+
+    /// An [crate::Error]
+    #[error(transparent)]
+    PkgInfo(#[from] crate::Error),
 
     /// An [alpm_types::Error]
     #[error(transparent)]
     AlpmTypes(#[from] alpm_types::Error),
 }
 
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::NoInputFile => Self::Io,
+            Error::Json(_) => Self::Parse,
+            Error::AlpmCommon(error) => error.into(),
+            Error::PkgInfo(error) => error.into(),
+            Error::AlpmTypes(error) => error.into(),
+        }
+    }
+}
+
 /// Create a file according to a PKGINFO schema
 ///
 /// ## Errors
@@ -101,26 +120,14 @@ pub fn create_file(command: CreateCommand) -> Result<(), Error> {
 
     // create any parent directories if necessary
     if let Some(output_dir) = output.0.parent() {
-        create_dir_all(output_dir).map_err(|source| alpm_pkginfo::Error::IoPath {
+        create_dir_all(output_dir).map_err(|source| crate::Error::IoPath {
             path: output_dir.to_path_buf(),
             context: t!("error-io-create-output-dir"),
             source,
         })?;
     }
 
-    let mut out = File::create(&output.0).map_err(|source| alpm_pkginfo::Error::IoPath {
-        path: output.0.clone(),
-        context: t!("error-io-create-output-file"),
-        source,
-    })?;
-
-    let _ = out
-        .write(data.as_bytes())
-        .map_err(|source| alpm_pkginfo::Error::IoPath {
-            path: output.0,
-            context: t!("error-io-write-output-file"),
-            source,
-        })?;
+    write_atomic(&output.0, data)?;
 
     Ok(())
 }
@@ -196,3 +203,27 @@ pub fn format(
     }
     Ok(())
 }
+
+/// Prints the JSON Schema of the JSON output of [`format`] on stdout.
+///
+/// The JSON Schema is generated from [`PackageInfo`] and describes all of its versions.
+/// If `pretty` is `true`, the JSON Schema is pretty-printed.
+///
+/// # Errors
+///
+/// Returns an error if the JSON Schema cannot be serialized.
+pub fn json_schema(pretty: bool) -> Result<(), Error> {
+    let schema = SchemaSettings::draft2020_12()
+        .for_serialize()
+        .into_generator()
+        .into_root_schema_for::<PackageInfo>();
+    println!(
+        "{}",
+        if pretty {
+            serde_json::to_string_pretty(&schema)?
+        } else {
+            serde_json::to_string(&schema)?
+        }
+    );
+    Ok(())
+}
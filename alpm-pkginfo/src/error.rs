@@ -1,5 +1,10 @@
-use std::{path::PathBuf, string::FromUtf8Error};
+use std::{
+    fmt::{Display, Formatter},
+    path::PathBuf,
+    string::FromUtf8Error,
+};
 
+use alpm_common::ErrorCategory;
 use fluent_i18n::t;
 
 /// The Error that can occur when working with PKGINFO files.
@@ -61,6 +66,13 @@ pub enum Error {
     #[error("{msg}", msg = t!("error-first-extra-data-not-pkgtype"))]
     FirstExtraDataNotPkgType,
 
+    /// A required field is missing when creating PKGINFO data.
+    #[error("{msg}", msg = t!("error-missing-field", { "keyword" => keyword }))]
+    MissingField {
+        /// The keyword of the missing field.
+        keyword: &'static str,
+    },
+
     /// An invalid enum variant
     #[error("{msg}", msg = t!("error-invalid-variant", { "error" => 0.to_string() }))]
     InvalidVariant(#[from] strum::ParseError),
@@ -68,4 +80,121 @@ pub enum Error {
     /// Unsupported schema version.
     #[error("{msg}", msg = t!("error-unsupported-schema", { "version" => .0 }))]
     UnsupportedSchemaVersion(String),
+
+    /// A line of PKGINFO data is not a key value pair.
+    #[error("{msg}", msg = t!("error-invalid-line"))]
+    InvalidLine,
+
+    /// A field of PKGINFO data is unknown.
+    #[error("{msg}", msg = t!("error-unknown-field", { "keyword" => keyword }))]
+    UnknownField {
+        /// The keyword of the unknown field.
+        keyword: String,
+    },
+
+    /// A field of PKGINFO data, that may only be set once, is set more than once.
+    #[error("{msg}", msg = t!("error-duplicate-field", { "keyword" => keyword }))]
+    DuplicateField {
+        /// The keyword of the duplicate field.
+        keyword: &'static str,
+    },
+
+    /// A field of PKGINFO data has an invalid value.
+    #[error("{msg}", msg = t!("error-invalid-field-value", {
+        "keyword" => keyword,
+        "reason" => reason
+    }))]
+    InvalidFieldValue {
+        /// The keyword of the field.
+        keyword: &'static str,
+        /// The reason why the value is invalid.
+        reason: String,
+    },
+
+    /// One or more fields of PKGINFO data are invalid.
+    #[error("{msg}", msg = t!("error-invalid-fields", {
+        "count" => .0.len(),
+        "errors" => .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n\n")
+    }))]
+    InvalidFields(Vec<PackageInfoFieldError>),
+}
+
+/// A location in PKGINFO data.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Span {
+    /// The line number (starting at 1).
+    pub line: usize,
+    /// The column (starting at 1) at which the span starts.
+    pub column: usize,
+    /// The length of the span in characters.
+    pub length: usize,
+    /// The content of the line.
+    pub content: String,
+}
+
+/// An [`Error`] related to a field of PKGINFO data, with the [`Span`] it relates to.
+///
+/// When displayed, the [`Span`] is annotated in the content of its line:
+///
+/// ```text
+/// The field 'pkgname' must only be set once.
+///  --> line 3, column 1
+///   |
+/// 3 | pkgname = other
+///   | ^^^^^^^
+/// ```
+#[derive(Debug, thiserror::Error)]
+pub struct PackageInfoFieldError {
+    /// The location of the error.
+    ///
+    /// Is [`None`] if the error does not relate to a specific location (e.g. if a required field
+    /// is missing).
+    pub span: Option<Span>,
+    /// The error.
+    pub error: Error,
+}
+
+impl Display for PackageInfoFieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error)?;
+        let Some(span) = self.span.as_ref() else {
+            return Ok(());
+        };
+
+        let line = span.line.to_string();
+        let gutter = " ".repeat(line.len());
+        write!(
+            f,
+            "\n{gutter}--> {}\n{gutter} |\n{line} | {}\n{gutter} | {}{}",
+            t!("error-field-location", {
+                "line" => span.line,
+                "column" => span.column
+            }),
+            span.content,
+            " ".repeat(span.column.saturating_sub(1)),
+            "^".repeat(span.length.max(1))
+        )
+    }
+}
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::AlpmType(error) => error.into(),
+            Error::IoPath { .. } | Error::IoRead { .. } => Self::Io,
+            Error::InvalidUTF8(_)
+            | Error::Deserialization { .. }
+            | Error::InvalidVariant(_)
+            | Error::InvalidLine => Self::Parse,
+            Error::UnsupportedSchemaVersion(_) => Self::Schema,
+            Error::ExtraDataEmpty
+            | Error::FirstExtraDataNotPkgType
+            | Error::MissingField { .. }
+            | Error::UnknownField { .. }
+            | Error::DuplicateField { .. }
+            | Error::InvalidFieldValue { .. }
+            | Error::InvalidFields(_) => Self::Validation,
+        }
+    }
 }
@@ -1,16 +1,31 @@
 #![doc = include_str!("../README.md")]
 
 pub mod package_info;
-pub use package_info::{PackageInfo, v1::PackageInfoV1, v2::PackageInfoV2};
+pub use package_info::{
+    PackageInfo,
+    builder::PackageInfoV2Builder,
+    v1::PackageInfoV1,
+    v2::PackageInfoV2,
+};
+
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
 
 #[cfg(feature = "cli")]
 #[doc(hidden)]
 pub mod cli;
 
+#[cfg(feature = "cli")]
+#[doc(hidden)]
+pub mod commands;
+
 mod error;
-pub use crate::error::Error;
+pub use crate::error::{Error, PackageInfoFieldError, Span};
 
 mod schema;
 pub use schema::PackageInfoSchema;
 
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 fluent_i18n::i18n!("locales");
@@ -2,14 +2,13 @@
 
 use std::process::ExitCode;
 
-use alpm_pkginfo::cli::{Cli, Command};
+use alpm_common::ErrorCategory;
+use alpm_pkginfo::{
+    cli::{Cli, Command},
+    commands::{create_file, format, json_schema, validate},
+};
 use clap::Parser;
 
-/// Commandline functions, that're called by the `alpm-pkginfo` executable.
-mod commands;
-
-use commands::{create_file, format, validate};
-
 fluent_i18n::i18n!("locales");
 
 fn main() -> ExitCode {
@@ -23,11 +22,12 @@ fn main() -> ExitCode {
             output_format,
             pretty,
         } => format(file, schema, output_format, pretty),
+        Command::JsonSchema { pretty } => json_schema(pretty),
     };
 
     if let Err(error) = result {
         eprintln!("{error}");
-        ExitCode::FAILURE
+        ErrorCategory::from(&error).into()
     } else {
         ExitCode::SUCCESS
     }
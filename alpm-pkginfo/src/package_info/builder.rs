@@ -0,0 +1,434 @@
+//! Programmatic creation of [PKGINFOv2] data.
+//!
+//! [PKGINFOv2]: https://alpm.archlinux.page/specifications/PKGINFOv2.5.html
+
+use alpm_types::{
+    Architecture,
+    Backup,
+    BuildDate,
+    ExtraData,
+    ExtraDataEntry,
+    FullVersion,
+    Group,
+    InstalledSize,
+    License,
+    Name,
+    OptionalDependency,
+    PackageDescription,
+    PackageRelation,
+    PackageType,
+    Packager,
+    RelationOrSoname,
+    Url,
+};
+
+use crate::{Error, PackageInfoV2};
+
+/// A builder for [`PackageInfoV2`].
+///
+/// Can be created using [`PackageInfoV2::builder`].
+/// All single-value fields are set using setters named after their [PKGINFO] keyword, while
+/// list fields can either be set as a whole or extended one value at a time (e.g. using
+/// [`PackageInfoV2Builder::add_depend`]).
+///
+/// The fields `pkgname`, `pkgver`, `pkgdesc`, `url`, `builddate`, `packager`, `size` and `arch`
+/// are required and validated when calling [`PackageInfoV2Builder::build`].
+/// If no `pkgbase` is set, the `pkgname` is used for it.
+/// If no package type is set, [`PackageType::Package`] is used.
+///
+/// # Examples
+///
+/// ```
+/// use alpm_pkginfo::PackageInfoV2;
+/// use alpm_types::{Name, PackageRelation};
+///
+/// # fn main() -> testresult::TestResult {
+/// let pkginfo = PackageInfoV2::builder()
+///     .pkgname("example".parse()?)
+///     .pkgver("1:1.0.0-1".parse()?)
+///     .pkgdesc("A project that does something".into())
+///     .url("https://example.org/".parse()?)
+///     .builddate(1729181726)
+///     .packager("John Doe <john@example.org>".parse()?)
+///     .size(181849963)
+///     .arch("any".parse()?)
+///     .add_depend(PackageRelation::new(Name::new("glibc")?, None))
+///     .build()?;
+///
+/// assert_eq!(pkginfo.depend.len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+#[derive(Clone, Debug, Default)]
+pub struct PackageInfoV2Builder {
+    pkgname: Option<Name>,
+    pkgbase: Option<Name>,
+    pkgtype: Option<PackageType>,
+    pkgver: Option<FullVersion>,
+    pkgdesc: Option<PackageDescription>,
+    url: Option<Url>,
+    builddate: Option<BuildDate>,
+    packager: Option<Packager>,
+    size: Option<InstalledSize>,
+    arch: Option<Architecture>,
+    license: Vec<License>,
+    replaces: Vec<PackageRelation>,
+    group: Vec<Group>,
+    conflict: Vec<PackageRelation>,
+    provides: Vec<RelationOrSoname>,
+    backup: Vec<Backup>,
+    depend: Vec<RelationOrSoname>,
+    optdepend: Vec<OptionalDependency>,
+    makedepend: Vec<PackageRelation>,
+    checkdepend: Vec<PackageRelation>,
+    xdata: Vec<ExtraDataEntry>,
+}
+
+impl PackageInfoV2Builder {
+    /// Creates a new, empty [`PackageInfoV2Builder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the package.
+    pub fn pkgname(mut self, pkgname: Name) -> Self {
+        self.pkgname = Some(pkgname);
+        self
+    }
+
+    /// Sets the name of the package base.
+    ///
+    /// If unset, the name of the package is used.
+    pub fn pkgbase(mut self, pkgbase: Name) -> Self {
+        self.pkgbase = Some(pkgbase);
+        self
+    }
+
+    /// Sets the type of the package.
+    ///
+    /// If unset, [`PackageType::Package`] is used.
+    pub fn pkgtype(mut self, pkgtype: PackageType) -> Self {
+        self.pkgtype = Some(pkgtype);
+        self
+    }
+
+    /// Sets the full version of the package.
+    pub fn pkgver(mut self, pkgver: FullVersion) -> Self {
+        self.pkgver = Some(pkgver);
+        self
+    }
+
+    /// Sets the description of the package.
+    pub fn pkgdesc(mut self, pkgdesc: PackageDescription) -> Self {
+        self.pkgdesc = Some(pkgdesc);
+        self
+    }
+
+    /// Sets the upstream URL of the package.
+    pub fn url(mut self, url: Url) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Sets the build date of the package as seconds since the epoch.
+    pub fn builddate(mut self, builddate: BuildDate) -> Self {
+        self.builddate = Some(builddate);
+        self
+    }
+
+    /// Sets the packager of the package.
+    pub fn packager(mut self, packager: Packager) -> Self {
+        self.packager = Some(packager);
+        self
+    }
+
+    /// Sets the installed size of the package in bytes.
+    pub fn size(mut self, size: InstalledSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the architecture of the package.
+    pub fn arch(mut self, arch: Architecture) -> Self {
+        self.arch = Some(arch);
+        self
+    }
+
+    /// Sets the licenses of the package.
+    pub fn license(mut self, license: Vec<License>) -> Self {
+        self.license = license;
+        self
+    }
+
+    /// Adds a license to the package.
+    pub fn add_license(mut self, license: License) -> Self {
+        self.license.push(license);
+        self
+    }
+
+    /// Sets the packages replaced by the package.
+    pub fn replaces(mut self, replaces: Vec<PackageRelation>) -> Self {
+        self.replaces = replaces;
+        self
+    }
+
+    /// Adds a package replaced by the package.
+    pub fn add_replaces(mut self, replaces: PackageRelation) -> Self {
+        self.replaces.push(replaces);
+        self
+    }
+
+    /// Sets the groups of the package.
+    pub fn group(mut self, group: Vec<Group>) -> Self {
+        self.group = group;
+        self
+    }
+
+    /// Adds a group to the package.
+    pub fn add_group(mut self, group: Group) -> Self {
+        self.group.push(group);
+        self
+    }
+
+    /// Sets the conflicts of the package.
+    pub fn conflict(mut self, conflict: Vec<PackageRelation>) -> Self {
+        self.conflict = conflict;
+        self
+    }
+
+    /// Adds a conflict to the package.
+    pub fn add_conflict(mut self, conflict: PackageRelation) -> Self {
+        self.conflict.push(conflict);
+        self
+    }
+
+    /// Sets the provisions of the package.
+    pub fn provides(mut self, provides: Vec<RelationOrSoname>) -> Self {
+        self.provides = provides;
+        self
+    }
+
+    /// Adds a provision to the package.
+    pub fn add_provides(mut self, provides: impl Into<RelationOrSoname>) -> Self {
+        self.provides.push(provides.into());
+        self
+    }
+
+    /// Sets the files of the package that are backed up.
+    pub fn backup(mut self, backup: Vec<Backup>) -> Self {
+        self.backup = backup;
+        self
+    }
+
+    /// Adds a file of the package that is backed up.
+    pub fn add_backup(mut self, backup: Backup) -> Self {
+        self.backup.push(backup);
+        self
+    }
+
+    /// Sets the run-time dependencies of the package.
+    pub fn depend(mut self, depend: Vec<RelationOrSoname>) -> Self {
+        self.depend = depend;
+        self
+    }
+
+    /// Adds a run-time dependency to the package.
+    pub fn add_depend(mut self, depend: impl Into<RelationOrSoname>) -> Self {
+        self.depend.push(depend.into());
+        self
+    }
+
+    /// Sets the optional dependencies of the package.
+    pub fn optdepend(mut self, optdepend: Vec<OptionalDependency>) -> Self {
+        self.optdepend = optdepend;
+        self
+    }
+
+    /// Adds an optional dependency to the package.
+    pub fn add_optdepend(mut self, optdepend: OptionalDependency) -> Self {
+        self.optdepend.push(optdepend);
+        self
+    }
+
+    /// Sets the build dependencies of the package.
+    pub fn makedepend(mut self, makedepend: Vec<PackageRelation>) -> Self {
+        self.makedepend = makedepend;
+        self
+    }
+
+    /// Adds a build dependency to the package.
+    pub fn add_makedepend(mut self, makedepend: PackageRelation) -> Self {
+        self.makedepend.push(makedepend);
+        self
+    }
+
+    /// Sets the test dependencies of the package.
+    pub fn checkdepend(mut self, checkdepend: Vec<PackageRelation>) -> Self {
+        self.checkdepend = checkdepend;
+        self
+    }
+
+    /// Adds a test dependency to the package.
+    pub fn add_checkdepend(mut self, checkdepend: PackageRelation) -> Self {
+        self.checkdepend.push(checkdepend);
+        self
+    }
+
+    /// Adds an extra data entry to the package.
+    ///
+    /// The `pkgtype` entry is set using [`PackageInfoV2Builder::pkgtype`] instead.
+    pub fn add_xdata(mut self, xdata: ExtraDataEntry) -> Self {
+        self.xdata.push(xdata);
+        self
+    }
+
+    /// Creates the [`PackageInfoV2`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - a required field is not set,
+    /// - or the extra data contains a `pkgtype` entry with an invalid package type.
+    pub fn build(self) -> Result<PackageInfoV2, Error> {
+        let pkgname = required(self.pkgname, "pkgname")?;
+
+        let mut xdata = Vec::with_capacity(self.xdata.len() + 1);
+        if !self.xdata.iter().any(|entry| entry.key() == "pkgtype") {
+            xdata.push(ExtraDataEntry::new(
+                "pkgtype".to_string(),
+                self.pkgtype.unwrap_or(PackageType::Package).to_string(),
+            ));
+        }
+        xdata.extend(self.xdata);
+
+        Ok(PackageInfoV2 {
+            pkgbase: self.pkgbase.unwrap_or_else(|| pkgname.clone()),
+            pkgname,
+            pkgver: required(self.pkgver, "pkgver")?,
+            pkgdesc: required(self.pkgdesc, "pkgdesc")?,
+            url: required(self.url, "url")?,
+            builddate: required(self.builddate, "builddate")?,
+            packager: required(self.packager, "packager")?,
+            size: required(self.size, "size")?,
+            arch: required(self.arch, "arch")?,
+            license: self.license,
+            replaces: self.replaces,
+            group: self.group,
+            conflict: self.conflict,
+            provides: self.provides,
+            backup: self.backup,
+            depend: self.depend,
+            optdepend: self.optdepend,
+            makedepend: self.makedepend,
+            checkdepend: self.checkdepend,
+            xdata: ExtraData::try_from(xdata)?,
+        })
+    }
+}
+
+/// Returns the value of the required field `keyword`.
+///
+/// # Errors
+///
+/// Returns an error if `value` is [`None`].
+fn required<T>(value: Option<T>, keyword: &'static str) -> Result<T, Error> {
+    value.ok_or(Error::MissingField { keyword })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use alpm_types::SonameV1;
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that a [`PackageInfoV2`] created by the builder equals the parsed representation.
+    #[test]
+    fn package_info_v2_builder() -> TestResult {
+        let pkginfo = PackageInfoV2::builder()
+            .pkgname(Name::new("example")?)
+            .pkgver(FullVersion::from_str("1:1.0.0-1")?)
+            .pkgdesc(PackageDescription::from("A project that does something"))
+            .url(Url::from_str("https://example.org/")?)
+            .builddate(1729181726)
+            .packager(Packager::from_str("John Doe <john@example.org>")?)
+            .size(181849963)
+            .arch(Architecture::from_str("any")?)
+            .add_license(License::from_str("GPL-3.0-or-later")?)
+            .add_group("package-group".to_string())
+            .add_provides(SonameV1::from_str("libexample.so=1-64")?)
+            .add_backup(Backup::from_str("etc/example/config.toml")?)
+            .add_depend(PackageRelation::from_str("glibc")?)
+            .add_optdepend(OptionalDependency::from_str(
+                "python: for special-python-script.py",
+            )?)
+            .add_makedepend(PackageRelation::from_str("cmake")?)
+            .add_checkdepend(PackageRelation::from_str("extra-test-tool")?)
+            .build()?;
+        let expected = PackageInfoV2::from_str(
+            r#"pkgname = example
+pkgbase = example
+xdata = pkgtype=pkg
+pkgver = 1:1.0.0-1
+pkgdesc = A project that does something
+url = https://example.org/
+builddate = 1729181726
+packager = John Doe <john@example.org>
+size = 181849963
+arch = any
+license = GPL-3.0-or-later
+group = package-group
+provides = libexample.so=1-64
+backup = etc/example/config.toml
+depend = glibc
+optdepend = python: for special-python-script.py
+makedepend = cmake
+checkdepend = extra-test-tool
+"#,
+        )?;
+
+        assert_eq!(pkginfo, expected);
+        Ok(())
+    }
+
+    /// Ensures that the package type is set as first extra data entry.
+    #[test]
+    fn package_info_v2_builder_pkgtype() -> TestResult {
+        let pkginfo = PackageInfoV2::builder()
+            .pkgname(Name::new("example-debug")?)
+            .pkgtype(PackageType::Debug)
+            .pkgver(FullVersion::from_str("1.0.0-1")?)
+            .pkgdesc(PackageDescription::default())
+            .url(Url::from_str("https://example.org/")?)
+            .builddate(1)
+            .packager(Packager::from_str("John Doe <john@example.org>")?)
+            .size(1)
+            .arch(Architecture::from_str("x86_64")?)
+            .add_xdata(ExtraDataEntry::from_str("key=value")?)
+            .build()?;
+
+        assert_eq!(pkginfo.xdata.pkg_type(), PackageType::Debug);
+        assert_eq!(pkginfo.xdata.as_ref()[0].key(), "pkgtype");
+        assert_eq!(pkginfo.xdata.as_ref()[1].key(), "key");
+        Ok(())
+    }
+
+    /// Ensures that missing required fields are reported.
+    #[test]
+    fn package_info_v2_builder_missing_field() -> TestResult {
+        let result = PackageInfoV2::builder()
+            .pkgname(Name::new("example")?)
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(Error::MissingField { keyword: "pkgver" })
+        ));
+        Ok(())
+    }
+}
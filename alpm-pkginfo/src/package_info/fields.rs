@@ -0,0 +1,218 @@
+//! Field-level validation of PKGINFO data.
+
+use std::{collections::BTreeSet, fmt::Display, str::FromStr};
+
+use crate::{Error, PackageInfoFieldError, Span};
+
+/// A field of a PKGINFO version.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Field {
+    /// The keyword of the field.
+    keyword: &'static str,
+    /// Whether the field must be set exactly once.
+    ///
+    /// If `false`, the field is optional and may be set any number of times.
+    single: bool,
+    /// Validates a single value of the field.
+    validate: fn(&str) -> Result<(), String>,
+}
+
+impl Field {
+    /// Creates a required [`Field`] called `keyword`, which must be set exactly once.
+    ///
+    /// Its value must be parseable as `T`.
+    pub(crate) const fn single<T>(keyword: &'static str) -> Self
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        Self {
+            keyword,
+            single: true,
+            validate: validate_value::<T>,
+        }
+    }
+
+    /// Creates an optional [`Field`] called `keyword`, which may be set any number of times.
+    ///
+    /// Each of its values must be parseable as `T`.
+    pub(crate) const fn list<T>(keyword: &'static str) -> Self
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        Self {
+            keyword,
+            single: false,
+            validate: validate_value::<T>,
+        }
+    }
+}
+
+/// Validates that `value` can be parsed as `T`.
+fn validate_value<T>(value: &str) -> Result<(), String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    T::from_str(value)
+        .map(|_| ())
+        .map_err(|error| error.to_string())
+}
+
+/// Validates each line of PKGINFO data in `input` against `fields`.
+///
+/// Instead of stopping at the first error, all errors are collected together with the [`Span`]
+/// they relate to.
+/// This includes invalid lines, unknown fields, invalid values, fields that must only be set once
+/// but are set more than once, and missing required fields.
+///
+/// # Errors
+///
+/// Returns an [`Error::InvalidFields`] with all collected errors, if any error is encountered.
+pub(crate) fn validate_fields(input: &str, fields: &[Field]) -> Result<(), Error> {
+    let mut errors = Vec::new();
+    let mut seen = BTreeSet::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let indentation = line.len() - line.trim_start().len();
+        let content = &line[indentation..];
+        if content.is_empty() || content.starts_with('#') {
+            continue;
+        }
+
+        let mut push = |start: usize, length: usize, error: Error| {
+            errors.push(PackageInfoFieldError {
+                span: Some(Span {
+                    line: index + 1,
+                    column: line[..start].chars().count() + 1,
+                    length,
+                    content: line.to_string(),
+                }),
+                error,
+            })
+        };
+
+        let Some((keyword, value)) = content
+            .split_once(" = ")
+            .filter(|(keyword, _)| !keyword.is_empty() && !keyword.contains([' ', '=']))
+        else {
+            push(indentation, content.chars().count(), Error::InvalidLine);
+            continue;
+        };
+        let keyword_length = keyword.chars().count();
+
+        let Some(field) = fields.iter().find(|field| field.keyword == keyword) else {
+            push(
+                indentation,
+                keyword_length,
+                Error::UnknownField {
+                    keyword: keyword.to_string(),
+                },
+            );
+            continue;
+        };
+
+        if !seen.insert(field.keyword) && field.single {
+            push(
+                indentation,
+                keyword_length,
+                Error::DuplicateField {
+                    keyword: field.keyword,
+                },
+            );
+            continue;
+        }
+
+        if let Err(reason) = (field.validate)(value) {
+            push(
+                indentation + keyword.len() + " = ".len(),
+                value.chars().count(),
+                Error::InvalidFieldValue {
+                    keyword: field.keyword,
+                    reason,
+                },
+            );
+        }
+    }
+
+    for field in fields {
+        if field.single && !seen.contains(field.keyword) {
+            errors.push(PackageInfoFieldError {
+                span: None,
+                error: Error::MissingField {
+                    keyword: field.keyword,
+                },
+            });
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InvalidFields(errors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alpm_types::Name;
+    use rstest::rstest;
+
+    use super::*;
+
+    const FIELDS: &[Field] = &[Field::single::<Name>("name"), Field::list::<u8>("number")];
+
+    /// Ensures that all errors are collected together with their spans.
+    #[rstest]
+    #[case::valid("name = foo\nnumber = 1\nnumber = 2\n", &[])]
+    #[case::comment("# comment\nname = foo\n", &[])]
+    #[case::invalid_line("name = foo\n  number=1\n", &[Some((2, 3, 8))])]
+    #[case::unknown_field("name = foo\nunknown = 1\n", &[Some((2, 1, 7))])]
+    #[case::duplicate_field("name = foo\nname = bar\n", &[Some((2, 1, 4))])]
+    #[case::invalid_values(
+        "name = -foo\nnumber = 1\nnumber = 256\n",
+        &[Some((1, 8, 4)), Some((3, 10, 3))]
+    )]
+    #[case::missing_field("number = 1\n", &[None])]
+    fn validate(#[case] input: &str, #[case] spans: &[Option<(usize, usize, usize)>]) {
+        match validate_fields(input, FIELDS) {
+            Ok(()) => assert!(spans.is_empty()),
+            Err(Error::InvalidFields(errors)) => assert_eq!(
+                errors
+                    .iter()
+                    .map(|error| error.span.as_ref().map(|span| (
+                        span.line,
+                        span.column,
+                        span.length
+                    )))
+                    .collect::<Vec<_>>(),
+                spans
+            ),
+            Err(error) => panic!("Unexpected error: {error}"),
+        }
+    }
+
+    /// Ensures that errors are displayed with an annotated span.
+    #[test]
+    fn display_span() {
+        let error = PackageInfoFieldError {
+            span: Some(Span {
+                line: 3,
+                column: 1,
+                length: 7,
+                content: "pkgname = other".to_string(),
+            }),
+            error: Error::DuplicateField { keyword: "pkgname" },
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "The field 'pkgname' must only be set once.
+ --> line 3, column 1
+  |
+3 | pkgname = other
+  | ^^^^^^^"
+        );
+    }
+}
@@ -1,5 +1,7 @@
 //! High-level PKGINFO handling.
 
+pub mod builder;
+mod fields;
 pub mod v1;
 pub mod v2;
 use std::{
@@ -20,6 +22,7 @@ use crate::{Error, PackageInfoSchema, PackageInfoV1, PackageInfoV2};
 ///
 /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
 #[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum PackageInfo {
     /// The [PKGINFOv1] file format.
@@ -25,7 +25,10 @@ use alpm_types::{
 };
 use serde_with::{DisplayFromStr, serde_as};
 
-use crate::Error;
+use crate::{
+    Error,
+    package_info::fields::{Field, validate_fields},
+};
 
 /// PKGINFO version 1
 ///
@@ -81,6 +84,7 @@ use crate::Error;
 /// ```
 #[serde_as]
 #[derive(Clone, Debug, serde::Deserialize, PartialEq, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct PackageInfoV1 {
     /// The name of the package.
@@ -170,6 +174,29 @@ pub struct PackageInfoV1 {
     pub checkdepend: Vec<PackageRelation>,
 }
 
+/// The fields of [`PackageInfoV1`].
+const FIELDS: &[Field] = &[
+    Field::single::<Name>("pkgname"),
+    Field::single::<Name>("pkgbase"),
+    Field::single::<FullVersion>("pkgver"),
+    Field::single::<PackageDescription>("pkgdesc"),
+    Field::single::<Url>("url"),
+    Field::single::<BuildDate>("builddate"),
+    Field::single::<Packager>("packager"),
+    Field::single::<InstalledSize>("size"),
+    Field::single::<Architecture>("arch"),
+    Field::list::<License>("license"),
+    Field::list::<PackageRelation>("replaces"),
+    Field::list::<Group>("group"),
+    Field::list::<PackageRelation>("conflict"),
+    Field::list::<RelationOrSoname>("provides"),
+    Field::list::<Backup>("backup"),
+    Field::list::<RelationOrSoname>("depend"),
+    Field::list::<OptionalDependency>("optdepend"),
+    Field::list::<PackageRelation>("makedepend"),
+    Field::list::<PackageRelation>("checkdepend"),
+];
+
 impl FromStr for PackageInfoV1 {
     type Err = Error;
     /// Create a PackageInfoV1 from a &str
@@ -178,7 +205,9 @@ impl FromStr for PackageInfoV1 {
     ///
     /// Returns an `Error` if any of the fields in `input` can not be validated according to
     /// `PackageInfoV1` or their respective own specification.
+    /// All invalid fields are reported at once using [`Error::InvalidFields`].
     fn from_str(input: &str) -> Result<PackageInfoV1, Self::Err> {
+        validate_fields(input, FIELDS)?;
         let pkginfo: PackageInfoV1 = alpm_parsers::custom_ini::from_str(input)?;
         Ok(pkginfo)
     }
@@ -365,4 +394,21 @@ checkdepend = other-extra-test-tool"#
         valid_pkginfov1.push_str(duplicate);
         assert!(PackageInfoV1::from_str(&valid_pkginfov1).is_err());
     }
+
+    /// Ensures that duplicate unique fields and invalid values are reported at once.
+    #[rstest]
+    fn pkginfov1_from_str_collects_errors(valid_pkginfov1: String) -> TestResult {
+        let pkginfo = valid_pkginfov1.replace("size = 181849963", "size = large")
+            + "\npkgname = other\npkgname = another";
+
+        let Err(Error::InvalidFields(errors)) = PackageInfoV1::from_str(&pkginfo) else {
+            panic!("Expected invalid fields");
+        };
+        assert_eq!(errors.len(), 3);
+        assert!(matches!(
+            errors[1].error,
+            Error::DuplicateField { keyword: "pkgname" }
+        ));
+        Ok(())
+    }
 }
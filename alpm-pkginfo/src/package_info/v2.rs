@@ -27,7 +27,13 @@ use alpm_types::{
 };
 use serde_with::{DisplayFromStr, TryFromInto, serde_as};
 
-use crate::Error;
+use crate::{
+    Error,
+    package_info::{
+        builder::PackageInfoV2Builder,
+        fields::{Field, validate_fields},
+    },
+};
 
 /// PKGINFO version 2
 ///
@@ -84,6 +90,7 @@ use crate::Error;
 /// ```
 #[serde_as]
 #[derive(Clone, Debug, serde::Deserialize, PartialEq, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct PackageInfoV2 {
     /// The name of the package.
@@ -177,6 +184,37 @@ pub struct PackageInfoV2 {
     pub xdata: ExtraData,
 }
 
+impl PackageInfoV2 {
+    /// Creates a new [`PackageInfoV2Builder`] for the programmatic creation of a [`PackageInfoV2`].
+    pub fn builder() -> PackageInfoV2Builder {
+        PackageInfoV2Builder::new()
+    }
+}
+
+/// The fields of [`PackageInfoV2`].
+const FIELDS: &[Field] = &[
+    Field::single::<Name>("pkgname"),
+    Field::single::<Name>("pkgbase"),
+    Field::list::<ExtraDataEntry>("xdata"),
+    Field::single::<FullVersion>("pkgver"),
+    Field::single::<PackageDescription>("pkgdesc"),
+    Field::single::<Url>("url"),
+    Field::single::<BuildDate>("builddate"),
+    Field::single::<Packager>("packager"),
+    Field::single::<InstalledSize>("size"),
+    Field::single::<Architecture>("arch"),
+    Field::list::<License>("license"),
+    Field::list::<PackageRelation>("replaces"),
+    Field::list::<Group>("group"),
+    Field::list::<PackageRelation>("conflict"),
+    Field::list::<RelationOrSoname>("provides"),
+    Field::list::<Backup>("backup"),
+    Field::list::<RelationOrSoname>("depend"),
+    Field::list::<OptionalDependency>("optdepend"),
+    Field::list::<PackageRelation>("makedepend"),
+    Field::list::<PackageRelation>("checkdepend"),
+];
+
 impl FromStr for PackageInfoV2 {
     type Err = Error;
     /// Create a PackageInfoV2 from a &str
@@ -185,7 +223,9 @@ impl FromStr for PackageInfoV2 {
     ///
     /// Returns an `Error` if any of the fields in `input` can not be validated according to
     /// `PackageInfoV2` or their respective own specification.
+    /// All invalid fields are reported at once using [`Error::InvalidFields`].
     fn from_str(input: &str) -> Result<PackageInfoV2, Self::Err> {
+        validate_fields(input, FIELDS)?;
         let pkg_info: PackageInfoV2 = alpm_parsers::custom_ini::from_str(input)?;
         Ok(pkg_info)
     }
@@ -1,7 +1,6 @@
 //! Schemas for PKGINFO data.
 
 use std::{
-    collections::HashMap,
     fmt::{Display, Formatter},
     fs::File,
     path::{Path, PathBuf},
@@ -9,7 +8,6 @@ use std::{
 };
 
 use alpm_common::FileFormatSchema;
-use alpm_parsers::custom_ini::parser::Item;
 use alpm_types::{SchemaVersion, semver_version::Version};
 use fluent_i18n::t;
 
@@ -146,29 +144,18 @@ impl FileFormatSchema for PackageInfoSchema {
     /// [PKGINFOv1]: https://alpm.archlinux.page/specifications/PKGINFOv1.5.html
     /// [PKGINFOv2]: https://alpm.archlinux.page/specifications/PKGINFOv2.5.html
     fn derive_from_str(s: &str) -> Result<PackageInfoSchema, Error> {
-        // Deserialize the file into a simple map, so we can take a look at whether there is a
-        // `xdata` string that indicates PKGINFOv2.
-        let raw: HashMap<String, Item> = alpm_parsers::custom_ini::from_str(s)?;
-        let value = match raw.get("xdata") {
-            Some(Item::Value(value)) => Some(value),
-            Some(Item::List(values)) => {
-                if !values.is_empty() {
-                    values.iter().next()
-                } else {
-                    return Err(Error::ExtraDataEmpty);
-                }
-            }
-            None => return Ok(Self::V1(SchemaVersion::new(Version::new(1, 0, 0)))),
-        };
-
-        if let Some(value) = value {
-            if value.starts_with("pkgtype") {
+        // Only look at the first `xdata` field that indicates PKGINFOv2, so that errors in any
+        // other line can be reported when parsing the data using the detected version.
+        match s
+            .lines()
+            .find_map(|line| line.trim_start().strip_prefix("xdata = "))
+        {
+            None => Ok(Self::V1(SchemaVersion::new(Version::new(1, 0, 0)))),
+            Some("") => Err(Error::ExtraDataEmpty),
+            Some(value) if value.starts_with("pkgtype") => {
                 Ok(Self::V2(SchemaVersion::new(Version::new(2, 0, 0))))
-            } else {
-                Err(Error::FirstExtraDataNotPkgType)
             }
-        } else {
-            Err(Error::ExtraDataEmpty)
+            Some(_) => Err(Error::FirstExtraDataNotPkgType),
         }
     }
 }
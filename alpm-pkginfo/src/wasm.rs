@@ -0,0 +1,48 @@
+//! Entry points for using the [PKGINFO] parser from JavaScript.
+//!
+//! The functions in this module are exported using [`wasm_bindgen`], so that the parser can be
+//! used when compiling for `wasm32-unknown-unknown` (e.g. in a browser).
+//! All data is exchanged as strings, while structured output is provided as JSON.
+//!
+//! [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+
+use std::str::FromStr;
+
+use wasm_bindgen::{JsError, prelude::wasm_bindgen};
+
+use crate::PackageInfo;
+
+/// Validates [PKGINFO] data provided as string `input`.
+///
+/// # Errors
+///
+/// Returns an error if `input` is not valid [PKGINFO] data.
+///
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+#[wasm_bindgen(js_name = validatePkginfo)]
+pub fn validate_pkginfo(input: &str) -> Result<(), JsError> {
+    PackageInfo::from_str(input)?;
+
+    Ok(())
+}
+
+/// Parses [PKGINFO] data provided as string `input` and returns it as JSON.
+///
+/// If `pretty` is `true`, the JSON output is pretty-printed.
+///
+/// # Errors
+///
+/// Returns an error if `input` is not valid [PKGINFO] data or if it cannot be serialized as JSON.
+///
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+#[wasm_bindgen(js_name = formatPkginfo)]
+pub fn format_pkginfo(input: &str, pretty: bool) -> Result<String, JsError> {
+    let pkginfo = PackageInfo::from_str(input)?;
+    let json = if pretty {
+        serde_json::to_string_pretty(&pkginfo)?
+    } else {
+        serde_json::to_string(&pkginfo)?
+    };
+
+    Ok(json)
+}
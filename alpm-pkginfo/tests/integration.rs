@@ -199,6 +199,21 @@ fn format_pkginfo_and_serialize_as_json(#[case] data: &str) -> TestResult {
     Ok(())
 }
 
+/// Ensures that the JSON Schema of the JSON output describes all PKGINFO versions.
+#[test]
+fn json_schema_describes_all_versions() -> TestResult {
+    let mut cmd = cargo_bin_cmd!("alpm-pkginfo");
+    cmd.args(["json-schema", "--pretty"]);
+    let cmd = cmd.unwrap();
+    let schema: serde_json::Value = serde_json::from_slice(&cmd.stdout)?;
+
+    for name in ["PackageInfoV1", "PackageInfoV2"] {
+        assert!(schema["$defs"][name]["properties"]["pkgname"].is_object());
+    }
+    assert!(schema["$defs"]["PackageInfoV2"]["properties"]["xdata"].is_object());
+    Ok(())
+}
+
 /// Return a valid PKGINFO file with all fields filled in.
 fn pkginfo_all_fields(xdata: Option<Vec<String>>) -> PackageInfoInput {
     PackageInfoInput {
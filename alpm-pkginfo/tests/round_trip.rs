@@ -0,0 +1,30 @@
+//! Round-trip property tests for [PKGINFO] data.
+//!
+//! These tests are only executed when the `arbitrary` feature flag is enabled.
+//!
+//! [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+#![cfg(feature = "arbitrary")]
+
+use std::str::FromStr;
+
+use alpm_pkginfo::{PackageInfo, arbitrary::PackageInfoText};
+use arbitrary::Unstructured;
+use proptest::{collection::vec, prelude::*};
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(500))]
+
+    /// Ensures that generated [PKGINFO] data can be parsed and that its string representation
+    /// parses to the same [`PackageInfo`].
+    ///
+    /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+    #[test]
+    fn pkginfo_round_trip(data in vec(any::<u8>(), 0..4096)) {
+        let mut unstructured = Unstructured::new(&data);
+        let text: PackageInfoText = unstructured.arbitrary().unwrap();
+
+        let pkginfo = PackageInfo::from_str(text.as_str()).unwrap();
+        let reparsed = PackageInfo::from_str(&pkginfo.to_string()).unwrap();
+        prop_assert_eq!(pkginfo, reparsed);
+    }
+}
@@ -0,0 +1,170 @@
+//! Benchmark for lookups in [alpm-repo-db] data.
+//!
+//! Compares lookups by package name and by provision name using a [`RepoDbIndex`] with a naive
+//! scan over all packages of a [`RepoDb`].
+//!
+//! The number of generated packages and lookups can be set using the `REPO_DB_PACKAGES` and
+//! `REPO_DB_LOOKUPS` environment variables:
+//!
+//! ```sh
+//! REPO_DB_PACKAGES=100000 cargo bench -p alpm-repo-db --bench index
+//! ```
+//!
+//! [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+
+use std::{
+    env::var,
+    hint::black_box,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use alpm_repo_db::{
+    db::{RepoDb, RepoDbIndex, RepoDbPackage},
+    desc::RepoDescFile,
+};
+use alpm_types::RelationOrSoname;
+use testresult::TestResult;
+
+/// Returns the value of the environment variable `name` or `default`, if it is unset or invalid.
+fn env_or(name: &str, default: usize) -> usize {
+    var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Creates a [`RepoDbPackage`] called `package-{index}` that provides `provision-{index}`.
+fn package(index: usize) -> TestResult<RepoDbPackage> {
+    let desc = format!(
+        r#"%FILENAME%
+package-{index}-1.0.0-1-any.pkg.tar.zst
+
+%NAME%
+package-{index}
+
+%BASE%
+package-{index}
+
+%VERSION%
+1.0.0-1
+
+%CSIZE%
+1
+
+%ISIZE%
+1
+
+%SHA256SUM%
+b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+
+%ARCH%
+any
+
+%BUILDDATE%
+1
+
+%PACKAGER%
+Foobar McFooface <foobar@mcfooface.org>
+
+%PROVIDES%
+provision-{index}=1.0.0
+lib:libprovision-{index}.so.1
+
+"#
+    );
+    Ok(RepoDbPackage {
+        desc: RepoDescFile::from_str(&desc)?,
+        files: None,
+    })
+}
+
+/// Returns the package called `name` by scanning all packages of `db`.
+fn scan_get<'a>(db: &'a RepoDb, name: &str) -> Option<&'a RepoDbPackage> {
+    db.packages()
+        .find(|package| package.name().as_ref() == name)
+}
+
+/// Returns all packages of `db` that provide a package relation called `name`, by scanning all
+/// packages of `db`.
+fn scan_providers<'a>(db: &'a RepoDb, name: &str) -> Vec<&'a RepoDbPackage> {
+    db.packages()
+        .filter(|package| {
+            package
+                .desc
+                .provides()
+                .iter()
+                .any(|provision| match provision {
+                    RelationOrSoname::Relation(relation) => relation.name.as_ref() == name,
+                    RelationOrSoname::SonameV1(_) | RelationOrSoname::SonameV2(_) => false,
+                })
+        })
+        .collect()
+}
+
+/// Calls `lookup` with `lookups` names and returns the average duration of a call.
+fn bench(names: &[String], lookups: usize, mut lookup: impl FnMut(&str) -> usize) -> Duration {
+    let start = Instant::now();
+    let mut found = 0;
+    for name in names.iter().cycle().take(lookups) {
+        found += lookup(black_box(name));
+    }
+    let elapsed = start.elapsed();
+    assert_eq!(found, lookups);
+
+    elapsed / lookups as u32
+}
+
+fn main() -> TestResult {
+    let packages = env_or("REPO_DB_PACKAGES", 20_000).max(1);
+    let lookups = env_or("REPO_DB_LOOKUPS", 1_000).max(1);
+
+    let mut db = RepoDb::default();
+    for index in 0..packages {
+        db.insert(package(index)?);
+    }
+
+    let start = Instant::now();
+    let index = RepoDbIndex::new(&db);
+    let index_creation = start.elapsed();
+
+    // Spread the looked up names over the entire database.
+    let step = (packages / lookups).max(1);
+    let package_names: Vec<String> = (0..packages)
+        .step_by(step)
+        .map(|index| format!("package-{index}"))
+        .collect();
+    let provision_names: Vec<String> = (0..packages)
+        .step_by(step)
+        .map(|index| format!("provision-{index}"))
+        .collect();
+
+    println!("RepoDb lookups ({packages} packages, {lookups} lookups)");
+    println!("  index creation:   {index_creation:?}");
+    println!(
+        "  by name (scan):   {:?}",
+        bench(&package_names, lookups, |name| scan_get(&db, name)
+            .iter()
+            .count())
+    );
+    println!(
+        "  by name (index):  {:?}",
+        bench(&package_names, lookups, |name| index
+            .get(name)
+            .iter()
+            .count())
+    );
+    println!(
+        "  provider (scan):  {:?}",
+        bench(&provision_names, lookups, |name| scan_providers(&db, name)
+            .len())
+    );
+    println!(
+        "  provider (index): {:?}",
+        bench(&provision_names, lookups, |name| index
+            .providers(name)
+            .count())
+    );
+
+    Ok(())
+}
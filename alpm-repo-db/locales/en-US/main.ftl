@@ -56,14 +56,6 @@ error-io-create-output-dir = creating output directory
 
 error-io-path-output-dir = creating output directory
 
-error-io-create-output-file = creating output file
-
-error-io-path-output-file = creating output file
-
-error-io-write-output-file = writing to output file
-
-error-io-path-write-file = writing to output file
-
 error-json-serialize-pretty = serializing to pretty JSON
 
 error-json-serialize = serializing to JSON
@@ -121,6 +113,13 @@ cli-output-long-help = A file path to write to.
   
   If no file is provided, stdout is used instead.
 
+cli-json-schema-about = Output the JSON Schema of the JSON output format of alpm-repo-files data.
+
+cli-json-schema-long-about = Output the JSON Schema of the JSON output format of alpm-repo-files data.
+  
+  The JSON Schema describes the data returned by the format command when using the JSON output
+  format and can be used by external tools to validate it.
+
 cli-validate-about = Validate an alpm-repo-files file.
 
 cli-validate-long-about = Validate an alpm-repo-files file.
@@ -138,10 +137,6 @@ cli-error-json = JSON error while { $context }:
 
 cli-error-stdin-is-terminal = Stdin is a terminal and cannot be piped to
 
-cli-error-io-path-opening-output-file-for-writing = opening output file for writing
-
-cli-error-io-writing-to-output-file = writing to output file
-
 cli-error-io-writing-to-stdout = writing to stdout
 
 cli-error-io-path-reading-file-to-string = reading file to string
@@ -151,3 +146,140 @@ cli-error-io-reading-stdin-to-string = reading stdin to string
 cli-error-json-serializing-alpm-repo-files-data-as-pretty-printed-json-string = serializing alpm-repo-files data as pretty printed JSON string
 
 cli-error-json-serializing-alpm-repo-files-data-as-json-string = serializing alpm-repo-files data as JSON string
+
+cli-error-json-serializing-json-schema-as-pretty-printed-json-string = serializing the JSON Schema as pretty printed JSON string
+
+cli-error-json-serializing-json-schema-as-json-string = serializing the JSON Schema as JSON string
+
+error-io-read-repo-db = reading the repository sync database
+
+error-io-read-repo-db-entries = reading the entries of the repository sync database
+
+error-io-read-repo-db-entry = reading an entry of the repository sync database
+
+error-invalid-repo-db-entry =
+  Invalid entry "{ $path }" in repository sync database:
+  { $reason }
+
+error-invalid-repo-db-entry-dir-name = The directory name does not match the package name and version (expected "{ $expected }").
+
+error-invalid-repo-db-entry-duplicate-name = The package name { $name } is used more than once.
+
+error-invalid-repo-db-entry-missing-desc = The package directory contains no desc file.
+
+error-invalid-repo-db-entry-unsorted = The package { $name } is not sorted after the package { $previous }.
+
+error-io-path-read-package = reading the package file
+
+error-io-path-read-signature = reading the signature file of the package
+
+error-io-write-repo-db = writing the repository sync database
+
+error-io-path-create-repo-db = creating the repository sync database file
+
+error-io-path-remove-repo-db-symlink = removing the symlink to the repository sync database
+
+error-io-path-create-repo-db-symlink = creating the symlink to the repository sync database
+
+error-io-path-run-gpg = running gpg to sign the file
+
+error-io-path-write-repo-db-signature = writing the signature file of the repository sync database
+
+error-io-path-remove-repo-db-signature = removing the outdated signature file of the repository sync database
+
+error-json-serialize-repo-db-verification = serializing the signature verification results of the repository sync database
+
+error-io-path-read-repo-dir = reading the repository directory
+
+error-json-serialize-repo-dir-report = serializing the consistency report of the repository directory
+
+error-json-serialize-repo-db-diff = serializing the differences between repository sync databases
+
+error-signing-failed =
+  Failed to sign the file "{ $path }":
+  { $reason }
+
+error-invalid-repo-db-file-name = The file name of the repository sync database "{ $path }" does not follow the pattern "<name>.db[.tar[.<extension>]]".
+
+db-cli-about = Create and update ALPM repository sync databases.
+
+db-cli-long-about = Create and update ALPM repository sync databases (alpm-repo-db) from package files.
+
+db-cli-add-about = Add package files to a repository sync database.
+
+db-cli-add-long-about =
+  Add package files to a repository sync database.
+
+  Creates the database if it does not exist yet.
+  Packages of the same name that are already present in the database are replaced.
+  Both the database (e.g. "core.db.tar.gz") and the files database (e.g. "core.files.tar.gz") are written.
+
+db-cli-add-packages-help = The package files to add
+
+db-cli-remove-about = Remove packages from a repository sync database.
+
+db-cli-remove-long-about =
+  Remove packages from a repository sync database.
+
+  Names of packages that are not present in the database are ignored.
+  Both the database (e.g. "core.db.tar.gz") and the files database (e.g. "core.files.tar.gz") are written.
+
+db-cli-remove-names-help = The names of the packages to remove
+
+db-cli-sign-help = Sign the repository sync database files
+
+db-cli-sign-long-help =
+  Sign the repository sync database files using gpg.
+
+  Creates a detached signature next to each database file (e.g. "core.db.tar.gz.sig").
+  Without this option, existing signatures of the database files are removed.
+
+db-cli-key-help = The key to sign the repository sync database files with
+
+db-cli-key-long-help =
+  The key to sign the repository sync database files with (e.g. an OpenPGP fingerprint).
+
+  If not provided, the default signing key of gpg is used.
+
+db-cli-diff-about = Compare two snapshots of a repository sync database.
+
+db-cli-diff-long-about =
+  Compare two snapshots of a repository sync database.
+
+  Prints the added, removed, upgraded and downgraded packages as JSON.
+
+db-cli-diff-old-help = The path of the old repository sync database
+
+db-cli-diff-new-help = The path of the new repository sync database
+
+db-cli-pretty-help = Pretty-print the JSON output
+
+db-cli-validate-about = Validate the consistency of a repository directory.
+
+db-cli-validate-long-about =
+  Validate the consistency of a repository directory.
+
+  Checks that every package in the repository sync database exists in the directory of the database with matching file name, size and checksum, and that its signature file is present and matches the database.
+  Package files that are not referenced by the database are reported as orphans.
+  Prints a per-package report as JSON and exits with a non-zero exit code if inconsistencies are found.
+
+db-cli-validate-require-signatures-help = Require a signature file for every package file
+
+db-cli-verify-about = Verify the signatures of a repository sync database.
+
+db-cli-verify-long-about =
+  Verify the signatures of a repository sync database.
+
+  The signatures are verified using the verifiers for repository metadata in the VOA hierarchy.
+  The results are printed as JSON.
+  Exits with a non-zero exit code if the signature of any database file is missing or invalid.
+
+db-cli-verify-os-help = The OS for which verifiers are looked up in the VOA hierarchy
+
+db-cli-db-file-help = The path of the repository sync database
+
+db-cli-db-file-long-help =
+  The path of the repository sync database (e.g. "core.db.tar.gz").
+
+  The compression of the database is derived from the file extension.
+  The files database is located next to it (e.g. "core.files.tar.gz").
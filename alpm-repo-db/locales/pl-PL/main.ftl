@@ -40,10 +40,6 @@ error-io-read-repo-desc = odczytu danych desc repozytorium pakietów
 
 error-io-create-output-dir = tworzenia katalogu wyjściowego
 
-error-io-create-output-file = tworzenia pliku wyjściowego
-
-error-io-write-output-file = zapisywania do pliku wyjściowego
-
 error-json-serialize-pretty = serializacji do formatu JSON z wcięciami
 
 error-json-serialize = serializacji do formatu JSON
@@ -0,0 +1,77 @@
+//! Command line interface for creating and updating [alpm-repo-db] files.
+//!
+//! [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+
+use std::process::ExitCode;
+
+use alpm_common::ErrorCategory;
+use alpm_repo_db::db::{
+    GpgSigner,
+    RepoDbSigner,
+    cli::{Cli, Command},
+    commands::{add, diff, remove, validate, verify},
+};
+use clap::Parser;
+
+// Initialize i18n support.
+fluent_i18n::i18n!("locales");
+
+/// Runs the `alpm-repo-db` executable.
+///
+/// Depending on [`Cli`], delegates to [`add`], [`remove`], [`diff`], [`validate`] or [`verify`].
+/// In case of success, exits with [`ExitCode::SUCCESS`].
+///
+/// If an error occurs, the error message is emitted on stderr and the executable exits with an
+/// [`ExitCode`] based on the [`ErrorCategory`] of the error.
+/// If [`validate`] finds inconsistencies or [`verify`] finds missing or invalid signatures, the
+/// executable exits with [`ExitCode::FAILURE`] as well.
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Add {
+            db_file,
+            packages,
+            sign,
+            key,
+        } => {
+            let signer = sign.then(|| GpgSigner::new(key));
+            add(
+                &db_file,
+                &packages,
+                signer.as_ref().map(|signer| signer as &dyn RepoDbSigner),
+            )
+            .map(|()| true)
+        }
+        Command::Remove {
+            db_file,
+            names,
+            sign,
+            key,
+        } => {
+            let signer = sign.then(|| GpgSigner::new(key));
+            remove(
+                &db_file,
+                &names,
+                signer.as_ref().map(|signer| signer as &dyn RepoDbSigner),
+            )
+            .map(|()| true)
+        }
+        Command::Diff { old, new, pretty } => diff(&old, &new, pretty).map(|()| true),
+        Command::Validate {
+            db_file,
+            require_signatures,
+            pretty,
+        } => validate(&db_file, require_signatures, pretty),
+        Command::Verify { db_file, os } => verify(&db_file, os),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("{error}");
+            ErrorCategory::from(&error).into()
+        }
+    }
+}
@@ -3,13 +3,14 @@
 //! [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
 
 use std::{
-    fs::{File, read_to_string},
+    fs::read_to_string,
     io::{IsTerminal, Read, Write, stdin, stdout},
     path::PathBuf,
     process::ExitCode,
     str::FromStr,
 };
 
+use alpm_common::{ErrorCategory, write_atomic};
 use alpm_repo_db::files::{
     RepoFiles,
     RepoFilesV1,
@@ -17,6 +18,7 @@ use alpm_repo_db::files::{
 };
 use clap::Parser;
 use fluent_i18n::t;
+use schemars::generate::SchemaSettings;
 
 // Initialize i18n support.
 fluent_i18n::i18n!("locales");
@@ -44,6 +46,17 @@ enum Error {
     StdinIsTerminal,
 }
 
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::AlpmFiles(error) => error.into(),
+            Error::Json { .. } => Self::Parse,
+            Error::StdinIsTerminal => Self::Io,
+        }
+    }
+}
+
 /// Creates [`alpm-repo-files`] data from a directory.
 ///
 /// Outputs data on [`stdout`] if no `output` is provided.
@@ -63,16 +76,7 @@ fn create_from_dir(input_dir: PathBuf, output: Option<PathBuf>) -> Result<(), Er
     let files = RepoFiles::V1(RepoFilesV1::try_from(input_dir)?);
 
     if let Some(output) = output {
-        let mut output_file =
-            File::create(&output).map_err(|source| alpm_repo_db::files::Error::IoPath {
-                path: output.to_path_buf(),
-                context: t!("cli-error-io-path-opening-output-file-for-writing"),
-                source,
-            })?;
-        write!(output_file, "{files}").map_err(|source| alpm_repo_db::files::Error::Io {
-            context: t!("cli-error-io-writing-to-output-file"),
-            source,
-        })?;
+        write_atomic(&output, files.to_string()).map_err(alpm_repo_db::files::Error::from)?;
     } else {
         stdout()
             .write(&files.to_string().into_bytes())
@@ -156,16 +160,7 @@ fn format_output(
     };
 
     if let Some(output) = output {
-        let mut output_file =
-            File::create(&output).map_err(|source| alpm_repo_db::files::Error::IoPath {
-                path: output.to_path_buf(),
-                context: t!("cli-opening-output-file-for-writing"),
-                source,
-            })?;
-        write!(output_file, "{data}").map_err(|source| alpm_repo_db::files::Error::Io {
-            context: t!("cli-writing-to-output-file"),
-            source,
-        })?;
+        write_atomic(&output, data).map_err(alpm_repo_db::files::Error::from)?;
     } else {
         write!(stdout(), "{data}").map_err(|source| alpm_repo_db::files::Error::Io {
             context: t!("cli-writing-to-stdout"),
@@ -216,14 +211,53 @@ fn validate_input(input_file: Option<PathBuf>) -> Result<(), Error> {
     Ok(())
 }
 
+/// Outputs the JSON Schema of the JSON representation of [`alpm-repo-files`] data on [`stdout`].
+///
+/// The JSON Schema is generated from [`RepoFiles`] and describes all of its versions.
+/// If `pretty` is `true`, the JSON Schema is pretty-printed.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - the JSON Schema cannot be serialized,
+/// - or [`stdout`] cannot be written to.
+///
+/// [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
+fn json_schema(pretty: bool) -> Result<(), Error> {
+    let schema = SchemaSettings::draft2020_12()
+        .for_serialize()
+        .into_generator()
+        .into_root_schema_for::<RepoFiles>();
+    let mut output = if pretty {
+        serde_json::to_string_pretty(&schema).map_err(|source| Error::Json {
+            context: t!("cli-error-json-serializing-json-schema-as-pretty-printed-json-string"),
+            source,
+        })?
+    } else {
+        serde_json::to_string(&schema).map_err(|source| Error::Json {
+            context: t!("cli-error-json-serializing-json-schema-as-json-string"),
+            source,
+        })?
+    };
+    output.push('\n');
+
+    write!(stdout(), "{output}").map_err(|source| alpm_repo_db::files::Error::Io {
+        context: t!("cli-error-io-writing-to-stdout"),
+        source,
+    })?;
+
+    Ok(())
+}
+
 /// Runs the `alpm-repo-files` executable.
 ///
 /// Depending on [`Cli`], delegates to `alpm_repo_create`, `alpm_repo_format` or
 /// `alpm_repo_validate`.
 /// In case of success, exits with [`ExitCode::SUCCESS`].
 ///
-/// If an error occurs, the error message is emitted on stderr and the executable exits with
-/// [`ExitCode::FAILURE`].
+/// If an error occurs, the error message is emitted on stderr and the executable exits with an
+/// [`ExitCode`] based on the [`ErrorCategory`] of the error.
 fn main() -> ExitCode {
     let cli = Cli::parse();
 
@@ -236,11 +270,12 @@ fn main() -> ExitCode {
             pretty,
         } => format_output(input_file, output, format, pretty),
         Command::Validate { input_file } => validate_input(input_file),
+        Command::JsonSchema { pretty } => json_schema(pretty),
     };
 
     if let Err(error) = result {
         eprintln!("{error}");
-        ExitCode::FAILURE
+        ErrorCategory::from(&error).into()
     } else {
         ExitCode::SUCCESS
     }
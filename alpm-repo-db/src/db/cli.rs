@@ -0,0 +1,174 @@
+//! CLI handling for the `alpm-repo-db` executable.
+
+use std::path::PathBuf;
+
+use alpm_types::Name;
+use clap::Parser;
+use fluent_i18n::t;
+use voa::core::Os;
+
+/// The command line interface for `alpm-repo-db`.
+#[derive(Clone, Debug, Parser)]
+#[command(
+    about = t!("db-cli-about"),
+    author,
+    long_about = t!("db-cli-long-about"),
+    name = "alpm-repo-db",
+    version
+)]
+pub struct Cli {
+    /// The commands of the `alpm-repo-db` executable.
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// A command of the `alpm-repo-db` executable.
+#[derive(Clone, Debug, Parser)]
+#[command(about, author, version)]
+pub enum Command {
+    /// The add command.
+    #[command(about = t!("db-cli-add-about"), long_about = t!("db-cli-add-long-about"))]
+    Add {
+        /// The path of the repository sync database.
+        #[arg(
+            env = "ALPM_REPO_DB_ADD_DB_FILE",
+            help = t!("db-cli-db-file-help"),
+            long_help = t!("db-cli-db-file-long-help"),
+            value_name = "DB_FILE"
+        )]
+        db_file: PathBuf,
+
+        /// The package files to add.
+        #[arg(
+            help = t!("db-cli-add-packages-help"),
+            required = true,
+            value_name = "PACKAGE"
+        )]
+        packages: Vec<PathBuf>,
+
+        /// Whether to sign the database files.
+        #[arg(
+            env = "ALPM_REPO_DB_ADD_SIGN",
+            help = t!("db-cli-sign-help"),
+            long,
+            long_help = t!("db-cli-sign-long-help")
+        )]
+        sign: bool,
+
+        /// The key to sign the database files with.
+        #[arg(
+            env = "ALPM_REPO_DB_ADD_KEY",
+            help = t!("db-cli-key-help"),
+            long,
+            long_help = t!("db-cli-key-long-help"),
+            requires = "sign",
+            value_name = "KEY"
+        )]
+        key: Option<String>,
+    },
+
+    /// The remove command.
+    #[command(about = t!("db-cli-remove-about"), long_about = t!("db-cli-remove-long-about"))]
+    Remove {
+        /// The path of the repository sync database.
+        #[arg(
+            env = "ALPM_REPO_DB_REMOVE_DB_FILE",
+            help = t!("db-cli-db-file-help"),
+            long_help = t!("db-cli-db-file-long-help"),
+            value_name = "DB_FILE"
+        )]
+        db_file: PathBuf,
+
+        /// The names of the packages to remove.
+        #[arg(
+            help = t!("db-cli-remove-names-help"),
+            required = true,
+            value_name = "NAME"
+        )]
+        names: Vec<Name>,
+
+        /// Whether to sign the database files.
+        #[arg(
+            env = "ALPM_REPO_DB_REMOVE_SIGN",
+            help = t!("db-cli-sign-help"),
+            long,
+            long_help = t!("db-cli-sign-long-help")
+        )]
+        sign: bool,
+
+        /// The key to sign the database files with.
+        #[arg(
+            env = "ALPM_REPO_DB_REMOVE_KEY",
+            help = t!("db-cli-key-help"),
+            long,
+            long_help = t!("db-cli-key-long-help"),
+            requires = "sign",
+            value_name = "KEY"
+        )]
+        key: Option<String>,
+    },
+
+    /// The diff command.
+    #[command(about = t!("db-cli-diff-about"), long_about = t!("db-cli-diff-long-about"))]
+    Diff {
+        /// The path of the old repository sync database.
+        #[arg(help = t!("db-cli-diff-old-help"), value_name = "OLD_DB_FILE")]
+        old: PathBuf,
+
+        /// The path of the new repository sync database.
+        #[arg(help = t!("db-cli-diff-new-help"), value_name = "NEW_DB_FILE")]
+        new: PathBuf,
+
+        /// Whether to pretty-print the JSON output.
+        #[arg(help = t!("db-cli-pretty-help"), long, short)]
+        pretty: bool,
+    },
+
+    /// The validate command.
+    #[command(about = t!("db-cli-validate-about"), long_about = t!("db-cli-validate-long-about"))]
+    Validate {
+        /// The path of the repository sync database.
+        #[arg(
+            env = "ALPM_REPO_DB_VALIDATE_DB_FILE",
+            help = t!("db-cli-db-file-help"),
+            long_help = t!("db-cli-db-file-long-help"),
+            value_name = "DB_FILE"
+        )]
+        db_file: PathBuf,
+
+        /// Whether to require a signature file for every package file.
+        #[arg(
+            env = "ALPM_REPO_DB_VALIDATE_REQUIRE_SIGNATURES",
+            help = t!("db-cli-validate-require-signatures-help"),
+            long
+        )]
+        require_signatures: bool,
+
+        /// Whether to pretty-print the JSON output.
+        #[arg(help = t!("db-cli-pretty-help"), long, short)]
+        pretty: bool,
+    },
+
+    /// The verify command.
+    #[command(about = t!("db-cli-verify-about"), long_about = t!("db-cli-verify-long-about"))]
+    Verify {
+        /// The path of the repository sync database.
+        #[arg(
+            env = "ALPM_REPO_DB_VERIFY_DB_FILE",
+            help = t!("db-cli-db-file-help"),
+            long_help = t!("db-cli-db-file-long-help"),
+            value_name = "DB_FILE"
+        )]
+        db_file: PathBuf,
+
+        /// The OS for which verifiers are looked up.
+        #[arg(
+            default_value = "arch",
+            env = "ALPM_REPO_DB_VERIFY_OS",
+            help = t!("db-cli-verify-os-help"),
+            long,
+            value_name = "OS"
+        )]
+        os: Os,
+    },
+}
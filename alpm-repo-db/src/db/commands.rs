@@ -0,0 +1,512 @@
+//! Commands for creating and updating [alpm-repo-db] files.
+//!
+//! [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+
+use std::{
+    fs::remove_file,
+    os::unix::fs::symlink,
+    path::{Path, PathBuf},
+};
+
+use alpm_compress::{
+    compression::{
+        Bzip2CompressionLevel,
+        CompressionSettings,
+        GzipCompressionLevel,
+        XzCompressionLevel,
+        ZstdCompressionLevel,
+        ZstdThreads,
+    },
+    decompression::DecompressionSettings,
+};
+use alpm_types::Name;
+use fluent_i18n::t;
+use voa::core::Os;
+
+use crate::{
+    Error,
+    db::{
+        RepoDb,
+        RepoDbKind,
+        RepoDbPackage,
+        RepoDbSigner,
+        RepoDbVerification,
+        sign_file,
+        signature_path,
+        verify_file,
+    },
+};
+
+/// The paths of the files that make up an [alpm-repo-db].
+///
+/// Like with [repo-add], a database is identified by the path of its [`RepoDbKind::Db`] archive
+/// (e.g. `repo/core.db.tar.gz`).
+/// The [`RepoDbKind::Files`] archive is located next to it (e.g. `repo/core.files.tar.gz`).
+/// If the archives carry a file extension, symlinks without it (e.g. `repo/core.db` and
+/// `repo/core.files`) point at them.
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+/// [repo-add]: https://man.archlinux.org/man/repo-add.8
+#[derive(Clone, Debug)]
+pub struct RepoDbPaths {
+    /// The directory that contains all files.
+    dir: PathBuf,
+    /// The name of the repository (e.g. `core`).
+    name: String,
+    /// The file extension of the archives (e.g. `.tar.gz`), which may be empty.
+    extension: String,
+}
+
+impl RepoDbPaths {
+    /// Creates a new [`RepoDbPaths`] from the path of a [`RepoDbKind::Db`] archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file name of `path` does not follow the pattern
+    /// `<name>.db[.tar[.<extension>]]`.
+    pub fn new(path: &Path) -> Result<Self, Error> {
+        let invalid = || Error::InvalidRepoDbFileName {
+            path: path.to_path_buf(),
+        };
+        let file_name = path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .ok_or_else(invalid)?;
+        let (name, extension) = file_name
+            .match_indices(".db")
+            .map(|(index, _)| (&file_name[..index], &file_name[index + 3..]))
+            .find(|(name, extension)| {
+                !name.is_empty() && (extension.is_empty() || extension.starts_with(".tar"))
+            })
+            .ok_or_else(invalid)?;
+
+        Ok(Self {
+            dir: path.parent().map(Path::to_path_buf).unwrap_or_default(),
+            name: name.to_string(),
+            extension: extension.to_string(),
+        })
+    }
+
+    /// Returns the path of the archive of `kind`.
+    pub fn archive(&self, kind: RepoDbKind) -> PathBuf {
+        self.dir.join(format!(
+            "{}.{}{}",
+            self.name,
+            kind_name(kind),
+            self.extension
+        ))
+    }
+
+    /// Returns the path of the symlink to the archive of `kind`.
+    ///
+    /// Returns [`None`] if the archives carry no file extension.
+    pub fn symlink(&self, kind: RepoDbKind) -> Option<PathBuf> {
+        (!self.extension.is_empty())
+            .then(|| self.dir.join(format!("{}.{}", self.name, kind_name(kind))))
+    }
+
+    /// Returns the [`CompressionSettings`] for the archives.
+    ///
+    /// Uses the default compression level of the compression algorithm that is indicated by the
+    /// file extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file extension does not indicate a supported compression algorithm.
+    pub fn compression(&self) -> Result<CompressionSettings, Error> {
+        if self.extension.is_empty() {
+            return Ok(CompressionSettings::None);
+        }
+
+        let settings =
+            match DecompressionSettings::from_archive_path(&self.archive(RepoDbKind::Db))? {
+                DecompressionSettings::Bzip2 => CompressionSettings::Bzip2 {
+                    compression_level: Bzip2CompressionLevel::default(),
+                },
+                DecompressionSettings::Gzip => CompressionSettings::Gzip {
+                    compression_level: GzipCompressionLevel::default(),
+                },
+                DecompressionSettings::Xz => CompressionSettings::Xz {
+                    compression_level: XzCompressionLevel::default(),
+                },
+                DecompressionSettings::Zstd => CompressionSettings::Zstd {
+                    compression_level: ZstdCompressionLevel::default(),
+                    threads: ZstdThreads::default(),
+                },
+                DecompressionSettings::None => CompressionSettings::None,
+            };
+        Ok(settings)
+    }
+
+    /// Reads the existing [`RepoDb`].
+    ///
+    /// Prefers the [`RepoDbKind::Files`] archive, as it also contains the [alpm-repo-files] data
+    /// of each package.
+    /// Returns an empty [`RepoDb`] if neither archive exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`RepoDb::from_file`] fails.
+    ///
+    /// [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
+    pub fn read(&self) -> Result<RepoDb, Error> {
+        for kind in [RepoDbKind::Files, RepoDbKind::Db] {
+            let path = self.archive(kind);
+            if path.exists() {
+                return RepoDb::from_file(path);
+            }
+        }
+        Ok(RepoDb::default())
+    }
+
+    /// Writes `db` to the archives of all [`RepoDbKind`]s and creates their symlinks.
+    ///
+    /// If `signer` is [`Some`], each archive is signed and a symlink to each signature file is
+    /// created as well.
+    /// Otherwise, any existing signature files are removed, as they no longer match the archives.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - [`RepoDbPaths::compression`] fails,
+    /// - [`RepoDb::write_to_file`] fails,
+    /// - [`sign_file`] fails,
+    /// - an outdated signature file cannot be removed,
+    /// - or a symlink cannot be created.
+    pub fn write(&self, db: &RepoDb, signer: Option<&dyn RepoDbSigner>) -> Result<(), Error> {
+        let settings = self.compression()?;
+        for kind in [RepoDbKind::Db, RepoDbKind::Files] {
+            let archive = self.archive(kind);
+            db.write_to_file(&archive, kind, &settings)?;
+
+            let signature = signature_path(&archive);
+            match signer {
+                Some(signer) => {
+                    sign_file(&archive, signer)?;
+                }
+                None if signature.exists() => {
+                    remove_file(&signature).map_err(|source| Error::IoPath {
+                        path: signature.clone(),
+                        context: t!("error-io-path-remove-repo-db-signature"),
+                        source,
+                    })?;
+                }
+                None => {}
+            }
+
+            let Some(link) = self.symlink(kind) else {
+                continue;
+            };
+            replace_symlink(&archive, &link)?;
+            let signature_link = signature_path(&link);
+            if signer.is_some() {
+                replace_symlink(&signature, &signature_link)?;
+            } else {
+                remove_symlink(&signature_link)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the signatures of the archives of all [`RepoDbKind`]s.
+    ///
+    /// Uses verifiers for `os` from the [VOA] hierarchy.
+    /// Archives that do not exist are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`verify_file`] fails for one of the archives.
+    ///
+    /// [VOA]: https://uapi-group.org/specifications/specs/file_hierarchy_for_the_verification_of_os_artifacts/
+    pub fn verify(&self, os: Os) -> Result<Vec<RepoDbVerification>, Error> {
+        [RepoDbKind::Db, RepoDbKind::Files]
+            .into_iter()
+            .map(|kind| self.archive(kind))
+            .filter(|archive| archive.exists())
+            .map(|archive| verify_file(archive, os.clone()))
+            .collect()
+    }
+}
+
+/// Removes the symlink at `link`, if it exists.
+///
+/// # Errors
+///
+/// Returns an error if the symlink cannot be removed.
+fn remove_symlink(link: &Path) -> Result<(), Error> {
+    if link.is_symlink() {
+        remove_file(link).map_err(|source| Error::IoPath {
+            path: link.to_path_buf(),
+            context: t!("error-io-path-remove-repo-db-symlink"),
+            source,
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Creates a symlink at `link` that points to the file name of `target`.
+///
+/// Any existing symlink at `link` is replaced.
+///
+/// # Errors
+///
+/// Returns an error if the existing symlink cannot be removed or the symlink cannot be created.
+fn replace_symlink(target: &Path, link: &Path) -> Result<(), Error> {
+    remove_symlink(link)?;
+    // The file name is always present, as all targets have been derived from one.
+    let target = target.file_name().map(PathBuf::from).unwrap_or_default();
+    symlink(target, link).map_err(|source| Error::IoPath {
+        path: link.to_path_buf(),
+        context: t!("error-io-path-create-repo-db-symlink"),
+        source,
+    })
+}
+
+/// Returns the name that is used for a [`RepoDbKind`] in file names.
+fn kind_name(kind: RepoDbKind) -> &'static str {
+    match kind {
+        RepoDbKind::Db => "db",
+        RepoDbKind::Files => "files",
+    }
+}
+
+/// Adds the package files at `packages` to the [alpm-repo-db] at `db_file`.
+///
+/// Creates the database, if it does not exist yet.
+/// Packages of the same name that are already present in the database are replaced.
+/// If `signer` is [`Some`], the database files are signed.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - the file name of `db_file` is not valid,
+/// - the existing database cannot be read,
+/// - a [`RepoDbPackage`] cannot be created from one of the `packages`,
+/// - or the database cannot be written.
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+pub fn add(
+    db_file: &Path,
+    packages: &[PathBuf],
+    signer: Option<&dyn RepoDbSigner>,
+) -> Result<(), Error> {
+    let paths = RepoDbPaths::new(db_file)?;
+    let mut db = paths.read()?;
+    for package in packages {
+        db.insert(RepoDbPackage::from_package_file(package)?);
+    }
+    paths.write(&db, signer)
+}
+
+/// Removes the packages with the names `names` from the [alpm-repo-db] at `db_file`.
+///
+/// Names of packages that are not present in the database are ignored.
+/// If `signer` is [`Some`], the database files are signed.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - the file name of `db_file` is not valid,
+/// - the existing database cannot be read,
+/// - or the database cannot be written.
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+pub fn remove(
+    db_file: &Path,
+    names: &[Name],
+    signer: Option<&dyn RepoDbSigner>,
+) -> Result<(), Error> {
+    let paths = RepoDbPaths::new(db_file)?;
+    let mut db = paths.read()?;
+    for name in names {
+        db.remove(name);
+    }
+    paths.write(&db, signer)
+}
+
+/// Prints the differences between the [alpm-repo-db]s at `old` and `new` as JSON to stdout.
+///
+/// If `pretty` is `true`, the JSON output is pretty-printed.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - one of the databases cannot be read,
+/// - or the differences cannot be serialized as JSON.
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+pub fn diff(old: &Path, new: &Path, pretty: bool) -> Result<(), Error> {
+    let diff = RepoDb::from_file(old)?.diff(&RepoDb::from_file(new)?);
+    let json = if pretty {
+        serde_json::to_string_pretty(&diff)
+    } else {
+        serde_json::to_string(&diff)
+    }
+    .map_err(|source| Error::Json {
+        context: t!("error-json-serialize-repo-db-diff"),
+        source,
+    })?;
+    println!("{json}");
+
+    Ok(())
+}
+
+/// Validates the consistency of the repository directory of the [alpm-repo-db] at `db_file`.
+///
+/// The repository directory is the directory that contains `db_file`.
+/// Prints the [`RepoDirReport`][crate::db::RepoDirReport] as JSON to stdout.
+/// If `pretty` is `true`, the JSON output is pretty-printed.
+/// Returns `true` if the repository directory is consistent.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - the file name of `db_file` is not valid,
+/// - the database cannot be read,
+/// - [`RepoDb::validate_dir`] fails,
+/// - or the report cannot be serialized as JSON.
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+pub fn validate(db_file: &Path, require_signatures: bool, pretty: bool) -> Result<bool, Error> {
+    let paths = RepoDbPaths::new(db_file)?;
+    let dir = if paths.dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        paths.dir.as_path()
+    };
+    let report = RepoDb::from_file(db_file)?.validate_dir(dir, require_signatures)?;
+    let json = if pretty {
+        serde_json::to_string_pretty(&report)
+    } else {
+        serde_json::to_string(&report)
+    }
+    .map_err(|source| Error::Json {
+        context: t!("error-json-serialize-repo-dir-report"),
+        source,
+    })?;
+    println!("{json}");
+
+    Ok(report.is_consistent())
+}
+
+/// Verifies the signatures of the [alpm-repo-db] at `db_file`.
+///
+/// Uses verifiers for `os` from the [VOA] hierarchy and prints the results as JSON to stdout.
+/// Returns `true` if the signatures of all database files are valid.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - the file name of `db_file` is not valid,
+/// - the database file does not exist,
+/// - the signatures cannot be verified,
+/// - or the results cannot be serialized as JSON.
+///
+/// [VOA]: https://uapi-group.org/specifications/specs/file_hierarchy_for_the_verification_of_os_artifacts/
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+pub fn verify(db_file: &Path, os: Os) -> Result<bool, Error> {
+    let paths = RepoDbPaths::new(db_file)?;
+    if !paths.archive(RepoDbKind::Db).exists() {
+        return Err(Error::IoPath {
+            path: db_file.to_path_buf(),
+            context: t!("error-io-path-open-file"),
+            source: std::io::ErrorKind::NotFound.into(),
+        });
+    }
+
+    let verifications = paths.verify(os)?;
+    let json = serde_json::to_string_pretty(&verifications).map_err(|source| Error::Json {
+        context: t!("error-json-serialize-repo-db-verification"),
+        source,
+    })?;
+    println!("{json}");
+
+    Ok(verifications.iter().all(RepoDbVerification::is_valid))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tempfile::tempdir;
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that the paths of all files of a database are derived from its file name.
+    #[rstest]
+    #[case::gzip("repo/core.db.tar.gz", "repo/core.files.tar.gz", Some("repo/core.db"))]
+    #[case::zstd("core.db.tar.zst", "core.files.tar.zst", Some("core.db"))]
+    #[case::dotted_name(
+        "repo/my.db.repo.db.tar",
+        "repo/my.db.repo.files.tar",
+        Some("repo/my.db.repo.db")
+    )]
+    #[case::no_extension("repo/core.db", "repo/core.files", None)]
+    fn repo_db_paths(
+        #[case] db_file: &str,
+        #[case] files: &str,
+        #[case] symlink: Option<&str>,
+    ) -> TestResult {
+        let paths = RepoDbPaths::new(Path::new(db_file))?;
+        assert_eq!(paths.archive(RepoDbKind::Db), PathBuf::from(db_file));
+        assert_eq!(paths.archive(RepoDbKind::Files), PathBuf::from(files));
+        assert_eq!(paths.symlink(RepoDbKind::Db), symlink.map(PathBuf::from));
+        Ok(())
+    }
+
+    /// A [`RepoDbSigner`] that returns a static signature.
+    #[derive(Debug)]
+    struct StaticSigner;
+
+    impl RepoDbSigner for StaticSigner {
+        fn sign_file(&self, _path: &Path) -> Result<Vec<u8>, Error> {
+            Ok(b"signature".to_vec())
+        }
+    }
+
+    /// Ensures that signatures and their symlinks are created when signing and removed otherwise.
+    #[test]
+    fn repo_db_paths_write_signatures() -> TestResult {
+        let temp_dir = tempdir()?;
+        let paths = RepoDbPaths::new(&temp_dir.path().join("core.db.tar.gz"))?;
+        let db = RepoDb::default();
+
+        paths.write(&db, Some(&StaticSigner))?;
+        for kind in [RepoDbKind::Db, RepoDbKind::Files] {
+            let link = paths.symlink(kind).ok_or("symlink missing")?;
+            assert!(signature_path(paths.archive(kind)).is_file());
+            assert!(signature_path(&link).is_symlink());
+            assert!(signature_path(&link).is_file());
+        }
+
+        paths.write(&db, None)?;
+        for kind in [RepoDbKind::Db, RepoDbKind::Files] {
+            let link = paths.symlink(kind).ok_or("symlink missing")?;
+            assert!(!signature_path(paths.archive(kind)).exists());
+            assert!(!signature_path(&link).is_symlink());
+            assert!(link.is_symlink());
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that invalid database file names are rejected.
+    #[rstest]
+    #[case::no_db("repo/core.tar.gz")]
+    #[case::no_name("repo/.db.tar.gz")]
+    #[case::wrong_extension("repo/core.db.gz")]
+    fn repo_db_paths_invalid(#[case] db_file: &str) {
+        assert!(matches!(
+            RepoDbPaths::new(Path::new(db_file)),
+            Err(Error::InvalidRepoDbFileName { .. })
+        ));
+    }
+}
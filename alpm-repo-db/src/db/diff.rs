@@ -0,0 +1,233 @@
+//! Comparison of [alpm-repo-db] snapshots.
+//!
+//! [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+
+use std::cmp::Ordering;
+
+use alpm_types::{FullVersion, Name};
+use serde::Serialize;
+
+use crate::db::RepoDb;
+
+/// A package that is present in only one of two compared [`RepoDb`]s.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct RepoDbDiffPackage {
+    /// The name of the package.
+    pub name: Name,
+    /// The version of the package.
+    pub version: FullVersion,
+}
+
+/// A package that is present in two compared [`RepoDb`]s in different versions.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct RepoDbVersionChange {
+    /// The name of the package.
+    pub name: Name,
+    /// The version of the package in the old [`RepoDb`].
+    pub old: FullVersion,
+    /// The version of the package in the new [`RepoDb`].
+    pub new: FullVersion,
+}
+
+/// The differences between two snapshots of an [alpm-repo-db].
+///
+/// Created using [`RepoDb::diff`].
+/// All lists are ordered by package name.
+/// Packages that are present in both snapshots in the same version are not considered.
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct RepoDbDiff {
+    /// The packages that are only present in the new [`RepoDb`].
+    pub added: Vec<RepoDbDiffPackage>,
+    /// The packages that are only present in the old [`RepoDb`].
+    pub removed: Vec<RepoDbDiffPackage>,
+    /// The packages that have a higher version in the new [`RepoDb`].
+    pub upgraded: Vec<RepoDbVersionChange>,
+    /// The packages that have a lower version in the new [`RepoDb`].
+    pub downgraded: Vec<RepoDbVersionChange>,
+}
+
+impl RepoDbDiff {
+    /// Returns `true` if there are no differences.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.upgraded.is_empty()
+            && self.downgraded.is_empty()
+    }
+}
+
+impl RepoDb {
+    /// Returns the differences between `self` and `other`.
+    ///
+    /// Considers `self` the old and `other` the new snapshot of an [alpm-repo-db].
+    /// Versions are compared according to [alpm-package-version].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use alpm_repo_db::db::RepoDb;
+    ///
+    /// # fn main() -> testresult::TestResult {
+    /// let old = RepoDb::from_file("old/core.db")?;
+    /// let new = RepoDb::from_file("new/core.db")?;
+    /// for change in old.diff(&new).upgraded {
+    ///     println!("{}: {} -> {}", change.name, change.old, change.new);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [alpm-package-version]: https://alpm.archlinux.page/specifications/alpm-package-version.7.html
+    /// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+    pub fn diff(&self, other: &RepoDb) -> RepoDbDiff {
+        let mut diff = RepoDbDiff::default();
+
+        for (name, package) in &self.packages {
+            let Some(other_package) = other.packages.get(name) else {
+                diff.removed.push(RepoDbDiffPackage {
+                    name: name.clone(),
+                    version: package.version().clone(),
+                });
+                continue;
+            };
+
+            let change = RepoDbVersionChange {
+                name: name.clone(),
+                old: package.version().clone(),
+                new: other_package.version().clone(),
+            };
+            match change.new.cmp(&change.old) {
+                Ordering::Greater => diff.upgraded.push(change),
+                Ordering::Less => diff.downgraded.push(change),
+                Ordering::Equal => {}
+            }
+        }
+
+        diff.added = other
+            .packages
+            .iter()
+            .filter(|(name, _)| !self.packages.contains_key(*name))
+            .map(|(name, package)| RepoDbDiffPackage {
+                name: name.clone(),
+                version: package.version().clone(),
+            })
+            .collect();
+
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::{db::RepoDbPackage, desc::RepoDescFile};
+
+    /// Creates a [`RepoDbPackage`] with `name` and `version`.
+    fn package(name: &str, version: &str) -> TestResult<RepoDbPackage> {
+        let desc = format!(
+            r#"%FILENAME%
+{name}-{version}-any.pkg.tar.zst
+
+%NAME%
+{name}
+
+%BASE%
+{name}
+
+%VERSION%
+{version}
+
+%CSIZE%
+1
+
+%ISIZE%
+1
+
+%SHA256SUM%
+b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+
+%ARCH%
+any
+
+%BUILDDATE%
+1
+
+%PACKAGER%
+Foobar McFooface <foobar@mcfooface.org>
+
+"#
+        );
+        Ok(RepoDbPackage {
+            desc: RepoDescFile::from_str(&desc)?,
+            files: None,
+        })
+    }
+
+    /// Creates a [`RepoDb`] from a list of package names and versions.
+    fn repo_db(packages: &[(&str, &str)]) -> TestResult<RepoDb> {
+        let mut db = RepoDb::default();
+        for (name, version) in packages {
+            db.insert(package(name, version)?);
+        }
+        Ok(db)
+    }
+
+    /// Ensures that added, removed, upgraded and downgraded packages are detected.
+    #[test]
+    fn repo_db_diff() -> TestResult {
+        let old = repo_db(&[
+            ("bar", "1.0.0-1"),
+            ("baz", "1.0.0-1"),
+            ("foo", "1.0.0-1"),
+            ("qux", "1:1.0.0-1"),
+        ])?;
+        let new = repo_db(&[
+            ("baz", "1.0.0-1"),
+            ("foo", "1.0.0-2"),
+            ("new", "0.1.0-1"),
+            ("qux", "2.0.0-1"),
+        ])?;
+
+        let diff = old.diff(&new);
+
+        assert_eq!(
+            diff.added,
+            vec![RepoDbDiffPackage {
+                name: Name::new("new")?,
+                version: FullVersion::from_str("0.1.0-1")?,
+            }]
+        );
+        assert_eq!(
+            diff.removed,
+            vec![RepoDbDiffPackage {
+                name: Name::new("bar")?,
+                version: FullVersion::from_str("1.0.0-1")?,
+            }]
+        );
+        assert_eq!(
+            diff.upgraded,
+            vec![RepoDbVersionChange {
+                name: Name::new("foo")?,
+                old: FullVersion::from_str("1.0.0-1")?,
+                new: FullVersion::from_str("1.0.0-2")?,
+            }]
+        );
+        assert_eq!(
+            diff.downgraded,
+            vec![RepoDbVersionChange {
+                name: Name::new("qux")?,
+                old: FullVersion::from_str("1:1.0.0-1")?,
+                new: FullVersion::from_str("2.0.0-1")?,
+            }]
+        );
+        assert!(old.diff(&old).is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+//! Indexed lookups in [alpm-repo-db] data.
+//!
+//! [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+
+use alpm_types::RelationOrSoname;
+
+use crate::db::{RepoDb, RepoDbPackage};
+
+/// Returns the name under which `provision` is indexed.
+///
+/// - Package relations are indexed by their name (e.g. `foo` for `foo=1.0.0`).
+/// - [alpm-sonamev1] entries are indexed by their shared object name (e.g. `libfoo.so`).
+/// - [alpm-sonamev2] entries are indexed by their full representation (e.g. `lib:libfoo.so.1`).
+///
+/// [alpm-sonamev1]: https://alpm.archlinux.page/specifications/alpm-sonamev1.7.html
+/// [alpm-sonamev2]: https://alpm.archlinux.page/specifications/alpm-sonamev2.7.html
+fn provision_name(provision: &RelationOrSoname) -> String {
+    match provision {
+        RelationOrSoname::Relation(relation) => relation.name.to_string(),
+        RelationOrSoname::SonameV1(soname) => soname.shared_object_name().to_string(),
+        RelationOrSoname::SonameV2(soname) => soname.to_string(),
+    }
+}
+
+/// A read-only index over the packages of a [`RepoDb`].
+///
+/// Packages and provisions are kept in sorted arrays, so that lookups by package name and by
+/// provision name are binary searches that do not allocate.
+/// This is meant for tooling that repeatedly queries large databases.
+///
+/// Created using [`RepoDb::index`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use alpm_repo_db::db::RepoDb;
+///
+/// # fn main() -> testresult::TestResult {
+/// let db = RepoDb::from_file("/var/lib/pacman/sync/core.db")?;
+/// let index = db.index();
+/// for package in index.providers("sh") {
+///     println!("{} provides sh", package.name());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RepoDbIndex<'a> {
+    /// The packages, ordered by name.
+    packages: Vec<&'a RepoDbPackage>,
+    /// The provision names and the index of the providing package in `packages`, ordered by
+    /// provision name and package name.
+    provisions: Vec<(String, usize)>,
+}
+
+impl<'a> RepoDbIndex<'a> {
+    /// Creates a new [`RepoDbIndex`] for `db`.
+    pub fn new(db: &'a RepoDb) -> Self {
+        // Packages in a RepoDb are already ordered by name.
+        let packages: Vec<&RepoDbPackage> = db.packages().collect();
+        let mut provisions: Vec<(String, usize)> = packages
+            .iter()
+            .enumerate()
+            .flat_map(|(index, package)| {
+                package
+                    .desc
+                    .provides()
+                    .iter()
+                    .map(move |provision| (provision_name(provision), index))
+            })
+            .collect();
+        provisions.sort();
+        provisions.dedup();
+
+        Self {
+            packages,
+            provisions,
+        }
+    }
+
+    /// Returns the number of indexed packages.
+    pub fn len(&self) -> usize {
+        self.packages.len()
+    }
+
+    /// Returns `true` if no packages are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+
+    /// Returns the package called `name`.
+    pub fn get(&self, name: &str) -> Option<&'a RepoDbPackage> {
+        self.packages
+            .binary_search_by(|package| package.name().as_ref().cmp(name))
+            .ok()
+            .map(|index| self.packages[index])
+    }
+
+    /// Returns all packages that provide `name`, ordered by package name.
+    ///
+    /// Only the explicit provisions of packages are considered, not their own names.
+    /// Package relations are matched by name (e.g. `sh` matches `sh=1.0.0`), [alpm-sonamev1]
+    /// entries by their shared object name (e.g. `libfoo.so`) and [alpm-sonamev2] entries by
+    /// their full representation (e.g. `lib:libfoo.so.1`).
+    /// Use [`RepoDbIndex::satisfiers`] to also consider package names.
+    ///
+    /// [alpm-sonamev1]: https://alpm.archlinux.page/specifications/alpm-sonamev1.7.html
+    /// [alpm-sonamev2]: https://alpm.archlinux.page/specifications/alpm-sonamev2.7.html
+    pub fn providers(&self, name: &str) -> impl Iterator<Item = &'a RepoDbPackage> {
+        let start = self
+            .provisions
+            .partition_point(|(provision, _)| provision.as_str() < name);
+        self.provisions[start..]
+            .iter()
+            .take_while(move |(provision, _)| provision == name)
+            .map(|(_, index)| self.packages[*index])
+    }
+
+    /// Returns all packages that are called `name` or provide `name`.
+    ///
+    /// The package called `name` (if any) is returned first, followed by
+    /// [`RepoDbIndex::providers`].
+    pub fn satisfiers(&self, name: &str) -> impl Iterator<Item = &'a RepoDbPackage> {
+        self.get(name).into_iter().chain(
+            self.providers(name)
+                .filter(move |provider| provider.name().as_ref() != name),
+        )
+    }
+
+    /// Returns an iterator over all indexed packages, ordered by name.
+    pub fn packages(&self) -> impl Iterator<Item = &'a RepoDbPackage> {
+        self.packages.iter().copied()
+    }
+}
+
+impl RepoDb {
+    /// Creates a [`RepoDbIndex`] for fast lookups by package and provision name.
+    pub fn index(&self) -> RepoDbIndex<'_> {
+        RepoDbIndex::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::desc::RepoDescFile;
+
+    /// Creates a [`RepoDbPackage`] called `name` that provides `provides`.
+    fn package(name: &str, provides: &[&str]) -> TestResult<RepoDbPackage> {
+        let provides = if provides.is_empty() {
+            String::new()
+        } else {
+            format!("%PROVIDES%\n{}\n\n", provides.join("\n"))
+        };
+        let desc = format!(
+            r#"%FILENAME%
+{name}-1.0.0-1-any.pkg.tar.zst
+
+%NAME%
+{name}
+
+%BASE%
+{name}
+
+%VERSION%
+1.0.0-1
+
+%CSIZE%
+1
+
+%ISIZE%
+1
+
+%SHA256SUM%
+b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+
+%ARCH%
+any
+
+%BUILDDATE%
+1
+
+%PACKAGER%
+Foobar McFooface <foobar@mcfooface.org>
+
+{provides}"#
+        );
+        Ok(RepoDbPackage {
+            desc: RepoDescFile::from_str(&desc)?,
+            files: None,
+        })
+    }
+
+    /// Ensures that packages can be looked up by name and by provision.
+    #[test]
+    fn repo_db_index_lookup() -> TestResult {
+        let mut db = RepoDb::default();
+        db.insert(package("bash", &["sh", "lib:libreadline.so.8"])?);
+        db.insert(package("dash", &["sh=0.5"])?);
+        db.insert(package("sh", &[])?);
+        db.insert(package("zsh", &[])?);
+
+        let index = db.index();
+        let names = |packages: Vec<&RepoDbPackage>| -> Vec<String> {
+            packages
+                .into_iter()
+                .map(|package| package.name().to_string())
+                .collect()
+        };
+
+        assert_eq!(index.len(), 4);
+        assert_eq!(
+            index.get("zsh").map(|package| package.name().to_string()),
+            Some("zsh".to_string())
+        );
+        assert!(index.get("fish").is_none());
+        assert_eq!(names(index.providers("sh").collect()), ["bash", "dash"]);
+        assert_eq!(
+            names(index.providers("lib:libreadline.so.8").collect()),
+            ["bash"]
+        );
+        assert_eq!(
+            names(index.satisfiers("sh").collect()),
+            ["sh", "bash", "dash"]
+        );
+        assert!(index.providers("fish").next().is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,329 @@
+//! Reading and writing of [alpm-repo-db] files.
+//!
+//! [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+
+mod diff;
+mod index;
+mod package;
+mod repo_dir;
+mod signature;
+mod writer;
+
+#[cfg(feature = "cli")]
+#[doc(hidden)]
+pub mod cli;
+
+#[cfg(feature = "cli")]
+#[doc(hidden)]
+pub mod commands;
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use alpm_compress::decompression::{CompressionDecoder, DecompressionSettings};
+use alpm_types::{FullVersion, GroupLookup, Name};
+pub use diff::{RepoDbDiff, RepoDbDiffPackage, RepoDbVersionChange};
+use fluent_i18n::t;
+pub use index::RepoDbIndex;
+pub use repo_dir::{RepoDirIssue, RepoDirPackageReport, RepoDirReport};
+pub use signature::{
+    GpgSigner,
+    RepoDbSignatureSigner,
+    RepoDbSignatureStatus,
+    RepoDbSigner,
+    RepoDbVerification,
+    sign_file,
+    signature_path,
+    verify_file,
+    verify_file_with,
+};
+use tar::{Archive, EntryType};
+pub use writer::{RepoDbKind, RepoDbStreamWriter};
+
+use crate::{Error, desc::RepoDescFile, files::RepoFiles};
+
+/// The file name of [alpm-repo-desc] files in an [alpm-repo-db].
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+/// [alpm-repo-desc]: https://alpm.archlinux.page/specifications/alpm-repo-desc.5.html
+pub const DESC_FILE_NAME: &str = "desc";
+
+/// The file name of [alpm-repo-files] files in an [alpm-repo-db].
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+/// [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
+pub const FILES_FILE_NAME: &str = "files";
+
+/// A single package in an [alpm-repo-db].
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct RepoDbPackage {
+    /// The [alpm-repo-desc] data of the package.
+    ///
+    /// [alpm-repo-desc]: https://alpm.archlinux.page/specifications/alpm-repo-desc.5.html
+    pub desc: RepoDescFile,
+
+    /// The optional [alpm-repo-files] data of the package.
+    ///
+    /// This is only present in `.files` variants of an [alpm-repo-db].
+    ///
+    /// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+    /// [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
+    pub files: Option<RepoFiles>,
+}
+
+impl RepoDbPackage {
+    /// Returns the name of the package.
+    pub fn name(&self) -> &Name {
+        self.desc.name()
+    }
+
+    /// Returns the version of the package.
+    pub fn version(&self) -> &FullVersion {
+        self.desc.version()
+    }
+}
+
+/// A representation of an [alpm-repo-db].
+///
+/// Provides access to the typed [alpm-repo-desc] (and optional [alpm-repo-files]) data of each
+/// package in a repository sync database (e.g. `core.db` or `core.files`).
+///
+/// # Examples
+///
+/// ```no_run
+/// use alpm_repo_db::db::RepoDb;
+/// use alpm_types::Name;
+///
+/// # fn main() -> testresult::TestResult {
+/// let db = RepoDb::from_file("/var/lib/pacman/sync/core.db")?;
+/// if let Some(package) = db.get(&Name::new("pacman")?) {
+///     println!("{} {}", package.name(), package.version());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+/// [alpm-repo-desc]: https://alpm.archlinux.page/specifications/alpm-repo-desc.5.html
+/// [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
+#[derive(Clone, Debug, Default, serde::Serialize)]
+pub struct RepoDb {
+    packages: BTreeMap<Name, RepoDbPackage>,
+}
+
+impl RepoDb {
+    /// Creates a [`RepoDb`] from a file at `path`.
+    ///
+    /// Opens the file and defers to [`RepoDb::from_reader`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the file at `path` cannot be opened for reading,
+    /// - or [`RepoDb::from_reader`] fails.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|source| Error::IoPath {
+            path: path.to_path_buf(),
+            context: t!("error-io-path-open-file"),
+            source,
+        })?;
+        Self::from_reader(file)
+    }
+
+    /// Creates a [`RepoDb`] from a `reader`.
+    ///
+    /// The compression of the data is detected based on its magic bytes, as [alpm-repo-db] files
+    /// commonly use file extensions (i.e. `.db` and `.files`) that do not indicate the
+    /// compression algorithm.
+    /// Uncompressed tar archives are supported as well.
+    ///
+    /// Each package directory in the archive is expected to contain an [alpm-repo-desc] file
+    /// named `desc` and may contain an [alpm-repo-files] file named `files`.
+    /// Other files are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the data cannot be decompressed or read as tar archive,
+    /// - an [alpm-repo-desc] or [alpm-repo-files] file cannot be parsed,
+    /// - the name of a package directory does not match the name and version of its package,
+    /// - a package directory contains no [alpm-repo-desc] file,
+    /// - or the same package name is used by more than one package.
+    ///
+    /// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+    /// [alpm-repo-desc]: https://alpm.archlinux.page/specifications/alpm-repo-desc.5.html
+    /// [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
+    pub fn from_reader(reader: impl Read) -> Result<Self, Error> {
+        let mut reader = BufReader::new(reader);
+        let magic_bytes = reader.fill_buf().map_err(|source| Error::IoRead {
+            context: t!("error-io-read-repo-db"),
+            source,
+        })?;
+        let settings = DecompressionSettings::from_magic_bytes(magic_bytes);
+        let decoder = CompressionDecoder::from_reader(reader, settings)?;
+        let mut archive = Archive::new(decoder);
+
+        let mut descs: BTreeMap<PathBuf, RepoDescFile> = BTreeMap::new();
+        let mut files: BTreeMap<PathBuf, RepoFiles> = BTreeMap::new();
+
+        let entries = archive.entries().map_err(|source| Error::IoRead {
+            context: t!("error-io-read-repo-db-entries"),
+            source,
+        })?;
+        for entry in entries {
+            let mut entry = entry.map_err(|source| Error::IoRead {
+                context: t!("error-io-read-repo-db-entry"),
+                source,
+            })?;
+            if entry.header().entry_type() != EntryType::Regular {
+                continue;
+            }
+            let path = entry
+                .path()
+                .map_err(|source| Error::IoRead {
+                    context: t!("error-io-read-repo-db-entry"),
+                    source,
+                })?
+                .into_owned();
+            let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+                continue;
+            };
+            if file_name != DESC_FILE_NAME && file_name != FILES_FILE_NAME {
+                continue;
+            }
+
+            let mut content = String::new();
+            entry
+                .read_to_string(&mut content)
+                .map_err(|source| Error::IoRead {
+                    context: t!("error-io-read-repo-db-entry"),
+                    source,
+                })?;
+
+            let invalid = |reason: String| Error::InvalidRepoDbEntry {
+                path: path.clone(),
+                reason,
+            };
+            if file_name == DESC_FILE_NAME {
+                let desc =
+                    RepoDescFile::from_str(&content).map_err(|error| invalid(error.to_string()))?;
+                descs.insert(dir.to_path_buf(), desc);
+            } else {
+                let repo_files =
+                    RepoFiles::from_str(&content).map_err(|error| invalid(error.to_string()))?;
+                files.insert(dir.to_path_buf(), repo_files);
+            }
+        }
+
+        let mut packages = BTreeMap::new();
+        for (dir, desc) in descs {
+            let expected_dir = format!("{}-{}", desc.name(), desc.version());
+            if dir.as_os_str() != expected_dir.as_str() {
+                return Err(Error::InvalidRepoDbEntry {
+                    path: dir,
+                    reason: t!("error-invalid-repo-db-entry-dir-name", {
+                        "expected" => expected_dir,
+                    }),
+                });
+            }
+
+            let package = RepoDbPackage {
+                files: files.remove(&dir),
+                desc,
+            };
+            let name = package.name().clone();
+            if packages.insert(name.clone(), package).is_some() {
+                return Err(Error::InvalidRepoDbEntry {
+                    path: dir,
+                    reason: t!("error-invalid-repo-db-entry-duplicate-name", {
+                        "name" => name.to_string(),
+                    }),
+                });
+            }
+        }
+
+        // Any remaining files data has no corresponding desc data.
+        if let Some(dir) = files.into_keys().next() {
+            return Err(Error::InvalidRepoDbEntry {
+                path: dir,
+                reason: t!("error-invalid-repo-db-entry-missing-desc"),
+            });
+        }
+
+        Ok(Self { packages })
+    }
+
+    /// Returns the package with the name `name`.
+    ///
+    /// Returns [`None`] if no such package exists.
+    pub fn get(&self, name: &Name) -> Option<&RepoDbPackage> {
+        self.packages.get(name)
+    }
+
+    /// Returns the package with the name `name` and the version `version`.
+    ///
+    /// Returns [`None`] if no such package exists.
+    pub fn get_version(&self, name: &Name, version: &FullVersion) -> Option<&RepoDbPackage> {
+        self.get(name)
+            .filter(|package| package.version() == version)
+    }
+
+    /// Returns an iterator over all packages, sorted by name.
+    pub fn packages(&self) -> impl Iterator<Item = &RepoDbPackage> {
+        self.packages.values()
+    }
+
+    /// Returns a [`GroupLookup`] of the groups of all packages.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use alpm_repo_db::db::RepoDb;
+    ///
+    /// # fn main() -> testresult::TestResult {
+    /// let db = RepoDb::from_file("core.db")?;
+    /// let lookup = db.group_lookup();
+    /// if let Some(members) = lookup.members("base-devel") {
+    ///     for member in members {
+    ///         println!("{member}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn group_lookup(&self) -> GroupLookup {
+        self.packages()
+            .map(|package| (package.name().clone(), package.desc.groups().to_vec()))
+            .collect()
+    }
+
+    /// Returns the number of packages.
+    pub fn len(&self) -> usize {
+        self.packages.len()
+    }
+
+    /// Returns `true` if there are no packages, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+}
+
+impl IntoIterator for RepoDb {
+    type Item = RepoDbPackage;
+    type IntoIter = std::collections::btree_map::IntoValues<Name, RepoDbPackage>;
+
+    /// Returns an iterator over all packages, sorted by name.
+    fn into_iter(self) -> Self::IntoIter {
+        self.packages.into_values()
+    }
+}
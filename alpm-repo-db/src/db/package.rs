@@ -0,0 +1,290 @@
+//! Creation of [alpm-repo-db] entries from [alpm-package] files.
+//!
+//! [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+//! [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+
+use std::{
+    fs::{File, read, read_dir},
+    io::{BufReader, Read, sink},
+    path::{Component, Path, PathBuf},
+    str::FromStr,
+};
+
+use alpm_common::MetadataFile;
+use alpm_compress::decompression::{CompressionDecoder, DecompressionSettings};
+use alpm_package::Package;
+use alpm_pkginfo::PackageInfo;
+use alpm_types::{
+    ChecksumHasher,
+    MetadataFileName,
+    PackageFileName,
+    Sha256Checksum,
+    digests::Sha256,
+};
+use fluent_i18n::t;
+use tar::Archive;
+
+use crate::{
+    Error,
+    db::{RepoDb, RepoDbPackage, signature_path},
+    desc::{DescBuilder, RepoDescFile},
+    files::{RepoFiles, RepoFilesV1},
+};
+
+impl RepoDbPackage {
+    /// Creates a [`RepoDbPackage`] from the [alpm-package] file at `path`.
+    ///
+    /// The [alpm-repo-descv2] data is created using a [`DescBuilder`].
+    /// If a detached OpenPGP signature exists next to the package file (i.e. `path` with an
+    /// additional `.sig` extension), it is added to the [alpm-repo-descv2] data.
+    ///
+    /// The [alpm-repo-files] data contains the paths of all data files and directories of the
+    /// package.
+    /// Like with [repo-add], hidden files in the root of the package are not considered.
+    ///
+    /// The package file is read only once: its size and SHA-256 checksum are calculated while its
+    /// entries are streamed, without extracting them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - `path` is not a valid [alpm-package] file,
+    /// - the package file or its signature file cannot be read,
+    /// - the package contains no valid [PKGINFO] data,
+    /// - the signature is not a valid OpenPGP signature,
+    /// - or the data files of the package cannot be read.
+    ///
+    /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+    /// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+    /// [alpm-repo-descv2]: https://alpm.archlinux.page/specifications/alpm-repo-descv2.5.html
+    /// [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
+    /// [repo-add]: https://man.archlinux.org/man/repo-add.8
+    pub fn from_package_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let package = Package::try_from(path)?;
+        let scan = PackageScan::read(&package)?;
+
+        let mut builder = DescBuilder::new(package);
+        if let Some(signature) = read_signature(path)? {
+            builder = builder.pgp_signature_bytes(&signature)?;
+        }
+        let desc = builder.build_from(scan.pkginfo, scan.compressed_size, scan.sha256_checksum);
+
+        Ok(Self {
+            desc: RepoDescFile::V2(desc),
+            files: Some(RepoFiles::V1(RepoFilesV1::try_from(scan.paths)?)),
+        })
+    }
+}
+
+impl RepoDb {
+    /// Creates a [`RepoDb`] from all [alpm-package] files in the directory `dir`.
+    ///
+    /// A [`RepoDbPackage`] is created for each package file using
+    /// [`RepoDbPackage::from_package_file`], so that the [alpm-repo-desc] and [alpm-repo-files]
+    /// data of each package is created while reading the package file only once.
+    /// Files in `dir` that are not named like [alpm-package] files (e.g. signature files) are
+    /// ignored.
+    /// If `dir` contains more than one version of a package, only the highest version is added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - `dir` cannot be read,
+    /// - or [`RepoDbPackage::from_package_file`] fails for one of the package files.
+    ///
+    /// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+    /// [alpm-repo-desc]: https://alpm.archlinux.page/specifications/alpm-repo-desc.5.html
+    /// [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
+    pub fn from_package_dir(dir: impl AsRef<Path>) -> Result<Self, Error> {
+        let dir = dir.as_ref();
+        let read_dir_error = |source| Error::IoPath {
+            path: dir.to_path_buf(),
+            context: t!("error-io-path-read-repo-dir"),
+            source,
+        };
+
+        let mut package_paths = Vec::new();
+        for entry in read_dir(dir).map_err(read_dir_error)? {
+            let entry = entry.map_err(read_dir_error)?;
+            let is_package_file = entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| PackageFileName::from_str(name).is_ok());
+            if is_package_file {
+                package_paths.push(entry.path());
+            }
+        }
+        package_paths.sort();
+
+        let mut db = Self::default();
+        for path in package_paths {
+            let package = RepoDbPackage::from_package_file(&path)?;
+            let is_newer = db
+                .get(package.name())
+                .is_none_or(|existing| existing.version() < package.version());
+            if is_newer {
+                db.insert(package);
+            }
+        }
+
+        Ok(db)
+    }
+}
+
+/// The data of an [alpm-package] file that is required for its [alpm-repo-db] entry.
+///
+/// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+struct PackageScan {
+    /// The [PKGINFO] data of the package.
+    ///
+    /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+    pkginfo: PackageInfo,
+    /// The size of the package file in bytes.
+    compressed_size: u64,
+    /// The SHA-256 checksum of the package file.
+    sha256_checksum: Sha256Checksum,
+    /// The paths of all data files and directories (see [`files_path`]).
+    paths: Vec<PathBuf>,
+}
+
+impl PackageScan {
+    /// Reads the [`PackageScan`] of `package`.
+    ///
+    /// Streams the package file once: the size and SHA-256 checksum are calculated from the
+    /// compressed data, while the [PKGINFO] data and the paths of all data entries are collected
+    /// from the decompressed tar archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the package file cannot be read or decompressed,
+    /// - an entry of the package file cannot be read,
+    /// - or the package contains no valid [PKGINFO] data.
+    ///
+    /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+    fn read(package: &Package) -> Result<Self, Error> {
+        let path = package.to_path_buf();
+        let read_error = |source| Error::IoPath {
+            path: path.clone(),
+            context: t!("error-io-path-read-package"),
+            source,
+        };
+
+        let file = File::open(&path).map_err(read_error)?;
+        let settings = DecompressionSettings::from_archive_path(&path)?;
+        let mut hasher = ChecksumHasher::<Sha256>::new();
+        let mut compressed_size = 0;
+        let mut pkginfo = None;
+        let mut paths = Vec::new();
+        {
+            let reader = HashingReader {
+                inner: &file,
+                hasher: &mut hasher,
+                size: &mut compressed_size,
+            };
+            let decoder = CompressionDecoder::from_reader(BufReader::new(reader), settings)?;
+            let mut archive = Archive::new(decoder);
+            for entry in archive.entries().map_err(read_error)? {
+                let mut entry = entry.map_err(read_error)?;
+                let entry_path = entry.path().map_err(read_error)?.into_owned();
+                if entry_path.as_os_str() == MetadataFileName::PackageInfo.as_ref() {
+                    pkginfo = Some(
+                        PackageInfo::from_reader(&mut entry)
+                            .map_err(alpm_package::Error::AlpmPackageInfo)?,
+                    );
+                } else if let Some(path) =
+                    files_path(&entry_path, entry.header().entry_type().is_dir())
+                {
+                    paths.push(path);
+                }
+            }
+        }
+
+        // The tar archive may end before the package file (e.g. due to padding), but the checksum
+        // covers the entire file.
+        std::io::copy(
+            &mut HashingReader {
+                inner: &file,
+                hasher: &mut hasher,
+                size: &mut compressed_size,
+            },
+            &mut sink(),
+        )
+        .map_err(read_error)?;
+
+        Ok(Self {
+            pkginfo: pkginfo.ok_or(alpm_package::Error::MetadataFileNotFound {
+                name: MetadataFileName::PackageInfo,
+            })?,
+            compressed_size,
+            sha256_checksum: hasher.finalize(),
+            paths,
+        })
+    }
+}
+
+/// A reader that tracks the size and SHA-256 checksum of all data read from `inner`.
+struct HashingReader<'a, R> {
+    inner: R,
+    hasher: &'a mut ChecksumHasher<Sha256>,
+    size: &'a mut u64,
+}
+
+impl<R: Read> Read for HashingReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let amount = self.inner.read(buf)?;
+        self.hasher.update(&buf[..amount]);
+        *self.size += amount as u64;
+        Ok(amount)
+    }
+}
+
+/// Reads the detached OpenPGP signature of the package file at `path`.
+///
+/// Returns [`None`] if no signature file exists next to the package file.
+///
+/// # Errors
+///
+/// Returns an error if the signature file exists, but cannot be read.
+fn read_signature(path: &Path) -> Result<Option<Vec<u8>>, Error> {
+    let signature_path = signature_path(path);
+    if !signature_path.exists() {
+        return Ok(None);
+    }
+
+    read(&signature_path)
+        .map(Some)
+        .map_err(|source| Error::IoPath {
+            path: signature_path,
+            context: t!("error-io-path-read-signature"),
+            source,
+        })
+}
+
+/// Returns the [alpm-repo-files] path of the package entry at `path`.
+///
+/// Paths of directories (i.e. if `is_dir` is `true`) carry a trailing slash.
+/// Returns [`None`] for hidden files in the root of the package (which includes all metadata
+/// files) and for the root of the package itself.
+///
+/// [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
+fn files_path(path: &Path, is_dir: bool) -> Option<PathBuf> {
+    let is_hidden_root_file =
+        path.components().count() == 1 && path.to_str().is_some_and(|path| path.starts_with('.'));
+    if is_hidden_root_file || path.components().all(|c| c == Component::CurDir) {
+        return None;
+    }
+
+    if is_dir {
+        let mut dir = path.to_string_lossy().trim_end_matches('/').to_string();
+        dir.push('/');
+        Some(PathBuf::from(dir))
+    } else {
+        Some(path.to_path_buf())
+    }
+}
@@ -0,0 +1,229 @@
+//! Consistency validation of repository directories.
+//!
+//! A repository directory contains an [alpm-repo-db] and the [alpm-package] files (and their
+//! signatures) that are referenced by it.
+//!
+//! [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+//! [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+
+use std::{
+    collections::HashSet,
+    fs::{read, read_dir},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use alpm_types::{FullVersion, Name, PackageFileName};
+use base64::{Engine, prelude::BASE64_STANDARD};
+use fluent_i18n::t;
+use serde::Serialize;
+
+use crate::{
+    Error,
+    db::{RepoDb, RepoDbPackage, signature_path},
+};
+
+/// An inconsistency between an entry of an [alpm-repo-db] and the repository directory.
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case", tag = "issue")]
+pub enum RepoDirIssue {
+    /// The package file does not exist.
+    MissingPackageFile,
+
+    /// The package file name does not match the name, version and architecture of the package.
+    FileNameMismatch,
+
+    /// The size of the package file does not match the size in the database.
+    SizeMismatch {
+        /// The size recorded in the database.
+        expected: u64,
+        /// The actual size of the package file.
+        actual: u64,
+    },
+
+    /// The SHA-256 checksum of the package file does not match the checksum in the database.
+    ChecksumMismatch {
+        /// The checksum recorded in the database.
+        expected: String,
+        /// The actual checksum of the package file.
+        actual: String,
+    },
+
+    /// The signature file of the package file does not exist, but is required.
+    MissingSignature,
+
+    /// The signature file of the package file does not match the signature in the database.
+    SignatureMismatch,
+}
+
+/// The validation result for a single package of an [alpm-repo-db].
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct RepoDirPackageReport {
+    /// The name of the package.
+    pub name: Name,
+    /// The version of the package.
+    pub version: FullVersion,
+    /// The file name of the package file.
+    pub file_name: PackageFileName,
+    /// The inconsistencies found for the package.
+    pub issues: Vec<RepoDirIssue>,
+}
+
+/// The result of the consistency validation of a repository directory.
+///
+/// Created using [`RepoDb::validate_dir`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct RepoDirReport {
+    /// The validation results of all packages in the database, ordered by name.
+    pub packages: Vec<RepoDirPackageReport>,
+    /// The package files in the repository directory that are not referenced by the database.
+    pub orphans: Vec<PathBuf>,
+}
+
+impl RepoDirReport {
+    /// Returns `true` if no inconsistencies and no orphaned package files have been found.
+    pub fn is_consistent(&self) -> bool {
+        self.orphans.is_empty()
+            && self
+                .packages
+                .iter()
+                .all(|package| package.issues.is_empty())
+    }
+}
+
+impl RepoDb {
+    /// Validates the consistency of the [`RepoDb`] with the repository directory `dir`.
+    ///
+    /// For each package in the database, checks that
+    ///
+    /// - the package file exists in `dir`,
+    /// - the file name matches the name, version and architecture of the package,
+    /// - the size and SHA-256 checksum of the package file match the database,
+    /// - a signature file exists next to the package file (only if `require_signatures` is `true`),
+    /// - and an existing signature file matches the signature in the database (if any).
+    ///
+    /// Additionally, all files in `dir` that are valid [alpm-package] file names, but are not
+    /// referenced by the database, are reported as orphans.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - `dir` cannot be read,
+    /// - or a package or signature file exists, but cannot be read.
+    ///
+    /// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+    pub fn validate_dir(
+        &self,
+        dir: impl AsRef<Path>,
+        require_signatures: bool,
+    ) -> Result<RepoDirReport, Error> {
+        let dir = dir.as_ref();
+        let packages = self
+            .packages()
+            .map(|package| validate_package(dir, package, require_signatures))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let referenced: HashSet<PathBuf> = self
+            .packages()
+            .map(|package| package.desc.file_name().to_path_buf())
+            .collect();
+        let entries = read_dir(dir).map_err(|source| Error::IoPath {
+            path: dir.to_path_buf(),
+            context: t!("error-io-path-read-repo-dir"),
+            source,
+        })?;
+        let mut orphans = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|source| Error::IoPath {
+                path: dir.to_path_buf(),
+                context: t!("error-io-path-read-repo-dir"),
+                source,
+            })?;
+            let file_name = PathBuf::from(entry.file_name());
+            let is_package_file = file_name
+                .to_str()
+                .is_some_and(|name| PackageFileName::from_str(name).is_ok());
+            if is_package_file && !referenced.contains(&file_name) {
+                orphans.push(entry.path());
+            }
+        }
+        orphans.sort();
+
+        Ok(RepoDirReport { packages, orphans })
+    }
+}
+
+/// Validates the package file and signature file of `package` in `dir`.
+///
+/// # Errors
+///
+/// Returns an error if the package file or signature file exists, but cannot be read.
+fn validate_package(
+    dir: &Path,
+    package: &RepoDbPackage,
+    require_signatures: bool,
+) -> Result<RepoDirPackageReport, Error> {
+    let desc = &package.desc;
+    let file_name = desc.file_name();
+    let mut issues = Vec::new();
+
+    if file_name.name() != desc.name()
+        || file_name.version() != desc.version()
+        || file_name.architecture() != desc.arch()
+    {
+        issues.push(RepoDirIssue::FileNameMismatch);
+    }
+
+    let path = dir.join(file_name.to_path_buf());
+    if path.is_file() {
+        let data = read(&path).map_err(|source| Error::IoPath {
+            path: path.clone(),
+            context: t!("error-io-path-read-package"),
+            source,
+        })?;
+        let actual = data.len() as u64;
+        if actual != desc.compressed_size() {
+            issues.push(RepoDirIssue::SizeMismatch {
+                expected: desc.compressed_size(),
+                actual,
+            });
+        } else {
+            if let Err(mismatch) = desc.sha256_checksum().verify(data) {
+                issues.push(RepoDirIssue::ChecksumMismatch {
+                    expected: mismatch.expected.to_string(),
+                    actual: mismatch.actual.to_string(),
+                });
+            }
+        }
+    } else {
+        issues.push(RepoDirIssue::MissingPackageFile);
+    }
+
+    let signature = signature_path(&path);
+    if !signature.is_file() {
+        if require_signatures {
+            issues.push(RepoDirIssue::MissingSignature);
+        }
+    } else if let Some(expected) = desc.pgp_signature() {
+        let data = read(&signature).map_err(|source| Error::IoPath {
+            path: signature.clone(),
+            context: t!("error-io-path-read-signature"),
+            source,
+        })?;
+        if BASE64_STANDARD.encode(data) != expected.to_string() {
+            issues.push(RepoDirIssue::SignatureMismatch);
+        }
+    }
+
+    Ok(RepoDirPackageReport {
+        name: package.name().clone(),
+        version: package.version().clone(),
+        file_name: file_name.clone(),
+        issues,
+    })
+}
@@ -0,0 +1,340 @@
+//! Signing and signature verification of [alpm-repo-db] files.
+//!
+//! [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+
+use std::{
+    collections::HashSet,
+    fs::write,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use fluent_i18n::t;
+use voa::{
+    commands::{
+        PurposeAndContext,
+        get_technology_settings,
+        get_voa_config,
+        openpgp_verify,
+        read_openpgp_signatures,
+        read_openpgp_verifiers,
+    },
+    core::{Context, Mode, Os, Purpose, Role},
+    openpgp::ModelBasedVerifier,
+    utils::RegularFile,
+};
+
+use crate::Error;
+
+/// The file extension of detached OpenPGP signatures.
+const SIGNATURE_FILE_EXTENSION: &str = "sig";
+
+/// Returns the path of the detached OpenPGP signature for the file at `path`.
+///
+/// The signature path is `path` with an additional `.sig` extension (e.g. `core.db.tar.gz.sig`
+/// for `core.db.tar.gz`).
+pub fn signature_path(path: impl AsRef<Path>) -> PathBuf {
+    let mut signature_path = path.as_ref().as_os_str().to_owned();
+    signature_path.push(format!(".{SIGNATURE_FILE_EXTENSION}"));
+    PathBuf::from(signature_path)
+}
+
+/// A signer that creates detached OpenPGP signatures for files.
+///
+/// Implementations are used by [`sign_file`] to sign [alpm-repo-db] files.
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+pub trait RepoDbSigner {
+    /// Creates a binary detached OpenPGP signature for the file at `path` and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file at `path` cannot be signed.
+    fn sign_file(&self, path: &Path) -> Result<Vec<u8>, Error>;
+}
+
+/// A [`RepoDbSigner`] that uses [gpg] to create signatures.
+///
+/// Like with [repo-add], the signature is created by the default signing key of the calling user,
+/// unless a specific key is requested.
+///
+/// [gpg]: https://man.archlinux.org/man/gpg.1
+/// [repo-add]: https://man.archlinux.org/man/repo-add.8
+#[derive(Clone, Debug, Default)]
+pub struct GpgSigner {
+    key: Option<String>,
+}
+
+impl GpgSigner {
+    /// Creates a new [`GpgSigner`].
+    ///
+    /// If `key` is [`Some`], the signature is created using the key it identifies (e.g. by
+    /// fingerprint or User ID).
+    pub fn new(key: Option<String>) -> Self {
+        Self { key }
+    }
+}
+
+impl RepoDbSigner for GpgSigner {
+    /// Creates a binary detached OpenPGP signature for the file at `path` using [gpg].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - [gpg] cannot be executed,
+    /// - or [gpg] fails to create the signature.
+    ///
+    /// [gpg]: https://man.archlinux.org/man/gpg.1
+    fn sign_file(&self, path: &Path) -> Result<Vec<u8>, Error> {
+        let mut command = Command::new("gpg");
+        command.args(["--detach-sign", "--no-armor", "--output", "-"]);
+        if let Some(key) = &self.key {
+            command.args(["--local-user", key]);
+        }
+        command.arg(path);
+
+        let output = command.output().map_err(|source| Error::IoPath {
+            path: path.to_path_buf(),
+            context: t!("error-io-path-run-gpg"),
+            source,
+        })?;
+        if !output.status.success() {
+            return Err(Error::SigningFailed {
+                path: path.to_path_buf(),
+                reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+
+        Ok(output.stdout)
+    }
+}
+
+/// Signs the file at `path` using `signer`.
+///
+/// Writes the detached OpenPGP signature to [`signature_path`] and returns that path.
+/// Any existing signature file is overwritten.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - [`RepoDbSigner::sign_file`] fails,
+/// - or the signature file cannot be written.
+pub fn sign_file(
+    path: impl AsRef<Path>,
+    signer: &(impl RepoDbSigner + ?Sized),
+) -> Result<PathBuf, Error> {
+    let path = path.as_ref();
+    let signature = signer.sign_file(path)?;
+    let signature_path = signature_path(path);
+    write(&signature_path, signature).map_err(|source| Error::IoPath {
+        path: signature_path.clone(),
+        context: t!("error-io-path-write-repo-db-signature"),
+        source,
+    })?;
+
+    Ok(signature_path)
+}
+
+/// The fingerprints of a verifier that successfully verified a signature.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+pub struct RepoDbSignatureSigner {
+    /// The fingerprint of the OpenPGP certificate.
+    pub certificate: String,
+    /// The fingerprint of the component key that issued the signature.
+    pub component: String,
+}
+
+/// The status of the signature verification of an [alpm-repo-db] file.
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase", tag = "status")]
+pub enum RepoDbSignatureStatus {
+    /// No signature file exists for the file.
+    Unsigned,
+
+    /// The signature has been successfully verified.
+    Valid {
+        /// The verifiers that successfully verified a signature.
+        signers: Vec<RepoDbSignatureSigner>,
+    },
+
+    /// The signature could not be verified.
+    Invalid {
+        /// The reason why the signature could not be verified.
+        reason: String,
+    },
+}
+
+/// The result of the signature verification of an [alpm-repo-db] file.
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+pub struct RepoDbVerification {
+    /// The path of the verified file.
+    pub path: PathBuf,
+    /// The path of the signature file.
+    pub signature: PathBuf,
+    /// The status of the verification.
+    #[serde(flatten)]
+    pub status: RepoDbSignatureStatus,
+}
+
+impl RepoDbVerification {
+    /// Returns `true` if the signature has been successfully verified.
+    pub fn is_valid(&self) -> bool {
+        matches!(self.status, RepoDbSignatureStatus::Valid { .. })
+    }
+}
+
+/// Verifies the signature of the file at `path` using verifiers from the [VOA] hierarchy.
+///
+/// Uses the OpenPGP artifact verifiers and trust anchors for the `os` and the
+/// [`Role::RepositoryMetadata`] purpose in the default context, as well as the [VOA]
+/// configuration for it.
+/// Defers to [`verify_file_with`] for the verification.
+///
+/// # Errors
+///
+/// Returns an error if [`verify_file_with`] fails.
+///
+/// [VOA]: https://uapi-group.org/specifications/specs/file_hierarchy_for_the_verification_of_os_artifacts/
+pub fn verify_file(path: impl AsRef<Path>, os: Os) -> Result<RepoDbVerification, Error> {
+    let purpose = Purpose::new(Role::RepositoryMetadata, Mode::ArtifactVerifier);
+    let artifact_verifiers = read_openpgp_verifiers(os.clone(), purpose.clone(), Context::Default);
+    let trust_anchors = read_openpgp_verifiers(
+        os.clone(),
+        Purpose::new(Role::RepositoryMetadata, Mode::TrustAnchor),
+        Context::Default,
+    );
+
+    let config = get_voa_config();
+    let purpose_and_context = PurposeAndContext::new(Some(purpose), Some(Context::Default));
+    let openpgp_settings =
+        get_technology_settings(&config, &os, purpose_and_context.as_ref()).openpgp_settings();
+    let verifier = ModelBasedVerifier::new(openpgp_settings, &artifact_verifiers, &trust_anchors);
+
+    verify_file_with(path, &verifier)
+}
+
+/// Verifies the signature of the file at `path` using `verifier`.
+///
+/// The signature is read from [`signature_path`].
+/// If no signature file exists, [`RepoDbSignatureStatus::Unsigned`] is returned.
+/// If the signature cannot be verified by `verifier`, [`RepoDbSignatureStatus::Invalid`] is
+/// returned.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - `path` is not a regular file,
+/// - the signature file cannot be read as OpenPGP signature,
+/// - or the fingerprint of a successful verifier cannot be derived.
+pub fn verify_file_with(
+    path: impl AsRef<Path>,
+    verifier: &ModelBasedVerifier,
+) -> Result<RepoDbVerification, Error> {
+    let path = path.as_ref();
+    let signature = signature_path(path);
+    let file = RegularFile::try_from(path.to_path_buf())?;
+    if !signature.exists() {
+        return Ok(RepoDbVerification {
+            path: path.to_path_buf(),
+            signature,
+            status: RepoDbSignatureStatus::Unsigned,
+        });
+    }
+
+    let signatures = read_openpgp_signatures(&HashSet::from_iter([RegularFile::try_from(
+        signature.clone(),
+    )?]))?;
+    let status = match openpgp_verify(verifier, &signatures, &file) {
+        Ok(checks) => {
+            let signers = checks
+                .iter()
+                .filter_map(|check| check.signer_info())
+                .map(|signer_info| {
+                    Ok(RepoDbSignatureSigner {
+                        certificate: signer_info
+                            .certificate()
+                            .fingerprint()
+                            .map_err(voa::Error::VoaOpenPgp)?
+                            .to_string(),
+                        component: signer_info.component_fingerprint().to_string(),
+                    })
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            RepoDbSignatureStatus::Valid { signers }
+        }
+        Err(voa::Error::VoaOpenPgp(error)) => RepoDbSignatureStatus::Invalid {
+            reason: error.to_string(),
+        },
+        Err(error) => return Err(error.into()),
+    };
+
+    Ok(RepoDbVerification {
+        path: path.to_path_buf(),
+        signature,
+        status,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::read, str::FromStr};
+
+    use tempfile::tempdir;
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// A [`RepoDbSigner`] that returns a static signature.
+    #[derive(Debug)]
+    struct StaticSigner;
+
+    impl RepoDbSigner for StaticSigner {
+        fn sign_file(&self, _path: &Path) -> Result<Vec<u8>, Error> {
+            Ok(b"signature".to_vec())
+        }
+    }
+
+    /// Ensures that signature paths carry an additional `.sig` extension.
+    #[test]
+    fn signature_path_appends_extension() {
+        assert_eq!(
+            signature_path("repo/core.db.tar.gz"),
+            PathBuf::from("repo/core.db.tar.gz.sig")
+        );
+    }
+
+    /// Ensures that signatures are written next to the signed file.
+    #[test]
+    fn sign_file_writes_signature() -> TestResult {
+        let temp_dir = tempdir()?;
+        let path = temp_dir.path().join("core.db.tar.gz");
+        write(&path, b"data")?;
+
+        let signature = sign_file(&path, &StaticSigner)?;
+
+        assert_eq!(signature, temp_dir.path().join("core.db.tar.gz.sig"));
+        assert_eq!(read(signature)?, b"signature");
+        Ok(())
+    }
+
+    /// Ensures that files without a signature file are reported as unsigned.
+    #[test]
+    fn verify_file_unsigned() -> TestResult {
+        let temp_dir = tempdir()?;
+        let path = temp_dir.path().join("core.db.tar.gz");
+        write(&path, b"data")?;
+
+        let verification = verify_file(&path, Os::from_str("arch")?)?;
+
+        assert_eq!(verification.status, RepoDbSignatureStatus::Unsigned);
+        assert!(!verification.is_valid());
+        Ok(())
+    }
+}
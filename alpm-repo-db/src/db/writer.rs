@@ -0,0 +1,444 @@
+//! Writing of [alpm-repo-db] files.
+//!
+//! [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+
+use std::{
+    fmt::Debug,
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use alpm_compress::compression::{CompressionEncoder, CompressionSettings};
+use alpm_types::Name;
+use fluent_i18n::t;
+use tar::{Builder, EntryType, Header};
+
+use crate::{
+    Error,
+    db::{DESC_FILE_NAME, FILES_FILE_NAME, RepoDb, RepoDbPackage},
+};
+
+/// The kind of an [alpm-repo-db].
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RepoDbKind {
+    /// A database that only contains [alpm-repo-desc] data (e.g. `core.db`).
+    ///
+    /// [alpm-repo-desc]: https://alpm.archlinux.page/specifications/alpm-repo-desc.5.html
+    Db,
+
+    /// A database that contains [alpm-repo-desc] and [alpm-repo-files] data (e.g. `core.files`).
+    ///
+    /// [alpm-repo-desc]: https://alpm.archlinux.page/specifications/alpm-repo-desc.5.html
+    /// [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
+    Files,
+}
+
+impl RepoDb {
+    /// Inserts `package`, replacing any package of the same name.
+    ///
+    /// Returns the replaced package, if any.
+    /// As an [alpm-repo-db] only tracks a single version of each package, this removes any other
+    /// version of the package from the database.
+    ///
+    /// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+    pub fn insert(&mut self, package: RepoDbPackage) -> Option<RepoDbPackage> {
+        self.packages.insert(package.name().clone(), package)
+    }
+
+    /// Removes the package with the name `name`.
+    ///
+    /// Returns the removed package, or [`None`] if no such package exists.
+    pub fn remove(&mut self, name: &Name) -> Option<RepoDbPackage> {
+        self.packages.remove(name)
+    }
+
+    /// Writes the [`RepoDb`] as uncompressed tar archive of `kind` to `writer`.
+    ///
+    /// Each package is written to a directory named `{name}-{version}`, which contains the
+    /// [alpm-repo-desc] file `desc` and (for [`RepoDbKind::Files`]) the [alpm-repo-files] file
+    /// `files`.
+    /// Packages without [alpm-repo-files] data are written without a `files` file.
+    ///
+    /// The output is deterministic: Packages are written in the order of their names and all
+    /// entries use the same modification time, ownership and permissions.
+    /// See [`RepoDbStreamWriter`] for writing packages without holding all of them in memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    ///
+    /// [alpm-repo-desc]: https://alpm.archlinux.page/specifications/alpm-repo-desc.5.html
+    /// [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
+    pub fn write_to(&self, writer: impl Write, kind: RepoDbKind) -> Result<(), Error> {
+        let mut stream = RepoDbStreamWriter::new(writer, kind);
+        for package in self.packages() {
+            stream.append(package)?;
+        }
+        stream.finish()?;
+
+        Ok(())
+    }
+
+    /// Writes the [`RepoDb`] as tar archive of `kind` to a file at `path`.
+    ///
+    /// The tar archive is compressed using `settings`.
+    /// Any existing file at `path` is overwritten.
+    /// See [`RepoDb::write_to`] for details on the archive layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the file at `path` cannot be created,
+    /// - a [`CompressionEncoder`] cannot be created using `settings`,
+    /// - [`RepoDb::write_to`] fails,
+    /// - or the compression stream cannot be finished.
+    pub fn write_to_file(
+        &self,
+        path: impl AsRef<Path>,
+        kind: RepoDbKind,
+        settings: &CompressionSettings,
+    ) -> Result<(), Error> {
+        let mut stream = RepoDbStreamWriter::create(path, kind, settings)?;
+        for package in self.packages() {
+            stream.append(package)?;
+        }
+        stream.finish_file()
+    }
+}
+
+/// A writer that streams the packages of an [alpm-repo-db] to a tar archive.
+///
+/// Unlike [`RepoDb::write_to`], the [`RepoDbStreamWriter`] does not require all packages to be
+/// held in memory at once.
+/// Each package is written to the tar archive as soon as it is appended, so that databases of
+/// arbitrary size can be created with bounded memory usage.
+///
+/// Packages must be appended in the order of their names, which keeps the output identical to
+/// that of [`RepoDb::write_to`].
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_repo_db::{
+///     db::{RepoDb, RepoDbKind, RepoDbPackage, RepoDbStreamWriter},
+///     desc::RepoDescFile,
+/// };
+///
+/// # fn main() -> testresult::TestResult {
+/// # let desc = r#"%FILENAME%
+/// # example-1.0.0-1-any.pkg.tar.zst
+/// #
+/// # %NAME%
+/// # example
+/// #
+/// # %BASE%
+/// # example
+/// #
+/// # %VERSION%
+/// # 1.0.0-1
+/// #
+/// # %DESC%
+/// # An example package
+/// #
+/// # %CSIZE%
+/// # 4634
+/// #
+/// # %ISIZE%
+/// # 0
+/// #
+/// # %SHA256SUM%
+/// # b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+/// #
+/// # %ARCH%
+/// # any
+/// #
+/// # %BUILDDATE%
+/// # 1729181726
+/// #
+/// # %PACKAGER%
+/// # Foobar McFooface <foobar@mcfooface.org>
+/// #
+/// # "#;
+/// let mut writer = RepoDbStreamWriter::new(Vec::new(), RepoDbKind::Db);
+/// // Packages are read one at a time (e.g. from package files) and written right away.
+/// writer.append(&RepoDbPackage {
+///     desc: RepoDescFile::from_str(desc)?,
+///     files: None,
+/// })?;
+/// let archive = writer.finish()?;
+///
+/// let db = RepoDb::from_reader(archive.as_slice())?;
+/// assert_eq!(db.len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+pub struct RepoDbStreamWriter<W: Write> {
+    builder: Builder<W>,
+    kind: RepoDbKind,
+    last_name: Option<Name>,
+}
+
+impl<W: Write> RepoDbStreamWriter<W> {
+    /// Creates a new [`RepoDbStreamWriter`] that writes an uncompressed tar archive of `kind` to
+    /// `writer`.
+    pub fn new(writer: W, kind: RepoDbKind) -> Self {
+        Self {
+            builder: Builder::new(writer),
+            kind,
+            last_name: None,
+        }
+    }
+
+    /// Appends `package` to the tar archive.
+    ///
+    /// See [`RepoDb::write_to`] for details on the layout of the written entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the name of `package` is not sorted after the name of the previously appended package,
+    /// - or writing the entries of `package` fails.
+    pub fn append(&mut self, package: &RepoDbPackage) -> Result<(), Error> {
+        let dir = PathBuf::from(format!("{}-{}", package.name(), package.version()));
+        if let Some(last_name) = self.last_name.as_ref()
+            && last_name >= package.name()
+        {
+            return Err(Error::InvalidRepoDbEntry {
+                path: dir,
+                reason: t!("error-invalid-repo-db-entry-unsorted", {
+                    "name" => package.name().to_string(),
+                    "previous" => last_name.to_string(),
+                }),
+            });
+        }
+
+        append_entry(&mut self.builder, &dir, None).map_err(io_error)?;
+        append_entry(
+            &mut self.builder,
+            &dir.join(DESC_FILE_NAME),
+            Some(package.desc.to_string().as_bytes()),
+        )
+        .map_err(io_error)?;
+        if self.kind == RepoDbKind::Files
+            && let Some(files) = package.files.as_ref()
+        {
+            append_entry(
+                &mut self.builder,
+                &dir.join(FILES_FILE_NAME),
+                Some(files.to_string().as_bytes()),
+            )
+            .map_err(io_error)?;
+        }
+        self.last_name = Some(package.name().clone());
+
+        Ok(())
+    }
+
+    /// Finishes the tar archive and returns the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tar archive cannot be finished or the writer cannot be flushed.
+    pub fn finish(self) -> Result<W, Error> {
+        let mut writer = self.builder.into_inner().map_err(io_error)?;
+        writer.flush().map_err(io_error)?;
+
+        Ok(writer)
+    }
+}
+
+impl RepoDbStreamWriter<CompressionEncoder<'_>> {
+    /// Creates a new [`RepoDbStreamWriter`] that writes a tar archive of `kind` to a file at
+    /// `path`.
+    ///
+    /// The tar archive is compressed using `settings`.
+    /// Any existing file at `path` is overwritten.
+    /// Use [`RepoDbStreamWriter::finish_file`] to finish the tar archive and the compression
+    /// stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the file at `path` cannot be created,
+    /// - or a [`CompressionEncoder`] cannot be created using `settings`.
+    pub fn create(
+        path: impl AsRef<Path>,
+        kind: RepoDbKind,
+        settings: &CompressionSettings,
+    ) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let file = File::create(path).map_err(|source| Error::IoPath {
+            path: path.to_path_buf(),
+            context: t!("error-io-path-create-repo-db"),
+            source,
+        })?;
+
+        Ok(Self::new(CompressionEncoder::new(file, settings)?, kind))
+    }
+
+    /// Finishes the tar archive and the compression stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the tar archive cannot be finished,
+    /// - or the compression stream cannot be finished.
+    pub fn finish_file(self) -> Result<(), Error> {
+        self.finish()?.finish()?;
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Debug for RepoDbStreamWriter<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RepoDbStreamWriter")
+            .field("kind", &self.kind)
+            .field("last_name", &self.last_name)
+            .finish()
+    }
+}
+
+/// Creates an [`Error::Io`] for an error that occurred while writing an [alpm-repo-db].
+///
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+fn io_error(source: std::io::Error) -> Error {
+    Error::Io {
+        context: t!("error-io-write-repo-db"),
+        source,
+    }
+}
+
+/// Appends a deterministic entry for `path` to `builder`.
+///
+/// Appends a regular file with `data` if `data` is [`Some`] and a directory otherwise.
+fn append_entry<W: Write>(
+    builder: &mut Builder<W>,
+    path: &Path,
+    data: Option<&[u8]>,
+) -> std::io::Result<()> {
+    let mut header = Header::new_gnu();
+    header.set_mtime(0);
+    header.set_uid(0);
+    header.set_gid(0);
+    match data {
+        Some(data) => {
+            header.set_entry_type(EntryType::Regular);
+            header.set_mode(0o644);
+            header.set_size(data.len() as u64);
+            builder.append_data(&mut header, path, data)
+        }
+        None => {
+            header.set_entry_type(EntryType::Directory);
+            header.set_mode(0o755);
+            header.set_size(0);
+            builder.append_data(&mut header, path, std::io::empty())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::{desc::RepoDescFile, files::RepoFiles};
+
+    const DESC: &str = r#"%FILENAME%
+example-1.0.0-1-any.pkg.tar.zst
+
+%NAME%
+example
+
+%BASE%
+example
+
+%VERSION%
+1.0.0-1
+
+%DESC%
+An example package
+
+%CSIZE%
+4634
+
+%ISIZE%
+0
+
+%SHA256SUM%
+b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+
+%ARCH%
+any
+
+%BUILDDATE%
+1729181726
+
+%PACKAGER%
+Foobar McFooface <foobar@mcfooface.org>
+
+"#;
+
+    /// Ensures that written databases can be read again and are deterministic.
+    #[test]
+    fn repo_db_write_roundtrip() -> TestResult {
+        let mut db = RepoDb::default();
+        let replaced = db.insert(RepoDbPackage {
+            desc: RepoDescFile::from_str(DESC)?,
+            files: Some(RepoFiles::from_str(
+                "%FILES%\nusr/\nusr/bin/\nusr/bin/example\n",
+            )?),
+        });
+        assert!(replaced.is_none());
+
+        for kind in [RepoDbKind::Db, RepoDbKind::Files] {
+            let mut first = Vec::new();
+            db.write_to(&mut first, kind)?;
+            let mut second = Vec::new();
+            db.write_to(&mut second, kind)?;
+            assert_eq!(first, second);
+
+            let read = RepoDb::from_reader(first.as_slice())?;
+            let package = read.get(&Name::new("example")?).ok_or("package missing")?;
+            assert_eq!(package.desc.to_string(), DESC);
+            assert_eq!(package.files.is_some(), kind == RepoDbKind::Files);
+        }
+
+        let removed = db.remove(&Name::new("example")?);
+        assert!(removed.is_some());
+        assert!(db.is_empty());
+
+        Ok(())
+    }
+
+    /// Ensures that the stream writer rejects packages that are not sorted by name.
+    #[test]
+    fn repo_db_stream_writer_unsorted() -> TestResult {
+        let package = RepoDbPackage {
+            desc: RepoDescFile::from_str(DESC)?,
+            files: None,
+        };
+
+        let mut writer = RepoDbStreamWriter::new(Vec::new(), RepoDbKind::Db);
+        writer.append(&package)?;
+        assert!(matches!(
+            writer.append(&package),
+            Err(Error::InvalidRepoDbEntry { .. })
+        ));
+
+        Ok(())
+    }
+}
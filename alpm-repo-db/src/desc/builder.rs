@@ -0,0 +1,180 @@
+//! Creation of [alpm-repo-descv2] data from [alpm-package] files.
+//!
+//! [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+//! [alpm-repo-descv2]: https://alpm.archlinux.page/specifications/alpm-repo-descv2.5.html
+
+use std::fs::read;
+
+use alpm_package::Package;
+use alpm_pkginfo::{PackageInfo, PackageInfoV1, PackageInfoV2};
+use alpm_types::{Base64OpenPGPSignature, Sha256Checksum};
+use base64::{Engine, prelude::BASE64_STANDARD};
+use fluent_i18n::t;
+
+use crate::{Error, desc::RepoDescFileV2};
+
+/// A builder for [alpm-repo-descv2] data of an [alpm-package] file.
+///
+/// The [alpm-repo-descv2] data is derived from the [PKGINFO] data of the package (e.g. build
+/// date, packager and package relations), as well as the size and SHA-256 checksum of the package
+/// file.
+/// Optionally, a detached OpenPGP signature for the package file can be added.
+///
+/// The resulting [`RepoDescFileV2`] is independent of an [alpm-repo-db] and can be written using
+/// its [`Display`](std::fmt::Display) implementation, which emits all sections in the order
+/// expected by [pacman].
+///
+/// # Examples
+///
+/// ```no_run
+/// use alpm_package::Package;
+/// use alpm_repo_db::desc::DescBuilder;
+///
+/// # fn main() -> testresult::TestResult {
+/// let package = Package::try_from(std::path::Path::new("example-1.0.0-1-any.pkg.tar.zst"))?;
+/// let desc = DescBuilder::new(package)
+///     .pgp_signature_bytes(&std::fs::read("example-1.0.0-1-any.pkg.tar.zst.sig")?)?
+///     .build()?;
+/// println!("{desc}");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+/// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+/// [alpm-repo-descv2]: https://alpm.archlinux.page/specifications/alpm-repo-descv2.5.html
+/// [pacman]: https://man.archlinux.org/man/pacman.8
+#[derive(Clone, Debug)]
+pub struct DescBuilder {
+    package: Package,
+    pgp_signature: Option<Base64OpenPGPSignature>,
+}
+
+impl DescBuilder {
+    /// Creates a new [`DescBuilder`] for `package`.
+    pub fn new(package: Package) -> Self {
+        Self {
+            package,
+            pgp_signature: None,
+        }
+    }
+
+    /// Sets the base64 encoded detached OpenPGP signature of the package file.
+    pub fn pgp_signature(mut self, signature: Base64OpenPGPSignature) -> Self {
+        self.pgp_signature = Some(signature);
+        self
+    }
+
+    /// Sets the detached OpenPGP signature of the package file from its raw `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the base64 encoded `bytes` are not a valid OpenPGP signature.
+    pub fn pgp_signature_bytes(self, bytes: &[u8]) -> Result<Self, Error> {
+        Ok(self.pgp_signature(Base64OpenPGPSignature::new(BASE64_STANDARD.encode(bytes))?))
+    }
+
+    /// Creates the [`RepoDescFileV2`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the package file cannot be read,
+    /// - or the package contains no valid [PKGINFO] data.
+    ///
+    /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+    pub fn build(self) -> Result<RepoDescFileV2, Error> {
+        let path = self.package.to_path_buf();
+        let data = read(&path).map_err(|source| Error::IoPath {
+            path: path.clone(),
+            context: t!("error-io-path-read-package"),
+            source,
+        })?;
+        let compressed_size = data.len() as u64;
+        let sha256_checksum = Sha256Checksum::calculate_from(data);
+        let pkginfo = self.package.read_pkginfo()?;
+
+        Ok(self.build_from(pkginfo, compressed_size, sha256_checksum))
+    }
+
+    /// Creates the [`RepoDescFileV2`] from the `pkginfo` data, the `compressed_size` and the
+    /// `sha256_checksum` of the package file.
+    ///
+    /// Allows callers that already read the package file to avoid reading it again.
+    pub(crate) fn build_from(
+        self,
+        pkginfo: PackageInfo,
+        compressed_size: u64,
+        sha256_checksum: Sha256Checksum,
+    ) -> RepoDescFileV2 {
+        match pkginfo {
+            PackageInfo::V1(PackageInfoV1 {
+                pkgname,
+                pkgbase,
+                pkgver,
+                pkgdesc,
+                url,
+                builddate,
+                packager,
+                size,
+                arch,
+                license,
+                replaces,
+                group,
+                conflict,
+                provides,
+                depend,
+                optdepend,
+                makedepend,
+                checkdepend,
+                ..
+            })
+            | PackageInfo::V2(PackageInfoV2 {
+                pkgname,
+                pkgbase,
+                pkgver,
+                pkgdesc,
+                url,
+                builddate,
+                packager,
+                size,
+                arch,
+                license,
+                replaces,
+                group,
+                conflict,
+                provides,
+                depend,
+                optdepend,
+                makedepend,
+                checkdepend,
+                ..
+            }) => RepoDescFileV2 {
+                file_name: self.package.file_name().clone(),
+                name: pkgname,
+                base: pkgbase,
+                version: pkgver,
+                description: pkgdesc,
+                groups: group,
+                compressed_size,
+                installed_size: size,
+                sha256_checksum,
+                pgp_signature: self.pgp_signature,
+                url: Some(url),
+                license,
+                arch,
+                build_date: builddate,
+                packager,
+                replaces,
+                conflicts: conflict,
+                provides,
+                dependencies: depend,
+                optional_dependencies: optdepend,
+                make_dependencies: makedepend,
+                check_dependencies: checkdepend,
+            },
+        }
+    }
+}
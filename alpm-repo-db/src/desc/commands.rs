@@ -3,11 +3,11 @@
 //! [alpm-repo-desc]: https://alpm.archlinux.page/specifications/alpm-repo-desc.5.html
 
 use std::{
-    fs::{File, create_dir_all},
-    io::{IsTerminal, Write, stdin},
+    fs::create_dir_all,
+    io::{IsTerminal, stdin},
 };
 
-use alpm_common::MetadataFile;
+use alpm_common::{MetadataFile, write_atomic};
 use fluent_i18n::t;
 
 use crate::{
@@ -107,18 +107,7 @@ pub fn create_file(command: CreateCommand) -> Result<(), Error> {
             })?;
         }
 
-        let mut out = File::create(&output_path).map_err(|source| Error::IoPath {
-            path: output_path.clone(),
-            context: t!("error-io-create-output-file"),
-            source,
-        })?;
-
-        out.write_all(data.as_bytes())
-            .map_err(|source| Error::IoPath {
-                path: output_path.clone(),
-                context: t!("error-io-write-output-file"),
-                source,
-            })?;
+        write_atomic(&output_path, data)?;
     } else {
         print!("{data}");
     }
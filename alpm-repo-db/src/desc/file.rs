@@ -10,6 +10,17 @@ use std::{
 };
 
 use alpm_common::{FileFormatSchema, MetadataFile};
+use alpm_types::{
+    Architecture,
+    Base64OpenPGPSignature,
+    CompressedSize,
+    FullVersion,
+    Group,
+    Name,
+    PackageFileName,
+    RelationOrSoname,
+    Sha256Checksum,
+};
 use fluent_i18n::t;
 
 use crate::{
@@ -378,6 +389,88 @@ impl MetadataFile<RepoDescSchema> for RepoDescFile {
     }
 }
 
+impl RepoDescFile {
+    /// Returns the name of the package.
+    pub fn name(&self) -> &Name {
+        match self {
+            Self::V1(file) => &file.name,
+            Self::V2(file) => &file.name,
+        }
+    }
+
+    /// Returns the version of the package.
+    pub fn version(&self) -> &FullVersion {
+        match self {
+            Self::V1(file) => &file.version,
+            Self::V2(file) => &file.version,
+        }
+    }
+
+    /// Returns the file name of the package.
+    pub fn file_name(&self) -> &PackageFileName {
+        match self {
+            Self::V1(file) => &file.file_name,
+            Self::V2(file) => &file.file_name,
+        }
+    }
+
+    /// Returns the architecture of the package.
+    pub fn arch(&self) -> &Architecture {
+        match self {
+            Self::V1(file) => &file.arch,
+            Self::V2(file) => &file.arch,
+        }
+    }
+
+    /// Returns the size of the package file.
+    pub fn compressed_size(&self) -> CompressedSize {
+        match self {
+            Self::V1(file) => file.compressed_size,
+            Self::V2(file) => file.compressed_size,
+        }
+    }
+
+    /// Returns the SHA-256 checksum of the package file.
+    pub fn sha256_checksum(&self) -> &Sha256Checksum {
+        match self {
+            Self::V1(file) => &file.sha256_checksum,
+            Self::V2(file) => &file.sha256_checksum,
+        }
+    }
+
+    /// Returns the base64 encoded OpenPGP detached signature of the package file, if any.
+    pub fn pgp_signature(&self) -> Option<&Base64OpenPGPSignature> {
+        match self {
+            Self::V1(file) => Some(&file.pgp_signature),
+            Self::V2(file) => file.pgp_signature.as_ref(),
+        }
+    }
+
+    /// Returns the virtual components or packages that the package provides.
+    pub fn provides(&self) -> &[RelationOrSoname] {
+        match self {
+            Self::V1(file) => &file.provides,
+            Self::V2(file) => &file.provides,
+        }
+    }
+
+    /// Returns the run-time dependencies of the package.
+    pub fn dependencies(&self) -> &[RelationOrSoname] {
+        match self {
+            Self::V1(file) => &file.dependencies,
+            Self::V2(file) => &file.dependencies,
+        }
+    }
+
+    /// Returns the groups the package belongs to.
+    pub fn groups(&self) -> &[Group] {
+        match self {
+            Self::V1(file) => &file.groups,
+            Self::V2(file) => &file.groups,
+        }
+    }
+}
+
 impl Display for RepoDescFile {
     /// Returns the textual representation of the [`RepoDescFile`] in its corresponding
     /// [alpm-repo-desc] format.
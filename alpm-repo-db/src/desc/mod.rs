@@ -2,6 +2,7 @@
 //!
 //! [alpm-repo-desc]: https://alpm.archlinux.page/specifications/alpm-repo-desc.5.html
 
+mod builder;
 mod file;
 mod parser;
 mod schema;
@@ -16,6 +17,7 @@ pub mod cli;
 #[doc(hidden)]
 pub mod commands;
 
+pub use builder::DescBuilder;
 pub use file::RepoDescFile;
 pub use parser::{Section, SectionKeyword};
 pub use schema::RepoDescSchema;
@@ -2,6 +2,7 @@
 
 use std::path::PathBuf;
 
+use alpm_common::ErrorCategory;
 use fluent_i18n::t;
 use winnow::error::{ContextError, ParseError};
 
@@ -13,6 +14,26 @@ use crate::desc::SectionKeyword;
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum Error {
+    /// An [`alpm_common::Error`] occurred.
+    #[error(transparent)]
+    AlpmCommon(#[from] alpm_common::Error),
+
+    /// An [`alpm_compress::Error`] occurred.
+    #[error(transparent)]
+    AlpmCompress(#[from] alpm_compress::Error),
+
+    /// An [`alpm_package::Error`] occurred.
+    #[error(transparent)]
+    AlpmPackage(#[from] alpm_package::Error),
+
+    /// An [`alpm_types::Error`] occurred.
+    #[error("{msg}", msg = t!("error-alpm-types", { "source" => .0.to_string() }))]
+    AlpmTypes(#[from] alpm_types::Error),
+
+    /// A [`crate::files::Error`] occurred.
+    #[error(transparent)]
+    RepoFiles(#[from] crate::files::Error),
+
     /// IO error.
     #[error("{msg}", msg = t!("error-io", { "context" => context, "source" => source.to_string() }))]
     Io {
@@ -97,6 +118,51 @@ pub enum Error {
     #[error("{msg}", msg = t!("error-unsupported-schema-version", { "version" => .0 }))]
     UnsupportedSchemaVersion(String),
 
+    /// An entry of an [alpm-repo-db] is not valid.
+    ///
+    /// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+    #[error("{msg}", msg = t!("error-invalid-repo-db-entry", {
+        "path" => path.display().to_string(),
+        "reason" => reason,
+    }))]
+    InvalidRepoDbEntry {
+        /// The path of the entry in the [alpm-repo-db].
+        ///
+        /// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+        path: PathBuf,
+        /// The reason why the entry is not valid.
+        reason: String,
+    },
+
+    /// The file name of an [alpm-repo-db] is not valid.
+    ///
+    /// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+    #[error("{msg}", msg = t!("error-invalid-repo-db-file-name", {
+        "path" => path.display().to_string(),
+    }))]
+    InvalidRepoDbFileName {
+        /// The path of the [alpm-repo-db].
+        ///
+        /// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+        path: PathBuf,
+    },
+
+    /// A file could not be signed.
+    #[error("{msg}", msg = t!("error-signing-failed", {
+        "path" => path.display().to_string(),
+        "reason" => reason,
+    }))]
+    SigningFailed {
+        /// The path of the file that could not be signed.
+        path: PathBuf,
+        /// The reason why the file could not be signed.
+        reason: String,
+    },
+
+    /// A [`voa::Error`] occurred.
+    #[error(transparent)]
+    Voa(#[from] voa::Error),
+
     /// Failed to parse v1 or v2.
     #[error("{msg}", msg = t!("error-invalid-format"))]
     InvalidFormat,
@@ -108,3 +174,31 @@ impl<'a> From<ParseError<&'a str, ContextError>> for Error {
         Self::ParseError(value.to_string())
     }
 }
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::AlpmCommon(error) => error.into(),
+            Error::AlpmPackage(error) => error.into(),
+            Error::AlpmTypes(error) => error.into(),
+            Error::RepoFiles(error) => error.into(),
+            Error::AlpmCompress(_)
+            | Error::Io { .. }
+            | Error::IoPath { .. }
+            | Error::IoRead { .. }
+            | Error::NoInputFile => Self::Io,
+            Error::ParseError(_) | Error::InvalidFormat => Self::Parse,
+            #[cfg(feature = "cli")]
+            Error::Json { .. } => Self::Parse,
+            Error::MissingSection(_)
+            | Error::DuplicateSection(_)
+            | Error::InvalidSectionForVersion { .. }
+            | Error::EmptySection(_)
+            | Error::InvalidRepoDbEntry { .. }
+            | Error::InvalidRepoDbFileName { .. } => Self::Validation,
+            Error::UnsupportedSchemaVersion(_) => Self::Schema,
+            Error::SigningFailed { .. } | Error::Voa(_) => Self::Crypto,
+        }
+    }
+}
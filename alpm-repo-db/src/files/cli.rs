@@ -119,4 +119,17 @@ pub enum Command {
         )]
         input_file: Option<PathBuf>,
     },
+
+    /// The json-schema command.
+    #[command(about = t!("cli-json-schema-about"), long_about = t!("cli-json-schema-long-about"))]
+    JsonSchema {
+        /// Determines whether the output will be displayed in a pretty non-minimized fashion.
+        #[arg(
+            env = "ALPM_REPO_FILES_JSON_SCHEMA_PRETTY",
+            help = t!("cli-format-pretty-help"),
+            long,
+            short,
+        )]
+        pretty: bool,
+    },
 }
@@ -2,6 +2,7 @@
 
 use std::path::PathBuf;
 
+use alpm_common::ErrorCategory;
 use fluent_i18n::t;
 use winnow::error::{ContextError, ParseError};
 
@@ -74,3 +75,16 @@ impl<'a> From<ParseError<&'a str, ContextError>> for Error {
         Self::ParseError(value.to_string())
     }
 }
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::AlpmCommon(error) => error.into(),
+            Error::InvalidFilesPaths { .. } => Self::Validation,
+            Error::Io { .. } | Error::IoPath { .. } => Self::Io,
+            Error::ParseError(_) => Self::Parse,
+            Error::UnknownSchemaVersion => Self::Schema,
+        }
+    }
+}
@@ -30,6 +30,7 @@ pub use v1::RepoFilesV1;
 ///
 /// [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
 #[derive(Clone, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum RepoFiles {
     /// Version 1 of the [alpm-repo-files] specification.
@@ -197,6 +197,7 @@ impl Display for RepoFilesV1PathErrors {
 ///
 /// [alpm-repo-files]: https://alpm.archlinux.page/specifications/alpm-repo-files.5.html
 #[derive(Clone, Debug, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RepoFilesV1(Vec<PathBuf>);
 
 impl AsRef<[PathBuf]> for RepoFilesV1 {
@@ -0,0 +1,399 @@
+//! Integration tests for creating and updating [alpm-repo-db] files from [alpm-package] files.
+//!
+//! [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+//! [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+
+use std::{
+    fs::{File, OpenOptions, copy, create_dir, create_dir_all, write},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use alpm_compress::compression::CompressionSettings;
+use alpm_mtree::create_mtree_v2_from_input_dir;
+use alpm_package::{InputDir, OutputDir, Package, PackageCreationConfig, PackageInput};
+use alpm_repo_db::{
+    db::{RepoDb, RepoDbKind, RepoDbPackage, RepoDirIssue},
+    desc::{DescBuilder, RepoDescFile},
+};
+use alpm_types::{MetadataFileName, Name};
+use tempfile::tempdir;
+use testresult::TestResult;
+
+/// Creates an [alpm-package] file for `name` in `version` in `path` and returns its path.
+///
+/// [alpm-package]: https://alpm.archlinux.page/specifications/alpm-package.7.html
+fn create_package(path: &Path, name: &str, version: &str) -> TestResult<PathBuf> {
+    let input_dir_path = path.join(format!("{name}-{version}-input"));
+    create_dir(&input_dir_path)?;
+    let input_dir = InputDir::new(input_dir_path)?;
+
+    let mut file = File::create(input_dir.join(MetadataFileName::BuildInfo.as_ref()))?;
+    write!(
+        file,
+        r#"format = 2
+builddate = 1
+builddir = /build
+startdir = /startdir/
+buildtool = devtools
+buildtoolver = 1:1.2.1-1-any
+installed = other-example-1.2.3-1-any
+packager = John Doe <john@example.org>
+pkgarch = any
+pkgbase = {name}
+pkgbuild_sha256sum = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+pkgname = {name}
+pkgver = {version}
+"#
+    )?;
+    let mut file = File::create(input_dir.join(MetadataFileName::PackageInfo.as_ref()))?;
+    write!(
+        file,
+        r#"pkgname = {name}
+pkgbase = {name}
+xdata = pkgtype=pkg
+pkgver = {version}
+pkgdesc = An example package
+url = https://example.org/
+builddate = 1
+packager = John Doe <john@example.org>
+size = 5
+arch = any
+license = GPL-3.0-or-later
+depend = bash
+"#
+    )?;
+    create_dir_all(input_dir.join("usr/bin"))?;
+    write(input_dir.join(format!("usr/bin/{name}")), "true\n")?;
+    create_mtree_v2_from_input_dir(&input_dir)?;
+
+    let output_dir_path = path.join(format!("{name}-{version}-output"));
+    create_dir(&output_dir_path)?;
+    let package_input: PackageInput = input_dir.try_into()?;
+    let config = PackageCreationConfig::new(
+        package_input,
+        OutputDir::new(output_dir_path)?,
+        CompressionSettings::default(),
+    )?;
+
+    Ok(Package::try_from(&config)?.to_path_buf())
+}
+
+/// Ensures that database entries are derived from package files.
+#[test]
+fn repo_db_package_from_package_file() -> TestResult {
+    let temp_dir = tempdir()?;
+    let package_path = create_package(temp_dir.path(), "example", "1.0.0-1")?;
+    write(
+        format!("{}.sig", package_path.display()),
+        b"not really a signature",
+    )?;
+
+    let package = RepoDbPackage::from_package_file(&package_path)?;
+    let RepoDescFile::V2(desc) = &package.desc else {
+        panic!("The desc data should be version 2");
+    };
+    assert_eq!(
+        desc.file_name.to_string(),
+        "example-1.0.0-1-any.pkg.tar.zst"
+    );
+    assert_eq!(desc.name.to_string(), "example");
+    assert_eq!(desc.version.to_string(), "1.0.0-1");
+    assert_eq!(
+        desc.compressed_size,
+        std::fs::metadata(&package_path)?.len()
+    );
+    assert_eq!(desc.installed_size, 5);
+    assert_eq!(
+        desc.pgp_signature.as_ref().map(ToString::to_string),
+        Some("bm90IHJlYWxseSBhIHNpZ25hdHVyZQ==".to_string())
+    );
+
+    let Some(files) = &package.files else {
+        panic!("The package should have files data");
+    };
+    assert_eq!(
+        files.as_ref(),
+        [
+            PathBuf::from("usr/"),
+            PathBuf::from("usr/bin/"),
+            PathBuf::from("usr/bin/example"),
+        ]
+    );
+
+    Ok(())
+}
+
+/// Ensures that a database is created from all package files in a directory.
+#[test]
+fn repo_db_from_package_dir() -> TestResult {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path();
+    let repo_dir = path.join("repo");
+    create_dir(&repo_dir)?;
+
+    for (name, version) in [("foo", "2.0.0-1"), ("foo", "1.0.0-1"), ("bar", "1.0.0-1")] {
+        let package_path = create_package(path, name, version)?;
+        copy(
+            &package_path,
+            repo_dir.join(package_path.file_name().ok_or("file name missing")?),
+        )?;
+    }
+    write(
+        repo_dir.join("bar-1.0.0-1-any.pkg.tar.zst.sig"),
+        b"not really a signature",
+    )?;
+
+    let db = RepoDb::from_package_dir(&repo_dir)?;
+    let versions: Vec<String> = db
+        .packages()
+        .map(|package| format!("{}-{}", package.name(), package.version()))
+        .collect();
+    assert_eq!(versions, ["bar-1.0.0-1", "foo-2.0.0-1"]);
+    assert!(db.packages().all(|package| package.files.is_some()));
+
+    // The size and checksum calculated while streaming the package files match the files.
+    let report = db.validate_dir(&repo_dir, false)?;
+    assert!(
+        report
+            .packages
+            .iter()
+            .all(|package| package.issues.is_empty())
+    );
+    assert_eq!(
+        report.orphans,
+        [repo_dir.join("foo-1.0.0-1-any.pkg.tar.zst")]
+    );
+
+    Ok(())
+}
+
+/// Ensures that desc data is created from package files with sections in the order of pacman.
+#[test]
+fn desc_builder_from_package() -> TestResult {
+    let temp_dir = tempdir()?;
+    let package_path = create_package(temp_dir.path(), "example", "1.0.0-1")?;
+
+    let desc = DescBuilder::new(Package::try_from(package_path.as_path())?)
+        .pgp_signature_bytes(b"not really a signature")?
+        .build()?;
+    assert_eq!(desc.name.to_string(), "example");
+    assert_eq!(
+        desc.compressed_size,
+        std::fs::metadata(&package_path)?.len()
+    );
+
+    let output = desc.to_string();
+    let sections: Vec<&str> = output
+        .lines()
+        .filter(|line| line.starts_with('%'))
+        .collect();
+    assert_eq!(
+        sections,
+        [
+            "%FILENAME%",
+            "%NAME%",
+            "%BASE%",
+            "%VERSION%",
+            "%DESC%",
+            "%CSIZE%",
+            "%ISIZE%",
+            "%SHA256SUM%",
+            "%PGPSIG%",
+            "%URL%",
+            "%LICENSE%",
+            "%ARCH%",
+            "%BUILDDATE%",
+            "%PACKAGER%",
+            "%DEPENDS%",
+        ]
+    );
+
+    Ok(())
+}
+
+/// Ensures that packages can be added to, replaced in and removed from a database.
+#[test]
+fn repo_db_add_replace_remove() -> TestResult {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path();
+    let db_path = path.join("core.db.tar.gz");
+    let files_path = path.join("core.files.tar.gz");
+    let settings = CompressionSettings::Gzip {
+        compression_level: Default::default(),
+    };
+
+    let mut db = RepoDb::default();
+    for (name, version) in [("foo", "1.0.0-1"), ("bar", "1.0.0-1")] {
+        db.insert(RepoDbPackage::from_package_file(create_package(
+            path, name, version,
+        )?)?);
+    }
+    let replaced = db.insert(RepoDbPackage::from_package_file(create_package(
+        path, "foo", "2.0.0-1",
+    )?)?);
+    assert_eq!(
+        replaced.map(|package| package.version().to_string()),
+        Some("1.0.0-1".to_string())
+    );
+    db.write_to_file(&db_path, RepoDbKind::Db, &settings)?;
+    db.write_to_file(&files_path, RepoDbKind::Files, &settings)?;
+
+    let read = RepoDb::from_file(&db_path)?;
+    let versions: Vec<String> = read
+        .packages()
+        .map(|package| format!("{}-{}", package.name(), package.version()))
+        .collect();
+    assert_eq!(versions, ["bar-1.0.0-1", "foo-2.0.0-1"]);
+    assert!(read.packages().all(|package| package.files.is_none()));
+
+    let mut read = RepoDb::from_file(&files_path)?;
+    assert!(read.packages().all(|package| package.files.is_some()));
+    assert!(read.remove(&Name::new("foo")?).is_some());
+    assert_eq!(read.len(), 1);
+
+    Ok(())
+}
+
+/// Ensures that inconsistencies between a database and its repository directory are detected.
+#[test]
+fn repo_db_validate_dir() -> TestResult {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path();
+    let repo_dir = path.join("repo");
+    create_dir(&repo_dir)?;
+
+    let mut db = RepoDb::default();
+    for name in ["foo", "bar", "baz"] {
+        let package_path = create_package(path, name, "1.0.0-1")?;
+        let repo_path = repo_dir.join(package_path.file_name().ok_or("file name missing")?);
+        copy(&package_path, &repo_path)?;
+        if name != "baz" {
+            db.insert(RepoDbPackage::from_package_file(&repo_path)?);
+        }
+    }
+    // Corrupt the package file of "bar", so that its size no longer matches.
+    let mut file = OpenOptions::new()
+        .append(true)
+        .open(repo_dir.join("bar-1.0.0-1-any.pkg.tar.zst"))?;
+    file.write_all(b"garbage")?;
+
+    let report = db.validate_dir(&repo_dir, false)?;
+    assert!(!report.is_consistent());
+    assert_eq!(
+        report.orphans,
+        [repo_dir.join("baz-1.0.0-1-any.pkg.tar.zst")]
+    );
+    let issues: Vec<(String, Vec<RepoDirIssue>)> = report
+        .packages
+        .into_iter()
+        .map(|package| (package.name.to_string(), package.issues))
+        .collect();
+    assert_eq!(issues[0].0, "bar");
+    assert!(matches!(
+        issues[0].1.as_slice(),
+        [RepoDirIssue::SizeMismatch { .. }]
+    ));
+    assert_eq!(issues[1], ("foo".to_string(), Vec::new()));
+
+    let report = db.validate_dir(&repo_dir, true)?;
+    assert!(
+        report
+            .packages
+            .iter()
+            .all(|package| package.issues.contains(&RepoDirIssue::MissingSignature))
+    );
+
+    Ok(())
+}
+
+/// Ensures that the `alpm-repo-db` CLI creates and updates databases.
+#[cfg(feature = "cli")]
+#[test]
+fn repo_db_cli_add_remove() -> TestResult {
+    use assert_cmd::cargo_bin_cmd;
+
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path();
+    let db_path = path.join("core.db.tar.zst");
+    let foo = create_package(path, "foo", "1.0.0-1")?;
+    let bar = create_package(path, "bar", "1.0.0-1")?;
+
+    let mut cmd = cargo_bin_cmd!("alpm-repo-db");
+    cmd.arg("add").arg(&db_path).arg(&foo).arg(&bar);
+    cmd.assert().success();
+
+    for link in ["core.db", "core.files"] {
+        assert!(path.join(link).is_symlink());
+    }
+    let db = RepoDb::from_file(path.join("core.db"))?;
+    assert_eq!(db.len(), 2);
+    let db = RepoDb::from_file(path.join("core.files.tar.zst"))?;
+    assert!(db.packages().all(|package| package.files.is_some()));
+
+    let mut cmd = cargo_bin_cmd!("alpm-repo-db");
+    cmd.arg("remove").arg(&db_path).arg("foo");
+    cmd.assert().success();
+
+    let db = RepoDb::from_file(&db_path)?;
+    let names: Vec<String> = db
+        .packages()
+        .map(|package| package.name().to_string())
+        .collect();
+    assert_eq!(names, ["bar"]);
+
+    Ok(())
+}
+
+/// Ensures that the `alpm-repo-db` CLI prints the differences between two databases as JSON.
+#[cfg(feature = "cli")]
+#[test]
+fn repo_db_cli_diff() -> TestResult {
+    use std::str::FromStr;
+
+    use alpm_types::FullVersion;
+    use assert_cmd::cargo_bin_cmd;
+
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path();
+    let old_path = path.join("old.db.tar.gz");
+    let new_path = path.join("new.db.tar.gz");
+    let settings = CompressionSettings::Gzip {
+        compression_level: Default::default(),
+    };
+
+    let mut old = RepoDb::default();
+    old.insert(RepoDbPackage::from_package_file(create_package(
+        path, "foo", "1.0.0-1",
+    )?)?);
+    old.insert(RepoDbPackage::from_package_file(create_package(
+        path, "bar", "1.0.0-1",
+    )?)?);
+    let mut new = old.clone();
+    new.insert(RepoDbPackage::from_package_file(create_package(
+        path, "foo", "1.1.0-1",
+    )?)?);
+    new.remove(&Name::new("bar")?);
+    old.write_to_file(&old_path, RepoDbKind::Db, &settings)?;
+    new.write_to_file(&new_path, RepoDbKind::Db, &settings)?;
+
+    let mut cmd = cargo_bin_cmd!("alpm-repo-db");
+    cmd.arg("diff").arg(&old_path).arg(&new_path);
+    let output = cmd.assert().success().get_output().clone();
+    let diff: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+
+    let version = |version: &str| -> TestResult<serde_json::Value> {
+        Ok(serde_json::to_value(FullVersion::from_str(version)?)?)
+    };
+    assert_eq!(
+        diff,
+        serde_json::json!({
+            "added": [],
+            "removed": [{"name": "bar", "version": version("1.0.0-1")?}],
+            "upgraded": [{"name": "foo", "old": version("1.0.0-1")?, "new": version("1.1.0-1")?}],
+            "downgraded": [],
+        })
+    );
+
+    Ok(())
+}
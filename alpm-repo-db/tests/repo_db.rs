@@ -0,0 +1,195 @@
+//! Integration tests for reading [alpm-repo-db] files.
+//!
+//! [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+
+use std::{fs::File, path::Path, str::FromStr};
+
+use alpm_compress::{
+    compression::{CompressionSettings, ZstdCompressionLevel, ZstdThreads},
+    tarball::TarballBuilder,
+};
+use alpm_repo_db::{Error, db::RepoDb};
+use alpm_types::{FullVersion, Name};
+use rstest::rstest;
+use tar::{EntryType, Header};
+use tempfile::tempdir;
+use testresult::TestResult;
+
+/// A placeholder for valid [alpm-repo-descv2] data of the package `foo` in version `1.0.0-1`.
+///
+/// [alpm-repo-descv2]: https://alpm.archlinux.page/specifications/alpm-repo-descv2.5.html
+const FOO_DESC: &str = "<foo desc>";
+
+/// Returns [alpm-repo-descv2] data for a package with `name` and `version`.
+///
+/// [alpm-repo-descv2]: https://alpm.archlinux.page/specifications/alpm-repo-descv2.5.html
+fn desc_data(name: &str, version: &str) -> String {
+    format!(
+        r#"%FILENAME%
+{name}-{version}-any.pkg.tar.zst
+
+%NAME%
+{name}
+
+%BASE%
+{name}
+
+%VERSION%
+{version}
+
+%DESC%
+An example package
+
+%CSIZE%
+4634
+
+%ISIZE%
+0
+
+%SHA256SUM%
+b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+
+%ARCH%
+any
+
+%BUILDDATE%
+1729181726
+
+%PACKAGER%
+Foobar McFooface <foobar@mcfooface.org>
+
+"#
+    )
+}
+
+/// Creates a repository sync database at `path` containing `entries` of paths and contents.
+fn create_repo_db(
+    path: &Path,
+    settings: &CompressionSettings,
+    entries: &[(&str, &str)],
+) -> TestResult {
+    let mut builder = TarballBuilder::new(File::create(path)?, settings)?;
+    for (entry_path, content) in entries {
+        let dir = Path::new(entry_path).parent().unwrap();
+        let mut header = Header::new_gnu();
+        header.set_entry_type(EntryType::Directory);
+        header.set_mode(0o755);
+        header.set_size(0);
+        builder
+            .inner_mut()
+            .append_data(&mut header, dir, std::io::empty())?;
+
+        let mut header = Header::new_gnu();
+        header.set_mode(0o644);
+        header.set_size(content.len() as u64);
+        builder
+            .inner_mut()
+            .append_data(&mut header, entry_path, content.as_bytes())?;
+    }
+    builder.finish()?;
+    Ok(())
+}
+
+/// Ensures that repository sync databases can be read, regardless of file name and compression.
+#[rstest]
+#[case::gzip_db("core.db", CompressionSettings::Gzip { compression_level: Default::default() })]
+#[case::zstd_db("core.db.tar.zst", CompressionSettings::Zstd {
+    compression_level: ZstdCompressionLevel::default(),
+    threads: ZstdThreads::new(1),
+})]
+#[case::uncompressed_db("core.db.tar", CompressionSettings::None)]
+fn read_repo_db(#[case] file_name: &str, #[case] settings: CompressionSettings) -> TestResult {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().join(file_name);
+    let foo = desc_data("foo", "1.0.0-1");
+    let bar = desc_data("bar", "1:2.0.0-3");
+    create_repo_db(
+        &path,
+        &settings,
+        &[("foo-1.0.0-1/desc", &foo), ("bar-1:2.0.0-3/desc", &bar)],
+    )?;
+
+    let db = RepoDb::from_file(&path)?;
+    assert_eq!(db.len(), 2);
+    let names: Vec<String> = db
+        .packages()
+        .map(|package| package.name().to_string())
+        .collect();
+    assert_eq!(names, ["bar", "foo"]);
+
+    let bar = Name::new("bar")?;
+    assert!(db.get(&bar).is_some());
+    assert!(
+        db.get_version(&bar, &FullVersion::from_str("1:2.0.0-3")?)
+            .is_some()
+    );
+    assert!(
+        db.get_version(&bar, &FullVersion::from_str("2.0.0-3")?)
+            .is_none()
+    );
+    assert!(db.get(&Name::new("baz")?).is_none());
+    assert!(db.packages().all(|package| package.files.is_none()));
+
+    Ok(())
+}
+
+/// Ensures that the files data of `.files` repository sync databases is read.
+#[test]
+fn read_repo_files_db() -> TestResult {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().join("core.files");
+    let foo = desc_data("foo", "1.0.0-1");
+    create_repo_db(
+        &path,
+        &CompressionSettings::Gzip {
+            compression_level: Default::default(),
+        },
+        &[
+            ("foo-1.0.0-1/desc", &foo),
+            (
+                "foo-1.0.0-1/files",
+                "%FILES%\nusr/\nusr/bin/\nusr/bin/foo\n",
+            ),
+        ],
+    )?;
+
+    let db = RepoDb::from_reader(File::open(&path)?)?;
+    let Some(package) = db.get(&Name::new("foo")?) else {
+        panic!("The package foo should exist");
+    };
+    let Some(files) = &package.files else {
+        panic!("The package foo should have files data");
+    };
+    assert_eq!(files.as_ref().len(), 3);
+
+    Ok(())
+}
+
+/// Ensures that invalid repository sync database entries are rejected.
+#[rstest]
+#[case::dir_name_mismatch(&[("foo-2.0.0-1/desc", FOO_DESC)])]
+#[case::missing_desc(&[
+    ("foo-1.0.0-1/desc", FOO_DESC),
+    ("bar-1.0.0-1/files", "%FILES%\n"),
+])]
+#[case::invalid_desc(&[("foo-1.0.0-1/desc", "%NAME%\nfoo\n")])]
+fn read_invalid_repo_db(#[case] entries: &[(&str, &str)]) -> TestResult {
+    let temp_dir = tempdir()?;
+    let path = temp_dir.path().join("core.db");
+    let foo = desc_data("foo", "1.0.0-1");
+    let entries: Vec<(&str, &str)> = entries
+        .iter()
+        .map(|(path, content)| match *content {
+            FOO_DESC => (*path, foo.as_str()),
+            content => (*path, content),
+        })
+        .collect();
+    create_repo_db(&path, &CompressionSettings::None, &entries)?;
+
+    assert!(matches!(
+        RepoDb::from_file(&path),
+        Err(Error::InvalidRepoDbEntry { .. })
+    ));
+
+    Ok(())
+}
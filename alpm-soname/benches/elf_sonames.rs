@@ -0,0 +1,164 @@
+//! Benchmark for the extraction of sonames from the ELF files of packages.
+//!
+//! Compares [`extract_elf_sonames`] and [`extract_elf_sonames_from_packages`] running on a single
+//! thread (which corresponds to the former, sequential implementation) with running them on the
+//! default [rayon] thread pool.
+//!
+//! The benchmarked package contains copies of the benchmark executable, which is itself an ELF
+//! file.
+//! The number of copies, the number of packages passed to
+//! [`extract_elf_sonames_from_packages`] and the number of iterations can be set using the
+//! `ELF_SONAMES_FILES`, `ELF_SONAMES_PACKAGES` and `ELF_SONAMES_ITERATIONS` environment
+//! variables:
+//!
+//! ```sh
+//! ELF_SONAMES_FILES=64 cargo bench -p alpm-soname --bench elf_sonames
+//! ```
+
+use std::{
+    env::{current_exe, var},
+    fs::{copy, create_dir_all, write},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use alpm_compress::compression::CompressionSettings;
+use alpm_mtree::create_mtree_v2_from_input_dir;
+use alpm_package::{InputDir, OutputDir, Package, PackageCreationConfig, PackageInput};
+use alpm_soname::{extract_elf_sonames, extract_elf_sonames_from_packages};
+use alpm_types::MetadataFileName;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use tempfile::tempdir;
+use testresult::TestResult;
+
+const BUILDINFO: &str = r#"
+format = 2
+builddate = 1
+builddir = /build
+startdir = /startdir/
+buildtool = devtools
+buildtoolver = 1:1.2.1-1-any
+packager = John Doe <john@example.org>
+pkgarch = any
+pkgbase = bench
+pkgbuild_sha256sum = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+pkgname = bench
+pkgver = 1:1.0.0-1
+"#;
+
+const PKGINFO: &str = r#"
+pkgname = bench
+pkgbase = bench
+xdata = pkgtype=pkg
+pkgver = 1:1.0.0-1
+pkgdesc = A package with many ELF files
+url = https://example.org/
+builddate = 1
+packager = John Doe <john@example.org>
+size = 181849963
+arch = any
+license = GPL-3.0-or-later
+"#;
+
+/// Returns the value of the environment variable `name` or `default`, if it is unset or invalid.
+fn env_or(name: &str, default: usize) -> usize {
+    var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Creates a package in `path`, that contains `files` copies of the current executable.
+fn create_package(path: &Path, files: usize) -> TestResult<PathBuf> {
+    let input_dir = path.join("input");
+    create_dir_all(&input_dir)?;
+    let input_dir = InputDir::new(input_dir)?;
+    let output_dir = OutputDir::new(path.join("output"))?;
+
+    write(
+        input_dir.join(MetadataFileName::PackageInfo.as_ref()),
+        PKGINFO,
+    )?;
+    write(
+        input_dir.join(MetadataFileName::BuildInfo.as_ref()),
+        BUILDINFO,
+    )?;
+
+    let executable = current_exe()?;
+    create_dir_all(input_dir.join("usr/bin"))?;
+    for index in 0..files {
+        copy(&executable, input_dir.join(format!("usr/bin/bench{index}")))?;
+    }
+
+    create_mtree_v2_from_input_dir(&input_dir)?;
+
+    let package_input: PackageInput = input_dir.try_into()?;
+    let config =
+        PackageCreationConfig::new(package_input, output_dir, CompressionSettings::default())?;
+
+    Ok(Package::try_from(&config)?.to_path_buf())
+}
+
+/// Runs [`extract_elf_sonames`] on `package` `iterations` times in `pool`.
+///
+/// Returns the average duration of a run.
+fn bench(package: &Path, pool: &ThreadPool, iterations: usize) -> TestResult<Duration> {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let elf_sonames = pool.install(|| extract_elf_sonames(package.to_path_buf()))?;
+        assert!(!elf_sonames.is_empty());
+    }
+    Ok(start.elapsed() / iterations as u32)
+}
+
+/// Runs [`extract_elf_sonames_from_packages`] on `packages` `iterations` times in `pool`.
+///
+/// Returns the average duration of a run.
+fn bench_packages(
+    packages: &[PathBuf],
+    pool: &ThreadPool,
+    iterations: usize,
+) -> TestResult<Duration> {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let elf_sonames = pool.install(|| extract_elf_sonames_from_packages(packages.to_vec()))?;
+        assert_eq!(elf_sonames.len(), packages.len());
+    }
+    Ok(start.elapsed() / iterations as u32)
+}
+
+fn main() -> TestResult {
+    let files = env_or("ELF_SONAMES_FILES", 32);
+    let package_count = env_or("ELF_SONAMES_PACKAGES", 8).max(1);
+    let iterations = env_or("ELF_SONAMES_ITERATIONS", 5).max(1);
+    let temp_dir = tempdir()?;
+    let package = create_package(temp_dir.path(), files)?;
+
+    let sequential_pool = ThreadPoolBuilder::new().num_threads(1).build()?;
+    let parallel_pool = ThreadPoolBuilder::new().build()?;
+    let sequential = bench(&package, &sequential_pool, iterations)?;
+    let parallel = bench(&package, &parallel_pool, iterations)?;
+
+    println!("extract_elf_sonames ({files} ELF files, {iterations} iterations)");
+    println!("  sequential: {sequential:?}");
+    println!(
+        "  parallel:   {parallel:?} ({} threads)",
+        parallel_pool.current_num_threads()
+    );
+
+    let packages = vec![package; package_count];
+    let sequential = bench_packages(&packages, &sequential_pool, iterations)?;
+    let parallel = bench_packages(&packages, &parallel_pool, iterations)?;
+
+    println!(
+        "extract_elf_sonames_from_packages ({package_count} packages with {files} ELF files, \
+         {iterations} iterations)"
+    );
+    println!("  sequential: {sequential:?}");
+    println!(
+        "  parallel:   {parallel:?} ({} threads)",
+        parallel_pool.current_num_threads()
+    );
+
+    Ok(())
+}
@@ -12,6 +12,10 @@ error-io-write-dependency-output = writing dependency to output
 
 error-io-write-provision-output = writing provision to output
 
+error-io-write-scan-output = writing scan result to output
+
+error-io-write-interpreter-output = writing interpreter to output
+
 error-io-read-error = I/O read error while { $context }:
   { $source }
 
@@ -43,6 +43,55 @@ pub enum Command {
         /// Package arguments for the get-dependencies command
         #[command(flatten)]
         args: PackageArgs,
+
+        /// A repository sync database to look up the providers of the dependencies in
+        ///
+        /// Can be provided multiple times.
+        /// If this or --local-db is provided, all soname dependencies of the ELF files in the
+        /// package are printed together with the names of the packages that provide them.
+        #[arg(long, value_name = "DB_FILE")]
+        repo_db: Vec<PathBuf>,
+
+        /// A local database directory to look up the providers of the dependencies in
+        ///
+        /// If this or --repo-db is provided, all soname dependencies of the ELF files in the
+        /// package are printed together with the names of the packages that provide them.
+        #[arg(long, value_name = "LOCAL_DB_DIR")]
+        local_db: Option<PathBuf>,
+    },
+
+    /// Scan a package for its soname provisions and dependencies
+    ///
+    /// Shared objects located in the lookup directory are considered provisions and all shared
+    /// objects required by ELF files in the package are considered dependencies.
+    /// In the plain output format, the data is printed as PKGINFO "provides" and "depend" lines.
+    #[command()]
+    Scan {
+        /// The lookup directory for shared libraries in `<prefix>:<directory>` format
+        #[arg(short, long, default_value = "lib:/usr/lib", value_name = "LOOKUP_DIR")]
+        lookup_dir: SonameLookupDirectory,
+
+        /// Package arguments for the scan command
+        #[command(flatten)]
+        args: PackageArgs,
+    },
+
+    /// Get the interpreters required by the files of a package
+    ///
+    /// Interpreters are detected from the shebang lines of scripts and the program interpreter of
+    /// ELF files.
+    #[command()]
+    GetInterpreters {
+        /// Package arguments for the get-interpreters command
+        #[command(flatten)]
+        args: PackageArgs,
+
+        /// A local database directory to look up the packages owning the interpreters in
+        ///
+        /// If provided, the names of the packages owning the interpreters are printed instead of
+        /// the interpreters.
+        #[arg(long, value_name = "LOCAL_DB_DIR")]
+        local_db: Option<PathBuf>,
     },
 
     /// Get raw dependencies without filtering by lookup directory
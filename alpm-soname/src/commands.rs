@@ -1,13 +1,25 @@
 //! Command line functions that are called by the `alpm-soname` executable.
 
-use std::{collections::BTreeMap, io::Write, path::PathBuf};
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    path::{Path, PathBuf},
+};
 
+use alpm_common::ErrorCategory;
+use alpm_db::local::LocalDb;
+use alpm_repo_db::db::RepoDb;
 use alpm_soname::{
     ElfSonames,
+    SonameProviderLookup,
     cli::{OutputFormat, PackageArgs},
     extract_elf_sonames,
     find_dependencies,
+    find_dependency_providers,
+    find_interpreters,
     find_provisions,
+    interpreter_relations,
+    scan_package,
 };
 use alpm_types::{Name, Soname, SonameLookupDirectory, SonameV2};
 use fluent_i18n::t;
@@ -26,6 +38,16 @@ pub enum Error {
     SonameError(#[from] alpm_soname::Error),
 }
 
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::Json(_) => Self::Parse,
+            Error::SonameError(error) => error.into(),
+        }
+    }
+}
+
 /// Get the provisions of a package and print them to the given output.
 ///
 /// See the [`find_provisions`] function for more details.
@@ -141,6 +163,135 @@ pub fn get_dependencies<W: Write>(
     Ok(())
 }
 
+/// Get the dependencies of a package and the packages providing them and print them to the
+/// given output.
+///
+/// The providers are looked up in the repository sync databases `repo_dbs` and the local database
+/// directory `local_db`.
+/// See the [`find_dependency_providers`] function for more details.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - one of `repo_dbs` or `local_db` cannot be read,
+/// - [`find_dependency_providers`] returns an error,
+/// - or the output stream can not be written to.
+pub fn get_dependency_providers<W: Write>(
+    args: PackageArgs,
+    lookup_dir: SonameLookupDirectory,
+    repo_dbs: &[PathBuf],
+    local_db: Option<&Path>,
+    output: &mut W,
+) -> Result<(), Error> {
+    let mut lookup = SonameProviderLookup::default();
+    for repo_db in repo_dbs {
+        lookup.add_repo_db(&RepoDb::from_file(repo_db).map_err(alpm_soname::Error::from)?);
+    }
+    if let Some(local_db) = local_db {
+        lookup.add_local_db(&LocalDb::from_dir(local_db).map_err(alpm_soname::Error::from)?);
+    }
+
+    let dependencies = find_dependency_providers(args.package, lookup_dir, &lookup)?;
+    match args.output_format {
+        OutputFormat::Plain => {
+            for dependency in &dependencies {
+                // Providers are listed on separate lines when pretty printing.
+                let separator = if args.pretty { "\n ⤷ " } else { " " };
+                let mut line = dependency.soname.to_string();
+                for provider in &dependency.providers {
+                    line.push_str(separator);
+                    line.push_str(provider.as_ref());
+                }
+                writeln!(output, "{line}").map_err(|source| alpm_soname::Error::IoWrite {
+                    context: t!("error-io-write-dependency-output"),
+                    source,
+                })?;
+            }
+        }
+        OutputFormat::Json => {
+            let json = if args.pretty {
+                serde_json::to_string_pretty(&dependencies)?
+            } else {
+                serde_json::to_string(&dependencies)?
+            };
+            writeln!(output, "{json}").map_err(|source| alpm_soname::Error::IoWrite {
+                context: t!("error-io-write-json"),
+                source,
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Get the interpreters required by the files of a package and print them to the given output.
+///
+/// If `local_db` is provided, the packages owning the interpreters are looked up in the local
+/// database directory `local_db` and printed as package relations instead (see
+/// [`interpreter_relations`]).
+/// See the [`find_interpreters`] function for more details.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - `local_db` cannot be read,
+/// - [`find_interpreters`] returns an error,
+/// - or the output stream can not be written to.
+pub fn get_interpreters<W: Write>(
+    args: PackageArgs,
+    local_db: Option<&Path>,
+    output: &mut W,
+) -> Result<(), Error> {
+    let interpreters = find_interpreters(args.package)?;
+    let relations = local_db
+        .map(|local_db| {
+            LocalDb::from_dir(local_db)
+                .map(|db| interpreter_relations(&interpreters, &db))
+                .map_err(alpm_soname::Error::from)
+        })
+        .transpose()?;
+
+    match args.output_format {
+        OutputFormat::Plain => {
+            let lines: Vec<String> = match &relations {
+                Some(relations) => relations.iter().map(ToString::to_string).collect(),
+                None => interpreters
+                    .iter()
+                    .map(|dependency| {
+                        format!(
+                            "{} {}",
+                            dependency.path.display(),
+                            dependency.interpreter.display()
+                        )
+                    })
+                    .collect(),
+            };
+            for line in lines {
+                writeln!(output, "{line}").map_err(|source| alpm_soname::Error::IoWrite {
+                    context: t!("error-io-write-interpreter-output"),
+                    source,
+                })?;
+            }
+        }
+        OutputFormat::Json => {
+            let json = match (&relations, args.pretty) {
+                (Some(relations), true) => serde_json::to_string_pretty(relations)?,
+                (Some(relations), false) => serde_json::to_string(relations)?,
+                (None, true) => serde_json::to_string_pretty(&interpreters)?,
+                (None, false) => serde_json::to_string(&interpreters)?,
+            };
+            writeln!(output, "{json}").map_err(|source| alpm_soname::Error::IoWrite {
+                context: t!("error-io-write-json"),
+                source,
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Get the raw ELF soname dependencies of a package and print them to the given output.
 ///
 /// Unlike [`get_dependencies`], this function does not filter the dependencies by the lookup
@@ -250,6 +401,43 @@ pub fn get_raw_dependencies<W: Write>(
     Ok(())
 }
 
+/// Scan a package for its soname provisions and dependencies and print them to the given output.
+///
+/// See the [`scan_package`] function for more details.
+///
+/// # Errors
+///
+/// Returns an error if [`scan_package`] returns an error or if the output stream can not be
+/// written to.
+pub fn scan<W: Write>(
+    args: PackageArgs,
+    lookup_dir: SonameLookupDirectory,
+    output: &mut W,
+) -> Result<(), Error> {
+    let metadata = scan_package(args.package, lookup_dir)?;
+    match args.output_format {
+        OutputFormat::Plain => {
+            write!(output, "{metadata}").map_err(|source| alpm_soname::Error::IoWrite {
+                context: t!("error-io-write-scan-output"),
+                source,
+            })?;
+        }
+        OutputFormat::Json => {
+            let json = if args.pretty {
+                serde_json::to_string_pretty(&metadata)?
+            } else {
+                serde_json::to_string(&metadata)?
+            };
+            writeln!(output, "{json}").map_err(|source| alpm_soname::Error::IoWrite {
+                context: t!("error-io-write-json"),
+                source,
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Groups a list of [`SonameV2`] data by their shared library prefixes.
 ///
 /// Returns a map of shared library prefixes, each with a list of raw [`Soname`] information
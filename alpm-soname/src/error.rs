@@ -2,6 +2,7 @@
 
 use std::path::PathBuf;
 
+use alpm_common::ErrorCategory;
 use fluent_i18n::t;
 
 /// The error that can occur when working with the library.
@@ -69,6 +70,14 @@ pub enum Error {
     #[error(transparent)]
     AlpmPackage(#[from] alpm_package::Error),
 
+    /// ALPM DB error
+    #[error(transparent)]
+    AlpmDb(#[from] alpm_db::Error),
+
+    /// ALPM repository database error
+    #[error(transparent)]
+    AlpmRepoDb(#[from] alpm_repo_db::Error),
+
     /// ELF format handling error
     #[error("{msg}", msg = t!("error-elf-error", {
         "context" => context,
@@ -98,3 +107,20 @@ pub enum Error {
         path: PathBuf,
     },
 }
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::IoPath { .. } | Error::IoWrite { .. } | Error::IoRead { .. } => Self::Io,
+            Error::AlpmPkginfo(error) => error.into(),
+            Error::AlpmType(error) => error.into(),
+            Error::AlpmPackage(error) => error.into(),
+            Error::AlpmDb(error) => error.into(),
+            Error::AlpmRepoDb(error) => error.into(),
+            Error::Elf { .. } => Self::Parse,
+            Error::ElfFileNotFound { .. } => Self::Validation,
+            Error::InputDirectoryNotSupported { .. } => Self::Unsupported,
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Detection of interpreter dependencies of packages.
+
+use std::{
+    collections::{BTreeSet, HashSet},
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use alpm_db::local::LocalDb;
+use alpm_package::Package;
+use alpm_types::{Name, PackageRelation};
+use fluent_i18n::t;
+use goblin::Hint;
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, lookup::parse_elf};
+
+/// The maximum number of bytes of a shebang line that are considered.
+///
+/// This corresponds to the limit used by the Linux kernel.
+const SHEBANG_MAX_LENGTH: u64 = 256;
+
+/// The directory in which commands called via `/usr/bin/env` are expected.
+const COMMAND_DIRECTORY: &str = "/usr/bin";
+
+/// The source of an [`InterpreterDependency`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, strum::Display)]
+pub enum InterpreterSource {
+    /// The shebang line of a script (e.g. `#!/usr/bin/python3`).
+    #[strum(serialize = "shebang")]
+    Shebang,
+
+    /// The `PT_INTERP` program header of an ELF file (e.g. `/usr/lib/ld-linux-x86-64.so.2`).
+    #[strum(serialize = "elf")]
+    Elf,
+}
+
+/// An interpreter required by a file in a package.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct InterpreterDependency {
+    /// The path to the file in the package archive.
+    pub path: PathBuf,
+    /// The absolute path of the interpreter.
+    pub interpreter: PathBuf,
+    /// Where the interpreter has been found.
+    pub source: InterpreterSource,
+}
+
+/// Returns the interpreter of the shebang line at the start of `data`.
+///
+/// Returns [`None`] if `data` does not start with a shebang line or if the interpreter is not an
+/// absolute path.
+/// If the interpreter is called via `/usr/bin/env` (e.g. `#!/usr/bin/env python3`), the command
+/// passed to it is returned as interpreter, located in `/usr/bin` (e.g. `/usr/bin/python3`).
+/// Options and environment variable assignments passed to `env` are skipped.
+fn shebang_interpreter(data: &[u8]) -> Option<PathBuf> {
+    let line = data.strip_prefix(b"#!")?;
+    let line = line.split(|byte| *byte == b'\n').next()?;
+    let mut words = str::from_utf8(line).ok()?.split_whitespace();
+
+    let interpreter = Path::new(words.next()?);
+    if !interpreter.is_absolute() {
+        return None;
+    }
+    if interpreter.file_name().is_some_and(|name| name == "env") {
+        return words
+            .find(|word| !word.starts_with('-') && !word.contains('='))
+            .map(|command| Path::new(COMMAND_DIRECTORY).join(command));
+    }
+
+    Some(interpreter.to_path_buf())
+}
+
+/// Finds the interpreters required by the files of a package.
+///
+/// This function opens the package file at `path` and inspects all data files in it:
+///
+/// - For scripts, the interpreter is taken from their shebang line (see [`InterpreterSource`]).
+/// - For ELF files, the interpreter is taken from their `PT_INTERP` program header.
+///
+/// Interpreters that are contained in the package itself are ignored.
+///
+/// # Errors
+///
+/// Returns an error if:
+///
+/// - the input `path` is a directory,
+/// - the package cannot be opened for reading,
+/// - the files in the package cannot be read,
+/// - or the ELF files in the package cannot be parsed.
+pub fn find_interpreters(path: PathBuf) -> Result<Vec<InterpreterDependency>, Error> {
+    if path.is_dir() {
+        return Err(Error::InputDirectoryNotSupported { path });
+    }
+
+    let package = Package::try_from(path.as_path())?;
+    let mut reader = package.into_reader()?;
+    let mut package_paths = HashSet::new();
+    let mut dependencies = Vec::new();
+    for entry in reader.data_entries()? {
+        let mut entry = entry?;
+        let path_in_archive = entry.path().to_path_buf();
+        package_paths.insert(Path::new("/").join(&path_in_archive));
+
+        let mut buffer = Vec::new();
+        entry
+            .by_ref()
+            .take(SHEBANG_MAX_LENGTH)
+            .read_to_end(&mut buffer)
+            .map_err(|source| Error::IoRead {
+                context: t!("error-io-read-archive-entry"),
+                source,
+            })?;
+
+        let (interpreter, source) = if let Some(interpreter) = shebang_interpreter(&buffer) {
+            (interpreter, InterpreterSource::Shebang)
+        } else if let Some(Ok(Hint::Elf(_))) = buffer.first_chunk().map(goblin::peek_bytes) {
+            entry
+                .read_to_end(&mut buffer)
+                .map_err(|source| Error::IoRead {
+                    context: t!("error-io-read-archive-entry"),
+                    source,
+                })?;
+            let Some(interpreter) = parse_elf(&buffer)?.and_then(|elf| elf.interpreter) else {
+                continue;
+            };
+            (PathBuf::from(interpreter), InterpreterSource::Elf)
+        } else {
+            continue;
+        };
+
+        debug!("Interpreter of {path_in_archive:?} ({source}): {interpreter:?}");
+        dependencies.push(InterpreterDependency {
+            path: path_in_archive,
+            interpreter,
+            source,
+        });
+    }
+
+    dependencies.retain(|dependency| !package_paths.contains(&dependency.interpreter));
+    Ok(dependencies)
+}
+
+/// Returns the package relations for the interpreters in `dependencies`.
+///
+/// The packages providing the interpreters are looked up by file ownership in the local
+/// database `db`.
+/// Interpreters, that are not owned by any package in `db`, are ignored.
+///
+/// The returned list is ordered by package name and contains each package only once.
+pub fn interpreter_relations(
+    dependencies: &[InterpreterDependency],
+    db: &LocalDb,
+) -> Vec<PackageRelation> {
+    let names: BTreeSet<&Name> = dependencies
+        .iter()
+        .filter_map(|dependency| {
+            let owner = db.owner(&dependency.interpreter);
+            if owner.is_none() {
+                debug!("No owner found for {:?}", dependency.interpreter);
+            }
+            owner.map(|entry| entry.name())
+        })
+        .collect();
+
+    names
+        .into_iter()
+        .map(|name| PackageRelation::new(name.clone(), None))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Ensures that interpreters are extracted from shebang lines.
+    #[rstest]
+    #[case::path(b"#!/usr/bin/python3\nprint()\n", Some("/usr/bin/python3"))]
+    #[case::path_with_argument(b"#!/bin/sh -e\n", Some("/bin/sh"))]
+    #[case::leading_space(b"#! /usr/bin/bash\n", Some("/usr/bin/bash"))]
+    #[case::env(b"#!/usr/bin/env python3\n", Some("/usr/bin/python3"))]
+    #[case::env_with_options(b"#!/usr/bin/env -S LC_ALL=C perl -w\n", Some("/usr/bin/perl"))]
+    #[case::env_without_command(b"#!/usr/bin/env\n", None)]
+    #[case::relative(b"#!python3\n", None)]
+    #[case::empty(b"#!\n", None)]
+    #[case::no_shebang(b"print()\n", None)]
+    #[case::elf(b"\x7fELF\x02\x01\x01", None)]
+    fn shebang_interpreter_from_data(#[case] data: &[u8], #[case] expected: Option<&str>) {
+        assert_eq!(shebang_interpreter(data), expected.map(PathBuf::from));
+    }
+}
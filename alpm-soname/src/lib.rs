@@ -4,8 +4,28 @@
 #[doc(hidden)]
 pub mod cli;
 
+mod interpreter;
+pub use interpreter::{
+    InterpreterDependency,
+    InterpreterSource,
+    find_interpreters,
+    interpreter_relations,
+};
+
 mod lookup;
-pub use lookup::{ElfSonames, extract_elf_sonames, find_dependencies, find_provisions};
+pub use lookup::{
+    ElfSonames,
+    extract_elf_sonames,
+    extract_elf_sonames_from_packages,
+    find_dependencies,
+    find_provisions,
+};
+
+mod providers;
+pub use providers::{SonameProviderLookup, SonameProviders, find_dependency_providers};
+
+mod scan;
+pub use scan::{SonameMetadata, scan_package};
 
 mod error;
 pub use error::Error;
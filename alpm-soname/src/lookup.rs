@@ -1,12 +1,17 @@
 //! Package lookup handling
-use std::{io::Read, path::PathBuf, str::FromStr};
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use alpm_package::Package;
 use alpm_pkginfo::PackageInfo;
 use alpm_types::{RelationOrSoname, Soname, SonameLookupDirectory, SonameV2};
 use fluent_i18n::t;
-use goblin::{Hint, Object};
+use goblin::{Hint, Object, elf::Elf};
 use log::{debug, trace};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use serde::{Deserialize, Serialize};
 
 use crate::Error;
@@ -20,6 +25,12 @@ pub struct ElfSonames {
     pub sonames: Vec<Soname>,
 }
 
+/// The maximum accumulated size of ELF file data that is parsed in parallel at once.
+///
+/// Bounds the memory used while extracting sonames from packages with many or large ELF files.
+/// A single ELF file larger than this is still read and parsed on its own.
+const ELF_BATCH_SIZE: usize = 64 * 1024 * 1024;
+
 /// Extracts the **sonames** from ELF files contained in a package.
 ///
 /// This function opens the package file, decompresses it, and reads the ELF files from
@@ -27,6 +38,8 @@ pub struct ElfSonames {
 /// From each ELF file it then extracts the shared object dependencies and returns them as a
 /// vector of [`ElfSonames`].
 ///
+/// The ELF files are read sequentially and parsed in parallel, in batches of limited size.
+///
 /// # Errors
 ///
 /// Returns an error if:
@@ -35,9 +48,131 @@ pub struct ElfSonames {
 /// - the ELF files in `package` cannot be read/parsed,
 /// - or the found shared objects cannot be parsed as [`Soname`].
 pub fn extract_elf_sonames(path: PathBuf) -> Result<Vec<ElfSonames>, Error> {
-    let package = Package::try_from(path.as_path())?;
-    let mut reader = package.into_reader()?;
     let mut elf_sonames = Vec::new();
+    let mut batch = Vec::new();
+    let mut batch_size = 0;
+
+    read_elf_entries(&path, |path_in_archive, buffer| {
+        batch_size += buffer.len();
+        batch.push((path_in_archive, buffer));
+        if batch_size >= ELF_BATCH_SIZE {
+            elf_sonames.extend(parse_elf_sonames(std::mem::take(&mut batch))?);
+            batch_size = 0;
+        }
+        Ok(())
+    })?;
+    elf_sonames.extend(parse_elf_sonames(batch)?);
+
+    Ok(elf_sonames)
+}
+
+/// Extracts the **sonames** from ELF files contained in several packages.
+///
+/// The packages at `paths` are processed in parallel, each using [`extract_elf_sonames`].
+/// Returns the path of each package together with the [`ElfSonames`] of its ELF files, in the
+/// order of `paths`.
+///
+/// # Note
+///
+/// As each package is processed with [`extract_elf_sonames`], up to one batch of ELF file data
+/// per thread is kept in memory at a time.
+///
+/// # Errors
+///
+/// Returns an error if [`extract_elf_sonames`] fails for any of the packages.
+pub fn extract_elf_sonames_from_packages(
+    paths: Vec<PathBuf>,
+) -> Result<Vec<(PathBuf, Vec<ElfSonames>)>, Error> {
+    paths
+        .into_par_iter()
+        .map(|path| {
+            let elf_sonames = extract_elf_sonames(path.clone())?;
+            Ok((path, elf_sonames))
+        })
+        .collect()
+}
+
+/// Parses the ELF files in `batch` in parallel and extracts their **sonames**.
+///
+/// Returns the [`ElfSonames`] of all ELF files in `batch`, in the order of `batch`.
+///
+/// # Errors
+///
+/// Returns an error if the ELF files in `batch` cannot be parsed, or if the found shared objects
+/// cannot be parsed as [`Soname`].
+fn parse_elf_sonames(batch: Vec<(PathBuf, Vec<u8>)>) -> Result<Vec<ElfSonames>, Error> {
+    batch
+        .into_par_iter()
+        .map(
+            |(path_in_archive, buffer)| -> Result<Option<ElfSonames>, Error> {
+                let Some(elf) = parse_elf(&buffer)? else {
+                    return Ok(None);
+                };
+                debug!("⤷ Dependencies of {path_in_archive:?}: {:?}", elf.libraries);
+                let sonames = elf
+                    .libraries
+                    .iter()
+                    .map(|library| Soname::from_str(library))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Some(ElfSonames {
+                    path: path_in_archive,
+                    sonames,
+                }))
+            },
+        )
+        .filter_map(Result::transpose)
+        .collect()
+}
+
+/// Calls `f` for each ELF file contained in the package at `path`.
+///
+/// `f` is called with the path of the ELF file in the package archive and the parsed ELF data,
+/// in the order in which the ELF files appear in the package archive.
+/// Only the data of one ELF file is kept in memory at a time.
+///
+/// # Errors
+///
+/// Returns an error if:
+///
+/// - the package cannot be opened for reading (see [`Package::try_from`]),
+/// - the ELF files in `package` cannot be read/parsed,
+/// - or `f` returns an error.
+pub(crate) fn for_each_elf(
+    path: &Path,
+    mut f: impl FnMut(PathBuf, &Elf<'_>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    read_elf_entries(path, |path_in_archive, buffer| {
+        if let Some(elf) = parse_elf(&buffer)? {
+            f(path_in_archive, &elf)?;
+        }
+        Ok(())
+    })
+}
+
+/// Reads the data of each ELF file contained in the package at `path` and passes it to `f`.
+///
+/// This function opens the package file, decompresses it, and reads the ELF files from
+/// the archive.
+/// Non-ELF files are skipped based on their header, without reading the rest of their data.
+/// `f` is called with the path of each ELF file in the package archive together with its data, in
+/// the order in which they appear in the package archive.
+///
+/// Reading is done sequentially, as the package archive is a single compressed stream.
+/// The data passed to `f` can be parsed independently (e.g. in parallel) using [`parse_elf`].
+///
+/// # Errors
+///
+/// Returns an error if:
+///
+/// - the package cannot be opened for reading (see [`Package::try_from`]),
+/// - the ELF files in `package` cannot be read,
+/// - or `f` returns an error.
+fn read_elf_entries(
+    path: &Path,
+    mut f: impl FnMut(PathBuf, Vec<u8>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let package = Package::try_from(path)?;
+    let mut reader = package.into_reader()?;
     for entry in reader.data_entries()? {
         let mut entry = entry?;
         let path_in_archive = entry.path().to_path_buf();
@@ -68,26 +203,27 @@ pub fn extract_elf_sonames(path: PathBuf) -> Result<Vec<ElfSonames>, Error> {
                 context: t!("error-io-read-archive-entry"),
                 source,
             })?;
-
-        // Parse the ELF file and collect the dependencies
-        let object = Object::parse(&buffer).map_err(|source| Error::Elf {
-            context: t!("error-parse-elf"),
-            source,
-        })?;
-        if let Object::Elf(elf) = object {
-            debug!("⤷ Dependencies: {:?}", elf.libraries);
-            let mut sonames = Vec::new();
-            for library in elf.libraries.iter() {
-                let soname = Soname::from_str(library)?;
-                sonames.push(soname);
-            }
-            elf_sonames.push(ElfSonames {
-                path: path_in_archive,
-                sonames,
-            });
-        }
+        f(path_in_archive, buffer)?;
     }
-    Ok(elf_sonames)
+    Ok(())
+}
+
+/// Parses `buffer` as ELF file.
+///
+/// Returns [`None`] if `buffer` is a valid object file, but not an ELF file.
+///
+/// # Errors
+///
+/// Returns an error if `buffer` cannot be parsed.
+pub(crate) fn parse_elf(buffer: &[u8]) -> Result<Option<Elf<'_>>, Error> {
+    let object = Object::parse(buffer).map_err(|source| Error::Elf {
+        context: t!("error-parse-elf"),
+        source,
+    })?;
+    Ok(match object {
+        Object::Elf(elf) => Some(elf),
+        _ => None,
+    })
 }
 
 /// Finds the **soname** data provided by a package.
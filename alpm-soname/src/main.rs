@@ -2,6 +2,7 @@
 
 use std::process::ExitCode;
 
+use alpm_common::ErrorCategory;
 use alpm_soname::cli::{Cli, Command};
 use clap::Parser;
 use log::{debug, error};
@@ -9,7 +10,14 @@ use simplelog::{Config, SimpleLogger};
 
 mod commands;
 
-use commands::{get_dependencies, get_provisions, get_raw_dependencies};
+use commands::{
+    get_dependencies,
+    get_dependency_providers,
+    get_interpreters,
+    get_provisions,
+    get_raw_dependencies,
+    scan,
+};
 
 fluent_i18n::i18n!("locales");
 
@@ -24,9 +32,28 @@ fn main() -> ExitCode {
         Command::GetProvisions { args, lookup_dir } => {
             get_provisions(args, lookup_dir, &mut std::io::stdout())
         }
-        Command::GetDependencies { args, lookup_dir } => {
-            get_dependencies(args, lookup_dir, &mut std::io::stdout())
+        Command::GetDependencies {
+            args,
+            lookup_dir,
+            repo_db,
+            local_db,
+        } => {
+            if repo_db.is_empty() && local_db.is_none() {
+                get_dependencies(args, lookup_dir, &mut std::io::stdout())
+            } else {
+                get_dependency_providers(
+                    args,
+                    lookup_dir,
+                    &repo_db,
+                    local_db.as_deref(),
+                    &mut std::io::stdout(),
+                )
+            }
         }
+        Command::GetInterpreters { args, local_db } => {
+            get_interpreters(args, local_db.as_deref(), &mut std::io::stdout())
+        }
+        Command::Scan { args, lookup_dir } => scan(args, lookup_dir, &mut std::io::stdout()),
         Command::GetRawDependencies { args, elf, detail } => {
             get_raw_dependencies(args, elf, detail, &mut std::io::stdout())
         }
@@ -34,7 +61,7 @@ fn main() -> ExitCode {
 
     if let Err(error) = result {
         error!("{error}");
-        ExitCode::FAILURE
+        ErrorCategory::from(&error).into()
     } else {
         ExitCode::SUCCESS
     }
@@ -0,0 +1,173 @@
+//! Lookup of soname providers in repository sync databases and local databases.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+};
+
+use alpm_db::local::LocalDb;
+use alpm_repo_db::db::RepoDb;
+use alpm_types::{Name, RelationOrSoname, SonameLookupDirectory, SonameV2};
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, extract_elf_sonames};
+
+/// A soname dependency and the packages that provide it.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SonameProviders {
+    /// The soname dependency.
+    pub soname: SonameV2,
+    /// The names of the packages that provide [`SonameProviders::soname`], ordered by name.
+    ///
+    /// Empty if no package provides the soname.
+    pub providers: Vec<Name>,
+}
+
+/// A lookup table for the [alpm-sonamev2] provisions of packages.
+///
+/// The provisions are collected from parsed [alpm-repo-db] and local [alpm-db] data, which allows
+/// resolving soname dependencies without access to the files of the providing packages.
+///
+/// [alpm-db]: https://alpm.archlinux.page/specifications/alpm-db.7.html
+/// [alpm-repo-db]: https://alpm.archlinux.page/specifications/alpm-repo-db.7.html
+/// [alpm-sonamev2]: https://alpm.archlinux.page/specifications/alpm-sonamev2.7.html
+#[derive(Clone, Debug, Default)]
+pub struct SonameProviderLookup {
+    /// The names of the providing packages by string representation of the provided soname.
+    provisions: BTreeMap<String, BTreeSet<Name>>,
+}
+
+impl SonameProviderLookup {
+    /// Adds the [alpm-sonamev2] provisions of the package `name`.
+    ///
+    /// All other entries in `provides` are ignored.
+    ///
+    /// [alpm-sonamev2]: https://alpm.archlinux.page/specifications/alpm-sonamev2.7.html
+    pub fn add_package(&mut self, name: &Name, provides: &[RelationOrSoname]) {
+        for provision in provides {
+            if let RelationOrSoname::SonameV2(soname) = provision {
+                self.provisions
+                    .entry(soname.to_string())
+                    .or_default()
+                    .insert(name.clone());
+            }
+        }
+    }
+
+    /// Adds the provisions of all packages in a [`RepoDb`].
+    pub fn add_repo_db(&mut self, db: &RepoDb) {
+        for package in db.packages() {
+            self.add_package(package.name(), package.desc.provides());
+        }
+    }
+
+    /// Adds the provisions of all packages in a [`LocalDb`].
+    pub fn add_local_db(&mut self, db: &LocalDb) {
+        for entry in db.entries() {
+            self.add_package(entry.name(), entry.desc.provides());
+        }
+    }
+
+    /// Returns the names of all packages that provide `soname`, ordered by name.
+    pub fn providers(&self, soname: &SonameV2) -> Vec<Name> {
+        self.provisions
+            .get(&soname.to_string())
+            .map(|names| names.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if no provisions have been added.
+    pub fn is_empty(&self) -> bool {
+        self.provisions.is_empty()
+    }
+}
+
+/// Finds the providers of the **soname** dependencies of a package.
+///
+/// This function takes a package file `path`, a lookup directory `lookup_dir` and a `lookup`
+/// table, and returns each soname dependency of the package together with the packages that
+/// provide it according to `lookup`.
+///
+/// Dependencies are extracted from the dynamic section of all ELF files contained in the package
+/// (see [`extract_elf_sonames`]) and are prefixed with the prefix of `lookup_dir`.
+/// Unlike [`find_dependencies`][`crate::find_dependencies`], the [PKGINFO] data of the package is
+/// not considered.
+///
+/// The returned list is ordered by soname and contains each soname only once.
+///
+/// # Errors
+///
+/// Returns an error if:
+///
+/// - the input `path` is a directory,
+/// - or the ELF files in the package cannot be read/parsed (see [`extract_elf_sonames`]).
+///
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+pub fn find_dependency_providers(
+    path: PathBuf,
+    lookup_dir: SonameLookupDirectory,
+    lookup: &SonameProviderLookup,
+) -> Result<Vec<SonameProviders>, Error> {
+    if path.is_dir() {
+        return Err(Error::InputDirectoryNotSupported { path });
+    }
+
+    let mut sonames: Vec<_> = extract_elf_sonames(path)?
+        .into_iter()
+        .flat_map(|elf| elf.sonames)
+        .collect();
+    sonames.sort();
+    sonames.dedup();
+
+    let providers = sonames
+        .into_iter()
+        .map(|soname| {
+            let soname = SonameV2::new(lookup_dir.prefix.clone(), soname);
+            let providers = lookup.providers(&soname);
+            debug!("Providers of {soname}: {providers:?}");
+            SonameProviders { soname, providers }
+        })
+        .collect();
+
+    Ok(providers)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that only soname provisions are added and that providers are ordered by name.
+    #[test]
+    fn soname_provider_lookup() -> TestResult {
+        let mut lookup = SonameProviderLookup::default();
+        assert!(lookup.is_empty());
+
+        lookup.add_package(
+            &Name::new("glibc")?,
+            &[
+                RelationOrSoname::from_str("lib:libc.so.6")?,
+                RelationOrSoname::from_str("libc")?,
+            ],
+        );
+        lookup.add_package(
+            &Name::new("compat-glibc")?,
+            &[RelationOrSoname::from_str("lib:libc.so.6")?],
+        );
+
+        assert_eq!(
+            lookup.providers(&SonameV2::from_str("lib:libc.so.6")?),
+            [Name::new("compat-glibc")?, Name::new("glibc")?]
+        );
+        assert!(
+            lookup
+                .providers(&SonameV2::from_str("lib:libm.so.6")?)
+                .is_empty()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+//! Automatic detection of soname provisions and dependencies of packages.
+
+use std::{
+    fmt::{Display, Formatter},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use alpm_types::{Soname, SonameLookupDirectory, SonameV2};
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, lookup::for_each_elf};
+
+/// The [alpm-sonamev2] provisions and dependencies of a package.
+///
+/// Created using [`scan_package`].
+///
+/// The [`Display`] implementation outputs the data as `provides` and `depend` lines, which can be
+/// added to the [PKGINFO] data of the package.
+///
+/// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
+/// [alpm-sonamev2]: https://alpm.archlinux.page/specifications/alpm-sonamev2.7.html
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SonameMetadata {
+    /// The sonames provided by the shared objects of the package.
+    pub provides: Vec<SonameV2>,
+    /// The sonames required by the ELF files of the package.
+    pub depends: Vec<SonameV2>,
+}
+
+impl Display for SonameMetadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for provision in &self.provides {
+            writeln!(f, "provides = {provision}")?;
+        }
+        for dependency in &self.depends {
+            writeln!(f, "depend = {dependency}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Scans a package file for its **soname** provisions and dependencies.
+///
+/// This function takes a package file `path` and a lookup directory `lookup_dir` and extracts
+/// the [alpm-sonamev2] data of all ELF files contained in the package:
+///
+/// - Each ELF file located directly in the directory of `lookup_dir` that encodes a soname in its
+///   dynamic section (`DT_SONAME`) is considered a provision.
+/// - Each shared object required by any ELF file (`DT_NEEDED`) is considered a dependency, unless
+///   it is provided by the package itself.
+///
+/// All sonames are prefixed with the prefix of `lookup_dir`.
+/// Both lists are ordered and do not contain duplicates.
+///
+/// # Errors
+///
+/// Returns an error if:
+///
+/// - the input `path` is a directory,
+/// - the package cannot be opened for reading,
+/// - the ELF files in the package cannot be read/parsed,
+/// - or the found sonames cannot be parsed as [`Soname`].
+///
+/// [alpm-sonamev2]: https://alpm.archlinux.page/specifications/alpm-sonamev2.7.html
+pub fn scan_package(
+    path: PathBuf,
+    lookup_dir: SonameLookupDirectory,
+) -> Result<SonameMetadata, Error> {
+    if path.is_dir() {
+        return Err(Error::InputDirectoryNotSupported { path });
+    }
+
+    let mut provides: Vec<Soname> = Vec::new();
+    let mut depends: Vec<Soname> = Vec::new();
+    for_each_elf(&path, |path_in_archive, elf| {
+        let in_lookup_dir = Path::new("/")
+            .join(&path_in_archive)
+            .parent()
+            .is_some_and(|parent| parent == lookup_dir.directory.inner());
+        if in_lookup_dir && let Some(soname) = elf.soname {
+            debug!("⤷ Provides: {soname}");
+            provides.push(Soname::from_str(soname)?);
+        }
+        for library in elf.libraries.iter() {
+            depends.push(Soname::from_str(library)?);
+        }
+        Ok(())
+    })?;
+
+    provides.sort();
+    provides.dedup();
+    depends.sort();
+    depends.dedup();
+    depends.retain(|soname| !provides.contains(soname));
+
+    let prefixed = |sonames: Vec<Soname>| -> Vec<SonameV2> {
+        sonames
+            .into_iter()
+            .map(|soname| SonameV2::new(lookup_dir.prefix.clone(), soname))
+            .collect()
+    };
+    Ok(SonameMetadata {
+        provides: prefixed(provides),
+        depends: prefixed(depends),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that the metadata is output as PKGINFO lines.
+    #[test]
+    fn soname_metadata_display() -> TestResult {
+        let metadata = SonameMetadata {
+            provides: vec![SonameV2::from_str("lib:libfoo.so.1")?],
+            depends: vec![
+                SonameV2::from_str("lib:libc.so.6")?,
+                SonameV2::from_str("lib:libm.so.6")?,
+            ],
+        };
+
+        assert_eq!(
+            metadata.to_string(),
+            "provides = lib:libfoo.so.1\ndepend = lib:libc.so.6\ndepend = lib:libm.so.6\n"
+        );
+        Ok(())
+    }
+}
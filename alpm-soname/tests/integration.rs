@@ -15,7 +15,16 @@ use std::{
     str::FromStr,
 };
 
-use alpm_soname::{ElfSonames, extract_elf_sonames, find_dependencies, find_provisions};
+use alpm_soname::{
+    ElfSonames,
+    InterpreterSource,
+    extract_elf_sonames,
+    extract_elf_sonames_from_packages,
+    find_dependencies,
+    find_interpreters,
+    find_provisions,
+    scan_package,
+};
 use alpm_types::{Soname, SonameLookupDirectory, SonameV2};
 use assert_cmd::cargo::cargo_bin_cmd;
 use rstest::rstest;
@@ -240,6 +249,13 @@ fn test_soname_lookup(#[case] config: SotestConfig) -> TestResult {
         sonames_detail.contains(&soname_binsotest2),
         "Expected to find {soname_binsotest2:?} in {sonames_detail:?}"
     );
+    assert_eq!(
+        extract_elf_sonames_from_packages(vec![lib.to_path_buf(), bin.to_path_buf()])?,
+        vec![
+            (lib.to_path_buf(), extract_elf_sonames(lib.to_path_buf())?),
+            (bin.to_path_buf(), sonames_detail.clone()),
+        ]
+    );
     let sonames_default = {
         let mut sonames_default: Vec<_> = sonames_detail
             .iter()
@@ -266,5 +282,24 @@ fn test_soname_lookup(#[case] config: SotestConfig) -> TestResult {
         soname_binsotest2
     );
 
+    let interpreters = find_interpreters(bin.to_path_buf())?;
+    assert!(
+        interpreters
+            .iter()
+            .any(|dependency| dependency.path == Path::new("usr/bin/sotest")
+                && dependency.source == InterpreterSource::Elf),
+        "Expected an ELF interpreter for usr/bin/sotest in {interpreters:?}"
+    );
+
+    let metadata = scan_package(bin.to_path_buf(), config.lookup.clone())?;
+    assert!(metadata.provides.is_empty());
+    assert_eq!(
+        metadata.depends,
+        sonames_default
+            .into_iter()
+            .map(|soname| SonameV2::new(config.lookup.prefix.clone(), soname))
+            .collect::<Vec<_>>()
+    );
+
     Ok(())
 }
@@ -25,6 +25,8 @@ error-parse = File parsing error:
 
 error-json = JSON error: { $error }
 
+error-source-checksum-count-mismatch = The keyword '{ $keyword }' provides { $checksums } hash digests, but { $sources } sources are defined.
+
 error-unsupported-schema-version = Unsupported schema version: { $version }
 
 error-bridge = alpm-pkgbuild bridge error: { $error }
@@ -53,3 +55,13 @@ error-bridge-unclearable-value = Tried to clear value for keyword '{ $keyword }'
 
 error-bridge-unexpected-array = Found array of values for keyword '{ $keyword }' that expects a single value:
   { $values }
+
+source-policy-insecure-scheme = The source uses the insecure scheme '{ $scheme }', but HTTPS is required.
+
+source-policy-forbidden-scheme = The source uses the forbidden scheme '{ $scheme }'.
+
+architecture-warning-unknown-architecture = The architecture '{ $architecture }' in section '{ $section }' is not a known alpm-architecture.
+
+architecture-warning-unknown-suffix = The keyword '{ $keyword }' in section '{ $section }' uses the unknown architecture '{ $architecture }', which is not listed in 'arch'.
+
+architecture-warning-undeclared-suffix = The keyword '{ $keyword }' in section '{ $section }' uses the architecture '{ $architecture }', which is not listed in 'arch'.
@@ -0,0 +1,96 @@
+//! Generation of arbitrary [SRCINFO] data for fuzzing and property tests.
+//!
+//! [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+
+use std::fmt::Display;
+
+use alpm_types::{
+    Architecture,
+    Backup,
+    Epoch,
+    License,
+    Name,
+    OptionalDependency,
+    PackageDescription,
+    PackageOption,
+    PackageRelation,
+    PackageRelease,
+    PackageVersion,
+    Url,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Valid [SRCINFO] data, that is created by construction from unstructured input.
+///
+/// The data consists of a `pkgbase` section and one or more `pkgname` sections.
+/// All values are created using the [`Arbitrary`] implementations of their respective types, so
+/// that the resulting data can always be parsed as [`SourceInfoV1`](crate::SourceInfoV1).
+///
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+#[derive(Clone, Debug)]
+pub struct SourceInfoText(String);
+
+impl SourceInfoText {
+    /// Returns the [SRCINFO] data as string slice.
+    ///
+    /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Appends an indented `key = value` line for each of an arbitrary number of `T` to `lines`.
+fn push_all<'a, T: Arbitrary<'a> + Display>(
+    u: &mut Unstructured<'a>,
+    lines: &mut Vec<String>,
+    key: &str,
+) -> Result<()> {
+    for value in u.arbitrary::<Vec<T>>()? {
+        lines.push(format!("\t{key} = {value}"));
+    }
+
+    Ok(())
+}
+
+impl<'a> Arbitrary<'a> for SourceInfoText {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut lines = vec![
+            format!("pkgbase = {}", u.arbitrary::<Name>()?),
+            format!("\tpkgdesc = {}", u.arbitrary::<PackageDescription>()?),
+            format!("\tpkgver = {}", u.arbitrary::<PackageVersion>()?),
+            format!("\tpkgrel = {}", u.arbitrary::<PackageRelease>()?),
+        ];
+        if let Some(epoch) = u.arbitrary::<Option<Epoch>>()? {
+            lines.push(format!("\tepoch = {epoch}"));
+        }
+        lines.push(format!("\turl = {}", u.arbitrary::<Url>()?));
+        lines.push(format!("\tarch = {}", u.arbitrary::<Architecture>()?));
+        push_all::<Name>(u, &mut lines, "groups")?;
+        push_all::<License>(u, &mut lines, "license")?;
+        push_all::<PackageRelation>(u, &mut lines, "checkdepends")?;
+        push_all::<PackageRelation>(u, &mut lines, "makedepends")?;
+        push_all::<PackageRelation>(u, &mut lines, "depends")?;
+        push_all::<OptionalDependency>(u, &mut lines, "optdepends")?;
+        push_all::<PackageRelation>(u, &mut lines, "provides")?;
+        push_all::<PackageRelation>(u, &mut lines, "conflicts")?;
+        push_all::<PackageRelation>(u, &mut lines, "replaces")?;
+        push_all::<PackageOption>(u, &mut lines, "options")?;
+        push_all::<Backup>(u, &mut lines, "backup")?;
+
+        // Add at least one package.
+        let packages = u.int_in_range(1..=4)?;
+        for _ in 0..packages {
+            lines.push(String::new());
+            lines.push(format!("pkgname = {}", u.arbitrary::<Name>()?));
+            if u.arbitrary()? {
+                lines.push(format!(
+                    "\tpkgdesc = {}",
+                    u.arbitrary::<PackageDescription>()?
+                ));
+            }
+            push_all::<PackageRelation>(u, &mut lines, "depends")?;
+        }
+
+        Ok(Self(lines.join("\n") + "\n"))
+    }
+}
@@ -166,4 +166,15 @@ pub enum Command {
         #[arg(short, long)]
         pretty: bool,
     },
+
+    /// Output the JSON Schema of the JSON output format
+    ///
+    /// The JSON Schema describes the data emitted by the `format` command when using the JSON
+    /// output format and can be used by external tools to validate it.
+    #[command()]
+    JsonSchema {
+        /// Pretty-print the output
+        #[arg(short, long)]
+        pretty: bool,
+    },
 }
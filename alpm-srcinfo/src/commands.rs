@@ -4,19 +4,21 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use alpm_common::MetadataFile;
-use alpm_srcinfo::{
+use alpm_common::{ErrorCategory, MetadataFile};
+use alpm_types::Architecture;
+use fluent_i18n::t;
+use schemars::generate::SchemaSettings;
+use thiserror::Error;
+
+use crate::{
     SourceInfo,
     SourceInfoSchema,
     SourceInfoV1,
     cli::{PackagesOutputFormat, SourceInfoOutputFormat},
     source_info::v1::merged::MergedPackage,
 };
-use alpm_types::Architecture;
-use fluent_i18n::t;
-use thiserror::Error;
 
-/// A high-level error wrapper around [`alpm_srcinfo::Error`] to add CLI error cases.
+/// A high-level error wrapper around [`crate::Error`] to add CLI error cases.
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum Error {
@@ -28,9 +30,20 @@ pub enum Error {
     #[error("{msg}", msg = t!("error-no-input-file"))]
     NoInputFile,
 
-    /// An [alpm_srcinfo::Error]
+    /// An [crate::Error]
     #[error(transparent)]
-    Srcinfo(#[from] alpm_srcinfo::Error),
+    Srcinfo(#[from] crate::Error),
+}
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::Json(_) => Self::Parse,
+            Error::NoInputFile => Self::Io,
+            Error::Srcinfo(error) => error.into(),
+        }
+    }
 }
 
 /// Take a [PKGBUILD], create [SRCINFO] data from it and print it.
@@ -110,6 +123,29 @@ pub fn format_source_info(
     Ok(())
 }
 
+/// Prints the JSON Schema of the JSON output of [`format_source_info`] on stdout.
+///
+/// The JSON Schema is generated from [`SourceInfoV1`].
+/// If `pretty` is `true`, the JSON Schema is pretty-printed.
+///
+/// # Errors
+///
+/// Returns an error if the JSON Schema cannot be serialized.
+pub fn json_schema(pretty: bool) -> Result<(), Error> {
+    let schema = SchemaSettings::draft2020_12()
+        .for_serialize()
+        .into_generator()
+        .into_root_schema_for::<SourceInfoV1>();
+    let json = if pretty {
+        serde_json::to_string_pretty(&schema)?
+    } else {
+        serde_json::to_string(&schema)?
+    };
+    println!("{json}");
+
+    Ok(())
+}
+
 /// Parses a SRCINFO file from a path or stdin and outputs all info grouped by packages for a given
 /// architecture in the specified format on stdout.
 ///
@@ -1,10 +1,13 @@
 //! All error types that are exposed by this crate.
 use std::{path::PathBuf, string::FromUtf8Error};
 
+use alpm_common::ErrorCategory;
+#[cfg(feature = "pkgbuild")]
 use alpm_pkgbuild::error::Error as PkgbuildError;
 use fluent_i18n::t;
 use thiserror::Error;
 
+#[cfg(feature = "pkgbuild")]
 use crate::pkgbuild_bridge::error::BridgeError;
 #[cfg(doc)]
 use crate::{SourceInfoV1, source_info::parser::SourceInfoContent};
@@ -60,6 +63,21 @@ pub enum Error {
     #[error("{msg}", msg = t!("error-parse", { "error" => .0 }))]
     ParseError(String),
 
+    /// The number of hash digests does not match the number of sources.
+    #[error("{msg}", msg = t!("error-source-checksum-count-mismatch", {
+        "keyword" => keyword,
+        "checksums" => checksums.to_string(),
+        "sources" => sources.to_string()
+    }))]
+    SourceChecksumCountMismatch {
+        /// The keyword of the list of hash digests (e.g. `sha256sums` or `sha256sums_x86_64`).
+        keyword: String,
+        /// The number of hash digests.
+        checksums: usize,
+        /// The number of sources.
+        sources: usize,
+    },
+
     /// Unsupported schema version
     #[error("{msg}", msg = t!("error-unsupported-schema-version", { "version" => .0 }))]
     UnsupportedSchemaVersion(String),
@@ -67,6 +85,7 @@ pub enum Error {
     /// A alpm-pkgbuild bridge error that occurred when converting a PKGBUILD to a [`SourceInfoV1`].
     ///
     /// See [`PkgbuildError`] for further details.
+    #[cfg(feature = "pkgbuild")]
     #[error("{msg}", msg = t!("error-bridge", { "error" => .0.to_string() }))]
     BridgeError(#[from] PkgbuildError),
 
@@ -74,6 +93,26 @@ pub enum Error {
     /// [`SourceInfoV1`] struct.
     ///
     /// See [`BridgeError`] for further details.
+    #[cfg(feature = "pkgbuild")]
     #[error("{msg}", msg = t!("error-bridge-conversion", { "error" => .0.to_string() }))]
     BridgeConversionError(#[from] BridgeError),
 }
+
+impl From<&Error> for ErrorCategory {
+    /// Returns the [`ErrorCategory`] of an [`Error`].
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::AlpmType(error) => error.into(),
+            Error::Io { .. } | Error::IoPath { .. } => Self::Io,
+            Error::InvalidUTF8(_) | Error::ParseError(_) => Self::Parse,
+            Error::MissingKeyword { .. } | Error::SourceChecksumCountMismatch { .. } => {
+                Self::Validation
+            }
+            Error::UnsupportedSchemaVersion(_) => Self::Schema,
+            #[cfg(feature = "pkgbuild")]
+            Error::BridgeError(_) => Self::Parse,
+            #[cfg(feature = "pkgbuild")]
+            Error::BridgeConversionError(_) => Self::Validation,
+        }
+    }
+}
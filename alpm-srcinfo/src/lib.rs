@@ -1,11 +1,20 @@
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
 #[cfg(feature = "cli")]
 #[doc(hidden)]
 pub mod cli;
+
+#[cfg(feature = "cli")]
+#[doc(hidden)]
+pub mod commands;
 pub mod error;
+#[cfg(feature = "pkgbuild")]
 pub mod pkgbuild_bridge;
 pub mod source_info;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub use error::Error;
 pub use source_info::{
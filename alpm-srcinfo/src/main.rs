@@ -2,15 +2,15 @@
 
 use std::process::ExitCode;
 
-use alpm_srcinfo::cli::{Cli, Command};
+use alpm_common::ErrorCategory;
+use alpm_srcinfo::{
+    cli::{Cli, Command},
+    commands::{create, format_packages, format_source_info, json_schema, validate},
+};
 use clap::Parser;
 
-mod commands;
-
 fluent_i18n::i18n!("locales");
 
-use crate::commands::{create, format_packages, format_source_info, validate};
-
 /// The entry point for the `alpm-srcinfo` binary.
 ///
 /// Parses the CLI arguments and calls the respective [`alpm_srcinfo`] library functions.
@@ -36,11 +36,12 @@ fn main() -> ExitCode {
             output_format,
             pretty,
         } => format_packages(file.as_ref(), schema, output_format, architecture, pretty),
+        Command::JsonSchema { pretty } => json_schema(pretty),
     };
 
     if let Err(error) = result {
         eprintln!("{error}");
-        ExitCode::FAILURE
+        ErrorCategory::from(&error).into()
     } else {
         ExitCode::SUCCESS
     }
@@ -17,6 +17,7 @@ use crate::{Error, SourceInfoSchema, SourceInfoV1};
 ///
 /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum SourceInfo {
     /// The [SRCINFO] file format.
     ///
@@ -0,0 +1,389 @@
+//! Validation of the [alpm-architecture] values and architecture specific keywords in SRCINFO
+//! data.
+//!
+//! The `arch` values of a section and the architecture suffixes of keywords (e.g.
+//! `depends_x86_64`) are checked against the [`SystemArchitecture`] variants known to
+//! [`alpm_types`].
+//! Findings are reported as [`ArchitectureWarning`]s via [`SourceInfoV1::architecture_warnings`],
+//! as they do not render SRCINFO data invalid, but likely point at a mistake.
+//!
+//! [alpm-architecture]: https://alpm.archlinux.page/specifications/alpm-architecture.7.html
+
+use std::fmt::Display;
+
+use alpm_types::{Architectures, Name, SystemArchitecture};
+use fluent_i18n::t;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    SourceInfoV1,
+    source_info::v1::{
+        package::{Override, PackageArchitecture},
+        package_base::PackageBaseArchitecture,
+    },
+};
+
+/// A warning about an [alpm-architecture] in SRCINFO data.
+///
+/// [alpm-architecture]: https://alpm.archlinux.page/specifications/alpm-architecture.7.html
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ArchitectureWarning {
+    /// An `arch` value is not a known [`SystemArchitecture`].
+    UnknownArchitecture {
+        /// The name of the package in which the value is set.
+        ///
+        /// Is [`None`] for the `pkgbase` section.
+        package: Option<Name>,
+        /// The unknown architecture.
+        architecture: SystemArchitecture,
+    },
+    /// The architecture suffix of a keyword is neither a known [`SystemArchitecture`], nor listed
+    /// in the `arch` values of its section.
+    UnknownSuffix {
+        /// The name of the package in which the keyword is set.
+        ///
+        /// Is [`None`] for the `pkgbase` section.
+        package: Option<Name>,
+        /// The keyword without its architecture suffix (e.g. `depends`).
+        keyword: String,
+        /// The unknown architecture of the suffix.
+        architecture: SystemArchitecture,
+    },
+    /// The architecture suffix of a keyword is a known [`SystemArchitecture`], which is not listed
+    /// in the `arch` values of its section.
+    UndeclaredSuffix {
+        /// The name of the package in which the keyword is set.
+        ///
+        /// Is [`None`] for the `pkgbase` section.
+        package: Option<Name>,
+        /// The keyword without its architecture suffix (e.g. `depends`).
+        keyword: String,
+        /// The undeclared architecture of the suffix.
+        architecture: SystemArchitecture,
+    },
+}
+
+impl ArchitectureWarning {
+    /// Returns the name of the package in which the warning occurred.
+    ///
+    /// Returns [`None`] for the `pkgbase` section.
+    pub fn package(&self) -> Option<&Name> {
+        match self {
+            Self::UnknownArchitecture { package, .. }
+            | Self::UnknownSuffix { package, .. }
+            | Self::UndeclaredSuffix { package, .. } => package.as_ref(),
+        }
+    }
+
+    /// Returns the architecture that the warning is about.
+    pub fn architecture(&self) -> &SystemArchitecture {
+        match self {
+            Self::UnknownArchitecture { architecture, .. }
+            | Self::UnknownSuffix { architecture, .. }
+            | Self::UndeclaredSuffix { architecture, .. } => architecture,
+        }
+    }
+}
+
+impl Display for ArchitectureWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let section = match self.package() {
+            Some(name) => format!("pkgname = {name}"),
+            None => "pkgbase".to_string(),
+        };
+        match self {
+            Self::UnknownArchitecture { architecture, .. } => write!(
+                f,
+                "{}",
+                t!("architecture-warning-unknown-architecture", {
+                    "architecture" => architecture.to_string(),
+                    "section" => section,
+                })
+            ),
+            Self::UnknownSuffix {
+                keyword,
+                architecture,
+                ..
+            } => write!(
+                f,
+                "{}",
+                t!("architecture-warning-unknown-suffix", {
+                    "keyword" => format!("{keyword}_{architecture}"),
+                    "architecture" => architecture.to_string(),
+                    "section" => section,
+                })
+            ),
+            Self::UndeclaredSuffix {
+                keyword,
+                architecture,
+                ..
+            } => write!(
+                f,
+                "{}",
+                t!("architecture-warning-undeclared-suffix", {
+                    "keyword" => format!("{keyword}_{architecture}"),
+                    "architecture" => architecture.to_string(),
+                    "section" => section,
+                })
+            ),
+        }
+    }
+}
+
+/// Returns the keywords for which architecture specific values are set in `properties`.
+fn package_base_keywords(properties: &PackageBaseArchitecture) -> Vec<&'static str> {
+    [
+        ("depends", properties.dependencies.is_empty()),
+        ("optdepends", properties.optional_dependencies.is_empty()),
+        ("provides", properties.provides.is_empty()),
+        ("conflicts", properties.conflicts.is_empty()),
+        ("replaces", properties.replaces.is_empty()),
+        ("checkdepends", properties.check_dependencies.is_empty()),
+        ("makedepends", properties.make_dependencies.is_empty()),
+        ("source", properties.sources.is_empty()),
+        ("b2sums", properties.b2_checksums.is_empty()),
+        ("md5sums", properties.md5_checksums.is_empty()),
+        ("sha1sums", properties.sha1_checksums.is_empty()),
+        ("sha224sums", properties.sha224_checksums.is_empty()),
+        ("sha256sums", properties.sha256_checksums.is_empty()),
+        ("sha384sums", properties.sha384_checksums.is_empty()),
+        ("sha512sums", properties.sha512_checksums.is_empty()),
+        ("cksums", properties.crc_checksums.is_empty()),
+    ]
+    .into_iter()
+    .filter_map(|(keyword, is_empty)| (!is_empty).then_some(keyword))
+    .collect()
+}
+
+/// Returns the keywords for which architecture specific values are set or cleared in
+/// `properties`.
+fn package_keywords(properties: &PackageArchitecture) -> Vec<&'static str> {
+    [
+        ("depends", matches!(properties.dependencies, Override::No)),
+        (
+            "optdepends",
+            matches!(properties.optional_dependencies, Override::No),
+        ),
+        ("provides", matches!(properties.provides, Override::No)),
+        ("conflicts", matches!(properties.conflicts, Override::No)),
+        ("replaces", matches!(properties.replaces, Override::No)),
+    ]
+    .into_iter()
+    .filter_map(|(keyword, is_unset)| (!is_unset).then_some(keyword))
+    .collect()
+}
+
+/// Checks the `architectures` and the architecture suffixes of `keywords` of a section.
+///
+/// The `keywords` are tuples of the architecture of a suffix and the keywords that use it.
+/// All findings are added to `warnings`.
+fn check_section<'a>(
+    package: Option<&Name>,
+    architectures: &Architectures,
+    keywords: impl Iterator<Item = (&'a SystemArchitecture, Vec<&'static str>)>,
+    warnings: &mut Vec<ArchitectureWarning>,
+) {
+    let declared: &[SystemArchitecture] = match architectures {
+        Architectures::Any => &[],
+        Architectures::Some(architectures) => architectures,
+    };
+
+    for architecture in declared {
+        if matches!(architecture, SystemArchitecture::Unknown(_)) {
+            warnings.push(ArchitectureWarning::UnknownArchitecture {
+                package: package.cloned(),
+                architecture: architecture.clone(),
+            });
+        }
+    }
+
+    for (architecture, keywords) in keywords {
+        if declared.contains(architecture) {
+            continue;
+        }
+        for keyword in keywords {
+            let package = package.cloned();
+            let keyword = keyword.to_string();
+            let architecture = architecture.clone();
+            warnings.push(if matches!(architecture, SystemArchitecture::Unknown(_)) {
+                ArchitectureWarning::UnknownSuffix {
+                    package,
+                    keyword,
+                    architecture,
+                }
+            } else {
+                ArchitectureWarning::UndeclaredSuffix {
+                    package,
+                    keyword,
+                    architecture,
+                }
+            });
+        }
+    }
+}
+
+impl SourceInfoV1 {
+    /// Returns all [`ArchitectureWarning`]s for the `arch` values and architecture specific
+    /// keywords.
+    ///
+    /// Checks the `pkgbase` section and all `pkgname` sections.
+    /// A `pkgname` section is checked against its own `arch` values, or those of the `pkgbase`
+    /// section, if it does not override them.
+    ///
+    /// - An `arch` value that is not a known [`SystemArchitecture`] leads to an
+    ///   [`ArchitectureWarning::UnknownArchitecture`].
+    /// - An architecture suffix that is not listed in the `arch` values of its section leads to an
+    ///   [`ArchitectureWarning::UnknownSuffix`] if it is not a known [`SystemArchitecture`] and to
+    ///   an [`ArchitectureWarning::UndeclaredSuffix`] otherwise.
+    ///
+    /// If a section uses `arch = any`, all architecture suffixes are considered undeclared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_srcinfo::{SourceInfoV1, source_info::v1::architecture::ArchitectureWarning};
+    /// use alpm_types::SystemArchitecture;
+    ///
+    /// # fn main() -> Result<(), alpm_srcinfo::Error> {
+    /// let source_info = SourceInfoV1::from_string(
+    ///     r#"
+    /// pkgbase = example
+    ///     pkgver = 1.0.0
+    ///     pkgrel = 1
+    ///     arch = x86_64
+    ///     depends_x86_64 = glibc
+    ///     depends_aarch64 = glibc
+    ///     depends_armv8 = glibc
+    ///
+    /// pkgname = example
+    /// "#,
+    /// )?;
+    ///
+    /// let warnings = source_info.architecture_warnings();
+    /// assert_eq!(warnings.len(), 2);
+    /// assert!(matches!(
+    ///     &warnings[0],
+    ///     ArchitectureWarning::UndeclaredSuffix {
+    ///         architecture: SystemArchitecture::Aarch64,
+    ///         ..
+    ///     }
+    /// ));
+    /// assert!(matches!(
+    ///     &warnings[1],
+    ///     ArchitectureWarning::UnknownSuffix { .. }
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn architecture_warnings(&self) -> Vec<ArchitectureWarning> {
+        let mut warnings = Vec::new();
+
+        check_section(
+            None,
+            &self.base.architectures,
+            self.base
+                .architecture_properties
+                .iter()
+                .map(|(architecture, properties)| {
+                    (architecture, package_base_keywords(properties))
+                }),
+            &mut warnings,
+        );
+
+        for package in &self.packages {
+            let architectures = match &package.architectures {
+                Some(architectures) => architectures,
+                None => &self.base.architectures,
+            };
+            let mut package_warnings = Vec::new();
+            check_section(
+                Some(&package.name),
+                architectures,
+                package
+                    .architecture_properties
+                    .iter()
+                    .map(|(architecture, properties)| (architecture, package_keywords(properties))),
+                &mut package_warnings,
+            );
+
+            // Unknown architectures inherited from the `pkgbase` section are already reported.
+            if package.architectures.is_none() {
+                package_warnings.retain(|warning| {
+                    !matches!(warning, ArchitectureWarning::UnknownArchitecture { .. })
+                });
+            }
+            warnings.extend(package_warnings);
+        }
+
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that unknown `arch` values are reported only once per section that declares them.
+    #[test]
+    fn unknown_architecture() -> TestResult {
+        let source_info = SourceInfoV1::from_string(
+            r#"
+pkgbase = example
+    pkgver = 1.0.0
+    pkgrel = 1
+    arch = x86_64
+    arch = armv8
+
+pkgname = example
+
+pkgname = example-other
+    arch = x86_64
+    arch = mips
+"#,
+        )?;
+
+        let warnings = source_info.architecture_warnings();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].package(), None);
+        assert_eq!(warnings[0].architecture().to_string(), "armv8");
+        assert_eq!(
+            warnings[1].package().map(ToString::to_string),
+            Some("example-other".to_string())
+        );
+        assert_eq!(warnings[1].architecture().to_string(), "mips");
+        Ok(())
+    }
+
+    /// Ensures that the suffixes of keywords in `pkgname` sections are checked against the
+    /// architectures of the package.
+    #[test]
+    fn package_suffix() -> TestResult {
+        let source_info = SourceInfoV1::from_string(
+            r#"
+pkgbase = example
+    pkgver = 1.0.0
+    pkgrel = 1
+    arch = x86_64
+    arch = aarch64
+
+pkgname = example
+    arch = x86_64
+    depends_x86_64 = glibc
+    depends_aarch64 =
+"#,
+        )?;
+
+        assert_eq!(
+            source_info.architecture_warnings(),
+            vec![ArchitectureWarning::UndeclaredSuffix {
+                package: Some(Name::new("example")?),
+                keyword: "depends".to_string(),
+                architecture: SystemArchitecture::Aarch64,
+            }]
+        );
+        Ok(())
+    }
+}
@@ -154,6 +154,79 @@ pub struct MergedSource {
     pub crc_checksum: Option<SkippableChecksum<Crc32Cksum>>,
 }
 
+impl MergedSource {
+    /// Returns all hash digests of the source.
+    ///
+    /// The hash digests are returned in a fixed order (Blake2, MD-5, SHA-1, SHA-224, SHA-256,
+    /// SHA-384, SHA-512, CRC-32/CKSUM) and only those that are set are included.
+    pub fn checksums(&self) -> Vec<SourceChecksum> {
+        [
+            self.b2_checksum.clone().map(SourceChecksum::B2),
+            self.md5_checksum.clone().map(SourceChecksum::Md5),
+            self.sha1_checksum.clone().map(SourceChecksum::Sha1),
+            self.sha224_checksum.clone().map(SourceChecksum::Sha224),
+            self.sha256_checksum.clone().map(SourceChecksum::Sha256),
+            self.sha384_checksum.clone().map(SourceChecksum::Sha384),
+            self.sha512_checksum.clone().map(SourceChecksum::Sha512),
+            self.crc_checksum.clone().map(SourceChecksum::Crc),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// A typed hash digest of a [`MergedSource`].
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum SourceChecksum {
+    /// A Blake2 hash digest.
+    B2(SkippableChecksum<Blake2b512>),
+    /// An MD-5 hash digest.
+    Md5(SkippableChecksum<Md5>),
+    /// A SHA-1 hash digest.
+    Sha1(SkippableChecksum<Sha1>),
+    /// A SHA-224 hash digest.
+    Sha224(SkippableChecksum<Sha224>),
+    /// A SHA-256 hash digest.
+    Sha256(SkippableChecksum<Sha256>),
+    /// A SHA-384 hash digest.
+    Sha384(SkippableChecksum<Sha384>),
+    /// A SHA-512 hash digest.
+    Sha512(SkippableChecksum<Sha512>),
+    /// A CRC-32/CKSUM hash digest.
+    Crc(SkippableChecksum<Crc32Cksum>),
+}
+
+impl SourceChecksum {
+    /// Returns the SRCINFO keyword of the hash digest (e.g. `sha256sums`).
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Self::B2(_) => "b2sums",
+            Self::Md5(_) => "md5sums",
+            Self::Sha1(_) => "sha1sums",
+            Self::Sha224(_) => "sha224sums",
+            Self::Sha256(_) => "sha256sums",
+            Self::Sha384(_) => "sha384sums",
+            Self::Sha512(_) => "sha512sums",
+            Self::Crc(_) => "cksums",
+        }
+    }
+
+    /// Returns `true` if the verification of the hash digest is skipped (i.e. it is `SKIP`).
+    pub fn is_skipped(&self) -> bool {
+        match self {
+            Self::B2(checksum) => matches!(checksum, SkippableChecksum::Skip),
+            Self::Md5(checksum) => matches!(checksum, SkippableChecksum::Skip),
+            Self::Sha1(checksum) => matches!(checksum, SkippableChecksum::Skip),
+            Self::Sha224(checksum) => matches!(checksum, SkippableChecksum::Skip),
+            Self::Sha256(checksum) => matches!(checksum, SkippableChecksum::Skip),
+            Self::Sha384(checksum) => matches!(checksum, SkippableChecksum::Skip),
+            Self::Sha512(checksum) => matches!(checksum, SkippableChecksum::Skip),
+            Self::Crc(checksum) => matches!(checksum, SkippableChecksum::Skip),
+        }
+    }
+}
+
 /// A convenience iterator to build a list of [`MergedSource`] from the disjoint vectors of sources
 /// and digests.
 ///
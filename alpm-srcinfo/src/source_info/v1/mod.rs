@@ -9,6 +9,7 @@ use std::{
     path::Path,
 };
 
+#[cfg(feature = "pkgbuild")]
 use alpm_pkgbuild::bridge::BridgeOutput;
 use alpm_types::Architecture;
 use fluent_i18n::t;
@@ -16,9 +17,13 @@ use serde::{Deserialize, Serialize};
 use winnow::Parser;
 use writer::{pkgbase_section, pkgname_section};
 
+pub mod architecture;
 pub mod merged;
+pub mod overrides;
 pub mod package;
 pub mod package_base;
+pub mod policy;
+pub mod recovery;
 pub mod writer;
 
 #[cfg(doc)]
@@ -39,6 +44,7 @@ use crate::{
 /// This is the entry point for parsing SRCINFO files. Once created,
 /// [`Self::packages_for_architecture`] can be used to create usable [`MergedPackage`]s.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SourceInfoV1 {
     /// The information of the `pkgbase` section.
     pub base: PackageBase,
@@ -125,6 +131,7 @@ impl SourceInfoV1 {
     /// [alpm-architecture]: https://alpm.archlinux.page/specifications/alpm-architecture.7.html
     /// [alpm-package-name]: https://alpm.archlinux.page/specifications/alpm-package-name.7.html
     /// [alpm-split-package]: https://alpm.archlinux.page/specifications/alpm-split-package.7.html
+    #[cfg(feature = "pkgbuild")]
     pub fn from_pkgbuild(pkgbuild_path: &Path) -> Result<SourceInfoV1, Error> {
         let output = BridgeOutput::from_file(pkgbuild_path)?;
         let source_info: SourceInfoV1 = output.try_into()?;
@@ -249,4 +256,26 @@ impl SourceInfoV1 {
             package_iterator: self.packages.iter(),
         }
     }
+
+    /// Get an iterator over all packages for a specific [`Architecture`], after validating the
+    /// hash digests of all sources.
+    ///
+    /// Works like [`Self::packages_for_architecture`], but first ensures that each list of hash
+    /// digests matches the respective list of sources (see
+    /// [`PackageBase::validate_source_checksums`]).
+    /// This guarantees, that each [`MergedSource`](merged::MergedSource) of the returned
+    /// [`MergedPackage`]s is paired with the correct hash digests.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a list of hash digests has a different length than the respective list
+    /// of sources.
+    pub fn try_packages_for_architecture<A: Into<Architecture>>(
+        &self,
+        architecture: A,
+    ) -> Result<MergedPackagesIterator<'_>, Error> {
+        self.base.validate_source_checksums()?;
+
+        Ok(self.packages_for_architecture(architecture))
+    }
 }
@@ -0,0 +1,310 @@
+//! Inspection of the fields that a `pkgname` section overrides in SRCINFO data.
+//!
+//! A [`MergedPackage`] only provides the result of merging a [`Package`] onto its
+//! [`PackageBase`].
+//! The [`OverriddenField`]s returned by [`Package::overridden_fields`] instead describe which
+//! fields of the `pkgbase` section are overridden by a `pkgname` section, so that e.g. linters can
+//! flag redundant overrides and tooling can display the inheritance of values.
+
+use alpm_types::SystemArchitecture;
+use serde::{Deserialize, Serialize};
+
+#[cfg(doc)]
+use crate::MergedPackage;
+use crate::source_info::v1::{
+    package::{Override, Package},
+    package_base::PackageBase,
+};
+
+/// The way in which a `pkgname` section overrides a field of the `pkgbase` section.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverrideKind {
+    /// The field is cleared (e.g. `depends =`).
+    Cleared,
+    /// The field is set to a package specific value.
+    Set,
+}
+
+/// A field of the `pkgbase` section that is overridden in a `pkgname` section.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct OverriddenField {
+    /// The keyword of the field without an architecture suffix (e.g. `depends`).
+    pub keyword: String,
+    /// The architecture of an architecture specific field (e.g. for `depends_x86_64`).
+    pub architecture: Option<SystemArchitecture>,
+    /// The way in which the field is overridden.
+    pub kind: OverrideKind,
+    /// Whether the override is redundant.
+    ///
+    /// An override is redundant, if it results in the value inherited from the `pkgbase` section
+    /// (e.g. a field is set to the same value or a field without a value is cleared).
+    pub redundant: bool,
+}
+
+impl OverriddenField {
+    /// Creates a new [`OverriddenField`] for `keyword`, if `kind` is [`Some`].
+    fn new(
+        keyword: &str,
+        architecture: Option<&SystemArchitecture>,
+        kind: Option<(OverrideKind, bool)>,
+    ) -> Option<Self> {
+        kind.map(|(kind, redundant)| Self {
+            keyword: keyword.to_string(),
+            architecture: architecture.cloned(),
+            kind,
+            redundant,
+        })
+    }
+}
+
+/// Returns the [`OverrideKind`] of an override of a single value and whether it is redundant.
+///
+/// Returns [`None`] if `value` is [`Override::No`].
+fn option_override<T: PartialEq>(
+    value: &Override<T>,
+    base: Option<&T>,
+) -> Option<(OverrideKind, bool)> {
+    match value {
+        Override::No => None,
+        Override::Clear => Some((OverrideKind::Cleared, base.is_none())),
+        Override::Yes { value } => Some((OverrideKind::Set, base == Some(value))),
+    }
+}
+
+/// Returns the [`OverrideKind`] of an override of a list and whether it is redundant.
+///
+/// Returns [`None`] if `value` is [`Override::No`].
+fn vec_override<T: PartialEq>(
+    value: &Override<Vec<T>>,
+    base: &[T],
+) -> Option<(OverrideKind, bool)> {
+    match value {
+        Override::No => None,
+        Override::Clear => Some((OverrideKind::Cleared, base.is_empty())),
+        Override::Yes { value } => Some((OverrideKind::Set, value.as_slice() == base)),
+    }
+}
+
+impl Package {
+    /// Returns all fields of `base` that are overridden by the [`Package`].
+    ///
+    /// The fields are returned in the order in which they are written to SRCINFO data, followed
+    /// by all architecture specific fields, ordered by architecture.
+    /// Fields that are not overridden are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_srcinfo::{
+    ///     SourceInfoV1,
+    ///     source_info::v1::overrides::{OverriddenField, OverrideKind},
+    /// };
+    ///
+    /// # fn main() -> testresult::TestResult {
+    /// let source_info = SourceInfoV1::from_string(
+    ///     r#"
+    /// pkgbase = example
+    ///     pkgver = 1.0.0
+    ///     pkgrel = 1
+    ///     arch = x86_64
+    ///     depends = glibc
+    ///
+    /// pkgname = example
+    ///     depends = glibc
+    /// "#,
+    /// )?;
+    ///
+    /// assert_eq!(
+    ///     source_info.packages[0].overridden_fields(&source_info.base),
+    ///     vec![OverriddenField {
+    ///         keyword: "depends".to_string(),
+    ///         architecture: None,
+    ///         kind: OverrideKind::Set,
+    ///         redundant: true,
+    ///     }]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn overridden_fields(&self, base: &PackageBase) -> Vec<OverriddenField> {
+        let architectures = self
+            .architectures
+            .as_ref()
+            .map(|architectures| (OverrideKind::Set, *architectures == base.architectures));
+
+        let mut fields: Vec<OverriddenField> = [
+            (
+                "pkgdesc",
+                option_override(&self.description, base.description.as_ref()),
+            ),
+            ("url", option_override(&self.url, base.url.as_ref())),
+            ("license", vec_override(&self.licenses, &base.licenses)),
+            (
+                "install",
+                option_override(&self.install, base.install.as_ref()),
+            ),
+            (
+                "changelog",
+                option_override(&self.changelog, base.changelog.as_ref()),
+            ),
+            ("groups", vec_override(&self.groups, &base.groups)),
+            ("arch", architectures),
+            ("backup", vec_override(&self.backups, &base.backups)),
+            ("options", vec_override(&self.options, &base.options)),
+            (
+                "depends",
+                vec_override(&self.dependencies, &base.dependencies),
+            ),
+            (
+                "optdepends",
+                vec_override(&self.optional_dependencies, &base.optional_dependencies),
+            ),
+            ("provides", vec_override(&self.provides, &base.provides)),
+            ("conflicts", vec_override(&self.conflicts, &base.conflicts)),
+            ("replaces", vec_override(&self.replaces, &base.replaces)),
+        ]
+        .into_iter()
+        .filter_map(|(keyword, kind)| OverriddenField::new(keyword, None, kind))
+        .collect();
+
+        for (architecture, properties) in &self.architecture_properties {
+            let base_properties = base.architecture_properties.get(architecture);
+            let base_dependencies =
+                base_properties.map_or(&[][..], |base| base.dependencies.as_slice());
+            let base_optional_dependencies =
+                base_properties.map_or(&[][..], |base| base.optional_dependencies.as_slice());
+            let base_provides = base_properties.map_or(&[][..], |base| base.provides.as_slice());
+            let base_conflicts = base_properties.map_or(&[][..], |base| base.conflicts.as_slice());
+            let base_replaces = base_properties.map_or(&[][..], |base| base.replaces.as_slice());
+
+            fields.extend(
+                [
+                    (
+                        "depends",
+                        vec_override(&properties.dependencies, base_dependencies),
+                    ),
+                    (
+                        "optdepends",
+                        vec_override(
+                            &properties.optional_dependencies,
+                            base_optional_dependencies,
+                        ),
+                    ),
+                    (
+                        "provides",
+                        vec_override(&properties.provides, base_provides),
+                    ),
+                    (
+                        "conflicts",
+                        vec_override(&properties.conflicts, base_conflicts),
+                    ),
+                    (
+                        "replaces",
+                        vec_override(&properties.replaces, base_replaces),
+                    ),
+                ]
+                .into_iter()
+                .filter_map(|(keyword, kind)| {
+                    OverriddenField::new(keyword, Some(architecture), kind)
+                }),
+            );
+        }
+
+        fields
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testresult::TestResult;
+
+    use super::*;
+    use crate::SourceInfoV1;
+
+    /// Ensures that set and cleared fields are reported, including architecture specific ones.
+    #[test]
+    fn overridden_fields() -> TestResult {
+        let source_info = SourceInfoV1::from_string(
+            r#"
+pkgbase = example
+    pkgver = 1.0.0
+    pkgrel = 1
+    pkgdesc = An example
+    arch = x86_64
+    arch = aarch64
+    license = MIT
+    depends_x86_64 = glibc
+
+pkgname = example
+    pkgdesc = Another example
+    arch = x86_64
+    arch = aarch64
+    groups =
+    depends_x86_64 = glibc
+    provides_aarch64 = example-aarch64
+"#,
+        )?;
+
+        let fields = source_info.packages[0].overridden_fields(&source_info.base);
+        assert_eq!(
+            fields,
+            vec![
+                OverriddenField {
+                    keyword: "pkgdesc".to_string(),
+                    architecture: None,
+                    kind: OverrideKind::Set,
+                    redundant: false,
+                },
+                OverriddenField {
+                    keyword: "groups".to_string(),
+                    architecture: None,
+                    kind: OverrideKind::Cleared,
+                    redundant: true,
+                },
+                OverriddenField {
+                    keyword: "arch".to_string(),
+                    architecture: None,
+                    kind: OverrideKind::Set,
+                    redundant: true,
+                },
+                OverriddenField {
+                    keyword: "provides".to_string(),
+                    architecture: Some(SystemArchitecture::Aarch64),
+                    kind: OverrideKind::Set,
+                    redundant: false,
+                },
+                OverriddenField {
+                    keyword: "depends".to_string(),
+                    architecture: Some(SystemArchitecture::X86_64),
+                    kind: OverrideKind::Set,
+                    redundant: true,
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    /// Ensures that a package without overrides reports no fields.
+    #[test]
+    fn no_overridden_fields() -> TestResult {
+        let source_info = SourceInfoV1::from_string(
+            r#"
+pkgbase = example
+    pkgver = 1.0.0
+    pkgrel = 1
+    arch = any
+    license = MIT
+
+pkgname = example
+"#,
+        )?;
+
+        assert!(
+            source_info.packages[0]
+                .overridden_fields(&source_info.base)
+                .is_empty()
+        );
+        Ok(())
+    }
+}
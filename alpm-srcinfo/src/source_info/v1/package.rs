@@ -39,6 +39,7 @@ use crate::{MergedPackage, SourceInfoV1, source_info::v1::package_base::PackageB
 /// `Option<Option<T>>`, as serialization would flatten the structure. This type enables
 /// representation of this type of data.
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "override")]
 pub enum Override<T> {
     /// The property is not overridden.
@@ -126,6 +127,7 @@ impl<T> Override<Vec<T>> {
 /// Take a look at [SourceInfoV1::packages_for_architecture] on how to get the merged representation
 /// [MergedPackage] of a package.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Package {
     /// The alpm-package-name of the package.
     pub name: Name,
@@ -180,6 +182,7 @@ impl From<Name> for Package {
 /// For each [`Architecture`] defined in [`Package::architectures`] a [`PackageArchitecture`] is
 /// present in [`Package::architecture_properties`].
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PackageArchitecture {
     /// The (potentially overridden) list of run-time dependencies of the package.
     pub dependencies: Override<Vec<RelationOrSoname>>,
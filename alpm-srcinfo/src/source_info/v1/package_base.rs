@@ -41,6 +41,7 @@ use crate::{MergedPackage, SourceInfoV1, source_info::v1::package::Package};
 /// A [`MergedPackage`] (a full view on a package's metadata) can be created using
 /// [`SourceInfoV1::packages_for_architecture`].
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PackageBase {
     /// The alpm-package-name of the package base.
     pub name: Name,
@@ -120,6 +121,7 @@ pub struct PackageBase {
 /// For each [`Architecture`] defined in [`PackageBase::architectures`] a
 /// [`PackageBaseArchitecture`] is present in [`PackageBase::architecture_properties`].
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PackageBaseArchitecture {
     /// The list of run-time dependencies of the package base.
     pub dependencies: Vec<RelationOrSoname>,
@@ -160,6 +162,34 @@ pub struct PackageBaseArchitecture {
 }
 
 impl PackageBaseArchitecture {
+    /// Validates that all lists of hash digests match the number of sources for `architecture`.
+    ///
+    /// See [`PackageBase::validate_source_checksums`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a list of hash digests has a different length than the list of
+    /// sources.
+    pub fn validate_source_checksums(
+        &self,
+        architecture: &SystemArchitecture,
+    ) -> Result<(), Error> {
+        validate_checksum_counts(
+            Some(architecture),
+            self.sources.len(),
+            [
+                ("b2sums", self.b2_checksums.len()),
+                ("md5sums", self.md5_checksums.len()),
+                ("sha1sums", self.sha1_checksums.len()),
+                ("sha224sums", self.sha224_checksums.len()),
+                ("sha256sums", self.sha256_checksums.len()),
+                ("sha384sums", self.sha384_checksums.len()),
+                ("sha512sums", self.sha512_checksums.len()),
+                ("cksums", self.crc_checksums.len()),
+            ],
+        )
+    }
+
     /// Merges in the architecture specific properties of a package.
     ///
     /// Each existing field of `properties` overrides the architecture-independent pendant on
@@ -509,4 +539,64 @@ impl PackageBase {
             crc_checksums,
         })
     }
+
+    /// Validates that all lists of hash digests match the number of sources.
+    ///
+    /// By convention, the n-th entry of each list of hash digests (e.g. `sha256sums`) belongs to
+    /// the n-th entry of `source`.
+    /// Lists of hash digests that are not used (i.e. that are empty) are ignored.
+    /// This check is applied to the architecture-independent data, as well as to each set of
+    /// [`PackageBaseArchitecture`] properties.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a list of hash digests has a different length than the respective list
+    /// of sources.
+    pub fn validate_source_checksums(&self) -> Result<(), Error> {
+        validate_checksum_counts(
+            None,
+            self.sources.len(),
+            [
+                ("b2sums", self.b2_checksums.len()),
+                ("md5sums", self.md5_checksums.len()),
+                ("sha1sums", self.sha1_checksums.len()),
+                ("sha224sums", self.sha224_checksums.len()),
+                ("sha256sums", self.sha256_checksums.len()),
+                ("sha384sums", self.sha384_checksums.len()),
+                ("sha512sums", self.sha512_checksums.len()),
+                ("cksums", self.crc_checksums.len()),
+            ],
+        )?;
+
+        for (architecture, properties) in &self.architecture_properties {
+            properties.validate_source_checksums(architecture)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Ensures that each list of hash digests in `checksums` is either empty or has `sources` entries.
+///
+/// Each entry of `checksums` is a tuple of the keyword and the length of a list of hash digests.
+/// If `architecture` is provided, it is appended as suffix to the keyword in the error.
+fn validate_checksum_counts(
+    architecture: Option<&SystemArchitecture>,
+    sources: usize,
+    checksums: [(&str, usize); 8],
+) -> Result<(), Error> {
+    for (keyword, count) in checksums {
+        if count != 0 && count != sources {
+            return Err(Error::SourceChecksumCountMismatch {
+                keyword: match architecture {
+                    Some(architecture) => format!("{keyword}_{architecture}"),
+                    None => keyword.to_string(),
+                },
+                checksums: count,
+                sources,
+            });
+        }
+    }
+
+    Ok(())
 }
@@ -0,0 +1,439 @@
+//! Policy checks and rewriting of the sources in SRCINFO data.
+//!
+//! A [`SourcePolicy`] describes which [alpm-package-source] locations are acceptable (e.g. only
+//! HTTPS, no FTP) and which [`SourceRewriter`] hooks should be applied to them.
+//! Applying a policy to a [`SourceInfoV1`] via [`SourceInfoV1::apply_source_policy`] returns a
+//! modified copy of the data and a [`SourcePolicyReport`] that lists all changes and all remaining
+//! violations.
+//!
+//! [alpm-package-source]: https://alpm.archlinux.page/specifications/alpm-package-source.7.html
+
+use std::{fmt::Display, str::FromStr};
+
+use alpm_types::{Source, SourceUrl, SystemArchitecture, Url};
+use fluent_i18n::t;
+use serde::{Deserialize, Serialize};
+
+use crate::SourceInfoV1;
+
+/// A hook that may rewrite the [`SourceUrl`] of a [`Source`].
+///
+/// Rewriters are applied in the order in which they are added to a [`SourcePolicy`].
+/// Each rewriter is passed the output of the previous one.
+pub trait SourceRewriter {
+    /// Returns a short, human readable name of the rewriter.
+    ///
+    /// The name is used to attribute changes in a [`SourcePolicyReport`].
+    fn name(&self) -> &str;
+
+    /// Returns a replacement for `source_url` or [`None`] if it should be left untouched.
+    fn rewrite(&self, source_url: &SourceUrl) -> Option<SourceUrl>;
+}
+
+/// Rewrites plain `http://` source URLs to `https://`.
+///
+/// VCS information (e.g. `git+http://`) is retained.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HttpsUpgradeRewriter;
+
+impl SourceRewriter for HttpsUpgradeRewriter {
+    fn name(&self) -> &str {
+        "https-upgrade"
+    }
+
+    fn rewrite(&self, source_url: &SourceUrl) -> Option<SourceUrl> {
+        let url = source_url.url.as_str();
+        let rest = url.strip_prefix("http://")?;
+        let url = Url::from_str(&format!("https://{rest}")).ok()?;
+
+        Some(SourceUrl {
+            url,
+            vcs_info: source_url.vcs_info.clone(),
+        })
+    }
+}
+
+/// Rewrites GitHub auto-generated archive URLs to release asset URLs.
+///
+/// Archives generated by GitHub on the fly (e.g.
+/// `https://github.com/owner/project/archive/refs/tags/v1.0.0.tar.gz`) are not guaranteed to be
+/// stable over time.
+/// This rewriter replaces them with the URL of an uploaded release asset (e.g.
+/// `https://github.com/owner/project/releases/download/v1.0.0/project-v1.0.0.tar.gz`).
+///
+/// The file name of the release asset is created from a template, in which the placeholders
+/// `{owner}`, `{repo}`, `{tag}` and `{ext}` are replaced.
+/// The default template is `{repo}-{tag}.{ext}`.
+#[derive(Clone, Debug)]
+pub struct GitHubArchiveRewriter {
+    asset_template: String,
+}
+
+impl GitHubArchiveRewriter {
+    /// Creates a new [`GitHubArchiveRewriter`] using a custom release asset file name template.
+    pub fn new(asset_template: impl Into<String>) -> Self {
+        Self {
+            asset_template: asset_template.into(),
+        }
+    }
+}
+
+impl Default for GitHubArchiveRewriter {
+    fn default() -> Self {
+        Self::new("{repo}-{tag}.{ext}")
+    }
+}
+
+impl SourceRewriter for GitHubArchiveRewriter {
+    fn name(&self) -> &str {
+        "github-archive"
+    }
+
+    fn rewrite(&self, source_url: &SourceUrl) -> Option<SourceUrl> {
+        // VCS sources are never archives.
+        if source_url.vcs_info.is_some() {
+            return None;
+        }
+
+        let url = source_url.url.inner();
+        if url.host_str() != Some("github.com") {
+            return None;
+        }
+
+        // Supported layouts:
+        // - `/{owner}/{repo}/archive/refs/tags/{tag}.{ext}`
+        // - `/{owner}/{repo}/archive/{tag}.{ext}`
+        let segments: Vec<&str> = url.path_segments()?.collect();
+        let (owner, repo, file) = match segments.as_slice() {
+            [owner, repo, "archive", "refs", "tags", file] => (*owner, *repo, *file),
+            [owner, repo, "archive", file] => (*owner, *repo, *file),
+            _ => return None,
+        };
+
+        let (tag, ext) = ["tar.gz", "tar.bz2", "tar.xz", "zip"]
+            .iter()
+            .find_map(|ext| file.strip_suffix(&format!(".{ext}")).map(|tag| (tag, *ext)))?;
+        if tag.is_empty() {
+            return None;
+        }
+
+        let asset = self
+            .asset_template
+            .replace("{owner}", owner)
+            .replace("{repo}", repo)
+            .replace("{tag}", tag)
+            .replace("{ext}", ext);
+        let url = Url::from_str(&format!(
+            "https://github.com/{owner}/{repo}/releases/download/{tag}/{asset}"
+        ))
+        .ok()?;
+
+        Some(SourceUrl {
+            url,
+            vcs_info: None,
+        })
+    }
+}
+
+/// A policy that is applied to all sources of SRCINFO data.
+///
+/// Rewriters are applied first, after which all checks are run on the resulting sources.
+///
+/// # Examples
+///
+/// ```
+/// use alpm_srcinfo::source_info::v1::policy::{GitHubArchiveRewriter, SourcePolicy};
+///
+/// let policy = SourcePolicy::new()
+///     .require_https(true)
+///     .forbid_scheme("ftp")
+///     .with_rewriter(GitHubArchiveRewriter::default());
+/// ```
+#[derive(Default)]
+pub struct SourcePolicy {
+    require_https: bool,
+    forbidden_schemes: Vec<String>,
+    rewriters: Vec<Box<dyn SourceRewriter>>,
+}
+
+impl std::fmt::Debug for SourcePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SourcePolicy")
+            .field("require_https", &self.require_https)
+            .field("forbidden_schemes", &self.forbidden_schemes)
+            .field(
+                "rewriters",
+                &self.rewriters.iter().map(|r| r.name()).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl SourcePolicy {
+    /// Creates a new, permissive [`SourcePolicy`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether `http://` URLs are considered a violation.
+    pub fn require_https(mut self, require_https: bool) -> Self {
+        self.require_https = require_https;
+        self
+    }
+
+    /// Adds a URL scheme (e.g. `ftp`) that is considered a violation.
+    ///
+    /// Schemes are compared case-insensitively.
+    pub fn forbid_scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.forbidden_schemes
+            .push(scheme.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Adds a [`SourceRewriter`] hook.
+    pub fn with_rewriter(mut self, rewriter: impl SourceRewriter + 'static) -> Self {
+        self.rewriters.push(Box::new(rewriter));
+        self
+    }
+
+    /// Applies all rewriters to `source_url` and returns the final URL and the names of all
+    /// rewriters that changed it.
+    fn rewrite(&self, source_url: &SourceUrl) -> (SourceUrl, Vec<String>) {
+        let mut current = source_url.clone();
+        let mut applied = Vec::new();
+
+        for rewriter in &self.rewriters {
+            if let Some(rewritten) = rewriter.rewrite(&current)
+                && rewritten != current
+            {
+                applied.push(rewriter.name().to_string());
+                current = rewritten;
+            }
+        }
+
+        (current, applied)
+    }
+
+    /// Checks `source_url` and returns all violations of the policy.
+    fn check(&self, source_url: &SourceUrl) -> Vec<SourcePolicyViolation> {
+        let scheme = source_url.url.inner().scheme().to_ascii_lowercase();
+        let mut violations = Vec::new();
+
+        if self.require_https && scheme == "http" {
+            violations.push(SourcePolicyViolation::InsecureScheme {
+                scheme: scheme.clone(),
+            });
+        }
+        if self.forbidden_schemes.contains(&scheme) {
+            violations.push(SourcePolicyViolation::ForbiddenScheme { scheme });
+        }
+
+        violations
+    }
+
+    /// Applies the policy to a list of `sources` and records all findings in `report`.
+    fn apply_to(
+        &self,
+        sources: &mut [Source],
+        architecture: Option<SystemArchitecture>,
+        report: &mut SourcePolicyReport,
+    ) {
+        for (index, source) in sources.iter_mut().enumerate() {
+            let Source::SourceUrl {
+                filename,
+                source_url,
+            } = source
+            else {
+                continue;
+            };
+            let location = SourceLocation {
+                architecture: architecture.clone(),
+                index,
+            };
+
+            let (rewritten, rewriters) = self.rewrite(source_url);
+            if !rewriters.is_empty() {
+                let original = Source::SourceUrl {
+                    filename: filename.clone(),
+                    source_url: source_url.clone(),
+                };
+                *source_url = rewritten;
+                report.changes.push(SourceChange {
+                    location: location.clone(),
+                    original,
+                    rewritten: source.clone(),
+                    rewriters,
+                });
+            }
+
+            let Source::SourceUrl { source_url, .. } = source else {
+                continue;
+            };
+            for violation in self.check(source_url) {
+                report.violations.push(SourceViolation {
+                    location: location.clone(),
+                    source: source.clone(),
+                    violation,
+                });
+            }
+        }
+    }
+}
+
+/// The location of a [`Source`] in SRCINFO data.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SourceLocation {
+    /// The architecture for architecture specific sources (e.g. `source_x86_64`).
+    ///
+    /// Is [`None`] for architecture-independent sources.
+    pub architecture: Option<SystemArchitecture>,
+    /// The zero-based index of the source in its list.
+    pub index: usize,
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.architecture {
+            Some(architecture) => write!(f, "source_{architecture}[{}]", self.index),
+            None => write!(f, "source[{}]", self.index),
+        }
+    }
+}
+
+/// A violation of a [`SourcePolicy`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SourcePolicyViolation {
+    /// The source uses an insecure scheme, while HTTPS is required.
+    InsecureScheme {
+        /// The scheme of the source URL.
+        scheme: String,
+    },
+    /// The source uses a forbidden scheme.
+    ForbiddenScheme {
+        /// The scheme of the source URL.
+        scheme: String,
+    },
+}
+
+impl Display for SourcePolicyViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InsecureScheme { scheme } => write!(
+                f,
+                "{}",
+                t!("source-policy-insecure-scheme", { "scheme" => scheme })
+            ),
+            Self::ForbiddenScheme { scheme } => write!(
+                f,
+                "{}",
+                t!("source-policy-forbidden-scheme", { "scheme" => scheme })
+            ),
+        }
+    }
+}
+
+/// A [`Source`] that has been changed by one or more [`SourceRewriter`]s.
+///
+/// # Note
+///
+/// Checksums of rewritten sources are not changed and have to be updated separately.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SourceChange {
+    /// The location of the source.
+    pub location: SourceLocation,
+    /// The source before rewriting.
+    pub original: Source,
+    /// The source after rewriting.
+    pub rewritten: Source,
+    /// The names of all rewriters that changed the source, in the order of application.
+    pub rewriters: Vec<String>,
+}
+
+/// A [`Source`] that violates a [`SourcePolicy`] after all rewriters have been applied.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SourceViolation {
+    /// The location of the source.
+    pub location: SourceLocation,
+    /// The offending source.
+    pub source: Source,
+    /// The violated rule.
+    pub violation: SourcePolicyViolation,
+}
+
+/// The report created when applying a [`SourcePolicy`] to SRCINFO data.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SourcePolicyReport {
+    /// All changes made by rewriters.
+    pub changes: Vec<SourceChange>,
+    /// All violations that remain after rewriting.
+    pub violations: Vec<SourceViolation>,
+}
+
+impl SourcePolicyReport {
+    /// Returns `true` if no violations remain.
+    pub fn is_compliant(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl SourceInfoV1 {
+    /// Applies a [`SourcePolicy`] to all sources and returns the rewritten [`SourceInfoV1`]
+    /// together with a [`SourcePolicyReport`].
+    ///
+    /// Architecture-independent as well as architecture specific sources are considered.
+    /// Local file sources are never rewritten or reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_srcinfo::{
+    ///     SourceInfoV1,
+    ///     source_info::v1::policy::{HttpsUpgradeRewriter, SourcePolicy},
+    /// };
+    ///
+    /// # fn main() -> Result<(), alpm_srcinfo::Error> {
+    /// let source_info = SourceInfoV1::from_string(
+    ///     r#"
+    /// pkgbase = example
+    ///     pkgver = 1.0.0
+    ///     pkgrel = 1
+    ///     arch = x86_64
+    ///     source = http://example.org/example-1.0.0.tar.gz
+    ///     source = ftp://example.org/example-1.0.0.tar.gz.sig
+    ///
+    /// pkgname = example
+    /// "#,
+    /// )?;
+    ///
+    /// let policy = SourcePolicy::new()
+    ///     .require_https(true)
+    ///     .forbid_scheme("ftp")
+    ///     .with_rewriter(HttpsUpgradeRewriter);
+    /// let (source_info, report) = source_info.apply_source_policy(&policy);
+    ///
+    /// assert_eq!(
+    ///     source_info.base.sources[0].to_string(),
+    ///     "https://example.org/example-1.0.0.tar.gz"
+    /// );
+    /// assert_eq!(report.changes.len(), 1);
+    /// assert_eq!(report.violations.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn apply_source_policy(&self, policy: &SourcePolicy) -> (SourceInfoV1, SourcePolicyReport) {
+        let mut source_info = self.clone();
+        let mut report = SourcePolicyReport::default();
+
+        policy.apply_to(&mut source_info.base.sources, None, &mut report);
+        for (architecture, properties) in source_info.base.architecture_properties.iter_mut() {
+            policy.apply_to(
+                &mut properties.sources,
+                Some(architecture.clone()),
+                &mut report,
+            );
+        }
+
+        (source_info, report)
+    }
+}
@@ -0,0 +1,236 @@
+//! Tolerant parsing of SRCINFO data.
+//!
+//! Unlike [`SourceInfoV1::from_string`], which stops at the first error, the functionality in this
+//! module skips malformed lines and sections and collects all errors encountered on the way.
+//! This is useful for tools that need to report as many problems as possible at once (e.g. editors
+//! or linters).
+
+use winnow::Parser;
+
+use crate::{Error, SourceInfoV1, source_info::parser::SourceInfoContent};
+
+/// An [`Error`] encountered while parsing SRCINFO data, with the line it relates to.
+#[derive(Debug)]
+pub struct SourceInfoError {
+    /// The line number (starting at 1) at which the error occurred.
+    ///
+    /// Is [`None`] if the error does not relate to a specific line (e.g. if a required keyword is
+    /// missing).
+    pub line: Option<usize>,
+    /// The error.
+    pub error: Error,
+}
+
+/// The result of parsing SRCINFO data using [`SourceInfoV1::from_string_tolerant`].
+#[derive(Debug)]
+pub struct RecoveredSourceInfo {
+    /// The [`SourceInfoV1`] created from all lines that could be parsed.
+    ///
+    /// Is [`None`] if no [`SourceInfoV1`] can be created even after skipping malformed lines (e.g.
+    /// if the `pkgbase` section header is malformed or a required keyword is missing).
+    pub source_info: Option<SourceInfoV1>,
+    /// All errors, in the order in which they have been encountered.
+    pub errors: Vec<SourceInfoError>,
+}
+
+impl RecoveredSourceInfo {
+    /// Returns `true` if no errors have been encountered.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl SourceInfoV1 {
+    /// Parses a SRCINFO file's content into a [`SourceInfoV1`] struct, while recovering from
+    /// errors.
+    ///
+    /// Each time the parser encounters an error, the error is recorded together with its line
+    /// number and parsing is restarted with the offending data skipped:
+    ///
+    /// - A malformed line in a section is skipped.
+    /// - A malformed `pkgname` section header leads to the entire `pkgname` section being skipped.
+    /// - A malformed `pkgbase` section header cannot be recovered from, as all other data depends
+    ///   on it.
+    ///
+    /// If parsing eventually succeeds, the parsed data is converted into a [`SourceInfoV1`] (see
+    /// [`SourceInfoV1::from_raw`]).
+    /// Errors during this conversion are recorded without line number.
+    ///
+    /// ```rust
+    /// use alpm_srcinfo::SourceInfoV1;
+    ///
+    /// let source_info_data = r#"
+    /// pkgbase = example
+    ///     pkgver = 1.0.0
+    ///     pkgrel = 1
+    ///     arch = x86_64
+    ///     depends glibc
+    ///
+    /// pkgname = example
+    ///     unknown = foo
+    /// "#;
+    ///
+    /// let recovered = SourceInfoV1::from_string_tolerant(source_info_data);
+    /// assert!(recovered.source_info.is_some());
+    /// let lines: Vec<_> = recovered.errors.iter().map(|error| error.line).collect();
+    /// assert_eq!(lines, [Some(6), Some(9)]);
+    /// ```
+    pub fn from_string_tolerant(content: &str) -> RecoveredSourceInfo {
+        // A temporary fix for <https://github.com/winnow-rs/winnow/issues/847>
+        let content_no_tabs = content.replace('\t', " ");
+        let mut lines: Vec<&str> = content_no_tabs.lines().collect();
+        let mut errors = Vec::new();
+
+        // Each iteration either succeeds, gives up or blanks at least one non-empty line, so this
+        // terminates.
+        let source_info = loop {
+            let input = lines.join("\n") + "\n";
+            let err = match SourceInfoContent::parser.parse(input.as_str()) {
+                Ok(parsed) => match SourceInfoV1::from_raw(parsed) {
+                    Ok(source_info) => break Some(source_info),
+                    Err(error) => {
+                        errors.push(SourceInfoError { line: None, error });
+                        break None;
+                    }
+                },
+                Err(err) => err,
+            };
+
+            let index = input[..err.offset()].matches('\n').count();
+            errors.push(SourceInfoError {
+                line: Some(index + 1),
+                error: Error::ParseError(format!("{err}")),
+            });
+
+            let Some(line) = lines.get(index).copied().map(str::trim_start) else {
+                break None;
+            };
+            if line.is_empty() || line.starts_with("pkgbase") {
+                break None;
+            }
+
+            // Skip an entire `pkgname` section if its header is malformed, so that its properties
+            // are not attributed to the preceding section.
+            let end = if line.starts_with("pkgname") {
+                lines[index + 1..]
+                    .iter()
+                    .position(|line| line.trim_start().starts_with("pkgname"))
+                    .map_or(lines.len(), |position| index + 1 + position)
+            } else {
+                index + 1
+            };
+            for line in &mut lines[index..end] {
+                *line = "";
+            }
+        };
+
+        RecoveredSourceInfo {
+            source_info,
+            errors,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Ensures that errors in multiple lines and sections are collected.
+    #[rstest]
+    #[case::valid(
+        r#"pkgbase = example
+    pkgver = 1.0.0
+    pkgrel = 1
+    arch = x86_64
+
+pkgname = example
+"#,
+        true,
+        &[],
+        &["example"]
+    )]
+    #[case::invalid_lines(
+        r#"pkgbase = example
+    pkgver = 1.0.0
+    pkgrel = 1
+    arch = x86_64
+    depends glibc
+    makedepends = cmake
+
+pkgname = example
+    unknown = foo
+    depends = glibc
+"#,
+        true,
+        &[Some(5), Some(9)],
+        &["example"]
+    )]
+    #[case::invalid_pkgname_section(
+        r#"pkgbase = example
+    pkgver = 1.0.0
+    pkgrel = 1
+    arch = x86_64
+
+pkgname = exa mple
+    depends = glibc
+
+pkgname = example-other
+"#,
+        true,
+        &[Some(6)],
+        &["example-other"]
+    )]
+    #[case::invalid_pkgbase_header(
+        r#"pkgbase = exa mple
+    pkgver = 1.0.0
+
+pkgname = example
+"#,
+        false,
+        &[Some(1)],
+        &[]
+    )]
+    #[case::missing_keyword(
+        r#"pkgbase = example
+    pkgrel = 1
+    arch = x86_64
+    pkgver 1.0.0
+
+pkgname = example
+"#,
+        false,
+        &[Some(4), None],
+        &[]
+    )]
+    fn from_string_tolerant(
+        #[case] input: &str,
+        #[case] has_source_info: bool,
+        #[case] lines: &[Option<usize>],
+        #[case] packages: &[&str],
+    ) {
+        let recovered = SourceInfoV1::from_string_tolerant(input);
+
+        assert_eq!(recovered.source_info.is_some(), has_source_info);
+        assert_eq!(recovered.is_valid(), lines.is_empty());
+        assert_eq!(
+            recovered
+                .errors
+                .iter()
+                .map(|error| error.line)
+                .collect::<Vec<_>>(),
+            lines
+        );
+        if let Some(source_info) = recovered.source_info {
+            assert_eq!(
+                source_info
+                    .packages
+                    .iter()
+                    .map(|package| package.name.to_string())
+                    .collect::<Vec<_>>(),
+                packages
+            );
+        }
+    }
+}
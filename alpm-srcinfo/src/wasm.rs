@@ -0,0 +1,85 @@
+//! Entry points for using the [SRCINFO] parser from JavaScript.
+//!
+//! The functions in this module are exported using [`wasm_bindgen`], so that the parser can be
+//! used when compiling for `wasm32-unknown-unknown` (e.g. in a browser).
+//! All data is exchanged as strings, while structured output is provided as JSON.
+//!
+//! [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+
+use std::str::FromStr;
+
+use alpm_types::Architecture;
+use wasm_bindgen::{JsError, prelude::wasm_bindgen};
+
+use crate::{SourceInfo, source_info::v1::merged::MergedPackage};
+
+/// Serializes `value` as (optionally `pretty`) JSON.
+fn to_json(value: &impl serde::Serialize, pretty: bool) -> Result<String, JsError> {
+    let json = if pretty {
+        serde_json::to_string_pretty(value)?
+    } else {
+        serde_json::to_string(value)?
+    };
+
+    Ok(json)
+}
+
+/// Validates [SRCINFO] data provided as string `input`.
+///
+/// # Errors
+///
+/// Returns an error if `input` is not valid [SRCINFO] data.
+///
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+#[wasm_bindgen(js_name = validateSrcinfo)]
+pub fn validate_srcinfo(input: &str) -> Result<(), JsError> {
+    SourceInfo::from_str(input)?;
+
+    Ok(())
+}
+
+/// Parses [SRCINFO] data provided as string `input` and returns it as JSON.
+///
+/// If `pretty` is `true`, the JSON output is pretty-printed.
+///
+/// # Errors
+///
+/// Returns an error if `input` is not valid [SRCINFO] data or if it cannot be serialized as JSON.
+///
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+#[wasm_bindgen(js_name = formatSrcinfo)]
+pub fn format_srcinfo(input: &str, pretty: bool) -> Result<String, JsError> {
+    to_json(&SourceInfo::from_str(input)?, pretty)
+}
+
+/// Parses [SRCINFO] data provided as string `input` and returns all packages for `architecture`
+/// as JSON.
+///
+/// Each package is merged with the package base and the architecture specific properties, which
+/// represents the package as it would be built for `architecture`.
+/// If `pretty` is `true`, the JSON output is pretty-printed.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - `architecture` is not a valid [alpm-architecture],
+/// - `input` is not valid [SRCINFO] data,
+/// - or the packages cannot be serialized as JSON.
+///
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+/// [alpm-architecture]: https://alpm.archlinux.page/specifications/alpm-architecture.7.html
+#[wasm_bindgen(js_name = formatSrcinfoPackages)]
+pub fn format_srcinfo_packages(
+    input: &str,
+    architecture: &str,
+    pretty: bool,
+) -> Result<String, JsError> {
+    let architecture = Architecture::from_str(architecture)?;
+    let SourceInfo::V1(source_info) = SourceInfo::from_str(input)?;
+    let packages: Vec<MergedPackage> = source_info
+        .packages_for_architecture(architecture)
+        .collect();
+
+    to_json(&packages, pretty)
+}
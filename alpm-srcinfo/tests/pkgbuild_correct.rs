@@ -1,4 +1,5 @@
 //! Happy-path tests for the PKGBUILD to SRCINFO conversion.
+#![cfg(feature = "pkgbuild")]
 
 use std::path::PathBuf;
 
@@ -1,4 +1,5 @@
 //! Error test cases for the BridgeOutput to SRCINFO conversion.
+#![cfg(feature = "pkgbuild")]
 
 use std::path::PathBuf;
 
@@ -0,0 +1,28 @@
+//! Round-trip property tests for [SRCINFO] data.
+//!
+//! These tests are only executed when the `arbitrary` feature flag is enabled.
+//!
+//! [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+#![cfg(feature = "arbitrary")]
+
+use alpm_srcinfo::{SourceInfoV1, arbitrary::SourceInfoText};
+use arbitrary::Unstructured;
+use proptest::{collection::vec, prelude::*};
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(500))]
+
+    /// Ensures that generated [SRCINFO] data can be parsed and that its [SRCINFO] representation
+    /// parses to the same [`SourceInfoV1`].
+    ///
+    /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+    #[test]
+    fn srcinfo_round_trip(data in vec(any::<u8>(), 0..4096)) {
+        let mut unstructured = Unstructured::new(&data);
+        let text: SourceInfoText = unstructured.arbitrary().unwrap();
+
+        let source_info = SourceInfoV1::from_string(text.as_str()).unwrap();
+        let reparsed = SourceInfoV1::from_string(&source_info.as_srcinfo()).unwrap();
+        prop_assert_eq!(source_info, reparsed);
+    }
+}
@@ -0,0 +1,138 @@
+//! Integration tests for the validation and merging of source hash digests in SRCINFO data.
+
+use alpm_srcinfo::{Error, SourceInfoV1, source_info::v1::merged::SourceChecksum};
+use alpm_types::SystemArchitecture;
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use testresult::TestResult;
+
+/// Ensures that each merged source exposes all of its hash digests.
+#[test]
+fn merged_source_checksums() -> TestResult {
+    let source_info = SourceInfoV1::from_string(
+        r#"
+pkgbase = example
+    pkgver = 1.0.0
+    pkgrel = 1
+    arch = x86_64
+    source = https://example.org/example-1.0.0.tar.gz
+    source = local.patch
+    sha256sums = 9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08
+    sha256sums = SKIP
+    b2sums = SKIP
+    b2sums = SKIP
+    source_x86_64 = x86_64.patch
+    sha256sums_x86_64 = SKIP
+
+pkgname = example
+"#,
+    )?;
+
+    let package = source_info
+        .try_packages_for_architecture(SystemArchitecture::X86_64)?
+        .next()
+        .unwrap();
+    let checksums = package
+        .sources
+        .iter()
+        .map(|source| {
+            source
+                .checksums()
+                .iter()
+                .map(|checksum| (checksum.keyword(), checksum.is_skipped()))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        checksums,
+        vec![
+            vec![("b2sums", true), ("sha256sums", false)],
+            vec![("b2sums", true), ("sha256sums", true)],
+            vec![("sha256sums", true)],
+        ]
+    );
+    assert!(matches!(
+        package.sources[0].checksums()[1],
+        SourceChecksum::Sha256(_)
+    ));
+
+    Ok(())
+}
+
+/// Ensures that lists of hash digests that do not match the number of sources are rejected.
+#[rstest]
+#[case::too_few(
+    r#"
+pkgbase = example
+    pkgver = 1.0.0
+    pkgrel = 1
+    arch = x86_64
+    source = https://example.org/example-1.0.0.tar.gz
+    source = local.patch
+    sha256sums = SKIP
+
+pkgname = example
+"#,
+    "sha256sums",
+    1,
+    2
+)]
+#[case::too_many(
+    r#"
+pkgbase = example
+    pkgver = 1.0.0
+    pkgrel = 1
+    arch = x86_64
+    source = local.patch
+    md5sums = SKIP
+    md5sums = SKIP
+
+pkgname = example
+"#,
+    "md5sums",
+    2,
+    1
+)]
+#[case::architecture_specific(
+    r#"
+pkgbase = example
+    pkgver = 1.0.0
+    pkgrel = 1
+    arch = x86_64
+    source = local.patch
+    sha256sums = SKIP
+    source_x86_64 = x86_64.patch
+    b2sums_x86_64 = SKIP
+    b2sums_x86_64 = SKIP
+
+pkgname = example
+"#,
+    "b2sums_x86_64",
+    2,
+    1
+)]
+fn source_checksum_count_mismatch(
+    #[case] input: &str,
+    #[case] expected_keyword: &str,
+    #[case] expected_checksums: usize,
+    #[case] expected_sources: usize,
+) -> TestResult {
+    let source_info = SourceInfoV1::from_string(input)?;
+
+    match source_info.try_packages_for_architecture(SystemArchitecture::X86_64) {
+        Err(Error::SourceChecksumCountMismatch {
+            keyword,
+            checksums,
+            sources,
+        }) => {
+            assert_eq!(keyword, expected_keyword);
+            assert_eq!(checksums, expected_checksums);
+            assert_eq!(sources, expected_sources);
+        }
+        Err(error) => panic!("Unexpected error: {error}"),
+        Ok(_) => panic!("Expected a source checksum count mismatch"),
+    }
+
+    Ok(())
+}
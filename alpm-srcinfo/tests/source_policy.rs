@@ -0,0 +1,126 @@
+//! Integration tests for applying source policies to SRCINFO data.
+
+use alpm_srcinfo::{
+    SourceInfoV1,
+    source_info::v1::policy::{
+        GitHubArchiveRewriter,
+        HttpsUpgradeRewriter,
+        SourcePolicy,
+        SourcePolicyViolation,
+    },
+};
+use alpm_types::SystemArchitecture;
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use testresult::TestResult;
+
+const SRCINFO: &str = r#"
+pkgbase = example
+    pkgver = 1.0.0
+    pkgrel = 1
+    arch = x86_64
+    arch = aarch64
+    source = https://github.com/example/example/archive/refs/tags/v1.0.0.tar.gz
+    source = ftp://example.org/example.patch
+    source = git+http://example.org/example.git#tag=v1.0.0
+    source = local.patch
+    source_aarch64 = http://example.org/aarch64.patch
+
+pkgname = example
+"#;
+
+/// Ensures that GitHub archive URLs are rewritten to release asset URLs.
+#[rstest]
+#[case::refs_tags(
+    "https://github.com/example/project/archive/refs/tags/v1.0.0.tar.gz",
+    Some("https://github.com/example/project/releases/download/v1.0.0/project-v1.0.0.tar.gz")
+)]
+#[case::short(
+    "https://github.com/example/project/archive/1.0.0.zip",
+    Some("https://github.com/example/project/releases/download/1.0.0/project-1.0.0.zip")
+)]
+#[case::release_asset(
+    "https://github.com/example/project/releases/download/1.0.0/project-1.0.0.tar.gz",
+    None
+)]
+#[case::other_host("https://example.org/example/project/archive/1.0.0.tar.gz", None)]
+fn github_archive_rewriter(#[case] input: &str, #[case] expected: Option<&str>) -> TestResult {
+    use alpm_srcinfo::source_info::v1::policy::SourceRewriter;
+
+    let rewritten = GitHubArchiveRewriter::default().rewrite(&input.parse()?);
+    assert_eq!(
+        rewritten.map(|url| url.to_string()),
+        expected.map(ToString::to_string)
+    );
+
+    Ok(())
+}
+
+/// Ensures that a policy rewrites sources and reports remaining violations.
+#[test]
+fn apply_source_policy() -> TestResult {
+    let source_info = SourceInfoV1::from_string(SRCINFO)?;
+    let policy = SourcePolicy::new()
+        .require_https(true)
+        .forbid_scheme("FTP")
+        .with_rewriter(GitHubArchiveRewriter::default())
+        .with_rewriter(HttpsUpgradeRewriter);
+
+    let (rewritten, report) = source_info.apply_source_policy(&policy);
+
+    let sources: Vec<String> = rewritten
+        .base
+        .sources
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        sources,
+        vec![
+            "https://github.com/example/example/releases/download/v1.0.0/example-v1.0.0.tar.gz",
+            "ftp://example.org/example.patch",
+            "git+https://example.org/example.git#tag=v1.0.0",
+            "local.patch",
+        ]
+    );
+    assert_eq!(
+        rewritten.base.architecture_properties[&SystemArchitecture::Aarch64].sources[0].to_string(),
+        "https://example.org/aarch64.patch"
+    );
+
+    assert_eq!(report.changes.len(), 3);
+    assert_eq!(report.changes[0].rewriters, vec!["github-archive"]);
+    assert_eq!(
+        report.changes[2].location.architecture,
+        Some(SystemArchitecture::Aarch64)
+    );
+
+    assert!(!report.is_compliant());
+    assert_eq!(report.violations.len(), 1);
+    assert_eq!(report.violations[0].location.index, 1);
+    assert_eq!(
+        report.violations[0].violation,
+        SourcePolicyViolation::ForbiddenScheme {
+            scheme: "ftp".to_string()
+        }
+    );
+
+    // The original data is left untouched.
+    assert_eq!(source_info, SourceInfoV1::from_string(SRCINFO)?);
+
+    Ok(())
+}
+
+/// Ensures that an empty policy leaves all sources untouched.
+#[test]
+fn permissive_policy() -> TestResult {
+    let source_info = SourceInfoV1::from_string(SRCINFO)?;
+
+    let (rewritten, report) = source_info.apply_source_policy(&SourcePolicy::new());
+
+    assert_eq!(rewritten, source_info);
+    assert!(report.changes.is_empty());
+    assert!(report.is_compliant());
+
+    Ok(())
+}
@@ -55,6 +55,14 @@ error-unknown-compression = Unknown compression algorithm file extension: { $val
 
 error-unknown-filetype = Unknown file type identifier: { $value }
 
+error-package-description-too-long = The package description is { $length } characters long, but at most { $max_length } characters are allowed
+
+error-checksum-mismatch = The checksum { $actual } does not match the expected checksum { $expected }
+
+error-checksum-verification-io = I/O error while verifying a checksum: { $source }
+
+error-source-checksum-count-mismatch = The number of checksums ({ $checksums }) does not match the number of sources ({ $sources })
+
 error-invalid-architectures = The architecture combination is invalid: { $architectures } ({ $context })
 
 error-invalid-base64-encoding-pgp-signature = base64 encoded OpenPGP detached signature
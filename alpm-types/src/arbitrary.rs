@@ -0,0 +1,350 @@
+//! Implementations of [`Arbitrary`] for the core types of this crate.
+//!
+//! All values are created by construction: a valid string representation is assembled from the
+//! unstructured input and then parsed.
+//! This ensures, that only values are created, that could also have been parsed from real-world
+//! metadata files, which allows using them in round-trip property tests and fuzz harnesses.
+
+use std::str::FromStr;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{
+    AbsolutePath,
+    Architecture,
+    BuildEnvironmentOption,
+    BuildTool,
+    BuildToolVersion,
+    Checksum,
+    Epoch,
+    FullVersion,
+    InstalledPackage,
+    License,
+    MinimalVersion,
+    Name,
+    OptionalDependency,
+    PackageDescription,
+    PackageOption,
+    PackageRelation,
+    PackageRelease,
+    PackageVersion,
+    Packager,
+    RelationOrSoname,
+    RelativeFilePath,
+    SystemArchitecture,
+    Url,
+    Version,
+    checksum::DigestString,
+};
+
+/// The characters that an [alpm-package-name] may start with.
+///
+/// [alpm-package-name]: https://alpm.archlinux.page/specifications/alpm-package-name.7.html
+const NAME_START: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789_@+";
+
+/// The characters that an [alpm-package-name] may contain.
+///
+/// [alpm-package-name]: https://alpm.archlinux.page/specifications/alpm-package-name.7.html
+const NAME_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789_@+-.";
+
+/// Alphanumeric ASCII characters.
+const ALPHANUMERIC: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// The characters that an [alpm-pkgver] may contain (a conservative subset of all allowed ones).
+///
+/// [alpm-pkgver]: https://alpm.archlinux.page/specifications/alpm-pkgver.7.html
+const PKGVER_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789._+";
+
+/// The characters used in free-form text (e.g. descriptions).
+const TEXT_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 .,-_()";
+
+/// A selection of SPDX license expressions.
+const LICENSES: &[&str] = &[
+    "Apache-2.0",
+    "BSD-3-Clause",
+    "GPL-2.0-only",
+    "GPL-3.0-or-later",
+    "LGPL-2.1-or-later",
+    "MIT",
+    "MIT OR Apache-2.0",
+    "MPL-2.0",
+    "Zlib",
+];
+
+/// The comparison operators of a [`crate::VersionRequirement`].
+const COMPARISONS: &[&str] = &["<", "<=", "=", ">=", ">"];
+
+/// Creates a string that starts with a character of `first`, followed by up to `max_len - 1`
+/// characters of `rest`.
+fn string_from_chars(
+    u: &mut Unstructured<'_>,
+    first: &[u8],
+    rest: &[u8],
+    max_len: usize,
+) -> Result<String> {
+    let mut output = String::from(char::from(*u.choose(first)?));
+    for _ in 0..u.int_in_range(0..=max_len.saturating_sub(1))? {
+        output.push(char::from(*u.choose(rest)?));
+    }
+
+    Ok(output)
+}
+
+/// Parses `input` as `T`.
+///
+/// # Errors
+///
+/// Returns [`arbitrary::Error::IncorrectFormat`] if `input` cannot be parsed.
+/// As all inputs are created by construction, this should never happen.
+fn parse<T: FromStr>(input: &str) -> Result<T> {
+    T::from_str(input).map_err(|_| arbitrary::Error::IncorrectFormat)
+}
+
+impl<'a> Arbitrary<'a> for Name {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        parse(&string_from_chars(u, NAME_START, NAME_CHARS, 32)?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for BuildTool {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Epoch {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.int_in_range(1..=99)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for PackageRelease {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let major = u.int_in_range(1..=999)?;
+        let minor = if u.arbitrary()? {
+            Some(u.int_in_range(1..=99)?)
+        } else {
+            None
+        };
+
+        Ok(Self::new(major, minor))
+    }
+}
+
+impl<'a> Arbitrary<'a> for PackageVersion {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        parse(&string_from_chars(u, ALPHANUMERIC, PKGVER_CHARS, 16)?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for FullVersion {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?, u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MinimalVersion {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Version {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?, u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Architecture {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let architectures = [
+            "any",
+            "aarch64",
+            "arm",
+            "armv6h",
+            "armv7h",
+            "i386",
+            "i486",
+            "i686",
+            "pentium4",
+            "riscv32",
+            "riscv64",
+            "x86_64",
+            "x86_64_v2",
+            "x86_64_v3",
+            "x86_64_v4",
+        ];
+
+        parse(u.choose(&architectures)?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for SystemArchitecture {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        match u.arbitrary()? {
+            Architecture::Some(architecture) => Ok(architecture),
+            Architecture::Any => Ok(SystemArchitecture::X86_64),
+        }
+    }
+}
+
+impl<'a, D: DigestString> Arbitrary<'a> for Checksum<D> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let input: &[u8] = u.arbitrary()?;
+        Ok(Self::calculate_from(input))
+    }
+}
+
+impl<'a> Arbitrary<'a> for PackageDescription {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from(
+            string_from_chars(u, ALPHANUMERIC, TEXT_CHARS, 80)?.as_str(),
+        ))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Url {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let host = string_from_chars(u, ALPHANUMERIC, ALPHANUMERIC, 16)?.to_lowercase();
+        let path = string_from_chars(u, ALPHANUMERIC, NAME_CHARS, 16)?;
+
+        parse(&format!("https://{host}.example.org/{path}"))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Packager {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let first_name = string_from_chars(u, ALPHANUMERIC, ALPHANUMERIC, 12)?;
+        let last_name = string_from_chars(u, ALPHANUMERIC, ALPHANUMERIC, 12)?;
+        let local_part = string_from_chars(u, ALPHANUMERIC, ALPHANUMERIC, 12)?.to_lowercase();
+
+        parse(&format!(
+            "{first_name} {last_name} <{local_part}@example.org>"
+        ))
+    }
+}
+
+impl<'a> Arbitrary<'a> for License {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        parse(u.choose(LICENSES)?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for PackageRelation {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let name: Name = u.arbitrary()?;
+        let requirement = if u.arbitrary()? {
+            let comparison = u.choose(COMPARISONS)?;
+            let version: Version = u.arbitrary()?;
+            format!("{comparison}{version}")
+        } else {
+            String::new()
+        };
+
+        parse(&format!("{name}{requirement}"))
+    }
+}
+
+impl<'a> Arbitrary<'a> for OptionalDependency {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // Version requirements with an epoch are not used, as the epoch delimiter ":" can not be
+        // distinguished from the description delimiter.
+        let name: Name = u.arbitrary()?;
+        let requirement = if u.arbitrary()? {
+            let comparison = u.choose(COMPARISONS)?;
+            let version: PackageVersion = u.arbitrary()?;
+            format!("{comparison}{version}")
+        } else {
+            String::new()
+        };
+        let description = if u.arbitrary()? {
+            format!(
+                ": {}",
+                string_from_chars(u, ALPHANUMERIC, TEXT_CHARS, 40)?.trim()
+            )
+        } else {
+            String::new()
+        };
+
+        parse(&format!("{name}{requirement}{description}"))
+    }
+}
+
+impl<'a> Arbitrary<'a> for RelationOrSoname {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::Relation(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for RelativeFilePath {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let directory = string_from_chars(u, ALPHANUMERIC, NAME_CHARS, 16)?;
+        let file = string_from_chars(u, ALPHANUMERIC, NAME_CHARS, 16)?;
+
+        parse(&format!("etc/{directory}/{file}"))
+    }
+}
+
+impl<'a> Arbitrary<'a> for AbsolutePath {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let directory = string_from_chars(u, ALPHANUMERIC, NAME_CHARS, 16)?;
+
+        parse(&format!("/{directory}"))
+    }
+}
+
+impl<'a> Arbitrary<'a> for InstalledPackage {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?, u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for BuildToolVersion {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.arbitrary()? {
+            Ok(Self::DevTools {
+                version: u.arbitrary()?,
+                architecture: u.arbitrary()?,
+            })
+        } else {
+            Ok(Self::Makepkg(u.arbitrary()?))
+        }
+    }
+}
+
+impl<'a> Arbitrary<'a> for BuildEnvironmentOption {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let options = [
+            "buildflags",
+            "ccache",
+            "check",
+            "color",
+            "distcc",
+            "makeflags",
+            "sign",
+        ];
+        let negation = if u.arbitrary()? { "!" } else { "" };
+
+        parse(&format!("{negation}{}", u.choose(&options)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for PackageOption {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let options = [
+            "autodeps",
+            "debug",
+            "docs",
+            "emptydirs",
+            "libtool",
+            "lto",
+            "purge",
+            "staticlibs",
+            "strip",
+            "zipman",
+        ];
+        let negation = if u.arbitrary()? { "!" } else { "" };
+
+        parse(&format!("{negation}{}", u.choose(&options)?))
+    }
+}
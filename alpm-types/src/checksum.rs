@@ -1,11 +1,13 @@
 use std::{
     fmt::{Debug, Display, Formatter},
+    io::Read,
     marker::PhantomData,
     ops::DerefMut,
     str::FromStr,
 };
 
 use digest::{Digest, FixedOutput, HashMarker, Output, OutputSizeUser, Update};
+use fluent_i18n::t;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use strum::{Display, EnumString, VariantArray, VariantNames};
 use winnow::{
@@ -19,6 +21,7 @@ use winnow::{
 
 use crate::{
     Error,
+    Source,
     digests::{Blake2b512, Md5, Sha1, Sha224, Sha256, Sha384, Sha512},
 };
 
@@ -270,6 +273,21 @@ impl<D: DigestString> Serialize for Checksum<D> {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl<D: DigestString> schemars::JsonSchema for Checksum<D> {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Checksum".into()
+    }
+
+    /// Returns the JSON Schema of the hex `String` representation of a [`Checksum`].
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": "^[a-f0-9]+$",
+        })
+    }
+}
+
 impl<'de, D: DigestString> Deserialize<'de> for Checksum<D> {
     fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
     where
@@ -280,6 +298,147 @@ impl<'de, D: DigestString> Deserialize<'de> for Checksum<D> {
     }
 }
 
+/// An incremental hasher for the creation of a [`Checksum`].
+///
+/// Allows calculating a [`Checksum`] for data that is not available at once (e.g. because it is
+/// streamed).
+/// Data is added using [`ChecksumHasher::update`] or the [`Write`](std::io::Write)
+/// implementation.
+///
+/// ## Examples
+/// ```
+/// use std::io::Write;
+///
+/// use alpm_types::{ChecksumHasher, Sha256Checksum, digests::Sha256};
+///
+/// # fn main() -> Result<(), std::io::Error> {
+/// let mut hasher = ChecksumHasher::<Sha256>::new();
+/// hasher.update("foo");
+/// hasher.write_all(b"\n")?;
+///
+/// assert_eq!(hasher.finalize(), Sha256Checksum::calculate_from("foo\n"));
+/// # Ok(())
+/// # }
+/// ```
+pub struct ChecksumHasher<D: DigestString> {
+    hasher: D,
+}
+
+impl<D: DigestString> ChecksumHasher<D> {
+    /// Creates a new [`ChecksumHasher`].
+    pub fn new() -> Self {
+        Self { hasher: D::new() }
+    }
+
+    /// Adds `input` to the data of the [`ChecksumHasher`].
+    pub fn update(&mut self, input: impl AsRef<[u8]>) {
+        Digest::update(&mut self.hasher, input);
+    }
+
+    /// Consumes the [`ChecksumHasher`] and returns the [`Checksum`] of all added data.
+    pub fn finalize(self) -> Checksum<D> {
+        Checksum {
+            digest: self.hasher.finalize()[..].to_vec(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<D: DigestString> Default for ChecksumHasher<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: DigestString> Debug for ChecksumHasher<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChecksumHasher").finish_non_exhaustive()
+    }
+}
+
+impl<D: DigestString> std::io::Write for ChecksumHasher<D> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The [`Checksum`] of data does not match the expected one.
+///
+/// Returned by [`Checksum::verify`] and as part of a [`ChecksumVerificationError`] by
+/// [`Checksum::verify_reader`].
+#[derive(thiserror::Error)]
+#[error("{msg}", msg = t!("error-checksum-mismatch", {
+    "expected" => expected.to_string(),
+    "actual" => actual.to_string()
+}))]
+pub struct ChecksumMismatch<D: DigestString> {
+    /// The expected checksum.
+    pub expected: Checksum<D>,
+    /// The checksum of the verified data.
+    pub actual: Checksum<D>,
+}
+
+impl<D: DigestString> Debug for ChecksumMismatch<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChecksumMismatch")
+            .field("expected", &self.expected)
+            .field("actual", &self.actual)
+            .finish()
+    }
+}
+
+/// An error that occurs when verifying the data of a reader against a [`Checksum`].
+///
+/// Returned by [`Checksum::verify_reader`].
+#[derive(thiserror::Error)]
+pub enum ChecksumVerificationError<D: DigestString> {
+    /// The data cannot be read.
+    #[error("{msg}", msg = t!("error-checksum-verification-io", { "source" => .0.to_string() }))]
+    Io(#[from] std::io::Error),
+
+    /// The checksum of the data does not match.
+    #[error("{0}")]
+    Mismatch(ChecksumMismatch<D>),
+}
+
+impl<D: DigestString> From<ChecksumMismatch<D>> for ChecksumVerificationError<D> {
+    /// Converts a [`ChecksumMismatch`] into a [`ChecksumVerificationError::Mismatch`].
+    fn from(value: ChecksumMismatch<D>) -> Self {
+        Self::Mismatch(value)
+    }
+}
+
+impl<D: DigestString> Debug for ChecksumVerificationError<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(source) => f.debug_tuple("Io").field(source).finish(),
+            Self::Mismatch(mismatch) => f.debug_tuple("Mismatch").field(mismatch).finish(),
+        }
+    }
+}
+
+/// Compares `a` and `b` in constant time.
+///
+/// The time needed for the comparison only depends on the length of the inputs, not on their
+/// contents.
+/// This prevents leaking the position of the first differing byte through timing side-channels.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let difference = a
+        .iter()
+        .zip(b)
+        .fold(0u8, |difference, (a, b)| difference | (a ^ b));
+
+    std::hint::black_box(difference) == 0
+}
+
 impl<D: DigestString> Checksum<D> {
     /// Calculate a new Checksum for data that may be represented as a list of bytes
     ///
@@ -307,6 +466,80 @@ impl<D: DigestString> Checksum<D> {
         &self.digest
     }
 
+    /// Verifies that `data` matches the [`Checksum`].
+    ///
+    /// The digests are compared in constant time.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ChecksumMismatch`] if the checksum of `data` does not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_types::Sha256Checksum;
+    ///
+    /// let checksum = Sha256Checksum::calculate_from("foo\n");
+    /// assert!(checksum.verify("foo\n").is_ok());
+    ///
+    /// let mismatch = checksum.verify("bar\n").unwrap_err();
+    /// assert_eq!(mismatch.expected, checksum);
+    /// assert_eq!(mismatch.actual, Sha256Checksum::calculate_from("bar\n"));
+    /// ```
+    pub fn verify(&self, data: impl AsRef<[u8]>) -> Result<(), ChecksumMismatch<D>> {
+        self.verify_checksum(Self::calculate_from(data))
+    }
+
+    /// Verifies that all data read from `reader` matches the [`Checksum`].
+    ///
+    /// The data is read in chunks, so that large files do not have to be kept in memory.
+    /// The digests are compared in constant time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - reading from `reader` fails,
+    /// - or the checksum of the read data does not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_types::{ChecksumVerificationError, Sha256Checksum};
+    ///
+    /// let checksum = Sha256Checksum::calculate_from("foo\n");
+    /// assert!(checksum.verify_reader(&b"foo\n"[..]).is_ok());
+    /// assert!(matches!(
+    ///     checksum.verify_reader(&b"bar\n"[..]),
+    ///     Err(ChecksumVerificationError::Mismatch(_))
+    /// ));
+    /// ```
+    pub fn verify_reader(&self, mut reader: impl Read) -> Result<(), ChecksumVerificationError<D>> {
+        let mut hasher = ChecksumHasher::<D>::new();
+        std::io::copy(&mut reader, &mut hasher)?;
+
+        Ok(self.verify_checksum(hasher.finalize())?)
+    }
+
+    /// Compares the [`Checksum`] with `actual` in constant time.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ChecksumMismatch`] if `actual` does not match.
+    fn verify_checksum(&self, actual: Checksum<D>) -> Result<(), ChecksumMismatch<D>> {
+        if constant_time_eq(&self.digest, &actual.digest) {
+            return Ok(());
+        }
+
+        Err(ChecksumMismatch {
+            expected: Checksum {
+                digest: self.digest.clone(),
+                _marker: PhantomData,
+            },
+            actual,
+        })
+    }
+
     /// Recognizes an ASCII hexadecimal [`Checksum`] from a string slice.
     ///
     /// Consumes all input.
@@ -499,6 +732,40 @@ pub enum SkippableChecksum<D: DigestString + Clone> {
     },
 }
 
+#[cfg(feature = "schemars")]
+impl<D: DigestString + Clone> schemars::JsonSchema for SkippableChecksum<D> {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "SkippableChecksum".into()
+    }
+
+    /// Returns the JSON Schema of the internally tagged representation of a
+    /// [`SkippableChecksum`].
+    ///
+    /// The schema is not derived, as the digest `D` is only a marker type, that does not implement
+    /// [`schemars::JsonSchema`].
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "type": { "const": "Skip" },
+                    },
+                    "required": ["type"],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "type": { "const": "Checksum" },
+                        "digest": generator.subschema_for::<Checksum<D>>(),
+                    },
+                    "required": ["type", "digest"],
+                },
+            ],
+        })
+    }
+}
+
 impl<D: DigestString + Clone> SkippableChecksum<D> {
     /// Determines whether the [`SkippableChecksum`] is skipped.
     ///
@@ -583,6 +850,110 @@ impl<D: DigestString + Clone> PartialEq for SkippableChecksum<D> {
     }
 }
 
+/// A pairing of [`Source`]s with the [`SkippableChecksum`]s of one checksum array.
+///
+/// In [PKGBUILD] and [SRCINFO] files, each checksum array (e.g. `sha256sums` or the
+/// architecture-specific `sha256sums_x86_64`) contains one entry per entry of the related source
+/// array (e.g. `source` or `source_x86_64`).
+/// A [`SourceChecksums`] pairs the entries of both arrays, independently of the digest `D`.
+///
+/// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
+/// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
+#[derive(Clone, Copy, Debug)]
+pub struct SourceChecksums<'a, D: DigestString + Clone> {
+    sources: &'a [Source],
+    checksums: &'a [SkippableChecksum<D>],
+}
+
+impl<'a, D: DigestString + Clone> SourceChecksums<'a, D> {
+    /// Creates a new [`SourceChecksums`] from a list of `sources` and a list of `checksums`.
+    ///
+    /// An empty list of `checksums` is considered valid, as checksum arrays for digests that are
+    /// not used are omitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `checksums` is not empty and its length does not match that of
+    /// `sources`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::{SkippableChecksum, Source, SourceChecksums, digests::Sha256};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let sources = vec![
+    ///     Source::from_str("https://example.org/foo.tar.gz")?,
+    ///     Source::from_str("git+https://example.org/bar.git")?,
+    /// ];
+    /// let checksums = vec![
+    ///     SkippableChecksum::<Sha256>::from_str(
+    ///         "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c",
+    ///     )?,
+    ///     SkippableChecksum::<Sha256>::from_str("SKIP")?,
+    /// ];
+    ///
+    /// let pairs = SourceChecksums::new(&sources, &checksums)?;
+    /// let skipped: Vec<String> = pairs
+    ///     .iter()
+    ///     .filter(|(_, checksum)| checksum.is_none())
+    ///     .map(|(source, _)| source.to_string())
+    ///     .collect();
+    /// assert_eq!(skipped, vec!["git+https://example.org/bar.git"]);
+    ///
+    /// // The number of checksums must match the number of sources.
+    /// assert!(SourceChecksums::new(&sources, &checksums[..1]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(
+        sources: &'a [Source],
+        checksums: &'a [SkippableChecksum<D>],
+    ) -> Result<Self, Error> {
+        if !checksums.is_empty() && checksums.len() != sources.len() {
+            return Err(Error::SourceChecksumCountMismatch {
+                sources: sources.len(),
+                checksums: checksums.len(),
+            });
+        }
+
+        Ok(Self::new_unchecked(sources, checksums))
+    }
+
+    /// Creates a new [`SourceChecksums`] from a list of `sources` and a list of `checksums`
+    /// without validating their lengths.
+    ///
+    /// Surplus `checksums` are ignored, while surplus `sources` are paired with [`None`] during
+    /// iteration.
+    /// This is useful for tolerant consumers (e.g. lint rules), that should not fail on
+    /// inconsistent input.
+    pub fn new_unchecked(sources: &'a [Source], checksums: &'a [SkippableChecksum<D>]) -> Self {
+        Self { sources, checksums }
+    }
+
+    /// Returns `true` if no checksums are present.
+    pub fn is_empty(&self) -> bool {
+        self.checksums.is_empty()
+    }
+
+    /// Returns an iterator over all [`Source`]s and their optional [`Checksum`].
+    ///
+    /// A [`Source`] is paired with [`None`] if its checksum is [`SkippableChecksum::Skip`] or if
+    /// no checksum is present for it.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a Source, Option<&'a Checksum<D>>)> + use<'a, D> {
+        let checksums = self.checksums;
+        self.sources.iter().enumerate().map(move |(index, source)| {
+            let checksum = match checksums.get(index) {
+                Some(SkippableChecksum::Checksum { digest }) => Some(digest),
+                Some(SkippableChecksum::Skip) | None => None,
+            };
+            (source, checksum)
+        })
+    }
+}
+
 /// CRC-32/CKSUM hasher state.
 ///
 /// This implementation tracks the length of the input data and appends it to the checksum
@@ -635,6 +1006,7 @@ mod tests {
     use insta::assert_snapshot;
     use proptest::prelude::*;
     use rstest::rstest;
+    use testresult::TestResult;
 
     use super::*;
     use crate::configure_insta;
@@ -949,4 +1321,84 @@ mod tests {
         assert_eq!(SkippableChecksum::Skip, checksum);
         assert_eq!(format!("{}", checksum), hex_digest);
     }
+
+    /// Ensures that sources are paired with their checksums and that skipped or missing checksums
+    /// are represented as [`None`].
+    #[rstest]
+    #[case::matching(&["b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c", "SKIP"], vec![true, false])]
+    #[case::no_checksums(&[], vec![false, false])]
+    fn source_checksums_pairs(
+        #[case] checksums: &[&str],
+        #[case] expected: Vec<bool>,
+    ) -> TestResult {
+        let sources = vec![
+            Source::from_str("https://example.org/foo.tar.gz")?,
+            Source::from_str("foo.patch")?,
+        ];
+        let checksums = checksums
+            .iter()
+            .map(|checksum| SkippableChecksum::<Sha256>::from_str(checksum))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let pairs = SourceChecksums::new(&sources, &checksums)?;
+        let result: Vec<bool> = pairs
+            .iter()
+            .map(|(_, checksum)| checksum.is_some())
+            .collect();
+
+        assert_eq!(result, expected);
+        assert_eq!(pairs.is_empty(), checksums.is_empty());
+        Ok(())
+    }
+
+    /// Ensures that a mismatch between the number of sources and checksums is detected, unless
+    /// validation is skipped.
+    #[test]
+    fn source_checksums_count_mismatch() -> TestResult {
+        let sources = vec![Source::from_str("foo.patch")?];
+        let checksums = vec![
+            SkippableChecksum::<Md5>::Skip,
+            SkippableChecksum::<Md5>::Skip,
+        ];
+
+        assert!(matches!(
+            SourceChecksums::new(&sources, &checksums),
+            Err(Error::SourceChecksumCountMismatch {
+                sources: 1,
+                checksums: 2
+            })
+        ));
+        assert_eq!(
+            SourceChecksums::new_unchecked(&sources, &checksums)
+                .iter()
+                .count(),
+            1
+        );
+        Ok(())
+    }
+
+    /// Ensures that digests are only considered equal if they have the same length and contents.
+    #[rstest]
+    #[case(b"", b"", true)]
+    #[case(b"foo", b"foo", true)]
+    #[case(b"foo", b"fob", false)]
+    #[case(b"foo", b"fo", false)]
+    fn constant_time_comparison(#[case] a: &[u8], #[case] b: &[u8], #[case] equal: bool) {
+        assert_eq!(constant_time_eq(a, b), equal);
+    }
+
+    /// Ensures that reading data for verification supports data spanning multiple chunks.
+    #[test]
+    fn verify_reader_large_input() -> TestResult {
+        let data = vec![b'a'; 100_000];
+        let checksum = Sha512Checksum::calculate_from(&data);
+
+        checksum.verify_reader(data.as_slice())?;
+        let Err(ChecksumVerificationError::Mismatch(mismatch)) = checksum.verify_reader(&data[1..])
+        else {
+            panic!("Expected a checksum mismatch");
+        };
+        assert_eq!(mismatch.actual, Sha512Checksum::calculate_from(&data[1..]));
+        Ok(())
+    }
 }
@@ -101,6 +101,7 @@ fn option_name_parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
 /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
 /// [PKGBUILD]: https://man.archlinux.org/man/PKGBUILD.5
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum MakepkgOption {
     /// A [`BuildEnvironmentOption`]
@@ -172,6 +173,7 @@ impl Display for MakepkgOption {
 /// # }
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, VariantNames)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum BuildEnvironmentOption {
     /// Use or unset the values of build flags (e.g. `CPPFLAGS`, `CFLAGS`, `CXXFLAGS`, `LDFLAGS`)
@@ -308,6 +310,7 @@ impl Display for BuildEnvironmentOption {
 /// # }
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, VariantNames)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum PackageOption {
     /// Automatically add dependencies and provisions (see [alpm-sonamev2]).
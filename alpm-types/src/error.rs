@@ -194,12 +194,36 @@ pub enum Error {
         value: String,
     },
 
+    /// The number of checksums does not match the number of sources.
+    #[error("{msg}", msg = t!("error-source-checksum-count-mismatch", {
+        "sources" => sources.to_string(),
+        "checksums" => checksums.to_string()
+    }))]
+    SourceChecksumCountMismatch {
+        /// The number of sources.
+        sources: usize,
+        /// The number of checksums.
+        checksums: usize,
+    },
+
     /// A string represents an unknown file type identifier.
     #[error("{msg}", msg = t!("error-unknown-filetype", { "value" => value }))]
     UnknownFileTypeIdentifier {
         /// A string representing an unknown file type identifier.
         value: String,
     },
+
+    /// A package description is longer than allowed.
+    #[error("{msg}", msg = t!("error-package-description-too-long", {
+        "length" => length.to_string(),
+        "max_length" => max_length.to_string()
+    }))]
+    PackageDescriptionTooLong {
+        /// The length of the description in characters.
+        length: usize,
+        /// The maximum length of the description in characters.
+        max_length: usize,
+    },
 }
 
 impl From<std::num::ParseIntError> for crate::error::Error {
@@ -1,10 +1,16 @@
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
 mod checksum;
 pub use checksum::{
     Blake2b512Checksum,
     Checksum,
     ChecksumAlgorithm,
+    ChecksumHasher,
+    ChecksumMismatch,
+    ChecksumVerificationError,
     Crc32CksumChecksum,
     DigestString as Digest,
     Md5Checksum,
@@ -14,6 +20,7 @@ pub use checksum::{
     Sha384Checksum,
     Sha512Checksum,
     SkippableChecksum,
+    SourceChecksums,
 };
 
 mod source;
@@ -47,6 +54,9 @@ pub use file_type::FileTypeIdentifier;
 mod error;
 pub use error::Error;
 
+mod libalpm;
+pub use libalpm::LibalpmString;
+
 mod license;
 pub use license::License;
 
@@ -86,11 +96,19 @@ pub use openpgp::{
 };
 
 mod pkg;
-pub use pkg::{ExtraData, ExtraDataEntry, PackageBaseName, PackageDescription, PackageType};
+pub use pkg::{
+    ExtraData,
+    ExtraDataEntry,
+    PackageBaseName,
+    PackageDescription,
+    PackageDescriptionPolicy,
+    PackageType,
+};
 
 mod relation;
 pub use relation::{
     Group,
+    GroupLookup,
     OptionalDependency,
     PackageRelation,
     RelationOrSoname,
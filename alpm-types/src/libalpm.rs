@@ -0,0 +1,357 @@
+//! Conversion of types to and from the string conventions of [libalpm].
+//!
+//! Most types in this crate share their string representation with [libalpm] (see e.g.
+//! `alpm_dep_compute_string` and `alpm_dep_from_string` in `alpm.h`).
+//! The conventions differ in the following aspects, which the [`LibalpmString`] trait accounts for:
+//!
+//! - [libalpm] considers a version with an explicit [alpm-epoch] of `0` (e.g. `0:1.0.0-1`) equal to
+//!   the same version without an epoch (e.g. `1.0.0-1`), and [makepkg] never writes an explicit
+//!   epoch of `0`. Such epochs are normalized, so that values exchanged with [libalpm]-based code
+//!   compare equal to those read from package metadata.
+//! - [libalpm] separates the description of a dependency string at the first occurrence of `": "`,
+//!   which allows versions with an epoch in optional dependencies that have a description (e.g.
+//!   `example>=1:1.0.0: for examples`).
+//!
+//! [alpm-epoch]: https://alpm.archlinux.page/specifications/alpm-epoch.7.html
+//! [libalpm]: https://man.archlinux.org/man/libalpm.3
+//! [makepkg]: https://man.archlinux.org/man/makepkg.8
+
+use std::str::FromStr;
+
+use crate::{
+    Epoch,
+    Error,
+    FullVersion,
+    OptionalDependency,
+    PackageFileName,
+    PackageRelation,
+    RelationOrSoname,
+    Version,
+    VersionRequirement,
+};
+
+/// Conversion to and from the string representation used by [libalpm].
+///
+/// # Examples
+///
+/// ```
+/// use alpm_types::{LibalpmString, PackageRelation};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let relation = PackageRelation::from_libalpm_str("example>=0:1.0.0-1")?;
+/// assert_eq!(relation.to_libalpm_string(), "example>=1.0.0-1");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [libalpm]: https://man.archlinux.org/man/libalpm.3
+pub trait LibalpmString: Sized {
+    /// Returns the string representation of `self` as used by [libalpm].
+    ///
+    /// An explicit epoch of `0` is omitted.
+    ///
+    /// [libalpm]: https://man.archlinux.org/man/libalpm.3
+    fn to_libalpm_string(&self) -> String;
+
+    /// Creates `Self` from a string slice as used by [libalpm].
+    ///
+    /// An explicit epoch of `0` is removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is not a valid string representation of `Self`.
+    ///
+    /// [libalpm]: https://man.archlinux.org/man/libalpm.3
+    fn from_libalpm_str(s: &str) -> Result<Self, Error>;
+}
+
+/// Returns `epoch`, unless it is an explicit epoch of `0`.
+fn normalize_epoch(epoch: Option<Epoch>) -> Option<Epoch> {
+    epoch.filter(|epoch| epoch.0 != 0)
+}
+
+/// Returns `version` with a normalized epoch (see [`normalize_epoch`]).
+fn normalize_version(version: Version) -> Version {
+    Version {
+        epoch: normalize_epoch(version.epoch),
+        ..version
+    }
+}
+
+/// Returns `version` with a normalized epoch (see [`normalize_epoch`]).
+fn normalize_full_version(version: FullVersion) -> FullVersion {
+    FullVersion {
+        epoch: normalize_epoch(version.epoch),
+        ..version
+    }
+}
+
+/// Returns `relation` with a normalized epoch in its version requirement (see
+/// [`normalize_epoch`]).
+fn normalize_relation(relation: PackageRelation) -> PackageRelation {
+    PackageRelation::new(
+        relation.name,
+        relation.version_requirement.map(|requirement| {
+            VersionRequirement::new(
+                requirement.comparison,
+                normalize_version(requirement.version),
+            )
+        }),
+    )
+}
+
+impl LibalpmString for Version {
+    /// Returns the string representation of the [`Version`] as used by libalpm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::{LibalpmString, Version};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(Version::from_str("0:1.0.0")?.to_libalpm_string(), "1.0.0");
+    /// assert_eq!(
+    ///     Version::from_str("1:1.0.0-1")?.to_libalpm_string(),
+    ///     "1:1.0.0-1"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn to_libalpm_string(&self) -> String {
+        normalize_version(self.clone()).to_string()
+    }
+
+    fn from_libalpm_str(s: &str) -> Result<Self, Error> {
+        Ok(normalize_version(Version::from_str(s)?))
+    }
+}
+
+impl LibalpmString for FullVersion {
+    /// Returns the string representation of the [`FullVersion`] as used by libalpm.
+    ///
+    /// This corresponds to the version of a package (see `alpm_pkg_get_version`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_types::{FullVersion, LibalpmString};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let version = FullVersion::from_libalpm_str("0:1.0.0-1")?;
+    /// assert_eq!(version.epoch, None);
+    /// assert_eq!(version.to_libalpm_string(), "1.0.0-1");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn to_libalpm_string(&self) -> String {
+        normalize_full_version(self.clone()).to_string()
+    }
+
+    fn from_libalpm_str(s: &str) -> Result<Self, Error> {
+        Ok(normalize_full_version(FullVersion::from_str(s)?))
+    }
+}
+
+impl LibalpmString for PackageRelation {
+    /// Returns the string representation of the [`PackageRelation`] as used by libalpm.
+    ///
+    /// This corresponds to a dependency string without description (see
+    /// `alpm_dep_compute_string`).
+    fn to_libalpm_string(&self) -> String {
+        normalize_relation(self.clone()).to_string()
+    }
+
+    fn from_libalpm_str(s: &str) -> Result<Self, Error> {
+        Ok(normalize_relation(PackageRelation::from_str(s)?))
+    }
+}
+
+impl LibalpmString for OptionalDependency {
+    /// Returns the string representation of the [`OptionalDependency`] as used by libalpm.
+    ///
+    /// This corresponds to a dependency string with an optional description, delimited by `": "`
+    /// (see `alpm_dep_compute_string`).
+    /// When parsing, the description starts after the first occurrence of `": "` (see
+    /// `alpm_dep_from_string`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_types::{LibalpmString, OptionalDependency};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let dependency = OptionalDependency::from_libalpm_str("example=0:1.0.0: for examples")?;
+    /// assert_eq!(
+    ///     dependency.to_libalpm_string(),
+    ///     "example=1.0.0: for examples"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn to_libalpm_string(&self) -> String {
+        OptionalDependency::new(
+            normalize_relation(self.package_relation().clone()),
+            self.description().clone(),
+        )
+        .to_string()
+    }
+
+    fn from_libalpm_str(s: &str) -> Result<Self, Error> {
+        let Some((relation, description)) = s.split_once(": ") else {
+            return Ok(OptionalDependency::new(
+                PackageRelation::from_libalpm_str(s)?,
+                None,
+            ));
+        };
+        // Validate the description using the parser of the regular string representation.
+        let description = OptionalDependency::from_str(&format!("example: {description}"))?
+            .description()
+            .clone();
+
+        Ok(OptionalDependency::new(
+            PackageRelation::from_libalpm_str(relation)?,
+            description,
+        ))
+    }
+}
+
+impl LibalpmString for RelationOrSoname {
+    /// Returns the string representation of the [`RelationOrSoname`] as used by libalpm.
+    ///
+    /// Libalpm does not distinguish between package relations and shared object names, which are
+    /// both represented as dependency strings (e.g. `libexample.so=1-64` or
+    /// `lib:libexample.so.1`).
+    fn to_libalpm_string(&self) -> String {
+        match self {
+            RelationOrSoname::Relation(relation) => relation.to_libalpm_string(),
+            RelationOrSoname::SonameV1(_) | RelationOrSoname::SonameV2(_) => self.to_string(),
+        }
+    }
+
+    fn from_libalpm_str(s: &str) -> Result<Self, Error> {
+        Ok(match RelationOrSoname::from_str(s)? {
+            RelationOrSoname::Relation(relation) => {
+                RelationOrSoname::Relation(normalize_relation(relation))
+            }
+            soname => soname,
+        })
+    }
+}
+
+impl LibalpmString for PackageFileName {
+    /// Returns the string representation of the [`PackageFileName`] as used by libalpm.
+    ///
+    /// This corresponds to the file name of a package in a sync database (see
+    /// `alpm_pkg_get_filename`).
+    /// The file name of the package's detached signature is created by appending `.sig`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_types::{LibalpmString, PackageFileName};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let file_name = PackageFileName::from_libalpm_str("example-0:1.0.0-1-x86_64.pkg.tar.zst")?;
+    /// assert_eq!(
+    ///     file_name.to_libalpm_string(),
+    ///     "example-1.0.0-1-x86_64.pkg.tar.zst"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn to_libalpm_string(&self) -> String {
+        PackageFileName {
+            version: normalize_full_version(self.version.clone()),
+            ..self.clone()
+        }
+        .to_string()
+    }
+
+    fn from_libalpm_str(s: &str) -> Result<Self, Error> {
+        let file_name = PackageFileName::from_str(s)?;
+        Ok(PackageFileName {
+            version: normalize_full_version(file_name.version.clone()),
+            ..file_name
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that package relations round-trip through the libalpm string representation.
+    #[rstest]
+    #[case::name("example", "example")]
+    #[case::greater_or_equal("example>=1.0.0", "example>=1.0.0")]
+    #[case::less("example<1:1.0.0-1", "example<1:1.0.0-1")]
+    #[case::equal_zero_epoch("example=0:1.0.0-1", "example=1.0.0-1")]
+    fn package_relation_libalpm_string(#[case] input: &str, #[case] expected: &str) -> TestResult {
+        let relation = PackageRelation::from_libalpm_str(input)?;
+        assert_eq!(relation.to_libalpm_string(), expected);
+        assert_eq!(PackageRelation::from_libalpm_str(expected)?, relation);
+        Ok(())
+    }
+
+    /// Ensures that optional dependencies round-trip through the libalpm string representation.
+    #[rstest]
+    #[case::name("example", "example")]
+    #[case::description("example: an example", "example: an example")]
+    #[case::zero_epoch("example>=0:1.0.0: an example", "example>=1.0.0: an example")]
+    #[case::epoch("example>=1:1.0.0: an example", "example>=1:1.0.0: an example")]
+    #[case::empty_description("example: ", "example")]
+    fn optional_dependency_libalpm_string(
+        #[case] input: &str,
+        #[case] expected: &str,
+    ) -> TestResult {
+        let dependency = OptionalDependency::from_libalpm_str(input)?;
+        assert_eq!(dependency.to_libalpm_string(), expected);
+        assert_eq!(OptionalDependency::from_libalpm_str(expected)?, dependency);
+        Ok(())
+    }
+
+    /// Ensures that package relations and sonames round-trip through the libalpm string
+    /// representation.
+    #[rstest]
+    #[case::relation("example=0:1.0.0", "example=1.0.0")]
+    #[case::soname_v1("libexample.so=1-64", "libexample.so=1-64")]
+    #[case::soname_v2("lib:libexample.so.1", "lib:libexample.so.1")]
+    fn relation_or_soname_libalpm_string(
+        #[case] input: &str,
+        #[case] expected: &str,
+    ) -> TestResult {
+        let relation = RelationOrSoname::from_libalpm_str(input)?;
+        assert_eq!(relation.to_libalpm_string(), expected);
+        assert_eq!(RelationOrSoname::from_libalpm_str(expected)?, relation);
+        Ok(())
+    }
+
+    /// Ensures that versions round-trip through the libalpm string representation.
+    #[rstest]
+    #[case::full("1.0.0-1", "1.0.0-1")]
+    #[case::epoch("2:1.0.0-1", "2:1.0.0-1")]
+    #[case::zero_epoch("0:1.0.0-1", "1.0.0-1")]
+    fn full_version_libalpm_string(#[case] input: &str, #[case] expected: &str) -> TestResult {
+        let version = FullVersion::from_libalpm_str(input)?;
+        assert_eq!(version.to_libalpm_string(), expected);
+        assert_eq!(FullVersion::from_libalpm_str(expected)?, version);
+
+        let version = Version::from_libalpm_str(input)?;
+        assert_eq!(version.to_libalpm_string(), expected);
+        assert_eq!(Version::from_libalpm_str(expected)?, version);
+        Ok(())
+    }
+
+    /// Ensures that invalid strings are rejected.
+    #[rstest]
+    #[case::relation_with_description("example: an example")]
+    #[case::relation_with_whitespace("example >= 1.0.0")]
+    fn invalid_package_relation_libalpm_string(#[case] input: &str) {
+        assert!(PackageRelation::from_libalpm_str(input).is_err());
+    }
+}
@@ -50,6 +50,18 @@ impl Serialize for License {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for License {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "License".into()
+    }
+
+    /// Returns the JSON Schema of the `String` representation of a [`License`].
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        String::json_schema(generator)
+    }
+}
+
 impl<'de> Deserialize<'de> for License {
     /// Custom serde serialization as Spdx doesn't provide a serde [`Deserialize`] implementation.
     /// This implements deserialization from a string type.
@@ -136,6 +148,42 @@ impl License {
     pub fn is_spdx(&self) -> bool {
         matches!(self, License::Spdx(_))
     }
+
+    /// Returns the canonical SPDX form of a non-standard license, if it can be derived
+    ///
+    /// Non-standard licenses often use imprecise (e.g. `GPL` or `apache2`) or deprecated (e.g.
+    /// `GPL-2.0`) identifiers, which can be mapped to valid SPDX license expressions.
+    ///
+    /// Returns [`None`] if the license already is a valid SPDX license expression, or if no valid
+    /// SPDX license expression can be derived from it.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::License;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let license = License::from_str("GPL-2.0")?;
+    /// assert_eq!(
+    ///     license.canonical_spdx(),
+    ///     Some(License::from_valid_spdx("GPL-2.0-only".to_string())?)
+    /// );
+    ///
+    /// assert_eq!(License::from_str("MIT")?.canonical_spdx(), None);
+    /// assert_eq!(License::from_str("Custom-License")?.canonical_spdx(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn canonical_spdx(&self) -> Option<License> {
+        let License::Unknown(identifier) = self else {
+            return None;
+        };
+        let canonical = Expression::canonicalize(identifier).ok()??;
+
+        Self::from_valid_spdx(canonical).ok()
+    }
 }
 
 impl FromStr for License {
@@ -252,4 +300,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    #[case("GPL", Some("GPL-2.0-only"))]
+    #[case("GPL-2.0", Some("GPL-2.0-only"))]
+    #[case("GPL-3.0+", Some("GPL-3.0-or-later"))]
+    #[case("apache2", Some("Apache-2.0"))]
+    #[case("MIT", None)]
+    #[case("Custom-License", None)]
+    fn test_canonical_spdx(
+        #[case] input: &str,
+        #[case] expected: Option<&str>,
+    ) -> testresult::TestResult<()> {
+        let license = License::from_str(input)?;
+        let canonical = license.canonical_spdx();
+        assert_eq!(
+            canonical.as_ref().map(ToString::to_string).as_deref(),
+            expected
+        );
+        assert!(canonical.is_none_or(|license| license.is_spdx()));
+
+        Ok(())
+    }
 }
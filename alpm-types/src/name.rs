@@ -130,6 +130,7 @@ impl Display for BuildTool {
 ///
 /// [alpm-package-name]: https://alpm.archlinux.page/specifications/alpm-package-name.7.html
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Name(String);
 
 impl Name {
@@ -217,6 +218,7 @@ impl AsRef<str> for Name {
 /// This type wraps a [`Name`] and is used to represent the name of a shared object file
 /// that ends with the `.so` suffix.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SharedObjectName(pub(crate) Name);
 
 impl SharedObjectName {
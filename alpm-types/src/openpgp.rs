@@ -59,6 +59,7 @@ use crate::Error;
 /// # }
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum OpenPGPIdentifier {
     /// An OpenPGP Key ID.
     #[serde(rename = "openpgp_key_id")]
@@ -139,6 +140,7 @@ impl Display for OpenPGPIdentifier {
 /// # }
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct OpenPGPKeyId(String);
 
 impl OpenPGPKeyId {
@@ -232,6 +234,7 @@ impl Display for OpenPGPKeyId {
 /// # }
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct OpenPGPv4Fingerprint(String);
 
 impl OpenPGPv4Fingerprint {
@@ -42,6 +42,7 @@ use crate::{Error, SharedLibraryPrefix};
 /// # }
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AbsolutePath(PathBuf);
 
 impl AbsolutePath {
@@ -154,6 +155,7 @@ pub type StartDirectory = AbsolutePath;
 /// # }
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RelativePath(PathBuf);
 
 impl RelativePath {
@@ -228,6 +230,7 @@ impl Display for RelativePath {
 /// # }
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RelativeFilePath(PathBuf);
 
 impl RelativeFilePath {
@@ -44,10 +44,15 @@ pub enum PackageType {
 /// This type enforces the following invariants on the contained string:
 /// - No leading/trailing spaces
 /// - Tabs and newlines are substituted with spaces.
+/// - Other control characters are removed.
 /// - Multiple, consecutive spaces are substituted with a single space.
 ///
 /// This is a type alias for [`String`].
 ///
+/// A [`PackageDescription`] is always created lossily (e.g. using [`PackageDescription::new`] or
+/// when deserializing), unless [`PackageDescription::new_strict`] is used.
+/// The length of a description can be limited using a [`PackageDescriptionPolicy`].
+///
 /// ## Examples
 ///
 /// ```
@@ -58,9 +63,16 @@ pub enum PackageType {
 /// let description = PackageDescription::from("my special package ");
 ///
 /// assert_eq!(&description.to_string(), "my special package");
+///
+/// // Control characters are removed
+/// let description = PackageDescription::from("my\u{7}\nspecial package");
+///
+/// assert_eq!(&description.to_string(), "my special package");
 /// # }
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(from = "String")]
 pub struct PackageDescription(String);
 
 impl PackageDescription {
@@ -68,6 +80,136 @@ impl PackageDescription {
     pub fn new(description: &str) -> Self {
         Self::from(description)
     }
+
+    /// Creates a new [`PackageDescription`] from `description`, according to `policy`.
+    ///
+    /// Normalizes `description` like [`PackageDescription::new`] and truncates it to the maximum
+    /// length of `policy` (if any).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_types::{PackageDescription, PackageDescriptionPolicy};
+    ///
+    /// let policy = PackageDescriptionPolicy::new().max_length(10);
+    /// let description = PackageDescription::new_lossy("my\tspecial package", &policy);
+    ///
+    /// assert_eq!(description.as_ref(), "my special");
+    /// ```
+    pub fn new_lossy(description: &str, policy: &PackageDescriptionPolicy) -> Self {
+        let mut description = normalize_description(description);
+        if let Some(max_length) = policy.max_length
+            && let Some((index, _)) = description.char_indices().nth(max_length)
+        {
+            description.truncate(index);
+            description.truncate(description.trim_end().len());
+        }
+
+        Self(description)
+    }
+
+    /// Creates a new [`PackageDescription`] from `description`, according to `policy`.
+    ///
+    /// Leading, trailing and consecutive spaces are normalized like with
+    /// [`PackageDescription::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - `description` contains a control character (e.g. a newline or a tab),
+    /// - or the normalized `description` is longer than the maximum length of `policy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alpm_types::{PackageDescription, PackageDescriptionPolicy};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let policy = PackageDescriptionPolicy::new().max_length(20);
+    /// let description = PackageDescription::new_strict(" my  special package", &policy)?;
+    /// assert_eq!(description.as_ref(), "my special package");
+    ///
+    /// assert!(PackageDescription::new_strict("my\nspecial package", &policy).is_err());
+    /// assert!(PackageDescription::new_strict("my very special package", &policy).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_strict(description: &str, policy: &PackageDescriptionPolicy) -> Result<Self, Error> {
+        if let Some(invalid_char) = description.chars().find(|ch| ch.is_control()) {
+            return Err(Error::ValueContainsInvalidChars { invalid_char });
+        }
+
+        let description = normalize_description(description);
+        let length = description.chars().count();
+        if let Some(max_length) = policy.max_length
+            && length > max_length
+        {
+            return Err(Error::PackageDescriptionTooLong { length, max_length });
+        }
+
+        Ok(Self(description))
+    }
+}
+
+/// Normalizes a package description.
+///
+/// Trims leading and trailing whitespace.
+/// Replaces any new lines, tabs and other whitespace control characters with a space.
+/// Removes all other control characters.
+/// Replaces any consecutive spaces with a single space.
+fn normalize_description(value: &str) -> String {
+    let mut description = String::with_capacity(value.len());
+    for ch in value.trim().chars() {
+        let ch = match ch {
+            ch if ch.is_control() && ch.is_whitespace() => ' ',
+            ch if ch.is_control() => continue,
+            ch => ch,
+        };
+        // Skip all spaces at the start and all spaces that follow a space.
+        if ch == ' ' && (description.is_empty() || description.ends_with(' ')) {
+            continue;
+        }
+        description.push(ch);
+    }
+    description.truncate(description.trim_end().len());
+
+    description
+}
+
+/// The policy for the creation of a [`PackageDescription`].
+///
+/// By default, the length of a description is not limited.
+///
+/// ## Examples
+///
+/// ```
+/// use alpm_types::PackageDescriptionPolicy;
+///
+/// let policy = PackageDescriptionPolicy::new().max_length(80);
+/// assert_eq!(policy.get_max_length(), Some(80));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PackageDescriptionPolicy {
+    max_length: Option<usize>,
+}
+
+impl PackageDescriptionPolicy {
+    /// Creates a new [`PackageDescriptionPolicy`] without a maximum length.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum length of a description in characters.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Returns the maximum length of a description in characters, if any.
+    pub fn get_max_length(&self) -> Option<usize> {
+        self.max_length
+    }
 }
 
 impl Default for PackageDescription {
@@ -100,22 +242,19 @@ impl From<&str> for PackageDescription {
     ///
     /// Trims leading and trailing whitespace.
     /// Replaces any new lines and tabs with a space.
+    /// Removes any other control characters.
     /// Replaces any consecutive spaces with a single space.
     fn from(value: &str) -> Self {
-        // Trim front and back and replace unwanted whitespace chars.
-        let mut description = value.trim().replace(['\n', '\r', '\t'], " ");
-
-        // Remove all spaces that follow a space.
-        let mut previous = ' ';
-        description.retain(|ch| {
-            if ch == ' ' && previous == ' ' {
-                return false;
-            };
-            previous = ch;
-            true
-        });
+        Self(normalize_description(value))
+    }
+}
 
-        Self(description)
+impl From<String> for PackageDescription {
+    /// Creates a new [`PackageDescription`] from a [`String`].
+    ///
+    /// Delegates to the [`From`] implementation for [`str`].
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
     }
 }
 
@@ -156,6 +295,21 @@ pub struct ExtraDataEntry {
     value: String,
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ExtraDataEntry {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "ExtraDataEntry".into()
+    }
+
+    /// Returns the JSON Schema of the `key=value` representation of an [`ExtraDataEntry`].
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": "^[^=]+=.+$",
+        })
+    }
+}
+
 impl ExtraDataEntry {
     /// Create a new extra_data
     pub fn new(key: String, value: String) -> Self {
@@ -397,7 +551,57 @@ mod tests {
     #[case("in    between    words", "in between words")]
     #[case("\nsome\t whitespace\n chars\n", "some whitespace chars")]
     #[case("  \neverything\t   combined\n yeah \n   ", "everything combined yeah")]
+    #[case("control\u{0}\u{1b}[31m chars\u{7f}", "control[31m chars")]
+    #[case("\u{b}vertical\u{c}whitespace\r\n", "vertical whitespace")]
     fn package_description(#[case] input: &str, #[case] result: &str) {
         assert_eq!(PackageDescription::new(input).to_string(), result);
     }
+
+    /// Ensures that deserialized descriptions are normalized.
+    #[test]
+    fn package_description_deserialize() -> TestResult {
+        let description: PackageDescription = serde_json::from_str(r#""  a\ndescription\u0007 ""#)?;
+        assert_eq!(description.as_ref(), "a description");
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::unlimited(None, "a long\tdescription", "a long description")]
+    #[case::truncated(Some(6), "a long description", "a long")]
+    #[case::truncated_space(Some(7), "a long description", "a long")]
+    #[case::multibyte(Some(3), "äöüß", "äöü")]
+    #[case::short(Some(100), "short", "short")]
+    fn package_description_lossy(
+        #[case] max_length: Option<usize>,
+        #[case] input: &str,
+        #[case] result: &str,
+    ) {
+        let mut policy = PackageDescriptionPolicy::new();
+        if let Some(max_length) = max_length {
+            policy = policy.max_length(max_length);
+        }
+        assert_eq!(
+            PackageDescription::new_lossy(input, &policy).as_ref(),
+            result
+        );
+    }
+
+    #[rstest]
+    #[case::newline("a\ndescription", Error::ValueContainsInvalidChars { invalid_char: '\n' })]
+    #[case::escape("a \u{1b}description", Error::ValueContainsInvalidChars { invalid_char: '\u{1b}' })]
+    #[case::too_long("a  long description", Error::PackageDescriptionTooLong { length: 18, max_length: 10 })]
+    fn package_description_strict_error(#[case] input: &str, #[case] error: Error) {
+        let policy = PackageDescriptionPolicy::new().max_length(10);
+        assert_eq!(PackageDescription::new_strict(input, &policy), Err(error));
+    }
+
+    #[test]
+    fn package_description_strict() -> TestResult {
+        let policy = PackageDescriptionPolicy::new().max_length(10);
+        assert_eq!(
+            PackageDescription::new_strict("  a    short ", &policy)?.as_ref(),
+            "a short"
+        );
+        Ok(())
+    }
 }
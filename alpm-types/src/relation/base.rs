@@ -30,6 +30,7 @@ use crate::{Error, Name, VersionRequirement};
 ///
 /// [alpm-package-relations]: https://alpm.archlinux.page/specifications/alpm-package-relation.7.html
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PackageRelation {
     /// The name of the package
     pub name: Name,
@@ -227,6 +228,7 @@ impl FromStr for PackageRelation {
 /// [alpm-package-relation]: https://alpm.archlinux.page/specifications/alpm-package-relation.7.html
 /// [optional dependency]: https://alpm.archlinux.page/specifications/alpm-package-relation.7.html#optional-dependency
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct OptionalDependency {
     package_relation: PackageRelation,
     description: Option<String>,
@@ -27,6 +27,7 @@ use crate::{Error, PackageRelation, SonameV1, SonameV2};
 /// [alpm-db-desc]: https://alpm.archlinux.page/specifications/alpm-db-desc.5.html
 /// [alpm-package-relations]: https://alpm.archlinux.page/specifications/alpm-package-relation.7.html
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum RelationOrSoname {
     /// A package relation (as [`PackageRelation`]).
@@ -41,6 +42,24 @@ pub enum RelationOrSoname {
     SonameV2(SonameV2),
 }
 
+impl From<PackageRelation> for RelationOrSoname {
+    fn from(value: PackageRelation) -> Self {
+        RelationOrSoname::Relation(value)
+    }
+}
+
+impl From<SonameV1> for RelationOrSoname {
+    fn from(value: SonameV1) -> Self {
+        RelationOrSoname::SonameV1(value)
+    }
+}
+
+impl From<SonameV2> for RelationOrSoname {
+    fn from(value: SonameV2) -> Self {
+        RelationOrSoname::SonameV2(value)
+    }
+}
+
 impl PartialEq<PackageRelation> for RelationOrSoname {
     fn eq(&self, other: &PackageRelation) -> bool {
         self.to_string() == other.to_string()
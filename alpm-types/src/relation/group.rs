@@ -0,0 +1,147 @@
+//! Lookup of the members of package groups.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{Group, Name};
+
+/// A lookup of the member packages of [`Group`]s.
+///
+/// Collects the group memberships of packages (e.g. from the metadata of a package repository)
+/// and allows resolving a [`Group`] into the set of its member packages.
+///
+/// # Examples
+///
+/// ```
+/// use alpm_types::{GroupLookup, Name};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let mut lookup = GroupLookup::new();
+/// lookup.insert(Name::new("gcc")?, ["base-devel".to_string()]);
+/// lookup.insert(Name::new("make")?, ["base-devel".to_string()]);
+/// lookup.insert(Name::new("vim")?, ["editors".to_string()]);
+///
+/// assert!(lookup.is_member("base-devel", &Name::new("gcc")?));
+/// assert_eq!(
+///     lookup.members("base-devel").map(|members| members.len()),
+///     Some(2)
+/// );
+///
+/// // Members of a group can be opted out of when resolving the group.
+/// let resolved = lookup.resolve("base-devel", &[Name::new("gcc")?]);
+/// assert_eq!(resolved, Some([Name::new("make")?].into()));
+///
+/// // Unknown groups can not be resolved.
+/// assert_eq!(lookup.resolve("unknown", &[]), None);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GroupLookup {
+    groups: BTreeMap<Group, BTreeSet<Name>>,
+}
+
+impl GroupLookup {
+    /// Creates a new, empty [`GroupLookup`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the package `name` as member of each of the `groups`.
+    pub fn insert(&mut self, name: Name, groups: impl IntoIterator<Item = Group>) {
+        for group in groups {
+            self.groups.entry(group).or_default().insert(name.clone());
+        }
+    }
+
+    /// Returns the names of all known groups, sorted alphabetically.
+    pub fn groups(&self) -> impl Iterator<Item = &Group> {
+        self.groups.keys()
+    }
+
+    /// Returns the member packages of `group`.
+    ///
+    /// Returns [`None`] if `group` is unknown.
+    pub fn members(&self, group: &str) -> Option<&BTreeSet<Name>> {
+        self.groups.get(group)
+    }
+
+    /// Returns the groups that the package `name` is a member of.
+    pub fn groups_of<'a>(&'a self, name: &'a Name) -> impl Iterator<Item = &'a Group> {
+        self.groups
+            .iter()
+            .filter(move |(_, members)| members.contains(name))
+            .map(|(group, _)| group)
+    }
+
+    /// Returns `true` if the package `name` is a member of `group`.
+    pub fn is_member(&self, group: &str, name: &Name) -> bool {
+        self.members(group)
+            .is_some_and(|members| members.contains(name))
+    }
+
+    /// Resolves `group` into the set of its member packages.
+    ///
+    /// All packages in `excluded` are opted out of and are not part of the returned set.
+    ///
+    /// Returns [`None`] if `group` is unknown.
+    pub fn resolve(&self, group: &str, excluded: &[Name]) -> Option<BTreeSet<Name>> {
+        self.members(group).map(|members| {
+            members
+                .iter()
+                .filter(|member| !excluded.contains(member))
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Returns the number of known groups.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns `true` if there are no known groups.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+}
+
+impl<G: IntoIterator<Item = Group>> FromIterator<(Name, G)> for GroupLookup {
+    /// Creates a [`GroupLookup`] from pairs of package names and the groups they are a member of.
+    fn from_iter<T: IntoIterator<Item = (Name, G)>>(iter: T) -> Self {
+        let mut lookup = Self::new();
+        for (name, groups) in iter {
+            lookup.insert(name, groups);
+        }
+        lookup
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that the groups of a package are returned.
+    #[test]
+    fn group_lookup_groups_of() -> TestResult {
+        let lookup: GroupLookup = [
+            (Name::new("gcc")?, vec!["base-devel".to_string()]),
+            (
+                Name::new("make")?,
+                vec!["base-devel".to_string(), "build".to_string()],
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let make = Name::new("make")?;
+        assert_eq!(
+            lookup.groups_of(&make).collect::<Vec<_>>(),
+            vec!["base-devel", "build"]
+        );
+        assert_eq!(lookup.len(), 2);
+        assert!(!lookup.is_member("build", &Name::new("gcc")?));
+        Ok(())
+    }
+}
@@ -2,8 +2,10 @@
 
 mod base;
 mod composite;
+mod group;
 mod soname;
 
 pub use base::{Group, OptionalDependency, PackageRelation};
 pub use composite::RelationOrSoname;
+pub use group::GroupLookup;
 pub use soname::{SharedLibraryPrefix, Soname, SonameV1, SonameV2, VersionOrSoname};
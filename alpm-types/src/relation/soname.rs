@@ -139,6 +139,7 @@ impl Display for VersionOrSoname {
 /// [SRCINFO]: https://alpm.archlinux.page/specifications/SRCINFO.5.html
 /// [PKGINFO]: https://alpm.archlinux.page/specifications/PKGINFO.5.html
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum SonameV1 {
     /// Basic representation of a _shared object_ file.
     ///
@@ -512,6 +513,7 @@ pub type SharedLibraryPrefix = Name;
 /// The _soname_ data may contain only a shared object name (e.g. `libexample.so`) or a shared
 /// object name, that also encodes version information (e.g. `libexample.so.1`).
 #[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Soname {
     /// The name part of a shared object's _soname_.
     pub name: SharedObjectName,
@@ -661,6 +663,7 @@ impl FromStr for Soname {
 /// [`PackageInfoV1`]: https://docs.rs/alpm_pkginfo/latest/alpm_pkginfo/struct.PackageInfoV1.html
 /// [`PackageInfoV2`]: https://docs.rs/alpm_pkginfo/latest/alpm_pkginfo/struct.PackageInfoV2.html
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SonameV2 {
     /// The directory prefix of the shared object file.
     pub prefix: SharedLibraryPrefix,
@@ -12,6 +12,7 @@ use crate::{Error, SourceUrl};
 ///
 /// It can be either a local file (next to the PKGBUILD) or a URL.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type")]
 pub enum Source {
     /// A local file source.
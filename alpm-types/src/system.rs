@@ -61,6 +61,7 @@ use crate::Error;
     Serialize,
     VariantNames,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[strum(serialize_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
 pub enum SystemArchitecture {
@@ -151,6 +152,7 @@ impl FromStr for SystemArchitecture {
 ///
 /// [alpm-architecture]: https://alpm.archlinux.page/specifications/alpm-architecture.7.html
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UnknownArchitecture(String);
 
 impl UnknownArchitecture {
@@ -292,6 +294,7 @@ impl AsRef<str> for UnknownArchitecture {
     Serialize,
     VariantNames,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[strum(serialize_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
 pub enum Architecture {
@@ -360,6 +363,7 @@ impl From<SystemArchitecture> for Architecture {
     Serialize,
     VariantNames,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[strum(serialize_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
 pub enum Architectures {
@@ -523,6 +527,7 @@ impl TryFrom<Vec<Architecture>> for Architectures {
 #[derive(
     Clone, Copy, Debug, Deserialize, Display, EnumString, Eq, Ord, PartialEq, PartialOrd, Serialize,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[strum(serialize_all = "lowercase")]
 pub enum ElfArchitectureFormat {
     /// 32-bit
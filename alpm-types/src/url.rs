@@ -43,7 +43,8 @@ use crate::Error;
 /// # }
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-pub struct Url(url::Url);
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Url(#[cfg_attr(feature = "schemars", schemars(with = "String"))] url::Url);
 
 impl Url {
     /// Creates a new `Url` instance.
@@ -152,6 +153,7 @@ impl Display for Url {
 /// # }
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SourceUrl {
     /// The URL from where the sources are retrieved.
     pub url: Url,
@@ -323,6 +325,7 @@ impl SourceUrl {
 /// Each system supports addressing different types of objects and may optionally require signature
 /// verification for those objects.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "protocol", rename_all = "lowercase")]
 pub enum VcsInfo {
     /// Bazaar/Breezy VCS information.
@@ -355,6 +358,45 @@ pub enum VcsInfo {
 }
 
 impl VcsInfo {
+    /// Returns `true` if the VCS information pins a specific revision of the sources.
+    ///
+    /// Sources are considered pinned, if their URL fragment refers to a commit, revision or tag.
+    /// Sources without URL fragment or with a URL fragment that refers to a branch always track
+    /// the latest state of the respective branch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::SourceUrl;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let url = SourceUrl::from_str("git+https://example.org/project.git#tag=v1.0.0")?;
+    /// assert!(url.vcs_info.is_some_and(|vcs_info| vcs_info.is_pinned()));
+    ///
+    /// let url = SourceUrl::from_str("git+https://example.org/project.git#branch=main")?;
+    /// assert!(url.vcs_info.is_some_and(|vcs_info| !vcs_info.is_pinned()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_pinned(&self) -> bool {
+        match self {
+            VcsInfo::Bzr { fragment } => fragment.is_some(),
+            VcsInfo::Fossil { fragment } => matches!(
+                fragment,
+                Some(FossilFragment::Commit(_) | FossilFragment::Tag(_))
+            ),
+            VcsInfo::Git { fragment, .. } => {
+                matches!(fragment, Some(GitFragment::Commit(_) | GitFragment::Tag(_)))
+            }
+            VcsInfo::Hg { fragment } => {
+                matches!(fragment, Some(HgFragment::Revision(_) | HgFragment::Tag(_)))
+            }
+            VcsInfo::Svn { fragment } => fragment.is_some(),
+        }
+    }
+
     /// Recognizes VCS-specific URL fragment and query based on a [`VcsProtocol`].
     ///
     /// As the parser is parameterized due to the earlier detected [`VcsProtocol`], it returns a
@@ -469,6 +511,7 @@ fn fragment_value(input: &mut &str) -> ModalResult<String> {
 
 /// The available URL fragments and their values when using the Breezy VCS in a [`SourceUrl`].
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum BzrFragment {
     /// A specific revision in the repository.
@@ -507,6 +550,7 @@ impl BzrFragment {
 
 /// The available URL fragments and their values when using the Fossil VCS in a [`SourceUrl`].
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum FossilFragment {
     /// A specific branch in the repository.
@@ -556,6 +600,7 @@ impl FossilFragment {
 
 /// The available URL fragments and their values when using the Git VCS in a [`SourceUrl`].
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum GitFragment {
     /// A specific branch in the repository.
@@ -613,6 +658,7 @@ fn git_query(input: &mut &str) -> ModalResult<bool> {
 
 /// An optional version specification used in a [`SourceUrl`] for the Hg VCS.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum HgFragment {
     /// A specific branch in the repository.
@@ -662,6 +708,7 @@ impl HgFragment {
 
 /// The available URL fragments and their values when using Apache Subversion in a [`SourceUrl`].
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum SvnFragment {
     /// A specific revision in the repository.
@@ -833,6 +880,30 @@ mod tests {
         Ok(())
     }
 
+    /// Ensure that only VCS information referring to commits, revisions or tags is pinned.
+    #[rstest]
+    #[case("bzr+https://example/project", false)]
+    #[case("bzr+https://example/project#revision=2", true)]
+    #[case("fossil+https://example/project#branch=feature", false)]
+    #[case("fossil+https://example/project#commit=154021a", true)]
+    #[case("git+https://example/project", false)]
+    #[case("git+https://example/project#branch=feature", false)]
+    #[case("git+https://example/project#commit=154021a", true)]
+    #[case("git+https://example/project?signed#tag=v1.0.0", true)]
+    #[case("hg+https://example/project#branch=feature", false)]
+    #[case("hg+https://example/project#revision=154021a", true)]
+    #[case("svn+https://example/project", false)]
+    #[case("svn+https://example/project#revision=42", true)]
+    fn test_vcs_info_is_pinned(#[case] input: &str, #[case] pinned: bool) -> TestResult {
+        let source_url = SourceUrl::from_str(input)?;
+        let Some(vcs_info) = source_url.vcs_info else {
+            panic!("'{input}' should contain VCS information");
+        };
+        assert_eq!(vcs_info.is_pinned(), pinned);
+
+        Ok(())
+    }
+
     /// Run the parser for SourceUrl and ensure that the expected parse error messages show up.
     #[rstest]
     #[case("git+https://example/project#revision=v1.0.0?signed")]
@@ -49,6 +49,7 @@ use crate::{Error, VersionSegments};
 ///
 /// [alpm-epoch]: https://alpm.archlinux.page/specifications/alpm-epoch.7.html
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Epoch(pub usize);
 
 impl Epoch {
@@ -113,6 +114,7 @@ impl Display for Epoch {
 ///
 /// [alpm-pkgrel]: https://alpm.archlinux.page/specifications/alpm-pkgrel.7.html
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PackageRelease {
     /// The major version of this package release.
     pub major: usize,
@@ -235,6 +237,7 @@ impl Ord for PackageRelease {
 /// assert!(PackageVersion::new("1<0".to_string()).is_err());
 /// ```
 #[derive(Clone, Debug, Deserialize, Eq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PackageVersion(pub(crate) String);
 
 impl PackageVersion {
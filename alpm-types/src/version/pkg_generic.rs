@@ -48,6 +48,7 @@ use crate::{FullVersion, MinimalVersion};
 ///
 /// [alpm-package-version]: https://alpm.archlinux.page/specifications/alpm-package-version.7.html
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Version {
     /// The version of the package
     pub pkgver: PackageVersion,
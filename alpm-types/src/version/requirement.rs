@@ -43,6 +43,7 @@ use crate::{Error, Version};
 ///
 /// [alpm-comparison]: https://alpm.archlinux.page/specifications/alpm-comparison.7.html
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct VersionRequirement {
     /// Version comparison function
     pub comparison: VersionComparison,
@@ -372,6 +373,7 @@ impl FromStr for VersionRequirement {
     Serialize,
     Deserialize,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum VersionComparison {
     /// Less than or equal to
     #[strum(to_string = "<=")]
@@ -3,7 +3,10 @@ use std::{fmt::Display, path::PathBuf};
 use alpm_types::{MetadataFileName, PKGBUILD_FILE_NAME, SRCINFO_FILE_NAME};
 use clap::{Parser, ValueEnum};
 
-use crate::sync::PackageRepositories;
+use crate::sync::{
+    PackageRepositories,
+    mirror::{MirrorBackend, SignaturePolicy},
+};
 
 #[derive(Debug, Parser)]
 #[clap(name = "dev-scripts", about = "Dev scripts for the ALPM project")]
@@ -102,7 +105,56 @@ pub enum TestFilesCmd {
         #[arg(short, long)]
         repositories: Option<Vec<PackageRepositories>>,
 
+        /// Skip files of packages that have been validated successfully before.
+        ///
+        /// The validation results are tracked per package in the `metadata/extraction` directory
+        /// of the cache and are reset whenever a package is extracted again.
+        /// Only applies to "build-info", "package-info" and "m-tree" files.
+        #[arg(long)]
+        skip_validated: bool,
+
+        /// The type of file that should be tested.
+        file_type: TestFileType,
+    },
+
+    /// Run robustness tests against corrupted versions of a specific file type.
+    ///
+    /// Each file is systematically corrupted (truncation, bit flips, encoding changes and
+    /// injection of huge lines) and handed to the respective parser.
+    /// The test fails if a parser panics or hangs, in which case the corrupted data is written to
+    /// the output directory.
+    ///
+    /// The required data needs to be downloaded up front using "dev-scripts test-files download".
+    Corrupt {
+        /// Package repositories to test.
+        ///
+        /// If not set, all official repositories are tested.
+        #[arg(short, long)]
+        repositories: Option<Vec<PackageRepositories>>,
+
+        /// The seed for the pseudo-random corruptions.
+        ///
+        /// Runs with the same seed and data produce the same corruptions.
+        #[arg(short, long, default_value_t = 1)]
+        seed: u64,
+
+        /// The number of corrupted variants per file and type of corruption.
+        #[arg(short, long, default_value_t = 4)]
+        iterations: usize,
+
+        /// The number of seconds after which a parser is considered to hang.
+        #[arg(short, long, default_value_t = 10, value_name = "SECONDS")]
+        timeout: u64,
+
+        /// The directory to which corrupted data that triggers a panic or hang is written.
+        ///
+        /// If unset, defaults to "corruption" in the cache directory.
+        #[arg(short, long, value_name = "DIR")]
+        output_dir: Option<PathBuf>,
+
         /// The type of file that should be tested.
+        ///
+        /// Only "build-info", "src-info", "package-info" and "m-tree" are supported.
         file_type: TestFileType,
     },
 
@@ -157,7 +209,7 @@ pub enum DownloadCmd {
     Databases {
         /// The domain + base path under which the mirror can be found.
         ///
-        /// The mirror must support the `rsync` protocol
+        /// The mirror must support the protocol of the selected `backend`.
         #[arg(short, long, env, default_value = "mirror.pseudoform.org/packages")]
         mirror: String,
 
@@ -167,6 +219,21 @@ pub enum DownloadCmd {
         /// `rsync` will not report changes for files that it downloaded last time.
         #[arg(short, long, default_value_t = false)]
         force_extract: bool,
+
+        /// The backend used for downloading files from the mirror.
+        #[arg(long, default_value_t = MirrorBackend::default())]
+        backend: MirrorBackend,
+
+        /// The maximum number of concurrent downloads when using the `http` backend.
+        #[arg(long, default_value_t = 8)]
+        concurrency: usize,
+
+        /// The maximum number of package repositories that are synchronized in parallel.
+        ///
+        /// The progress of all repositories is shown at once and the errors of all failed
+        /// repositories are reported together.
+        #[arg(long, default_value_t = 1)]
+        repository_concurrency: usize,
     },
     /// The packages contain the following file types for each package.
     /// - `.INSTALL`
@@ -176,7 +243,7 @@ pub enum DownloadCmd {
     Packages {
         /// The domain + base path under which the mirror can be found.
         ///
-        /// The mirror must support the `rsync` protocol
+        /// The mirror must support the protocol of the selected `backend`.
         #[arg(short, long, env, default_value = "mirror.pseudoform.org/packages")]
         mirror: String,
 
@@ -186,6 +253,31 @@ pub enum DownloadCmd {
         /// `rsync` will not report changes for files that it downloaded last time.
         #[arg(short, long, default_value_t = false)]
         force_extract: bool,
+
+        /// The backend used for downloading files from the mirror.
+        #[arg(long, default_value_t = MirrorBackend::default())]
+        backend: MirrorBackend,
+
+        /// The maximum number of concurrent downloads when using the `http` backend.
+        #[arg(long, default_value_t = 8)]
+        concurrency: usize,
+
+        /// The maximum number of package repositories that are synchronized in parallel.
+        ///
+        /// The progress of all repositories is shown at once and the errors of all failed
+        /// repositories are reported together.
+        #[arg(long, default_value_t = 1)]
+        repository_concurrency: usize,
+
+        /// Verify the signature of each downloaded package before extracting it.
+        ///
+        /// The signatures are verified using the OpenPGP verifiers for packages in the VOA
+        /// hierarchy. The policy decides whether to fail, skip the extraction of packages, or only
+        /// emit a warning, if a signature cannot be verified.
+        /// The results are recorded per package in the `metadata/signatures` directory of the
+        /// cache.
+        #[arg(long)]
+        verify_signatures: Option<SignaturePolicy>,
     },
 }
 
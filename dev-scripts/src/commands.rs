@@ -2,6 +2,7 @@ use std::{
     fs::{remove_dir_all, write},
     path::PathBuf,
     process::exit,
+    time::Duration,
 };
 
 use alpm_common::MetadataFile;
@@ -14,7 +15,8 @@ use crate::{
     CacheDir,
     Error,
     cli::{CleanCmd, DownloadCmd, TestFilesCmd},
-    consts::{DATABASES_DIR, DOWNLOAD_DIR, PACKAGES_DIR, PKGSRC_DIR},
+    consts::{CORRUPTION_DIR, DATABASES_DIR, DOWNLOAD_DIR, PACKAGES_DIR, PKGSRC_DIR},
+    corruption::CorruptionRunner,
     sync::{
         PackageRepositories,
         aur::AurDownloader,
@@ -30,11 +32,13 @@ use crate::{
 /// This function relegates to functions that:
 /// - Download packages.
 /// - Test file parsers on all files.
+/// - Test file parsers on corrupted versions of all files.
 /// - Clean up downloaded files.
 pub(crate) fn test_files(cmd: TestFilesCmd, cache_dir: CacheDir) -> Result<(), Error> {
     match cmd {
         TestFilesCmd::Test {
             repositories,
+            skip_validated,
             file_type,
         } => {
             let repositories = PackageRepositories::iter()
@@ -44,6 +48,30 @@ pub(crate) fn test_files(cmd: TestFilesCmd, cache_dir: CacheDir) -> Result<(), E
                 cache_dir,
                 file_type,
                 repositories,
+                skip_validated,
+            };
+            runner.run_tests()?;
+        }
+        TestFilesCmd::Corrupt {
+            repositories,
+            seed,
+            iterations,
+            timeout,
+            output_dir,
+            file_type,
+        } => {
+            let repositories = PackageRepositories::iter()
+                .filter(|v| repositories.clone().is_none_or(|r| r.contains(v)))
+                .collect();
+            let output_dir = output_dir.unwrap_or_else(|| cache_dir.as_ref().join(CORRUPTION_DIR));
+            let runner = CorruptionRunner {
+                cache_dir,
+                file_type,
+                repositories,
+                seed,
+                iterations,
+                timeout: Duration::from_secs(timeout),
+                output_dir,
             };
             runner.run_tests()?;
         }
@@ -67,12 +95,19 @@ pub(crate) fn test_files(cmd: TestFilesCmd, cache_dir: CacheDir) -> Result<(), E
                 DownloadCmd::Databases {
                     mirror,
                     force_extract,
+                    backend,
+                    concurrency,
+                    repository_concurrency,
                 } => {
                     let downloader = MirrorDownloader {
                         cache_dir,
                         mirror,
                         repositories,
                         extract_all: force_extract,
+                        backend,
+                        concurrency,
+                        repository_concurrency,
+                        signature_policy: None,
                     };
                     warn!(
                         "Beginning database retrieval\nIf the process is unexpectedly halted, rerun with `--force-extract` flag"
@@ -82,12 +117,20 @@ pub(crate) fn test_files(cmd: TestFilesCmd, cache_dir: CacheDir) -> Result<(), E
                 DownloadCmd::Packages {
                     mirror,
                     force_extract,
+                    backend,
+                    concurrency,
+                    repository_concurrency,
+                    verify_signatures,
                 } => {
                     let downloader = MirrorDownloader {
                         cache_dir,
                         mirror,
                         repositories,
                         extract_all: force_extract,
+                        backend,
+                        concurrency,
+                        repository_concurrency,
+                        signature_policy: verify_signatures,
                     };
                     warn!(
                         "Beginning package retrieval\nIf the process is unexpectedly halted, rerun with `--force-extract` flag"
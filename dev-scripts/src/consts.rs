@@ -16,3 +16,11 @@ pub(crate) const PACKAGES_DIR: &str = "packages";
 /// The name of the directory component for repository databases - either downloads or other
 /// artifacts ("databases").
 pub(crate) const DATABASES_DIR: &str = "databases";
+/// The name of the directory for corrupted data that triggers defects in parsers ("corruption").
+pub(crate) const CORRUPTION_DIR: &str = "corruption";
+/// The name of the directory for metadata about cached artifacts ("metadata").
+pub(crate) const METADATA_DIR: &str = "metadata";
+/// The name of the directory component for package signature verification records ("signatures").
+pub(crate) const SIGNATURES_DIR: &str = "signatures";
+/// The name of the directory component for package extraction state files ("extraction").
+pub(crate) const EXTRACTION_DIR: &str = "extraction";
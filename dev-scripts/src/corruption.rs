@@ -0,0 +1,408 @@
+//! Robustness tests of parsers against corrupted versions of downloaded artifacts.
+
+use std::{
+    fs::{create_dir_all, read, write},
+    io::Read,
+    path::{Path, PathBuf},
+    sync::mpsc::{RecvTimeoutError, channel},
+    thread,
+    time::Duration,
+};
+
+use alpm_buildinfo::BuildInfo;
+use alpm_common::MetadataFile;
+use alpm_mtree::Mtree;
+use alpm_pkginfo::PackageInfo;
+use alpm_srcinfo::SourceInfo;
+use flate2::read::GzDecoder;
+use log::{debug, info};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use strum::IntoEnumIterator;
+
+use crate::{
+    CacheDir,
+    Error,
+    cli::TestFileType,
+    sync::PackageRepositories,
+    testing::TestRunner,
+    ui::get_progress_bar,
+};
+
+/// The gzip magic number, with which compressed ALPM-MTREE files start.
+const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+
+/// The length of the line injected by [`Corruption::HugeLine`] (16 MiB).
+const HUGE_LINE_LENGTH: usize = 16 * 1024 * 1024;
+
+/// A systematic corruption of file contents.
+#[derive(Clone, Copy, Debug, strum::Display, strum::EnumIter, Eq, PartialEq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Corruption {
+    /// Truncates the data at a random offset.
+    Truncation,
+    /// Flips random bits in the data.
+    BitFlip,
+    /// Re-encodes the data from UTF-8 to UTF-16 (little endian).
+    Encoding,
+    /// Injects a huge line at a random line boundary of the data.
+    HugeLine,
+}
+
+impl Corruption {
+    /// Returns a copy of `data` with the corruption applied, using `rng` as source of randomness.
+    pub fn apply(&self, data: &[u8], rng: &mut XorShift) -> Vec<u8> {
+        match self {
+            Self::Truncation => data[..rng.below(data.len())].to_vec(),
+            Self::BitFlip => {
+                let mut data = data.to_vec();
+                if !data.is_empty() {
+                    for _ in 0..=data.len() / 64 {
+                        let index = rng.below(data.len());
+                        data[index] ^= 1 << rng.below(8);
+                    }
+                }
+                data
+            }
+            Self::Encoding => String::from_utf8_lossy(data)
+                .encode_utf16()
+                .flat_map(u16::to_le_bytes)
+                .collect(),
+            Self::HugeLine => {
+                let boundaries: Vec<usize> = std::iter::once(0)
+                    .chain(
+                        data.iter()
+                            .enumerate()
+                            .filter(|(_, byte)| **byte == b'\n')
+                            .map(|(index, _)| index + 1),
+                    )
+                    .collect();
+                let offset = boundaries[rng.below(boundaries.len())];
+
+                let mut corrupted = Vec::with_capacity(data.len() + HUGE_LINE_LENGTH + 1);
+                corrupted.extend_from_slice(&data[..offset]);
+                corrupted.extend(std::iter::repeat_n(b'a', HUGE_LINE_LENGTH));
+                corrupted.push(b'\n');
+                corrupted.extend_from_slice(&data[offset..]);
+                corrupted
+            }
+        }
+    }
+}
+
+/// A minimal, seedable xorshift pseudo-random number generator.
+///
+/// Corruptions are meant to be reproducible for a given seed, which is why no system randomness
+/// is used.
+#[derive(Clone, Debug)]
+pub struct XorShift(u64);
+
+impl XorShift {
+    /// Creates a new [`XorShift`] from a `seed`.
+    pub fn new(seed: u64) -> Self {
+        // The state of a xorshift generator must never be zero.
+        Self(seed.max(1))
+    }
+
+    /// Returns the next pseudo-random number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a pseudo-random number in the range `0..max`, or `0` if `max` is `0`.
+    pub fn below(&mut self, max: usize) -> usize {
+        if max == 0 {
+            return 0;
+        }
+        (self.next_u64() % max as u64) as usize
+    }
+}
+
+/// The outcome of running a parser on corrupted data, which indicates a defect in the parser.
+#[derive(Clone, Copy, Debug, strum::Display, Eq, PartialEq)]
+#[strum(serialize_all = "kebab-case")]
+enum Defect {
+    /// The parser panicked.
+    Panic,
+    /// The parser did not finish within the timeout.
+    Hang,
+}
+
+/// Runs the parser for `file_type` on `data`.
+///
+/// Returns whether parsing succeeded.
+/// Errors of the parser are expected for corrupted data and are therefore not returned.
+fn parse(file_type: TestFileType, data: &[u8]) -> bool {
+    match file_type {
+        TestFileType::BuildInfo => BuildInfo::from_reader_with_schema(data, None).is_ok(),
+        TestFileType::SrcInfo => SourceInfo::from_reader_with_schema(data, None).is_ok(),
+        TestFileType::PackageInfo => PackageInfo::from_reader_with_schema(data, None).is_ok(),
+        TestFileType::MTree => Mtree::from_reader_with_schema(data, None).is_ok(),
+        TestFileType::RemoteDesc
+        | TestFileType::RemoteFiles
+        | TestFileType::LocalDesc
+        | TestFileType::LocalFiles
+        | TestFileType::Signatures => unreachable!("unsupported file types are rejected early"),
+    }
+}
+
+/// Runs the parser for `file_type` on `data` in a separate thread.
+///
+/// Returns a [`Defect`] if the parser panics or does not finish within `timeout`.
+/// A parser that does not finish is left running in the background.
+fn check(file_type: TestFileType, data: Vec<u8>, timeout: Duration) -> Option<Defect> {
+    let (sender, receiver) = channel();
+    thread::spawn(move || {
+        let parsed = parse(file_type, &data);
+        // The receiver is gone if the timeout has been reached, which is reported already.
+        let _ = sender.send(parsed);
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(parsed) => {
+            debug!("Parsing corrupted data finished (success: {parsed})");
+            None
+        }
+        Err(RecvTimeoutError::Timeout) => Some(Defect::Hang),
+        Err(RecvTimeoutError::Disconnected) => Some(Defect::Panic),
+    }
+}
+
+/// Reads the contents of `file`.
+///
+/// Compressed ALPM-MTREE files are decompressed, so that corruptions target the parser instead of
+/// the decompression.
+///
+/// # Errors
+///
+/// Returns an error if `file` cannot be read or decompressed.
+fn read_contents(file: &Path) -> Result<Vec<u8>, Error> {
+    let data = read(file).map_err(|source| Error::IoPath {
+        path: file.to_path_buf(),
+        context: "reading the file".to_string(),
+        source,
+    })?;
+    if !data.starts_with(&GZIP_MAGIC_NUMBER) {
+        return Ok(data);
+    }
+
+    let mut decompressed = Vec::new();
+    GzDecoder::new(data.as_slice())
+        .read_to_end(&mut decompressed)
+        .map_err(|source| Error::IoPath {
+            path: file.to_path_buf(),
+            context: "decompressing the file".to_string(),
+            source,
+        })?;
+    Ok(decompressed)
+}
+
+/// Writes the corrupted `data` to `path`, creating parent directories if needed.
+///
+/// # Errors
+///
+/// Returns an error if the parent directory cannot be created or the file cannot be written.
+fn write_corrupted_data(path: &Path, data: &[u8]) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).map_err(|source| Error::IoPath {
+            path: parent.to_path_buf(),
+            context: "creating the output directory for corrupted data".to_string(),
+            source,
+        })?;
+    }
+    write(path, data).map_err(|source| Error::IoPath {
+        path: path.to_path_buf(),
+        context: "writing corrupted data".to_string(),
+        source,
+    })
+}
+
+/// The entry point for running robustness tests of parsers against corrupted ALPM metadata files.
+///
+/// Each downloaded file of the targeted type is corrupted using each [`Corruption`] and handed to
+/// the respective parser.
+/// Parsers are expected to return errors for corrupted data, but must neither panic nor hang.
+/// Corrupted data that triggers a panic or a hang is written to an output directory, so that it
+/// can be used for reproducing the issue (e.g. as part of a fuzzing corpus).
+#[derive(Clone, Debug)]
+pub struct CorruptionRunner {
+    /// The directory in which test data is stored.
+    pub cache_dir: CacheDir,
+    /// The type of file that is targeted in the test.
+    pub file_type: TestFileType,
+    /// The list of repositories against which the test runs.
+    pub repositories: Vec<PackageRepositories>,
+    /// The seed for the pseudo-random corruptions.
+    pub seed: u64,
+    /// The number of corrupted variants created per file and [`Corruption`].
+    pub iterations: usize,
+    /// The time after which a parser is considered to hang.
+    pub timeout: Duration,
+    /// The directory to which corrupted data triggering defects is written.
+    pub output_dir: PathBuf,
+}
+
+impl CorruptionRunner {
+    /// Run robustness tests on all local test files that have been downloaded via the
+    /// `test-files download` command.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the file type is not supported,
+    /// - the test files cannot be found or read,
+    /// - corrupted data triggering a defect cannot be written to the output directory,
+    /// - or any parser panics or hangs.
+    pub fn run_tests(&self) -> Result<(), Error> {
+        if !matches!(
+            self.file_type,
+            TestFileType::BuildInfo
+                | TestFileType::SrcInfo
+                | TestFileType::PackageInfo
+                | TestFileType::MTree
+        ) {
+            return Err(Error::UnsupportedFileType {
+                file_type: self.file_type,
+            });
+        }
+
+        let test_files = TestRunner {
+            cache_dir: self.cache_dir.clone(),
+            file_type: self.file_type,
+            repositories: self.repositories.clone(),
+            skip_validated: false,
+        }
+        .find_files_of_type()?;
+        info!(
+            "Found {} {} files for corruption tests",
+            test_files.len(),
+            self.file_type
+        );
+
+        let progress_bar = get_progress_bar(test_files.len() as u64);
+
+        let results: Vec<Result<Vec<(PathBuf, String)>, Error>> = test_files
+            .into_par_iter()
+            .enumerate()
+            .map(|(index, file)| {
+                let result = self.test_file(index, &file);
+                progress_bar.inc(1);
+                result
+            })
+            .collect();
+
+        progress_bar.finish_with_message("Corruption run finished.");
+
+        let mut failures = Vec::new();
+        for result in results {
+            failures.extend(result?);
+        }
+
+        if !failures.is_empty() {
+            return Err(Error::TestFailed {
+                failures: failures
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, (path, message))| (index, path, message))
+                    .collect(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Runs all corruptions for the `file` with the `index` in the list of test files.
+    ///
+    /// Returns the paths of the written corrupted data and a description of the defect for each
+    /// defect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `file` cannot be read or if corrupted data cannot be written.
+    fn test_file(&self, index: usize, file: &Path) -> Result<Vec<(PathBuf, String)>, Error> {
+        let data = read_contents(file)?;
+        // Derive a distinct, but reproducible stream of corruptions for each file.
+        let mut rng = XorShift::new(self.seed ^ (index as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+
+        let mut failures = Vec::new();
+        for corruption in Corruption::iter() {
+            for iteration in 0..self.iterations {
+                let corrupted = corruption.apply(&data, &mut rng);
+                let Some(defect) = check(self.file_type, corrupted.clone(), self.timeout) else {
+                    continue;
+                };
+
+                let output_path = self
+                    .output_dir
+                    .join(self.file_type.to_string())
+                    .join(format!(
+                        "{defect}-{corruption}-{index}-{iteration}-{}",
+                        self.seed
+                    ));
+                write_corrupted_data(&output_path, &corrupted)?;
+                failures.push((
+                    output_path,
+                    format!("The parser reported a {defect} for {corruption} of {file:?}"),
+                ));
+            }
+        }
+
+        Ok(failures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const DATA: &[u8] = b"pkgname = example\npkgver = 1.0.0-1\n";
+
+    /// Ensures that corruptions change the data in the expected way.
+    #[rstest]
+    #[case::truncation(Corruption::Truncation)]
+    #[case::bit_flip(Corruption::BitFlip)]
+    #[case::encoding(Corruption::Encoding)]
+    #[case::huge_line(Corruption::HugeLine)]
+    fn corruption_apply(#[case] corruption: Corruption) {
+        let corrupted = corruption.apply(DATA, &mut XorShift::new(1));
+        assert_ne!(corrupted, DATA);
+
+        match corruption {
+            Corruption::Truncation => assert!(DATA.starts_with(&corrupted)),
+            Corruption::BitFlip | Corruption::Encoding => {}
+            Corruption::HugeLine => assert_eq!(corrupted.len(), DATA.len() + HUGE_LINE_LENGTH + 1),
+        }
+    }
+
+    /// Ensures that corruptions are reproducible for a seed and can handle empty data.
+    #[rstest]
+    #[case::truncation(Corruption::Truncation)]
+    #[case::bit_flip(Corruption::BitFlip)]
+    #[case::encoding(Corruption::Encoding)]
+    #[case::huge_line(Corruption::HugeLine)]
+    fn corruption_reproducible(#[case] corruption: Corruption) {
+        assert_eq!(
+            corruption.apply(DATA, &mut XorShift::new(42)),
+            corruption.apply(DATA, &mut XorShift::new(42))
+        );
+        corruption.apply(&[], &mut XorShift::new(42));
+    }
+
+    /// Ensures that parser errors are not reported as defects.
+    #[test]
+    fn check_parser_error() {
+        assert_eq!(
+            check(
+                TestFileType::PackageInfo,
+                b"invalid".to_vec(),
+                Duration::from_secs(10)
+            ),
+            None
+        );
+    }
+}
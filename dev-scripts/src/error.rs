@@ -13,6 +13,14 @@ pub enum Error {
     #[error(transparent)]
     AlpmBuildinfo(#[from] alpm_buildinfo::Error),
 
+    /// An `alpm_common::Error` occurred.
+    #[error(transparent)]
+    AlpmCommon(#[from] alpm_common::Error),
+
+    /// An `alpm_compress::Error` occurred.
+    #[error(transparent)]
+    AlpmCompress(#[from] alpm_compress::Error),
+
     /// An `alpm_pkginfo::Error` occurred.
     #[error(transparent)]
     AlpmPackageInfo(#[from] alpm_pkginfo::Error),
@@ -21,6 +29,10 @@ pub enum Error {
     #[error(transparent)]
     AlpmMtree(#[from] alpm_mtree::Error),
 
+    /// An `alpm_repo_db::Error` occurred.
+    #[error(transparent)]
+    AlpmRepoDb(#[from] alpm_repo_db::Error),
+
     /// An `alpm_srcinfo::Error` occurred.
     #[error(transparent)]
     AlpmSourceInfo(#[from] alpm_srcinfo::Error),
@@ -37,6 +49,17 @@ pub enum Error {
     #[error("Failed to determine the current user's cache directory")]
     CannotGetCacheDir,
 
+    /// The checksum of a downloaded file does not match the expected one.
+    #[error("The SHA-256 checksum of {path:?} is {actual}, but {expected} is expected")]
+    ChecksumMismatch {
+        /// The path of the downloaded file.
+        path: PathBuf,
+        /// The expected checksum.
+        expected: String,
+        /// The actual checksum.
+        actual: String,
+    },
+
     /// A command failed.
     #[error("A command failed:{message}\nstdout:\n{stdout}\nstderr:\n{stderr}")]
     CommandFailed {
@@ -100,6 +123,40 @@ pub enum Error {
     #[error("Rsync report error:\n{message}")]
     RsyncReport { message: String },
 
+    /// One or more package repositories cannot be synchronized.
+    #[error(
+        "Synchronizing the package repositories failed\n{}",
+        failures
+            .iter()
+            .map(|(repository, error)| format!("{} failed with error:\n{error}", repository.bold()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )]
+    RepositorySyncFailed {
+        /// The failed package repositories as tuples of names and errors.
+        failures: Vec<(String, Error)>,
+    },
+
+    /// The signatures of one or more package files cannot be verified.
+    #[error(
+        "The signatures of packages in repository {repository} cannot be verified\n{}",
+        failures
+            .iter()
+            .map(|(path, message)| format!("{} failed with error:\n{message}", path.to_string_lossy().bold()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )]
+    SignatureVerificationFailed {
+        /// The name of the package repository.
+        repository: String,
+        /// The failed package files as tuples of paths and messages.
+        failures: Vec<(PathBuf, String)>,
+    },
+
+    /// A thread pool cannot be created.
+    #[error("Failed to create a thread pool:\n{0}")]
+    ThreadPool(#[from] rayon::ThreadPoolBuildError),
+
     /// A test run failed.
     #[error(
         "The test run failed\n{}",
@@ -116,6 +173,13 @@ pub enum Error {
         failures: Vec<(usize, PathBuf, String)>,
     },
 
+    /// A file type is not supported by a command.
+    #[error("The file type {file_type} is not supported by this command")]
+    UnsupportedFileType {
+        /// The unsupported file type.
+        file_type: crate::cli::TestFileType,
+    },
+
     /// A `voa::Error` occurred.
     #[error(transparent)]
     Voa(#[from] voa::Error),
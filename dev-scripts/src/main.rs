@@ -17,10 +17,13 @@ mod cli;
 mod cmd;
 mod commands;
 mod consts;
+pub mod corruption;
 mod error;
+mod state;
 pub mod sync;
 pub mod testing;
 mod ui;
+mod verify;
 
 /// Runs a command of the `dev-scripts` executable.
 fn run_command() -> Result<(), Error> {
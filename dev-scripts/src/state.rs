@@ -0,0 +1,259 @@
+//! Persistent tracking of extracted and validated package files.
+//!
+//! The state of each package repository is stored as JSON in the metadata directory of the cache.
+//! It records from which package file (and which version of it) a package has been extracted and
+//! for which file types the extracted files have been validated successfully.
+//! This allows skipping unchanged packages reliably, even if a previous run has been interrupted.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    fs::{create_dir_all, metadata, read_to_string},
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    CacheDir,
+    Error,
+    cli::TestFileType,
+    consts::{EXTRACTION_DIR, METADATA_DIR},
+    sync::PackageRepositories,
+};
+
+/// Identifies a specific version of a package file on disk.
+///
+/// A package file is considered unchanged, as long as its size and modification time are the same.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FileFingerprint {
+    /// The size of the file in bytes.
+    size: u64,
+    /// The modification time of the file in seconds since the Unix epoch.
+    modified: u64,
+}
+
+impl FileFingerprint {
+    /// Creates a new [`FileFingerprint`] from the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata of `path` cannot be retrieved.
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        let metadata = metadata(path).map_err(|source| Error::IoPath {
+            path: path.to_path_buf(),
+            context: "retrieving the metadata of the file".to_string(),
+            source,
+        })?;
+        let modified = metadata
+            .modified()
+            .map_err(|source| Error::IoPath {
+                path: path.to_path_buf(),
+                context: "retrieving the modification time of the file".to_string(),
+                source,
+            })?
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        Ok(Self {
+            size: metadata.len(),
+            modified,
+        })
+    }
+}
+
+/// The state of a single extracted package.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct PackageState {
+    /// The file name of the package file from which the files have been extracted.
+    #[serde(default)]
+    file_name: String,
+    /// The fingerprint of the package file from which the files have been extracted.
+    fingerprint: FileFingerprint,
+    /// The file types that have been validated successfully since the extraction.
+    validated: BTreeSet<String>,
+}
+
+/// The extraction and validation state of all packages of a package repository.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ExtractionState {
+    /// The state of each extracted package, keyed by the name of its extraction directory.
+    packages: BTreeMap<String, PackageState>,
+}
+
+impl ExtractionState {
+    /// Returns the path of the state file for `repository` in `cache_dir`.
+    pub fn path(cache_dir: &CacheDir, repository: &PackageRepositories) -> PathBuf {
+        cache_dir
+            .as_ref()
+            .join(METADATA_DIR)
+            .join(EXTRACTION_DIR)
+            .join(format!("{repository}.json"))
+    }
+
+    /// Reads an [`ExtractionState`] from the JSON file at `path`.
+    ///
+    /// Returns an empty [`ExtractionState`] if `path` does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not contain a valid state.
+    pub fn from_file(path: &Path) -> Result<Self, Error> {
+        if !path.exists() {
+            debug!("No extraction state found at {path:?}");
+            return Ok(Self::default());
+        }
+
+        let contents = read_to_string(path).map_err(|source| Error::IoPath {
+            path: path.to_path_buf(),
+            context: "reading the extraction state".to_string(),
+            source,
+        })?;
+        serde_json::from_str(&contents).map_err(|source| Error::Json {
+            context: format!("deserializing the extraction state {path:?}"),
+            source,
+        })
+    }
+
+    /// Writes the [`ExtractionState`] as JSON to the file at `path`.
+    ///
+    /// The parent directory of `path` is created if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state cannot be serialized or written.
+    pub fn to_file(&self, path: &Path) -> Result<(), Error> {
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent).map_err(|source| Error::IoPath {
+                path: parent.to_path_buf(),
+                context: "recursively creating the directory".to_string(),
+                source,
+            })?;
+        }
+
+        let contents = serde_json::to_string(self).map_err(|source| Error::Json {
+            context: "serializing the extraction state".to_string(),
+            source,
+        })?;
+        alpm_common::write_atomic(path, contents)?;
+
+        Ok(())
+    }
+
+    /// Checks whether the package `name` has been extracted from the package file `file_name`
+    /// with `fingerprint`.
+    pub fn is_extracted(&self, name: &str, file_name: &str, fingerprint: &FileFingerprint) -> bool {
+        self.packages
+            .get(name)
+            .is_some_and(|state| state.file_name == file_name && state.fingerprint == *fingerprint)
+    }
+
+    /// Records that the package `name` has been extracted from the package file `file_name` with
+    /// `fingerprint`.
+    ///
+    /// Resets all previous validation results of the package.
+    pub fn set_extracted(&mut self, name: String, file_name: String, fingerprint: FileFingerprint) {
+        self.packages.insert(
+            name,
+            PackageState {
+                file_name,
+                fingerprint,
+                validated: BTreeSet::new(),
+            },
+        );
+    }
+
+    /// Removes the state of all packages whose package file in `package_dir` has changed since
+    /// their extraction.
+    ///
+    /// A package file has changed if it no longer exists, or if its fingerprint differs from the
+    /// one recorded during extraction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the fingerprint of an existing package file cannot be created.
+    pub fn invalidate_changed(&mut self, package_dir: &Path) -> Result<(), Error> {
+        let mut changed = Vec::new();
+        for (name, state) in self.packages.iter() {
+            let path = package_dir.join(&state.file_name);
+            if state.file_name.is_empty()
+                || !path.is_file()
+                || FileFingerprint::from_path(&path)? != state.fingerprint
+            {
+                debug!("Package file of {name} has changed since its extraction");
+                changed.push(name.clone());
+            }
+        }
+        for name in changed {
+            self.packages.remove(&name);
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the files of `file_type` of the package `name` have been validated
+    /// successfully since the package has been extracted.
+    pub fn is_validated(&self, name: &str, file_type: TestFileType) -> bool {
+        self.packages
+            .get(name)
+            .is_some_and(|state| state.validated.contains(&file_type.to_string()))
+    }
+
+    /// Records that the files of `file_type` of the package `name` have been validated
+    /// successfully.
+    ///
+    /// Does nothing, if the package `name` has not been extracted.
+    pub fn set_validated(&mut self, name: &str, file_type: TestFileType) {
+        if let Some(state) = self.packages.get_mut(name) {
+            state.validated.insert(file_type.to_string());
+        }
+    }
+
+    /// Removes the state of all packages that are not in `names`.
+    pub fn retain(&mut self, names: &HashSet<String>) {
+        self.packages.retain(|name, _| names.contains(name));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::write;
+
+    use testresult::TestResult;
+
+    use super::*;
+
+    /// Ensures that the state of packages is discarded once their package file changes or is
+    /// removed.
+    #[test]
+    fn invalidate_changed_package_files() -> TestResult {
+        let tmp_dir = tempfile::tempdir()?;
+        let mut state = ExtractionState::default();
+        for name in ["unchanged", "changed", "removed"] {
+            let file_name = format!("{name}-1.0.0-1-x86_64.pkg.tar.zst");
+            let path = tmp_dir.path().join(&file_name);
+            write(&path, name)?;
+            state.set_extracted(
+                name.to_string(),
+                file_name,
+                FileFingerprint::from_path(&path)?,
+            );
+            state.set_validated(name, TestFileType::PackageInfo);
+        }
+        write(
+            tmp_dir.path().join("changed-1.0.0-1-x86_64.pkg.tar.zst"),
+            "changed data",
+        )?;
+        std::fs::remove_file(tmp_dir.path().join("removed-1.0.0-1-x86_64.pkg.tar.zst"))?;
+
+        state.invalidate_changed(tmp_dir.path())?;
+
+        assert!(state.is_validated("unchanged", TestFileType::PackageInfo));
+        assert!(!state.is_validated("changed", TestFileType::PackageInfo));
+        assert!(!state.is_validated("removed", TestFileType::PackageInfo));
+
+        Ok(())
+    }
+}
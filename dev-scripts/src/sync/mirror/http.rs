@@ -0,0 +1,375 @@
+//! Downloading of repository sync databases and packages from a mirror via HTTP(S).
+//!
+//! In contrast to the `rsync` based approach, this only requires a plain HTTP(S) mirror.
+//! The list of packages and their checksums is retrieved from the repository sync database of a
+//! package repository.
+
+use std::{
+    collections::HashSet,
+    fs::{File, OpenOptions, metadata, read, remove_file, rename},
+    io::BufReader,
+    path::{Path, PathBuf},
+};
+
+use alpm_repo_db::db::RepoDb;
+use alpm_types::{ChecksumVerificationError, Sha256Checksum};
+use indicatif::MultiProgress;
+use log::{debug, trace, warn};
+use rayon::{
+    ThreadPoolBuilder,
+    iter::{IntoParallelIterator, ParallelIterator},
+};
+use reqwest::{
+    StatusCode,
+    blocking::{Client, Response},
+    header::RANGE,
+};
+
+use crate::{Error, sync::filenames_in_dir, ui::get_progress_bar};
+
+/// The file extension used for partially downloaded files.
+const PARTIAL_EXTENSION: &str = "part";
+
+/// A package file that should be downloaded from a mirror.
+#[derive(Clone, Debug)]
+struct PackageDownload {
+    /// The file name of the package file.
+    file_name: String,
+    /// The size of the package file in bytes.
+    size: u64,
+    /// The SHA-256 checksum of the package file.
+    sha256: Sha256Checksum,
+}
+
+/// A client for downloading files from a package repository on a mirror via HTTP(S).
+#[derive(Clone, Debug)]
+pub struct HttpMirror {
+    /// The HTTP client used for all requests.
+    client: Client,
+    /// The base URL of the mirror.
+    base_url: String,
+    /// The maximum number of concurrent downloads.
+    concurrency: usize,
+    /// The shared progress display to which progress bars are added.
+    progress: MultiProgress,
+}
+
+impl HttpMirror {
+    /// Creates a new [`HttpMirror`] for `mirror`.
+    ///
+    /// If `mirror` does not provide a URL scheme, `https://` is used.
+    /// At most `concurrency` files are downloaded in parallel.
+    pub fn new(mirror: &str, concurrency: usize) -> Self {
+        let mirror = mirror.trim_end_matches('/');
+        let base_url = if mirror.starts_with("http://") || mirror.starts_with("https://") {
+            mirror.to_string()
+        } else {
+            format!("https://{mirror}")
+        };
+
+        Self {
+            client: Client::new(),
+            base_url,
+            concurrency: concurrency.max(1),
+            progress: MultiProgress::new(),
+        }
+    }
+
+    /// Sets the shared progress display to which the progress bars of downloads are added.
+    ///
+    /// This allows showing the progress of several [`HttpMirror`]s that are used in parallel.
+    pub fn with_progress(mut self, progress: MultiProgress) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Returns the URL of the file `file_name` in the package repository `repo_name`.
+    fn url(&self, repo_name: &str, file_name: &str) -> String {
+        format!("{}/{repo_name}/os/x86_64/{file_name}", self.base_url)
+    }
+
+    /// Downloads the repository sync database `file_name` of `repo_name` to `dest`.
+    ///
+    /// Returns `true` if the contents of `dest` changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be downloaded or written.
+    pub fn download_database(
+        &self,
+        repo_name: &str,
+        file_name: &str,
+        dest: &Path,
+    ) -> Result<bool, Error> {
+        let url = self.url(repo_name, file_name);
+        let response = self.get(&url, None)?;
+        let data = response.bytes().map_err(|source| Error::HttpQueryFailed {
+            context: format!("reading the response body of {url}"),
+            source,
+        })?;
+
+        if dest.exists()
+            && read(dest).map_err(|source| Error::IoPath {
+                path: dest.to_path_buf(),
+                context: "reading the file".to_string(),
+                source,
+            })? == data.as_ref()
+        {
+            return Ok(false);
+        }
+
+        alpm_common::write_atomic(dest, &data)?;
+        Ok(true)
+    }
+
+    /// Downloads all packages and signatures of the package repository `repo_name` to `dest`.
+    ///
+    /// The repository sync database of `repo_name` is downloaded to `database` once and the
+    /// packages are retrieved from it.
+    /// Package files that already exist in `dest` with the expected size are not downloaded
+    /// again.
+    /// Interrupted downloads are resumed using HTTP range requests.
+    /// After a package file is downloaded, its SHA-256 checksum is verified against the one in the
+    /// repository sync database.
+    /// Files in `dest`, that are not part of the package repository are removed.
+    ///
+    /// Returns the file names of all package files that have been downloaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the repository sync database cannot be downloaded, written or parsed,
+    /// - a package or signature file cannot be downloaded,
+    /// - the checksum of a downloaded package file does not match,
+    /// - or obsolete files cannot be removed.
+    pub fn download_packages(
+        &self,
+        repo_name: &str,
+        database: &Path,
+        dest: &Path,
+    ) -> Result<Vec<PathBuf>, Error> {
+        let packages = self.packages(repo_name, database)?;
+
+        let mut expected_files = HashSet::new();
+        let mut downloads = Vec::new();
+        for package in packages {
+            expected_files.insert(package.file_name.clone());
+            expected_files.insert(signature_file_name(&package.file_name));
+
+            if is_up_to_date(dest, &package)
+                && dest.join(signature_file_name(&package.file_name)).exists()
+            {
+                trace!("Package file {} is up-to-date", package.file_name);
+                continue;
+            }
+            downloads.push(package);
+        }
+
+        debug!(
+            "Downloading {} package files for repository {repo_name}",
+            downloads.len()
+        );
+        let progress_bar = self.progress.add(get_progress_bar(downloads.len() as u64));
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(self.concurrency)
+            .build()?;
+        let changed = pool.install(|| {
+            downloads
+                .into_par_iter()
+                .map(|package| {
+                    let result = self.download_package(repo_name, &package, dest);
+                    progress_bar.inc(1);
+                    result
+                })
+                .collect::<Result<Vec<Option<PathBuf>>, Error>>()
+        })?;
+        progress_bar.finish_with_message("Finished downloading packages.");
+
+        // Remove all files that are no longer part of the package repository.
+        // This includes stale partial downloads.
+        for file_name in filenames_in_dir(dest)?.difference(&expected_files) {
+            let path = dest.join(file_name);
+            debug!("Removing obsolete file {path:?}");
+            remove_file(&path).map_err(|source| Error::IoPath {
+                path,
+                context: "removing an obsolete file".to_string(),
+                source,
+            })?;
+        }
+
+        Ok(changed.into_iter().flatten().collect())
+    }
+
+    /// Retrieves the list of packages in the package repository `repo_name`.
+    ///
+    /// The repository sync database of `repo_name` is downloaded to `database` and parsed from
+    /// there (see [`HttpMirror::download_database`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository sync database cannot be downloaded, written or parsed.
+    fn packages(&self, repo_name: &str, database: &Path) -> Result<Vec<PackageDownload>, Error> {
+        self.download_database(repo_name, &format!("{repo_name}.db"), database)?;
+        let repo_db = RepoDb::from_file(database)?;
+
+        Ok(repo_db
+            .packages()
+            .map(|package| PackageDownload {
+                file_name: package.desc.file_name().to_string(),
+                size: package.desc.compressed_size(),
+                sha256: package.desc.sha256_checksum().clone(),
+            })
+            .collect())
+    }
+
+    /// Downloads a single `package` and its signature to `dest` and verifies its checksum.
+    ///
+    /// Files that already exist are not downloaded again.
+    /// Returns the file name of the package file, if it has been downloaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be downloaded or if the checksum does not match.
+    fn download_package(
+        &self,
+        repo_name: &str,
+        package: &PackageDownload,
+        dest: &Path,
+    ) -> Result<Option<PathBuf>, Error> {
+        let signature = signature_file_name(&package.file_name);
+        if !dest.join(&signature).exists() {
+            self.download_file(&self.url(repo_name, &signature), &dest.join(&signature))?;
+        }
+
+        if is_up_to_date(dest, package) {
+            return Ok(None);
+        }
+
+        let path = dest.join(&package.file_name);
+        self.download_file(&self.url(repo_name, &package.file_name), &path)?;
+
+        let file = File::open(&path).map_err(|source| Error::IoPath {
+            path: path.clone(),
+            context: "opening the downloaded package file".to_string(),
+            source,
+        })?;
+        match package.sha256.verify_reader(BufReader::new(file)) {
+            Ok(()) => {}
+            Err(ChecksumVerificationError::Io(source)) => {
+                return Err(Error::IoPath {
+                    path,
+                    context: "reading the downloaded package file".to_string(),
+                    source,
+                });
+            }
+            Err(ChecksumVerificationError::Mismatch(mismatch)) => {
+                remove_file(&path).map_err(|source| Error::IoPath {
+                    path: path.clone(),
+                    context: "removing a package file with invalid checksum".to_string(),
+                    source,
+                })?;
+                return Err(Error::ChecksumMismatch {
+                    path,
+                    expected: mismatch.expected.to_string(),
+                    actual: mismatch.actual.to_string(),
+                });
+            }
+        }
+
+        Ok(Some(PathBuf::from(&package.file_name)))
+    }
+
+    /// Downloads the file at `url` to `path`.
+    ///
+    /// The data is first written to a partial file next to `path`, which is renamed to `path`
+    /// once the download is complete.
+    /// If a partial file exists, the download is resumed using an HTTP range request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be downloaded or written.
+    fn download_file(&self, url: &str, path: &Path) -> Result<(), Error> {
+        let partial = partial_path(path);
+        let offset = metadata(&partial).map(|metadata| metadata.len()).ok();
+
+        let mut response = match self.get(url, offset) {
+            // The partial file is complete (or invalid), download the file from scratch.
+            Err(Error::HttpQueryFailed { source, .. })
+                if source.status() == Some(StatusCode::RANGE_NOT_SATISFIABLE) =>
+            {
+                warn!("Discarding partial download {partial:?}");
+                self.get(url, None)?
+            }
+            result => result?,
+        };
+
+        let mut file = if offset.is_some() && response.status() == StatusCode::PARTIAL_CONTENT {
+            trace!("Resuming download of {url} at byte {offset:?}");
+            OpenOptions::new().append(true).open(&partial)
+        } else {
+            File::create(&partial)
+        }
+        .map_err(|source| Error::IoPath {
+            path: partial.clone(),
+            context: "opening the file for writing".to_string(),
+            source,
+        })?;
+
+        response
+            .copy_to(&mut file)
+            .map_err(|source| Error::HttpQueryFailed {
+                context: format!("downloading {url}"),
+                source,
+            })?;
+
+        rename(&partial, path).map_err(|source| Error::IoPath {
+            path: partial,
+            context: format!("renaming the file to {path:?}"),
+            source,
+        })?;
+
+        Ok(())
+    }
+
+    /// Sends a GET request for `url`.
+    ///
+    /// If `offset` is provided, only the data starting at byte `offset` is requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response has an error status code.
+    fn get(&self, url: &str, offset: Option<u64>) -> Result<Response, Error> {
+        let mut request = self.client.get(url);
+        if let Some(offset) = offset {
+            request = request.header(RANGE, format!("bytes={offset}-"));
+        }
+
+        trace!("Requesting {url}");
+        request
+            .send()
+            .and_then(Response::error_for_status)
+            .map_err(|source| Error::HttpQueryFailed {
+                context: format!("requesting {url}"),
+                source,
+            })
+    }
+}
+
+/// Returns the file name of the signature for the package file `file_name`.
+fn signature_file_name(file_name: &str) -> String {
+    format!("{file_name}.sig")
+}
+
+/// Checks whether the file of `package` exists in `dest` and has the expected size.
+fn is_up_to_date(dest: &Path, package: &PackageDownload) -> bool {
+    metadata(dest.join(&package.file_name)).is_ok_and(|metadata| metadata.len() == package.size)
+}
+
+/// Returns the path of the partial download for `path`.
+fn partial_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".");
+    file_name.push(PARTIAL_EXTENSION);
+    path.with_file_name(file_name)
+}
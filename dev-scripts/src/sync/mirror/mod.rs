@@ -2,30 +2,67 @@
 //!
 //! This includes the database files or packages.
 
+mod http;
 mod rsync_changes;
+mod signatures;
 
 use std::{
     collections::HashSet,
-    fs::{create_dir_all, read_dir, remove_dir_all},
-    path::{Path, PathBuf},
+    fs::{File, create_dir_all, remove_dir_all, write},
+    io::{BufRead, BufReader},
+    path::{Component, Path, PathBuf},
     process::Command,
     str::FromStr,
+    sync::{
+        Mutex,
+        PoisonError,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
 };
 
+use alpm_compress::{
+    decompression::{CompressionDecoder, DecompressionSettings},
+    tarball::TarballReader,
+};
 use alpm_types::{INSTALL_SCRIPTLET_FILE_NAME, MetadataFileName, PackageFileName};
-use log::{debug, info, trace};
+use clap::ValueEnum;
+use indicatif::{MultiProgress, ProgressBar};
+use log::{debug, info, trace, warn};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
+pub use signatures::SignaturePolicy;
+use signatures::{SignatureRecord, SignatureStatus};
+use strum::Display;
 
 use super::{PackageRepositories, filenames_in_dir};
 use crate::{
     CacheDir,
     Error,
     cmd::ensure_success,
-    consts::{DATABASES_DIR, DOWNLOAD_DIR, PACKAGES_DIR},
-    sync::mirror::rsync_changes::Report,
-    ui::get_progress_bar,
+    consts::{DATABASES_DIR, DOWNLOAD_DIR, METADATA_DIR, PACKAGES_DIR, SIGNATURES_DIR},
+    state::{ExtractionState, FileFingerprint},
+    sync::mirror::{http::HttpMirror, rsync_changes::Report},
+    ui::{get_progress_bar, get_status_spinner},
+    verify::{openpgp_verify_file, with_package_verifier},
 };
 
+/// The backend used for downloading files from a package mirror.
+#[derive(Clone, Copy, Debug, Default, Display, Eq, PartialEq, ValueEnum)]
+pub enum MirrorBackend {
+    /// Download files using the `rsync` executable.
+    ///
+    /// The mirror must support the `rsync` protocol.
+    #[default]
+    #[strum(to_string = "rsync")]
+    Rsync,
+
+    /// Download files via HTTP(S) without relying on external executables.
+    ///
+    /// Works with any plain HTTP(S) mirror.
+    #[strum(to_string = "http")]
+    Http,
+}
+
 /// The entry point for downloading any data from package mirrors.
 #[derive(Clone, Debug)]
 pub struct MirrorDownloader {
@@ -37,6 +74,20 @@ pub struct MirrorDownloader {
     pub repositories: Vec<PackageRepositories>,
     /// Whether to extract all packages (regardless of changes).
     pub extract_all: bool,
+    /// The backend used for downloading files.
+    pub backend: MirrorBackend,
+    /// The maximum number of concurrent downloads.
+    ///
+    /// Only used by [`MirrorBackend::Http`].
+    pub concurrency: usize,
+    /// The maximum number of package repositories that are synchronized in parallel.
+    ///
+    /// If `1`, the repositories are synchronized one after another.
+    pub repository_concurrency: usize,
+    /// The policy for verifying the signatures of downloaded packages before extraction.
+    ///
+    /// If [`None`], signatures are not verified.
+    pub signature_policy: Option<SignaturePolicy>,
 }
 
 impl MirrorDownloader {
@@ -45,6 +96,16 @@ impl MirrorDownloader {
     ///
     /// - `desc`
     /// - `files`
+    ///
+    /// Up to [`MirrorDownloader::repository_concurrency`] repositories are synchronized in
+    /// parallel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the download or extraction directories cannot be created.
+    /// If the database of one or more repositories cannot be synchronized, the remaining
+    /// repositories are still synchronized and an [`Error::RepositorySyncFailed`] with all
+    /// failures is returned.
     pub fn sync_remote_databases(&self) -> Result<(), Error> {
         let download_dir = self
             .cache_dir
@@ -65,96 +126,180 @@ impl MirrorDownloader {
             source,
         })?;
 
-        for repo in self.repositories.iter() {
-            let name = repo.to_string();
-            info!("Downloading database for repository {name}");
-
-            let filename = format!("{name}.files");
-            let file_source = format!("rsync://{}/{name}/os/x86_64/{filename}", self.mirror);
-
-            let download_dest = download_dir.join(filename);
-
-            // Download the db from the mirror
-            let mut db_sync_command = Command::new("rsync");
-            db_sync_command
-                .args([
-                    "--recursive",
-                    "--perms",
-                    "--times",
-                    // Report changes status
-                    "--itemize-changes",
-                    // Copy files instead of symlinks
-                    // Symlinks may point to files up the tree of where we're looking at,
-                    // which is why normal symlinks would be invalid.
-                    "--copy-links",
-                ])
-                .arg(file_source)
-                .arg(&download_dest);
-
-            trace!("Running command: {db_sync_command:?}");
-            let output = db_sync_command.output().map_err(|source| Error::Io {
-                context: format!("synchronizing repository database for {name}"),
-                source,
-            })?;
+        let progress = MultiProgress::new();
+        self.for_each_repository(&progress, |repo, status| {
+            self.sync_remote_database(repo, &download_dir, &target_dir, status)
+        })
+    }
 
-            ensure_success(
-                &output,
-                format!("synchronizing repository database for {name}"),
-            )?;
-
-            trace!(
-                "Rsync reports: {}",
-                String::from_utf8_lossy(&output.stdout).trim()
-            );
-
-            let repo_target_dir = target_dir.join(&name);
-            if repo_target_dir.exists() {
-                if !self.extract_all
-                    && Report::parser(&output.stdout)
-                        .map_err(|source| Error::Parser(source.to_string()))?
-                        .file_content_updated()?
-                        .is_none()
-                {
-                    debug!("Database {name} is unchanged upstream, skipping extraction");
-                    continue;
-                } else {
-                    // There are old versions of the files, remove them.
-                    remove_dir_all(&repo_target_dir).map_err(|source| Error::IoPath {
-                        path: repo_target_dir.clone(),
-                        context: "recursively removing the directory".to_string(),
-                        source,
-                    })?;
-                }
+    /// Downloads and unpacks the file database of the repository `repo`.
+    ///
+    /// The database is downloaded to `download_dir` and extracted to a directory named after
+    /// `repo` in `target_dir`.
+    /// The current step is reported as message of the `status` spinner.
+    fn sync_remote_database(
+        &self,
+        repo: &PackageRepositories,
+        download_dir: &Path,
+        target_dir: &Path,
+        status: &ProgressBar,
+    ) -> Result<(), Error> {
+        let name = repo.to_string();
+        info!("Downloading database for repository {name}");
+        status.set_message("downloading database");
+
+        let filename = format!("{name}.files");
+        let download_dest = download_dir.join(&filename);
+
+        let changed = match self.backend {
+            MirrorBackend::Rsync => self.rsync_database(&name, &filename, &download_dest)?,
+            MirrorBackend::Http => HttpMirror::new(&self.mirror, self.concurrency)
+                .download_database(&name, &filename, &download_dest)?,
+        };
+
+        let repo_target_dir = target_dir.join(&name);
+        if repo_target_dir.exists() {
+            if !self.extract_all && !changed {
+                debug!("Database {name} is unchanged upstream, skipping extraction");
+                return Ok(());
+            } else {
+                // There are old versions of the files, remove them.
+                remove_dir_all(&repo_target_dir).map_err(|source| Error::IoPath {
+                    path: repo_target_dir.clone(),
+                    context: "recursively removing the directory".to_string(),
+                    source,
+                })?;
             }
-            create_dir_all(&repo_target_dir).map_err(|source| Error::IoPath {
-                path: repo_target_dir.clone(),
-                context: "recursively creating the directory".to_string(),
-                source,
-            })?;
+        }
+        create_dir_all(&repo_target_dir).map_err(|source| Error::IoPath {
+            path: repo_target_dir.clone(),
+            context: "recursively creating the directory".to_string(),
+            source,
+        })?;
 
-            debug!("Extracting db to {repo_target_dir:?}");
+        status.set_message("extracting database");
+        extract_database(&download_dest, &repo_target_dir)
+    }
 
-            // Extract the db into the target folder.
-            let mut tar_command = Command::new("tar");
-            tar_command
-                .arg("-x")
-                .arg("-f")
-                .arg(&download_dest)
-                .arg("-C")
-                .arg(&repo_target_dir);
+    /// Runs `sync` for each of the [`MirrorDownloader::repositories`] and collects all failures.
+    ///
+    /// Up to [`MirrorDownloader::repository_concurrency`] repositories are processed in parallel,
+    /// each on its own thread.
+    /// This way the nested parallel iterators used by `sync` (e.g. for extracting packages) still
+    /// use the global [`rayon`] thread pool.
+    /// Each repository is represented by a status spinner in `progress`, which is passed to
+    /// `sync`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::RepositorySyncFailed`] with the errors of all repositories for which
+    /// `sync` failed, in the order of [`MirrorDownloader::repositories`].
+    fn for_each_repository<F>(&self, progress: &MultiProgress, sync: F) -> Result<(), Error>
+    where
+        F: Fn(&PackageRepositories, &ProgressBar) -> Result<(), Error> + Sync,
+    {
+        let spinners: Vec<ProgressBar> = self
+            .repositories
+            .iter()
+            .map(|repo| get_status_spinner(progress, &repo.to_string()))
+            .collect();
+        let next = AtomicUsize::new(0);
+        let failures = Mutex::new(Vec::new());
+        let workers = self
+            .repository_concurrency
+            .clamp(1, self.repositories.len().max(1));
+
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| {
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let (Some(repo), Some(spinner)) =
+                            (self.repositories.get(index), spinners.get(index))
+                        else {
+                            break;
+                        };
+
+                        match sync(repo, spinner) {
+                            Ok(()) => spinner.finish_with_message("done"),
+                            Err(error) => {
+                                spinner.abandon_with_message("failed");
+                                failures
+                                    .lock()
+                                    .unwrap_or_else(PoisonError::into_inner)
+                                    .push((index, repo.to_string(), error));
+                            }
+                        }
+                    }
+                });
+            }
+        });
 
-            trace!("Running command: {tar_command:?}");
-            let output = tar_command.output().map_err(|source| Error::Io {
-                context: format!("extracting the repository database for {name}"),
-                source,
-            })?;
-            ensure_success(
-                &output,
-                format!("Extracting the repository database for {name}"),
-            )?;
+        let mut failures = failures
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner);
+        if failures.is_empty() {
+            return Ok(());
         }
+        failures.sort_by_key(|(index, _, _)| *index);
 
-        Ok(())
+        Err(Error::RepositorySyncFailed {
+            failures: failures
+                .into_iter()
+                .map(|(_, repository, error)| (repository, error))
+                .collect(),
+        })
+    }
+
+    /// Downloads the repository sync database `filename` of repository `name` to `download_dest`
+    /// using `rsync`.
+    ///
+    /// Returns `true` if the contents of the file changed.
+    fn rsync_database(
+        &self,
+        name: &str,
+        filename: &str,
+        download_dest: &Path,
+    ) -> Result<bool, Error> {
+        let file_source = format!("rsync://{}/{name}/os/x86_64/{filename}", self.mirror);
+
+        // Download the db from the mirror
+        let mut db_sync_command = Command::new("rsync");
+        db_sync_command
+            .args([
+                "--recursive",
+                "--perms",
+                "--times",
+                // Report changes status
+                "--itemize-changes",
+                // Copy files instead of symlinks
+                // Symlinks may point to files up the tree of where we're looking at,
+                // which is why normal symlinks would be invalid.
+                "--copy-links",
+            ])
+            .arg(file_source)
+            .arg(download_dest);
+
+        trace!("Running command: {db_sync_command:?}");
+        let output = db_sync_command.output().map_err(|source| Error::Io {
+            context: format!("synchronizing repository database for {name}"),
+            source,
+        })?;
+
+        ensure_success(
+            &output,
+            format!("synchronizing repository database for {name}"),
+        )?;
+
+        trace!(
+            "Rsync reports: {}",
+            String::from_utf8_lossy(&output.stdout).trim()
+        );
+
+        Ok(Report::parser(&output.stdout)
+            .map_err(|source| Error::Parser(source.to_string()))?
+            .file_content_updated()?
+            .is_some())
     }
 
     /// Download all official repository packages and extract all files that're interesting to us.
@@ -164,6 +309,19 @@ impl MirrorDownloader {
     ///  - `.MTREE`
     ///  - `.PKGINFO`
     ///  - `.INSTALL` (Optional)
+    ///
+    /// Up to [`MirrorDownloader::repository_concurrency`] repositories are synchronized in
+    /// parallel.
+    /// Package data of packages that are no longer on the mirror is only cleaned up if all
+    /// repositories have been synchronized successfully.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the download or extraction directories cannot be created, or if the
+    /// cleanup of package data fails.
+    /// If the packages of one or more repositories cannot be synchronized, the remaining
+    /// repositories are still synchronized and an [`Error::RepositorySyncFailed`] with all
+    /// failures is returned.
     pub fn sync_remote_packages(&self) -> Result<(), Error> {
         let download_dir = self
             .cache_dir
@@ -184,69 +342,16 @@ impl MirrorDownloader {
             source,
         })?;
 
-        for repo in self.repositories.iter() {
-            let repo_name = repo.to_string();
-            info!("Downloading packages for repository {repo_name}");
-
-            let file_source = format!("rsync://{}/{repo_name}/os/x86_64/", self.mirror);
-            let download_dest = download_dir.join(&repo_name);
-            let changed = self.download_packages(&repo_name, file_source, &download_dest)?;
-
-            let packages: Vec<PathBuf> = if self.extract_all {
-                let files: Vec<_> = read_dir(&download_dest)
-                    .map_err(|source| Error::IoPath {
-                        path: download_dest.to_path_buf(),
-                        context: "reading entries in directory".to_string(),
-                        source,
-                    })?
-                    .map(|result| {
-                        result.map_err(|source| Error::IoPath {
-                            path: download_dest.to_path_buf(),
-                            context: "reading a directory entry".to_string(),
-                            source,
-                        })
-                    })
-                    .collect::<Result<_, Error>>()?;
-                files
-                    .into_iter()
-                    .map(|entry| entry.path().to_owned())
-                    .collect::<Vec<_>>()
-            } else {
-                changed
-                    .into_iter()
-                    .map(|pkg| download_dest.join(pkg))
-                    .collect()
-            }
-            .into_iter()
-            // Filter out any dotfiles.
-            // Those might be temporary download artifacts from previous rsync runs.
-            .filter(|entry| {
-                if let Some(path) = entry.to_str() {
-                    !path.starts_with('.')
-                } else {
-                    false
-                }
-            })
-            .collect();
-
-            info!("Extracting packages for repository {repo_name}");
-            let progress_bar = get_progress_bar(packages.len() as u64);
-            packages
-                .into_par_iter()
-                .filter(|file| {
-                    file.extension()
-                        .is_none_or(|ext| ext.to_str().is_none_or(|ext| ext != "sig"))
-                })
-                .map(|pkg| {
-                    // Extract all files that we're interested in.
-                    let result = extract_pkg_files(&pkg, &target_dir, &repo_name);
-                    progress_bar.inc(1);
-                    result
-                })
-                .collect::<Result<Vec<()>, Error>>()?;
-            // Finish the progress_bar
-            progress_bar.finish_with_message("Finished extracting files for repository {repo}.");
-        }
+        let progress = MultiProgress::new();
+        self.for_each_repository(&progress, |repo, status| {
+            self.sync_remote_repository_packages(
+                repo,
+                &download_dir,
+                &target_dir,
+                &progress,
+                status,
+            )
+        })?;
 
         // Clean up package data of packages that're no longer on the mirror.
         for repo in self.repositories.iter() {
@@ -256,6 +361,12 @@ impl MirrorDownloader {
                 .map(remove_tarball_suffix)
                 .collect::<Result<HashSet<String>, Error>>()?;
 
+            // Forget the state of packages that are no longer on the mirror.
+            let state_path = ExtractionState::path(&self.cache_dir, repo);
+            let mut state = ExtractionState::from_file(&state_path)?;
+            state.retain(&mirror_packages);
+            state.to_file(&state_path)?;
+
             let local_packages = filenames_in_dir(&target_dir.join(repo.to_string()))?;
 
             // Get the packages that no longer exist on the mirror.
@@ -280,6 +391,252 @@ impl MirrorDownloader {
         Ok(())
     }
 
+    /// Downloads the packages of the repository `repo` and extracts the metadata files from them.
+    ///
+    /// The packages are downloaded to a directory named after `repo` in `download_dir` and their
+    /// metadata files extracted to `target_dir`.
+    /// When using [`MirrorBackend::Http`], the repository sync database of `repo` is stored in
+    /// `download_dir` as well.
+    /// Progress bars for the individual steps are added to `progress`, while the current step is
+    /// reported as message of the `status` spinner.
+    fn sync_remote_repository_packages(
+        &self,
+        repo: &PackageRepositories,
+        download_dir: &Path,
+        target_dir: &Path,
+        progress: &MultiProgress,
+        status: &ProgressBar,
+    ) -> Result<(), Error> {
+        let repo_name = repo.to_string();
+        info!("Downloading packages for repository {repo_name}");
+        status.set_message("downloading packages");
+
+        let download_dest = download_dir.join(&repo_name);
+        let changed = match self.backend {
+            MirrorBackend::Rsync => {
+                let file_source = format!("rsync://{}/{repo_name}/os/x86_64/", self.mirror);
+                self.download_packages(&repo_name, file_source, &download_dest)?
+            }
+            MirrorBackend::Http => {
+                create_dir_all(&download_dest).map_err(|source| Error::IoPath {
+                    path: download_dest.clone(),
+                    context: "recursively creating the directory".to_string(),
+                    source,
+                })?;
+                HttpMirror::new(&self.mirror, self.concurrency)
+                    .with_progress(progress.clone())
+                    .download_packages(
+                        &repo_name,
+                        &download_dir.join(format!("{repo_name}.db")),
+                        &download_dest,
+                    )?
+            }
+        };
+
+        let changed: HashSet<PathBuf> = changed.into_iter().collect();
+        let state_path = ExtractionState::path(&self.cache_dir, repo);
+        let mut state = ExtractionState::from_file(&state_path)?;
+
+        // Select all packages that changed upstream or have not been extracted from the current
+        // version of their package file.
+        // This also covers packages, whose extraction has been interrupted in a previous run.
+        let mut packages = Vec::new();
+        for file_name in filenames_in_dir(&download_dest)? {
+            // Filter out any dotfiles and signatures.
+            // Dotfiles might be temporary download artifacts from previous rsync runs.
+            if file_name.starts_with('.') || file_name.ends_with(".sig") {
+                continue;
+            }
+
+            let pkg = download_dest.join(&file_name);
+            let pkg_name = remove_tarball_suffix(file_name.clone())?;
+            let fingerprint = FileFingerprint::from_path(&pkg)?;
+            if self.extract_all
+                || changed.contains(Path::new(&file_name))
+                || !state.is_extracted(&pkg_name, &file_name, &fingerprint)
+            {
+                packages.push((pkg, pkg_name, file_name, fingerprint));
+            } else {
+                trace!("Package {pkg_name} is unchanged, skipping extraction");
+            }
+        }
+
+        let packages = if let Some(policy) = self.signature_policy {
+            status.set_message("verifying signatures");
+            let verified: HashSet<PathBuf> = self
+                .verify_packages(
+                    policy,
+                    &repo_name,
+                    &download_dest,
+                    target_dir,
+                    packages.iter().map(|(pkg, _, _, _)| pkg.clone()).collect(),
+                    progress,
+                )?
+                .into_iter()
+                .collect();
+            packages
+                .into_iter()
+                .filter(|(pkg, _, _, _)| verified.contains(pkg))
+                .collect()
+        } else {
+            packages
+        };
+
+        info!("Extracting packages for repository {repo_name}");
+        status.set_message("extracting packages");
+        let progress_bar = progress.add(get_progress_bar(packages.len() as u64));
+        let results: Vec<(String, String, FileFingerprint, Result<(), Error>)> = packages
+            .into_par_iter()
+            .map(|(pkg, pkg_name, file_name, fingerprint)| {
+                // Extract all files that we're interested in.
+                let result = extract_pkg_files(&pkg, target_dir, &repo_name);
+                progress_bar.inc(1);
+                (pkg_name, file_name, fingerprint, result)
+            })
+            .collect();
+        // Finish the progress_bar
+        progress_bar.finish_with_message(format!(
+            "Finished extracting files for repository {repo_name}."
+        ));
+
+        // Record all successful extractions before returning the first error (if any).
+        let mut first_error = None;
+        for (pkg_name, file_name, fingerprint, result) in results {
+            match result {
+                Ok(()) => state.set_extracted(pkg_name, file_name, fingerprint),
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+        state.to_file(&state_path)?;
+        if let Some(error) = first_error {
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the signatures of the package files `packages` of the repository `repo_name`.
+    ///
+    /// Each package file is verified with its detached signature, using the artifact verifiers and
+    /// trust anchors for packages in the VOA hierarchy.
+    /// The result of each verification is recorded in a [`SignatureRecord`] for the repository in
+    /// the metadata directory of the cache.
+    ///
+    /// Depending on `policy`, packages that fail verification lead to an error, are skipped (and
+    /// their previously extracted files in `target_dir` are removed) or are only warned about.
+    ///
+    /// The progress of the verification is shown in a progress bar that is added to `progress`.
+    ///
+    /// Returns the package files that should be extracted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the VOA hierarchy cannot be used,
+    /// - the [`SignatureRecord`] cannot be read or written,
+    /// - `policy` is [`SignaturePolicy::Fail`] and the signature of a package file cannot be
+    ///   verified,
+    /// - or the extracted files of a skipped package cannot be removed.
+    fn verify_packages(
+        &self,
+        policy: SignaturePolicy,
+        repo_name: &str,
+        download_dest: &Path,
+        target_dir: &Path,
+        packages: Vec<PathBuf>,
+        progress: &MultiProgress,
+    ) -> Result<Vec<PathBuf>, Error> {
+        info!("Verifying package signatures for repository {repo_name}");
+        let results = with_package_verifier(true, |model_verifier| {
+            let progress_bar = progress.add(get_progress_bar(packages.len() as u64));
+            let results: Vec<(PathBuf, SignatureStatus)> = packages
+                .into_par_iter()
+                .map(|pkg| {
+                    let mut signature = pkg.clone().into_os_string();
+                    signature.push(".sig");
+                    let status =
+                        match openpgp_verify_file(pkg.clone(), signature.into(), model_verifier) {
+                            Ok(()) => SignatureStatus::Verified,
+                            Err(error) => SignatureStatus::Failed {
+                                reason: error.to_string(),
+                            },
+                        };
+                    progress_bar.inc(1);
+                    (pkg, status)
+                })
+                .collect();
+            progress_bar.finish_with_message("Finished verifying package signatures.");
+
+            Ok(results)
+        })?;
+
+        // Record the results, while dropping packages that are no longer on the mirror.
+        let record_path = self
+            .cache_dir
+            .as_ref()
+            .join(METADATA_DIR)
+            .join(SIGNATURES_DIR)
+            .join(format!("{repo_name}.json"));
+        let mut record = SignatureRecord::from_file(&record_path)?;
+        for (pkg, status) in results.iter() {
+            if let Some(file_name) = pkg.file_name() {
+                record.insert(file_name.to_string_lossy().to_string(), status.clone());
+            }
+        }
+        record.retain(&filenames_in_dir(download_dest)?);
+        record.to_file(&record_path)?;
+
+        let mut verified = Vec::new();
+        let mut failed = Vec::new();
+        for (pkg, status) in results {
+            match status {
+                SignatureStatus::Verified => verified.push(pkg),
+                SignatureStatus::Failed { reason } => failed.push((pkg, reason)),
+            }
+        }
+
+        match policy {
+            SignaturePolicy::Fail if !failed.is_empty() => {
+                Err(Error::SignatureVerificationFailed {
+                    repository: repo_name.to_string(),
+                    failures: failed,
+                })
+            }
+            SignaturePolicy::Fail => Ok(verified),
+            SignaturePolicy::Skip => {
+                for (pkg, reason) in failed {
+                    warn!(
+                        "Skipping extraction of {pkg:?}, as its signature cannot be verified:\n{reason}"
+                    );
+                    let Some(file_name) = pkg.file_name() else {
+                        continue;
+                    };
+                    let pkg_target_dir = target_dir.join(repo_name).join(remove_tarball_suffix(
+                        file_name.to_string_lossy().to_string(),
+                    )?);
+                    if pkg_target_dir.exists() {
+                        remove_dir_all(&pkg_target_dir).map_err(|source| Error::IoPath {
+                            path: pkg_target_dir.clone(),
+                            context: "recursively removing the directory".to_string(),
+                            source,
+                        })?;
+                    }
+                }
+                Ok(verified)
+            }
+            SignaturePolicy::Warn => {
+                for (pkg, reason) in failed.iter() {
+                    warn!("The signature of {pkg:?} cannot be verified:\n{reason}");
+                }
+                verified.extend(failed.into_iter().map(|(pkg, _)| pkg));
+                Ok(verified)
+            }
+        }
+    }
+
     /// Downloads all packages and signatures of a package repository to a local directory.
     fn download_packages(
         &self,
@@ -360,30 +717,68 @@ impl MirrorDownloader {
     }
 }
 
-/// Get the list of all files inside a given compressed tarball.
+/// Extracts the repository sync database at `path` to `target_dir`.
 ///
-/// This function provides data which is necessary to determine which subset of files should be
-/// extracted.
-fn get_tar_file_list(pkg: &Path) -> Result<HashSet<String>, Error> {
-    let mut tar_command = Command::new("tar");
-    tar_command.arg("-tf").arg(pkg);
-    trace!("Running command: {tar_command:?}");
-    let peek_output = tar_command.output().map_err(|source| Error::Io {
-        context: format!("list contents of tar file {pkg:?}"),
+/// The compression of the database is detected based on its magic bytes, as repository sync
+/// databases commonly use file extensions that do not indicate the compression algorithm.
+/// Entries with paths that are absolute or point outside of `target_dir` are skipped.
+fn extract_database(path: &Path, target_dir: &Path) -> Result<(), Error> {
+    debug!("Extracting db to {target_dir:?}");
+
+    let file = File::open(path).map_err(|source| Error::IoPath {
+        path: path.to_path_buf(),
+        context: "opening the file for reading".to_string(),
+        source,
+    })?;
+    let mut reader = BufReader::new(file);
+    let magic_bytes = reader.fill_buf().map_err(|source| Error::IoPath {
+        path: path.to_path_buf(),
+        context: "reading the magic bytes of the file".to_string(),
         source,
     })?;
-    ensure_success(
-        &peek_output,
-        format!("Listing contents of tar file {pkg:?}"),
-    )?;
-
-    Ok(String::from_utf8_lossy(&peek_output.stdout)
-        .lines()
-        .map(|line| line.to_string())
-        .collect())
+    let settings = DecompressionSettings::from_magic_bytes(magic_bytes);
+    let mut tarball = TarballReader::new(CompressionDecoder::from_reader(reader, settings)?);
+
+    for entry in tarball.entries()? {
+        let mut entry = entry?;
+        if !is_contained_path(entry.path()) {
+            debug!("Skipping entry {:?} in {path:?}", entry.path());
+            continue;
+        }
+
+        let entry_path = target_dir.join(entry.path());
+        if entry.is_dir() {
+            create_dir_all(&entry_path).map_err(|source| Error::IoPath {
+                path: entry_path.clone(),
+                context: "recursively creating the directory".to_string(),
+                source,
+            })?;
+        } else if entry.is_file() {
+            if let Some(parent) = entry_path.parent() {
+                create_dir_all(parent).map_err(|source| Error::IoPath {
+                    path: parent.to_path_buf(),
+                    context: "recursively creating the directory".to_string(),
+                    source,
+                })?;
+            }
+            write(&entry_path, entry.content()?).map_err(|source| Error::IoPath {
+                path: entry_path.clone(),
+                context: "writing the extracted file".to_string(),
+                source,
+            })?;
+        }
+    }
+
+    Ok(())
 }
 
-/// Use `tar` to extract relevant package metadata and script files from packages files.
+/// Checks whether `path` is relative and only consists of normal components.
+fn is_contained_path(path: &Path) -> bool {
+    path.components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+}
+
+/// Extracts relevant package metadata and script files from package files.
 ///
 /// This function attempts to extract ".MTREE", ".BUILDINFO", ".PKGINFO" and ".INSTALL" files.
 /// Extracted files are placed in a directory structure that reflects the package's association with
@@ -391,8 +786,7 @@ fn get_tar_file_list(pkg: &Path) -> Result<HashSet<String>, Error> {
 ///
 /// ## Note
 ///
-/// Since some files are optional, we have to take a look at the files in that tarball to determine
-/// which of the files need to be actually extracted.
+/// Since some files are optional, only the files that are present in the package are extracted.
 ///
 /// # Panics
 ///
@@ -405,9 +799,6 @@ fn extract_pkg_files(pkg: &Path, target_dir: &Path, repo_name: &str) -> Result<(
         .to_string();
     let pkg_name = remove_tarball_suffix(pkg_file_name)?;
 
-    // Peek into the pkg tar to see what kind of files we need to extract.
-    let files = get_tar_file_list(pkg)?;
-
     // Create the target directory where all the files should be extracted to.
     let pkg_target_dir = target_dir.join(repo_name).join(pkg_name);
     create_dir_all(&pkg_target_dir).map_err(|source| Error::IoPath {
@@ -416,37 +807,34 @@ fn extract_pkg_files(pkg: &Path, target_dir: &Path, repo_name: &str) -> Result<(
         source,
     })?;
 
-    let mut cmd_args = vec![
-        "-C".to_string(),
-        pkg_target_dir.to_string_lossy().to_string(),
-        "-xf".to_string(),
-        pkg.to_string_lossy().to_string(),
-    ];
-
-    // Check for each of the known filetypes, whether it exists in the package.
-    // If it does, add it to the tar command for extraction.
-    for filetype in [
+    let filetypes = [
         MetadataFileName::Mtree.as_ref(),
         MetadataFileName::BuildInfo.as_ref(),
         MetadataFileName::PackageInfo.as_ref(),
         INSTALL_SCRIPTLET_FILE_NAME,
-    ] {
-        if files.contains(filetype) {
-            cmd_args.push(filetype.to_string());
-        }
-    }
+    ];
 
-    // Run the extraction command
-    let mut tar_command = Command::new("tar");
-    tar_command.args(cmd_args);
+    trace!("Extracting files from {pkg:?}");
+    let mut tarball = TarballReader::try_from(pkg)?;
+    for entry in tarball.entries()? {
+        let mut entry = entry?;
+        let Some(filetype) = filetypes
+            .into_iter()
+            .find(|filetype| entry.path() == Path::new(filetype))
+        else {
+            continue;
+        };
+        if !entry.is_file() {
+            continue;
+        }
 
-    trace!("Running command: {tar_command:?}");
-    let output = tar_command.output().map_err(|source| Error::IoPath {
-        path: pkg.to_path_buf(),
-        context: "extracting files".to_string(),
-        source,
-    })?;
-    ensure_success(&output, format!("Extracting files from tar file {pkg:?}"))?;
+        let path = pkg_target_dir.join(filetype);
+        write(&path, entry.content()?).map_err(|source| Error::IoPath {
+            path: path.clone(),
+            context: "writing the extracted file".to_string(),
+            source,
+        })?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,116 @@
+//! Verification of package signatures after downloading packages from a mirror.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs::{create_dir_all, read_to_string},
+    path::Path,
+};
+
+use clap::ValueEnum;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use crate::Error;
+
+/// The policy for handling packages, whose signature cannot be verified.
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq, ValueEnum)]
+pub enum SignaturePolicy {
+    /// Abort with an error, if the signature of any package cannot be verified.
+    #[strum(to_string = "fail")]
+    Fail,
+
+    /// Skip the extraction of packages, whose signature cannot be verified.
+    #[strum(to_string = "skip")]
+    Skip,
+
+    /// Emit a warning for packages, whose signature cannot be verified, but extract them anyway.
+    #[strum(to_string = "warn")]
+    Warn,
+}
+
+/// The outcome of the signature verification of a single package file.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase", tag = "status")]
+pub enum SignatureStatus {
+    /// The signature of the package file has been verified successfully.
+    Verified,
+
+    /// The signature of the package file cannot be verified.
+    Failed {
+        /// The reason for the failed verification.
+        reason: String,
+    },
+}
+
+/// The signature verification results of all package files of a package repository.
+///
+/// The results are stored as JSON in the cache directory, so that the verification status of each
+/// package file can be inspected after a sync.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SignatureRecord {
+    /// The verification status of each package file, keyed by file name.
+    packages: BTreeMap<String, SignatureStatus>,
+}
+
+impl SignatureRecord {
+    /// Reads a [`SignatureRecord`] from the JSON file at `path`.
+    ///
+    /// Returns an empty [`SignatureRecord`] if `path` does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not contain a valid record.
+    pub fn from_file(path: &Path) -> Result<Self, Error> {
+        if !path.exists() {
+            debug!("No signature record found at {path:?}");
+            return Ok(Self::default());
+        }
+
+        let contents = read_to_string(path).map_err(|source| Error::IoPath {
+            path: path.to_path_buf(),
+            context: "reading the signature record".to_string(),
+            source,
+        })?;
+        serde_json::from_str(&contents).map_err(|source| Error::Json {
+            context: format!("deserializing the signature record {path:?}"),
+            source,
+        })
+    }
+
+    /// Writes the [`SignatureRecord`] as JSON to the file at `path`.
+    ///
+    /// The parent directory of `path` is created if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record cannot be serialized or written.
+    pub fn to_file(&self, path: &Path) -> Result<(), Error> {
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent).map_err(|source| Error::IoPath {
+                path: parent.to_path_buf(),
+                context: "recursively creating the directory".to_string(),
+                source,
+            })?;
+        }
+
+        let contents = serde_json::to_string_pretty(self).map_err(|source| Error::Json {
+            context: "serializing the signature record".to_string(),
+            source,
+        })?;
+        alpm_common::write_atomic(path, contents)?;
+
+        Ok(())
+    }
+
+    /// Sets the [`SignatureStatus`] of the package file `file_name`.
+    pub fn insert(&mut self, file_name: String, status: SignatureStatus) {
+        self.packages.insert(file_name, status);
+    }
+
+    /// Removes the records of all package files that are not in `file_names`.
+    pub fn retain(&mut self, file_names: &HashSet<String>) {
+        self.packages
+            .retain(|file_name, _| file_names.contains(file_name));
+    }
+}
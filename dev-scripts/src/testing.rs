@@ -1,6 +1,10 @@
 //! Tests against downloaded artifacts.
 
-use std::{collections::HashSet, fs::read_dir, path::PathBuf, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs::read_dir,
+    path::{Path, PathBuf},
+};
 
 use alpm_buildinfo::BuildInfo;
 use alpm_common::MetadataFile;
@@ -9,79 +13,18 @@ use alpm_pkginfo::PackageInfo;
 use alpm_srcinfo::SourceInfo;
 use log::{debug, info};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
-use voa::{
-    commands::{
-        PurposeAndContext,
-        get_technology_settings,
-        get_voa_config,
-        openpgp_verify,
-        read_openpgp_signatures,
-        read_openpgp_verifiers,
-    },
-    core::{Context, Os, Purpose},
-    openpgp::ModelBasedVerifier,
-    utils::RegularFile,
-};
 
 use crate::{
     CacheDir,
     Error,
     cli::TestFileType,
     consts::{AUR_DIR, DATABASES_DIR, DOWNLOAD_DIR, PACKAGES_DIR, PKGSRC_DIR},
+    state::ExtractionState,
     sync::PackageRepositories,
     ui::get_progress_bar,
+    verify::{openpgp_verify_file, with_package_verifier},
 };
 
-/// Verifies a `file` using a `signature` and a [`ModelBasedVerifier`].
-///
-/// The success or failure of the verification is transmitted through logging.
-///
-/// # Errors
-///
-/// Returns an error if
-///
-/// - the `signature` cannot be read as an OpenPGP signature
-/// - the `file` cannot be read
-fn openpgp_verify_file(
-    file: PathBuf,
-    signature: PathBuf,
-    model_verifier: &ModelBasedVerifier,
-) -> Result<(), Error> {
-    debug!("Verifying {file:?} with {signature:?}");
-
-    let signatures = read_openpgp_signatures(&HashSet::from_iter([RegularFile::try_from(
-        signature.clone(),
-    )?]))?;
-
-    let check_results = openpgp_verify(
-        model_verifier,
-        &signatures,
-        &RegularFile::try_from(file.clone())?,
-    )?;
-
-    // Look at the signer info of all check results and return an error if there is none.
-    for check_result in check_results {
-        if let Some(signer_info) = check_result.signer_info() {
-            debug!(
-                "Successfully verified using {} {}",
-                signer_info
-                    .certificate()
-                    .fingerprint()
-                    .map_err(voa::Error::VoaOpenPgp)?,
-                signer_info.component_fingerprint()
-            )
-        } else {
-            return Err(Error::VoaVerificationFailed {
-                file,
-                signature,
-                context: "".to_string(),
-            });
-        }
-    }
-
-    Ok(())
-}
-
 /// This is the entry point for running validation tests of parsers on ALPM metadata files.
 #[derive(Clone, Debug)]
 pub struct TestRunner {
@@ -91,115 +34,147 @@ pub struct TestRunner {
     pub file_type: TestFileType,
     /// The list of repositories against which the test runs.
     pub repositories: Vec<PackageRepositories>,
+    /// Whether to skip files of packages that have been validated successfully before.
+    ///
+    /// Only applies to file types extracted from package files.
+    pub skip_validated: bool,
 }
 
 impl TestRunner {
     /// Run validation on all local test files that have been downloaded via the
     /// `test-files download` command.
     pub fn run_tests(&self) -> Result<(), Error> {
-        let test_files = self.find_files_of_type()?;
+        let mut test_files = self.find_files_of_type()?;
+
+        // Track the validation state of files extracted from package files.
+        // The state of packages whose package file has changed since their extraction is
+        // discarded, as their extracted files are outdated.
+        let mut states = HashMap::new();
+        if matches!(
+            self.file_type,
+            TestFileType::BuildInfo | TestFileType::PackageInfo | TestFileType::MTree
+        ) {
+            for repo in self.repositories.iter() {
+                let path = ExtractionState::path(&self.cache_dir, repo);
+                let mut state = ExtractionState::from_file(&path)?;
+                state.invalidate_changed(
+                    &self
+                        .cache_dir
+                        .as_ref()
+                        .join(DOWNLOAD_DIR)
+                        .join(PACKAGES_DIR)
+                        .join(repo.to_string()),
+                )?;
+                states.insert(repo.to_string(), (path, state));
+            }
+        }
+
+        if self.skip_validated {
+            let total = test_files.len();
+            test_files.retain(|file| {
+                !package_of_file(file).is_some_and(|(repo, pkg)| {
+                    states
+                        .get(&repo)
+                        .is_some_and(|(_, state)| state.is_validated(&pkg, self.file_type))
+                })
+            });
+            info!(
+                "Skipping {} {} files that have been validated before",
+                total - test_files.len(),
+                self.file_type
+            );
+        }
         info!(
             "Found {} {} files for testing",
             test_files.len(),
             self.file_type
         );
 
-        // Cache the certificates used for VOA-based verification as that significantly increases
-        // speed.
-        let os = Os::from_str("arch").map_err(voa::Error::VoaCore)?;
-
-        let (artifact_verifiers, anchors) = if matches!(self.file_type, TestFileType::Signatures) {
-            let artifact_verifiers = read_openpgp_verifiers(
-                os.clone(),
-                Purpose::from_str("package").map_err(voa::Error::VoaCore)?,
-                Context::Default,
-            );
-            let anchors = read_openpgp_verifiers(
-                os.clone(),
-                Purpose::from_str("trust-anchor-package").map_err(voa::Error::VoaCore)?,
-                Context::Default,
-            );
-            (artifact_verifiers, anchors)
-        } else {
-            (Vec::new(), Vec::new())
-        };
-
-        let config = get_voa_config();
-        let purpose_and_context = PurposeAndContext::new(
-            Some(Purpose::from_str("package").map_err(voa::Error::VoaCore)?),
-            Some(Context::Default),
-        );
-        let openpgp_settings =
-            get_technology_settings(&config, &os, purpose_and_context.as_ref()).openpgp_settings();
-
-        let model_verifier =
-            ModelBasedVerifier::new(openpgp_settings, &artifact_verifiers, &anchors);
-
-        let progress_bar = get_progress_bar(test_files.len() as u64);
-
-        // Run the validate subcommand for all files in parallel.
-        let asserts: Vec<(PathBuf, Result<(), Error>)> = test_files
-            .into_par_iter()
-            .map(|file| {
-                let result = match self.file_type {
-                    TestFileType::BuildInfo => BuildInfo::from_file_with_schema(&file, None)
-                        .map(|_| ())
-                        .map_err(|err| err.into()),
-                    TestFileType::SrcInfo => SourceInfo::from_file_with_schema(&file, None)
-                        .map(|_| ())
-                        .map_err(|err| err.into()),
-                    TestFileType::MTree => Mtree::from_file_with_schema(&file, None)
-                        .map(|_| ())
-                        .map_err(|err| err.into()),
-                    TestFileType::PackageInfo => PackageInfo::from_file_with_schema(&file, None)
-                        .map(|_| ())
-                        .map_err(|err| err.into()),
-                    TestFileType::RemoteDesc => unimplemented!(),
-                    TestFileType::RemoteFiles => unimplemented!(),
-                    TestFileType::LocalDesc => unimplemented!(),
-                    TestFileType::LocalFiles => unimplemented!(),
-                    TestFileType::Signatures => {
-                        let data = {
-                            let mut data = file.clone();
-                            data.set_extension("");
-                            data
-                        };
-
-                        openpgp_verify_file(data, file.clone(), &model_verifier)
+        // Only read the certificates used for VOA-based verification if they are needed.
+        let read_certificates = matches!(self.file_type, TestFileType::Signatures);
+        with_package_verifier(read_certificates, |model_verifier| {
+            let progress_bar = get_progress_bar(test_files.len() as u64);
+
+            // Run the validate subcommand for all files in parallel.
+            let asserts: Vec<(PathBuf, Result<(), Error>)> = test_files
+                .into_par_iter()
+                .map(|file| {
+                    let result = match self.file_type {
+                        TestFileType::BuildInfo => BuildInfo::from_file_with_schema(&file, None)
+                            .map(|_| ())
+                            .map_err(|err| err.into()),
+                        TestFileType::SrcInfo => SourceInfo::from_file_with_schema(&file, None)
+                            .map(|_| ())
+                            .map_err(|err| err.into()),
+                        TestFileType::MTree => Mtree::from_file_with_schema(&file, None)
+                            .map(|_| ())
+                            .map_err(|err| err.into()),
+                        TestFileType::PackageInfo => {
+                            PackageInfo::from_file_with_schema(&file, None)
+                                .map(|_| ())
+                                .map_err(|err| err.into())
+                        }
+                        TestFileType::RemoteDesc => unimplemented!(),
+                        TestFileType::RemoteFiles => unimplemented!(),
+                        TestFileType::LocalDesc => unimplemented!(),
+                        TestFileType::LocalFiles => unimplemented!(),
+                        TestFileType::Signatures => {
+                            let data = {
+                                let mut data = file.clone();
+                                data.set_extension("");
+                                data
+                            };
+
+                            openpgp_verify_file(data, file.clone(), model_verifier)
+                        }
+                    };
+
+                    progress_bar.inc(1);
+                    (file, result)
+                })
+                .collect();
+
+            // Finish the progress_bar
+            progress_bar.finish_with_message("Validation run finished.");
+
+            // Record all files for which validation succeeded.
+            if !states.is_empty() {
+                for (file, _) in asserts.iter().filter(|(_, result)| result.is_ok()) {
+                    if let Some((repo, pkg)) = package_of_file(file)
+                        && let Some((_, state)) = states.get_mut(&repo)
+                    {
+                        state.set_validated(&pkg, self.file_type);
                     }
-                };
-
-                progress_bar.inc(1);
-                (file, result)
-            })
-            .collect();
-
-        // Finish the progress_bar
-        progress_bar.finish_with_message("Validation run finished.");
-
-        // Get all files and the respective error for which validation failed.
-        let failures: Vec<(PathBuf, Error)> = asserts
-            .into_iter()
-            .filter_map(|(path, result)| {
-                if let Err(err) = result {
-                    Some((path, err))
-                } else {
-                    None
                 }
-            })
-            .collect();
+                for (path, state) in states.values() {
+                    state.to_file(path)?;
+                }
+            }
 
-        if !failures.is_empty() {
-            return Err(Error::TestFailed {
-                failures: failures
-                    .iter()
-                    .enumerate()
-                    .map(|(index, failure)| (index, failure.0.clone(), failure.1.to_string()))
-                    .collect::<Vec<_>>(),
-            });
-        }
+            // Get all files and the respective error for which validation failed.
+            let failures: Vec<(PathBuf, Error)> = asserts
+                .into_iter()
+                .filter_map(|(path, result)| {
+                    if let Err(err) = result {
+                        Some((path, err))
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            if !failures.is_empty() {
+                return Err(Error::TestFailed {
+                    failures: failures
+                        .iter()
+                        .enumerate()
+                        .map(|(index, failure)| (index, failure.0.clone(), failure.1.to_string()))
+                        .collect::<Vec<_>>(),
+                });
+            }
 
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Searches the download directory for all files of the given type.
@@ -294,6 +269,17 @@ impl TestRunner {
     }
 }
 
+/// Returns the names of the package repository and package of a `file` extracted from a package.
+///
+/// Relies on the directory structure `${pacman-repo}/${package-name}/${file}`.
+fn package_of_file(file: &Path) -> Option<(String, String)> {
+    let pkg_dir = file.parent()?;
+    let pkg = pkg_dir.file_name()?.to_string_lossy().to_string();
+    let repo = pkg_dir.parent()?.file_name()?.to_string_lossy().to_string();
+
+    Some((repo, pkg))
+}
+
 /// Collects all regular files in a list of directories and filters them by extension.
 ///
 /// Skips non-existent paths in `dirs`.
@@ -402,6 +388,7 @@ mod tests {
             cache_dir: CacheDir::from(tmp_dir.path().to_owned()),
             file_type,
             repositories: PackageRepositories::iter().collect(),
+            skip_validated: false,
         };
         let found_files = HashSet::from_iter(runner.find_files_of_type()?.into_iter());
 
@@ -454,6 +441,7 @@ mod tests {
             cache_dir: CacheDir::from(tmp_dir.path().to_owned()),
             file_type,
             repositories: PackageRepositories::iter().collect(),
+            skip_validated: false,
         };
         let found_files = HashSet::from_iter(runner.find_files_of_type()?.into_iter());
 
@@ -500,6 +488,7 @@ mod tests {
             cache_dir: CacheDir::from(tmp_dir.path().to_owned()),
             file_type,
             repositories: PackageRepositories::iter().collect(),
+            skip_validated: false,
         };
         let found_files = HashSet::from_iter(runner.find_files_of_type()?.into_iter());
 
@@ -1,4 +1,6 @@
-use indicatif::{ProgressBar, ProgressStyle};
+use std::time::Duration;
+
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 
 /// Get a styled indicatif progress bar for reuse across the project.
 pub fn get_progress_bar(items: u64) -> ProgressBar {
@@ -12,3 +14,21 @@ pub fn get_progress_bar(items: u64) -> ProgressBar {
 
     bar
 }
+
+/// Get a styled indicatif spinner that shows the status of a task named `name`.
+///
+/// The spinner is added to `progress`, so that the status of several tasks running in parallel
+/// can be shown at once.
+/// The current status is set as message of the returned spinner.
+pub fn get_status_spinner(progress: &MultiProgress, name: &str) -> ProgressBar {
+    let spinner = progress.add(ProgressBar::new_spinner());
+    spinner.set_style(
+        ProgressStyle::with_template("{spinner:.green} [{elapsed_precise}] {prefix:.bold}: {msg}")
+            .expect("This progress syntax is valid"),
+    );
+    spinner.set_prefix(name.to_string());
+    spinner.set_message("waiting");
+    spinner.enable_steady_tick(Duration::from_millis(100));
+
+    spinner
+}
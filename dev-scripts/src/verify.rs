@@ -0,0 +1,121 @@
+//! Verification of package signatures using the [VOA] hierarchy.
+//!
+//! [VOA]: https://uapi-group.org/specifications/specs/file_hierarchy_for_the_verification_of_os_artifacts/
+
+use std::{collections::HashSet, path::PathBuf, str::FromStr};
+
+use log::debug;
+use voa::{
+    commands::{
+        PurposeAndContext,
+        get_technology_settings,
+        get_voa_config,
+        openpgp_verify,
+        read_openpgp_signatures,
+        read_openpgp_verifiers,
+    },
+    core::{Context, Os, Purpose},
+    openpgp::ModelBasedVerifier,
+    utils::RegularFile,
+};
+
+use crate::Error;
+
+/// Calls `f` with a [`ModelBasedVerifier`] for packages of Arch Linux.
+///
+/// The verifier uses the OpenPGP settings of the VOA configuration for the `package` purpose.
+/// If `read_certificates` is `true`, the artifact verifiers and trust anchors for packages are read
+/// from the VOA hierarchy.
+/// Otherwise the verifier is created without any certificates.
+///
+/// The certificates are only read once, as that significantly increases the speed of
+/// verification.
+///
+/// # Errors
+///
+/// Returns an error if the OS or one of the purposes cannot be created, or if `f` returns an
+/// error.
+pub(crate) fn with_package_verifier<T>(
+    read_certificates: bool,
+    f: impl FnOnce(&ModelBasedVerifier) -> Result<T, Error>,
+) -> Result<T, Error> {
+    let os = Os::from_str("arch").map_err(voa::Error::VoaCore)?;
+
+    let (artifact_verifiers, anchors) = if read_certificates {
+        let artifact_verifiers = read_openpgp_verifiers(
+            os.clone(),
+            Purpose::from_str("package").map_err(voa::Error::VoaCore)?,
+            Context::Default,
+        );
+        let anchors = read_openpgp_verifiers(
+            os.clone(),
+            Purpose::from_str("trust-anchor-package").map_err(voa::Error::VoaCore)?,
+            Context::Default,
+        );
+        (artifact_verifiers, anchors)
+    } else {
+        (Vec::new(), Vec::new())
+    };
+
+    let config = get_voa_config();
+    let purpose_and_context = PurposeAndContext::new(
+        Some(Purpose::from_str("package").map_err(voa::Error::VoaCore)?),
+        Some(Context::Default),
+    );
+    let openpgp_settings =
+        get_technology_settings(&config, &os, purpose_and_context.as_ref()).openpgp_settings();
+
+    let model_verifier = ModelBasedVerifier::new(openpgp_settings, &artifact_verifiers, &anchors);
+
+    f(&model_verifier)
+}
+
+/// Verifies a `file` using a `signature` and a [`ModelBasedVerifier`].
+///
+/// The success or failure of the verification is transmitted through logging.
+///
+/// # Errors
+///
+/// Returns an error if
+///
+/// - the `signature` cannot be read as an OpenPGP signature
+/// - the `file` cannot be read
+pub(crate) fn openpgp_verify_file(
+    file: PathBuf,
+    signature: PathBuf,
+    model_verifier: &ModelBasedVerifier,
+) -> Result<(), Error> {
+    debug!("Verifying {file:?} with {signature:?}");
+
+    let signatures = read_openpgp_signatures(&HashSet::from_iter([RegularFile::try_from(
+        signature.clone(),
+    )?]))?;
+
+    let check_results = openpgp_verify(
+        model_verifier,
+        &signatures,
+        &RegularFile::try_from(file.clone())?,
+    )?;
+
+    // Look at the signer info of all check results and return an error if there is none.
+    for check_result in check_results {
+        if let Some(signer_info) = check_result.signer_info() {
+            debug!(
+                "Successfully verified using {} {}",
+                signer_info
+                    .certificate()
+                    .fingerprint()
+                    .map_err(voa::Error::VoaOpenPgp)?,
+                signer_info.component_fingerprint()
+            )
+        } else {
+            return Err(Error::VoaVerificationFailed {
+                file,
+                signature,
+                context: "".to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-from alpm import alpm_types, alpm_srcinfo
+from alpm import alpm_types, alpm_srcinfo, alpm_pkginfo, alpm_buildinfo
 from alpm.alpm_types import ALPMError
 
-__all__ = ["alpm_types", "alpm_srcinfo", "ALPMError"]
+__all__ = ["alpm_types", "alpm_srcinfo", "alpm_pkginfo", "alpm_buildinfo", "ALPMError"]
@@ -0,0 +1,15 @@
+"""A module for parsing of ALPM BUILDINFO files."""
+
+from . import error, build_info, schema
+from .error import BuildInfoError
+from .build_info import BuildInfo
+from .schema import BuildInfoSchema
+
+__all__ = [
+    "BuildInfoError",
+    "error",
+    "build_info",
+    "BuildInfo",
+    "schema",
+    "BuildInfoSchema",
+]
@@ -0,0 +1,154 @@
+"""Data representations and integrations for reading of BUILDINFO data."""
+
+from pathlib import Path
+from typing import Optional, Union
+
+from alpm.alpm_buildinfo.schema import BuildInfoSchema
+from alpm.alpm_types import (
+    Architecture,
+    BuildEnvironmentOption,
+    FullVersion,
+    PackageOption,
+    Sha256Checksum,
+)
+
+class BuildInfo:
+    """The representation of BUILDINFO data.
+
+    Tracks all available versions of the file format. Fields that are only available in
+    specific versions of the file format are None for all other versions.
+    """
+
+    __hash__ = None  # type: ignore
+
+    def __init__(
+        self, buildinfo: str, schema: Optional["BuildInfoSchema"] = None
+    ) -> None:
+        """Create BuildInfo from a string representation.
+
+        Optionally validated using a BuildInfoSchema.
+
+        If schema is None, attempts to detect the BuildInfoSchema from buildinfo.
+
+        Args:
+            buildinfo (str): The content of a BUILDINFO as a string.
+            schema (Optional[BuildInfoSchema]): The schema to validate against.
+
+        Raises:
+            BuildInfoError: If the content is not a valid BUILDINFO representation or
+                does not match the provided schema.
+
+        """
+
+    @staticmethod
+    def from_file(
+        path: Union[Path, str], schema: Optional["BuildInfoSchema"] = None
+    ) -> "BuildInfo":
+        """Read the file at the specified path and convert it into a BuildInfo.
+
+        Optionally validated using a BuildInfoSchema.
+
+        If schema is None, attempts to detect the BuildInfoSchema from the file
+        contents.
+
+        Args:
+            path (Path | str): The path to the BUILDINFO file.
+            schema (Optional[BuildInfoSchema]): The schema to validate against.
+
+        Returns:
+            BuildInfo: The BuildInfo instance created from the file content.
+
+        Raises:
+            BuildInfoError: If the file cannot be read, its content is not a valid
+                BUILDINFO representation or does not match the provided schema.
+
+        """
+
+    @property
+    def schema(self) -> "BuildInfoSchema":
+        """The schema of the BUILDINFO data."""
+
+    @property
+    def pkgname(self) -> str:
+        """The name of the package."""
+
+    @property
+    def pkgbase(self) -> str:
+        """The name of the package base, from which the package originates."""
+
+    @property
+    def pkgver(self) -> "FullVersion":
+        """The full version of the package."""
+
+    @property
+    def pkgarch(self) -> "Architecture":
+        """The CPU architecture of the package."""
+
+    @property
+    def pkgbuild_sha256sum(self) -> "Sha256Checksum":
+        """The SHA-256 checksum of the PKGBUILD used to build the package."""
+
+    @property
+    def packager(self) -> str:
+        """The User ID of the entity that built the package."""
+
+    @property
+    def builddate(self) -> int:
+        """The date at which the build of the package started, as Unix timestamp."""
+
+    @property
+    def builddir(self) -> str:
+        """The absolute path to the directory in which the package was built."""
+
+    @property
+    def buildenv(self) -> list["BuildEnvironmentOption"]:
+        """The build environment options used when building the package."""
+
+    @property
+    def options(self) -> list["PackageOption"]:
+        """The package options used when building the package."""
+
+    @property
+    def installed(self) -> list[str]:
+        """The packages installed in the build environment.
+
+        Each package is represented by its name, version and architecture (e.g.
+        "example-1.0.0-1-x86_64").
+        """
+
+    @property
+    def startdir(self) -> Optional[str]:
+        """The absolute path to the directory from which the build was started.
+
+        Only available for BUILDINFO version 2, None otherwise.
+        """
+
+    @property
+    def buildtool(self) -> Optional[str]:
+        """The name of the tool used to set up the build environment.
+
+        Only available for BUILDINFO version 2, None otherwise.
+        """
+
+    @property
+    def buildtoolver(self) -> Optional[str]:
+        """The version of the tool used to set up the build environment.
+
+        Only available for BUILDINFO version 2, None otherwise.
+        """
+
+    def to_str(self) -> str:
+        """Get a string representation in valid BUILDINFO format.
+
+        Returns:
+            str: The string representation of the BuildInfo instance.
+
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+
+__all__ = [
+    "BuildInfo",
+]
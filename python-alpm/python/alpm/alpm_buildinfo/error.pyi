@@ -0,0 +1,8 @@
+"""Exceptions used by alpm_buildinfo module."""
+
+class BuildInfoError(Exception):
+    """The high-level exception that can occur when using alpm_buildinfo module."""
+
+__all__ = [
+    "BuildInfoError",
+]
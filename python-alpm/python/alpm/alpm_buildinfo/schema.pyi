@@ -0,0 +1,70 @@
+"""Schemas for BUILDINFO data."""
+
+from pathlib import Path
+from typing import Union, TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from alpm.alpm_types import SchemaVersion
+
+class BuildInfoSchema:
+    """BUILDINFO schema.
+
+    The schema of a BUILDINFO refers to the minimum required keywords, as well as the
+    complete set of available keywords in a specific version.
+    """
+
+    def __init__(self, version: Union[str, "SchemaVersion"]) -> None:
+        """Create a BuildInfoSchema from SchemaVersion or its string representation.
+
+        Args:
+            version (Union[str, SchemaVersion]): either a SchemaVersion or a string
+                representation of SchemaVersion.
+
+        Raises:
+            ALPMError: if the string representation of the version is invalid.
+            BuildInfoError: if there is no corresponding schema for the provided
+                major version.
+
+        """
+
+    @staticmethod
+    def derive_from_str(buildinfo: str) -> "BuildInfoSchema":
+        """Derive the schema from a string containing BUILDINFO data.
+
+        Args:
+            buildinfo (str): The buildinfo string to derive the schema from.
+
+        Returns:
+            BuildInfoSchema: The derived schema.
+
+        Raises:
+            BuildInfoError: if the buildinfo string cannot be parsed.
+
+        """
+
+    @staticmethod
+    def derive_from_file(path: Union[str, Path]) -> "BuildInfoSchema":
+        """Derive the schema from a file containing BUILDINFO data.
+
+        Args:
+            path (Union[str, Path]): The path to the file containing BUILDINFO data.
+
+        Returns:
+            BuildInfoSchema: The derived schema.
+
+        Raises:
+            BuildInfoError: if the file cannot be read or parsed.
+
+        """
+
+    @property
+    def version(self) -> "SchemaVersion":
+        """The schema version."""
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+
+__all__ = [
+    """BuildInfoSchema""",
+]
@@ -0,0 +1,15 @@
+"""A module for parsing of ALPM PKGINFO files."""
+
+from . import error, package_info, schema
+from .error import PackageInfoError
+from .package_info import PackageInfo
+from .schema import PackageInfoSchema
+
+__all__ = [
+    "PackageInfoError",
+    "error",
+    "package_info",
+    "PackageInfo",
+    "schema",
+    "PackageInfoSchema",
+]
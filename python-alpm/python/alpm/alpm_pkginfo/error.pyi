@@ -0,0 +1,8 @@
+"""Exceptions used by alpm_pkginfo module."""
+
+class PackageInfoError(Exception):
+    """The high-level exception that can occur when using alpm_pkginfo module."""
+
+__all__ = [
+    "PackageInfoError",
+]
@@ -0,0 +1,171 @@
+"""Data representations and integrations for reading of PKGINFO data."""
+
+from pathlib import Path
+from typing import Optional, Union
+
+from alpm.alpm_pkginfo.schema import PackageInfoSchema
+from alpm.alpm_types import (
+    Architecture,
+    FullVersion,
+    License,
+    OptionalDependency,
+    PackageRelation,
+    RelativeFilePath,
+    Url,
+)
+from alpm.type_aliases import RelationOrSoname
+
+class PackageInfo:
+    """The representation of PKGINFO data.
+
+    Tracks all available versions of the file format. Fields that are only available in
+    specific versions of the file format are None for all other versions.
+    """
+
+    __hash__ = None  # type: ignore
+
+    def __init__(
+        self, pkginfo: str, schema: Optional["PackageInfoSchema"] = None
+    ) -> None:
+        """Create PackageInfo from a string representation.
+
+        Optionally validated using a PackageInfoSchema.
+
+        If schema is None, attempts to detect the PackageInfoSchema from pkginfo.
+
+        Args:
+            pkginfo (str): The content of a PKGINFO as a string.
+            schema (Optional[PackageInfoSchema]): The schema to validate against.
+
+        Raises:
+            PackageInfoError: If the content is not a valid PKGINFO representation or
+                does not match the provided schema.
+
+        """
+
+    @staticmethod
+    def from_file(
+        path: Union[Path, str], schema: Optional["PackageInfoSchema"] = None
+    ) -> "PackageInfo":
+        """Read the file at the specified path and convert it into a PackageInfo.
+
+        Optionally validated using a PackageInfoSchema.
+
+        If schema is None, attempts to detect the PackageInfoSchema from the file
+        contents.
+
+        Args:
+            path (Path | str): The path to the PKGINFO file.
+            schema (Optional[PackageInfoSchema]): The schema to validate against.
+
+        Returns:
+            PackageInfo: The PackageInfo instance created from the file content.
+
+        Raises:
+            PackageInfoError: If the file cannot be read, its content is not a valid
+                PKGINFO representation or does not match the provided schema.
+
+        """
+
+    @property
+    def schema(self) -> "PackageInfoSchema":
+        """The schema of the PKGINFO data."""
+
+    @property
+    def pkgname(self) -> str:
+        """The name of the package."""
+
+    @property
+    def pkgbase(self) -> str:
+        """The name of the package base, from which this package originates."""
+
+    @property
+    def pkgver(self) -> "FullVersion":
+        """The full version of the package."""
+
+    @property
+    def pkgdesc(self) -> str:
+        """The description of the package."""
+
+    @property
+    def url(self) -> "Url":
+        """The URL for the project of the package."""
+
+    @property
+    def builddate(self) -> int:
+        """The date at which the build of the package started, as Unix timestamp."""
+
+    @property
+    def packager(self) -> str:
+        """The User ID of the entity that built the package."""
+
+    @property
+    def size(self) -> int:
+        """The size of the (uncompressed and unpacked) package contents in bytes."""
+
+    @property
+    def arch(self) -> "Architecture":
+        """The CPU architecture of the package."""
+
+    @property
+    def license(self) -> list["License"]:
+        """The licenses that apply to the package."""
+
+    @property
+    def replaces(self) -> list["PackageRelation"]:
+        """Virtual components or packages that the package replaces."""
+
+    @property
+    def group(self) -> list[str]:
+        """Arbitrary package groups that the package is part of."""
+
+    @property
+    def conflict(self) -> list["PackageRelation"]:
+        """Virtual components or packages that the package conflicts with."""
+
+    @property
+    def provides(self) -> list["RelationOrSoname"]:
+        """Virtual components or packages that the package provides."""
+
+    @property
+    def backup(self) -> list["RelativeFilePath"]:
+        """Relative paths to files in the package, that should be backed up."""
+
+    @property
+    def depend(self) -> list["RelationOrSoname"]:
+        """Run-time dependencies of the package."""
+
+    @property
+    def optdepend(self) -> list["OptionalDependency"]:
+        """Optional dependencies of the package."""
+
+    @property
+    def makedepend(self) -> list["PackageRelation"]:
+        """Build-time dependencies of the package."""
+
+    @property
+    def checkdepend(self) -> list["PackageRelation"]:
+        """Test dependencies of the package."""
+
+    @property
+    def xdata(self) -> Optional[list[tuple[str, str]]]:
+        """Extra data of the package as key-value pairs.
+
+        Only available for PKGINFO version 2, None otherwise.
+        """
+
+    def to_str(self) -> str:
+        """Get a string representation in valid PKGINFO format.
+
+        Returns:
+            str: The string representation of the PackageInfo instance.
+
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+
+__all__ = [
+    "PackageInfo",
+]
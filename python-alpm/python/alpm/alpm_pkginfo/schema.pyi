@@ -0,0 +1,70 @@
+"""Schemas for PKGINFO data."""
+
+from pathlib import Path
+from typing import Union, TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from alpm.alpm_types import SchemaVersion
+
+class PackageInfoSchema:
+    """PKGINFO schema.
+
+    The schema of a PKGINFO refers to the minimum required keywords, as well as the
+    complete set of available keywords in a specific version.
+    """
+
+    def __init__(self, version: Union[str, "SchemaVersion"]) -> None:
+        """Create a PackageInfoSchema from SchemaVersion or its string representation.
+
+        Args:
+            version (Union[str, SchemaVersion]): either a SchemaVersion or a string
+                representation of SchemaVersion.
+
+        Raises:
+            ALPMError: if the string representation of the version is invalid.
+            PackageInfoError: if there is no corresponding schema for the provided
+                major version.
+
+        """
+
+    @staticmethod
+    def derive_from_str(pkginfo: str) -> "PackageInfoSchema":
+        """Derive the schema from a string containing PKGINFO data.
+
+        Args:
+            pkginfo (str): The pkginfo string to derive the schema from.
+
+        Returns:
+            PackageInfoSchema: The derived schema.
+
+        Raises:
+            PackageInfoError: if the pkginfo string cannot be parsed.
+
+        """
+
+    @staticmethod
+    def derive_from_file(path: Union[str, Path]) -> "PackageInfoSchema":
+        """Derive the schema from a file containing PKGINFO data.
+
+        Args:
+            path (Union[str, Path]): The path to the file containing PKGINFO data.
+
+        Returns:
+            PackageInfoSchema: The derived schema.
+
+        Raises:
+            PackageInfoError: if the file cannot be read or parsed.
+
+        """
+
+    @property
+    def version(self) -> "SchemaVersion":
+        """The schema version."""
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+
+__all__ = [
+    """PackageInfoSchema""",
+]
@@ -91,6 +91,17 @@ class SourceInfoV1:
 
         """
 
+    def merged_packages(self) -> list["MergedPackage"]:
+        """Get a list of all packages for all architectures of the package base.
+
+        The packages are ordered by the architectures of the package base. Packages that
+        are not available for an architecture are omitted.
+
+        Returns:
+            list[MergedPackage]: A list of all packages for all architectures.
+
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
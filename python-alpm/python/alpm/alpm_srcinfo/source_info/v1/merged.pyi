@@ -1,6 +1,6 @@
 """Provides fully resolved package metadata derived from SRCINFO data."""
 
-from typing import Optional, Union
+from typing import Any, Optional, Union
 from pathlib import Path
 
 from alpm.alpm_srcinfo.source_info.v1.package import Package
@@ -15,6 +15,7 @@ from alpm.alpm_types import (
     OptionalDependency,
     Source,
     SkippableBlake2b512Checksum,
+    SkippableCrc32CksumChecksum,
     SkippableMd5Checksum,
     SkippableSha1Checksum,
     SkippableSha224Checksum,
@@ -28,7 +29,8 @@ class MergedPackage:
 
     This struct incorporates all PackageBase properties and the Package specific
     overrides in an architecture-specific representation of a package. It can be
-    created using SourceInfoV1.packages_for_architecture.
+    created using SourceInfoV1.packages_for_architecture or
+    SourceInfoV1.merged_packages.
     """
 
     def __init__(
@@ -139,6 +141,16 @@ class MergedPackage:
     def no_extracts(self) -> list[str]:
         """The list of sources for the package that are not extracted."""
 
+    def to_dict(self) -> dict[str, Any]:
+        """Get all properties of the package as a dict.
+
+        The keys of the dict are the names of the properties of the package.
+
+        Returns:
+            dict[str, Any]: The properties of the package.
+
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
@@ -182,6 +194,22 @@ class MergedSource:
     def sha512_checksum(self) -> Optional[SkippableSha512Checksum]:
         """The optional SHA-512 hash digest of source."""
 
+    @property
+    def crc_checksum(self) -> Optional[SkippableCrc32CksumChecksum]:
+        """The optional CRC-32/CKSUM hash digest of source."""
+
+    def to_dict(self) -> dict[str, Any]:
+        """Get all properties of the source as a dict.
+
+        The keys of the dict are the names of the properties of the source.
+
+        Returns:
+            dict[str, Any]: The properties of the source.
+
+        """
+
+    def __repr__(self) -> str: ...
+
 __all__ = [
     "MergedPackage",
     "MergedSource",
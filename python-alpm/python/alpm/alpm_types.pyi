@@ -1378,6 +1378,31 @@ class Version:
     def __gt__(self, other: "Version") -> bool: ...
     def __ge__(self, other: "Version") -> bool: ...
 
+def vercmp(
+    a: Union[str, "Version", "FullVersion"], b: Union[str, "Version", "FullVersion"]
+) -> int:
+    """Compare two versions.
+
+    Output behavior is based on the behavior of the vercmp tool, which allows to
+    compare versions with and without PackageRelease and Epoch. As with vercmp, the
+    PackageRelease is only considered if both versions provide one.
+
+    Args:
+        a (Union[str, Version, FullVersion]): The first version or its string
+            representation.
+        b (Union[str, Version, FullVersion]): The second version or its string
+            representation.
+
+    Returns:
+        int: 1 if a is newer than b,
+             0 if they are equal,
+             -1 if a is older than b.
+
+    Raises:
+        ALPMError: If a or b is a string that can't be parsed as a valid version.
+
+    """
+
 __all__ = [
     "ALPMError",
     "Blake2b512Checksum",
@@ -1432,4 +1457,5 @@ __all__ = [
     "ElfArchitectureFormat",
     "FullVersion",
     "Version",
+    "vercmp",
 ]
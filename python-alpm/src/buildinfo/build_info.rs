@@ -0,0 +1,174 @@
+use std::path::PathBuf;
+
+use alpm_common::MetadataFile;
+use pyo3::prelude::*;
+
+use crate::{
+    buildinfo::schema::BuildInfoSchema,
+    macros::{impl_from, vec_convert},
+    types::{
+        checksum::Sha256Checksum,
+        env::{BuildEnvironmentOption, PackageOption},
+        system::Architecture,
+        version::FullVersion,
+    },
+};
+
+/// Returns a reference to the field `$field`, which is present in all variants of
+/// [`alpm_buildinfo::BuildInfo`].
+macro_rules! field {
+    ($build_info:expr, $field:ident) => {
+        match $build_info {
+            alpm_buildinfo::BuildInfo::V1(inner) => &inner.$field,
+            alpm_buildinfo::BuildInfo::V2(inner) => &inner.$field,
+        }
+    };
+}
+
+#[pyclass(frozen, eq, from_py_object)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BuildInfo(alpm_buildinfo::BuildInfo);
+
+#[pymethods]
+impl BuildInfo {
+    #[new]
+    #[pyo3(signature = (buildinfo, schema = None))]
+    fn new(
+        buildinfo: &str,
+        schema: Option<BuildInfoSchema>,
+    ) -> Result<Self, crate::buildinfo::error::Error> {
+        let schema: Option<alpm_buildinfo::BuildInfoSchema> = schema.map(From::from);
+        let inner = alpm_buildinfo::BuildInfo::from_str_with_schema(buildinfo, schema)?;
+        Ok(inner.into())
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (path, schema = None))]
+    fn from_file(
+        path: PathBuf,
+        schema: Option<BuildInfoSchema>,
+    ) -> Result<Self, crate::buildinfo::error::Error> {
+        let schema: Option<alpm_buildinfo::BuildInfoSchema> = schema.map(From::from);
+        let inner = alpm_buildinfo::BuildInfo::from_file_with_schema(&path, schema)?;
+        Ok(inner.into())
+    }
+
+    #[getter]
+    fn schema(&self) -> BuildInfoSchema {
+        match self.0 {
+            alpm_buildinfo::BuildInfo::V1(_) => alpm_buildinfo::BuildInfoSchema::V1(
+                alpm_types::SchemaVersion::new(semver::Version::new(1, 0, 0)),
+            ),
+            alpm_buildinfo::BuildInfo::V2(_) => alpm_buildinfo::BuildInfoSchema::V2(
+                alpm_types::SchemaVersion::new(semver::Version::new(2, 0, 0)),
+            ),
+        }
+        .into()
+    }
+
+    #[getter]
+    fn pkgname(&self) -> String {
+        field!(&self.0, pkgname).to_string()
+    }
+
+    #[getter]
+    fn pkgbase(&self) -> String {
+        field!(&self.0, pkgbase).to_string()
+    }
+
+    #[getter]
+    fn pkgver(&self) -> FullVersion {
+        field!(&self.0, pkgver).clone().into()
+    }
+
+    #[getter]
+    fn pkgarch(&self) -> Architecture {
+        field!(&self.0, pkgarch).clone().into()
+    }
+
+    #[getter]
+    fn pkgbuild_sha256sum(&self) -> Sha256Checksum {
+        field!(&self.0, pkgbuild_sha256sum).clone().into()
+    }
+
+    #[getter]
+    fn packager(&self) -> String {
+        field!(&self.0, packager).to_string()
+    }
+
+    #[getter]
+    fn builddate(&self) -> i64 {
+        *field!(&self.0, builddate)
+    }
+
+    #[getter]
+    fn builddir(&self) -> String {
+        field!(&self.0, builddir).to_string()
+    }
+
+    #[getter]
+    fn buildenv(&self) -> Vec<BuildEnvironmentOption> {
+        vec_convert!(field!(&self.0, buildenv).clone())
+    }
+
+    #[getter]
+    fn options(&self) -> Vec<PackageOption> {
+        vec_convert!(field!(&self.0, options).clone())
+    }
+
+    #[getter]
+    fn installed(&self) -> Vec<String> {
+        field!(&self.0, installed)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[getter]
+    fn startdir(&self) -> Option<String> {
+        match &self.0 {
+            alpm_buildinfo::BuildInfo::V1(_) => None,
+            alpm_buildinfo::BuildInfo::V2(inner) => Some(inner.startdir.to_string()),
+        }
+    }
+
+    #[getter]
+    fn buildtool(&self) -> Option<String> {
+        match &self.0 {
+            alpm_buildinfo::BuildInfo::V1(_) => None,
+            alpm_buildinfo::BuildInfo::V2(inner) => Some(inner.buildtool.to_string()),
+        }
+    }
+
+    #[getter]
+    fn buildtoolver(&self) -> Option<String> {
+        match &self.0 {
+            alpm_buildinfo::BuildInfo::V1(_) => None,
+            alpm_buildinfo::BuildInfo::V2(inner) => Some(inner.buildtoolver.to_string()),
+        }
+    }
+
+    fn to_str(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __str__(&self) -> String {
+        self.to_str()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "BuildInfo(pkgname='{}', pkgver='{}')",
+            field!(&self.0, pkgname),
+            field!(&self.0, pkgver),
+        )
+    }
+}
+
+impl_from!(BuildInfo, alpm_buildinfo::BuildInfo);
+
+#[pymodule(gil_used = false, name = "build_info", submodule)]
+pub mod py_build_info {
+    #[pymodule_export]
+    use super::BuildInfo;
+}
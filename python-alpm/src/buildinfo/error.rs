@@ -0,0 +1,28 @@
+use pyo3::{create_exception, prelude::*};
+
+use crate::macros::impl_from;
+
+create_exception!(
+    alpm_buildinfo,
+    BuildInfoError,
+    pyo3::exceptions::PyException,
+    "The high-level exception that can occur when using alpm_buildinfo module."
+);
+
+/// Error wrapper for alpm_buildinfo::Error, so that we can convert it to [`PyErr`].
+#[derive(Debug)]
+pub struct Error(alpm_buildinfo::Error);
+
+impl_from!(Error, alpm_buildinfo::Error);
+
+impl From<Error> for PyErr {
+    fn from(value: Error) -> PyErr {
+        BuildInfoError::new_err(value.0.to_string())
+    }
+}
+
+#[pymodule(gil_used = false, name = "error", submodule)]
+pub mod py_error {
+    #[pymodule_export]
+    use super::BuildInfoError;
+}
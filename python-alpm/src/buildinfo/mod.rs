@@ -0,0 +1,32 @@
+use pyo3::prelude::*;
+
+mod build_info;
+mod error;
+mod schema;
+
+#[pymodule(gil_used = false, name = "alpm_buildinfo", submodule)]
+pub mod py_buildinfo {
+    use pyo3::prelude::*;
+
+    #[pymodule_export]
+    use super::build_info::BuildInfo;
+    #[pymodule_export]
+    use super::build_info::py_build_info;
+    #[pymodule_export]
+    use super::error::BuildInfoError;
+    #[pymodule_export]
+    use super::error::py_error;
+    #[pymodule_export]
+    use super::schema::BuildInfoSchema;
+    #[pymodule_export]
+    use super::schema::py_schema;
+
+    #[pymodule_init]
+    fn init(m: &Bound<'_, PyModule>) -> PyResult<()> {
+        let modules = PyModule::import(m.py(), "sys")?.getattr("modules")?;
+        modules.set_item("alpm.alpm_buildinfo.build_info", m.getattr("build_info")?)?;
+        modules.set_item("alpm.alpm_buildinfo.error", m.getattr("error")?)?;
+        modules.set_item("alpm.alpm_buildinfo.schema", m.getattr("schema")?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use alpm_common::FileFormatSchema;
+use pyo3::prelude::*;
+
+use crate::{
+    macros::impl_from,
+    types::version::{SchemaVersion, SchemaVersionOrStr},
+};
+
+#[pyclass(frozen, eq, from_py_object)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BuildInfoSchema(alpm_buildinfo::BuildInfoSchema);
+
+#[pymethods]
+impl BuildInfoSchema {
+    #[new]
+    fn new(version: SchemaVersionOrStr) -> PyResult<BuildInfoSchema> {
+        let schema_version: alpm_types::SchemaVersion = version.try_into()?;
+        let inner = alpm_buildinfo::BuildInfoSchema::try_from(schema_version)
+            .map_err(crate::buildinfo::error::Error::from)?;
+        Ok(inner.into())
+    }
+
+    #[staticmethod]
+    fn derive_from_str(buildinfo: &str) -> Result<Self, crate::buildinfo::error::Error> {
+        let inner = alpm_buildinfo::BuildInfoSchema::derive_from_str(buildinfo)?;
+        Ok(inner.into())
+    }
+
+    #[staticmethod]
+    fn derive_from_file(path: std::path::PathBuf) -> Result<Self, crate::buildinfo::error::Error> {
+        let inner = alpm_buildinfo::BuildInfoSchema::derive_from_file(&path)?;
+        Ok(inner.into())
+    }
+
+    #[getter]
+    fn version(&self) -> SchemaVersion {
+        self.0.inner().clone().into()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("BuildInfoSchema({})", self.0)
+    }
+}
+
+impl_from!(BuildInfoSchema, alpm_buildinfo::BuildInfoSchema);
+
+#[pymodule(gil_used = false, name = "schema", submodule)]
+pub mod py_schema {
+    #[pymodule_export]
+    use super::BuildInfoSchema;
+}
@@ -3,7 +3,9 @@
 
 use pyo3::prelude::*;
 
+mod buildinfo;
 pub(crate) mod macros;
+mod pkginfo;
 mod srcinfo;
 mod types;
 
@@ -11,6 +13,10 @@ mod types;
 mod py_alpm {
     use pyo3::prelude::*;
 
+    #[pymodule_export]
+    use crate::buildinfo::py_buildinfo;
+    #[pymodule_export]
+    use crate::pkginfo::py_pkginfo;
     #[pymodule_export]
     use crate::srcinfo::py_srcinfo;
     #[pymodule_export]
@@ -23,6 +29,8 @@ mod py_alpm {
         let modules = PyModule::import(m.py(), "sys")?.getattr("modules")?;
         modules.set_item("alpm.alpm_types", m.getattr("alpm_types")?)?;
         modules.set_item("alpm.alpm_srcinfo", m.getattr("alpm_srcinfo")?)?;
+        modules.set_item("alpm.alpm_pkginfo", m.getattr("alpm_pkginfo")?)?;
+        modules.set_item("alpm.alpm_buildinfo", m.getattr("alpm_buildinfo")?)?;
         Ok(())
     }
 }
@@ -0,0 +1,32 @@
+use pyo3::prelude::*;
+
+mod error;
+mod package_info;
+mod schema;
+
+#[pymodule(gil_used = false, name = "alpm_pkginfo", submodule)]
+pub mod py_pkginfo {
+    use pyo3::prelude::*;
+
+    #[pymodule_export]
+    use super::error::PackageInfoError;
+    #[pymodule_export]
+    use super::error::py_error;
+    #[pymodule_export]
+    use super::package_info::PackageInfo;
+    #[pymodule_export]
+    use super::package_info::py_package_info;
+    #[pymodule_export]
+    use super::schema::PackageInfoSchema;
+    #[pymodule_export]
+    use super::schema::py_schema;
+
+    #[pymodule_init]
+    fn init(m: &Bound<'_, PyModule>) -> PyResult<()> {
+        let modules = PyModule::import(m.py(), "sys")?.getattr("modules")?;
+        modules.set_item("alpm.alpm_pkginfo.error", m.getattr("error")?)?;
+        modules.set_item("alpm.alpm_pkginfo.package_info", m.getattr("package_info")?)?;
+        modules.set_item("alpm.alpm_pkginfo.schema", m.getattr("schema")?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+use std::path::PathBuf;
+
+use alpm_common::MetadataFile;
+use pyo3::prelude::*;
+
+use crate::{
+    macros::{impl_from, vec_convert},
+    pkginfo::schema::PackageInfoSchema,
+    types::{
+        license::License,
+        path::RelativeFilePath,
+        relation::{OptionalDependency, PackageRelation, RelationOrSoname},
+        system::Architecture,
+        url::Url,
+        version::FullVersion,
+    },
+};
+
+/// Returns a reference to the field `$field`, which is present in all variants of
+/// [`alpm_pkginfo::PackageInfo`].
+macro_rules! field {
+    ($package_info:expr, $field:ident) => {
+        match $package_info {
+            alpm_pkginfo::PackageInfo::V1(inner) => &inner.$field,
+            alpm_pkginfo::PackageInfo::V2(inner) => &inner.$field,
+        }
+    };
+}
+
+#[pyclass(frozen, eq, from_py_object)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PackageInfo(alpm_pkginfo::PackageInfo);
+
+#[pymethods]
+impl PackageInfo {
+    #[new]
+    #[pyo3(signature = (pkginfo, schema = None))]
+    fn new(
+        pkginfo: &str,
+        schema: Option<PackageInfoSchema>,
+    ) -> Result<Self, crate::pkginfo::error::Error> {
+        let schema: Option<alpm_pkginfo::PackageInfoSchema> = schema.map(From::from);
+        let inner = alpm_pkginfo::PackageInfo::from_str_with_schema(pkginfo, schema)?;
+        Ok(inner.into())
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (path, schema = None))]
+    fn from_file(
+        path: PathBuf,
+        schema: Option<PackageInfoSchema>,
+    ) -> Result<Self, crate::pkginfo::error::Error> {
+        let schema: Option<alpm_pkginfo::PackageInfoSchema> = schema.map(From::from);
+        let inner = alpm_pkginfo::PackageInfo::from_file_with_schema(&path, schema)?;
+        Ok(inner.into())
+    }
+
+    #[getter]
+    fn schema(&self) -> PackageInfoSchema {
+        match self.0 {
+            alpm_pkginfo::PackageInfo::V1(_) => alpm_pkginfo::PackageInfoSchema::V1(
+                alpm_types::SchemaVersion::new(semver::Version::new(1, 0, 0)),
+            ),
+            alpm_pkginfo::PackageInfo::V2(_) => alpm_pkginfo::PackageInfoSchema::V2(
+                alpm_types::SchemaVersion::new(semver::Version::new(2, 0, 0)),
+            ),
+        }
+        .into()
+    }
+
+    #[getter]
+    fn pkgname(&self) -> String {
+        field!(&self.0, pkgname).to_string()
+    }
+
+    #[getter]
+    fn pkgbase(&self) -> String {
+        field!(&self.0, pkgbase).to_string()
+    }
+
+    #[getter]
+    fn pkgver(&self) -> FullVersion {
+        field!(&self.0, pkgver).clone().into()
+    }
+
+    #[getter]
+    fn pkgdesc(&self) -> String {
+        field!(&self.0, pkgdesc).to_string()
+    }
+
+    #[getter]
+    fn url(&self) -> Url {
+        field!(&self.0, url).clone().into()
+    }
+
+    #[getter]
+    fn builddate(&self) -> i64 {
+        *field!(&self.0, builddate)
+    }
+
+    #[getter]
+    fn packager(&self) -> String {
+        field!(&self.0, packager).to_string()
+    }
+
+    #[getter]
+    fn size(&self) -> u64 {
+        *field!(&self.0, size)
+    }
+
+    #[getter]
+    fn arch(&self) -> Architecture {
+        field!(&self.0, arch).clone().into()
+    }
+
+    #[getter]
+    fn license(&self) -> Vec<License> {
+        vec_convert!(field!(&self.0, license).clone())
+    }
+
+    #[getter]
+    fn replaces(&self) -> Vec<PackageRelation> {
+        vec_convert!(field!(&self.0, replaces).clone())
+    }
+
+    #[getter]
+    fn group(&self) -> Vec<String> {
+        field!(&self.0, group).clone()
+    }
+
+    #[getter]
+    fn conflict(&self) -> Vec<PackageRelation> {
+        vec_convert!(field!(&self.0, conflict).clone())
+    }
+
+    #[getter]
+    fn provides(&self) -> Vec<RelationOrSoname> {
+        vec_convert!(field!(&self.0, provides).clone())
+    }
+
+    #[getter]
+    fn backup(&self) -> Vec<RelativeFilePath> {
+        vec_convert!(field!(&self.0, backup).clone())
+    }
+
+    #[getter]
+    fn depend(&self) -> Vec<RelationOrSoname> {
+        vec_convert!(field!(&self.0, depend).clone())
+    }
+
+    #[getter]
+    fn optdepend(&self) -> Vec<OptionalDependency> {
+        vec_convert!(field!(&self.0, optdepend).clone())
+    }
+
+    #[getter]
+    fn makedepend(&self) -> Vec<PackageRelation> {
+        vec_convert!(field!(&self.0, makedepend).clone())
+    }
+
+    #[getter]
+    fn checkdepend(&self) -> Vec<PackageRelation> {
+        vec_convert!(field!(&self.0, checkdepend).clone())
+    }
+
+    #[getter]
+    fn xdata(&self) -> Option<Vec<(String, String)>> {
+        match &self.0 {
+            alpm_pkginfo::PackageInfo::V1(_) => None,
+            alpm_pkginfo::PackageInfo::V2(inner) => Some(
+                Vec::<alpm_types::ExtraDataEntry>::from(inner.xdata.clone())
+                    .into_iter()
+                    .map(|entry| (entry.key().to_string(), entry.value().to_string()))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn to_str(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __str__(&self) -> String {
+        self.to_str()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PackageInfo(pkgname='{}', pkgver='{}')",
+            field!(&self.0, pkgname),
+            field!(&self.0, pkgver),
+        )
+    }
+}
+
+impl_from!(PackageInfo, alpm_pkginfo::PackageInfo);
+
+#[pymodule(gil_used = false, name = "package_info", submodule)]
+pub mod py_package_info {
+    #[pymodule_export]
+    use super::PackageInfo;
+}
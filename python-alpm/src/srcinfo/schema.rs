@@ -1,9 +1,10 @@
-use std::str::FromStr;
-
 use alpm_common::FileFormatSchema;
 use pyo3::prelude::*;
 
-use crate::{macros::impl_from, types::version::SchemaVersion};
+use crate::{
+    macros::impl_from,
+    types::version::{SchemaVersion, SchemaVersionOrStr},
+};
 
 #[pyclass(frozen, eq, from_py_object)]
 #[derive(Clone, Debug, PartialEq)]
@@ -49,26 +50,6 @@ impl SourceInfoSchema {
 
 impl_from!(SourceInfoSchema, alpm_srcinfo::SourceInfoSchema);
 
-#[derive(Debug, FromPyObject, IntoPyObject)]
-pub enum SchemaVersionOrStr {
-    SchemaVersion(SchemaVersion),
-    Str(String),
-}
-
-impl TryFrom<SchemaVersionOrStr> for alpm_types::SchemaVersion {
-    type Error = crate::types::Error;
-
-    fn try_from(value: SchemaVersionOrStr) -> Result<Self, Self::Error> {
-        match value {
-            SchemaVersionOrStr::SchemaVersion(v) => Ok(v.into()),
-            SchemaVersionOrStr::Str(s) => {
-                let v = alpm_types::SchemaVersion::from_str(&s)?;
-                Ok(v)
-            }
-        }
-    }
-}
-
 #[pymodule(gil_used = false, name = "schema", submodule)]
 pub mod py_schema {
     #[pymodule_export]
@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 use alpm_srcinfo::source_info::v1::merged as alpm_srcinfo_merged;
-use pyo3::prelude::*;
+use pyo3::{prelude::*, types::PyDict};
 
 use crate::{
     macros::{impl_from, vec_convert},
@@ -9,6 +9,7 @@ use crate::{
     types::{
         checksum::{
             SkippableBlake2b512Checksum,
+            SkippableCrc32CksumChecksum,
             SkippableMd5Checksum,
             SkippableSha1Checksum,
             SkippableSha224Checksum,
@@ -181,6 +182,32 @@ impl MergedPackage {
         self.0.no_extracts.clone()
     }
 
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("name", self.name())?;
+        dict.set_item("description", self.description())?;
+        dict.set_item("url", self.url())?;
+        dict.set_item("licenses", self.licenses())?;
+        dict.set_item("architecture", self.architecture())?;
+        dict.set_item("changelog", self.changelog())?;
+        dict.set_item("install", self.install())?;
+        dict.set_item("groups", self.groups())?;
+        dict.set_item("options", self.options())?;
+        dict.set_item("backups", self.backups())?;
+        dict.set_item("version", self.version())?;
+        dict.set_item("pgp_fingerprints", self.pgp_fingerprints())?;
+        dict.set_item("dependencies", self.dependencies())?;
+        dict.set_item("optional_dependencies", self.optional_dependencies())?;
+        dict.set_item("provides", self.provides())?;
+        dict.set_item("conflicts", self.conflicts())?;
+        dict.set_item("replaces", self.replaces())?;
+        dict.set_item("check_dependencies", self.check_dependencies())?;
+        dict.set_item("make_dependencies", self.make_dependencies())?;
+        dict.set_item("sources", self.sources())?;
+        dict.set_item("no_extracts", self.no_extracts())?;
+        Ok(dict)
+    }
+
     fn __str__(&self) -> String {
         self.0.name.to_string()
     }
@@ -242,6 +269,29 @@ impl MergedSource {
     fn sha512_checksum(&self) -> Option<SkippableSha512Checksum> {
         self.0.sha512_checksum.clone().map(From::from)
     }
+
+    #[getter]
+    fn crc_checksum(&self) -> Option<SkippableCrc32CksumChecksum> {
+        self.0.crc_checksum.clone().map(From::from)
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("source", self.source())?;
+        dict.set_item("b2_checksum", self.b2_checksum())?;
+        dict.set_item("md5_checksum", self.md5_checksum())?;
+        dict.set_item("sha1_checksum", self.sha1_checksum())?;
+        dict.set_item("sha224_checksum", self.sha224_checksum())?;
+        dict.set_item("sha256_checksum", self.sha256_checksum())?;
+        dict.set_item("sha384_checksum", self.sha384_checksum())?;
+        dict.set_item("sha512_checksum", self.sha512_checksum())?;
+        dict.set_item("crc_checksum", self.crc_checksum())?;
+        Ok(dict)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("MergedSource(source='{}')", self.0.source)
+    }
 }
 
 impl_from!(MergedSource, alpm_srcinfo_merged::MergedSource);
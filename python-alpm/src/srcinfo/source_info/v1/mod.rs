@@ -52,6 +52,14 @@ impl SourceInfoV1 {
             .collect()
     }
 
+    pub fn merged_packages(&self) -> Vec<merged::MergedPackage> {
+        Vec::<alpm_types::Architecture>::from(&self.0.base.architectures)
+            .into_iter()
+            .flat_map(|architecture| self.0.packages_for_architecture(architecture))
+            .map(From::from)
+            .collect()
+    }
+
     pub fn as_srcinfo(&self) -> String {
         self.0.as_srcinfo()
     }
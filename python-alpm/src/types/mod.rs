@@ -123,6 +123,8 @@ pub mod py_types {
     use version::SchemaVersion;
     #[pymodule_export]
     use version::Version;
+    #[pymodule_export]
+    use version::vercmp;
 
     use super::*;
 }
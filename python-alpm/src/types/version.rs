@@ -111,6 +111,26 @@ impl SchemaVersion {
 
 impl_from!(SchemaVersion, alpm_types::SchemaVersion);
 
+#[derive(Debug, FromPyObject, IntoPyObject)]
+pub enum SchemaVersionOrStr {
+    SchemaVersion(SchemaVersion),
+    Str(String),
+}
+
+impl TryFrom<SchemaVersionOrStr> for alpm_types::SchemaVersion {
+    type Error = crate::types::Error;
+
+    fn try_from(value: SchemaVersionOrStr) -> Result<Self, Self::Error> {
+        match value {
+            SchemaVersionOrStr::SchemaVersion(v) => Ok(v.into()),
+            SchemaVersionOrStr::Str(s) => {
+                let v = alpm_types::SchemaVersion::from_str(&s)?;
+                Ok(v)
+            }
+        }
+    }
+}
+
 #[pyclass(frozen, eq, ord, from_py_object)]
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
 pub struct Epoch(alpm_types::Epoch);
@@ -307,3 +327,37 @@ impl Version {
 }
 
 impl_from!(Version, alpm_types::Version);
+
+#[derive(Debug, FromPyObject)]
+pub enum VersionOrStr {
+    Version(Version),
+    FullVersion(FullVersion),
+    Str(String),
+}
+
+impl TryFrom<VersionOrStr> for alpm_types::Version {
+    type Error = crate::types::Error;
+
+    fn try_from(value: VersionOrStr) -> Result<Self, Self::Error> {
+        match value {
+            VersionOrStr::Version(v) => Ok(v.into()),
+            VersionOrStr::FullVersion(v) => Ok(alpm_types::FullVersion::from(v).into()),
+            VersionOrStr::Str(s) => {
+                let v = alpm_types::Version::from_str(&s)?;
+                Ok(v)
+            }
+        }
+    }
+}
+
+#[pyfunction]
+pub fn vercmp(a: VersionOrStr, b: VersionOrStr) -> Result<i8, crate::types::Error> {
+    let mut a: alpm_types::Version = a.try_into()?;
+    let mut b: alpm_types::Version = b.try_into()?;
+    // Like vercmp, only consider the package release if both versions provide one.
+    if a.pkgrel.is_none() || b.pkgrel.is_none() {
+        a.pkgrel = None;
+        b.pkgrel = None;
+    }
+    Ok(alpm_types::Version::vercmp(&a, &b))
+}
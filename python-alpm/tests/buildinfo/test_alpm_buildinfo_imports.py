@@ -0,0 +1,20 @@
+"""Tests that all items in alpm_buildinfo module can be imported."""
+
+
+def test_shortcut_imports() -> None:
+    """Test that all items reexported from submodule can be imported from
+    alpm.alpm_buildinfo.
+    """
+    from alpm.alpm_buildinfo import (  # noqa: F401
+        BuildInfo,
+        BuildInfoError,
+        BuildInfoSchema,
+    )
+
+
+def test_imports() -> None:
+    """Test that all items can be imported without errors."""
+    from alpm.alpm_buildinfo import build_info, error, schema  # noqa: F401
+    from alpm.alpm_buildinfo.build_info import BuildInfo  # noqa: F401
+    from alpm.alpm_buildinfo.error import BuildInfoError  # noqa: F401
+    from alpm.alpm_buildinfo.schema import BuildInfoSchema  # noqa: F401
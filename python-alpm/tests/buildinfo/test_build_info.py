@@ -0,0 +1,110 @@
+"""Tests for BuildInfo."""
+
+import tempfile
+from pathlib import Path
+
+import pytest
+from alpm.alpm_buildinfo import BuildInfo, BuildInfoError, BuildInfoSchema
+from alpm.alpm_types import (
+    Architecture,
+    BuildEnvironmentOption,
+    Epoch,
+    FullVersion,
+    PackageOption,
+    PackageRelease,
+    PackageVersion,
+    Sha256Checksum,
+)
+
+
+def test_from_str_v1(valid_buildinfo_v1_content: str) -> None:
+    """Test parsing valid BUILDINFO version 1 data from string."""
+    buildinfo = BuildInfo(valid_buildinfo_v1_content)
+    assert buildinfo.schema == BuildInfoSchema("1")
+    assert buildinfo.pkgname == "foo"
+    assert buildinfo.startdir is None
+    assert buildinfo.buildtool is None
+    assert buildinfo.buildtoolver is None
+
+
+def test_from_str_v2(valid_buildinfo_v2_content: str) -> None:
+    """Test parsing valid BUILDINFO version 2 data from string."""
+    buildinfo = BuildInfo(valid_buildinfo_v2_content)
+    assert buildinfo.schema == BuildInfoSchema("2")
+    assert buildinfo.pkgname == "foo"
+    assert buildinfo.pkgbase == "foo"
+    assert buildinfo.pkgver == FullVersion(
+        PackageVersion("1.0.0"), PackageRelease(1), Epoch(1)
+    )
+    assert buildinfo.pkgarch == Architecture("any")
+    assert buildinfo.pkgbuild_sha256sum == Sha256Checksum(
+        "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c"
+    )
+    assert buildinfo.packager == "Foobar McFooface <foobar@mcfooface.org>"
+    assert buildinfo.builddate == 1
+    assert buildinfo.builddir == "/build"
+    assert buildinfo.buildenv == [
+        BuildEnvironmentOption("ccache"),
+        BuildEnvironmentOption("color"),
+    ]
+    assert buildinfo.options == [PackageOption("lto"), PackageOption("!strip")]
+    assert buildinfo.installed == ["bar-1.2.3-1-any", "beh-2.2.3-4-any"]
+    assert buildinfo.startdir == "/startdir/"
+    assert buildinfo.buildtool == "devtools"
+    assert buildinfo.buildtoolver == "1:1.2.1-1-any"
+
+
+def test_from_str_with_schema(valid_buildinfo_v2_content: str) -> None:
+    """Test parsing BUILDINFO data from string with explicit schema."""
+    buildinfo = BuildInfo(valid_buildinfo_v2_content, BuildInfoSchema("2"))
+    assert buildinfo.schema == BuildInfoSchema("2")
+
+
+def test_from_str_schema_mismatch(valid_buildinfo_v1_content: str) -> None:
+    """Test parsing BUILDINFO data that does not match the schema raises error."""
+    with pytest.raises(BuildInfoError):
+        BuildInfo(valid_buildinfo_v1_content, BuildInfoSchema("2"))
+
+
+@pytest.mark.parametrize(
+    "content",
+    [
+        "not valid",
+        "",
+        "format = 2\npkgname = foo\n",
+    ],
+)
+def test_from_str_invalid(content: str) -> None:
+    """Test parsing invalid BUILDINFO data from string raises error."""
+    with pytest.raises(BuildInfoError):
+        BuildInfo(content)
+
+
+@pytest.mark.parametrize("schema", [None, BuildInfoSchema("2")])
+def test_from_file(
+    valid_buildinfo_v2_content: str, schema: BuildInfoSchema | None
+) -> None:
+    """Test parsing BUILDINFO data from file."""
+    with tempfile.NamedTemporaryFile(mode="w", suffix=".BUILDINFO", delete=True) as tmp:
+        tmp.write(valid_buildinfo_v2_content)
+        tmp.flush()
+
+        buildinfo = BuildInfo.from_file(Path(tmp.name), schema)
+        assert buildinfo == BuildInfo(valid_buildinfo_v2_content)
+
+
+def test_from_file_nonexistent() -> None:
+    """Test parsing BUILDINFO data from nonexistent file raises error."""
+    with pytest.raises(BuildInfoError):
+        BuildInfo.from_file("/nonexistent/file.BUILDINFO")
+
+
+@pytest.mark.parametrize(
+    "content_fixture", ["valid_buildinfo_v1_content", "valid_buildinfo_v2_content"]
+)
+def test_to_str(content_fixture: str, request: pytest.FixtureRequest) -> None:
+    """Test that BUILDINFO data roundtrips through its string representation."""
+    content = request.getfixturevalue(content_fixture)
+    buildinfo = BuildInfo(content)
+    assert buildinfo.to_str() == content
+    assert str(buildinfo) == content
@@ -0,0 +1,54 @@
+"""Tests for BuildInfoSchema."""
+
+import tempfile
+
+import pytest
+from alpm.alpm_buildinfo import BuildInfoError, BuildInfoSchema
+from alpm.alpm_types import SchemaVersion
+
+
+def test_creation() -> None:
+    """Test creating BuildInfoSchema with different version formats."""
+    assert str(BuildInfoSchema("1").version) == "1.0.0"
+    assert str(BuildInfoSchema("2.0.0").version) == "2.0.0"
+    assert str(BuildInfoSchema(SchemaVersion(2)).version) == "2.0.0"
+
+
+def test_creation_unsupported_version() -> None:
+    """Test creating BuildInfoSchema with an unsupported version raises error."""
+    with pytest.raises(BuildInfoError):
+        BuildInfoSchema("3")
+
+
+def test_derive_from_str(
+    valid_buildinfo_v1_content: str, valid_buildinfo_v2_content: str
+) -> None:
+    """Test deriving BuildInfoSchema from BUILDINFO string content."""
+    assert BuildInfoSchema.derive_from_str(
+        valid_buildinfo_v1_content
+    ) == BuildInfoSchema("1")
+    assert BuildInfoSchema.derive_from_str(
+        valid_buildinfo_v2_content
+    ) == BuildInfoSchema("2")
+
+
+def test_derive_from_str_invalid() -> None:
+    """Test deriving BuildInfoSchema from data without format raises error."""
+    with pytest.raises(BuildInfoError):
+        BuildInfoSchema.derive_from_str("pkgname = foo\n")
+
+
+def test_derive_from_file(valid_buildinfo_v2_content: str) -> None:
+    """Test deriving BuildInfoSchema from BUILDINFO file."""
+    with tempfile.NamedTemporaryFile(mode="w", suffix=".BUILDINFO", delete=True) as tmp:
+        tmp.write(valid_buildinfo_v2_content)
+        tmp.flush()
+
+        schema = BuildInfoSchema.derive_from_file(tmp.name)
+        assert str(schema.version) == "2.0.0"
+
+
+def test_derive_from_file_nonexistent() -> None:
+    """Test deriving BuildInfoSchema from nonexistent file raises error."""
+    with pytest.raises(BuildInfoError):
+        BuildInfoSchema.derive_from_file("/nonexistent/file.BUILDINFO")
@@ -97,3 +97,90 @@ package_example() {
     echo "Building something"
 }
 """
+
+
+@pytest.fixture
+def valid_pkginfo_v1_content() -> str:
+    """Fixture providing valid PKGINFO version 1 content."""
+    return """pkgname = example
+pkgbase = example
+pkgver = 1:1.0.0-1
+pkgdesc = A project that does something
+url = https://example.org/
+builddate = 1729181726
+packager = John Doe <john@example.org>
+size = 181849963
+arch = any
+license = GPL-3.0-or-later
+depend = glibc
+"""
+
+
+@pytest.fixture
+def valid_pkginfo_v2_content() -> str:
+    """Fixture providing valid PKGINFO version 2 content."""
+    return """pkgname = example
+pkgbase = example
+xdata = pkgtype=pkg
+pkgver = 1:1.0.0-1
+pkgdesc = A project that does something
+url = https://example.org/
+builddate = 1729181726
+packager = John Doe <john@example.org>
+size = 181849963
+arch = any
+license = GPL-3.0-or-later
+license = LGPL-3.0-or-later
+replaces = other-package>0.9.0-3
+group = package-group
+conflict = conflicting-package<1.0.0
+provides = some-component
+backup = etc/example/config.toml
+depend = glibc
+depend = libother.so=0-64
+optdepend = python: for special-python-script.py
+makedepend = cmake
+checkdepend = extra-test-tool
+"""
+
+
+@pytest.fixture
+def valid_buildinfo_v1_content() -> str:
+    """Fixture providing valid BUILDINFO version 1 content."""
+    return """format = 1
+pkgname = foo
+pkgbase = foo
+pkgver = 1:1.0.0-1
+pkgarch = any
+pkgbuild_sha256sum = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+packager = Foobar McFooface <foobar@mcfooface.org>
+builddate = 1
+builddir = /build
+buildenv = ccache
+options = lto
+installed = bar-1.2.3-1-any
+"""
+
+
+@pytest.fixture
+def valid_buildinfo_v2_content() -> str:
+    """Fixture providing valid BUILDINFO version 2 content."""
+    return """format = 2
+pkgname = foo
+pkgbase = foo
+pkgver = 1:1.0.0-1
+pkgarch = any
+pkgbuild_sha256sum = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+packager = Foobar McFooface <foobar@mcfooface.org>
+builddate = 1
+builddir = /build
+startdir = /startdir/
+buildtool = devtools
+buildtoolver = 1:1.2.1-1-any
+buildenv = ccache
+buildenv = color
+options = lto
+options = !strip
+installed = bar-1.2.3-1-any
+installed = beh-2.2.3-4-any
+"""
@@ -0,0 +1,20 @@
+"""Tests that all items in alpm_pkginfo module can be imported."""
+
+
+def test_shortcut_imports() -> None:
+    """Test that all items reexported from submodule can be imported from
+    alpm.alpm_pkginfo.
+    """
+    from alpm.alpm_pkginfo import (  # noqa: F401
+        PackageInfo,
+        PackageInfoError,
+        PackageInfoSchema,
+    )
+
+
+def test_imports() -> None:
+    """Test that all items can be imported without errors."""
+    from alpm.alpm_pkginfo import error, package_info, schema  # noqa: F401
+    from alpm.alpm_pkginfo.error import PackageInfoError  # noqa: F401
+    from alpm.alpm_pkginfo.package_info import PackageInfo  # noqa: F401
+    from alpm.alpm_pkginfo.schema import PackageInfoSchema  # noqa: F401
@@ -0,0 +1,119 @@
+"""Tests for PackageInfo."""
+
+import tempfile
+from pathlib import Path
+
+import pytest
+from alpm.alpm_pkginfo import PackageInfo, PackageInfoError, PackageInfoSchema
+from alpm.alpm_types import (
+    Architecture,
+    Epoch,
+    FullVersion,
+    License,
+    PackageRelease,
+    PackageVersion,
+)
+
+
+def test_from_str_v1(valid_pkginfo_v1_content: str) -> None:
+    """Test parsing valid PKGINFO version 1 data from string."""
+    pkginfo = PackageInfo(valid_pkginfo_v1_content)
+    assert pkginfo.schema == PackageInfoSchema("1")
+    assert pkginfo.pkgname == "example"
+    assert pkginfo.xdata is None
+
+
+def test_from_str_v2(valid_pkginfo_v2_content: str) -> None:
+    """Test parsing valid PKGINFO version 2 data from string."""
+    pkginfo = PackageInfo(valid_pkginfo_v2_content)
+    assert pkginfo.schema == PackageInfoSchema("2")
+    assert pkginfo.pkgname == "example"
+    assert pkginfo.pkgbase == "example"
+    assert pkginfo.pkgver == FullVersion(
+        PackageVersion("1.0.0"), PackageRelease(1), Epoch(1)
+    )
+    assert pkginfo.pkgdesc == "A project that does something"
+    assert str(pkginfo.url) == "https://example.org/"
+    assert pkginfo.builddate == 1729181726
+    assert pkginfo.packager == "John Doe <john@example.org>"
+    assert pkginfo.size == 181849963
+    assert pkginfo.arch == Architecture("any")
+    assert pkginfo.license == [
+        License("GPL-3.0-or-later"),
+        License("LGPL-3.0-or-later"),
+    ]
+    assert [str(relation) for relation in pkginfo.replaces] == [
+        "other-package>0.9.0-3"
+    ]
+    assert pkginfo.group == ["package-group"]
+    assert [str(relation) for relation in pkginfo.conflict] == [
+        "conflicting-package<1.0.0"
+    ]
+    assert [str(relation) for relation in pkginfo.provides] == ["some-component"]
+    assert [str(path) for path in pkginfo.backup] == ["etc/example/config.toml"]
+    assert [str(relation) for relation in pkginfo.depend] == [
+        "glibc",
+        "libother.so=0-64",
+    ]
+    assert [str(dependency) for dependency in pkginfo.optdepend] == [
+        "python: for special-python-script.py"
+    ]
+    assert [str(relation) for relation in pkginfo.makedepend] == ["cmake"]
+    assert [str(relation) for relation in pkginfo.checkdepend] == ["extra-test-tool"]
+    assert pkginfo.xdata == [("pkgtype", "pkg")]
+
+
+def test_from_str_with_schema(valid_pkginfo_v2_content: str) -> None:
+    """Test parsing PKGINFO data from string with explicit schema."""
+    pkginfo = PackageInfo(valid_pkginfo_v2_content, PackageInfoSchema("2"))
+    assert pkginfo.schema == PackageInfoSchema("2")
+
+
+def test_from_str_schema_mismatch(valid_pkginfo_v1_content: str) -> None:
+    """Test parsing PKGINFO data that does not match the schema raises error."""
+    with pytest.raises(PackageInfoError):
+        PackageInfo(valid_pkginfo_v1_content, PackageInfoSchema("2"))
+
+
+@pytest.mark.parametrize(
+    "content",
+    [
+        "not valid",
+        "",
+        "pkgname = example\n",
+    ],
+)
+def test_from_str_invalid(content: str) -> None:
+    """Test parsing invalid PKGINFO data from string raises error."""
+    with pytest.raises(PackageInfoError):
+        PackageInfo(content)
+
+
+@pytest.mark.parametrize("schema", [None, PackageInfoSchema("2")])
+def test_from_file(
+    valid_pkginfo_v2_content: str, schema: PackageInfoSchema | None
+) -> None:
+    """Test parsing PKGINFO data from file."""
+    with tempfile.NamedTemporaryFile(mode="w", suffix=".PKGINFO", delete=True) as tmp:
+        tmp.write(valid_pkginfo_v2_content)
+        tmp.flush()
+
+        pkginfo = PackageInfo.from_file(Path(tmp.name), schema)
+        assert pkginfo == PackageInfo(valid_pkginfo_v2_content)
+
+
+def test_from_file_nonexistent() -> None:
+    """Test parsing PKGINFO data from nonexistent file raises error."""
+    with pytest.raises(PackageInfoError):
+        PackageInfo.from_file("/nonexistent/file.PKGINFO")
+
+
+@pytest.mark.parametrize(
+    "content_fixture", ["valid_pkginfo_v1_content", "valid_pkginfo_v2_content"]
+)
+def test_to_str(content_fixture: str, request: pytest.FixtureRequest) -> None:
+    """Test that PKGINFO data roundtrips through its string representation."""
+    content = request.getfixturevalue(content_fixture)
+    pkginfo = PackageInfo(content)
+    assert pkginfo.to_str() == content
+    assert str(pkginfo) == content
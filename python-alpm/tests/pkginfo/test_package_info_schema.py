@@ -0,0 +1,48 @@
+"""Tests for PackageInfoSchema."""
+
+import tempfile
+
+import pytest
+from alpm.alpm_pkginfo import PackageInfoError, PackageInfoSchema
+from alpm.alpm_types import SchemaVersion
+
+
+def test_creation() -> None:
+    """Test creating PackageInfoSchema with different version formats."""
+    assert str(PackageInfoSchema("1").version) == "1.0.0"
+    assert str(PackageInfoSchema("2.0.0").version) == "2.0.0"
+    assert str(PackageInfoSchema(SchemaVersion(2)).version) == "2.0.0"
+
+
+def test_creation_unsupported_version() -> None:
+    """Test creating PackageInfoSchema with an unsupported version raises error."""
+    with pytest.raises(PackageInfoError):
+        PackageInfoSchema("3")
+
+
+def test_derive_from_str(
+    valid_pkginfo_v1_content: str, valid_pkginfo_v2_content: str
+) -> None:
+    """Test deriving PackageInfoSchema from PKGINFO string content."""
+    assert PackageInfoSchema.derive_from_str(
+        valid_pkginfo_v1_content
+    ) == PackageInfoSchema("1")
+    assert PackageInfoSchema.derive_from_str(
+        valid_pkginfo_v2_content
+    ) == PackageInfoSchema("2")
+
+
+def test_derive_from_file(valid_pkginfo_v2_content: str) -> None:
+    """Test deriving PackageInfoSchema from PKGINFO file."""
+    with tempfile.NamedTemporaryFile(mode="w", suffix=".PKGINFO", delete=True) as tmp:
+        tmp.write(valid_pkginfo_v2_content)
+        tmp.flush()
+
+        schema = PackageInfoSchema.derive_from_file(tmp.name)
+        assert str(schema.version) == "2.0.0"
+
+
+def test_derive_from_file_nonexistent() -> None:
+    """Test deriving PackageInfoSchema from nonexistent file raises error."""
+    with pytest.raises(PackageInfoError):
+        PackageInfoSchema.derive_from_file("/nonexistent/file.PKGINFO")
@@ -52,3 +52,26 @@ def test_source_info_v1_from_pkgbuild_file_invalid() -> None:
         tmp.flush()
         with pytest.raises(alpm_srcinfo.SourceInfoError):
             alpm_srcinfo.SourceInfoV1.from_pkgbuild(tmp.name)
+
+
+def test_source_info_v1_merged_packages(valid_srcinfo_content: str) -> None:
+    """Test getting the merged packages for all architectures."""
+    srcinfo = alpm_srcinfo.SourceInfoV1(valid_srcinfo_content)
+    packages = srcinfo.merged_packages()
+    assert sorted(str(package.architecture) for package in packages) == [
+        "aarch64",
+        "x86_64",
+    ]
+    assert all(package.name == "example" for package in packages)
+
+
+def test_merged_package_to_dict(valid_srcinfo_content: str) -> None:
+    """Test converting a merged package to a dict."""
+    srcinfo = alpm_srcinfo.SourceInfoV1(valid_srcinfo_content)
+    package = srcinfo.merged_packages()[0]
+    package_dict = package.to_dict()
+    assert package_dict["name"] == "example"
+    assert package_dict["description"] == "overridden"
+    assert package_dict["groups"] == ["overridden"]
+    assert package_dict["architecture"] == package.architecture
+    assert package_dict["sources"] == []
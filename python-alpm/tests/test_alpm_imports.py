@@ -4,7 +4,14 @@
 def test_imports() -> None:
     """Test that all items can be imported without errors."""
     import alpm  # noqa: F401
-    from alpm import ALPMError, alpm_srcinfo, alpm_types, type_aliases  # noqa: F401
+    from alpm import (  # noqa: F401
+        ALPMError,
+        alpm_buildinfo,
+        alpm_pkginfo,
+        alpm_srcinfo,
+        alpm_types,
+        type_aliases,
+    )
 
 
 def test_type_aliases_imports() -> None:
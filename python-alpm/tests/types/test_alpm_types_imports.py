@@ -57,4 +57,5 @@ def test_imports() -> None:
         makepkg_option_from_str,
         openpgp_identifier_from_str,
         relation_or_soname_from_str,
+        vercmp,
     )
@@ -414,6 +414,38 @@ def test_version_repr() -> None:
     assert "Version" in repr_str
 
 
+@pytest.mark.parametrize(
+    "a, b, expected",
+    [
+        ("1.0", "1.0", 0),
+        ("1.0", "1.0.0", -1),
+        ("1.1", "1.0", 1),
+        ("1.0-2", "1.0-1", 1),
+        ("1.0", "1.0-1", 0),
+        ("1:1.0", "2.0", 1),
+        ("1.0a", "1.0", -1),
+    ],
+)
+def test_vercmp(a: str, b: str, expected: int) -> None:
+    """Test vercmp with string representations of versions."""
+    assert alpm_types.vercmp(a, b) == expected
+    assert alpm_types.vercmp(b, a) == -expected
+
+
+def test_vercmp_with_version_objects() -> None:
+    """Test vercmp with Version and FullVersion objects."""
+    version = alpm_types.Version.from_str("1.2.3")
+    full_version = alpm_types.FullVersion.from_str("1.2.4-1")
+    assert alpm_types.vercmp(version, full_version) == -1
+    assert alpm_types.vercmp(full_version, "1.2.4-1") == 0
+
+
+def test_vercmp_invalid() -> None:
+    """Test vercmp with an invalid version string raises error."""
+    with pytest.raises(ALPMError):
+        alpm_types.vercmp("1.0-", "1.0")
+
+
 def test_schema_version_error_handling() -> None:
     """Test SchemaVersion error handling for invalid pre-release and build metadata."""
     # Test invalid pre-release identifier